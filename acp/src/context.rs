@@ -510,6 +510,8 @@ fn append_catalog_signature_parts(nodes: &[ExplorerNode], signature: &mut String
             ExplorerNodeKind::Schema => "schema:",
             ExplorerNodeKind::Table => "table:",
             ExplorerNodeKind::View => "view:",
+            ExplorerNodeKind::MaterializedView => "matview:",
+            ExplorerNodeKind::ForeignTable => "foreign:",
         });
         signature.push_str(&node.qualified_name);
         signature.push('|');
@@ -681,12 +683,22 @@ fn append_catalog_summary(lines: &mut Vec<String>, nodes: &[ExplorerNode]) {
                 let table_count = node
                     .children
                     .iter()
-                    .filter(|child| matches!(child.kind, ExplorerNodeKind::Table))
+                    .filter(|child| {
+                        matches!(
+                            child.kind,
+                            ExplorerNodeKind::Table | ExplorerNodeKind::ForeignTable
+                        )
+                    })
                     .count();
                 let view_count = node
                     .children
                     .iter()
-                    .filter(|child| matches!(child.kind, ExplorerNodeKind::View))
+                    .filter(|child| {
+                        matches!(
+                            child.kind,
+                            ExplorerNodeKind::View | ExplorerNodeKind::MaterializedView
+                        )
+                    })
                     .count();
                 let relation_names = node
                     .children
@@ -702,10 +714,15 @@ fn append_catalog_summary(lines: &mut Vec<String>, nodes: &[ExplorerNode]) {
                 }
                 lines.push(summary);
             }
-            ExplorerNodeKind::Table | ExplorerNodeKind::View => {
+            ExplorerNodeKind::Table
+            | ExplorerNodeKind::View
+            | ExplorerNodeKind::MaterializedView
+            | ExplorerNodeKind::ForeignTable => {
                 let kind = match node.kind {
                     ExplorerNodeKind::Table => "table",
                     ExplorerNodeKind::View => "view",
+                    ExplorerNodeKind::MaterializedView => "materialized view",
+                    ExplorerNodeKind::ForeignTable => "foreign table",
                     ExplorerNodeKind::Schema => unreachable!(),
                 };
                 lines.push(format!("- {kind}: {}", node.qualified_name));
@@ -717,7 +734,10 @@ fn append_catalog_summary(lines: &mut Vec<String>, nodes: &[ExplorerNode]) {
 fn count_relations(node: &ExplorerNode) -> usize {
     match node.kind {
         ExplorerNodeKind::Schema => node.children.iter().map(count_relations).sum(),
-        ExplorerNodeKind::Table | ExplorerNodeKind::View => 1,
+        ExplorerNodeKind::Table
+        | ExplorerNodeKind::View
+        | ExplorerNodeKind::MaterializedView
+        | ExplorerNodeKind::ForeignTable => 1,
     }
 }
 
@@ -805,7 +825,10 @@ fn collect_table_sources(nodes: &[ExplorerNode]) -> Vec<TablePreviewSource> {
 fn collect_table_sources_inner(nodes: &[ExplorerNode], sources: &mut Vec<TablePreviewSource>) {
     for node in nodes {
         match node.kind {
-            ExplorerNodeKind::Table | ExplorerNodeKind::View => sources.push(TablePreviewSource {
+            ExplorerNodeKind::Table
+            | ExplorerNodeKind::View
+            | ExplorerNodeKind::MaterializedView
+            | ExplorerNodeKind::ForeignTable => sources.push(TablePreviewSource {
                 schema: node.schema.clone(),
                 table_name: node.name.clone(),
                 qualified_name: node.qualified_name.clone(),
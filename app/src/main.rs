@@ -11,7 +11,11 @@ use dioxus::{
     LaunchBuilder,
     desktop::{
         Config, LogicalSize, WindowBuilder,
-        tao::{event_loop::EventLoopBuilder, window::Icon as TaoIcon},
+        tao::{
+            dpi::LogicalPosition,
+            event_loop::{EventLoop, EventLoopBuilder},
+            window::Icon as TaoIcon,
+        },
     },
     prelude::*,
 };
@@ -74,6 +78,7 @@ fn launch_app() {
     ))]
     event_loop_builder.with_app_id("dev.shovel.app");
     let event_loop = event_loop_builder.build();
+    let window = main_window_builder(&event_loop);
 
     LaunchBuilder::desktop()
         .with_cfg(
@@ -86,21 +91,41 @@ fn launch_app() {
                 // This is the only practical GPU-backed improvement available in the current
                 // Dioxus desktop/webview renderer without rewriting the app around WGPU/Freya.
                 .with_disable_dma_buf_on_wayland(should_disable_wayland_dma_buf())
-                .with_window(main_window_builder()),
+                .with_window(window),
         )
         .launch(Root);
 }
 
-fn main_window_builder() -> WindowBuilder {
-    let window = WindowBuilder::new()
+/// The main window, opened at the size, position and maximized state it had
+/// when the app last closed.
+fn main_window_builder<T>(event_loop: &EventLoop<T>) -> WindowBuilder {
+    let geometry = ui::saved_window_geometry();
+    let mut window = WindowBuilder::new()
         .with_title("Shovel")
-        .with_inner_size(LogicalSize::new(1440.0, 920.0))
+        .with_inner_size(LogicalSize::new(geometry.width, geometry.height))
         .with_min_inner_size(LogicalSize::new(720.0, 480.0))
+        .with_maximized(geometry.maximized)
         .with_always_on_top(false)
         .with_resizable(true)
         .with_decorations(false)
         .with_window_icon(Some(load_app_icon()));
 
+    // A position on a monitor that has since been unplugged would open the
+    // window off screen, so it is only used while its title bar is visible.
+    if let (Some(x), Some(y)) = (geometry.x, geometry.y) {
+        let title_bar = (x + geometry.width / 2.0, y + 16.0);
+        let on_screen = event_loop.available_monitors().any(|monitor| {
+            let scale = monitor.scale_factor();
+            let origin = monitor.position().to_logical::<f64>(scale);
+            let size = monitor.size().to_logical::<f64>(scale);
+            (origin.x..origin.x + size.width).contains(&title_bar.0)
+                && (origin.y..origin.y + size.height).contains(&title_bar.1)
+        });
+        if on_screen {
+            window = window.with_position(LogicalPosition::new(x, y));
+        }
+    }
+
     #[cfg(target_os = "windows")]
     let window = window.with_taskbar_icon(Some(load_app_icon()));
 
@@ -1,6 +1,7 @@
-use models::SshTunnelConfig;
+use models::{SshAuth, SshTunnelConfig};
 use std::{
     collections::HashMap,
+    path::PathBuf,
     process::Stdio,
     sync::{Arc, Mutex, MutexGuard, OnceLock},
 };
@@ -19,8 +20,26 @@ pub struct OpenedSshTunnel {
 
 struct SshTunnelHandle {
     child: Mutex<Option<Child>>,
+    /// Kept while the tunnel is open, since ssh may ask for the password
+    /// at any point while it connects.
+    askpass: Mutex<Option<AskpassScript>>,
 }
 
+/// Askpass helper in a directory only the current user can enter. Both are
+/// removed when this is dropped.
+struct AskpassScript {
+    dir: PathBuf,
+    path: PathBuf,
+}
+
+impl Drop for AskpassScript {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.dir);
+    }
+}
+
+const ASKPASS_PASSWORD_ENV: &str = "SHOVEL_SSH_PASSWORD";
+
 static SSH_TUNNELS: OnceLock<Mutex<HashMap<String, Arc<SshTunnelHandle>>>> = OnceLock::new();
 
 pub async fn open_ssh_tunnel(
@@ -53,8 +72,6 @@ pub async fn open_ssh_tunnel(
         .arg("-l")
         .arg(ssh_user)
         .arg("-o")
-        .arg("BatchMode=yes")
-        .arg("-o")
         .arg("ExitOnForwardFailure=yes")
         .arg("-o")
         .arg("ConnectTimeout=10")
@@ -63,13 +80,38 @@ pub async fn open_ssh_tunnel(
         .arg("-o")
         .arg("ServerAliveCountMax=3");
 
-    if !config.private_key_path.trim().is_empty() {
-        command
-            .arg("-i")
-            .arg(config.private_key_path.trim())
-            .arg("-o")
-            .arg("IdentitiesOnly=yes");
-    }
+    let askpass_script = match config.auth() {
+        SshAuth::PrivateKey(key_path) => {
+            command
+                .arg("-o")
+                .arg("BatchMode=yes")
+                .arg("-i")
+                .arg(key_path)
+                .arg("-o")
+                .arg("IdentitiesOnly=yes");
+            None
+        }
+        SshAuth::Password(password) => {
+            // ssh never reads passwords from stdin, so hand it over through an
+            // askpass helper that echoes an environment variable back.
+            let script = write_askpass_script()?;
+            command
+                .arg("-o")
+                .arg("PreferredAuthentications=password,keyboard-interactive")
+                .arg("-o")
+                .arg("PubkeyAuthentication=no")
+                .arg("-o")
+                .arg("NumberOfPasswordPrompts=1")
+                .env("SSH_ASKPASS", &script.path)
+                .env("SSH_ASKPASS_REQUIRE", "force")
+                .env(ASKPASS_PASSWORD_ENV, password);
+            Some(script)
+        }
+        SshAuth::Agent => {
+            command.arg("-o").arg("BatchMode=yes");
+            None
+        }
+    };
 
     command
         .arg(ssh_host)
@@ -100,7 +142,9 @@ pub async fn open_ssh_tunnel(
                 return Err(format!("ssh tunnel failed: {details}"));
             }
             Ok(None) => sleep(Duration::from_millis(150)).await,
-            Err(err) => return Err(format!("failed to monitor ssh tunnel process: {err}")),
+            Err(err) => {
+                return Err(format!("failed to monitor ssh tunnel process: {err}"));
+            }
         }
     }
 
@@ -108,6 +152,7 @@ pub async fn open_ssh_tunnel(
         local_port,
         handle: Arc::new(SshTunnelHandle {
             child: Mutex::new(Some(child)),
+            askpass: Mutex::new(askpass_script),
         }),
     })
 }
@@ -138,6 +183,59 @@ async fn allocate_local_port() -> Result<u16, String> {
     Ok(port)
 }
 
+/// Writes the askpass helper into a fresh directory with mode 0700. Creating
+/// the directory and the file both fail if something already exists at the
+/// path, so another local user cannot plant a file or symlink there first.
+#[cfg(unix)]
+fn write_askpass_script() -> Result<AskpassScript, String> {
+    use std::{
+        fs,
+        io::Write,
+        os::unix::fs::{DirBuilderExt, OpenOptionsExt},
+        sync::atomic::{AtomicU64, Ordering},
+        time::{SystemTime, UNIX_EPOCH},
+    };
+
+    static ASKPASS_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.subsec_nanos())
+        .unwrap_or_default();
+    let dir = std::env::temp_dir().join(format!(
+        "shovel-askpass-{}-{}-{nanos}",
+        std::process::id(),
+        ASKPASS_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    fs::DirBuilder::new()
+        .mode(0o700)
+        .create(&dir)
+        .map_err(|err| format!("failed to create ssh askpass directory: {err}"))?;
+    let script = AskpassScript {
+        path: dir.join("askpass.sh"),
+        dir,
+    };
+
+    let mut file = fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .mode(0o700)
+        .open(&script.path)
+        .map_err(|err| format!("failed to create ssh askpass helper: {err}"))?;
+    let contents = format!("#!/bin/sh\nprintf '%s\\n' \"${ASKPASS_PASSWORD_ENV}\"\n");
+    file.write_all(contents.as_bytes())
+        .map_err(|err| format!("failed to write ssh askpass helper: {err}"))?;
+    Ok(script)
+}
+
+#[cfg(not(unix))]
+fn write_askpass_script() -> Result<AskpassScript, String> {
+    Err(
+        "SSH password authentication is not supported on this platform, use a private key"
+            .to_string(),
+    )
+}
+
 fn ssh_tunnels() -> &'static Mutex<HashMap<String, Arc<SshTunnelHandle>>> {
     SSH_TUNNELS.get_or_init(|| Mutex::new(HashMap::new()))
 }
@@ -150,6 +248,10 @@ fn ssh_tunnels_guard() -> MutexGuard<'static, HashMap<String, Arc<SshTunnelHandl
 }
 
 fn shutdown_tunnel(tunnel: Arc<SshTunnelHandle>) {
+    if let Ok(mut guard) = tunnel.askpass.lock() {
+        guard.take();
+    }
+
     let child = match tunnel.child.lock() {
         Ok(mut guard) => guard.take(),
         Err(_) => None,
@@ -212,4 +314,27 @@ mod tests {
         // `SSH_TUNNELS` map and may kill child processes. These should be tested
         // with integration tests that can safely manage that state.
     }
+
+    #[cfg(unix)]
+    #[test]
+    fn askpass_script_lives_in_a_private_directory_removed_on_drop() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let script = super::write_askpass_script().expect("askpass helper should be written");
+        let dir = script.dir.clone();
+        let mode = std::fs::metadata(&dir)
+            .expect("askpass directory should exist")
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o777, 0o700);
+        assert!(script.path.starts_with(&dir));
+        assert!(
+            std::fs::read_to_string(&script.path)
+                .expect("askpass helper should be readable")
+                .contains(super::ASKPASS_PASSWORD_ENV)
+        );
+
+        drop(script);
+        assert!(!dir.exists());
+    }
 }
@@ -21,6 +21,7 @@ use models::{
 };
 #[cfg(feature = "clickhouse")]
 use reqwest::Url;
+use std::sync::atomic::{AtomicU64, Ordering};
 
 /// Releases an SSH tunnel that was previously opened for a connection
 /// session, identified by the session's identity key.
@@ -171,7 +172,32 @@ pub async fn connect_to_db(
     request: ConnectionRequest,
 ) -> Result<DatabaseConnection, DatabaseError> {
     let session_key = request.identity_key();
+    connect_with_session_key(request, &session_key).await
+}
+
+/// Opens a short-lived connection for a connection test.
+///
+/// Any SSH tunnel is registered under the returned probe key instead of the
+/// request's identity key, so a session already open to the same target keeps
+/// its tunnel. Pass the key to [`release_ssh_tunnel`] once the test is done.
+pub async fn connect_for_test(
+    request: ConnectionRequest,
+) -> Result<(DatabaseConnection, String), DatabaseError> {
+    static NEXT_PROBE_ID: AtomicU64 = AtomicU64::new(1);
+
+    let probe_key = format!(
+        "test-{}:{}",
+        NEXT_PROBE_ID.fetch_add(1, Ordering::Relaxed),
+        request.identity_key()
+    );
+    let connection = connect_with_session_key(request, &probe_key).await?;
+    Ok((connection, probe_key))
+}
 
+async fn connect_with_session_key(
+    request: ConnectionRequest,
+    session_key: &str,
+) -> Result<DatabaseConnection, DatabaseError> {
     match request {
         #[cfg(feature = "sqlite")]
         ConnectionRequest::Sqlite(data) => {
@@ -203,6 +229,7 @@ pub async fn connect_to_db(
                     username: data.username.clone(),
                     password: data.password.clone(),
                     database: data.database.clone(),
+                    parameters: data.parameters.clone(),
                 };
                 PgDriver::connect(config)
                     .await
@@ -211,7 +238,7 @@ pub async fn connect_to_db(
             }
             .await;
 
-            finalize_tunnel(&session_key, resolved, &result);
+            finalize_tunnel(session_key, resolved, &result);
             result
         }
         #[cfg(feature = "mysql")]
@@ -249,7 +276,7 @@ pub async fn connect_to_db(
             }
             .await;
 
-            finalize_tunnel(&session_key, resolved, &result);
+            finalize_tunnel(session_key, resolved, &result);
             result
         }
         #[cfg(feature = "clickhouse")]
@@ -288,7 +315,7 @@ pub async fn connect_to_db(
             }
             .await;
 
-            finalize_tunnel(&session_key, resolved, &result);
+            finalize_tunnel(session_key, resolved, &result);
             result
         }
         #[allow(unreachable_patterns)]
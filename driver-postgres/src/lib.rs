@@ -8,6 +8,8 @@ pub struct PgConfig {
     pub username: String,
     pub password: String,
     pub database: String,
+    /// Extra libpq-style parameters such as `sslmode` or `application_name`.
+    pub parameters: Vec<(String, String)>,
 }
 
 pub struct PgDriver {}
@@ -41,6 +43,7 @@ impl database::DatabaseDriver for PgDriver {
                 .password(&info.password)
                 .database(&database)
                 .ssl_mode(PgSslMode::Prefer);
+            let options = with_parameters(options, &info.parameters)?;
 
             match Self::Pool::connect_with(options).await {
                 Ok(pool) => return Ok(pool),
@@ -54,6 +57,21 @@ impl database::DatabaseDriver for PgDriver {
     }
 }
 
+fn with_parameters(
+    mut options: PgConnectOptions,
+    parameters: &[(String, String)],
+) -> Result<PgConnectOptions, sqlx::Error> {
+    for (key, value) in parameters {
+        options = match key.as_str() {
+            "sslmode" => options.ssl_mode(PgSslMode::from_str(value)?),
+            "sslrootcert" => options.ssl_root_cert(value),
+            "application_name" => options.application_name(value),
+            _ => options,
+        };
+    }
+    Ok(options)
+}
+
 fn looks_like_dsn(value: &str) -> bool {
     let value = value.trim().to_ascii_lowercase();
     value.starts_with("postgres://") || value.starts_with("postgresql://")
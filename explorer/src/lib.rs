@@ -1,17 +1,34 @@
 use database::DatabaseDriver;
 use driver_clickhouse::ClickHouseDriver;
-use models::{DatabaseConnection, DatabaseError, ExplorerNode, ExplorerNodeKind, QueryOutput};
+use models::{
+    ColumnInfo, DatabaseConnection, DatabaseError, ExplorerNode, ExplorerNodeKind,
+    MaterializedViewInfo, QueryOutput, RoleInfo, RoutineInfo, SchemaColumn, SchemaTable,
+    SequenceInfo, TableCatalogInfo, TableGrant, TableIndex, TableRowSecurity,
+};
 use sqlx::Row;
 
 mod mysql;
 mod postgres;
 mod sqlite;
 
-pub use mysql::{describe_table_mysql, load_connection_tree_mysql, load_table_columns_mysql};
+pub use mysql::{
+    describe_table_mysql, load_column_index_mysql, load_connection_tree_mysql,
+    load_routine_definition_mysql, load_schema_routines_mysql, load_schema_table_catalog_mysql,
+    load_table_column_info_mysql, load_table_columns_mysql, load_table_ddl_mysql,
+};
 pub use postgres::{
-    describe_table_postgres, load_connection_tree_postgres, load_table_columns_postgres,
+    describe_table_postgres, load_column_index_postgres, load_connection_tree_postgres,
+    load_databases_postgres, load_roles_postgres, load_routine_definition_postgres,
+    load_schema_materialized_views_postgres, load_schema_routines_postgres,
+    load_schema_sequences_postgres, load_schema_table_catalog_postgres,
+    load_schema_tables_postgres, load_table_column_info_postgres, load_table_columns_postgres,
+    load_table_ddl_postgres, load_table_grants_postgres, load_table_indexes_postgres,
+    load_table_row_security_postgres,
+};
+pub use sqlite::{
+    describe_table_sqlite, load_column_index_sqlite, load_connection_tree_sqlite,
+    load_table_column_info_sqlite, load_table_columns_sqlite, load_table_ddl_sqlite,
 };
-pub use sqlite::{describe_table_sqlite, load_connection_tree_sqlite, load_table_columns_sqlite};
 
 pub async fn describe_table(
     connection: DatabaseConnection,
@@ -142,6 +159,36 @@ pub async fn describe_table(
     }
 }
 
+/// CREATE statements that rebuild `table`: the table itself, then indexes
+/// the statement does not already cover.
+pub async fn load_table_ddl(
+    connection: DatabaseConnection,
+    schema: Option<String>,
+    table: String,
+) -> Result<String, DatabaseError> {
+    match connection {
+        DatabaseConnection::Sqlite(pool) => load_table_ddl_sqlite(&pool, schema, table).await,
+        DatabaseConnection::Postgres(pool) => load_table_ddl_postgres(&pool, schema, table).await,
+        DatabaseConnection::MySql(pool) => load_table_ddl_mysql(&pool, schema, table).await,
+        DatabaseConnection::ClickHouse(config) => {
+            let schema_name = schema.unwrap_or_else(|| config.database.clone());
+            let create_sql = if schema_name.is_empty() {
+                format!("SHOW CREATE TABLE {}", quote_clickhouse_identifier(&table))
+            } else {
+                format!(
+                    "SHOW CREATE TABLE {}.{}",
+                    quote_clickhouse_identifier(&schema_name),
+                    quote_clickhouse_identifier(&table)
+                )
+            };
+            let create_statement = ClickHouseDriver
+                .execute_text_query(&config, &create_sql)
+                .await?;
+            Ok(format!("{};", create_statement.trim_end()))
+        }
+    }
+}
+
 pub async fn load_table_columns(
     connection: DatabaseConnection,
     schema: Option<String>,
@@ -171,6 +218,280 @@ pub async fn load_table_columns(
     }
 }
 
+/// Lists the columns of `table` with their type and key membership, for the
+/// column nodes under a table in the explorer tree.
+pub async fn load_table_column_info(
+    connection: DatabaseConnection,
+    schema: Option<String>,
+    table: String,
+) -> Result<Vec<ColumnInfo>, DatabaseError> {
+    match connection {
+        DatabaseConnection::Sqlite(pool) => {
+            load_table_column_info_sqlite(&pool, schema, table).await
+        }
+        DatabaseConnection::Postgres(pool) => {
+            load_table_column_info_postgres(&pool, schema, table).await
+        }
+        DatabaseConnection::MySql(pool) => load_table_column_info_mysql(&pool, schema, table).await,
+        DatabaseConnection::ClickHouse(config) => {
+            let schema_name = schema.unwrap_or_else(|| config.database.clone());
+            let sql = format!(
+                "select name, type, is_in_primary_key, default_expression, default_kind, comment from system.columns where database = {} and table = {} order by position",
+                clickhouse_string_literal(&schema_name),
+                clickhouse_string_literal(&table)
+            );
+            let response = ClickHouseDriver.execute_json_query(&config, &sql).await?;
+
+            Ok(response
+                .data
+                .into_iter()
+                .map(|row| {
+                    let data_type = clickhouse_value_to_string(row.get(1));
+                    ColumnInfo {
+                        name: clickhouse_value_to_string(row.first()),
+                        nullable: data_type.starts_with("Nullable("),
+                        data_type,
+                        primary_key: clickhouse_value_to_string(row.get(2)) == "1",
+                        foreign_key: false,
+                        default_value: Some(clickhouse_value_to_string(row.get(3)))
+                            .filter(|value| !value.is_empty()),
+                        identity: false,
+                        generated: matches!(
+                            clickhouse_value_to_string(row.get(4)).as_str(),
+                            "MATERIALIZED" | "ALIAS"
+                        ),
+                        comment: Some(clickhouse_value_to_string(row.get(5)))
+                            .filter(|value| !value.is_empty()),
+                    }
+                })
+                .collect())
+        }
+    }
+}
+
+/// Lists every column of every table and view on the connection, for the
+/// database tree filter.
+pub async fn load_column_index(
+    connection: DatabaseConnection,
+) -> Result<Vec<SchemaColumn>, DatabaseError> {
+    match connection {
+        DatabaseConnection::Sqlite(pool) => load_column_index_sqlite(&pool).await,
+        DatabaseConnection::Postgres(pool) => load_column_index_postgres(&pool).await,
+        DatabaseConnection::MySql(pool) => load_column_index_mysql(&pool).await,
+        DatabaseConnection::ClickHouse(config) => {
+            let sql = "select database, table, name from system.columns where database not in ('system', 'INFORMATION_SCHEMA', 'information_schema') order by database, table, position";
+            let response = ClickHouseDriver.execute_json_query(&config, sql).await?;
+
+            Ok(response
+                .data
+                .into_iter()
+                .map(|row| SchemaColumn {
+                    schema: Some(clickhouse_value_to_string(row.first())),
+                    table_name: clickhouse_value_to_string(row.get(1)),
+                    column_name: clickhouse_value_to_string(row.get(2)),
+                })
+                .collect())
+        }
+    }
+}
+
+/// Lists the functions and procedures in `schema`. Drivers without stored
+/// routines return an empty list.
+pub async fn load_schema_routines(
+    connection: DatabaseConnection,
+    schema: String,
+) -> Result<Vec<RoutineInfo>, DatabaseError> {
+    match connection {
+        DatabaseConnection::Postgres(pool) => load_schema_routines_postgres(&pool, schema).await,
+        DatabaseConnection::MySql(pool) => load_schema_routines_mysql(&pool, schema).await,
+        DatabaseConnection::Sqlite(_) | DatabaseConnection::ClickHouse(_) => Ok(Vec::new()),
+    }
+}
+
+/// Lists the sequences in `schema`. Only PostgreSQL has standalone sequences;
+/// other drivers return an empty list.
+pub async fn load_schema_sequences(
+    connection: DatabaseConnection,
+    schema: String,
+) -> Result<Vec<SequenceInfo>, DatabaseError> {
+    match connection {
+        DatabaseConnection::Postgres(pool) => load_schema_sequences_postgres(&pool, schema).await,
+        DatabaseConnection::Sqlite(_)
+        | DatabaseConnection::MySql(_)
+        | DatabaseConnection::ClickHouse(_) => Ok(Vec::new()),
+    }
+}
+
+/// Loads whether row-level security is on for a table and the policies
+/// defined on it. Only PostgreSQL has row security.
+pub async fn load_table_row_security(
+    connection: DatabaseConnection,
+    schema: Option<String>,
+    table: String,
+) -> Result<TableRowSecurity, DatabaseError> {
+    match connection {
+        DatabaseConnection::Postgres(pool) => {
+            load_table_row_security_postgres(&pool, schema, table).await
+        }
+        DatabaseConnection::Sqlite(_)
+        | DatabaseConnection::MySql(_)
+        | DatabaseConnection::ClickHouse(_) => Err(DatabaseError::UnsupportedDriver(
+            "Row-level security is only available for PostgreSQL.".to_string(),
+        )),
+    }
+}
+
+/// Lists the server's roles, leaving out the built-in `pg_*` ones. Only
+/// PostgreSQL is supported.
+pub async fn load_roles(connection: DatabaseConnection) -> Result<Vec<RoleInfo>, DatabaseError> {
+    match connection {
+        DatabaseConnection::Postgres(pool) => load_roles_postgres(&pool).await,
+        DatabaseConnection::Sqlite(_)
+        | DatabaseConnection::MySql(_)
+        | DatabaseConnection::ClickHouse(_) => Err(DatabaseError::UnsupportedDriver(
+            "Roles are only available for PostgreSQL.".to_string(),
+        )),
+    }
+}
+
+/// Lists the privileges granted on a table, one entry per role and
+/// privilege. Only PostgreSQL is supported.
+pub async fn load_table_grants(
+    connection: DatabaseConnection,
+    schema: Option<String>,
+    table: String,
+) -> Result<Vec<TableGrant>, DatabaseError> {
+    match connection {
+        DatabaseConnection::Postgres(pool) => {
+            load_table_grants_postgres(&pool, schema, table).await
+        }
+        DatabaseConnection::Sqlite(_)
+        | DatabaseConnection::MySql(_)
+        | DatabaseConnection::ClickHouse(_) => Err(DatabaseError::UnsupportedDriver(
+            "Table privileges are only available for PostgreSQL.".to_string(),
+        )),
+    }
+}
+
+/// Lists the indexes of a table with the constraint each one backs, if any.
+/// Only PostgreSQL is supported.
+pub async fn load_table_indexes(
+    connection: DatabaseConnection,
+    schema: Option<String>,
+    table: String,
+) -> Result<Vec<TableIndex>, DatabaseError> {
+    match connection {
+        DatabaseConnection::Postgres(pool) => {
+            load_table_indexes_postgres(&pool, schema, table).await
+        }
+        DatabaseConnection::Sqlite(_)
+        | DatabaseConnection::MySql(_)
+        | DatabaseConnection::ClickHouse(_) => Err(DatabaseError::UnsupportedDriver(
+            "Index management is only available for PostgreSQL.".to_string(),
+        )),
+    }
+}
+
+/// Lists the tables of `schema` with their columns, indexes and constraints,
+/// as compared by the schema diff. Only PostgreSQL is supported.
+pub async fn load_schema_tables(
+    connection: DatabaseConnection,
+    schema: String,
+) -> Result<Vec<SchemaTable>, DatabaseError> {
+    match connection {
+        DatabaseConnection::Postgres(pool) => load_schema_tables_postgres(&pool, schema).await,
+        DatabaseConnection::Sqlite(_)
+        | DatabaseConnection::MySql(_)
+        | DatabaseConnection::ClickHouse(_) => Err(DatabaseError::UnsupportedDriver(
+            "Schema diff is only available for PostgreSQL.".to_string(),
+        )),
+    }
+}
+
+/// Lists the materialized views in `schema` with whether each one holds data.
+/// Only PostgreSQL reports this; other drivers return an empty list.
+pub async fn load_schema_materialized_views(
+    connection: DatabaseConnection,
+    schema: String,
+) -> Result<Vec<MaterializedViewInfo>, DatabaseError> {
+    match connection {
+        DatabaseConnection::Postgres(pool) => {
+            load_schema_materialized_views_postgres(&pool, schema).await
+        }
+        DatabaseConnection::Sqlite(_)
+        | DatabaseConnection::MySql(_)
+        | DatabaseConnection::ClickHouse(_) => Ok(Vec::new()),
+    }
+}
+
+/// Loads the estimated row count, on-disk size and comment of every table in
+/// `schema` from the catalog, without scanning any table. SQLite keeps no such
+/// metadata and returns an empty list.
+pub async fn load_schema_table_catalog(
+    connection: DatabaseConnection,
+    schema: String,
+) -> Result<Vec<TableCatalogInfo>, DatabaseError> {
+    match connection {
+        DatabaseConnection::Postgres(pool) => {
+            load_schema_table_catalog_postgres(&pool, schema).await
+        }
+        DatabaseConnection::MySql(pool) => load_schema_table_catalog_mysql(&pool, schema).await,
+        DatabaseConnection::ClickHouse(config) => {
+            let sql = format!(
+                r#"
+                select name, total_rows, total_bytes, comment
+                from system.tables
+                where database = {}
+                order by name
+                "#,
+                clickhouse_string_literal(&schema)
+            );
+            let output = ClickHouseDriver.execute_json_query(&config, &sql).await?;
+            Ok(output
+                .data
+                .iter()
+                .map(|row| TableCatalogInfo {
+                    name: clickhouse_value_to_string(row.first()),
+                    estimated_rows: clickhouse_value_to_string(row.get(1)).parse().ok(),
+                    total_bytes: clickhouse_value_to_string(row.get(2)).parse().ok(),
+                    comment: Some(clickhouse_value_to_string(row.get(3)))
+                        .filter(|value| !value.is_empty()),
+                })
+                .collect())
+        }
+        DatabaseConnection::Sqlite(_) => Ok(Vec::new()),
+    }
+}
+
+/// Lists the other databases a connection could switch to. Only PostgreSQL
+/// scopes a connection to one database; MySQL and ClickHouse already show
+/// every database as a schema, so they return an empty list.
+pub async fn load_databases(connection: DatabaseConnection) -> Result<Vec<String>, DatabaseError> {
+    match connection {
+        DatabaseConnection::Postgres(pool) => load_databases_postgres(&pool).await,
+        DatabaseConnection::Sqlite(_)
+        | DatabaseConnection::MySql(_)
+        | DatabaseConnection::ClickHouse(_) => Ok(Vec::new()),
+    }
+}
+
+pub async fn load_routine_definition(
+    connection: DatabaseConnection,
+    routine: RoutineInfo,
+) -> Result<String, DatabaseError> {
+    match connection {
+        DatabaseConnection::Postgres(pool) => {
+            load_routine_definition_postgres(&pool, &routine).await
+        }
+        DatabaseConnection::MySql(pool) => load_routine_definition_mysql(&pool, &routine).await,
+        DatabaseConnection::Sqlite(_) | DatabaseConnection::ClickHouse(_) => {
+            Err(DatabaseError::UnsupportedDriver(
+                "Stored routines are not supported for this connection.".to_string(),
+            ))
+        }
+    }
+}
+
 pub async fn load_connection_tree(
     connection: DatabaseConnection,
 ) -> Result<Vec<ExplorerNode>, DatabaseError> {
@@ -202,7 +523,10 @@ pub async fn load_connection_tree(
                 if !clickhouse_relation_supports_preview(&engine, &create_table_query) {
                     continue;
                 }
-                let kind = if engine.to_ascii_lowercase().contains("view") {
+                let engine_lower = engine.to_ascii_lowercase();
+                let kind = if engine_lower == "materializedview" {
+                    ExplorerNodeKind::MaterializedView
+                } else if engine_lower.contains("view") {
                     ExplorerNodeKind::View
                 } else {
                     ExplorerNodeKind::Table
@@ -1,4 +1,7 @@
-use models::{DatabaseError, ExplorerNode, ExplorerNodeKind, QueryOutput};
+use models::{
+    ColumnInfo, DatabaseError, ExplorerNode, ExplorerNodeKind, QueryOutput, RoutineInfo,
+    RoutineKind, SchemaColumn, TableCatalogInfo,
+};
 use sqlx::Row;
 
 pub async fn describe_table_mysql(
@@ -11,7 +14,13 @@ pub async fn describe_table_mysql(
 
     let overview_rows = sqlx::query(
         r#"
-        select table_type, engine
+        select
+            table_type,
+            engine,
+            case
+                when table_type = 'VIEW' then ''
+                else cast(table_comment as char)
+            end as table_comment
         from information_schema.tables
         where table_schema = ?
           and table_name = ?
@@ -32,12 +41,20 @@ pub async fn describe_table_mysql(
             .ok()
             .flatten()
             .unwrap_or_else(|| table_type.clone());
-        rows.push(structure_row(
-            "table",
-            table.clone(),
-            engine,
-            String::new(),
-            format!("schema: {schema_name}"),
+        let comment = row
+            .try_get::<Option<String>, _>("table_comment")
+            .ok()
+            .flatten()
+            .unwrap_or_default();
+        rows.push(with_comment(
+            structure_row(
+                "table",
+                table.clone(),
+                engine,
+                String::new(),
+                format!("schema: {schema_name}"),
+            ),
+            comment,
         ));
     }
 
@@ -68,7 +85,13 @@ pub async fn describe_table_mysql(
 
     let column_rows = sqlx::query(
         r#"
-        select column_name, column_type, is_nullable, column_default, extra
+        select
+            column_name,
+            column_type,
+            is_nullable,
+            column_default,
+            extra,
+            cast(column_comment as char) as column_comment
         from information_schema.columns
         where table_schema = ?
           and table_name = ?
@@ -99,12 +122,20 @@ pub async fn describe_table_mysql(
             .ok()
             .flatten()
             .unwrap_or_default();
-        rows.push(structure_row(
-            "column",
-            column_name,
-            column_type,
-            String::new(),
-            mysql_column_details(&is_nullable, default_value, &extra),
+        let comment = row
+            .try_get::<Option<String>, _>("column_comment")
+            .ok()
+            .flatten()
+            .unwrap_or_default();
+        rows.push(with_comment(
+            structure_row(
+                "column",
+                column_name,
+                column_type,
+                String::new(),
+                mysql_column_details(&is_nullable, default_value, &extra),
+            ),
+            comment,
         ));
     }
 
@@ -286,6 +317,26 @@ pub async fn describe_table_mysql(
     Ok(QueryOutput::Table(structure_page(rows)))
 }
 
+/// The CREATE TABLE statement MySQL reports for `table`; it already lists
+/// the indexes.
+pub async fn load_table_ddl_mysql(
+    pool: &sqlx::MySqlPool,
+    schema: Option<String>,
+    table: String,
+) -> Result<String, DatabaseError> {
+    let schema_name = mysql_effective_schema_name(pool, schema.as_deref()).await?;
+    let create_sql = format!(
+        "show create table {}",
+        qualified_mysql_table_name(&schema_name, &table)
+    );
+    let row = sqlx::query(&create_sql)
+        .fetch_one(pool)
+        .await
+        .map_err(DatabaseError::MySql)?;
+    let create_statement = row.try_get::<String, _>(1).map_err(DatabaseError::MySql)?;
+    Ok(format!("{};", create_statement.trim_end()))
+}
+
 pub async fn load_table_columns_mysql(
     pool: &sqlx::MySqlPool,
     schema: Option<String>,
@@ -315,6 +366,241 @@ pub async fn load_table_columns_mysql(
         .collect()
 }
 
+pub async fn load_table_column_info_mysql(
+    pool: &sqlx::MySqlPool,
+    schema: Option<String>,
+    table: String,
+) -> Result<Vec<ColumnInfo>, DatabaseError> {
+    let schema_name = mysql_effective_schema_name(pool, schema.as_deref()).await?;
+    let rows = sqlx::query(
+        r#"
+        select
+            c.column_name as column_name,
+            c.column_type as data_type,
+            cast(c.is_nullable = 'YES' as signed) as nullable,
+            cast(c.column_key = 'PRI' as signed) as primary_key,
+            cast(exists (
+                select 1 from information_schema.key_column_usage k
+                where k.table_schema = c.table_schema
+                  and k.table_name = c.table_name
+                  and k.column_name = c.column_name
+                  and k.referenced_table_name is not null
+            ) as signed) as foreign_key,
+            case
+                when c.extra like '%auto_increment%' then 'auto_increment'
+                else cast(c.column_default as char)
+            end as column_default,
+            cast(c.extra like '%auto_increment%' as signed) as is_identity,
+            cast(
+                c.extra like '%VIRTUAL GENERATED%' or c.extra like '%STORED GENERATED%'
+                as signed
+            ) as is_generated,
+            cast(nullif(c.column_comment, '') as char) as comment
+        from information_schema.columns c
+        where c.table_schema = ?
+          and c.table_name = ?
+        order by c.ordinal_position
+        "#,
+    )
+    .bind(schema_name)
+    .bind(table)
+    .fetch_all(pool)
+    .await
+    .map_err(DatabaseError::MySql)?;
+
+    rows.into_iter()
+        .map(|row| {
+            Ok(ColumnInfo {
+                name: row.try_get("column_name")?,
+                data_type: row.try_get("data_type")?,
+                nullable: row.try_get::<i64, _>("nullable")? != 0,
+                primary_key: row.try_get::<i64, _>("primary_key")? != 0,
+                foreign_key: row.try_get::<i64, _>("foreign_key")? != 0,
+                default_value: row.try_get("column_default")?,
+                identity: row.try_get::<i64, _>("is_identity")? != 0,
+                generated: row.try_get::<i64, _>("is_generated")? != 0,
+                comment: row.try_get("comment")?,
+            })
+        })
+        .collect::<Result<Vec<_>, sqlx::Error>>()
+        .map_err(DatabaseError::MySql)
+}
+
+pub async fn load_column_index_mysql(
+    pool: &sqlx::MySqlPool,
+) -> Result<Vec<SchemaColumn>, DatabaseError> {
+    let rows = sqlx::query(
+        r#"
+        select table_schema, table_name, column_name
+        from information_schema.columns
+        where table_schema not in ('information_schema', 'performance_schema', 'sys')
+        order by table_schema, table_name, ordinal_position
+        "#,
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(DatabaseError::MySql)?;
+
+    rows.into_iter()
+        .map(|row| {
+            Ok(SchemaColumn {
+                schema: Some(row.try_get("table_schema")?),
+                table_name: row.try_get("table_name")?,
+                column_name: row.try_get("column_name")?,
+            })
+        })
+        .collect::<Result<Vec<_>, sqlx::Error>>()
+        .map_err(DatabaseError::MySql)
+}
+
+pub async fn load_schema_table_catalog_mysql(
+    pool: &sqlx::MySqlPool,
+    schema: String,
+) -> Result<Vec<TableCatalogInfo>, DatabaseError> {
+    let rows = sqlx::query(
+        r#"
+        select
+            table_name as name,
+            cast(table_rows as signed) as estimated_rows,
+            cast(data_length + index_length as signed) as total_bytes,
+            case
+                when table_type = 'VIEW' then null
+                else cast(nullif(table_comment, '') as char)
+            end as comment
+        from information_schema.tables
+        where table_schema = ?
+        order by table_name
+        "#,
+    )
+    .bind(&schema)
+    .fetch_all(pool)
+    .await
+    .map_err(DatabaseError::MySql)?;
+
+    rows.into_iter()
+        .map(|row| {
+            let estimated_rows: Option<i64> = row.try_get("estimated_rows")?;
+            let total_bytes: Option<i64> = row.try_get("total_bytes")?;
+            Ok(TableCatalogInfo {
+                name: row.try_get("name")?,
+                estimated_rows: estimated_rows.and_then(|rows| u64::try_from(rows).ok()),
+                total_bytes: total_bytes.and_then(|bytes| u64::try_from(bytes).ok()),
+                comment: row.try_get("comment")?,
+            })
+        })
+        .collect::<Result<Vec<_>, sqlx::Error>>()
+        .map_err(DatabaseError::MySql)
+}
+
+pub async fn load_schema_routines_mysql(
+    pool: &sqlx::MySqlPool,
+    schema: String,
+) -> Result<Vec<RoutineInfo>, DatabaseError> {
+    let routine_rows = sqlx::query(
+        r#"
+        select
+            routine_name as name,
+            routine_type as kind,
+            coalesce(dtd_identifier, '') as return_type,
+            routine_body as language
+        from information_schema.routines
+        where routine_schema = ?
+        order by routine_name
+        "#,
+    )
+    .bind(&schema)
+    .fetch_all(pool)
+    .await
+    .map_err(DatabaseError::MySql)?;
+
+    let parameter_rows = sqlx::query(
+        r#"
+        select specific_name as name, routine_type as kind, dtd_identifier as data_type
+        from information_schema.parameters
+        where specific_schema = ?
+          and ordinal_position > 0
+        order by specific_name, ordinal_position
+        "#,
+    )
+    .bind(&schema)
+    .fetch_all(pool)
+    .await
+    .map_err(DatabaseError::MySql)?;
+
+    // A function and a procedure may share a name, so parameters are keyed by both.
+    let mut argument_types: std::collections::HashMap<(String, String), Vec<String>> =
+        std::collections::HashMap::new();
+    for row in parameter_rows {
+        let name = row
+            .try_get::<String, _>("name")
+            .map_err(DatabaseError::MySql)?;
+        let kind = row
+            .try_get::<String, _>("kind")
+            .map_err(DatabaseError::MySql)?;
+        let data_type = row
+            .try_get::<String, _>("data_type")
+            .map_err(DatabaseError::MySql)?;
+        argument_types
+            .entry((name, kind))
+            .or_default()
+            .push(data_type);
+    }
+
+    routine_rows
+        .into_iter()
+        .map(|row| {
+            let name = row.try_get::<String, _>("name")?;
+            let kind = row.try_get::<String, _>("kind")?;
+            Ok(RoutineInfo {
+                schema: schema.clone(),
+                argument_types: argument_types
+                    .get(&(name.clone(), kind.clone()))
+                    .cloned()
+                    .unwrap_or_default(),
+                kind: if kind.eq_ignore_ascii_case("PROCEDURE") {
+                    RoutineKind::Procedure
+                } else {
+                    RoutineKind::Function
+                },
+                return_type: row.try_get("return_type")?,
+                language: row.try_get("language")?,
+                identity: name.clone(),
+                name,
+            })
+        })
+        .collect::<Result<Vec<_>, sqlx::Error>>()
+        .map_err(DatabaseError::MySql)
+}
+
+pub async fn load_routine_definition_mysql(
+    pool: &sqlx::MySqlPool,
+    routine: &RoutineInfo,
+) -> Result<String, DatabaseError> {
+    let keyword = match routine.kind {
+        RoutineKind::Function => "FUNCTION",
+        RoutineKind::Procedure => "PROCEDURE",
+    };
+    let sql = format!(
+        "show create {keyword} {}",
+        qualified_mysql_table_name(&routine.schema, &routine.identity)
+    );
+    let row = sqlx::query(&sql)
+        .fetch_one(pool)
+        .await
+        .map_err(DatabaseError::MySql)?;
+
+    // Column 2 is `Create Function` / `Create Procedure`; it is NULL when the
+    // current user lacks privileges on the routine.
+    row.try_get::<Option<String>, _>(2)
+        .map_err(DatabaseError::MySql)?
+        .ok_or_else(|| {
+            DatabaseError::UnsupportedDriver(format!(
+                "No permission to read the definition of {}.",
+                routine.name
+            ))
+        })
+}
+
 pub async fn load_connection_tree_mysql(
     pool: &sqlx::MySqlPool,
 ) -> Result<Vec<ExplorerNode>, DatabaseError> {
@@ -420,6 +706,12 @@ fn structure_row(
     ]
 }
 
+/// Fills the trailing comment cell of a structure row.
+fn with_comment(mut row: Vec<String>, comment: String) -> Vec<String> {
+    row.push(comment);
+    row
+}
+
 fn structure_page(rows: Vec<Vec<String>>) -> models::QueryPage {
     models::QueryPage {
         columns: vec![
@@ -428,8 +720,16 @@ fn structure_page(rows: Vec<Vec<String>>) -> models::QueryPage {
             "type".to_string(),
             "target".to_string(),
             "details".to_string(),
+            "comment".to_string(),
         ],
-        rows,
+        // Only tables and columns carry comments; other rows leave the cell empty.
+        rows: rows
+            .into_iter()
+            .map(|mut row| {
+                row.resize(6, String::new());
+                row
+            })
+            .collect(),
         editable: None,
         offset: 0,
         page_size: 0,
@@ -1,4 +1,9 @@
-use models::{DatabaseError, ExplorerNode, ExplorerNodeKind, QueryOutput};
+use models::{
+    ColumnInfo, DatabaseError, ExplorerNode, ExplorerNodeKind, MaterializedViewInfo, PolicyInfo,
+    QueryOutput, RoleInfo, RoutineInfo, RoutineKind, SchemaColumn, SchemaTable, SchemaTableColumn,
+    SchemaTableConstraint, SchemaTableIndex, SequenceInfo, TableCatalogInfo, TableGrant,
+    TableIndex, TableRowSecurity,
+};
 use sqlx::Row;
 
 pub async fn describe_table_postgres(
@@ -9,6 +14,65 @@ pub async fn describe_table_postgres(
     let schema_name = schema.unwrap_or_else(|| "public".to_string());
     let mut rows = Vec::new();
 
+    let table_comment = sqlx::query_scalar::<_, Option<String>>(
+        "select obj_description(format('%I.%I', $1::text, $2::text)::regclass, 'pg_class')",
+    )
+    .bind(&schema_name)
+    .bind(&table)
+    .fetch_one(pool)
+    .await
+    .map_err(DatabaseError::Postgres)?;
+    if let Some(comment) = table_comment {
+        rows.push(with_comment(
+            structure_row(
+                "table",
+                table.clone(),
+                "comment",
+                String::new(),
+                String::new(),
+            ),
+            comment,
+        ));
+    }
+
+    let view_row = sqlx::query(
+        r#"
+        select
+          case c.relkind when 'm' then 'MATERIALIZED VIEW' else 'VIEW' end as view_kind,
+          pg_get_viewdef(c.oid, true) as definition
+        from pg_class c
+        join pg_namespace n on n.oid = c.relnamespace
+        where n.nspname = $1
+          and c.relname = $2
+          and c.relkind in ('v', 'm')
+        "#,
+    )
+    .bind(&schema_name)
+    .bind(&table)
+    .fetch_optional(pool)
+    .await
+    .map_err(DatabaseError::Postgres)?;
+    if let Some(row) = view_row {
+        let view_kind = row
+            .try_get::<String, _>("view_kind")
+            .unwrap_or_else(|_| "VIEW".to_string());
+        let definition = row
+            .try_get::<String, _>("definition")
+            .unwrap_or_else(|_| String::new());
+        rows.push(structure_row(
+            "table",
+            table.clone(),
+            "definition",
+            String::new(),
+            format!(
+                "CREATE {view_kind} {}.{} AS\n{}",
+                super::quote_identifier(&schema_name),
+                super::quote_identifier(&table),
+                definition.trim_end()
+            ),
+        ));
+    }
+
     let column_rows = sqlx::query(
         r#"
         select
@@ -16,7 +80,13 @@ pub async fn describe_table_postgres(
           column_name,
           data_type,
           is_nullable,
-          column_default
+          column_default,
+          identity_generation,
+          generation_expression,
+          col_description(
+            format('%I.%I', table_schema, table_name)::regclass,
+            ordinal_position::int
+          ) as comment
         from information_schema.columns
         where table_schema = $1
           and table_name = $2
@@ -42,13 +112,34 @@ pub async fn describe_table_postgres(
             .try_get::<Option<String>, _>("column_default")
             .ok()
             .flatten();
+        let identity = row
+            .try_get::<Option<String>, _>("identity_generation")
+            .ok()
+            .flatten();
+        let generation_expression = row
+            .try_get::<Option<String>, _>("generation_expression")
+            .ok()
+            .flatten();
+        let comment = row
+            .try_get::<Option<String>, _>("comment")
+            .ok()
+            .flatten()
+            .unwrap_or_default();
 
-        rows.push(structure_row(
-            "column",
-            column_name,
-            data_type,
-            String::new(),
-            postgres_column_details(&is_nullable, default_value),
+        rows.push(with_comment(
+            structure_row(
+                "column",
+                column_name,
+                data_type,
+                String::new(),
+                postgres_column_details(
+                    &is_nullable,
+                    default_value,
+                    identity,
+                    generation_expression,
+                ),
+            ),
+            comment,
         ));
     }
 
@@ -180,6 +271,132 @@ pub async fn describe_table_postgres(
     Ok(QueryOutput::Table(structure_page(rows)))
 }
 
+/// Rebuilds the CREATE TABLE statement of `table` from the catalog, followed
+/// by the indexes that do not back a constraint.
+pub async fn load_table_ddl_postgres(
+    pool: &sqlx::PgPool,
+    schema: Option<String>,
+    table: String,
+) -> Result<String, DatabaseError> {
+    let schema_name = schema.unwrap_or_else(|| "public".to_string());
+
+    let column_rows = sqlx::query(
+        r#"
+        select
+          a.attname as column_name,
+          format_type(a.atttypid, a.atttypmod) as data_type,
+          a.attnotnull as not_null,
+          case when a.attgenerated = '' then pg_get_expr(d.adbin, d.adrelid) end as default_value,
+          case a.attidentity when 'a' then 'ALWAYS' when 'd' then 'BY DEFAULT' end as identity,
+          case when a.attgenerated <> '' then pg_get_expr(d.adbin, d.adrelid) end as generation_expression
+        from pg_attribute a
+        join pg_class c on c.oid = a.attrelid
+        join pg_namespace n on n.oid = c.relnamespace
+        left join pg_attrdef d on d.adrelid = a.attrelid and d.adnum = a.attnum
+        where n.nspname = $1
+          and c.relname = $2
+          and a.attnum > 0
+          and not a.attisdropped
+        order by a.attnum
+        "#,
+    )
+    .bind(&schema_name)
+    .bind(&table)
+    .fetch_all(pool)
+    .await
+    .map_err(DatabaseError::Postgres)?;
+    if column_rows.is_empty() {
+        return Err(DatabaseError::UnsupportedDriver(format!(
+            "Table {schema_name}.{table} was not found"
+        )));
+    }
+    let mut columns = Vec::new();
+    for row in column_rows {
+        columns.push(PostgresDdlColumn {
+            name: row
+                .try_get::<String, _>("column_name")
+                .map_err(DatabaseError::Postgres)?,
+            data_type: row
+                .try_get::<String, _>("data_type")
+                .unwrap_or_else(|_| "text".to_string()),
+            not_null: row.try_get::<bool, _>("not_null").unwrap_or(false),
+            default_value: row
+                .try_get::<Option<String>, _>("default_value")
+                .ok()
+                .flatten(),
+            identity: row.try_get::<Option<String>, _>("identity").ok().flatten(),
+            generation_expression: row
+                .try_get::<Option<String>, _>("generation_expression")
+                .ok()
+                .flatten(),
+        });
+    }
+
+    let constraint_rows = sqlx::query(
+        r#"
+        select
+          c.conname as constraint_name,
+          pg_get_constraintdef(c.oid, true) as definition
+        from pg_constraint c
+        join pg_class t on t.oid = c.conrelid
+        join pg_namespace n on n.oid = t.relnamespace
+        where n.nspname = $1
+          and t.relname = $2
+          and c.contype in ('p', 'u', 'c', 'f', 'x')
+        order by
+          case c.contype when 'p' then 0 when 'u' then 1 when 'c' then 2 when 'f' then 3 else 4 end,
+          c.conname
+        "#,
+    )
+    .bind(&schema_name)
+    .bind(&table)
+    .fetch_all(pool)
+    .await
+    .map_err(DatabaseError::Postgres)?;
+    let mut constraints = Vec::new();
+    for row in constraint_rows {
+        constraints.push((
+            row.try_get::<String, _>("constraint_name")
+                .map_err(DatabaseError::Postgres)?,
+            row.try_get::<String, _>("definition")
+                .unwrap_or_else(|_| String::new()),
+        ));
+    }
+
+    let indexes = sqlx::query_scalar::<_, String>(
+        r#"
+        select pg_get_indexdef(i.indexrelid)
+        from pg_index i
+        join pg_class t on t.oid = i.indrelid
+        join pg_namespace n on n.oid = t.relnamespace
+        join pg_class ic on ic.oid = i.indexrelid
+        where n.nspname = $1
+          and t.relname = $2
+          and not exists (
+            select 1
+            from pg_constraint con
+            where con.conindid = i.indexrelid
+              and con.conrelid = i.indrelid
+              and con.contype in ('p', 'u', 'x')
+          )
+        order by ic.relname
+        "#,
+    )
+    .bind(&schema_name)
+    .bind(&table)
+    .fetch_all(pool)
+    .await
+    .map_err(DatabaseError::Postgres)?;
+
+    Ok(postgres_table_ddl(
+        &schema_name,
+        &table,
+        &columns,
+        &constraints,
+        &indexes,
+    ))
+}
+
 pub async fn load_table_columns_postgres(
     pool: &sqlx::PgPool,
     schema: Option<String>,
@@ -209,15 +426,669 @@ pub async fn load_table_columns_postgres(
         .collect()
 }
 
+pub async fn load_table_column_info_postgres(
+    pool: &sqlx::PgPool,
+    schema: Option<String>,
+    table: String,
+) -> Result<Vec<ColumnInfo>, DatabaseError> {
+    let schema_name = schema.unwrap_or_else(|| "public".to_string());
+    let rows = sqlx::query(
+        r#"
+        select
+            a.attname as column_name,
+            format_type(a.atttypid, a.atttypmod) as data_type,
+            not a.attnotnull as nullable,
+            exists (
+                select 1 from pg_constraint con
+                where con.conrelid = a.attrelid
+                  and con.contype = 'p'
+                  and a.attnum = any(con.conkey)
+            ) as primary_key,
+            exists (
+                select 1 from pg_constraint con
+                where con.conrelid = a.attrelid
+                  and con.contype = 'f'
+                  and a.attnum = any(con.conkey)
+            ) as foreign_key,
+            coalesce(
+                (
+                    select pg_get_expr(d.adbin, d.adrelid)
+                    from pg_attrdef d
+                    where d.adrelid = a.attrelid and d.adnum = a.attnum
+                ),
+                case when a.attidentity <> '' then 'generated as identity' end
+            ) as column_default,
+            a.attidentity <> '' as is_identity,
+            a.attgenerated <> '' as is_generated,
+            col_description(a.attrelid, a.attnum) as comment
+        from pg_attribute a
+        where a.attrelid = format('%I.%I', $1::text, $2::text)::regclass
+          and a.attnum > 0
+          and not a.attisdropped
+        order by a.attnum
+        "#,
+    )
+    .bind(schema_name)
+    .bind(table)
+    .fetch_all(pool)
+    .await
+    .map_err(DatabaseError::Postgres)?;
+
+    rows.into_iter()
+        .map(|row| {
+            Ok(ColumnInfo {
+                name: row.try_get("column_name")?,
+                data_type: row.try_get("data_type")?,
+                nullable: row.try_get("nullable")?,
+                primary_key: row.try_get("primary_key")?,
+                foreign_key: row.try_get("foreign_key")?,
+                default_value: row.try_get("column_default")?,
+                identity: row.try_get("is_identity")?,
+                generated: row.try_get("is_generated")?,
+                comment: row.try_get("comment")?,
+            })
+        })
+        .collect::<Result<Vec<_>, sqlx::Error>>()
+        .map_err(DatabaseError::Postgres)
+}
+
+pub async fn load_column_index_postgres(
+    pool: &sqlx::PgPool,
+) -> Result<Vec<SchemaColumn>, DatabaseError> {
+    let rows = sqlx::query(
+        r#"
+        select n.nspname::text as table_schema, c.relname::text as table_name,
+            a.attname::text as column_name
+        from pg_attribute a
+        join pg_class c on c.oid = a.attrelid
+        join pg_namespace n on n.oid = c.relnamespace
+        where c.relkind in ('r', 'p', 'v', 'm', 'f')
+          and n.nspname not in ('pg_catalog', 'information_schema')
+          and n.nspname not like 'pg_toast%'
+          and a.attnum > 0
+          and not a.attisdropped
+        order by n.nspname, c.relname, a.attnum
+        "#,
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(DatabaseError::Postgres)?;
+
+    rows.into_iter()
+        .map(|row| {
+            Ok(SchemaColumn {
+                schema: Some(row.try_get("table_schema")?),
+                table_name: row.try_get("table_name")?,
+                column_name: row.try_get("column_name")?,
+            })
+        })
+        .collect::<Result<Vec<_>, sqlx::Error>>()
+        .map_err(DatabaseError::Postgres)
+}
+
+pub async fn load_schema_routines_postgres(
+    pool: &sqlx::PgPool,
+    schema: String,
+) -> Result<Vec<RoutineInfo>, DatabaseError> {
+    let rows = sqlx::query(
+        r#"
+        select
+            p.proname::text as name,
+            p.prokind::text as kind,
+            array(
+                select format_type(arg.type_oid, null)
+                from unnest(p.proargtypes::oid[]) with ordinality as arg(type_oid, position)
+                order by arg.position
+            ) as argument_types,
+            coalesce(pg_get_function_result(p.oid), '') as return_type,
+            l.lanname::text as language,
+            p.oid::regprocedure::text as identity
+        from pg_proc p
+        join pg_namespace n on n.oid = p.pronamespace
+        join pg_language l on l.oid = p.prolang
+        where n.nspname = $1
+          and p.prokind in ('f', 'p')
+        order by p.proname, identity
+        "#,
+    )
+    .bind(&schema)
+    .fetch_all(pool)
+    .await
+    .map_err(DatabaseError::Postgres)?;
+
+    rows.into_iter()
+        .map(|row| {
+            let kind = row.try_get::<String, _>("kind")?;
+            Ok(RoutineInfo {
+                schema: schema.clone(),
+                name: row.try_get("name")?,
+                kind: if kind == "p" {
+                    RoutineKind::Procedure
+                } else {
+                    RoutineKind::Function
+                },
+                argument_types: row.try_get("argument_types")?,
+                return_type: row.try_get("return_type")?,
+                language: row.try_get("language")?,
+                identity: row.try_get("identity")?,
+            })
+        })
+        .collect::<Result<Vec<_>, sqlx::Error>>()
+        .map_err(DatabaseError::Postgres)
+}
+
+/// Databases on the server that accept connections, templates excluded.
+pub async fn load_databases_postgres(pool: &sqlx::PgPool) -> Result<Vec<String>, DatabaseError> {
+    sqlx::query_scalar::<_, String>(
+        r#"
+        select datname::text
+        from pg_database
+        where datallowconn and not datistemplate
+        order by datname
+        "#,
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(DatabaseError::Postgres)
+}
+
+pub async fn load_schema_sequences_postgres(
+    pool: &sqlx::PgPool,
+    schema: String,
+) -> Result<Vec<SequenceInfo>, DatabaseError> {
+    let rows = sqlx::query(
+        r#"
+        select
+            s.sequencename::text as name,
+            format_type(s.data_type, null) as data_type,
+            s.last_value,
+            s.increment_by,
+            s.min_value,
+            s.max_value,
+            s.cycle,
+            (
+                select format('%I.%I', c.relname, a.attname)
+                from pg_depend d
+                join pg_class c on c.oid = d.refobjid
+                join pg_attribute a on a.attrelid = d.refobjid and a.attnum = d.refobjsubid
+                where d.classid = 'pg_class'::regclass
+                  and d.objid = format('%I.%I', s.schemaname, s.sequencename)::regclass
+                  and d.deptype in ('a', 'i')
+                limit 1
+            ) as owned_by
+        from pg_sequences s
+        where s.schemaname = $1
+        order by s.sequencename
+        "#,
+    )
+    .bind(&schema)
+    .fetch_all(pool)
+    .await
+    .map_err(DatabaseError::Postgres)?;
+
+    rows.into_iter()
+        .map(|row| {
+            Ok(SequenceInfo {
+                schema: schema.clone(),
+                name: row.try_get("name")?,
+                data_type: row.try_get("data_type")?,
+                last_value: row.try_get("last_value")?,
+                increment: row.try_get("increment_by")?,
+                min_value: row.try_get("min_value")?,
+                max_value: row.try_get("max_value")?,
+                cycle: row.try_get("cycle")?,
+                owned_by: row.try_get("owned_by")?,
+            })
+        })
+        .collect::<Result<Vec<_>, sqlx::Error>>()
+        .map_err(DatabaseError::Postgres)
+}
+
+pub async fn load_table_row_security_postgres(
+    pool: &sqlx::PgPool,
+    schema: Option<String>,
+    table: String,
+) -> Result<TableRowSecurity, DatabaseError> {
+    let schema_name = schema.unwrap_or_else(|| "public".to_string());
+
+    let state = sqlx::query(
+        r#"
+        select c.relrowsecurity, c.relforcerowsecurity
+        from pg_class c
+        join pg_namespace n on n.oid = c.relnamespace
+        where n.nspname = $1
+          and c.relname = $2
+        "#,
+    )
+    .bind(&schema_name)
+    .bind(&table)
+    .fetch_optional(pool)
+    .await
+    .map_err(DatabaseError::Postgres)?;
+    let Some(state) = state else {
+        return Ok(TableRowSecurity::default());
+    };
+
+    let rows = sqlx::query(
+        r#"
+        select
+            p.polname::text as name,
+            case p.polcmd
+                when 'r' then 'SELECT'
+                when 'a' then 'INSERT'
+                when 'w' then 'UPDATE'
+                when 'd' then 'DELETE'
+                else 'ALL'
+            end as command,
+            p.polpermissive as permissive,
+            array(
+                select case when r.oid = 0 then 'public' else pg_get_userbyid(r.oid)::text end
+                from unnest(p.polroles) as r(oid)
+                order by 1
+            ) as roles,
+            pg_get_expr(p.polqual, p.polrelid) as using_expr,
+            pg_get_expr(p.polwithcheck, p.polrelid) as with_check_expr
+        from pg_policy p
+        join pg_class c on c.oid = p.polrelid
+        join pg_namespace n on n.oid = c.relnamespace
+        where n.nspname = $1
+          and c.relname = $2
+        order by p.polname
+        "#,
+    )
+    .bind(&schema_name)
+    .bind(&table)
+    .fetch_all(pool)
+    .await
+    .map_err(DatabaseError::Postgres)?;
+
+    let policies = rows
+        .into_iter()
+        .map(|row| {
+            Ok(PolicyInfo {
+                name: row.try_get("name")?,
+                command: row.try_get("command")?,
+                permissive: row.try_get("permissive")?,
+                roles: row.try_get("roles")?,
+                using_expr: row.try_get("using_expr")?,
+                with_check_expr: row.try_get("with_check_expr")?,
+            })
+        })
+        .collect::<Result<Vec<_>, sqlx::Error>>()
+        .map_err(DatabaseError::Postgres)?;
+
+    Ok(TableRowSecurity {
+        enabled: state
+            .try_get("relrowsecurity")
+            .map_err(DatabaseError::Postgres)?,
+        forced: state
+            .try_get("relforcerowsecurity")
+            .map_err(DatabaseError::Postgres)?,
+        policies,
+    })
+}
+
+pub async fn load_roles_postgres(pool: &sqlx::PgPool) -> Result<Vec<RoleInfo>, DatabaseError> {
+    let rows = sqlx::query(
+        r#"
+        select
+            r.rolname::text as name,
+            r.rolcanlogin as can_login,
+            r.rolsuper as superuser,
+            r.rolcreatedb as create_db,
+            r.rolcreaterole as create_role,
+            array(
+                select g.rolname::text
+                from pg_auth_members m
+                join pg_roles g on g.oid = m.roleid
+                where m.member = r.oid
+                order by 1
+            ) as member_of
+        from pg_roles r
+        where r.rolname !~ '^pg_'
+        order by r.rolname
+        "#,
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(DatabaseError::Postgres)?;
+
+    rows.into_iter()
+        .map(|row| {
+            Ok(RoleInfo {
+                name: row.try_get("name")?,
+                can_login: row.try_get("can_login")?,
+                superuser: row.try_get("superuser")?,
+                create_db: row.try_get("create_db")?,
+                create_role: row.try_get("create_role")?,
+                member_of: row.try_get("member_of")?,
+            })
+        })
+        .collect::<Result<Vec<_>, sqlx::Error>>()
+        .map_err(DatabaseError::Postgres)
+}
+
+pub async fn load_table_grants_postgres(
+    pool: &sqlx::PgPool,
+    schema: Option<String>,
+    table: String,
+) -> Result<Vec<TableGrant>, DatabaseError> {
+    let schema_name = schema.unwrap_or_else(|| "public".to_string());
+
+    let rows = sqlx::query(
+        r#"
+        select
+            grantee::text as grantee,
+            privilege_type::text as privilege,
+            is_grantable = 'YES' as is_grantable
+        from information_schema.role_table_grants
+        where table_schema = $1
+          and table_name = $2
+        order by grantee, privilege_type
+        "#,
+    )
+    .bind(&schema_name)
+    .bind(&table)
+    .fetch_all(pool)
+    .await
+    .map_err(DatabaseError::Postgres)?;
+
+    rows.into_iter()
+        .map(|row| {
+            Ok(TableGrant {
+                grantee: row.try_get("grantee")?,
+                privilege: row.try_get("privilege")?,
+                is_grantable: row.try_get("is_grantable")?,
+            })
+        })
+        .collect::<Result<Vec<_>, sqlx::Error>>()
+        .map_err(DatabaseError::Postgres)
+}
+
+pub async fn load_table_indexes_postgres(
+    pool: &sqlx::PgPool,
+    schema: Option<String>,
+    table: String,
+) -> Result<Vec<TableIndex>, DatabaseError> {
+    let schema_name = schema.unwrap_or_else(|| "public".to_string());
+
+    let rows = sqlx::query(
+        r#"
+        select
+            ic.relname::text as name,
+            pg_get_indexdef(i.indexrelid) as definition,
+            am.amname::text as method,
+            i.indisunique as is_unique,
+            con.conname::text as constraint_name
+        from pg_index i
+        join pg_class tc on tc.oid = i.indrelid
+        join pg_namespace n on n.oid = tc.relnamespace
+        join pg_class ic on ic.oid = i.indexrelid
+        join pg_am am on am.oid = ic.relam
+        left join pg_constraint con
+          on con.conindid = i.indexrelid
+         and con.contype in ('p', 'u', 'x')
+        where n.nspname = $1
+          and tc.relname = $2
+        order by ic.relname
+        "#,
+    )
+    .bind(&schema_name)
+    .bind(&table)
+    .fetch_all(pool)
+    .await
+    .map_err(DatabaseError::Postgres)?;
+
+    rows.into_iter()
+        .map(|row| {
+            Ok(TableIndex {
+                name: row.try_get("name")?,
+                definition: row.try_get("definition")?,
+                method: row.try_get("method")?,
+                is_unique: row.try_get("is_unique")?,
+                constraint: row.try_get("constraint_name")?,
+            })
+        })
+        .collect::<Result<Vec<_>, sqlx::Error>>()
+        .map_err(DatabaseError::Postgres)
+}
+
+/// Tables of `schema` with their columns, standalone indexes and
+/// constraints, for comparing two schemas.
+pub async fn load_schema_tables_postgres(
+    pool: &sqlx::PgPool,
+    schema: String,
+) -> Result<Vec<SchemaTable>, DatabaseError> {
+    let table_rows = sqlx::query(
+        r#"
+        select c.relname::text as table_name
+        from pg_class c
+        join pg_namespace n on n.oid = c.relnamespace
+        where n.nspname = $1
+          and c.relkind in ('r', 'p')
+          and not c.relispartition
+        order by c.relname
+        "#,
+    )
+    .bind(&schema)
+    .fetch_all(pool)
+    .await
+    .map_err(DatabaseError::Postgres)?;
+    let mut tables = table_rows
+        .into_iter()
+        .map(|row| {
+            Ok(SchemaTable {
+                name: row.try_get("table_name")?,
+                ..SchemaTable::default()
+            })
+        })
+        .collect::<Result<Vec<_>, sqlx::Error>>()
+        .map_err(DatabaseError::Postgres)?;
+
+    let column_rows = sqlx::query(
+        r#"
+        select
+            c.relname::text as table_name,
+            a.attname::text as column_name,
+            format_type(a.atttypid, a.atttypmod) as data_type,
+            not a.attnotnull as nullable
+        from pg_attribute a
+        join pg_class c on c.oid = a.attrelid
+        join pg_namespace n on n.oid = c.relnamespace
+        where n.nspname = $1
+          and c.relkind in ('r', 'p')
+          and a.attnum > 0
+          and not a.attisdropped
+        order by c.relname, a.attnum
+        "#,
+    )
+    .bind(&schema)
+    .fetch_all(pool)
+    .await
+    .map_err(DatabaseError::Postgres)?;
+    for row in column_rows {
+        let table_name: String = row.try_get("table_name").map_err(DatabaseError::Postgres)?;
+        let column = SchemaTableColumn {
+            name: row
+                .try_get("column_name")
+                .map_err(DatabaseError::Postgres)?,
+            data_type: row.try_get("data_type").map_err(DatabaseError::Postgres)?,
+            nullable: row.try_get("nullable").map_err(DatabaseError::Postgres)?,
+        };
+        if let Some(table) = tables.iter_mut().find(|table| table.name == table_name) {
+            table.columns.push(column);
+        }
+    }
+
+    // The text after USING leaves out the schema-qualified table name, so
+    // the same index compares equal across schemas.
+    let index_rows = sqlx::query(
+        r#"
+        select
+            tc.relname::text as table_name,
+            ic.relname::text as index_name,
+            i.indisunique as is_unique,
+            regexp_replace(pg_get_indexdef(i.indexrelid), '^.* USING ', '') as definition
+        from pg_index i
+        join pg_class tc on tc.oid = i.indrelid
+        join pg_namespace n on n.oid = tc.relnamespace
+        join pg_class ic on ic.oid = i.indexrelid
+        where n.nspname = $1
+          and tc.relkind in ('r', 'p')
+          and not exists (
+              select 1 from pg_constraint con where con.conindid = i.indexrelid
+          )
+        order by tc.relname, ic.relname
+        "#,
+    )
+    .bind(&schema)
+    .fetch_all(pool)
+    .await
+    .map_err(DatabaseError::Postgres)?;
+    for row in index_rows {
+        let table_name: String = row.try_get("table_name").map_err(DatabaseError::Postgres)?;
+        let index = SchemaTableIndex {
+            name: row.try_get("index_name").map_err(DatabaseError::Postgres)?,
+            is_unique: row.try_get("is_unique").map_err(DatabaseError::Postgres)?,
+            definition: row.try_get("definition").map_err(DatabaseError::Postgres)?,
+        };
+        if let Some(table) = tables.iter_mut().find(|table| table.name == table_name) {
+            table.indexes.push(index);
+        }
+    }
+
+    let constraint_rows = sqlx::query(
+        r#"
+        select
+            c.relname::text as table_name,
+            con.conname::text as constraint_name,
+            pg_get_constraintdef(con.oid) as definition
+        from pg_constraint con
+        join pg_class c on c.oid = con.conrelid
+        join pg_namespace n on n.oid = c.relnamespace
+        where n.nspname = $1
+          and c.relkind in ('r', 'p')
+        order by c.relname, con.conname
+        "#,
+    )
+    .bind(&schema)
+    .fetch_all(pool)
+    .await
+    .map_err(DatabaseError::Postgres)?;
+    for row in constraint_rows {
+        let table_name: String = row.try_get("table_name").map_err(DatabaseError::Postgres)?;
+        let constraint = SchemaTableConstraint {
+            name: row
+                .try_get("constraint_name")
+                .map_err(DatabaseError::Postgres)?,
+            definition: row.try_get("definition").map_err(DatabaseError::Postgres)?,
+        };
+        if let Some(table) = tables.iter_mut().find(|table| table.name == table_name) {
+            table.constraints.push(constraint);
+        }
+    }
+
+    Ok(tables)
+}
+
+pub async fn load_schema_materialized_views_postgres(
+    pool: &sqlx::PgPool,
+    schema: String,
+) -> Result<Vec<MaterializedViewInfo>, DatabaseError> {
+    let rows = sqlx::query(
+        r#"
+        select matviewname::text as name, ispopulated
+        from pg_matviews
+        where schemaname = $1
+        order by matviewname
+        "#,
+    )
+    .bind(&schema)
+    .fetch_all(pool)
+    .await
+    .map_err(DatabaseError::Postgres)?;
+
+    rows.into_iter()
+        .map(|row| {
+            Ok(MaterializedViewInfo {
+                schema: schema.clone(),
+                name: row.try_get("name")?,
+                is_populated: row.try_get("ispopulated")?,
+            })
+        })
+        .collect::<Result<Vec<_>, sqlx::Error>>()
+        .map_err(DatabaseError::Postgres)
+}
+
+pub async fn load_schema_table_catalog_postgres(
+    pool: &sqlx::PgPool,
+    schema: String,
+) -> Result<Vec<TableCatalogInfo>, DatabaseError> {
+    let rows = sqlx::query(
+        r#"
+        select
+            c.relname::text as name,
+            c.relkind::text as relkind,
+            c.reltuples::float8 as reltuples,
+            pg_total_relation_size(c.oid)::bigint as total_bytes,
+            obj_description(c.oid, 'pg_class') as comment
+        from pg_class c
+        join pg_namespace n on n.oid = c.relnamespace
+        where n.nspname = $1
+          and c.relkind in ('r', 'p', 'm', 'v', 'f')
+        order by c.relname
+        "#,
+    )
+    .bind(&schema)
+    .fetch_all(pool)
+    .await
+    .map_err(DatabaseError::Postgres)?;
+
+    rows.into_iter()
+        .map(|row| {
+            // Views and foreign tables keep no rows of their own.
+            let stored = matches!(
+                row.try_get::<String, _>("relkind")?.as_str(),
+                "r" | "p" | "m"
+            );
+            let reltuples: f64 = row.try_get("reltuples")?;
+            let total_bytes: i64 = row.try_get("total_bytes")?;
+            Ok(TableCatalogInfo {
+                name: row.try_get("name")?,
+                // PostgreSQL 14+ stores -1 until the table is first analyzed.
+                estimated_rows: (stored && reltuples >= 0.0).then_some(reltuples.round() as u64),
+                total_bytes: stored.then(|| u64::try_from(total_bytes).ok()).flatten(),
+                comment: row.try_get("comment")?,
+            })
+        })
+        .collect::<Result<Vec<_>, sqlx::Error>>()
+        .map_err(DatabaseError::Postgres)
+}
+
+pub async fn load_routine_definition_postgres(
+    pool: &sqlx::PgPool,
+    routine: &RoutineInfo,
+) -> Result<String, DatabaseError> {
+    sqlx::query_scalar::<_, String>("select pg_get_functiondef($1::regprocedure)")
+        .bind(&routine.identity)
+        .fetch_one(pool)
+        .await
+        .map_err(DatabaseError::Postgres)
+}
+
 pub async fn load_connection_tree_postgres(
     pool: &sqlx::PgPool,
 ) -> Result<Vec<ExplorerNode>, DatabaseError> {
     let rows = sqlx::query(
         r#"
-        select table_schema, table_name, table_type
+        select table_schema::text, table_name::text, table_type::text
         from information_schema.tables
         where table_schema not in ('pg_catalog', 'information_schema')
-        order by table_schema, table_type, table_name
+        union all
+        select schemaname::text, matviewname::text, 'MATERIALIZED VIEW'
+        from pg_matviews
+        where schemaname not in ('pg_catalog', 'information_schema')
+        order by 1, 3, 2
         "#,
     )
     .fetch_all(pool)
@@ -238,11 +1109,7 @@ pub async fn load_connection_tree_postgres(
             .try_get::<String, _>("table_type")
             .map_err(DatabaseError::Postgres)?;
 
-        let kind = if table_type.eq_ignore_ascii_case("view") {
-            ExplorerNodeKind::View
-        } else {
-            ExplorerNodeKind::Table
-        };
+        let kind = postgres_relation_kind(&table_type);
         let qualified_name = format!(
             "{}.{}",
             super::quote_identifier(&schema),
@@ -273,6 +1140,18 @@ pub async fn load_connection_tree_postgres(
         .collect())
 }
 
+/// Maps an `information_schema.tables.table_type` value (or the synthetic
+/// `MATERIALIZED VIEW` from `pg_matviews`) to a tree node kind.
+fn postgres_relation_kind(table_type: &str) -> ExplorerNodeKind {
+    match table_type.to_ascii_uppercase().as_str() {
+        "VIEW" => ExplorerNodeKind::View,
+        "MATERIALIZED VIEW" => ExplorerNodeKind::MaterializedView,
+        // PostgreSQL 9.x reported `FOREIGN TABLE`; newer releases say `FOREIGN`.
+        "FOREIGN" | "FOREIGN TABLE" => ExplorerNodeKind::ForeignTable,
+        _ => ExplorerNodeKind::Table,
+    }
+}
+
 fn structure_row(
     section: impl Into<String>,
     name: impl Into<String>,
@@ -289,6 +1168,12 @@ fn structure_row(
     ]
 }
 
+/// Fills the trailing comment cell of a structure row.
+fn with_comment(mut row: Vec<String>, comment: String) -> Vec<String> {
+    row.push(comment);
+    row
+}
+
 fn structure_page(rows: Vec<Vec<String>>) -> models::QueryPage {
     models::QueryPage {
         columns: vec![
@@ -297,8 +1182,16 @@ fn structure_page(rows: Vec<Vec<String>>) -> models::QueryPage {
             "type".to_string(),
             "target".to_string(),
             "details".to_string(),
+            "comment".to_string(),
         ],
-        rows,
+        // Only tables and columns carry comments; other rows leave the cell empty.
+        rows: rows
+            .into_iter()
+            .map(|mut row| {
+                row.resize(6, String::new());
+                row
+            })
+            .collect(),
         editable: None,
         offset: 0,
         page_size: 0,
@@ -307,11 +1200,131 @@ fn structure_page(rows: Vec<Vec<String>>) -> models::QueryPage {
     }
 }
 
-fn postgres_column_details(is_nullable: &str, default_value: Option<String>) -> String {
+/// Column of a PostgreSQL table as its CREATE TABLE statement lists it.
+struct PostgresDdlColumn {
+    name: String,
+    data_type: String,
+    not_null: bool,
+    default_value: Option<String>,
+    /// `ALWAYS` or `BY DEFAULT` for identity columns.
+    identity: Option<String>,
+    generation_expression: Option<String>,
+}
+
+fn postgres_table_ddl(
+    schema: &str,
+    table: &str,
+    columns: &[PostgresDdlColumn],
+    constraints: &[(String, String)],
+    indexes: &[String],
+) -> String {
+    let column_lines = columns.iter().map(|column| {
+        let mut line = format!(
+            "    {} {}",
+            super::quote_identifier(&column.name),
+            column.data_type
+        );
+        if let Some(expression) = &column.generation_expression {
+            line.push_str(&format!(" GENERATED ALWAYS AS ({expression}) STORED"));
+        } else if let Some(generation) = &column.identity {
+            line.push_str(&format!(" GENERATED {generation} AS IDENTITY"));
+        } else if let Some(default_value) = &column.default_value {
+            line.push_str(&format!(" DEFAULT {default_value}"));
+        }
+        if column.not_null {
+            line.push_str(" NOT NULL");
+        }
+        line
+    });
+    let constraint_lines = constraints.iter().map(|(name, definition)| {
+        format!(
+            "    CONSTRAINT {} {definition}",
+            super::quote_identifier(name)
+        )
+    });
+    let mut ddl = format!(
+        "CREATE TABLE {}.{} (\n{}\n);",
+        super::quote_identifier(schema),
+        super::quote_identifier(table),
+        column_lines
+            .chain(constraint_lines)
+            .collect::<Vec<_>>()
+            .join(",\n")
+    );
+    for index in indexes {
+        ddl.push_str(&format!("\n\n{index};"));
+    }
+    ddl
+}
+
+fn postgres_column_details(
+    is_nullable: &str,
+    default_value: Option<String>,
+    identity: Option<String>,
+    generation_expression: Option<String>,
+) -> String {
     super::join_non_empty([
         is_nullable
             .eq_ignore_ascii_case("NO")
             .then(|| "NOT NULL".to_string()),
         default_value.map(|value| format!("default {value}")),
+        identity.map(|generation| format!("identity {}", generation.to_ascii_lowercase())),
+        generation_expression.map(|expression| format!("generated always as ({expression})")),
     ])
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{PostgresDdlColumn, postgres_table_ddl};
+
+    fn column(name: &str, data_type: &str, not_null: bool) -> PostgresDdlColumn {
+        PostgresDdlColumn {
+            name: name.to_string(),
+            data_type: data_type.to_string(),
+            not_null,
+            default_value: None,
+            identity: None,
+            generation_expression: None,
+        }
+    }
+
+    #[test]
+    fn table_ddl_lists_columns_constraints_and_indexes() {
+        let columns = vec![
+            PostgresDdlColumn {
+                identity: Some("ALWAYS".to_string()),
+                ..column("id", "bigint", true)
+            },
+            PostgresDdlColumn {
+                default_value: Some("'new'::text".to_string()),
+                ..column("status", "text", true)
+            },
+            column("price", "numeric(10,2)", false),
+            PostgresDdlColumn {
+                generation_expression: Some("price * 2::numeric".to_string()),
+                ..column("total", "numeric", false)
+            },
+        ];
+        let constraints = [
+            ("orders_pkey", "PRIMARY KEY (id)"),
+            ("orders_price_check", "CHECK (price > 0::numeric)"),
+        ]
+        .map(|(name, definition)| (name.to_string(), definition.to_string()));
+        let indexes =
+            ["CREATE INDEX orders_status_idx ON public.orders USING btree (status)".to_string()];
+
+        assert_eq!(
+            postgres_table_ddl("public", "orders", &columns, &constraints, &indexes),
+            r#"CREATE TABLE "public"."orders" (
+    "id" bigint GENERATED ALWAYS AS IDENTITY NOT NULL,
+    "status" text DEFAULT 'new'::text NOT NULL,
+    "price" numeric(10,2),
+    "total" numeric GENERATED ALWAYS AS (price * 2::numeric) STORED,
+    CONSTRAINT "orders_pkey" PRIMARY KEY (id),
+    CONSTRAINT "orders_price_check" CHECK (price > 0::numeric)
+);
+
+CREATE INDEX orders_status_idx ON public.orders USING btree (status);"#
+        );
+    }
+}
@@ -1,4 +1,6 @@
-use models::{DatabaseError, ExplorerNode, ExplorerNodeKind, QueryOutput};
+use models::{
+    ColumnInfo, DatabaseError, ExplorerNode, ExplorerNodeKind, QueryOutput, SchemaColumn,
+};
 use sqlx::Row;
 
 pub async fn describe_table_sqlite(
@@ -177,6 +179,42 @@ pub async fn describe_table_sqlite(
     Ok(QueryOutput::Table(structure_page(rows)))
 }
 
+/// The CREATE TABLE statement SQLite stored for `table`, followed by its
+/// explicitly created indexes.
+pub async fn load_table_ddl_sqlite(
+    pool: &sqlx::SqlitePool,
+    schema: Option<String>,
+    table: String,
+) -> Result<String, DatabaseError> {
+    let schema_name = schema.unwrap_or_else(|| "main".to_string());
+    let sql = format!(
+        r#"
+        select sql
+        from {}.sqlite_master
+        where tbl_name = ?1
+          and type in ('table', 'index')
+          and sql is not null
+        order by case type when 'table' then 0 else 1 end, name
+        "#,
+        super::quote_identifier(&schema_name)
+    );
+    let statements = sqlx::query_scalar::<_, String>(&sql)
+        .bind(&table)
+        .fetch_all(pool)
+        .await
+        .map_err(DatabaseError::Sqlite)?;
+    if statements.is_empty() {
+        return Err(DatabaseError::UnsupportedDriver(format!(
+            "Table {table} was not found"
+        )));
+    }
+    Ok(statements
+        .iter()
+        .map(|statement| format!("{};", statement.trim_end().trim_end_matches(';')))
+        .collect::<Vec<_>>()
+        .join("\n\n"))
+}
+
 pub async fn load_table_columns_sqlite(
     pool: &sqlx::SqlitePool,
     schema: Option<String>,
@@ -202,6 +240,103 @@ pub async fn load_table_columns_sqlite(
         .collect()
 }
 
+pub async fn load_table_column_info_sqlite(
+    pool: &sqlx::SqlitePool,
+    schema: Option<String>,
+    table: String,
+) -> Result<Vec<ColumnInfo>, DatabaseError> {
+    let schema_name = schema.unwrap_or_else(|| "main".to_string());
+    let columns_sql = format!(
+        "PRAGMA {}.table_xinfo({})",
+        super::quote_identifier(&schema_name),
+        super::quote_identifier(&table)
+    );
+    let foreign_keys_sql = format!(
+        "PRAGMA {}.foreign_key_list({})",
+        super::quote_identifier(&schema_name),
+        super::quote_identifier(&table)
+    );
+
+    let column_rows = sqlx::query(&columns_sql)
+        .fetch_all(pool)
+        .await
+        .map_err(DatabaseError::Sqlite)?;
+    let foreign_key_rows = sqlx::query(&foreign_keys_sql)
+        .fetch_all(pool)
+        .await
+        .map_err(DatabaseError::Sqlite)?;
+    let foreign_key_columns = foreign_key_rows
+        .into_iter()
+        .map(|row| row.try_get::<String, _>("from"))
+        .collect::<Result<std::collections::HashSet<_>, sqlx::Error>>()
+        .map_err(DatabaseError::Sqlite)?;
+
+    let primary_key_count = column_rows
+        .iter()
+        .filter(|row| row.try_get::<i64, _>("pk").is_ok_and(|pk| pk > 0))
+        .count();
+
+    column_rows
+        .into_iter()
+        .map(|row| {
+            // table_xinfo marks virtual table columns hidden = 1 and
+            // generated columns 2 (virtual) or 3 (stored).
+            let hidden = row.try_get::<i64, _>("hidden")?;
+            if hidden == 1 {
+                return Ok(None);
+            }
+            let name = row.try_get::<String, _>("name")?;
+            let data_type = row.try_get::<String, _>("type")?;
+            let primary_key = row.try_get::<i64, _>("pk")? > 0;
+            Ok(Some(ColumnInfo {
+                nullable: row.try_get::<i64, _>("notnull")? == 0,
+                foreign_key: foreign_key_columns.contains(&name),
+                default_value: row.try_get("dflt_value")?,
+                // A lone INTEGER PRIMARY KEY aliases the rowid.
+                identity: primary_key
+                    && primary_key_count == 1
+                    && data_type.eq_ignore_ascii_case("integer"),
+                generated: hidden > 1,
+                comment: None,
+                primary_key,
+                data_type,
+                name,
+            }))
+        })
+        .filter_map(Result::transpose)
+        .collect::<Result<Vec<_>, sqlx::Error>>()
+        .map_err(DatabaseError::Sqlite)
+}
+
+pub async fn load_column_index_sqlite(
+    pool: &sqlx::SqlitePool,
+) -> Result<Vec<SchemaColumn>, DatabaseError> {
+    let rows = sqlx::query(
+        r#"
+        select m.name as table_name, c.name as column_name
+        from sqlite_master m
+        join pragma_table_info(m.name) c
+        where m.type in ('table', 'view')
+          and m.name not like 'sqlite_%'
+        order by m.name, c.cid
+        "#,
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(DatabaseError::Sqlite)?;
+
+    rows.into_iter()
+        .map(|row| {
+            Ok(SchemaColumn {
+                schema: Some("main".to_string()),
+                table_name: row.try_get("table_name")?,
+                column_name: row.try_get("column_name")?,
+            })
+        })
+        .collect::<Result<Vec<_>, sqlx::Error>>()
+        .map_err(DatabaseError::Sqlite)
+}
+
 pub async fn load_connection_tree_sqlite(
     pool: &sqlx::SqlitePool,
 ) -> Result<Vec<ExplorerNode>, DatabaseError> {
@@ -1,4 +1,4 @@
-use crate::{ConnectionRequest, DatabaseConnection, DatabaseKind};
+use crate::{ConnectionEnvironment, ConnectionRequest, DatabaseConnection, DatabaseKind};
 
 #[derive(Clone, Debug)]
 pub struct ConnectionSession {
@@ -7,6 +7,10 @@ pub struct ConnectionSession {
     pub kind: DatabaseKind,
     pub request: ConnectionRequest,
     pub connection: DatabaseConnection,
+    /// Session this one was opened from to browse another database on the
+    /// same server. Disconnecting the parent closes it too.
+    pub parent_id: Option<u64>,
+    pub environment: Option<ConnectionEnvironment>,
 }
 
 #[derive(Clone, Debug)]
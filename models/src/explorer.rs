@@ -3,6 +3,26 @@ pub enum ExplorerNodeKind {
     Schema,
     Table,
     View,
+    MaterializedView,
+    ForeignTable,
+}
+
+impl ExplorerNodeKind {
+    /// Returns `true` for nodes whose rows can be previewed with `select *`.
+    pub fn is_relation(self) -> bool {
+        !matches!(self, ExplorerNodeKind::Schema)
+    }
+
+    /// Returns the human-facing name of this node kind.
+    pub fn label(self) -> &'static str {
+        match self {
+            ExplorerNodeKind::Schema => "Schema",
+            ExplorerNodeKind::Table => "Table",
+            ExplorerNodeKind::View => "View",
+            ExplorerNodeKind::MaterializedView => "Materialized view",
+            ExplorerNodeKind::ForeignTable => "Foreign table",
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -13,3 +33,254 @@ pub struct ExplorerNode {
     pub qualified_name: String,
     pub children: Vec<ExplorerNode>,
 }
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RoutineKind {
+    Function,
+    Procedure,
+}
+
+impl RoutineKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            RoutineKind::Function => "Function",
+            RoutineKind::Procedure => "Procedure",
+        }
+    }
+}
+
+/// A stored function or procedure listed under a schema.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RoutineInfo {
+    pub schema: String,
+    pub name: String,
+    pub kind: RoutineKind,
+    pub argument_types: Vec<String>,
+    pub return_type: String,
+    pub language: String,
+    /// Server-side handle used to fetch the definition; the `regprocedure`
+    /// signature on PostgreSQL so overloads stay distinct.
+    pub identity: String,
+}
+
+impl RoutineInfo {
+    /// Returns `name(type, ...)` for display in the tree.
+    pub fn signature(&self) -> String {
+        format!("{}({})", self.name, self.argument_types.join(", "))
+    }
+
+    /// Returns `Function · plpgsql → integer`, omitting parts the server left empty.
+    pub fn summary(&self) -> String {
+        let mut summary = self.kind.label().to_string();
+        if !self.language.is_empty() {
+            summary.push_str(" · ");
+            summary.push_str(&self.language.to_lowercase());
+        }
+        if !self.return_type.is_empty() {
+            summary.push_str(" → ");
+            summary.push_str(&self.return_type);
+        }
+        summary
+    }
+}
+
+/// A materialized view listed under a schema, with its state from
+/// `pg_matviews`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MaterializedViewInfo {
+    pub schema: String,
+    pub name: String,
+    /// `false` after `create materialized view ... with no data`, until the
+    /// first refresh; such a view cannot be queried or refreshed concurrently.
+    pub is_populated: bool,
+}
+
+/// Catalog metadata for a table or view listed under a schema. The row and
+/// size figures come from planner statistics, so they can lag behind until
+/// the next `ANALYZE`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TableCatalogInfo {
+    pub name: String,
+    /// `None` when the table has never been analyzed, and for views.
+    pub estimated_rows: Option<u64>,
+    pub total_bytes: Option<u64>,
+    /// Text set with `COMMENT ON TABLE` or the driver's equivalent.
+    pub comment: Option<String>,
+}
+
+/// One row security policy from `pg_policy`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PolicyInfo {
+    pub name: String,
+    /// `ALL`, `SELECT`, `INSERT`, `UPDATE` or `DELETE`.
+    pub command: String,
+    /// `false` for restrictive policies, which every row must also pass.
+    pub permissive: bool,
+    /// Role names the policy applies to; `public` covers everyone.
+    pub roles: Vec<String>,
+    pub using_expr: Option<String>,
+    pub with_check_expr: Option<String>,
+}
+
+/// Row-level security state of a PostgreSQL table and the policies on it.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TableRowSecurity {
+    pub enabled: bool,
+    /// Whether the table owner is subject to the policies too.
+    pub forced: bool,
+    pub policies: Vec<PolicyInfo>,
+}
+
+/// A role from `pg_roles` with its attributes and the roles it belongs to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RoleInfo {
+    pub name: String,
+    pub can_login: bool,
+    pub superuser: bool,
+    pub create_db: bool,
+    pub create_role: bool,
+    pub member_of: Vec<String>,
+}
+
+/// One privilege on a table, from `information_schema.role_table_grants`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TableGrant {
+    pub grantee: String,
+    /// `SELECT`, `INSERT`, `UPDATE`, `DELETE`, `TRUNCATE`, `REFERENCES` or `TRIGGER`.
+    pub privilege: String,
+    /// Whether the grantee may pass the privilege on to other roles.
+    pub is_grantable: bool,
+}
+
+/// Index on a table, from `pg_index`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TableIndex {
+    pub name: String,
+    /// `CREATE INDEX` statement as `pg_get_indexdef` prints it.
+    pub definition: String,
+    /// Access method, e.g. `btree` or `gin`.
+    pub method: String,
+    pub is_unique: bool,
+    /// Primary key or unique constraint the index backs; dropping the index
+    /// alone fails while the constraint exists.
+    pub constraint: Option<String>,
+}
+
+/// Access method of a new index.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum IndexMethod {
+    #[default]
+    BTree,
+    Hash,
+    Gin,
+    Gist,
+    Brin,
+}
+
+impl IndexMethod {
+    pub const ALL: [IndexMethod; 5] = [
+        IndexMethod::BTree,
+        IndexMethod::Hash,
+        IndexMethod::Gin,
+        IndexMethod::Gist,
+        IndexMethod::Brin,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            IndexMethod::BTree => "BTREE",
+            IndexMethod::Hash => "HASH",
+            IndexMethod::Gin => "GIN",
+            IndexMethod::Gist => "GIST",
+            IndexMethod::Brin => "BRIN",
+        }
+    }
+
+    /// Only B-tree indexes can enforce uniqueness.
+    pub fn supports_unique(self) -> bool {
+        self == IndexMethod::BTree
+    }
+}
+
+/// Index to create on a table.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IndexDefinition {
+    /// Left empty, the server picks a name.
+    pub name: String,
+    pub columns: Vec<String>,
+    pub method: IndexMethod,
+    pub unique: bool,
+    /// Builds without blocking writes, at the cost of a slower build.
+    pub concurrently: bool,
+    /// `WHERE` condition of a partial index, without the keyword.
+    pub predicate: String,
+}
+
+/// A sequence listed under a schema, with its state from `pg_sequences`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SequenceInfo {
+    pub schema: String,
+    pub name: String,
+    pub data_type: String,
+    /// `None` until the sequence is first used, or when the current role may
+    /// not read it.
+    pub last_value: Option<i64>,
+    pub increment: i64,
+    pub min_value: i64,
+    pub max_value: i64,
+    /// Whether the sequence wraps around instead of failing at its limit.
+    pub cycle: bool,
+    /// `table.column` of the column that owns the sequence, e.g. a serial.
+    pub owned_by: Option<String>,
+}
+
+impl SequenceInfo {
+    /// Returns `Sequence · bigint · at 42`, or `unused` before the first
+    /// `nextval()`.
+    pub fn summary(&self) -> String {
+        match self.last_value {
+            Some(value) => format!("Sequence · {} · at {value}", self.data_type),
+            None => format!("Sequence · {} · unused", self.data_type),
+        }
+    }
+
+    /// Returns `1 … 2147483647, cycles` for the value range.
+    pub fn limits(&self) -> String {
+        let mut limits = format!("{} … {}", self.min_value, self.max_value);
+        if self.cycle {
+            limits.push_str(", cycles");
+        }
+        limits
+    }
+}
+
+/// A column name with the table it belongs to, for searching a whole
+/// connection without loading each table's columns.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SchemaColumn {
+    pub schema: Option<String>,
+    pub table_name: String,
+    pub column_name: String,
+}
+
+/// A column listed under a table in the explorer tree.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ColumnInfo {
+    pub name: String,
+    pub data_type: String,
+    pub nullable: bool,
+    pub primary_key: bool,
+    /// Whether the column takes part in a foreign key constraint.
+    pub foreign_key: bool,
+    /// Default expression as the database reports it, or a marker such as
+    /// `auto_increment` for generated keys. `None` when the column has none.
+    pub default_value: Option<String>,
+    /// Identity or auto-increment key the database numbers itself. Inserts
+    /// may still set it explicitly.
+    pub identity: bool,
+    /// Computed column whose value the database derives; inserts must leave
+    /// it out.
+    pub generated: bool,
+    /// Text set with `COMMENT ON COLUMN` or the driver's equivalent.
+    pub comment: Option<String>,
+}
@@ -5,8 +5,10 @@ mod chat;
 mod connection;
 mod execution_plan;
 mod explorer;
+mod monitor;
 mod query;
 mod saved_query;
+mod schema_diff;
 mod semantic_cache;
 mod settings;
 
@@ -17,7 +19,9 @@ pub use chat::*;
 pub use connection::*;
 pub use execution_plan::*;
 pub use explorer::*;
+pub use monitor::*;
 pub use query::*;
 pub use saved_query::*;
+pub use schema_diff::*;
 pub use semantic_cache::*;
 pub use settings::*;
@@ -0,0 +1,252 @@
+/// One server backend as reported by `pg_stat_activity`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ServerActivity {
+    pub pid: i32,
+    pub user: String,
+    pub database: String,
+    pub application: String,
+    pub state: String,
+    pub query: String,
+    pub query_start: Option<String>,
+    /// Seconds since `query_start`, used to spot long-running statements.
+    pub query_seconds: Option<f64>,
+    pub wait_event_type: String,
+    pub wait_event: String,
+}
+
+/// One normalized statement from `pg_stat_statements`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StatementStat {
+    /// Statement text with constants replaced by `$1`, `$2`, ...
+    pub query: String,
+    pub calls: i64,
+    pub total_exec_ms: f64,
+    pub mean_exec_ms: f64,
+    pub rows: i64,
+}
+
+/// One lock from `pg_locks`, joined with the backend that holds or awaits it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ServerLock {
+    pub pid: i32,
+    pub user: String,
+    pub lock_type: String,
+    pub mode: String,
+    pub granted: bool,
+    /// `schema.table` for relation locks, empty for other lock types.
+    pub relation: String,
+    /// Backends this one is waiting on, from `pg_blocking_pids`.
+    pub blocked_by: Vec<i32>,
+    pub query: String,
+    pub query_seconds: Option<f64>,
+}
+
+/// Server identity reported by a connection check.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ServerInfo {
+    /// Product and version, e.g. `PostgreSQL 16.2`.
+    pub version: String,
+    /// Database the session landed in, when the server has one selected.
+    pub database: Option<String>,
+}
+
+/// On-disk footprint of one table, split the way PostgreSQL's size functions
+/// report it. `total_bytes` covers the heap, indexes and TOAST together.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TableSizeDetail {
+    pub name: String,
+    /// `None` when the table has never been analyzed.
+    pub estimated_rows: Option<u64>,
+    pub total_bytes: u64,
+    pub table_bytes: u64,
+    pub index_bytes: u64,
+    pub toast_bytes: u64,
+}
+
+/// Table sizes in one schema, with the whole database for scale.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SchemaSizeReport {
+    pub database: String,
+    pub database_bytes: u64,
+    pub tables: Vec<TableSizeDetail>,
+}
+
+/// Maintenance command run on a single PostgreSQL table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MaintenanceKind {
+    Vacuum,
+    VacuumAnalyze,
+    VacuumFull,
+    Analyze,
+    Reindex,
+}
+
+impl MaintenanceKind {
+    pub const ALL: [MaintenanceKind; 5] = [
+        MaintenanceKind::Vacuum,
+        MaintenanceKind::VacuumAnalyze,
+        MaintenanceKind::VacuumFull,
+        MaintenanceKind::Analyze,
+        MaintenanceKind::Reindex,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            MaintenanceKind::Vacuum => "VACUUM",
+            MaintenanceKind::VacuumAnalyze => "VACUUM ANALYZE",
+            MaintenanceKind::VacuumFull => "VACUUM FULL",
+            MaintenanceKind::Analyze => "ANALYZE",
+            MaintenanceKind::Reindex => "REINDEX",
+        }
+    }
+
+    /// Whether the command holds an exclusive lock that blocks reads and
+    /// writes for as long as it runs.
+    pub fn locks_table(self) -> bool {
+        matches!(self, MaintenanceKind::VacuumFull | MaintenanceKind::Reindex)
+    }
+}
+
+/// Vacuum and analyze history of a table from `pg_stat_user_tables`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TableMaintenanceStats {
+    pub last_vacuum: Option<String>,
+    pub last_autovacuum: Option<String>,
+    pub last_analyze: Option<String>,
+    pub last_autoanalyze: Option<String>,
+    pub live_tuples: i64,
+    /// Rows deleted or updated away that a vacuum has not reclaimed yet.
+    pub dead_tuples: i64,
+}
+
+/// Dead rows per live row above which a table is flagged as bloated.
+pub const TABLE_BLOAT_RATIO: f64 = 0.2;
+
+/// Activity and I/O counters of a table from `pg_stat_user_tables` and
+/// `pg_statio_user_tables`, since statistics were last reset.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TableStats {
+    pub live_tuples: i64,
+    pub dead_tuples: i64,
+    pub last_vacuum: Option<String>,
+    pub last_autovacuum: Option<String>,
+    pub last_analyze: Option<String>,
+    pub last_autoanalyze: Option<String>,
+    /// `None` when the table has no indexes.
+    pub index_scans: Option<i64>,
+    pub seq_scans: i64,
+    /// Heap blocks read from disk rather than found in shared buffers.
+    pub heap_blocks_read: i64,
+    pub heap_blocks_hit: i64,
+}
+
+impl TableStats {
+    /// Dead rows per live row, or `None` while the table has no live rows.
+    pub fn dead_ratio(&self) -> Option<f64> {
+        (self.live_tuples > 0).then(|| self.dead_tuples as f64 / self.live_tuples as f64)
+    }
+
+    /// Whether dead rows exceed [`TABLE_BLOAT_RATIO`] of the live ones.
+    pub fn is_bloated(&self) -> bool {
+        match self.dead_ratio() {
+            Some(ratio) => ratio > TABLE_BLOAT_RATIO,
+            None => self.dead_tuples > 0,
+        }
+    }
+
+    /// Share of heap block reads served from shared buffers.
+    pub fn cache_hit_ratio(&self) -> Option<f64> {
+        let total = self.heap_blocks_read + self.heap_blocks_hit;
+        (total > 0).then(|| self.heap_blocks_hit as f64 / total as f64)
+    }
+}
+
+/// Reported while a maintenance command runs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MaintenanceEvent {
+    /// The command was sent on the backend with this pid, which
+    /// `pg_cancel_backend` can stop.
+    Started { pid: i32 },
+    /// A new phase or progress line from the matching `pg_stat_progress_*` view.
+    Progress(String),
+}
+
+/// Message received on a channel the session listens to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ServerNotification {
+    pub channel: String,
+    pub payload: String,
+    /// Backend that sent the `NOTIFY`.
+    pub pid: i32,
+}
+
+/// Change to the channels a notification listener is subscribed to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ListenCommand {
+    Listen(String),
+    Unlisten(String),
+}
+
+/// One run-time parameter from `pg_settings`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ServerSetting {
+    pub name: String,
+    pub setting: String,
+    /// Unit `setting` is counted in, e.g. `kB` or `ms`.
+    pub unit: Option<String>,
+    pub category: String,
+    pub short_desc: String,
+    /// Where the current value came from, e.g. `default`, `configuration file`.
+    pub source: String,
+    /// When the parameter can change; `user` ones can be set by any session.
+    pub context: String,
+}
+
+impl ServerSetting {
+    /// Whether the value was set anywhere rather than left at its built-in default.
+    pub fn is_modified(&self) -> bool {
+        self.source != "default"
+    }
+
+    pub fn is_user_settable(&self) -> bool {
+        self.context == "user"
+    }
+
+    /// The value with its unit, e.g. `4096 kB`.
+    pub fn display_value(&self) -> String {
+        match self.unit.as_deref() {
+            Some(unit) if !unit.is_empty() => format!("{} {unit}", self.setting),
+            _ => self.setting.clone(),
+        }
+    }
+}
+
+/// Server-wide configuration of a PostgreSQL connection.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ServerSettings {
+    pub server_version: String,
+    pub server_encoding: String,
+    /// Whether the connected role may run `ALTER SYSTEM`.
+    pub is_superuser: bool,
+    /// Ordered by category, then name.
+    pub settings: Vec<ServerSetting>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TableStats;
+
+    #[test]
+    fn table_stats_flags_dead_rows_above_a_fifth_of_live_ones() {
+        let stats = |live_tuples, dead_tuples| TableStats {
+            live_tuples,
+            dead_tuples,
+            ..TableStats::default()
+        };
+
+        assert!(!stats(1000, 200).is_bloated());
+        assert!(stats(1000, 201).is_bloated());
+        assert!(stats(0, 5).is_bloated());
+        assert!(!stats(0, 0).is_bloated());
+    }
+}
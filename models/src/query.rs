@@ -1,5 +1,6 @@
 use crate::ExecutionPlan;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum SqlKeywordCase {
@@ -19,19 +20,23 @@ pub struct SqlFormatSettings {
     pub max_inline_block: u8,
     pub max_inline_arguments: Option<u8>,
     pub max_inline_top_level: Option<u8>,
+    /// Puts each column of a SELECT list, and each item of the other
+    /// clauses, on its own indented line, however short the clause is.
+    pub one_item_per_line: bool,
 }
 
 impl Default for SqlFormatSettings {
     fn default() -> Self {
         Self {
             keyword_case: SqlKeywordCase::Uppercase,
-            indent_width: 2,
+            indent_width: 4,
             lines_between_queries: 1,
             inline: false,
             joins_as_top_level: true,
             max_inline_block: 40,
             max_inline_arguments: Some(4),
             max_inline_top_level: Some(40),
+            one_item_per_line: true,
         }
     }
 }
@@ -92,12 +97,34 @@ pub struct EditableTableContext {
     pub row_locators: Vec<String>,
 }
 
+/// A row read back right after it was inserted, so it can be shown without
+/// reloading the page. `values` follow the table's column order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InsertedRow {
+    pub locator: String,
+    pub columns: Vec<String>,
+    pub values: Vec<String>,
+}
+
+/// How many staged edits Ctrl+Z can step back through.
+pub const PENDING_UNDO_LIMIT: usize = 50;
+
 #[derive(Clone, Debug, PartialEq, Eq, Default)]
 pub struct PendingTableChanges {
     pub next_insert_id: u64,
     pub inserted_rows: Vec<PendingInsertRow>,
     pub updated_cells: Vec<PendingCellChange>,
     pub deleted_rows: Vec<PendingDeleteRow>,
+    /// Staged edits as they were before each recent edit, oldest first.
+    pub undo_history: Vec<PendingEditSnapshot>,
+}
+
+/// The staged rows and cells at one point in the undo history.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PendingEditSnapshot {
+    pub inserted_rows: Vec<PendingInsertRow>,
+    pub updated_cells: Vec<PendingCellChange>,
+    pub deleted_rows: Vec<PendingDeleteRow>,
 }
 
 impl PendingTableChanges {
@@ -106,6 +133,49 @@ impl PendingTableChanges {
             && self.updated_cells.is_empty()
             && self.deleted_rows.is_empty()
     }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo_history.is_empty()
+    }
+
+    /// Remembers the current staged edits so the next edit can be undone,
+    /// dropping the oldest entry past [`PENDING_UNDO_LIMIT`].
+    pub fn record_undo_point(&mut self) {
+        if self.undo_history.len() >= PENDING_UNDO_LIMIT {
+            self.undo_history.remove(0);
+        }
+        self.undo_history.push(PendingEditSnapshot {
+            inserted_rows: self.inserted_rows.clone(),
+            updated_cells: self.updated_cells.clone(),
+            deleted_rows: self.deleted_rows.clone(),
+        });
+    }
+
+    /// Runs `edit` as one undoable step. Edits that leave the staged rows
+    /// and cells unchanged do not add to the history.
+    pub fn stage_edit(&mut self, edit: impl FnOnce(&mut Self)) {
+        self.record_undo_point();
+        edit(self);
+        if self.undo_history.last().is_some_and(|snapshot| {
+            snapshot.inserted_rows == self.inserted_rows
+                && snapshot.updated_cells == self.updated_cells
+                && snapshot.deleted_rows == self.deleted_rows
+        }) {
+            self.undo_history.pop();
+        }
+    }
+
+    /// Restores the staged edits from before the most recent edit. Returns
+    /// `false` when there is nothing to undo.
+    pub fn undo(&mut self) -> bool {
+        let Some(snapshot) = self.undo_history.pop() else {
+            return false;
+        };
+        self.inserted_rows = snapshot.inserted_rows;
+        self.updated_cells = snapshot.updated_cells;
+        self.deleted_rows = snapshot.deleted_rows;
+        true
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -143,6 +213,149 @@ pub enum QueryOutput {
     AffectedRows(u64),
 }
 
+/// Aggregate profile of a single table column.
+///
+/// `min`, `max` and `avg` are rendered as text by the database and are `None`
+/// when the column is entirely NULL or the type does not support the aggregate.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ColumnStats {
+    pub column: String,
+    pub total_count: u64,
+    pub distinct_count: u64,
+    pub null_count: u64,
+    pub min: Option<String>,
+    pub max: Option<String>,
+    pub avg: Option<String>,
+}
+
+/// Total rows of a table preview, exact or taken from planner statistics.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TableRowCount {
+    pub rows: u64,
+    pub estimated: bool,
+}
+
+/// Parsing options for importing a CSV file into a table.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CsvImportOptions {
+    pub has_header: bool,
+    pub delimiter: char,
+    pub quote: char,
+    /// Target columns, in file order, used when the file has no header row.
+    pub columns: Vec<String>,
+    /// Target column of each file column, in file order, with `None` skipping
+    /// that file column. Takes precedence over header names and `columns`
+    /// when not empty.
+    pub column_mapping: Vec<Option<String>>,
+}
+
+impl Default for CsvImportOptions {
+    fn default() -> Self {
+        Self {
+            has_header: true,
+            delimiter: ',',
+            quote: '"',
+            columns: Vec::new(),
+            column_mapping: Vec::new(),
+        }
+    }
+}
+
+/// First rows of a CSV file, read to set up an import.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CsvFilePreview {
+    /// Header names, or `column_1`, `column_2`, ... when the file has none.
+    pub headers: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+/// Formatting options for writing a result to a CSV file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CsvExportOptions {
+    pub delimiter: char,
+    /// End records with `\r\n` instead of `\n`.
+    pub crlf: bool,
+    /// Quote every field, not only the ones that need it.
+    pub quote_all: bool,
+}
+
+impl Default for CsvExportOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: ',',
+            crlf: false,
+            quote_all: false,
+        }
+    }
+}
+
+/// Layout of a JSON export file.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum JsonExportFormat {
+    /// A pretty-printed array of row objects.
+    #[default]
+    Array,
+    /// One compact row object per line (NDJSON).
+    Lines,
+    /// The row array wrapped in an object with the source table, column
+    /// types and export time.
+    ArrayWithMetadata,
+}
+
+/// Options for writing a result to a JSON file.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct JsonExportOptions {
+    pub format: JsonExportFormat,
+    pub schema: Option<String>,
+    pub table_name: Option<String>,
+    /// Declared type of each result column, by column name. Columns without a
+    /// known type export as strings.
+    pub column_types: HashMap<String, String>,
+}
+
+/// Options for writing a result as an SQL script of INSERT statements.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SqlInsertExportOptions {
+    pub schema: Option<String>,
+    pub table_name: String,
+    /// Rows per multi-row `VALUES` statement; 1 writes one INSERT per row.
+    pub batch_size: usize,
+    /// Declared type of each result column, by column name. Numbers and
+    /// booleans in columns with a known type are written unquoted.
+    pub column_types: HashMap<String, String>,
+}
+
+impl Default for SqlInsertExportOptions {
+    fn default() -> Self {
+        Self {
+            schema: None,
+            table_name: "exported_table".to_string(),
+            batch_size: 1,
+            column_types: HashMap::new(),
+        }
+    }
+}
+
+/// Where a full (re-queried) export reads its rows from.
+#[derive(Clone, Debug, PartialEq)]
+pub enum QueryExportSource {
+    Table(TablePreviewSource),
+    Sql(String),
+}
+
+/// State of an explicit user transaction on a connection session.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TransactionStatus {
+    /// No transaction is open; statements autocommit.
+    #[default]
+    Idle,
+    /// A transaction is open and accepting statements.
+    Active,
+    /// A statement failed and the server rejects further statements until
+    /// the transaction is rolled back (PostgreSQL semantics).
+    Aborted,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum WorkspaceTabKind {
     Query,
@@ -165,6 +378,8 @@ pub struct QueryTabState {
     pub filter: Option<QueryFilter>,
     pub sort: Option<QuerySort>,
     pub tab_kind: WorkspaceTabKind,
+    /// Table a `Structure` tab describes.
+    pub structure_source: Option<TablePreviewSource>,
     pub is_loading_more: bool,
     pub pending_table_changes: PendingTableChanges,
     pub execution_plan: Option<ExecutionPlan>,
@@ -198,6 +413,9 @@ pub struct QueryHistoryItem {
     pub connection_type: String,
     #[serde(default)]
     pub error_message: Option<String>,
+    /// Kept when the history is trimmed or cleared, and listed first.
+    #[serde(default)]
+    pub pinned: bool,
 }
 
 /// Filter for searching query history.
@@ -217,3 +435,50 @@ pub enum QueryHistoryErrorStatus {
     Failed,
     Any,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{PENDING_UNDO_LIMIT, PendingCellChange, PendingTableChanges};
+
+    fn cell_change(value: &str) -> PendingCellChange {
+        PendingCellChange {
+            locator: "1".to_string(),
+            column_name: "name".to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    #[test]
+    fn undo_steps_back_through_staged_edits() {
+        let mut changes = PendingTableChanges::default();
+        changes.record_undo_point();
+        changes.updated_cells.push(cell_change("first"));
+        changes.record_undo_point();
+        changes.updated_cells[0] = cell_change("second");
+
+        assert!(changes.undo());
+        assert_eq!(changes.updated_cells, vec![cell_change("first")]);
+        assert!(changes.undo());
+        assert!(changes.is_empty());
+        assert!(!changes.undo());
+    }
+
+    #[test]
+    fn unchanged_edits_leave_no_undo_step() {
+        let mut changes = PendingTableChanges::default();
+        changes.stage_edit(|changes| changes.updated_cells.push(cell_change("first")));
+        changes.stage_edit(|changes| changes.updated_cells[0] = cell_change("first"));
+
+        assert_eq!(changes.undo_history.len(), 1);
+    }
+
+    #[test]
+    fn undo_history_is_capped() {
+        let mut changes = PendingTableChanges::default();
+        for _ in 0..PENDING_UNDO_LIMIT + 10 {
+            changes.record_undo_point();
+        }
+
+        assert_eq!(changes.undo_history.len(), PENDING_UNDO_LIMIT);
+    }
+}
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::ops::Range;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum SavedQueryKind {
@@ -34,4 +35,293 @@ impl SavedQuery {
             SavedQueryKind::Snippet => "Snippet",
         }
     }
+
+    /// Distinct `${name}` variables of the SQL, in order of first use. They
+    /// are asked for when the query is loaded.
+    pub fn variables(&self) -> Vec<String> {
+        let mut names: Vec<String> = Vec::new();
+        for (_, name) in variable_spans(&self.sql) {
+            if !names.iter().any(|existing| existing == name) {
+                names.push(name.to_string());
+            }
+        }
+        names
+    }
+
+    /// The SQL with every `${name}` replaced by its value. Variables without
+    /// a value are left as they are.
+    pub fn render(&self, values: &[(String, String)]) -> String {
+        let mut rendered = String::with_capacity(self.sql.len());
+        let mut cursor = 0;
+        for (range, name) in variable_spans(&self.sql) {
+            let Some((_, value)) = values.iter().find(|(variable, _)| variable == name) else {
+                continue;
+            };
+            rendered.push_str(&self.sql[cursor..range.start]);
+            rendered.push_str(value);
+            cursor = range.end;
+        }
+        rendered.push_str(&self.sql[cursor..]);
+        rendered
+    }
+}
+
+/// Saved queries grouped by folder, with folders and the titles in each in
+/// alphabetical order.
+pub fn saved_queries_by_folder(mut items: Vec<SavedQuery>) -> Vec<(String, Vec<SavedQuery>)> {
+    items.sort_by(|left, right| {
+        left.folder_name()
+            .cmp(right.folder_name())
+            .then_with(|| left.title.cmp(&right.title))
+            .then_with(|| left.id.cmp(&right.id))
+    });
+    let mut folders: Vec<(String, Vec<SavedQuery>)> = Vec::new();
+    for item in items {
+        match folders.last_mut() {
+            Some((folder, entries)) if folder == item.folder_name() => entries.push(item),
+            _ => folders.push((item.folder_name().to_string(), vec![item])),
+        }
+    }
+    folders
+}
+
+/// Byte range and name of each `${name}` in `sql`. Names are identifiers;
+/// anything else between the braces is not a variable.
+fn variable_spans(sql: &str) -> Vec<(Range<usize>, &str)> {
+    let mut spans = Vec::new();
+    let mut from = 0;
+    while let Some(offset) = sql[from..].find("${") {
+        let start = from + offset;
+        let name_start = start + 2;
+        let Some(length) = sql[name_start..].find('}') else {
+            break;
+        };
+        let name = &sql[name_start..name_start + length];
+        let is_identifier = name
+            .chars()
+            .next()
+            .is_some_and(|first| first.is_alphabetic() || first == '_')
+            && name.chars().all(|ch| ch.is_alphanumeric() || ch == '_');
+        if is_identifier {
+            spans.push((start..name_start + length + 1, name));
+            from = name_start + length + 1;
+        } else {
+            from = name_start;
+        }
+    }
+    spans
+}
+
+/// Placeholder of a [`QueryTemplate`], written `{{name}}` in its body.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TemplateParam {
+    pub name: String,
+    #[serde(default)]
+    pub default_value: String,
+}
+
+/// Reusable SQL with `{{param}}` placeholders that are filled in before it is
+/// inserted into the editor.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QueryTemplate {
+    pub id: u64,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    pub body: String,
+    #[serde(default)]
+    pub parameters: Vec<TemplateParam>,
+    /// Shipped with the app rather than saved by the user; never persisted.
+    #[serde(skip)]
+    pub builtin: bool,
+}
+
+impl QueryTemplate {
+    /// `body` with every `{{name}}` replaced by its value. Placeholders
+    /// without a value are left as they are.
+    pub fn render(&self, values: &[(String, String)]) -> String {
+        let mut rendered = String::with_capacity(self.body.len());
+        let mut rest = self.body.as_str();
+        while let Some(start) = rest.find("{{") {
+            let Some(length) = rest[start + 2..].find("}}") else {
+                break;
+            };
+            let name = rest[start + 2..start + 2 + length].trim();
+            rendered.push_str(&rest[..start]);
+            match values.iter().find(|(param, _)| param == name) {
+                Some((_, value)) => rendered.push_str(value),
+                None => rendered.push_str(&rest[start..start + length + 4]),
+            }
+            rest = &rest[start + length + 4..];
+        }
+        rendered.push_str(rest);
+        rendered
+    }
+}
+
+/// Distinct `{{name}}` placeholders of `body`, in order of first use.
+pub fn template_placeholders(body: &str) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    let mut rest = body;
+    while let Some(start) = rest.find("{{") {
+        let Some(length) = rest[start + 2..].find("}}") else {
+            break;
+        };
+        let name = rest[start + 2..start + 2 + length].trim();
+        if !name.is_empty() && !names.iter().any(|existing| existing == name) {
+            names.push(name.to_string());
+        }
+        rest = &rest[start + length + 4..];
+    }
+    names
+}
+
+/// PostgreSQL templates that ship with the app.
+pub fn builtin_query_templates() -> Vec<QueryTemplate> {
+    let param = |name: &str, default_value: &str| TemplateParam {
+        name: name.to_string(),
+        default_value: default_value.to_string(),
+    };
+    let template = |name: &str, description: &str, body: &str, parameters| QueryTemplate {
+        id: 0,
+        name: name.to_string(),
+        description: description.to_string(),
+        body: body.to_string(),
+        parameters,
+        builtin: true,
+    };
+
+    vec![
+        template(
+            "Find Blocking Queries",
+            "Sessions waiting on a lock, with the session holding it.",
+            "select\n    blocked.pid as blocked_pid,\n    blocked.usename as blocked_user,\n    now() - blocked.query_start as waiting_for,\n    blocked.query as blocked_query,\n    blocking.pid as blocking_pid,\n    blocking.usename as blocking_user,\n    blocking.state as blocking_state,\n    blocking.query as blocking_query\nfrom pg_stat_activity blocked\njoin lateral unnest(pg_blocking_pids(blocked.pid)) as blocker(pid) on true\njoin pg_stat_activity blocking on blocking.pid = blocker.pid\nwhere now() - blocked.query_start >= interval '{{min_wait_seconds}} seconds'\norder by waiting_for desc;",
+            vec![param("min_wait_seconds", "0")],
+        ),
+        template(
+            "Table Bloat Estimate",
+            "Dead row share per table and the space it roughly takes up.",
+            "select\n    schemaname as schema,\n    relname as table_name,\n    n_live_tup as live_rows,\n    n_dead_tup as dead_rows,\n    round(100.0 * n_dead_tup / nullif(n_live_tup + n_dead_tup, 0), 1) as dead_percent,\n    pg_size_pretty(pg_total_relation_size(relid)) as total_size,\n    pg_size_pretty((pg_total_relation_size(relid) * n_dead_tup / nullif(n_live_tup + n_dead_tup, 0))::bigint) as estimated_bloat\nfrom pg_stat_user_tables\nwhere schemaname = '{{schema}}'\norder by n_dead_tup desc\nlimit {{limit}};",
+            vec![param("schema", "public"), param("limit", "20")],
+        ),
+        template(
+            "Unused Indexes",
+            "Indexes scanned at most a few times since statistics were reset, largest first. Unique and primary key indexes are left out.",
+            "select\n    stat.schemaname as schema,\n    stat.relname as table_name,\n    stat.indexrelname as index_name,\n    stat.idx_scan as scans,\n    pg_size_pretty(pg_relation_size(stat.indexrelid)) as index_size\nfrom pg_stat_user_indexes stat\njoin pg_index idx on idx.indexrelid = stat.indexrelid\nwhere stat.idx_scan <= {{max_scans}}\n  and not idx.indisunique\n  and not idx.indisprimary\norder by pg_relation_size(stat.indexrelid) desc;",
+            vec![param("max_scans", "0")],
+        ),
+        template(
+            "Slow Queries from pg_stat_statements",
+            "Statements with the highest mean execution time. Needs the pg_stat_statements extension on PostgreSQL 13 or later.",
+            "select\n    calls,\n    round(total_exec_time::numeric, 1) as total_ms,\n    round(mean_exec_time::numeric, 1) as mean_ms,\n    rows,\n    query\nfrom pg_stat_statements\nwhere mean_exec_time >= {{min_mean_ms}}\norder by mean_exec_time desc\nlimit {{limit}};",
+            vec![param("min_mean_ms", "100"), param("limit", "20")],
+        ),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        QueryTemplate, SavedQuery, SavedQueryKind, builtin_query_templates,
+        saved_queries_by_folder, template_placeholders,
+    };
+
+    #[test]
+    fn saved_query_variables_are_listed_once_and_rendered() {
+        let query = SavedQuery {
+            id: 1,
+            title: "Orders".to_string(),
+            folder: "Reports".to_string(),
+            sql: "select * from orders where day >= '${from}' and day < '${to}' and '${from}' <> '${ not a var }' and x = '${}' and y = '${unclosed"
+                .to_string(),
+            kind: SavedQueryKind::Query,
+            connection_name: None,
+        };
+
+        assert_eq!(
+            query.variables(),
+            vec!["from".to_string(), "to".to_string()]
+        );
+        assert_eq!(
+            query.render(&[("from".to_string(), "2024-01-01".to_string())]),
+            "select * from orders where day >= '2024-01-01' and day < '${to}' and '2024-01-01' <> '${ not a var }' and x = '${}' and y = '${unclosed"
+        );
+    }
+
+    #[test]
+    fn saved_queries_are_grouped_by_sorted_folder() {
+        let query = |id: u64, title: &str, folder: &str| SavedQuery {
+            id,
+            title: title.to_string(),
+            folder: folder.to_string(),
+            sql: "select 1".to_string(),
+            kind: SavedQueryKind::Query,
+            connection_name: None,
+        };
+        let folders = saved_queries_by_folder(vec![
+            query(1, "Orders", "Reports"),
+            query(2, "Scratch", " "),
+            query(3, "Customers", "Reports"),
+        ]);
+
+        let summary = folders
+            .iter()
+            .map(|(folder, items)| {
+                let titles = items
+                    .iter()
+                    .map(|item| item.title.as_str())
+                    .collect::<Vec<_>>();
+                (folder.as_str(), titles)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            vec![
+                ("General", vec!["Scratch"]),
+                ("Reports", vec!["Customers", "Orders"]),
+            ]
+        );
+    }
+
+    #[test]
+    fn render_replaces_known_placeholders_only() {
+        let template = QueryTemplate {
+            id: 1,
+            name: "Rows".to_string(),
+            description: String::new(),
+            body: "select * from {{ table }} where id = {{id}} and {{missing}} limit {{id}}"
+                .to_string(),
+            parameters: Vec::new(),
+            builtin: false,
+        };
+        let values = vec![
+            ("table".to_string(), "orders".to_string()),
+            ("id".to_string(), "7".to_string()),
+        ];
+
+        assert_eq!(
+            template.render(&values),
+            "select * from orders where id = 7 and {{missing}} limit 7"
+        );
+    }
+
+    #[test]
+    fn template_placeholders_lists_each_name_once() {
+        assert_eq!(
+            template_placeholders("{{a}} {{ b }} {{a}} {{}} {{unclosed"),
+            vec!["a".to_string(), "b".to_string()]
+        );
+    }
+
+    #[test]
+    fn builtin_templates_declare_every_placeholder() {
+        for template in builtin_query_templates() {
+            let declared = template
+                .parameters
+                .iter()
+                .map(|param| param.name.clone())
+                .collect::<Vec<_>>();
+            assert_eq!(template_placeholders(&template.body), declared);
+        }
+    }
 }
@@ -0,0 +1,535 @@
+/// A table of one schema with the parts the schema diff compares.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SchemaTable {
+    pub name: String,
+    pub columns: Vec<SchemaTableColumn>,
+    /// Indexes that do not back a constraint; those come with the constraint.
+    pub indexes: Vec<SchemaTableIndex>,
+    pub constraints: Vec<SchemaTableConstraint>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SchemaTableColumn {
+    pub name: String,
+    /// Type as `format_type` prints it, e.g. `character varying(80)`.
+    pub data_type: String,
+    pub nullable: bool,
+}
+
+impl SchemaTableColumn {
+    fn describe(&self) -> String {
+        if self.nullable {
+            format!("{} {}", self.name, self.data_type)
+        } else {
+            format!("{} {} not null", self.name, self.data_type)
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SchemaTableIndex {
+    pub name: String,
+    pub is_unique: bool,
+    /// Everything after `USING` in the index definition, e.g.
+    /// `btree (email) WHERE active`, so it does not name the schema.
+    pub definition: String,
+}
+
+impl SchemaTableIndex {
+    fn describe(&self) -> String {
+        if self.is_unique {
+            format!("{} unique {}", self.name, self.definition)
+        } else {
+            format!("{} {}", self.name, self.definition)
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SchemaTableConstraint {
+    pub name: String,
+    /// Output of `pg_get_constraintdef`, e.g. `PRIMARY KEY (id)`.
+    pub definition: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SchemaChangeKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+/// One difference between a left schema and a right one. "Added" means the
+/// right schema has something the left one lacks.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SchemaChange {
+    TableAdded(SchemaTable),
+    TableRemoved(SchemaTable),
+    ColumnAdded {
+        table: String,
+        column: SchemaTableColumn,
+    },
+    ColumnRemoved {
+        table: String,
+        column: SchemaTableColumn,
+    },
+    ColumnChanged {
+        table: String,
+        left: SchemaTableColumn,
+        right: SchemaTableColumn,
+    },
+    IndexAdded {
+        table: String,
+        index: SchemaTableIndex,
+    },
+    IndexRemoved {
+        table: String,
+        index: SchemaTableIndex,
+    },
+    IndexChanged {
+        table: String,
+        left: SchemaTableIndex,
+        right: SchemaTableIndex,
+    },
+    ConstraintAdded {
+        table: String,
+        constraint: SchemaTableConstraint,
+    },
+    ConstraintRemoved {
+        table: String,
+        constraint: SchemaTableConstraint,
+    },
+    ConstraintChanged {
+        table: String,
+        left: SchemaTableConstraint,
+        right: SchemaTableConstraint,
+    },
+}
+
+impl SchemaChange {
+    pub fn table(&self) -> &str {
+        match self {
+            Self::TableAdded(table) | Self::TableRemoved(table) => &table.name,
+            Self::ColumnAdded { table, .. }
+            | Self::ColumnRemoved { table, .. }
+            | Self::ColumnChanged { table, .. }
+            | Self::IndexAdded { table, .. }
+            | Self::IndexRemoved { table, .. }
+            | Self::IndexChanged { table, .. }
+            | Self::ConstraintAdded { table, .. }
+            | Self::ConstraintRemoved { table, .. }
+            | Self::ConstraintChanged { table, .. } => table,
+        }
+    }
+
+    pub fn kind(&self) -> SchemaChangeKind {
+        match self {
+            Self::TableAdded(_)
+            | Self::ColumnAdded { .. }
+            | Self::IndexAdded { .. }
+            | Self::ConstraintAdded { .. } => SchemaChangeKind::Added,
+            Self::TableRemoved(_)
+            | Self::ColumnRemoved { .. }
+            | Self::IndexRemoved { .. }
+            | Self::ConstraintRemoved { .. } => SchemaChangeKind::Removed,
+            Self::ColumnChanged { .. }
+            | Self::IndexChanged { .. }
+            | Self::ConstraintChanged { .. } => SchemaChangeKind::Changed,
+        }
+    }
+
+    /// Kind of object that differs: table, column, index or constraint.
+    pub fn object(&self) -> &'static str {
+        match self {
+            Self::TableAdded(_) | Self::TableRemoved(_) => "table",
+            Self::ColumnAdded { .. } | Self::ColumnRemoved { .. } | Self::ColumnChanged { .. } => {
+                "column"
+            }
+            Self::IndexAdded { .. } | Self::IndexRemoved { .. } | Self::IndexChanged { .. } => {
+                "index"
+            }
+            Self::ConstraintAdded { .. }
+            | Self::ConstraintRemoved { .. }
+            | Self::ConstraintChanged { .. } => "constraint",
+        }
+    }
+
+    /// How the object looks in the left schema, empty when it is missing there.
+    pub fn left_text(&self) -> String {
+        match self {
+            Self::TableRemoved(table) => describe_table(table),
+            Self::ColumnRemoved { column, .. } | Self::ColumnChanged { left: column, .. } => {
+                column.describe()
+            }
+            Self::IndexRemoved { index, .. } | Self::IndexChanged { left: index, .. } => {
+                index.describe()
+            }
+            Self::ConstraintRemoved { constraint, .. }
+            | Self::ConstraintChanged {
+                left: constraint, ..
+            } => format!("{} {}", constraint.name, constraint.definition),
+            _ => String::new(),
+        }
+    }
+
+    /// How the object looks in the right schema, empty when it is missing there.
+    pub fn right_text(&self) -> String {
+        match self {
+            Self::TableAdded(table) => describe_table(table),
+            Self::ColumnAdded { column, .. } | Self::ColumnChanged { right: column, .. } => {
+                column.describe()
+            }
+            Self::IndexAdded { index, .. } | Self::IndexChanged { right: index, .. } => {
+                index.describe()
+            }
+            Self::ConstraintAdded { constraint, .. }
+            | Self::ConstraintChanged {
+                right: constraint, ..
+            } => format!("{} {}", constraint.name, constraint.definition),
+            _ => String::new(),
+        }
+    }
+
+    /// PostgreSQL statements that make `schema` (the left one) match the
+    /// right schema for this change.
+    fn migration(&self, schema: &str) -> Vec<String> {
+        let table = qualified_name(schema, self.table());
+        match self {
+            Self::TableAdded(added) => {
+                let columns = added
+                    .columns
+                    .iter()
+                    .map(|column| format!("  {}", column_definition(column)))
+                    .collect::<Vec<_>>()
+                    .join(",\n");
+                let mut statements = vec![format!("create table {table} (\n{columns}\n);")];
+                statements.extend(
+                    added
+                        .constraints
+                        .iter()
+                        .map(|constraint| add_constraint(&table, constraint)),
+                );
+                statements.extend(
+                    added
+                        .indexes
+                        .iter()
+                        .map(|index| create_index(&table, index)),
+                );
+                statements
+            }
+            Self::TableRemoved(_) => vec![format!("drop table {table};")],
+            Self::ColumnAdded { column, .. } => vec![format!(
+                "alter table {table} add column {};",
+                column_definition(column)
+            )],
+            Self::ColumnRemoved { column, .. } => vec![format!(
+                "alter table {table} drop column {};",
+                quote_identifier(&column.name)
+            )],
+            Self::ColumnChanged { left, right, .. } => {
+                let column = quote_identifier(&right.name);
+                let mut statements = Vec::new();
+                if left.data_type != right.data_type {
+                    statements.push(format!(
+                        "alter table {table} alter column {column} type {};",
+                        right.data_type
+                    ));
+                }
+                if left.nullable != right.nullable {
+                    let action = if right.nullable {
+                        "drop not null"
+                    } else {
+                        "set not null"
+                    };
+                    statements.push(format!(
+                        "alter table {table} alter column {column} {action};"
+                    ));
+                }
+                statements
+            }
+            Self::IndexAdded { index, .. } => vec![create_index(&table, index)],
+            Self::IndexRemoved { index, .. } => vec![drop_index(schema, index)],
+            Self::IndexChanged { left, right, .. } => {
+                vec![drop_index(schema, left), create_index(&table, right)]
+            }
+            Self::ConstraintAdded { constraint, .. } => vec![add_constraint(&table, constraint)],
+            Self::ConstraintRemoved { constraint, .. } => {
+                vec![drop_constraint(&table, constraint)]
+            }
+            Self::ConstraintChanged { left, right, .. } => {
+                vec![drop_constraint(&table, left), add_constraint(&table, right)]
+            }
+        }
+    }
+}
+
+/// Differences between two schemas, ordered by table name. Tables are matched
+/// by name, and so are their columns, indexes and constraints.
+pub fn diff_schemas(left: &[SchemaTable], right: &[SchemaTable]) -> Vec<SchemaChange> {
+    let mut changes = Vec::new();
+    for table in left {
+        match right.iter().find(|other| other.name == table.name) {
+            Some(other) => diff_table(table, other, &mut changes),
+            None => changes.push(SchemaChange::TableRemoved(table.clone())),
+        }
+    }
+    for table in right {
+        if !left.iter().any(|other| other.name == table.name) {
+            changes.push(SchemaChange::TableAdded(table.clone()));
+        }
+    }
+    // Stable, so each table keeps its columns before indexes and constraints.
+    changes.sort_by(|a, b| a.table().cmp(b.table()));
+    changes
+}
+
+fn diff_table(left: &SchemaTable, right: &SchemaTable, changes: &mut Vec<SchemaChange>) {
+    let table = || left.name.clone();
+
+    for column in &left.columns {
+        match right.columns.iter().find(|other| other.name == column.name) {
+            Some(other) if other != column => changes.push(SchemaChange::ColumnChanged {
+                table: table(),
+                left: column.clone(),
+                right: other.clone(),
+            }),
+            Some(_) => {}
+            None => changes.push(SchemaChange::ColumnRemoved {
+                table: table(),
+                column: column.clone(),
+            }),
+        }
+    }
+    for column in &right.columns {
+        if !left.columns.iter().any(|other| other.name == column.name) {
+            changes.push(SchemaChange::ColumnAdded {
+                table: table(),
+                column: column.clone(),
+            });
+        }
+    }
+
+    for index in &left.indexes {
+        match right.indexes.iter().find(|other| other.name == index.name) {
+            Some(other) if other != index => changes.push(SchemaChange::IndexChanged {
+                table: table(),
+                left: index.clone(),
+                right: other.clone(),
+            }),
+            Some(_) => {}
+            None => changes.push(SchemaChange::IndexRemoved {
+                table: table(),
+                index: index.clone(),
+            }),
+        }
+    }
+    for index in &right.indexes {
+        if !left.indexes.iter().any(|other| other.name == index.name) {
+            changes.push(SchemaChange::IndexAdded {
+                table: table(),
+                index: index.clone(),
+            });
+        }
+    }
+
+    for constraint in &left.constraints {
+        match right
+            .constraints
+            .iter()
+            .find(|other| other.name == constraint.name)
+        {
+            Some(other) if other != constraint => changes.push(SchemaChange::ConstraintChanged {
+                table: table(),
+                left: constraint.clone(),
+                right: other.clone(),
+            }),
+            Some(_) => {}
+            None => changes.push(SchemaChange::ConstraintRemoved {
+                table: table(),
+                constraint: constraint.clone(),
+            }),
+        }
+    }
+    for constraint in &right.constraints {
+        if !left
+            .constraints
+            .iter()
+            .any(|other| other.name == constraint.name)
+        {
+            changes.push(SchemaChange::ConstraintAdded {
+                table: table(),
+                constraint: constraint.clone(),
+            });
+        }
+    }
+}
+
+/// A PostgreSQL script that brings `schema` (the left side of `changes`) in
+/// line with the right side. Constraint definitions are copied verbatim, so
+/// foreign keys may still name the right schema and need a review.
+pub fn schema_migration_sql(schema: &str, changes: &[SchemaChange]) -> String {
+    changes
+        .iter()
+        .flat_map(|change| change.migration(schema))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn describe_table(table: &SchemaTable) -> String {
+    let columns = table.columns.len();
+    if columns == 1 {
+        format!("{} (1 column)", table.name)
+    } else {
+        format!("{} ({columns} columns)", table.name)
+    }
+}
+
+fn column_definition(column: &SchemaTableColumn) -> String {
+    let name = quote_identifier(&column.name);
+    if column.nullable {
+        format!("{name} {}", column.data_type)
+    } else {
+        format!("{name} {} not null", column.data_type)
+    }
+}
+
+fn create_index(table: &str, index: &SchemaTableIndex) -> String {
+    let unique = if index.is_unique { "unique " } else { "" };
+    format!(
+        "create {unique}index {} on {table} using {};",
+        quote_identifier(&index.name),
+        index.definition
+    )
+}
+
+fn drop_index(schema: &str, index: &SchemaTableIndex) -> String {
+    format!("drop index {};", qualified_name(schema, &index.name))
+}
+
+fn add_constraint(table: &str, constraint: &SchemaTableConstraint) -> String {
+    format!(
+        "alter table {table} add constraint {} {};",
+        quote_identifier(&constraint.name),
+        constraint.definition
+    )
+}
+
+fn drop_constraint(table: &str, constraint: &SchemaTableConstraint) -> String {
+    format!(
+        "alter table {table} drop constraint {};",
+        quote_identifier(&constraint.name)
+    )
+}
+
+fn qualified_name(schema: &str, name: &str) -> String {
+    format!("{}.{}", quote_identifier(schema), quote_identifier(name))
+}
+
+fn quote_identifier(identifier: &str) -> String {
+    format!("\"{}\"", identifier.replace('"', "\"\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        SchemaChange, SchemaChangeKind, SchemaTable, SchemaTableColumn, SchemaTableConstraint,
+        SchemaTableIndex, diff_schemas, schema_migration_sql,
+    };
+
+    fn column(name: &str, data_type: &str, nullable: bool) -> SchemaTableColumn {
+        SchemaTableColumn {
+            name: name.to_string(),
+            data_type: data_type.to_string(),
+            nullable,
+        }
+    }
+
+    fn users(columns: Vec<SchemaTableColumn>) -> SchemaTable {
+        SchemaTable {
+            name: "users".to_string(),
+            columns,
+            indexes: Vec::new(),
+            constraints: vec![SchemaTableConstraint {
+                name: "users_pkey".to_string(),
+                definition: "PRIMARY KEY (id)".to_string(),
+            }],
+        }
+    }
+
+    #[test]
+    fn diff_schemas_reports_tables_columns_and_type_changes() {
+        let left = vec![
+            users(vec![
+                column("id", "integer", false),
+                column("name", "text", true),
+                column("legacy", "text", true),
+            ]),
+            SchemaTable {
+                name: "audit".to_string(),
+                ..SchemaTable::default()
+            },
+        ];
+        let mut right_users = users(vec![
+            column("id", "bigint", false),
+            column("name", "text", true),
+            column("email", "text", false),
+        ]);
+        right_users.indexes.push(SchemaTableIndex {
+            name: "users_email_idx".to_string(),
+            is_unique: true,
+            definition: "btree (email)".to_string(),
+        });
+        let right = vec![right_users];
+
+        let changes = diff_schemas(&left, &right);
+        let summary = changes
+            .iter()
+            .map(|change| (change.table(), change.object(), change.kind()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            vec![
+                ("audit", "table", SchemaChangeKind::Removed),
+                ("users", "column", SchemaChangeKind::Changed),
+                ("users", "column", SchemaChangeKind::Removed),
+                ("users", "column", SchemaChangeKind::Added),
+                ("users", "index", SchemaChangeKind::Added),
+            ]
+        );
+        assert_eq!(changes[1].left_text(), "id integer not null");
+        assert_eq!(changes[1].right_text(), "id bigint not null");
+        assert!(diff_schemas(&right, &right).is_empty());
+    }
+
+    #[test]
+    fn schema_migration_sql_targets_the_left_schema() {
+        let changes = vec![
+            SchemaChange::TableAdded(users(vec![column("id", "integer", false)])),
+            SchemaChange::ColumnChanged {
+                table: "orders".to_string(),
+                left: column("total", "integer", false),
+                right: column("total", "numeric(12,2)", true),
+            },
+            SchemaChange::IndexRemoved {
+                table: "orders".to_string(),
+                index: SchemaTableIndex {
+                    name: "orders_total_idx".to_string(),
+                    is_unique: false,
+                    definition: "btree (total)".to_string(),
+                },
+            },
+        ];
+
+        assert_eq!(
+            schema_migration_sql("app", &changes),
+            [
+                "create table \"app\".\"users\" (\n  \"id\" integer not null\n);",
+                "alter table \"app\".\"users\" add constraint \"users_pkey\" PRIMARY KEY (id);",
+                "alter table \"app\".\"orders\" alter column \"total\" type numeric(12,2);",
+                "alter table \"app\".\"orders\" alter column \"total\" drop not null;",
+                "drop index \"app\".\"orders_total_idx\";",
+            ]
+            .join("\n")
+        );
+    }
+}
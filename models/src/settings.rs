@@ -6,15 +6,17 @@ pub enum WorkspaceToolPanel {
     Explorer,
     SavedQueries,
     History,
+    Notifications,
     Agent,
 }
 
 impl WorkspaceToolPanel {
-    pub const ALL: [Self; 5] = [
+    pub const ALL: [Self; 6] = [
         Self::Connections,
         Self::Explorer,
         Self::SavedQueries,
         Self::History,
+        Self::Notifications,
         Self::Agent,
     ];
 
@@ -24,6 +26,7 @@ impl WorkspaceToolPanel {
             Self::Explorer => "Explorer",
             Self::SavedQueries => "Saved Queries",
             Self::History => "History",
+            Self::Notifications => "Notifications",
             Self::Agent => "ACP Agent",
         }
     }
@@ -86,7 +89,68 @@ impl Default for WorkspaceToolLayout {
                 WorkspaceToolPanel::SavedQueries,
                 WorkspaceToolPanel::History,
             ],
-            inspector: vec![WorkspaceToolPanel::Agent],
+            inspector: vec![
+                WorkspaceToolPanel::Notifications,
+                WorkspaceToolPanel::Agent,
+            ],
+        }
+    }
+}
+
+/// Resizable workspace panel sizes in pixels, restored on the next launch.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WorkspacePanelSizes {
+    pub sidebar_width: u32,
+    pub inspector_width: u32,
+    pub editor_height: u32,
+}
+
+impl Default for WorkspacePanelSizes {
+    fn default() -> Self {
+        Self {
+            sidebar_width: 320,
+            inspector_width: 360,
+            editor_height: 180,
+        }
+    }
+}
+
+/// Main window size and position in logical pixels, restored on the next
+/// launch. Without a saved position the window system places it.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WindowGeometry {
+    pub width: f64,
+    pub height: f64,
+    pub x: Option<f64>,
+    pub y: Option<f64>,
+    /// Width and height keep the size from before the window was maximized.
+    pub maximized: bool,
+}
+
+impl WindowGeometry {
+    pub const MIN_WIDTH: f64 = 720.0;
+    pub const MIN_HEIGHT: f64 = 480.0;
+
+    /// The geometry with a size no smaller than the window allows.
+    pub fn clamped(self) -> Self {
+        Self {
+            width: self.width.max(Self::MIN_WIDTH),
+            height: self.height.max(Self::MIN_HEIGHT),
+            ..self
+        }
+    }
+}
+
+impl Default for WindowGeometry {
+    fn default() -> Self {
+        Self {
+            width: 1440.0,
+            height: 920.0,
+            x: None,
+            y: None,
+            maximized: false,
         }
     }
 }
@@ -158,6 +222,37 @@ impl Default for DeepSeekSettings {
     }
 }
 
+/// How table previews count their total rows.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RowCountMode {
+    /// `count(*)` on every new table/filter combination.
+    Exact,
+    /// Planner statistics where the database keeps them; unfiltered only.
+    #[default]
+    Estimate,
+    Off,
+}
+
+/// Indent widths offered for Tab in the SQL editor.
+pub const EDITOR_INDENT_WIDTHS: [u8; 2] = [2, 4];
+
+/// Base font sizes offered in settings, in points.
+pub const FONT_SIZES: std::ops::RangeInclusive<u8> = 10..=18;
+/// Font size the stylesheet is written for; other sizes zoom the interface.
+pub const DEFAULT_FONT_SIZE: u8 = 12;
+
+impl RowCountMode {
+    pub const ALL: [Self; 3] = [Self::Exact, Self::Estimate, Self::Off];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Exact => "Exact",
+            Self::Estimate => "Estimate",
+            Self::Off => "Off",
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(default)]
 pub struct AppUiSettings {
@@ -169,10 +264,25 @@ pub struct AppUiSettings {
     pub show_connections: bool,
     pub show_explorer: bool,
     pub show_history: bool,
+    pub show_notifications: bool,
     pub show_sql_editor: bool,
     pub show_agent_panel: bool,
     pub default_page_size: u32,
+    pub row_count_mode: RowCountMode,
+    /// Spaces inserted by Tab in the SQL editor.
+    pub editor_indent_width: u8,
+    /// Base font size in points, applied by zooming the whole interface.
+    pub font_size: u8,
+    /// Seconds before PostgreSQL cancels a query run from the editor; `None`
+    /// lets queries run unbounded.
+    pub query_timeout_secs: Option<u32>,
+    /// Reconnect with backoff when a session's connection drops.
+    pub auto_reconnect: bool,
+    /// Formats the editor's SQL with the SQL Formatting settings before the
+    /// Run button sends it.
+    pub format_on_execute: bool,
     pub tool_panel_layout: WorkspaceToolLayout,
+    pub panel_sizes: WorkspacePanelSizes,
     pub codestral: CodeStralSettings,
     pub deepseek: DeepSeekSettings,
 }
@@ -188,19 +298,36 @@ impl Default for AppUiSettings {
             show_connections: false,
             show_explorer: true,
             show_history: false,
+            show_notifications: false,
             show_sql_editor: false,
             show_agent_panel: false,
             default_page_size: 100,
+            row_count_mode: RowCountMode::default(),
+            editor_indent_width: 4,
+            font_size: DEFAULT_FONT_SIZE,
+            query_timeout_secs: None,
+            auto_reconnect: true,
+            format_on_execute: false,
             tool_panel_layout: WorkspaceToolLayout::default(),
+            panel_sizes: WorkspacePanelSizes::default(),
             codestral: CodeStralSettings::default(),
             deepseek: DeepSeekSettings::default(),
         }
     }
 }
 
+impl AppUiSettings {
+    /// Zoom factor that renders the interface at `font_size`. Sizes outside
+    /// [`FONT_SIZES`] from a hand-edited settings file are clamped.
+    pub fn font_zoom(&self) -> f64 {
+        let size = self.font_size.clamp(*FONT_SIZES.start(), *FONT_SIZES.end());
+        f64::from(size) / f64::from(DEFAULT_FONT_SIZE)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::AppUiSettings;
+    use super::{AppUiSettings, WindowGeometry, WorkspacePanelSizes};
 
     #[test]
     fn fresh_default_keeps_sql_editor_collapsed() {
@@ -214,6 +341,12 @@ mod tests {
         assert!(!defaults.read_only_mode);
     }
 
+    #[test]
+    fn fresh_default_runs_sql_as_written() {
+        let defaults = AppUiSettings::default();
+        assert!(!defaults.format_on_execute);
+    }
+
     #[test]
     fn persisted_read_only_mode_true_is_preserved() {
         let settings: AppUiSettings = serde_json::from_str(
@@ -240,6 +373,55 @@ mod tests {
         assert!(settings.read_only_mode);
     }
 
+    #[test]
+    fn panel_sizes_fill_missing_fields_with_defaults() {
+        let settings: AppUiSettings =
+            serde_json::from_str(r#"{"panel_sizes":{"sidebar_width":410}}"#)
+                .expect("settings fixture should deserialize");
+
+        assert_eq!(settings.panel_sizes.sidebar_width, 410);
+        assert_eq!(
+            settings.panel_sizes.editor_height,
+            WorkspacePanelSizes::default().editor_height
+        );
+        assert_eq!(
+            serde_json::from_str::<AppUiSettings>("{}")
+                .expect("empty settings should deserialize")
+                .panel_sizes,
+            WorkspacePanelSizes::default()
+        );
+    }
+
+    #[test]
+    fn window_geometry_is_clamped_to_the_minimum_size() {
+        let geometry: WindowGeometry =
+            serde_json::from_str(r#"{"width":300,"height":1000,"x":-20}"#)
+                .expect("geometry fixture should deserialize");
+
+        assert_eq!(
+            geometry.clamped(),
+            WindowGeometry {
+                width: WindowGeometry::MIN_WIDTH,
+                height: 1000.0,
+                x: Some(-20.0),
+                y: None,
+                maximized: false,
+            }
+        );
+    }
+
+    #[test]
+    fn font_zoom_is_relative_to_the_default_size_and_clamped() {
+        let mut settings = AppUiSettings::default();
+        assert_eq!(settings.font_zoom(), 1.0);
+
+        settings.font_size = 15;
+        assert_eq!(settings.font_zoom(), 1.25);
+
+        settings.font_size = 40;
+        assert_eq!(settings.font_zoom(), 1.5);
+    }
+
     #[test]
     fn persisted_show_sql_editor_true_is_preserved() {
         let settings: AppUiSettings = serde_json::from_str(
@@ -0,0 +1,147 @@
+/// Whether a declared column type is a PostgreSQL array: `integer[]` from
+/// `format_type`, `_int4` as an underlying type name, or the
+/// information_schema spelling `ARRAY`.
+pub fn is_postgres_array_type(data_type: &str) -> bool {
+    let data_type = data_type.trim();
+    data_type.ends_with("[]")
+        || data_type.starts_with('_')
+        || data_type.eq_ignore_ascii_case("array")
+}
+
+/// Renders array elements as a PostgreSQL array literal such as
+/// `{1,NULL,"two words"}`, quoting elements that would otherwise be misread.
+pub fn format_postgres_array(elements: &[Option<String>]) -> String {
+    let elements = elements
+        .iter()
+        .map(|element| match element {
+            None => "NULL".to_string(),
+            Some(value) if array_element_needs_quotes(value) => {
+                format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+            }
+            Some(value) => value.clone(),
+        })
+        .collect::<Vec<_>>();
+    format!("{{{}}}", elements.join(","))
+}
+
+/// Splits a one-dimensional PostgreSQL array literal into its elements, with
+/// unquoted `NULL` as `None`. Returns `None` for nested arrays or text that is
+/// not an array literal.
+pub fn parse_postgres_array(text: &str) -> Option<Vec<Option<String>>> {
+    let inner = text.trim().strip_prefix('{')?.strip_suffix('}')?;
+    if inner.trim().is_empty() {
+        return Some(Vec::new());
+    }
+
+    let mut elements = Vec::new();
+    let mut chars = inner.chars().peekable();
+    loop {
+        while chars.next_if(|ch| ch.is_whitespace()).is_some() {}
+        match chars.peek() {
+            Some('{') => return None,
+            Some('"') => {
+                chars.next();
+                let mut value = String::new();
+                loop {
+                    match chars.next()? {
+                        '\\' => value.push(chars.next()?),
+                        '"' => break,
+                        ch => value.push(ch),
+                    }
+                }
+                elements.push(Some(value));
+                while chars.next_if(|ch| ch.is_whitespace()).is_some() {}
+            }
+            _ => {
+                let mut value = String::new();
+                while let Some(ch) = chars.next_if(|ch| *ch != ',') {
+                    match ch {
+                        '{' | '}' | '"' => return None,
+                        '\\' => value.push(chars.next()?),
+                        ch => value.push(ch),
+                    }
+                }
+                let value = value.trim_end();
+                if value.is_empty() {
+                    return None;
+                }
+                elements.push((!value.eq_ignore_ascii_case("null")).then(|| value.to_string()));
+            }
+        }
+
+        match chars.next() {
+            Some(',') => continue,
+            None => return Some(elements),
+            Some(_) => return None,
+        }
+    }
+}
+
+fn array_element_needs_quotes(value: &str) -> bool {
+    value.is_empty()
+        || value.eq_ignore_ascii_case("null")
+        || value
+            .chars()
+            .any(|ch| matches!(ch, '{' | '}' | ',' | '"' | '\\') || ch.is_whitespace())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_postgres_array, is_postgres_array_type, parse_postgres_array};
+
+    fn elements(values: &[Option<&str>]) -> Vec<Option<String>> {
+        values
+            .iter()
+            .map(|value| value.map(ToString::to_string))
+            .collect()
+    }
+
+    #[test]
+    fn detects_array_type_spellings() {
+        assert!(is_postgres_array_type("integer[]"));
+        assert!(is_postgres_array_type("character varying(20)[]"));
+        assert!(is_postgres_array_type("_text"));
+        assert!(is_postgres_array_type("ARRAY"));
+        assert!(!is_postgres_array_type("text"));
+    }
+
+    #[test]
+    fn formats_and_quotes_elements() {
+        let values = elements(&[
+            Some("1"),
+            None,
+            Some("two words"),
+            Some("null"),
+            Some(""),
+            Some(r#"say "hi"\"#),
+        ]);
+
+        assert_eq!(
+            format_postgres_array(&values),
+            r#"{1,NULL,"two words","null","","say \"hi\"\\"}"#
+        );
+        assert_eq!(format_postgres_array(&[]), "{}");
+    }
+
+    #[test]
+    fn parses_what_it_formats() {
+        let values = elements(&[Some("a,b"), None, Some("NULL"), Some(r#"q"\"#), Some(" x ")]);
+
+        assert_eq!(
+            parse_postgres_array(&format_postgres_array(&values)),
+            Some(values)
+        );
+    }
+
+    #[test]
+    fn parses_unquoted_elements_and_rejects_nested_arrays() {
+        assert_eq!(
+            parse_postgres_array("{1, 2 ,NULL}"),
+            Some(elements(&[Some("1"), Some("2"), None]))
+        );
+        assert_eq!(parse_postgres_array("{}"), Some(Vec::new()));
+        assert_eq!(parse_postgres_array("{{1,2},{3,4}}"), None);
+        assert_eq!(parse_postgres_array("[1, 2]"), None);
+        assert_eq!(parse_postgres_array("{1,}"), None);
+    }
+}
@@ -65,6 +65,28 @@ pub(super) fn build_outer_paginated_query(
     format!("{base_query}{where_clause}{order_by} limit {limit} offset {offset}")
 }
 
+pub(super) fn build_count_query(
+    qualified_table_name: &str,
+    filter: Option<&QueryFilter>,
+    dialect: SqlBuildDialect,
+) -> String {
+    let where_clause = build_filter_clause(filter, dialect.filter_expression);
+    format!(
+        "select count(*) from {}{where_clause}",
+        qualified_table_name.trim().trim_end_matches(';')
+    )
+}
+
+/// Whether the filter contributes at least one condition to the WHERE clause.
+pub(super) fn has_filter_conditions(filter: Option<&QueryFilter>) -> bool {
+    filter.is_some_and(|filter| {
+        filter.rules.iter().any(|rule| {
+            !rule.column_name.trim().is_empty()
+                && (rule.operator.is_nullary() || !rule.value.trim().is_empty())
+        })
+    })
+}
+
 fn build_filter_clause(
     filter: Option<&QueryFilter>,
     filter_expression_fn: fn(&str, QueryFilterOperator, &str) -> String,
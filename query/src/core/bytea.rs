@@ -0,0 +1,86 @@
+use models::{DatabaseConnection, DatabaseError, TablePreviewSource};
+use sqlx::Row;
+
+use super::{quote_identifier, sql_literal};
+
+/// Bytes of a `bytea` value shown in a result cell; the rest is elided.
+const BYTEA_PREVIEW_BYTES: usize = 32;
+
+/// Whether a declared column type is PostgreSQL `bytea`.
+pub fn is_postgres_bytea_type(data_type: &str) -> bool {
+    data_type
+        .trim()
+        .rsplit('.')
+        .next()
+        .is_some_and(|name| name.trim_matches('"').eq_ignore_ascii_case("bytea"))
+}
+
+/// Renders binary data as hex for a result cell, such as `0x0A1B2C (3 bytes)`.
+/// Only the first 32 bytes are written out, followed by `…` when there are
+/// more.
+pub fn format_bytea(bytes: &[u8]) -> String {
+    let mut text = String::with_capacity(2 + BYTEA_PREVIEW_BYTES * 2 + 16);
+    text.push_str("0x");
+    for byte in bytes.iter().take(BYTEA_PREVIEW_BYTES) {
+        text.push_str(&format!("{byte:02X}"));
+    }
+    if bytes.len() > BYTEA_PREVIEW_BYTES {
+        text.push('…');
+    }
+    text.push_str(&format!(" ({} bytes)", bytes.len()));
+    text
+}
+
+/// Reads the whole value of a binary cell, which the result grid only shows
+/// the start of. `locator` is the row's `ctid`, as for
+/// [`update_table_cell`](super::update_table_cell). A NULL cell reads as no
+/// bytes.
+pub async fn load_bytea_cell(
+    connection: DatabaseConnection,
+    source: TablePreviewSource,
+    locator: String,
+    column_name: String,
+) -> Result<Vec<u8>, DatabaseError> {
+    let DatabaseConnection::Postgres(pool) = connection else {
+        return Err(DatabaseError::UnsupportedDriver(
+            "Binary cells can only be read from PostgreSQL".to_string(),
+        ));
+    };
+
+    let sql = format!(
+        "select {} from {} where ctid = {}::tid",
+        quote_identifier(&column_name),
+        source.qualified_name,
+        sql_literal(&locator)
+    );
+    let row = sqlx::query(&sql)
+        .fetch_one(&pool)
+        .await
+        .map_err(DatabaseError::Postgres)?;
+    let bytes = row
+        .try_get::<Option<Vec<u8>>, _>(0)
+        .map_err(DatabaseError::Postgres)?;
+    Ok(bytes.unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_bytea, is_postgres_bytea_type};
+
+    #[test]
+    fn detects_bytea_type() {
+        assert!(is_postgres_bytea_type("bytea"));
+        assert!(is_postgres_bytea_type("pg_catalog.BYTEA"));
+        assert!(!is_postgres_bytea_type("bytea[]"));
+        assert!(!is_postgres_bytea_type("text"));
+    }
+
+    #[test]
+    fn formats_bytes_as_truncated_hex() {
+        assert_eq!(format_bytea(&[]), "0x (0 bytes)");
+        assert_eq!(format_bytea(&[0x0a, 0x1b, 0x2c]), "0x0A1B2C (3 bytes)");
+
+        let long = format_bytea(&[0xff; 40]);
+        assert_eq!(long, format!("0x{}… (40 bytes)", "FF".repeat(32)));
+    }
+}
@@ -1,11 +1,16 @@
 use database::DatabaseDriver;
 use driver_clickhouse::ClickHouseDriver;
-use models::{DatabaseConnection, DatabaseError, TablePreviewSource};
+use models::{
+    DatabaseConnection, DatabaseError, DatabaseKind, IndexDefinition, TablePreviewSource,
+};
+use sqlx::Executor;
 
+use super::monitor::postgres_only;
 use super::{
     load_clickhouse_create_statement, load_sqlite_create_statement,
     qualified_clickhouse_table_name, qualified_mysql_table_name, qualified_postgres_table_name,
-    qualified_sqlite_table_name, rewrite_create_table_statement,
+    qualified_sqlite_table_name, quote_identifier, quote_identifier_clickhouse,
+    rewrite_create_table_statement,
 };
 
 pub async fn create_table(
@@ -285,3 +290,346 @@ pub async fn duplicate_table(
         }
     }
 }
+
+/// Moves a PostgreSQL sequence with `setval`, so the next `nextval` returns
+/// `value + increment`. Returns the value the server stored.
+pub async fn set_sequence_value(
+    connection: DatabaseConnection,
+    schema: String,
+    sequence: String,
+    value: i64,
+) -> Result<i64, DatabaseError> {
+    let DatabaseConnection::Postgres(pool) = connection else {
+        return Err(DatabaseError::UnsupportedDriver(
+            "Sequences are only available for PostgreSQL.".to_string(),
+        ));
+    };
+
+    sqlx::query_scalar::<_, i64>("select setval(format('%I.%I', $1::text, $2::text)::regclass, $3)")
+        .bind(schema)
+        .bind(sequence)
+        .bind(value)
+        .fetch_one(&pool)
+        .await
+        .map_err(DatabaseError::Postgres)
+}
+
+/// Runs `refresh materialized view` on a PostgreSQL materialized view.
+/// `concurrently` keeps the view readable during the refresh, which
+/// PostgreSQL only allows for populated views with a unique index.
+pub async fn refresh_materialized_view(
+    connection: DatabaseConnection,
+    source: TablePreviewSource,
+    concurrently: bool,
+) -> Result<(), DatabaseError> {
+    let DatabaseConnection::Postgres(pool) = connection else {
+        return Err(DatabaseError::UnsupportedDriver(
+            "Materialized views are only available for PostgreSQL.".to_string(),
+        ));
+    };
+
+    let qualified_name = source.qualified_name.trim().trim_end_matches(';');
+    let sql = if concurrently {
+        format!("refresh materialized view concurrently {qualified_name}")
+    } else {
+        format!("refresh materialized view {qualified_name}")
+    };
+    sqlx::query(&sql)
+        .execute(&pool)
+        .await
+        .map_err(DatabaseError::Postgres)?;
+    Ok(())
+}
+
+/// Turns row-level security on or off for a PostgreSQL table. Policies stay
+/// defined either way; they are only enforced while it is on.
+pub async fn set_row_level_security(
+    connection: DatabaseConnection,
+    source: TablePreviewSource,
+    enabled: bool,
+) -> Result<(), DatabaseError> {
+    let DatabaseConnection::Postgres(pool) = connection else {
+        return Err(DatabaseError::UnsupportedDriver(
+            "Row-level security is only available for PostgreSQL.".to_string(),
+        ));
+    };
+
+    sqlx::query(&row_level_security_statement(&source, enabled))
+        .execute(&pool)
+        .await
+        .map_err(DatabaseError::Postgres)?;
+    Ok(())
+}
+
+fn row_level_security_statement(source: &TablePreviewSource, enabled: bool) -> String {
+    let qualified_name = source.qualified_name.trim().trim_end_matches(';');
+    let action = if enabled { "enable" } else { "disable" };
+    format!("alter table {qualified_name} {action} row level security")
+}
+
+/// Creates an index on a PostgreSQL table. A concurrent build cannot run in a
+/// transaction block, so the statement goes through the simple query protocol.
+pub async fn create_index(
+    connection: DatabaseConnection,
+    source: TablePreviewSource,
+    definition: IndexDefinition,
+) -> Result<(), DatabaseError> {
+    let DatabaseConnection::Postgres(pool) = connection else {
+        return Err(postgres_only("Index management"));
+    };
+
+    let sql = create_index_statement(&source, &definition);
+    (&pool)
+        .execute(sql.as_str())
+        .await
+        .map_err(DatabaseError::Postgres)?;
+    Ok(())
+}
+
+/// Drops an index of a PostgreSQL table, optionally without blocking writes.
+pub async fn drop_index(
+    connection: DatabaseConnection,
+    schema: Option<String>,
+    name: String,
+    concurrently: bool,
+) -> Result<(), DatabaseError> {
+    let DatabaseConnection::Postgres(pool) = connection else {
+        return Err(postgres_only("Index management"));
+    };
+
+    let sql = drop_index_statement(schema.as_deref(), &name, concurrently);
+    (&pool)
+        .execute(sql.as_str())
+        .await
+        .map_err(DatabaseError::Postgres)?;
+    Ok(())
+}
+
+/// `CREATE [UNIQUE] INDEX [CONCURRENTLY] [name] ON table USING method (columns)
+/// [WHERE predicate]`. Uniqueness is dropped for methods that cannot enforce it.
+pub fn create_index_statement(source: &TablePreviewSource, definition: &IndexDefinition) -> String {
+    let qualified_name = source.qualified_name.trim().trim_end_matches(';');
+    let mut sql = String::from("create ");
+    if definition.unique && definition.method.supports_unique() {
+        sql.push_str("unique ");
+    }
+    sql.push_str("index ");
+    if definition.concurrently {
+        sql.push_str("concurrently ");
+    }
+    let name = definition.name.trim();
+    if !name.is_empty() {
+        sql.push_str(&quote_identifier(name));
+        sql.push(' ');
+    }
+    let columns = definition
+        .columns
+        .iter()
+        .map(|column| quote_identifier(column))
+        .collect::<Vec<_>>()
+        .join(", ");
+    sql.push_str(&format!(
+        "on {qualified_name} using {} ({columns})",
+        definition.method.label().to_lowercase()
+    ));
+    let predicate = definition.predicate.trim().trim_end_matches(';').trim();
+    if !predicate.is_empty() {
+        sql.push_str(&format!(" where {predicate}"));
+    }
+    sql
+}
+
+fn drop_index_statement(schema: Option<&str>, name: &str, concurrently: bool) -> String {
+    let name = match schema {
+        Some(schema) => format!("{}.{}", quote_identifier(schema), quote_identifier(name)),
+        None => quote_identifier(name),
+    };
+    if concurrently {
+        format!("drop index concurrently {name}")
+    } else {
+        format!("drop index {name}")
+    }
+}
+
+/// Sets the comment on a table, or on one of its columns when `column` is
+/// given. An empty comment removes it.
+pub async fn set_table_comment(
+    connection: DatabaseConnection,
+    source: TablePreviewSource,
+    column: Option<String>,
+    comment: String,
+) -> Result<(), DatabaseError> {
+    let sql = comment_statement(connection.kind(), &source, column.as_deref(), &comment)?;
+
+    match connection {
+        DatabaseConnection::Postgres(pool) => {
+            sqlx::query(&sql)
+                .execute(&pool)
+                .await
+                .map_err(DatabaseError::Postgres)?;
+            Ok(())
+        }
+        DatabaseConnection::MySql(pool) => {
+            sqlx::query(&sql)
+                .execute(&pool)
+                .await
+                .map_err(DatabaseError::MySql)?;
+            Ok(())
+        }
+        DatabaseConnection::ClickHouse(config) => {
+            ClickHouseDriver.execute_text_query(&config, &sql).await?;
+            Ok(())
+        }
+        DatabaseConnection::Sqlite(_) => Err(DatabaseError::UnsupportedDriver(
+            "SQLite does not store comments.".to_string(),
+        )),
+    }
+}
+
+fn comment_statement(
+    kind: DatabaseKind,
+    source: &TablePreviewSource,
+    column: Option<&str>,
+    comment: &str,
+) -> Result<String, DatabaseError> {
+    let qualified_name = source.qualified_name.trim().trim_end_matches(';');
+    let comment = comment.trim();
+    match (kind, column) {
+        (DatabaseKind::Postgres, None) => Ok(format!(
+            "comment on table {qualified_name} is {}",
+            postgres_comment_literal(comment)
+        )),
+        (DatabaseKind::Postgres, Some(column)) => Ok(format!(
+            "comment on column {qualified_name}.{} is {}",
+            quote_identifier(column),
+            postgres_comment_literal(comment)
+        )),
+        (DatabaseKind::MySql, None) => Ok(format!(
+            "alter table {qualified_name} comment = {}",
+            backslash_escaped_literal(comment)
+        )),
+        (DatabaseKind::ClickHouse, None) => Ok(format!(
+            "alter table {qualified_name} modify comment {}",
+            backslash_escaped_literal(comment)
+        )),
+        (DatabaseKind::ClickHouse, Some(column)) => Ok(format!(
+            "alter table {qualified_name} comment column {} {}",
+            quote_identifier_clickhouse(column),
+            backslash_escaped_literal(comment)
+        )),
+        (DatabaseKind::MySql, Some(_)) => Err(DatabaseError::UnsupportedDriver(
+            "MySQL column comments are part of the column definition; change them with ALTER TABLE ... MODIFY COLUMN.".to_string(),
+        )),
+        (DatabaseKind::Sqlite, _) => Err(DatabaseError::UnsupportedDriver(
+            "SQLite does not store comments.".to_string(),
+        )),
+    }
+}
+
+/// `null` drops a PostgreSQL comment; standard strings keep backslashes as is.
+fn postgres_comment_literal(comment: &str) -> String {
+    if comment.is_empty() {
+        "null".to_string()
+    } else {
+        format!("'{}'", comment.replace('\'', "''"))
+    }
+}
+
+/// MySQL and ClickHouse read backslash escapes inside string literals.
+fn backslash_escaped_literal(value: &str) -> String {
+    format!("'{}'", value.replace('\\', "\\\\").replace('\'', "''"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        comment_statement, create_index_statement, drop_index_statement,
+        row_level_security_statement,
+    };
+    use models::{DatabaseKind, IndexDefinition, IndexMethod, TablePreviewSource};
+
+    fn source() -> TablePreviewSource {
+        TablePreviewSource {
+            schema: Some("public".to_string()),
+            table_name: "users".to_string(),
+            qualified_name: "\"public\".\"users\"".to_string(),
+        }
+    }
+
+    #[test]
+    fn postgres_comments_quote_the_column_and_text() {
+        assert_eq!(
+            comment_statement(DatabaseKind::Postgres, &source(), None, "Bob's table").unwrap(),
+            "comment on table \"public\".\"users\" is 'Bob''s table'"
+        );
+        assert_eq!(
+            comment_statement(DatabaseKind::Postgres, &source(), Some("e\"mail"), "  ").unwrap(),
+            "comment on column \"public\".\"users\".\"e\"\"mail\" is null"
+        );
+    }
+
+    #[test]
+    fn mysql_and_clickhouse_escape_backslashes() {
+        assert_eq!(
+            comment_statement(DatabaseKind::MySql, &source(), None, "C:\\tmp").unwrap(),
+            "alter table \"public\".\"users\" comment = 'C:\\\\tmp'"
+        );
+        assert_eq!(
+            comment_statement(DatabaseKind::ClickHouse, &source(), Some("id"), "it's").unwrap(),
+            "alter table \"public\".\"users\" comment column `id` 'it''s'"
+        );
+        assert!(comment_statement(DatabaseKind::MySql, &source(), Some("id"), "x").is_err());
+        assert!(comment_statement(DatabaseKind::Sqlite, &source(), None, "x").is_err());
+    }
+
+    #[test]
+    fn row_level_security_statement_toggles_the_table() {
+        assert_eq!(
+            row_level_security_statement(&source(), true),
+            "alter table \"public\".\"users\" enable row level security"
+        );
+        assert_eq!(
+            row_level_security_statement(&source(), false),
+            "alter table \"public\".\"users\" disable row level security"
+        );
+    }
+
+    #[test]
+    fn create_index_statement_builds_every_clause() {
+        let definition = IndexDefinition {
+            name: "users_email_idx".to_string(),
+            columns: vec!["email".to_string(), "Org Id".to_string()],
+            method: IndexMethod::BTree,
+            unique: true,
+            concurrently: true,
+            predicate: " deleted_at is null; ".to_string(),
+        };
+        assert_eq!(
+            create_index_statement(&source(), &definition),
+            "create unique index concurrently \"users_email_idx\" on \"public\".\"users\" using btree (\"email\", \"Org Id\") where deleted_at is null"
+        );
+
+        let definition = IndexDefinition {
+            columns: vec!["tags".to_string()],
+            method: IndexMethod::Gin,
+            unique: true,
+            ..IndexDefinition::default()
+        };
+        assert_eq!(
+            create_index_statement(&source(), &definition),
+            "create index on \"public\".\"users\" using gin (\"tags\")"
+        );
+    }
+
+    #[test]
+    fn drop_index_statement_qualifies_the_index() {
+        assert_eq!(
+            drop_index_statement(Some("public"), "users_email_idx", false),
+            "drop index \"public\".\"users_email_idx\""
+        );
+        assert_eq!(
+            drop_index_statement(None, "idx", true),
+            "drop index concurrently \"idx\""
+        );
+    }
+}
@@ -0,0 +1,134 @@
+/// Whether a declared column type is the PostgreSQL `hstore` extension type,
+/// possibly schema-qualified as `public.hstore`.
+pub fn is_postgres_hstore_type(data_type: &str) -> bool {
+    data_type
+        .trim()
+        .rsplit('.')
+        .next()
+        .is_some_and(|name| name.trim_matches('"').eq_ignore_ascii_case("hstore"))
+}
+
+/// Renders key/value pairs as an hstore literal such as
+/// `"a"=>"1", "b"=>NULL`, the way PostgreSQL prints them.
+pub fn format_postgres_hstore(pairs: &[(String, Option<String>)]) -> String {
+    pairs
+        .iter()
+        .map(|(key, value)| {
+            let value = value
+                .as_deref()
+                .map_or_else(|| "NULL".to_string(), quote_hstore_text);
+            format!("{}=>{value}", quote_hstore_text(key))
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Splits an hstore literal into its pairs, in order, with an unquoted `NULL`
+/// value as `None`. Returns `None` when the text is not an hstore literal.
+pub fn parse_postgres_hstore(text: &str) -> Option<Vec<(String, Option<String>)>> {
+    let mut pairs = Vec::new();
+    let mut chars = text.chars().peekable();
+    loop {
+        while chars.next_if(|ch| ch.is_whitespace()).is_some() {}
+        if chars.peek().is_none() {
+            return Some(pairs);
+        }
+
+        let (key, _) = hstore_token(&mut chars)?;
+        while chars.next_if(|ch| ch.is_whitespace()).is_some() {}
+        if chars.next() != Some('=') || chars.next() != Some('>') {
+            return None;
+        }
+        while chars.next_if(|ch| ch.is_whitespace()).is_some() {}
+        let (value, quoted) = hstore_token(&mut chars)?;
+        let value = (quoted || !value.eq_ignore_ascii_case("null")).then_some(value);
+        pairs.push((key, value));
+
+        while chars.next_if(|ch| ch.is_whitespace()).is_some() {}
+        match chars.next() {
+            Some(',') => continue,
+            None => return Some(pairs),
+            Some(_) => return None,
+        }
+    }
+}
+
+/// Reads one key or value, returning its text and whether it was quoted.
+fn hstore_token(chars: &mut std::iter::Peekable<std::str::Chars<'_>>) -> Option<(String, bool)> {
+    let mut value = String::new();
+    if chars.next_if_eq(&'"').is_some() {
+        loop {
+            match chars.next()? {
+                '\\' => value.push(chars.next()?),
+                '"' => return Some((value, true)),
+                ch => value.push(ch),
+            }
+        }
+    }
+
+    while let Some(ch) =
+        chars.next_if(|ch| !ch.is_whitespace() && !matches!(ch, ',' | '=' | '>' | '"'))
+    {
+        if ch == '\\' {
+            value.push(chars.next()?);
+        } else {
+            value.push(ch);
+        }
+    }
+    (!value.is_empty()).then_some((value, false))
+}
+
+fn quote_hstore_text(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_postgres_hstore, is_postgres_hstore_type, parse_postgres_hstore};
+
+    fn pairs(values: &[(&str, Option<&str>)]) -> Vec<(String, Option<String>)> {
+        values
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.map(ToString::to_string)))
+            .collect()
+    }
+
+    #[test]
+    fn detects_hstore_type() {
+        assert!(is_postgres_hstore_type("hstore"));
+        assert!(is_postgres_hstore_type("public.hstore"));
+        assert!(!is_postgres_hstore_type("text"));
+    }
+
+    #[test]
+    fn formats_pairs_like_postgres() {
+        let values = pairs(&[("a", Some("1")), ("b c", None), ("q\"", Some("x\\y"))]);
+
+        assert_eq!(
+            format_postgres_hstore(&values),
+            r#""a"=>"1", "b c"=>NULL, "q\""=>"x\\y""#
+        );
+        assert_eq!(format_postgres_hstore(&[]), "");
+    }
+
+    #[test]
+    fn parses_what_it_formats() {
+        let values = pairs(&[("k", Some("NULL")), ("n", None), ("a,b", Some("=>"))]);
+
+        assert_eq!(
+            parse_postgres_hstore(&format_postgres_hstore(&values)),
+            Some(values)
+        );
+    }
+
+    #[test]
+    fn parses_unquoted_tokens_and_rejects_malformed_text() {
+        assert_eq!(
+            parse_postgres_hstore("a=>1 , b => NULL"),
+            Some(pairs(&[("a", Some("1")), ("b", None)]))
+        );
+        assert_eq!(parse_postgres_hstore("  "), Some(Vec::new()));
+        assert_eq!(parse_postgres_hstore(r#""a"=>"#), None);
+        assert_eq!(parse_postgres_hstore(r#""a" "b""#), None);
+    }
+}
@@ -0,0 +1,265 @@
+use std::time::Duration;
+
+use models::{
+    DatabaseConnection, DatabaseError, MaintenanceEvent, MaintenanceKind, TableMaintenanceStats,
+    TablePreviewSource, TableStats,
+};
+use sqlx::{Executor, Row};
+
+use super::monitor::postgres_only;
+
+const PROGRESS_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Reads when a table was last vacuumed and analyzed, manually or by
+/// autovacuum, and how many dead rows it carries.
+pub async fn load_table_maintenance_stats(
+    connection: DatabaseConnection,
+    source: TablePreviewSource,
+) -> Result<TableMaintenanceStats, DatabaseError> {
+    let DatabaseConnection::Postgres(pool) = connection else {
+        return Err(postgres_only("Table maintenance"));
+    };
+
+    let row = sqlx::query(
+        r#"
+        select
+            to_char(last_vacuum, 'YYYY-MM-DD HH24:MI:SS') as last_vacuum,
+            to_char(last_autovacuum, 'YYYY-MM-DD HH24:MI:SS') as last_autovacuum,
+            to_char(last_analyze, 'YYYY-MM-DD HH24:MI:SS') as last_analyze,
+            to_char(last_autoanalyze, 'YYYY-MM-DD HH24:MI:SS') as last_autoanalyze,
+            n_live_tup,
+            n_dead_tup
+        from pg_stat_user_tables
+        where relid = $1::regclass
+        "#,
+    )
+    .bind(source.qualified_name.trim().trim_end_matches(';'))
+    .fetch_optional(&pool)
+    .await
+    .map_err(DatabaseError::Postgres)?;
+    let Some(row) = row else {
+        return Ok(TableMaintenanceStats::default());
+    };
+
+    Ok(TableMaintenanceStats {
+        last_vacuum: row
+            .try_get("last_vacuum")
+            .map_err(DatabaseError::Postgres)?,
+        last_autovacuum: row
+            .try_get("last_autovacuum")
+            .map_err(DatabaseError::Postgres)?,
+        last_analyze: row
+            .try_get("last_analyze")
+            .map_err(DatabaseError::Postgres)?,
+        last_autoanalyze: row
+            .try_get("last_autoanalyze")
+            .map_err(DatabaseError::Postgres)?,
+        live_tuples: row.try_get("n_live_tup").map_err(DatabaseError::Postgres)?,
+        dead_tuples: row.try_get("n_dead_tup").map_err(DatabaseError::Postgres)?,
+    })
+}
+
+/// Reads the row, scan and heap I/O counters of a table. A table the
+/// statistics collector has not seen yet reports zeros.
+pub async fn load_table_stats(
+    connection: DatabaseConnection,
+    source: TablePreviewSource,
+) -> Result<TableStats, DatabaseError> {
+    let DatabaseConnection::Postgres(pool) = connection else {
+        return Err(postgres_only("Table maintenance"));
+    };
+
+    let row = sqlx::query(
+        r#"
+        select
+            stat.n_live_tup,
+            stat.n_dead_tup,
+            to_char(stat.last_vacuum, 'YYYY-MM-DD HH24:MI:SS') as last_vacuum,
+            to_char(stat.last_autovacuum, 'YYYY-MM-DD HH24:MI:SS') as last_autovacuum,
+            to_char(stat.last_analyze, 'YYYY-MM-DD HH24:MI:SS') as last_analyze,
+            to_char(stat.last_autoanalyze, 'YYYY-MM-DD HH24:MI:SS') as last_autoanalyze,
+            stat.idx_scan,
+            coalesce(stat.seq_scan, 0) as seq_scan,
+            coalesce(io.heap_blks_read, 0) as heap_blks_read,
+            coalesce(io.heap_blks_hit, 0) as heap_blks_hit
+        from pg_stat_user_tables stat
+        join pg_statio_user_tables io on io.relid = stat.relid
+        where stat.relid = $1::regclass
+        "#,
+    )
+    .bind(source.qualified_name.trim().trim_end_matches(';'))
+    .fetch_optional(&pool)
+    .await
+    .map_err(DatabaseError::Postgres)?;
+    let Some(row) = row else {
+        return Ok(TableStats::default());
+    };
+
+    Ok(TableStats {
+        live_tuples: row.try_get("n_live_tup").map_err(DatabaseError::Postgres)?,
+        dead_tuples: row.try_get("n_dead_tup").map_err(DatabaseError::Postgres)?,
+        last_vacuum: row
+            .try_get("last_vacuum")
+            .map_err(DatabaseError::Postgres)?,
+        last_autovacuum: row
+            .try_get("last_autovacuum")
+            .map_err(DatabaseError::Postgres)?,
+        last_analyze: row
+            .try_get("last_analyze")
+            .map_err(DatabaseError::Postgres)?,
+        last_autoanalyze: row
+            .try_get("last_autoanalyze")
+            .map_err(DatabaseError::Postgres)?,
+        index_scans: row.try_get("idx_scan").map_err(DatabaseError::Postgres)?,
+        seq_scans: row.try_get("seq_scan").map_err(DatabaseError::Postgres)?,
+        heap_blocks_read: row
+            .try_get("heap_blks_read")
+            .map_err(DatabaseError::Postgres)?,
+        heap_blocks_hit: row
+            .try_get("heap_blks_hit")
+            .map_err(DatabaseError::Postgres)?,
+    })
+}
+
+/// Runs a maintenance command on its own connection and reports its backend
+/// pid, so `cancel_backend` can stop it on the server, then each new progress
+/// line until it finishes.
+pub async fn run_table_maintenance(
+    connection: DatabaseConnection,
+    source: TablePreviewSource,
+    kind: MaintenanceKind,
+    mut on_event: impl FnMut(MaintenanceEvent),
+) -> Result<(), DatabaseError> {
+    let DatabaseConnection::Postgres(pool) = connection else {
+        return Err(postgres_only("Table maintenance"));
+    };
+
+    let mut worker = pool.acquire().await.map_err(DatabaseError::Postgres)?;
+    let pid = sqlx::query_scalar::<_, i32>("select pg_backend_pid()")
+        .fetch_one(&mut *worker)
+        .await
+        .map_err(DatabaseError::Postgres)?;
+    on_event(MaintenanceEvent::Started { pid });
+
+    // VACUUM refuses to run inside a transaction block, so send it through the
+    // simple query protocol rather than as a prepared statement.
+    let sql = maintenance_statement(kind, &source.qualified_name);
+    let statement = (&mut *worker).execute(sql.as_str());
+    tokio::pin!(statement);
+
+    let progress_sql = progress_query(kind);
+    let mut last_progress = String::new();
+    let mut ticker = tokio::time::interval(PROGRESS_POLL_INTERVAL);
+    loop {
+        tokio::select! {
+            result = &mut statement => {
+                result.map_err(DatabaseError::Postgres)?;
+                return Ok(());
+            }
+            _ = ticker.tick() => {
+                // Progress views are missing on old servers; the command still
+                // runs, it just reports nothing until it ends.
+                let Ok(Some(row)) = sqlx::query(progress_sql)
+                    .bind(pid)
+                    .fetch_optional(&pool)
+                    .await
+                else {
+                    continue;
+                };
+                let phase: String = row.try_get("phase").unwrap_or_default();
+                let done: i64 = row.try_get("done").unwrap_or_default();
+                let total: i64 = row.try_get("total").unwrap_or_default();
+                let line = progress_line(&phase, done, total);
+                if line != last_progress {
+                    last_progress = line.clone();
+                    on_event(MaintenanceEvent::Progress(line));
+                }
+            }
+        }
+    }
+}
+
+fn maintenance_statement(kind: MaintenanceKind, qualified_name: &str) -> String {
+    let qualified_name = qualified_name.trim().trim_end_matches(';');
+    match kind {
+        MaintenanceKind::Vacuum => format!("vacuum (verbose) {qualified_name}"),
+        MaintenanceKind::VacuumAnalyze => format!("vacuum (verbose, analyze) {qualified_name}"),
+        MaintenanceKind::VacuumFull => format!("vacuum full {qualified_name}"),
+        MaintenanceKind::Analyze => format!("analyze {qualified_name}"),
+        MaintenanceKind::Reindex => format!("reindex table {qualified_name}"),
+    }
+}
+
+/// Phase and block counts of the backend `$1` from the progress view that
+/// matches the command. `VACUUM FULL` rewrites the table and reports as a
+/// `CLUSTER`.
+fn progress_query(kind: MaintenanceKind) -> &'static str {
+    match kind {
+        MaintenanceKind::Vacuum | MaintenanceKind::VacuumAnalyze => {
+            "select phase, heap_blks_scanned as done, heap_blks_total as total \
+             from pg_stat_progress_vacuum where pid = $1"
+        }
+        MaintenanceKind::VacuumFull => {
+            "select phase, heap_blks_scanned as done, heap_blks_total as total \
+             from pg_stat_progress_cluster where pid = $1"
+        }
+        MaintenanceKind::Analyze => {
+            "select phase, sample_blks_scanned as done, sample_blks_total as total \
+             from pg_stat_progress_analyze where pid = $1"
+        }
+        MaintenanceKind::Reindex => {
+            "select phase, blocks_done as done, blocks_total as total \
+             from pg_stat_progress_create_index where pid = $1"
+        }
+    }
+}
+
+fn progress_line(phase: &str, done: i64, total: i64) -> String {
+    if total > 0 {
+        let percent = done as f64 / total as f64 * 100.0;
+        format!("{phase}: {done} of {total} blocks ({percent:.0}%)")
+    } else {
+        phase.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{maintenance_statement, progress_line};
+    use models::MaintenanceKind;
+
+    #[test]
+    fn maintenance_statement_targets_the_quoted_table() {
+        let table = "\"public\".\"Orders\";";
+
+        assert_eq!(
+            maintenance_statement(MaintenanceKind::Vacuum, table),
+            "vacuum (verbose) \"public\".\"Orders\""
+        );
+        assert_eq!(
+            maintenance_statement(MaintenanceKind::VacuumAnalyze, table),
+            "vacuum (verbose, analyze) \"public\".\"Orders\""
+        );
+        assert_eq!(
+            maintenance_statement(MaintenanceKind::VacuumFull, table),
+            "vacuum full \"public\".\"Orders\""
+        );
+        assert_eq!(
+            maintenance_statement(MaintenanceKind::Analyze, table),
+            "analyze \"public\".\"Orders\""
+        );
+        assert_eq!(
+            maintenance_statement(MaintenanceKind::Reindex, table),
+            "reindex table \"public\".\"Orders\""
+        );
+    }
+
+    #[test]
+    fn progress_line_adds_block_counts_when_known() {
+        assert_eq!(
+            progress_line("scanning heap", 250, 1000),
+            "scanning heap: 250 of 1000 blocks (25%)"
+        );
+        assert_eq!(progress_line("initializing", 0, 0), "initializing");
+    }
+}
@@ -1,10 +1,21 @@
+mod arrays;
 mod build;
+mod bytea;
 mod ddl;
 mod editable;
 mod execution_plan;
+mod hstore;
+mod maintenance;
+mod monitor;
 mod mutations;
+mod notify;
+mod params;
 mod preview;
+mod row_count;
 mod rows;
+mod server_settings;
+mod stats;
+mod transaction;
 
 use database::DatabaseDriver;
 use driver_clickhouse::ClickHouseDriver;
@@ -13,13 +24,35 @@ use models::{
 };
 use sqlx::Row;
 
-pub use ddl::{create_table, drop_table, duplicate_table, truncate_table};
+pub use arrays::{format_postgres_array, is_postgres_array_type, parse_postgres_array};
+pub use bytea::{format_bytea, is_postgres_bytea_type, load_bytea_cell};
+pub use ddl::{
+    create_index, create_index_statement, create_table, drop_index, drop_table, duplicate_table,
+    refresh_materialized_view, set_row_level_security, set_sequence_value, set_table_comment,
+    truncate_table,
+};
 pub use execution_plan::execute_explain;
+pub use hstore::{format_postgres_hstore, is_postgres_hstore_type, parse_postgres_hstore};
+pub use maintenance::{load_table_maintenance_stats, load_table_stats, run_table_maintenance};
+pub use monitor::{
+    cancel_backend, load_schema_size_report, load_server_activity, load_server_info,
+    load_server_locks, load_statement_stats, ping_connection, reset_statement_stats,
+    terminate_backend,
+};
 pub use mutations::{
-    delete_table_row, insert_table_row, insert_table_row_with_values, next_table_primary_key_id,
-    update_table_cell,
+    delete_table_row, delete_table_rows, insert_table_row, insert_table_row_returning,
+    insert_table_row_with_values, next_table_primary_key_id, update_table_cell,
+};
+pub use notify::{listen_for_notifications, send_notification};
+pub use params::{
+    QueryParameterValue, describe_query_parameters, execute_query_with_parameters,
+    find_query_parameters,
 };
 pub use preview::load_table_preview_page;
+pub use row_count::load_table_row_count;
+pub use server_settings::{load_server_settings, set_server_setting};
+pub use stats::load_column_stats;
+pub use transaction::{SqlTransaction, begin_transaction};
 
 use self::{
     build::{
@@ -29,6 +62,7 @@ use self::{
         sqlite_filter_expression,
     },
     editable::editable_select_plan,
+    params::{dollar_quoted_end, is_identifier_byte},
     rows::{
         clickhouse_rows_to_page, clickhouse_rows_to_paginated_page, invalid_sqlite_locator,
         mysql_preview_rows_to_paginated_page, mysql_rows_to_page, mysql_rows_to_paginated_page,
@@ -92,10 +126,10 @@ pub async fn execute_query_page(
             execute_sqlite_query_page(&sql, &pool, page_size, offset, filter, sort).await
         }
         DatabaseConnection::Postgres(pool) => {
-            execute_postgres_query_page(&sql, &pool, page_size, offset, filter, sort).await
+            execute_postgres_query_page(&sql, &[], &pool, page_size, offset, filter, sort).await
         }
         DatabaseConnection::MySql(pool) => {
-            execute_mysql_query_page(&sql, &pool, page_size, offset, filter, sort).await
+            execute_mysql_query_page(&sql, &pool, &pool, page_size, offset, filter, sort).await
         }
         DatabaseConnection::ClickHouse(config) => {
             execute_clickhouse_query_page(&sql, &config, page_size, offset, filter, sort).await
@@ -103,14 +137,120 @@ pub async fn execute_query_page(
     }
 }
 
-async fn execute_sqlite_query_page(
-    sql: &str,
-    pool: &sqlx::SqlitePool,
+/// [`execute_query_page`] with PostgreSQL's `statement_timeout` set to
+/// `timeout_secs` for the run, so the server cancels a runaway query. Other
+/// drivers, and the scripts [`statement_timeout_applies`] rules out, run the
+/// query without a timeout.
+pub async fn execute_query_page_with_timeout(
+    connection: DatabaseConnection,
+    sql: String,
     page_size: u32,
     offset: u64,
     filter: Option<QueryFilter>,
     sort: Option<QuerySort>,
+    timeout_secs: Option<u32>,
 ) -> Result<QueryOutput, DatabaseError> {
+    let timeout_secs = timeout_secs.filter(|_| statement_timeout_applies(&connection, &sql));
+    let (DatabaseConnection::Postgres(pool), Some(timeout_secs)) = (&connection, timeout_secs)
+    else {
+        return execute_query_page(connection, sql, page_size, offset, filter, sort).await;
+    };
+
+    let mut timed = TimedConnection::acquire(pool, Some(timeout_secs)).await?;
+    let output = execute_postgres_query_page(
+        &sql,
+        &[],
+        &mut *timed.connection,
+        page_size,
+        offset,
+        filter,
+        sort,
+    )
+    .await;
+    timed.release().await;
+    output
+}
+
+/// Whether [`execute_query_page_with_timeout`] bounds `sql` with the query
+/// timeout. The timeout is reset once the run ends, which a script that
+/// opens or ends a transaction could roll back or leave pending, so those
+/// run without it.
+pub fn statement_timeout_applies(connection: &DatabaseConnection, sql: &str) -> bool {
+    matches!(connection, DatabaseConnection::Postgres(_)) && !controls_transactions(sql)
+}
+
+fn controls_transactions(sql: &str) -> bool {
+    statement_leading_keywords(sql).iter().any(|keyword| {
+        matches!(
+            keyword.as_str(),
+            "begin" | "start" | "commit" | "end" | "rollback" | "abort" | "savepoint" | "release"
+        )
+    })
+}
+
+fn statement_timeout_sql(timeout_secs: u32) -> String {
+    format!("set statement_timeout = '{timeout_secs}s'")
+}
+
+/// A pooled PostgreSQL connection with `statement_timeout` set on its
+/// session. [`TimedConnection::release`] resets it; a connection dropped
+/// before then, as when a run is cancelled, is closed instead of going back
+/// to the pool with the timeout still set.
+struct TimedConnection {
+    connection: sqlx::pool::PoolConnection<sqlx::Postgres>,
+    armed: bool,
+}
+
+impl TimedConnection {
+    async fn acquire(
+        pool: &sqlx::PgPool,
+        timeout_secs: Option<u32>,
+    ) -> Result<Self, DatabaseError> {
+        let connection = pool.acquire().await.map_err(DatabaseError::Postgres)?;
+        let mut timed = Self {
+            connection,
+            armed: timeout_secs.is_some(),
+        };
+        if let Some(timeout_secs) = timeout_secs {
+            sqlx::raw_sql(&statement_timeout_sql(timeout_secs))
+                .execute(&mut *timed.connection)
+                .await
+                .map_err(DatabaseError::Postgres)?;
+        }
+        Ok(timed)
+    }
+
+    async fn release(mut self) {
+        if self.armed
+            && sqlx::raw_sql("reset statement_timeout")
+                .execute(&mut *self.connection)
+                .await
+                .is_ok()
+        {
+            self.armed = false;
+        }
+    }
+}
+
+impl Drop for TimedConnection {
+    fn drop(&mut self) {
+        if self.armed {
+            self.connection.close_on_drop();
+        }
+    }
+}
+
+async fn execute_sqlite_query_page<'c, E>(
+    sql: &str,
+    executor: E,
+    page_size: u32,
+    offset: u64,
+    filter: Option<QueryFilter>,
+    sort: Option<QuerySort>,
+) -> Result<QueryOutput, DatabaseError>
+where
+    E: sqlx::Executor<'c, Database = sqlx::Sqlite>,
+{
     let normalized = sql.trim().to_lowercase();
 
     if let Some(plan) = editable_select_plan(sql) {
@@ -124,7 +264,7 @@ async fn execute_sqlite_query_page(
             SQLITE_DIALECT,
         );
         let rows = sqlx::query(&query)
-            .fetch_all(pool)
+            .fetch_all(executor)
             .await
             .map_err(DatabaseError::Sqlite)?;
         return Ok(QueryOutput::Table(sqlite_preview_rows_to_paginated_page(
@@ -144,7 +284,7 @@ async fn execute_sqlite_query_page(
             sort.as_ref(),
             SQLITE_DIALECT,
         ))
-        .fetch_all(pool)
+        .fetch_all(executor)
         .await
         .map_err(DatabaseError::Sqlite)?;
         return Ok(QueryOutput::Table(sqlite_rows_to_paginated_page(
@@ -154,30 +294,39 @@ async fn execute_sqlite_query_page(
 
     if is_tabular_query(&normalized) {
         let rows = sqlx::query(sql)
-            .fetch_all(pool)
+            .fetch_all(executor)
             .await
             .map_err(DatabaseError::Sqlite)?;
         return Ok(QueryOutput::Table(sqlite_rows_to_page(rows)));
     }
 
     let result = sqlx::query(sql)
-        .execute(pool)
+        .execute(executor)
         .await
         .map_err(DatabaseError::Sqlite)?;
     Ok(QueryOutput::AffectedRows(result.rows_affected()))
 }
 
-async fn execute_postgres_query_page(
+/// Runs a query page on PostgreSQL. `binds` are the text values for the
+/// query's `$N` parameters, in order; a query with parameters is never
+/// rewritten into an editable one, which would drop them.
+async fn execute_postgres_query_page<'c, E>(
     sql: &str,
-    pool: &sqlx::PgPool,
+    binds: &[Option<String>],
+    executor: E,
     page_size: u32,
     offset: u64,
     filter: Option<QueryFilter>,
     sort: Option<QuerySort>,
-) -> Result<QueryOutput, DatabaseError> {
+) -> Result<QueryOutput, DatabaseError>
+where
+    E: sqlx::Executor<'c, Database = sqlx::Postgres>,
+{
     let normalized = sql.trim().to_lowercase();
 
-    if let Some(plan) = editable_select_plan(sql) {
+    if binds.is_empty()
+        && let Some(plan) = editable_select_plan(sql)
+    {
         let query = build_editable_paginated_query(
             &plan,
             page_size,
@@ -188,7 +337,7 @@ async fn execute_postgres_query_page(
             POSTGRES_DIALECT,
         );
         let rows = sqlx::query(&query)
-            .fetch_all(pool)
+            .fetch_all(executor)
             .await
             .map_err(DatabaseError::Postgres)?;
         return Ok(QueryOutput::Table(postgres_preview_rows_to_paginated_page(
@@ -200,45 +349,59 @@ async fn execute_postgres_query_page(
     }
 
     if is_paginated_query(&normalized) {
-        let rows = sqlx::query(&build_paginated_query(
+        let query = build_paginated_query(
             sql,
             page_size,
             offset,
             filter.as_ref(),
             sort.as_ref(),
             POSTGRES_DIALECT,
-        ))
-        .fetch_all(pool)
-        .await
-        .map_err(DatabaseError::Postgres)?;
+        );
+        let rows = postgres_query_with_binds(&query, binds)
+            .fetch_all(executor)
+            .await
+            .map_err(DatabaseError::Postgres)?;
         return Ok(QueryOutput::Table(postgres_rows_to_paginated_page(
             rows, page_size, offset,
         )));
     }
 
     if is_tabular_query(&normalized) {
-        let rows = sqlx::query(sql)
-            .fetch_all(pool)
+        let rows = postgres_query_with_binds(sql, binds)
+            .fetch_all(executor)
             .await
             .map_err(DatabaseError::Postgres)?;
         return Ok(QueryOutput::Table(postgres_rows_to_page(rows)));
     }
 
-    let result = sqlx::query(sql)
-        .execute(pool)
+    let result = postgres_query_with_binds(sql, binds)
+        .execute(executor)
         .await
         .map_err(DatabaseError::Postgres)?;
     Ok(QueryOutput::AffectedRows(result.rows_affected()))
 }
 
-async fn execute_mysql_query_page(
+fn postgres_query_with_binds<'q>(
+    sql: &'q str,
+    binds: &'q [Option<String>],
+) -> sqlx::query::Query<'q, sqlx::Postgres, sqlx::postgres::PgArguments> {
+    binds.iter().fold(sqlx::query(sql), |query, value| {
+        query.bind(value.as_deref())
+    })
+}
+
+async fn execute_mysql_query_page<'c, E>(
     sql: &str,
     pool: &sqlx::MySqlPool,
+    executor: E,
     page_size: u32,
     offset: u64,
     filter: Option<QueryFilter>,
     sort: Option<QuerySort>,
-) -> Result<QueryOutput, DatabaseError> {
+) -> Result<QueryOutput, DatabaseError>
+where
+    E: sqlx::Executor<'c, Database = sqlx::MySql>,
+{
     let normalized = sql.trim().to_lowercase();
 
     if let Some(plan) = editable_select_plan(sql) {
@@ -255,7 +418,7 @@ async fn execute_mysql_query_page(
                 sort.as_ref(),
                 MYSQL_DIALECT,
             ))
-            .fetch_all(pool)
+            .fetch_all(executor)
             .await
             .map_err(DatabaseError::MySql)?;
             return Ok(QueryOutput::Table(mysql_rows_to_paginated_page(
@@ -276,7 +439,7 @@ async fn execute_mysql_query_page(
             MYSQL_DIALECT,
         );
         let rows = sqlx::query(&query)
-            .fetch_all(pool)
+            .fetch_all(executor)
             .await
             .map_err(DatabaseError::MySql)?;
         return Ok(QueryOutput::Table(mysql_preview_rows_to_paginated_page(
@@ -296,7 +459,7 @@ async fn execute_mysql_query_page(
             sort.as_ref(),
             MYSQL_DIALECT,
         ))
-        .fetch_all(pool)
+        .fetch_all(executor)
         .await
         .map_err(DatabaseError::MySql)?;
         return Ok(QueryOutput::Table(mysql_rows_to_paginated_page(
@@ -306,14 +469,14 @@ async fn execute_mysql_query_page(
 
     if is_tabular_query(&normalized) {
         let rows = sqlx::query(sql)
-            .fetch_all(pool)
+            .fetch_all(executor)
             .await
             .map_err(DatabaseError::MySql)?;
         return Ok(QueryOutput::Table(mysql_rows_to_page(rows)));
     }
 
     let result = sqlx::query(sql)
-        .execute(pool)
+        .execute(executor)
         .await
         .map_err(DatabaseError::MySql)?;
     Ok(QueryOutput::AffectedRows(result.rows_affected()))
@@ -452,6 +615,9 @@ fn statement_leading_keywords(sql: &str) -> Vec<String> {
                 }
                 index = (index + 2).min(bytes.len());
             }
+            b'$' if index == 0 || !is_identifier_byte(bytes[index - 1]) => {
+                index = dollar_quoted_end(sql, index).unwrap_or(index + 1);
+            }
             b';' => {
                 if let Some(keyword) = leading_sql_keyword(&sql[start..index]) {
                     statements.push(keyword);
@@ -683,13 +849,16 @@ async fn postgres_single_primary_key_column(
 #[cfg(test)]
 #[allow(clippy::items_after_test_module)]
 mod tests {
+    use super::mutations::mysql_inserted_key_values;
     use super::{
-        create_table, drop_table, duplicate_table, execute_query_page, is_read_only_sql,
-        leading_sql_keyword, mysql_locator_expression, parse_clickhouse_primary_key_expression,
-        parse_mysql_locator, preview_source_for_sql, reorder_clickhouse_primary_key_columns,
+        controls_transactions, create_table, delete_table_rows, drop_table, duplicate_table,
+        execute_query_page, insert_table_row_returning, is_read_only_sql, leading_sql_keyword,
+        load_table_preview_page, mysql_locator_expression, parse_clickhouse_primary_key_expression,
+        parse_mysql_locator, preview_source_for_sql, refresh_materialized_view,
+        reorder_clickhouse_primary_key_columns, set_sequence_value, statement_timeout_sql,
         truncate_table,
     };
-    use models::{DatabaseConnection, QueryOutput, TablePreviewSource};
+    use models::{DatabaseConnection, DatabaseError, QueryOutput, TablePreviewSource};
     use sqlx::SqlitePool;
 
     #[test]
@@ -773,6 +942,171 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn set_sequence_value_requires_postgres() {
+        let pool = SqlitePool::connect(":memory:").await.unwrap();
+
+        let result = set_sequence_value(
+            DatabaseConnection::Sqlite(pool),
+            "main".to_string(),
+            "products_id_seq".to_string(),
+            42,
+        )
+        .await;
+
+        assert!(matches!(result, Err(DatabaseError::UnsupportedDriver(_))));
+    }
+
+    #[tokio::test]
+    async fn refresh_materialized_view_requires_postgres() {
+        let pool = SqlitePool::connect(":memory:").await.unwrap();
+
+        let result = refresh_materialized_view(
+            DatabaseConnection::Sqlite(pool),
+            TablePreviewSource {
+                schema: Some("main".to_string()),
+                table_name: "daily_totals".to_string(),
+                qualified_name: "\"main\".\"daily_totals\"".to_string(),
+            },
+            false,
+        )
+        .await;
+
+        assert!(matches!(result, Err(DatabaseError::UnsupportedDriver(_))));
+    }
+
+    async fn sqlite_items_pool() -> SqlitePool {
+        let pool = SqlitePool::connect(":memory:").await.unwrap();
+        sqlx::query("create table items (id integer primary key, name text)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("insert into items (id, name) values (1, 'a'), (2, 'b'), (3, 'c')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        pool
+    }
+
+    fn items_source() -> TablePreviewSource {
+        TablePreviewSource {
+            schema: None,
+            table_name: "items".to_string(),
+            qualified_name: r#""items""#.to_string(),
+        }
+    }
+
+    async fn remaining_item_ids(pool: &SqlitePool) -> Vec<i64> {
+        sqlx::query_scalar("select id from items order by id")
+            .fetch_all(pool)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn delete_table_rows_removes_every_selected_sqlite_row() {
+        let pool = sqlite_items_pool().await;
+
+        let deleted = delete_table_rows(
+            DatabaseConnection::Sqlite(pool.clone()),
+            items_source(),
+            vec!["1".to_string(), "3".to_string()],
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(deleted, 2);
+        assert_eq!(remaining_item_ids(&pool).await, vec![2]);
+    }
+
+    #[tokio::test]
+    async fn delete_table_rows_rolls_back_when_a_locator_is_stale() {
+        let pool = sqlite_items_pool().await;
+
+        let result = delete_table_rows(
+            DatabaseConnection::Sqlite(pool.clone()),
+            items_source(),
+            vec!["1".to_string(), "99".to_string()],
+        )
+        .await;
+
+        assert!(matches!(result, Err(DatabaseError::UnsupportedDriver(_))));
+        assert_eq!(remaining_item_ids(&pool).await, vec![1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn insert_table_row_returning_reads_back_the_generated_key() {
+        let pool = sqlite_items_pool().await;
+
+        let inserted = insert_table_row_returning(
+            DatabaseConnection::Sqlite(pool.clone()),
+            items_source(),
+            vec![("name".to_string(), "d".to_string())],
+        )
+        .await
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(inserted.locator, "4");
+        assert_eq!(inserted.columns, vec!["id", "name"]);
+        assert_eq!(inserted.values, vec!["4", "d"]);
+        assert_eq!(remaining_item_ids(&pool).await, vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn mysql_inserted_keys_fall_back_to_the_auto_increment_id() {
+        let columns = ["tenant".to_string(), "id".to_string()];
+        let values = [("tenant".to_string(), "acme".to_string())];
+
+        assert_eq!(
+            mysql_inserted_key_values(&columns, &values, 42),
+            Some(vec!["acme".to_string(), "42".to_string()])
+        );
+        assert_eq!(mysql_inserted_key_values(&columns, &values, 0), None);
+        assert_eq!(mysql_inserted_key_values(&[], &values, 42), None);
+    }
+
+    #[tokio::test]
+    async fn sqlite_view_preview_is_read_only() {
+        let pool = SqlitePool::connect(":memory:").await.unwrap();
+        sqlx::query("create table products (id integer primary key, name text)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("insert into products (name) values ('Mouse'), ('Keyboard')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("create view product_names as select name from products")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let result = load_table_preview_page(
+            DatabaseConnection::Sqlite(pool),
+            TablePreviewSource {
+                schema: None,
+                table_name: "product_names".to_string(),
+                qualified_name: r#""product_names""#.to_string(),
+            },
+            100,
+            0,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+        match result {
+            QueryOutput::Table(page) => {
+                assert_eq!(page.columns, vec!["name"]);
+                assert_eq!(page.rows.len(), 2);
+                assert!(page.editable.is_none());
+            }
+            other => panic!("expected table result, got {other:?}"),
+        }
+    }
+
     #[tokio::test]
     async fn create_table_creates_sqlite_table() {
         let pool = SqlitePool::connect(":memory:").await.unwrap();
@@ -1081,6 +1415,27 @@ mod tests {
         assert_eq!(leading_sql_keyword("   "), None);
     }
 
+    #[test]
+    fn statement_timeout_is_left_off_scripts_that_control_transactions() {
+        assert_eq!(statement_timeout_sql(30), "set statement_timeout = '30s'");
+
+        assert!(!controls_transactions("select * from users"));
+        assert!(!controls_transactions(
+            "update users set name = 'x'; delete from sessions"
+        ));
+        assert!(!controls_transactions("call archive_orders()"));
+        assert!(!controls_transactions(
+            "do $$ begin perform pg_sleep(1); end $$"
+        ));
+        assert!(!controls_transactions("VACUUM ANALYZE users"));
+        assert!(!controls_transactions(
+            "create index concurrently users_email_idx on users (email)"
+        ));
+        assert!(controls_transactions("begin; update users set name = 'x'"));
+        assert!(controls_transactions("select 1; commit"));
+        assert!(controls_transactions("savepoint before_import"));
+    }
+
     #[test]
     fn is_read_only_sql_gates_dispatch_for_keyboard_shortcut_triggers() {
         assert!(is_read_only_sql("select * from users"));
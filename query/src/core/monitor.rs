@@ -0,0 +1,433 @@
+use super::execute_query;
+use models::{
+    DatabaseConnection, DatabaseError, QueryOutput, SchemaSizeReport, ServerActivity, ServerInfo,
+    ServerLock, StatementStat, TableSizeDetail,
+};
+use sqlx::Row;
+use std::time::{Duration, Instant};
+
+/// Lists the other backends connected to the server, newest query first.
+pub async fn load_server_activity(
+    connection: DatabaseConnection,
+) -> Result<Vec<ServerActivity>, DatabaseError> {
+    let DatabaseConnection::Postgres(pool) = connection else {
+        return Err(postgres_only("The activity monitor"));
+    };
+
+    let rows = sqlx::query(
+        r#"
+        select
+            pid,
+            coalesce(usename::text, '') as usename,
+            coalesce(datname::text, '') as datname,
+            coalesce(application_name, '') as application_name,
+            coalesce(state, '') as state,
+            coalesce(query, '') as query,
+            to_char(query_start, 'YYYY-MM-DD HH24:MI:SS') as query_start,
+            extract(epoch from now() - query_start)::float8 as query_seconds,
+            coalesce(wait_event_type, '') as wait_event_type,
+            coalesce(wait_event, '') as wait_event
+        from pg_stat_activity
+        where pid <> pg_backend_pid()
+        order by query_start desc nulls last, pid
+        "#,
+    )
+    .fetch_all(&pool)
+    .await
+    .map_err(DatabaseError::Postgres)?;
+
+    rows.into_iter()
+        .map(|row| {
+            Ok(ServerActivity {
+                pid: row.try_get("pid")?,
+                user: row.try_get("usename")?,
+                database: row.try_get("datname")?,
+                application: row.try_get("application_name")?,
+                state: row.try_get("state")?,
+                query: row.try_get("query")?,
+                query_start: row.try_get("query_start")?,
+                query_seconds: row.try_get("query_seconds")?,
+                wait_event_type: row.try_get("wait_event_type")?,
+                wait_event: row.try_get("wait_event")?,
+            })
+        })
+        .collect::<Result<Vec<_>, sqlx::Error>>()
+        .map_err(DatabaseError::Postgres)
+}
+
+/// Lists the locks held or awaited by other backends, waiting locks first.
+pub async fn load_server_locks(
+    connection: DatabaseConnection,
+) -> Result<Vec<ServerLock>, DatabaseError> {
+    let DatabaseConnection::Postgres(pool) = connection else {
+        return Err(postgres_only("The lock monitor"));
+    };
+
+    let rows = sqlx::query(
+        r#"
+        select
+            l.pid,
+            coalesce(a.usename::text, '') as usename,
+            l.locktype,
+            l.mode,
+            l.granted,
+            case
+                when c.oid is null then ''
+                else format('%I.%I', n.nspname, c.relname)
+            end as relation,
+            pg_blocking_pids(l.pid) as blocked_by,
+            coalesce(a.query, '') as query,
+            extract(epoch from now() - a.query_start)::float8 as query_seconds
+        from pg_locks l
+        left join pg_stat_activity a on a.pid = l.pid
+        left join pg_class c on c.oid = l.relation
+        left join pg_namespace n on n.oid = c.relnamespace
+        where l.pid is not null
+          and l.pid <> pg_backend_pid()
+        order by l.granted, l.pid, relation
+        "#,
+    )
+    .fetch_all(&pool)
+    .await
+    .map_err(DatabaseError::Postgres)?;
+
+    rows.into_iter()
+        .map(|row| {
+            Ok(ServerLock {
+                pid: row.try_get("pid")?,
+                user: row.try_get("usename")?,
+                lock_type: row.try_get("locktype")?,
+                mode: row.try_get("mode")?,
+                granted: row.try_get("granted")?,
+                relation: row.try_get("relation")?,
+                blocked_by: row.try_get("blocked_by")?,
+                query: row.try_get("query")?,
+                query_seconds: row.try_get("query_seconds")?,
+            })
+        })
+        .collect::<Result<Vec<_>, sqlx::Error>>()
+        .map_err(DatabaseError::Postgres)
+}
+
+/// Cancels the query running on backend `pid`, leaving its connection open.
+/// Returns `false` when the server reports that no such backend existed (or
+/// it could not be signalled).
+pub async fn cancel_backend(
+    connection: DatabaseConnection,
+    pid: i32,
+) -> Result<bool, DatabaseError> {
+    let DatabaseConnection::Postgres(pool) = connection else {
+        return Err(postgres_only("Cancelling queries"));
+    };
+
+    sqlx::query_scalar::<_, bool>("select pg_cancel_backend($1)")
+        .bind(pid)
+        .fetch_one(&pool)
+        .await
+        .map_err(DatabaseError::Postgres)
+}
+
+/// Terminates the backend `pid`. Returns `false` when the server reports that
+/// no such backend existed (or it could not be signalled).
+pub async fn terminate_backend(
+    connection: DatabaseConnection,
+    pid: i32,
+) -> Result<bool, DatabaseError> {
+    let DatabaseConnection::Postgres(pool) = connection else {
+        return Err(postgres_only("Terminating backends"));
+    };
+
+    sqlx::query_scalar::<_, bool>("select pg_terminate_backend($1)")
+        .bind(pid)
+        .fetch_one(&pool)
+        .await
+        .map_err(DatabaseError::Postgres)
+}
+
+/// Lists the `limit` statements with the most total execution time, or
+/// `None` when the `pg_stat_statements` extension is not installed in the
+/// current database.
+pub async fn load_statement_stats(
+    connection: DatabaseConnection,
+    limit: i64,
+) -> Result<Option<Vec<StatementStat>>, DatabaseError> {
+    let DatabaseConnection::Postgres(pool) = connection else {
+        return Err(postgres_only("Top queries"));
+    };
+
+    let (installed, version_num): (bool, i32) = sqlx::query_as(
+        r#"
+        select
+            exists(select 1 from pg_extension where extname = 'pg_stat_statements'),
+            current_setting('server_version_num')::int
+        "#,
+    )
+    .fetch_one(&pool)
+    .await
+    .map_err(DatabaseError::Postgres)?;
+    if !installed {
+        return Ok(None);
+    }
+
+    let rows = sqlx::query(statement_stats_query(version_num))
+        .bind(limit)
+        .fetch_all(&pool)
+        .await
+        .map_err(DatabaseError::Postgres)?;
+
+    rows.into_iter()
+        .map(|row| {
+            Ok(StatementStat {
+                query: row.try_get("query")?,
+                calls: row.try_get("calls")?,
+                total_exec_ms: row.try_get("total_exec_ms")?,
+                mean_exec_ms: row.try_get("mean_exec_ms")?,
+                rows: row.try_get("rows")?,
+            })
+        })
+        .collect::<Result<Vec<_>, sqlx::Error>>()
+        .map(Some)
+        .map_err(DatabaseError::Postgres)
+}
+
+/// Clears everything `pg_stat_statements` has collected so far.
+pub async fn reset_statement_stats(connection: DatabaseConnection) -> Result<(), DatabaseError> {
+    let DatabaseConnection::Postgres(pool) = connection else {
+        return Err(postgres_only("Top queries"));
+    };
+
+    sqlx::query("select pg_stat_statements_reset()")
+        .execute(&pool)
+        .await
+        .map_err(DatabaseError::Postgres)?;
+    Ok(())
+}
+
+/// PostgreSQL 13 split planning from execution time and renamed the
+/// `*_time` columns to `*_exec_time`.
+fn statement_stats_query(version_num: i32) -> &'static str {
+    if version_num >= 130000 {
+        r#"
+        select
+            coalesce(query, '') as query,
+            calls,
+            total_exec_time as total_exec_ms,
+            mean_exec_time as mean_exec_ms,
+            rows
+        from pg_stat_statements
+        order by total_exec_time desc
+        limit $1
+        "#
+    } else {
+        r#"
+        select
+            coalesce(query, '') as query,
+            calls,
+            total_time as total_exec_ms,
+            mean_time as mean_exec_ms,
+            rows
+        from pg_stat_statements
+        order by total_time desc
+        limit $1
+        "#
+    }
+}
+
+/// Measures every table, partitioned table and materialized view in `schema`,
+/// largest first, along with the size of the current database.
+pub async fn load_schema_size_report(
+    connection: DatabaseConnection,
+    schema: String,
+) -> Result<SchemaSizeReport, DatabaseError> {
+    let DatabaseConnection::Postgres(pool) = connection else {
+        return Err(postgres_only("The size report"));
+    };
+
+    let (database, database_bytes): (String, i64) = sqlx::query_as(
+        "select current_database()::text, pg_database_size(current_database())::bigint",
+    )
+    .fetch_one(&pool)
+    .await
+    .map_err(DatabaseError::Postgres)?;
+
+    let rows = sqlx::query(
+        r#"
+        select
+            c.relname::text as name,
+            c.reltuples::float8 as reltuples,
+            pg_total_relation_size(c.oid)::bigint as total_bytes,
+            pg_relation_size(c.oid)::bigint as table_bytes,
+            pg_indexes_size(c.oid)::bigint as index_bytes,
+            coalesce(pg_total_relation_size(nullif(c.reltoastrelid, 0)), 0)::bigint as toast_bytes
+        from pg_class c
+        join pg_namespace n on n.oid = c.relnamespace
+        where n.nspname = $1
+          and c.relkind in ('r', 'p', 'm')
+        order by total_bytes desc, c.relname
+        "#,
+    )
+    .bind(&schema)
+    .fetch_all(&pool)
+    .await
+    .map_err(DatabaseError::Postgres)?;
+
+    let bytes = |row: &sqlx::postgres::PgRow, column: &str| -> Result<u64, sqlx::Error> {
+        let value: i64 = row.try_get(column)?;
+        Ok(u64::try_from(value).unwrap_or_default())
+    };
+    let tables = rows
+        .into_iter()
+        .map(|row| {
+            let reltuples: f64 = row.try_get("reltuples")?;
+            Ok(TableSizeDetail {
+                name: row.try_get("name")?,
+                // PostgreSQL 14+ stores -1 until the table is first analyzed.
+                estimated_rows: (reltuples >= 0.0).then_some(reltuples.round() as u64),
+                total_bytes: bytes(&row, "total_bytes")?,
+                table_bytes: bytes(&row, "table_bytes")?,
+                index_bytes: bytes(&row, "index_bytes")?,
+                toast_bytes: bytes(&row, "toast_bytes")?,
+            })
+        })
+        .collect::<Result<Vec<_>, sqlx::Error>>()
+        .map_err(DatabaseError::Postgres)?;
+
+    Ok(SchemaSizeReport {
+        database,
+        database_bytes: u64::try_from(database_bytes).unwrap_or_default(),
+        tables,
+    })
+}
+
+/// Reads the server product, version, and current database, as shown after a
+/// connection test.
+pub async fn load_server_info(connection: DatabaseConnection) -> Result<ServerInfo, DatabaseError> {
+    let (product, sql) = match &connection {
+        DatabaseConnection::Sqlite(_) => ("SQLite", "select sqlite_version(), 'main'"),
+        DatabaseConnection::Postgres(_) => (
+            "PostgreSQL",
+            "select current_setting('server_version'), current_database()",
+        ),
+        DatabaseConnection::MySql(_) => ("MySQL", "select version(), coalesce(database(), '')"),
+        DatabaseConnection::ClickHouse(_) => ("ClickHouse", "select version(), currentDatabase()"),
+    };
+
+    let QueryOutput::Table(page) = execute_query(connection, sql.to_string()).await? else {
+        return Err(DatabaseError::UnsupportedDriver(
+            "The server did not report its version.".to_string(),
+        ));
+    };
+    let row = page.rows.first().cloned().unwrap_or_default();
+    let version = row.first().map(String::as_str).unwrap_or_default();
+    let database = row
+        .get(1)
+        .map(|value| value.trim())
+        .filter(|value| !value.is_empty())
+        .map(str::to_string);
+
+    Ok(ServerInfo {
+        version: server_version_label(product, version),
+        database,
+    })
+}
+
+/// Round trip of a `select 1` on `connection`, for showing how responsive
+/// the server is.
+pub async fn ping_connection(connection: DatabaseConnection) -> Result<Duration, DatabaseError> {
+    let started = Instant::now();
+    execute_query(connection, "select 1".to_string()).await?;
+    Ok(started.elapsed())
+}
+
+/// Joins the product name with the bare version number, dropping build
+/// suffixes such as `16.2 (Debian 16.2-1.pgdg120+2)` or `8.0.36-0ubuntu0`.
+/// MariaDB reports through the MySQL protocol and is named as such.
+fn server_version_label(product: &str, version: &str) -> String {
+    let product = if version.contains("MariaDB") {
+        "MariaDB"
+    } else {
+        product
+    };
+    let number = version
+        .split_whitespace()
+        .next()
+        .unwrap_or_default()
+        .split('-')
+        .next()
+        .unwrap_or_default();
+
+    if number.is_empty() {
+        product.to_string()
+    } else {
+        format!("{product} {number}")
+    }
+}
+
+pub(super) fn postgres_only(feature: &str) -> DatabaseError {
+    DatabaseError::UnsupportedDriver(format!("{feature} is only available for PostgreSQL."))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        load_schema_size_report, load_server_activity, load_server_info, load_server_locks,
+        load_statement_stats, server_version_label, terminate_backend,
+    };
+    use models::{DatabaseConnection, DatabaseError};
+    use sqlx::SqlitePool;
+
+    #[test]
+    fn server_version_label_drops_build_suffixes() {
+        assert_eq!(
+            server_version_label("PostgreSQL", "16.2 (Debian 16.2-1.pgdg120+2)"),
+            "PostgreSQL 16.2"
+        );
+        assert_eq!(
+            server_version_label("MySQL", "8.0.36-0ubuntu0.22.04.1"),
+            "MySQL 8.0.36"
+        );
+        assert_eq!(
+            server_version_label("MySQL", "10.11.6-MariaDB-0+deb12u1"),
+            "MariaDB 10.11.6"
+        );
+        assert_eq!(server_version_label("ClickHouse", ""), "ClickHouse");
+    }
+
+    #[tokio::test]
+    async fn server_info_reports_sqlite_version_and_main_database() {
+        let pool = SqlitePool::connect(":memory:").await.unwrap();
+        let info = load_server_info(DatabaseConnection::Sqlite(pool))
+            .await
+            .unwrap();
+
+        assert!(info.version.starts_with("SQLite 3."));
+        assert_eq!(info.database.as_deref(), Some("main"));
+    }
+
+    #[tokio::test]
+    async fn activity_monitor_rejects_non_postgres_connections() {
+        let pool = SqlitePool::connect(":memory:").await.unwrap();
+        let connection = DatabaseConnection::Sqlite(pool);
+
+        assert!(matches!(
+            load_server_activity(connection.clone()).await,
+            Err(DatabaseError::UnsupportedDriver(_))
+        ));
+        assert!(matches!(
+            load_server_locks(connection.clone()).await,
+            Err(DatabaseError::UnsupportedDriver(_))
+        ));
+        assert!(matches!(
+            terminate_backend(connection.clone(), 42).await,
+            Err(DatabaseError::UnsupportedDriver(_))
+        ));
+        assert!(matches!(
+            load_statement_stats(connection.clone(), 20).await,
+            Err(DatabaseError::UnsupportedDriver(_))
+        ));
+        assert!(matches!(
+            load_schema_size_report(connection, "main".to_string()).await,
+            Err(DatabaseError::UnsupportedDriver(_))
+        ));
+    }
+}
@@ -1,15 +1,17 @@
 use database::DatabaseDriver;
 use driver_clickhouse::ClickHouseDriver;
-use models::{DatabaseConnection, DatabaseError, TablePreviewSource};
+use models::{DatabaseConnection, DatabaseError, InsertedRow, TablePreviewSource};
 use sqlx::Row;
 
+use super::rows::{mysql_inserted_row, postgres_inserted_row, sqlite_inserted_row};
 use super::{
-    build_insert_row_sql, clickhouse_get_primary_key_columns, clickhouse_type_supports_auto_id,
-    invalid_sqlite_locator, mysql_effective_schema_name, mysql_primary_key_columns,
-    mysql_single_primary_key_column, mysql_type_supports_auto_id, parse_clickhouse_locator,
-    parse_mysql_locator, parse_next_numeric_id, postgres_single_primary_key_column,
-    postgres_type_supports_auto_id, quote_identifier, quote_identifier_clickhouse, sql_literal,
-    sqlite_single_primary_key_column, sqlite_type_supports_auto_id,
+    LOCATOR_COLUMN, build_insert_row_sql, clickhouse_get_primary_key_columns,
+    clickhouse_type_supports_auto_id, invalid_sqlite_locator, mysql_effective_schema_name,
+    mysql_locator_expression, mysql_primary_key_columns, mysql_single_primary_key_column,
+    mysql_type_supports_auto_id, parse_clickhouse_locator, parse_mysql_locator,
+    parse_next_numeric_id, postgres_single_primary_key_column, postgres_type_supports_auto_id,
+    quote_identifier, quote_identifier_clickhouse, sql_literal, sqlite_single_primary_key_column,
+    sqlite_type_supports_auto_id,
 };
 
 pub async fn update_table_cell(
@@ -19,60 +21,15 @@ pub async fn update_table_cell(
     column_name: String,
     value: String,
 ) -> Result<(), DatabaseError> {
-    let column = quote_identifier(&column_name);
-    let value_literal = sql_literal(&value);
-
     match connection {
         DatabaseConnection::Sqlite(pool) => {
-            let rowid = locator
-                .parse::<i64>()
-                .map_err(|_| invalid_sqlite_locator())?;
-            let sql = format!(
-                "update {} set {} = {} where rowid = {}",
-                source.qualified_name, column, value_literal, rowid
-            );
-            sqlx::query(&sql)
-                .execute(&pool)
-                .await
-                .map_err(DatabaseError::Sqlite)?;
-            Ok(())
+            update_sqlite_table_cell(&pool, &source, &locator, &column_name, &value).await
         }
         DatabaseConnection::Postgres(pool) => {
-            let sql = format!(
-                "update {} set {} = {} where ctid = {}::tid",
-                source.qualified_name,
-                column,
-                value_literal,
-                sql_literal(&locator)
-            );
-            sqlx::query(&sql)
-                .execute(&pool)
-                .await
-                .map_err(DatabaseError::Postgres)?;
-            Ok(())
+            update_postgres_table_cell(&pool, &source, &locator, &column_name, &value).await
         }
         DatabaseConnection::MySql(pool) => {
-            let schema_name = mysql_effective_schema_name(&pool, source.schema.as_deref()).await?;
-            let primary_key_columns =
-                mysql_primary_key_columns(&pool, &schema_name, &source.table_name).await?;
-            if primary_key_columns.is_empty() {
-                return Err(DatabaseError::UnsupportedDriver(
-                    "MySQL table must have a primary key for updates".to_string(),
-                ));
-            }
-
-            let conditions = parse_mysql_locator(&locator, &primary_key_columns)?;
-            let where_clause = conditions.join(" AND ");
-            let column = quote_identifier_clickhouse(&column_name);
-            let sql = format!(
-                "update {} set {} = {} where {}",
-                source.qualified_name, column, value_literal, where_clause
-            );
-            sqlx::query(&sql)
-                .execute(&pool)
-                .await
-                .map_err(DatabaseError::MySql)?;
-            Ok(())
+            update_mysql_table_cell(&pool, &pool, &source, &locator, &column_name, &value).await
         }
         DatabaseConnection::ClickHouse(config) => {
             let schema_name = source
@@ -158,34 +115,51 @@ pub async fn insert_table_row_with_values(
 ) -> Result<(), DatabaseError> {
     match connection {
         DatabaseConnection::Sqlite(pool) => {
-            let sql = build_insert_row_sql(&source, &column_values, quote_identifier);
-            sqlx::query(&sql)
-                .execute(&pool)
-                .await
-                .map_err(DatabaseError::Sqlite)?;
-            Ok(())
+            insert_sqlite_table_row_with_values(&pool, &source, &column_values).await
         }
         DatabaseConnection::Postgres(pool) => {
-            let sql = build_insert_row_sql(&source, &column_values, quote_identifier);
-            sqlx::query(&sql)
-                .execute(&pool)
-                .await
-                .map_err(DatabaseError::Postgres)?;
-            Ok(())
+            insert_postgres_table_row_with_values(&pool, &source, &column_values).await
         }
         DatabaseConnection::MySql(pool) => {
+            insert_mysql_table_row_with_values(&pool, &source, &column_values).await
+        }
+        DatabaseConnection::ClickHouse(config) => {
             let sql = build_insert_row_sql(&source, &column_values, quote_identifier_clickhouse);
-            sqlx::query(&sql)
-                .execute(&pool)
-                .await
-                .map_err(DatabaseError::MySql)?;
+
+            ClickHouseDriver.execute_text_query(&config, &sql).await?;
             Ok(())
         }
+    }
+}
+
+/// Inserts a row and reads it back with its generated keys and defaults
+/// filled in. Returns `None` when the driver cannot address the new row, e.g.
+/// ClickHouse or a MySQL table without a primary key.
+pub async fn insert_table_row_returning(
+    connection: DatabaseConnection,
+    source: TablePreviewSource,
+    column_values: Vec<(String, String)>,
+) -> Result<Option<InsertedRow>, DatabaseError> {
+    match connection {
+        DatabaseConnection::Sqlite(pool) => {
+            insert_sqlite_table_row_returning(&pool, &source, &column_values)
+                .await
+                .map(Some)
+        }
+        DatabaseConnection::Postgres(pool) => {
+            insert_postgres_table_row_returning(&pool, &source, &column_values)
+                .await
+                .map(Some)
+        }
+        DatabaseConnection::MySql(pool) => {
+            let mut connection = pool.acquire().await.map_err(DatabaseError::MySql)?;
+            insert_mysql_table_row_returning(&pool, &mut connection, &source, &column_values).await
+        }
         DatabaseConnection::ClickHouse(config) => {
             let sql = build_insert_row_sql(&source, &column_values, quote_identifier_clickhouse);
 
             ClickHouseDriver.execute_text_query(&config, &sql).await?;
-            Ok(())
+            Ok(None)
         }
     }
 }
@@ -342,53 +316,12 @@ pub async fn delete_table_row(
     locator: String,
 ) -> Result<(), DatabaseError> {
     match connection {
-        DatabaseConnection::Sqlite(pool) => {
-            let rowid = locator
-                .parse::<i64>()
-                .map_err(|_| invalid_sqlite_locator())?;
-            let sql = format!(
-                "delete from {} where rowid = {}",
-                source.qualified_name, rowid
-            );
-            sqlx::query(&sql)
-                .execute(&pool)
-                .await
-                .map_err(DatabaseError::Sqlite)?;
-            Ok(())
-        }
+        DatabaseConnection::Sqlite(pool) => delete_sqlite_table_row(&pool, &source, &locator).await,
         DatabaseConnection::Postgres(pool) => {
-            let sql = format!(
-                "delete from {} where ctid = {}::tid",
-                source.qualified_name,
-                sql_literal(&locator)
-            );
-            sqlx::query(&sql)
-                .execute(&pool)
-                .await
-                .map_err(DatabaseError::Postgres)?;
-            Ok(())
+            delete_postgres_table_row(&pool, &source, &locator).await
         }
         DatabaseConnection::MySql(pool) => {
-            let schema_name = mysql_effective_schema_name(&pool, source.schema.as_deref()).await?;
-            let primary_key_columns =
-                mysql_primary_key_columns(&pool, &schema_name, &source.table_name).await?;
-            if primary_key_columns.is_empty() {
-                return Err(DatabaseError::UnsupportedDriver(
-                    "MySQL table must have a primary key for deletes".to_string(),
-                ));
-            }
-
-            let conditions = parse_mysql_locator(&locator, &primary_key_columns)?;
-            let where_clause = conditions.join(" AND ");
-            let sql = format!(
-                "delete from {} where {}",
-                source.qualified_name, where_clause
-            );
-            sqlx::query(&sql)
-                .execute(&pool)
-                .await
-                .map_err(DatabaseError::MySql)?;
-            Ok(())
+            delete_mysql_table_row(&pool, &pool, &source, &locator).await
         }
         DatabaseConnection::ClickHouse(config) => {
             let schema_name = source
@@ -428,3 +361,466 @@ pub async fn delete_table_row(
         }
     }
 }
+
+// ---------------------------------------------------------------------------
+// Executor-generic row mutations
+//
+// These run against either a pool or a connection held by an open
+// `SqlTransaction`, so table edits land inside the user's transaction.
+// ---------------------------------------------------------------------------
+
+pub(super) async fn update_sqlite_table_cell<'c, E>(
+    executor: E,
+    source: &TablePreviewSource,
+    locator: &str,
+    column_name: &str,
+    value: &str,
+) -> Result<(), DatabaseError>
+where
+    E: sqlx::Executor<'c, Database = sqlx::Sqlite>,
+{
+    let rowid = locator
+        .parse::<i64>()
+        .map_err(|_| invalid_sqlite_locator())?;
+    let sql = format!(
+        "update {} set {} = {} where rowid = {}",
+        source.qualified_name,
+        quote_identifier(column_name),
+        sql_literal(value),
+        rowid
+    );
+    sqlx::query(&sql)
+        .execute(executor)
+        .await
+        .map_err(DatabaseError::Sqlite)?;
+    Ok(())
+}
+
+pub(super) async fn update_postgres_table_cell<'c, E>(
+    executor: E,
+    source: &TablePreviewSource,
+    locator: &str,
+    column_name: &str,
+    value: &str,
+) -> Result<(), DatabaseError>
+where
+    E: sqlx::Executor<'c, Database = sqlx::Postgres>,
+{
+    let sql = format!(
+        "update {} set {} = {} where ctid = {}::tid",
+        source.qualified_name,
+        quote_identifier(column_name),
+        sql_literal(value),
+        sql_literal(locator)
+    );
+    sqlx::query(&sql)
+        .execute(executor)
+        .await
+        .map_err(DatabaseError::Postgres)?;
+    Ok(())
+}
+
+pub(super) async fn update_mysql_table_cell<'c, E>(
+    pool: &sqlx::MySqlPool,
+    executor: E,
+    source: &TablePreviewSource,
+    locator: &str,
+    column_name: &str,
+    value: &str,
+) -> Result<(), DatabaseError>
+where
+    E: sqlx::Executor<'c, Database = sqlx::MySql>,
+{
+    let schema_name = mysql_effective_schema_name(pool, source.schema.as_deref()).await?;
+    let primary_key_columns =
+        mysql_primary_key_columns(pool, &schema_name, &source.table_name).await?;
+    if primary_key_columns.is_empty() {
+        return Err(DatabaseError::UnsupportedDriver(
+            "MySQL table must have a primary key for updates".to_string(),
+        ));
+    }
+
+    let conditions = parse_mysql_locator(locator, &primary_key_columns)?;
+    let where_clause = conditions.join(" AND ");
+    let sql = format!(
+        "update {} set {} = {} where {}",
+        source.qualified_name,
+        quote_identifier_clickhouse(column_name),
+        sql_literal(value),
+        where_clause
+    );
+    sqlx::query(&sql)
+        .execute(executor)
+        .await
+        .map_err(DatabaseError::MySql)?;
+    Ok(())
+}
+
+pub(super) async fn insert_sqlite_table_row_with_values<'c, E>(
+    executor: E,
+    source: &TablePreviewSource,
+    column_values: &[(String, String)],
+) -> Result<(), DatabaseError>
+where
+    E: sqlx::Executor<'c, Database = sqlx::Sqlite>,
+{
+    let sql = build_insert_row_sql(source, column_values, quote_identifier);
+    sqlx::query(&sql)
+        .execute(executor)
+        .await
+        .map_err(DatabaseError::Sqlite)?;
+    Ok(())
+}
+
+pub(super) async fn insert_postgres_table_row_with_values<'c, E>(
+    executor: E,
+    source: &TablePreviewSource,
+    column_values: &[(String, String)],
+) -> Result<(), DatabaseError>
+where
+    E: sqlx::Executor<'c, Database = sqlx::Postgres>,
+{
+    let sql = build_insert_row_sql(source, column_values, quote_identifier);
+    sqlx::query(&sql)
+        .execute(executor)
+        .await
+        .map_err(DatabaseError::Postgres)?;
+    Ok(())
+}
+
+pub(super) async fn insert_mysql_table_row_with_values<'c, E>(
+    executor: E,
+    source: &TablePreviewSource,
+    column_values: &[(String, String)],
+) -> Result<(), DatabaseError>
+where
+    E: sqlx::Executor<'c, Database = sqlx::MySql>,
+{
+    let sql = build_insert_row_sql(source, column_values, quote_identifier_clickhouse);
+    sqlx::query(&sql)
+        .execute(executor)
+        .await
+        .map_err(DatabaseError::MySql)?;
+    Ok(())
+}
+
+pub(super) async fn insert_sqlite_table_row_returning<'c, E>(
+    executor: E,
+    source: &TablePreviewSource,
+    column_values: &[(String, String)],
+) -> Result<InsertedRow, DatabaseError>
+where
+    E: sqlx::Executor<'c, Database = sqlx::Sqlite>,
+{
+    let sql = format!(
+        r#"{} returning rowid as "{LOCATOR_COLUMN}", *"#,
+        build_insert_row_sql(source, column_values, quote_identifier)
+    );
+    let row = sqlx::query(&sql)
+        .fetch_one(executor)
+        .await
+        .map_err(DatabaseError::Sqlite)?;
+    Ok(sqlite_inserted_row(&row))
+}
+
+pub(super) async fn insert_postgres_table_row_returning<'c, E>(
+    executor: E,
+    source: &TablePreviewSource,
+    column_values: &[(String, String)],
+) -> Result<InsertedRow, DatabaseError>
+where
+    E: sqlx::Executor<'c, Database = sqlx::Postgres>,
+{
+    let sql = format!(
+        r#"{} returning ctid::text as "{LOCATOR_COLUMN}", *"#,
+        build_insert_row_sql(source, column_values, quote_identifier)
+    );
+    let row = sqlx::query(&sql)
+        .fetch_one(executor)
+        .await
+        .map_err(DatabaseError::Postgres)?;
+    Ok(postgres_inserted_row(&row))
+}
+
+/// MySQL has no RETURNING, so the row is selected again by its primary key,
+/// taking an auto-increment value from the insert itself. Both statements run
+/// on `connection` so an open transaction sees its own insert.
+pub(super) async fn insert_mysql_table_row_returning(
+    pool: &sqlx::MySqlPool,
+    connection: &mut sqlx::MySqlConnection,
+    source: &TablePreviewSource,
+    column_values: &[(String, String)],
+) -> Result<Option<InsertedRow>, DatabaseError> {
+    let sql = build_insert_row_sql(source, column_values, quote_identifier_clickhouse);
+    let result = sqlx::query(&sql)
+        .execute(&mut *connection)
+        .await
+        .map_err(DatabaseError::MySql)?;
+
+    let schema_name = mysql_effective_schema_name(pool, source.schema.as_deref()).await?;
+    let primary_key_columns =
+        mysql_primary_key_columns(pool, &schema_name, &source.table_name).await?;
+    let Some(key_values) =
+        mysql_inserted_key_values(&primary_key_columns, column_values, result.last_insert_id())
+    else {
+        return Ok(None);
+    };
+    let locator = serde_json::to_string(&key_values)
+        .map_err(|err| DatabaseError::UnsupportedDriver(err.to_string()))?;
+    let where_clause = parse_mysql_locator(&locator, &primary_key_columns)?.join(" AND ");
+    let sql = format!(
+        r#"select {} as "{LOCATOR_COLUMN}", {}.* from {} where {}"#,
+        mysql_locator_expression(&primary_key_columns),
+        source.qualified_name,
+        source.qualified_name,
+        where_clause
+    );
+    let row = sqlx::query(&sql)
+        .fetch_optional(&mut *connection)
+        .await
+        .map_err(DatabaseError::MySql)?;
+    Ok(row.as_ref().map(mysql_inserted_row))
+}
+
+/// Primary key values of a freshly inserted MySQL row: the inserted value of
+/// each key column, or the auto-increment id for the one left to the
+/// database. `None` when the table has no primary key or a key is unknown.
+pub(super) fn mysql_inserted_key_values(
+    primary_key_columns: &[String],
+    column_values: &[(String, String)],
+    last_insert_id: u64,
+) -> Option<Vec<String>> {
+    if primary_key_columns.is_empty() {
+        return None;
+    }
+    primary_key_columns
+        .iter()
+        .map(|column| {
+            match column_values.iter().find(|(column_name, value)| {
+                column_name == column && !value.eq_ignore_ascii_case("null")
+            }) {
+                Some((_, value)) => Some(value.clone()),
+                None => (last_insert_id != 0).then(|| last_insert_id.to_string()),
+            }
+        })
+        .collect()
+}
+
+pub(super) async fn delete_sqlite_table_row<'c, E>(
+    executor: E,
+    source: &TablePreviewSource,
+    locator: &str,
+) -> Result<(), DatabaseError>
+where
+    E: sqlx::Executor<'c, Database = sqlx::Sqlite>,
+{
+    let rowid = locator
+        .parse::<i64>()
+        .map_err(|_| invalid_sqlite_locator())?;
+    let sql = format!(
+        "delete from {} where rowid = {}",
+        source.qualified_name, rowid
+    );
+    sqlx::query(&sql)
+        .execute(executor)
+        .await
+        .map_err(DatabaseError::Sqlite)?;
+    Ok(())
+}
+
+pub(super) async fn delete_postgres_table_row<'c, E>(
+    executor: E,
+    source: &TablePreviewSource,
+    locator: &str,
+) -> Result<(), DatabaseError>
+where
+    E: sqlx::Executor<'c, Database = sqlx::Postgres>,
+{
+    let sql = format!(
+        "delete from {} where ctid = {}::tid",
+        source.qualified_name,
+        sql_literal(locator)
+    );
+    sqlx::query(&sql)
+        .execute(executor)
+        .await
+        .map_err(DatabaseError::Postgres)?;
+    Ok(())
+}
+
+pub(super) async fn delete_mysql_table_row<'c, E>(
+    pool: &sqlx::MySqlPool,
+    executor: E,
+    source: &TablePreviewSource,
+    locator: &str,
+) -> Result<(), DatabaseError>
+where
+    E: sqlx::Executor<'c, Database = sqlx::MySql>,
+{
+    let schema_name = mysql_effective_schema_name(pool, source.schema.as_deref()).await?;
+    let primary_key_columns =
+        mysql_primary_key_columns(pool, &schema_name, &source.table_name).await?;
+    if primary_key_columns.is_empty() {
+        return Err(DatabaseError::UnsupportedDriver(
+            "MySQL table must have a primary key for deletes".to_string(),
+        ));
+    }
+
+    let conditions = parse_mysql_locator(locator, &primary_key_columns)?;
+    let where_clause = conditions.join(" AND ");
+    let sql = format!(
+        "delete from {} where {}",
+        source.qualified_name, where_clause
+    );
+    sqlx::query(&sql)
+        .execute(executor)
+        .await
+        .map_err(DatabaseError::MySql)?;
+    Ok(())
+}
+
+/// Delete every row behind `locators` in one statement.
+///
+/// The delete runs in its own transaction and is rolled back unless it
+/// removed exactly one row per locator, so a stale locator never leaves a
+/// partial delete behind. Returns the number of rows deleted.
+///
+/// ClickHouse is rejected: its deletes are asynchronous mutations that do
+/// not report how many rows they removed.
+pub async fn delete_table_rows(
+    connection: DatabaseConnection,
+    source: TablePreviewSource,
+    locators: Vec<String>,
+) -> Result<u64, DatabaseError> {
+    if locators.is_empty() {
+        return Ok(0);
+    }
+
+    match connection {
+        DatabaseConnection::Sqlite(pool) => {
+            let mut transaction = pool.begin().await.map_err(DatabaseError::Sqlite)?;
+            let deleted = delete_sqlite_table_rows(&mut *transaction, &source, &locators).await?;
+            check_deleted_row_count(locators.len(), deleted)?;
+            transaction.commit().await.map_err(DatabaseError::Sqlite)?;
+            Ok(deleted)
+        }
+        DatabaseConnection::Postgres(pool) => {
+            let mut transaction = pool.begin().await.map_err(DatabaseError::Postgres)?;
+            let deleted = delete_postgres_table_rows(&mut *transaction, &source, &locators).await?;
+            check_deleted_row_count(locators.len(), deleted)?;
+            transaction
+                .commit()
+                .await
+                .map_err(DatabaseError::Postgres)?;
+            Ok(deleted)
+        }
+        DatabaseConnection::MySql(pool) => {
+            let mut transaction = pool.begin().await.map_err(DatabaseError::MySql)?;
+            let deleted =
+                delete_mysql_table_rows(&pool, &mut *transaction, &source, &locators).await?;
+            check_deleted_row_count(locators.len(), deleted)?;
+            transaction.commit().await.map_err(DatabaseError::MySql)?;
+            Ok(deleted)
+        }
+        DatabaseConnection::ClickHouse(_) => Err(DatabaseError::UnsupportedDriver(
+            "Deleting selected rows is not supported for ClickHouse".to_string(),
+        )),
+    }
+}
+
+/// Fails when a multi-row delete did not remove exactly the rows it was
+/// asked to, so the caller rolls it back.
+pub(super) fn check_deleted_row_count(expected: usize, deleted: u64) -> Result<(), DatabaseError> {
+    if deleted == expected as u64 {
+        return Ok(());
+    }
+    Err(DatabaseError::UnsupportedDriver(format!(
+        "Expected to delete {expected} row(s) but {deleted} matched; nothing was deleted"
+    )))
+}
+
+pub(super) async fn delete_sqlite_table_rows<'c, E>(
+    executor: E,
+    source: &TablePreviewSource,
+    locators: &[String],
+) -> Result<u64, DatabaseError>
+where
+    E: sqlx::Executor<'c, Database = sqlx::Sqlite>,
+{
+    let rowids = locators
+        .iter()
+        .map(|locator| locator.parse::<i64>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| invalid_sqlite_locator())?;
+    let placeholders = vec!["?"; rowids.len()].join(", ");
+    let sql = format!(
+        "delete from {} where rowid in ({placeholders})",
+        source.qualified_name
+    );
+    let mut query = sqlx::query(&sql);
+    for rowid in rowids {
+        query = query.bind(rowid);
+    }
+    let result = query
+        .execute(executor)
+        .await
+        .map_err(DatabaseError::Sqlite)?;
+    Ok(result.rows_affected())
+}
+
+pub(super) async fn delete_postgres_table_rows<'c, E>(
+    executor: E,
+    source: &TablePreviewSource,
+    locators: &[String],
+) -> Result<u64, DatabaseError>
+where
+    E: sqlx::Executor<'c, Database = sqlx::Postgres>,
+{
+    let sql = format!(
+        "delete from {} where ctid = any($1::text[]::tid[])",
+        source.qualified_name
+    );
+    let result = sqlx::query(&sql)
+        .bind(locators)
+        .execute(executor)
+        .await
+        .map_err(DatabaseError::Postgres)?;
+    Ok(result.rows_affected())
+}
+
+pub(super) async fn delete_mysql_table_rows<'c, E>(
+    pool: &sqlx::MySqlPool,
+    executor: E,
+    source: &TablePreviewSource,
+    locators: &[String],
+) -> Result<u64, DatabaseError>
+where
+    E: sqlx::Executor<'c, Database = sqlx::MySql>,
+{
+    let schema_name = mysql_effective_schema_name(pool, source.schema.as_deref()).await?;
+    let primary_key_columns =
+        mysql_primary_key_columns(pool, &schema_name, &source.table_name).await?;
+    if primary_key_columns.is_empty() {
+        return Err(DatabaseError::UnsupportedDriver(
+            "MySQL table must have a primary key for deletes".to_string(),
+        ));
+    }
+
+    let row_conditions = locators
+        .iter()
+        .map(|locator| {
+            parse_mysql_locator(locator, &primary_key_columns)
+                .map(|conditions| format!("({})", conditions.join(" AND ")))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let sql = format!(
+        "delete from {} where {}",
+        source.qualified_name,
+        row_conditions.join(" OR ")
+    );
+    let result = sqlx::query(&sql)
+        .execute(executor)
+        .await
+        .map_err(DatabaseError::MySql)?;
+    Ok(result.rows_affected())
+}
@@ -0,0 +1,67 @@
+use models::{DatabaseConnection, DatabaseError, ListenCommand, ServerNotification};
+use sqlx::postgres::PgListener;
+use tokio::sync::mpsc::UnboundedReceiver;
+
+use super::monitor::postgres_only;
+
+/// Listens on a dedicated connection, so queries on the session never wait
+/// behind it, and reports every notification on the channels subscribed
+/// through `commands`. Returns once the command sender is dropped.
+pub async fn listen_for_notifications(
+    connection: DatabaseConnection,
+    mut commands: UnboundedReceiver<ListenCommand>,
+    mut on_notification: impl FnMut(ServerNotification),
+) -> Result<(), DatabaseError> {
+    let DatabaseConnection::Postgres(pool) = connection else {
+        return Err(postgres_only("LISTEN/NOTIFY"));
+    };
+
+    let mut listener = PgListener::connect_with(&pool)
+        .await
+        .map_err(DatabaseError::Postgres)?;
+    loop {
+        tokio::select! {
+            command = commands.recv() => {
+                match command {
+                    Some(ListenCommand::Listen(channel)) => listener
+                        .listen(&channel)
+                        .await
+                        .map_err(DatabaseError::Postgres)?,
+                    Some(ListenCommand::Unlisten(channel)) => listener
+                        .unlisten(&channel)
+                        .await
+                        .map_err(DatabaseError::Postgres)?,
+                    None => return Ok(()),
+                }
+            }
+            notification = listener.recv() => {
+                let notification = notification.map_err(DatabaseError::Postgres)?;
+                on_notification(ServerNotification {
+                    channel: notification.channel().to_string(),
+                    payload: notification.payload().to_string(),
+                    pid: notification.process_id() as i32,
+                });
+            }
+        }
+    }
+}
+
+/// Sends `payload` to every session listening on `channel`.
+pub async fn send_notification(
+    connection: DatabaseConnection,
+    channel: String,
+    payload: String,
+) -> Result<(), DatabaseError> {
+    let DatabaseConnection::Postgres(pool) = connection else {
+        return Err(postgres_only("LISTEN/NOTIFY"));
+    };
+
+    // `pg_notify` takes the channel as a value, so it needs no quoting.
+    sqlx::query("select pg_notify($1, $2)")
+        .bind(channel.trim())
+        .bind(payload)
+        .execute(&pool)
+        .await
+        .map_err(DatabaseError::Postgres)?;
+    Ok(())
+}
@@ -0,0 +1,408 @@
+use std::ops::Range;
+
+use models::{DatabaseConnection, DatabaseError, QueryOutput};
+use sqlx::{Either, Executor, PgConnection, Postgres, TypeInfo};
+
+use super::monitor::postgres_only;
+use super::{TimedConnection, execute_postgres_query_page, statement_timeout_applies};
+
+/// A parameter as it appears in the SQL: its byte range and its name as
+/// written, `:user_id` or `$1`.
+struct ParameterToken {
+    range: Range<usize>,
+    name: String,
+}
+
+/// Named (`:user_id`) and positional (`$1`) parameters in `sql`, each once,
+/// in order of first use. Literals, quoted identifiers, comments, `::` casts
+/// and array slices such as `[1:n]` are skipped.
+pub fn find_query_parameters(sql: &str) -> Vec<String> {
+    let mut names = Vec::new();
+    for token in parameter_tokens(sql) {
+        if !names.contains(&token.name) {
+            names.push(token.name);
+        }
+    }
+    names
+}
+
+/// The value entered for a query parameter, and the type chosen to cast it
+/// to. Without a type the one PostgreSQL infers is used; a value of `None`
+/// binds NULL.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct QueryParameterValue {
+    pub name: String,
+    pub value: Option<String>,
+    pub type_name: Option<String>,
+}
+
+/// Runs `sql` on PostgreSQL with its parameters bound rather than written
+/// into the text, returning the first `page_size` rows as
+/// [`execute_query_page`](super::execute_query_page) would, with
+/// `timeout_secs` applied as
+/// [`execute_query_page_with_timeout`](super::execute_query_page_with_timeout)
+/// does.
+pub async fn execute_query_with_parameters(
+    connection: DatabaseConnection,
+    sql: String,
+    parameters: Vec<QueryParameterValue>,
+    page_size: u32,
+    timeout_secs: Option<u32>,
+) -> Result<QueryOutput, DatabaseError> {
+    let timeout_secs = timeout_secs.filter(|_| statement_timeout_applies(&connection, &sql));
+    let DatabaseConnection::Postgres(pool) = connection else {
+        return Err(postgres_only("Query parameters"));
+    };
+
+    let mut timed = TimedConnection::acquire(&pool, timeout_secs).await?;
+    let output =
+        execute_postgres_query_with_parameters(&mut timed.connection, &sql, &parameters, page_size)
+            .await;
+    timed.release().await;
+    output
+}
+
+pub(super) async fn execute_postgres_query_with_parameters(
+    connection: &mut PgConnection,
+    sql: &str,
+    parameters: &[QueryParameterValue],
+    page_size: u32,
+) -> Result<QueryOutput, DatabaseError> {
+    let (positional, _) = positional_sql(sql);
+    let types = described_parameter_types(&mut *connection, &positional).await?;
+    let (bound, binds) = bound_sql(sql, parameters, &types);
+    execute_postgres_query_page(&bound, &binds, &mut *connection, page_size, 0, None, None).await
+}
+
+/// `sql` with every parameter rewritten to `$N` and cast to the type picked
+/// for it, or else the one PostgreSQL inferred, as in `$1::date`: values are
+/// sent as text, and the cast turns them into what the query expects. Also
+/// returns the value to bind to each `$N`, in order; a position nothing was
+/// entered for binds NULL. A type name that is not one is ignored.
+fn bound_sql(
+    sql: &str,
+    values: &[QueryParameterValue],
+    inferred: &[Option<String>],
+) -> (String, Vec<Option<String>>) {
+    let (_, positions) = positional_sql(sql);
+    let value_for = |name: &str| values.iter().find(|value| value.name == name);
+
+    let mut bound = String::with_capacity(sql.len());
+    let mut cursor = 0;
+    for token in parameter_tokens(sql) {
+        let Some(position) = positions.iter().position(|name| *name == token.name) else {
+            continue;
+        };
+        bound.push_str(&sql[cursor..token.range.start]);
+        bound.push_str(&format!("${}", position + 1));
+        let chosen = value_for(&token.name).and_then(|value| value.type_name.as_deref());
+        let type_name = [chosen, inferred.get(position).and_then(Option::as_deref)]
+            .into_iter()
+            .flatten()
+            .map(str::trim)
+            .find(|type_name| is_type_name(type_name));
+        if let Some(type_name) = type_name {
+            bound.push_str("::");
+            bound.push_str(type_name);
+        }
+        cursor = token.range.end;
+    }
+    bound.push_str(&sql[cursor..]);
+
+    let binds = positions
+        .iter()
+        .map(|name| value_for(name).and_then(|value| value.value.clone()))
+        .collect();
+    (bound, binds)
+}
+
+/// The type PostgreSQL infers for each of [`find_query_parameters`], in the
+/// same order, or `None` where it cannot tell. Named parameters are rewritten
+/// to positional ones first, since the server only understands `$N`. Other
+/// drivers have no way to ask and get `None` for every parameter.
+pub async fn describe_query_parameters(
+    connection: DatabaseConnection,
+    sql: String,
+) -> Result<Vec<Option<String>>, DatabaseError> {
+    let names = find_query_parameters(&sql);
+    let DatabaseConnection::Postgres(pool) = connection else {
+        return Ok(vec![None; names.len()]);
+    };
+
+    let (positional, positions) = positional_sql(&sql);
+    let types = described_parameter_types(&pool, &positional).await?;
+
+    Ok(names
+        .iter()
+        .map(|name| {
+            let position = positions.iter().position(|candidate| candidate == name)?;
+            types.get(position).cloned().flatten()
+        })
+        .collect())
+}
+
+/// The type PostgreSQL infers for each `$N` of `positional`, in order.
+async fn described_parameter_types<'c, E>(
+    executor: E,
+    positional: &str,
+) -> Result<Vec<Option<String>>, DatabaseError>
+where
+    E: Executor<'c, Database = Postgres>,
+{
+    let described = executor
+        .describe(positional)
+        .await
+        .map_err(DatabaseError::Postgres)?;
+    Ok(match described.parameters() {
+        Some(Either::Left(types)) => types
+            .iter()
+            .map(|type_info| Some(type_info.name().to_lowercase()))
+            .collect(),
+        _ => Vec::new(),
+    })
+}
+
+/// `sql` with each named parameter replaced by a positional one, numbered
+/// after the highest `$N` the query already uses, and the parameter each
+/// position stands for (`positions[0]` is `$1`).
+fn positional_sql(sql: &str) -> (String, Vec<String>) {
+    let tokens = parameter_tokens(sql);
+    let highest = tokens
+        .iter()
+        .filter_map(|token| token.name.strip_prefix('$')?.parse::<usize>().ok())
+        .max()
+        .unwrap_or(0);
+    let mut positions = (1..=highest).map(|n| format!("${n}")).collect::<Vec<_>>();
+
+    let mut rewritten = String::with_capacity(sql.len());
+    let mut cursor = 0;
+    for token in tokens.iter().filter(|token| token.name.starts_with(':')) {
+        let position = match positions.iter().position(|name| *name == token.name) {
+            Some(position) => position,
+            None => {
+                positions.push(token.name.clone());
+                positions.len() - 1
+            }
+        };
+        rewritten.push_str(&sql[cursor..token.range.start]);
+        rewritten.push_str(&format!("${}", position + 1));
+        cursor = token.range.end;
+    }
+    rewritten.push_str(&sql[cursor..]);
+    (rewritten, positions)
+}
+
+fn parameter_tokens(sql: &str) -> Vec<ParameterToken> {
+    let bytes = sql.as_bytes();
+    let mut tokens = Vec::new();
+    let mut bracket_depth = 0_usize;
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let previous = index.checked_sub(1).map(|previous| bytes[previous]);
+        match bytes[index] {
+            b'\'' => {
+                let escapes = matches!(previous, Some(b'e' | b'E'))
+                    && (index < 2 || !is_identifier_byte(bytes[index - 2]));
+                index = quoted_end(bytes, index, b'\'', escapes);
+            }
+            b'"' => index = quoted_end(bytes, index, b'"', false),
+            b'-' if bytes.get(index + 1) == Some(&b'-') => {
+                index = sql[index..]
+                    .find('\n')
+                    .map_or(bytes.len(), |offset| index + offset + 1);
+            }
+            b'/' if bytes.get(index + 1) == Some(&b'*') => index = block_comment_end(bytes, index),
+            b'[' => {
+                bracket_depth += 1;
+                index += 1;
+            }
+            b']' => {
+                bracket_depth = bracket_depth.saturating_sub(1);
+                index += 1;
+            }
+            b':' if bytes.get(index + 1) == Some(&b':') => index += 2,
+            b':' if bracket_depth == 0
+                && bytes
+                    .get(index + 1)
+                    .is_some_and(|next| next.is_ascii_alphabetic() || *next == b'_') =>
+            {
+                let end = identifier_end(bytes, index + 1);
+                tokens.push(ParameterToken {
+                    range: index..end,
+                    name: sql[index..end].to_string(),
+                });
+                index = end;
+            }
+            b'$' if previous.is_none_or(|previous| !is_identifier_byte(previous)) => {
+                let digits = bytes[index + 1..]
+                    .iter()
+                    .take_while(|byte| byte.is_ascii_digit())
+                    .count();
+                if digits > 0 {
+                    let end = index + 1 + digits;
+                    tokens.push(ParameterToken {
+                        range: index..end,
+                        name: sql[index..end].to_string(),
+                    });
+                    index = end;
+                } else {
+                    index = dollar_quoted_end(sql, index).unwrap_or(index + 1);
+                }
+            }
+            _ => index += 1,
+        }
+    }
+
+    tokens
+}
+
+/// End of the literal or quoted identifier opened by `quote` at `start`.
+/// Doubled quotes stay inside it, as do backslash escapes in `E'...'`.
+fn quoted_end(bytes: &[u8], start: usize, quote: u8, escapes: bool) -> usize {
+    let mut index = start + 1;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'\\' if escapes => index += 2,
+            byte if byte == quote => {
+                if bytes.get(index + 1) == Some(&quote) {
+                    index += 2;
+                } else {
+                    return index + 1;
+                }
+            }
+            _ => index += 1,
+        }
+    }
+    bytes.len()
+}
+
+/// End of the `/* */` comment at `start`. PostgreSQL lets them nest.
+fn block_comment_end(bytes: &[u8], start: usize) -> usize {
+    let mut depth = 0_usize;
+    let mut index = start;
+    while index + 1 < bytes.len() {
+        match (bytes[index], bytes[index + 1]) {
+            (b'/', b'*') => {
+                depth += 1;
+                index += 2;
+            }
+            (b'*', b'/') => {
+                depth -= 1;
+                index += 2;
+                if depth == 0 {
+                    return index;
+                }
+            }
+            _ => index += 1,
+        }
+    }
+    bytes.len()
+}
+
+/// End of the `$tag$ ... $tag$` literal at `start`, if a valid tag opens one
+/// there.
+pub(super) fn dollar_quoted_end(sql: &str, start: usize) -> Option<usize> {
+    let bytes = sql.as_bytes();
+    let tag_end = start + 1 + bytes[start + 1..].iter().position(|byte| *byte == b'$')?;
+    let name = &bytes[start + 1..tag_end];
+    let valid = name.first().is_none_or(|first| !first.is_ascii_digit())
+        && name.iter().copied().all(is_identifier_byte);
+    if !valid {
+        return None;
+    }
+    let tag = &sql[start..=tag_end];
+    Some(
+        sql[tag_end + 1..]
+            .find(tag)
+            .map_or(bytes.len(), |offset| tag_end + 1 + offset + tag.len()),
+    )
+}
+
+/// Whether `value` can follow `::` as written: a type name such as
+/// `timestamp with time zone`, `numeric(10, 2)` or `public.mood[]`, and
+/// nothing that could end the cast.
+fn is_type_name(value: &str) -> bool {
+    value
+        .chars()
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+        && value.chars().all(|ch| {
+            ch.is_ascii_alphanumeric()
+                || matches!(ch, '_' | ' ' | '.' | ',' | '(' | ')' | '[' | ']')
+        })
+}
+
+fn identifier_end(bytes: &[u8], start: usize) -> usize {
+    start
+        + bytes[start..]
+            .iter()
+            .take_while(|byte| is_identifier_byte(**byte))
+            .count()
+}
+
+pub(super) fn is_identifier_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'_' || !byte.is_ascii()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{QueryParameterValue, bound_sql, find_query_parameters, positional_sql};
+
+    fn value(name: &str, value: Option<&str>, type_name: Option<&str>) -> QueryParameterValue {
+        QueryParameterValue {
+            name: name.to_string(),
+            value: value.map(str::to_string),
+            type_name: type_name.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn finds_named_and_positional_parameters_outside_literals() {
+        let sql = "select id::text, tags[1:2], $1 from users /* :skip $9 */\n\
+                   where id = :user_id and name <> 'a :quoted $2' -- :commented\n\
+                   and body = $tag$ :body $3 $tag$ and e = E'it\\'s :not' and \":col\" = :user_id";
+        assert_eq!(find_query_parameters(sql), vec!["$1", ":user_id"]);
+    }
+
+    #[test]
+    fn named_parameters_become_positional_after_existing_ones() {
+        let (sql, positions) = positional_sql("select * from t where a = :a and b = $2 or c = :a");
+        assert_eq!(sql, "select * from t where a = $3 and b = $2 or c = $3");
+        assert_eq!(positions, vec!["$1", "$2", ":a"]);
+    }
+
+    #[test]
+    fn binding_sends_values_apart_from_the_sql() {
+        let values = vec![
+            value(":name", Some("O'Brien"), None),
+            value("$1", Some("null"), None),
+            value(":gone", None, None),
+        ];
+        let (sql, binds) = bound_sql("select :name, $1, :gone, ':name'", &values, &[]);
+        assert_eq!(sql, "select $2, $1, $3, ':name'");
+        assert_eq!(
+            binds,
+            vec![Some("null".to_string()), Some("O'Brien".to_string()), None]
+        );
+    }
+
+    #[test]
+    fn binding_casts_to_the_chosen_or_inferred_type() {
+        let values = vec![
+            value(":day", Some("2024-01-01"), Some("date")),
+            value(":ids", Some("{1,2}"), Some(" bigint[] ")),
+            value(":id", Some("7"), None),
+            value(":bad", Some("1"), Some("int; drop table users")),
+        ];
+        let inferred = [
+            Some("text".to_string()),
+            None,
+            Some("int4".to_string()),
+            None,
+        ];
+        let (sql, binds) = bound_sql("select :day, :ids, :id, :bad, :day", &values, &inferred);
+        assert_eq!(sql, "select $1::date, $2::bigint[], $3::int4, $4, $1::date");
+        assert_eq!(binds.len(), 4);
+    }
+}
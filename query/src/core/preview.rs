@@ -6,13 +6,14 @@ use models::{
 
 use super::rows::{
     mysql_preview_rows_to_paginated_page, mysql_rows_to_paginated_page,
-    postgres_preview_rows_to_paginated_page, sqlite_preview_rows_to_paginated_page,
+    postgres_preview_rows_to_paginated_page, postgres_rows_to_paginated_page,
+    sqlite_preview_rows_to_paginated_page, sqlite_rows_to_paginated_page,
 };
 use super::{
     CLICKHOUSE_DIALECT, LOCATOR_COLUMN, MYSQL_DIALECT, POSTGRES_DIALECT, SQLITE_DIALECT,
     build_clickhouse_locator, build_outer_paginated_query, clickhouse_get_primary_key_columns,
     clickhouse_json_value_to_string, mysql_effective_schema_name, mysql_locator_expression,
-    mysql_primary_key_columns, quote_identifier_clickhouse,
+    mysql_primary_key_columns, quote_identifier, quote_identifier_clickhouse,
 };
 
 pub async fn load_table_preview_page(
@@ -25,91 +26,25 @@ pub async fn load_table_preview_page(
 ) -> Result<QueryOutput, DatabaseError> {
     match connection {
         DatabaseConnection::Sqlite(pool) => {
-            let sql = build_outer_paginated_query(
-                format!(
-                    r#"select rowid as "{LOCATOR_COLUMN}", * from {}"#,
-                    source.qualified_name
-                ),
-                page_size,
-                offset,
-                filter.as_ref(),
-                sort.as_ref(),
-                SQLITE_DIALECT,
-            );
-            let rows = sqlx::query(&sql)
-                .fetch_all(&pool)
+            let mut connection = pool.acquire().await.map_err(DatabaseError::Sqlite)?;
+            load_sqlite_table_preview_page(&mut connection, source, page_size, offset, filter, sort)
                 .await
-                .map_err(DatabaseError::Sqlite)?;
-            Ok(QueryOutput::Table(sqlite_preview_rows_to_paginated_page(
-                rows, source, page_size, offset,
-            )))
         }
         DatabaseConnection::Postgres(pool) => {
-            let sql = build_outer_paginated_query(
-                format!(
-                    r#"select ctid::text as "{LOCATOR_COLUMN}", * from {}"#,
-                    source.qualified_name
-                ),
+            let mut connection = pool.acquire().await.map_err(DatabaseError::Postgres)?;
+            load_postgres_table_preview_page(
+                &mut connection,
+                source,
                 page_size,
                 offset,
-                filter.as_ref(),
-                sort.as_ref(),
-                POSTGRES_DIALECT,
-            );
-            let rows = sqlx::query(&sql)
-                .fetch_all(&pool)
-                .await
-                .map_err(DatabaseError::Postgres)?;
-            Ok(QueryOutput::Table(postgres_preview_rows_to_paginated_page(
-                rows, source, page_size, offset,
-            )))
+                filter,
+                sort,
+            )
+            .await
         }
         DatabaseConnection::MySql(pool) => {
-            let schema_name = mysql_effective_schema_name(&pool, source.schema.as_deref()).await?;
-            let primary_key_columns =
-                mysql_primary_key_columns(&pool, &schema_name, &source.table_name).await?;
-
-            if primary_key_columns.is_empty() {
-                let sql = build_outer_paginated_query(
-                    format!(r#"select * from {}"#, source.qualified_name),
-                    page_size,
-                    offset,
-                    filter.as_ref(),
-                    sort.as_ref(),
-                    MYSQL_DIALECT,
-                );
-                let rows = sqlx::query(&sql)
-                    .fetch_all(&pool)
-                    .await
-                    .map_err(DatabaseError::MySql)?;
-                Ok(QueryOutput::Table(mysql_rows_to_paginated_page(
-                    rows, page_size, offset,
-                )))
-            } else {
-                let locator_expr = mysql_locator_expression(&primary_key_columns);
-                let sql = build_outer_paginated_query(
-                    format!(
-                        r#"select {locator_expr} as "{LOCATOR_COLUMN}", * from {}"#,
-                        source.qualified_name
-                    ),
-                    page_size,
-                    offset,
-                    filter.as_ref(),
-                    sort.as_ref(),
-                    MYSQL_DIALECT,
-                );
-                let rows = sqlx::query(&sql)
-                    .fetch_all(&pool)
-                    .await
-                    .map_err(DatabaseError::MySql)?;
-                let source = models::TablePreviewSource {
-                    schema: Some(schema_name),
-                    ..source
-                };
-                Ok(QueryOutput::Table(mysql_preview_rows_to_paginated_page(
-                    rows, source, page_size, offset,
-                )))
-            }
+            load_mysql_table_preview_page(&pool, &pool, source, page_size, offset, filter, sort)
+                .await
         }
         DatabaseConnection::ClickHouse(config) => {
             let schema_name = source
@@ -199,3 +134,189 @@ pub async fn load_table_preview_page(
         }
     }
 }
+
+pub(super) async fn load_sqlite_table_preview_page(
+    connection: &mut sqlx::SqliteConnection,
+    source: TablePreviewSource,
+    page_size: u32,
+    offset: u64,
+    filter: Option<QueryFilter>,
+    sort: Option<QuerySort>,
+) -> Result<QueryOutput, DatabaseError> {
+    if sqlite_relation_is_view(&mut *connection, &source).await? {
+        let sql = build_outer_paginated_query(
+            format!("select * from {}", source.qualified_name),
+            page_size,
+            offset,
+            filter.as_ref(),
+            sort.as_ref(),
+            SQLITE_DIALECT,
+        );
+        let rows = sqlx::query(&sql)
+            .fetch_all(&mut *connection)
+            .await
+            .map_err(DatabaseError::Sqlite)?;
+        return Ok(QueryOutput::Table(sqlite_rows_to_paginated_page(
+            rows, page_size, offset,
+        )));
+    }
+
+    let sql = build_outer_paginated_query(
+        format!(
+            r#"select rowid as "{LOCATOR_COLUMN}", * from {}"#,
+            source.qualified_name
+        ),
+        page_size,
+        offset,
+        filter.as_ref(),
+        sort.as_ref(),
+        SQLITE_DIALECT,
+    );
+    let rows = sqlx::query(&sql)
+        .fetch_all(&mut *connection)
+        .await
+        .map_err(DatabaseError::Sqlite)?;
+    Ok(QueryOutput::Table(sqlite_preview_rows_to_paginated_page(
+        rows, source, page_size, offset,
+    )))
+}
+
+/// Views have no `rowid` to locate rows by, so they are previewed read-only.
+async fn sqlite_relation_is_view(
+    connection: &mut sqlx::SqliteConnection,
+    source: &TablePreviewSource,
+) -> Result<bool, DatabaseError> {
+    let schema = source
+        .schema
+        .as_deref()
+        .map_or_else(|| "main".to_string(), quote_identifier);
+    let kind = sqlx::query_scalar::<_, String>(&format!(
+        "select type from {schema}.sqlite_master where name = ?"
+    ))
+    .bind(&source.table_name)
+    .fetch_optional(connection)
+    .await
+    .map_err(DatabaseError::Sqlite)?;
+    Ok(kind.as_deref() == Some("view"))
+}
+
+pub(super) async fn load_postgres_table_preview_page(
+    connection: &mut sqlx::PgConnection,
+    source: TablePreviewSource,
+    page_size: u32,
+    offset: u64,
+    filter: Option<QueryFilter>,
+    sort: Option<QuerySort>,
+) -> Result<QueryOutput, DatabaseError> {
+    if !postgres_relation_has_ctid(&mut *connection, &source.qualified_name).await? {
+        let sql = build_outer_paginated_query(
+            format!("select * from {}", source.qualified_name),
+            page_size,
+            offset,
+            filter.as_ref(),
+            sort.as_ref(),
+            POSTGRES_DIALECT,
+        );
+        let rows = sqlx::query(&sql)
+            .fetch_all(&mut *connection)
+            .await
+            .map_err(DatabaseError::Postgres)?;
+        return Ok(QueryOutput::Table(postgres_rows_to_paginated_page(
+            rows, page_size, offset,
+        )));
+    }
+
+    let sql = build_outer_paginated_query(
+        format!(
+            r#"select ctid::text as "{LOCATOR_COLUMN}", * from {}"#,
+            source.qualified_name
+        ),
+        page_size,
+        offset,
+        filter.as_ref(),
+        sort.as_ref(),
+        POSTGRES_DIALECT,
+    );
+    let rows = sqlx::query(&sql)
+        .fetch_all(&mut *connection)
+        .await
+        .map_err(DatabaseError::Postgres)?;
+    Ok(QueryOutput::Table(postgres_preview_rows_to_paginated_page(
+        rows, source, page_size, offset,
+    )))
+}
+
+/// Views, materialized views and foreign tables have no usable `ctid`, so
+/// they preview through a plain `select *` with cell editing disabled.
+async fn postgres_relation_has_ctid(
+    connection: &mut sqlx::PgConnection,
+    qualified_name: &str,
+) -> Result<bool, DatabaseError> {
+    let relkind = sqlx::query_scalar::<_, String>(
+        "select relkind::text from pg_catalog.pg_class where oid = to_regclass($1)",
+    )
+    .bind(qualified_name)
+    .fetch_optional(connection)
+    .await
+    .map_err(DatabaseError::Postgres)?;
+    Ok(!matches!(relkind.as_deref(), Some("v" | "m" | "f")))
+}
+
+pub(super) async fn load_mysql_table_preview_page<'c, E>(
+    pool: &sqlx::MySqlPool,
+    executor: E,
+    source: TablePreviewSource,
+    page_size: u32,
+    offset: u64,
+    filter: Option<QueryFilter>,
+    sort: Option<QuerySort>,
+) -> Result<QueryOutput, DatabaseError>
+where
+    E: sqlx::Executor<'c, Database = sqlx::MySql>,
+{
+    let schema_name = mysql_effective_schema_name(pool, source.schema.as_deref()).await?;
+    let primary_key_columns =
+        mysql_primary_key_columns(pool, &schema_name, &source.table_name).await?;
+
+    if primary_key_columns.is_empty() {
+        let sql = build_outer_paginated_query(
+            format!(r#"select * from {}"#, source.qualified_name),
+            page_size,
+            offset,
+            filter.as_ref(),
+            sort.as_ref(),
+            MYSQL_DIALECT,
+        );
+        let rows = sqlx::query(&sql)
+            .fetch_all(executor)
+            .await
+            .map_err(DatabaseError::MySql)?;
+        Ok(QueryOutput::Table(mysql_rows_to_paginated_page(
+            rows, page_size, offset,
+        )))
+    } else {
+        let locator_expr = mysql_locator_expression(&primary_key_columns);
+        let sql = build_outer_paginated_query(
+            format!(
+                r#"select {locator_expr} as "{LOCATOR_COLUMN}", * from {}"#,
+                source.qualified_name
+            ),
+            page_size,
+            offset,
+            filter.as_ref(),
+            sort.as_ref(),
+            MYSQL_DIALECT,
+        );
+        let rows = sqlx::query(&sql)
+            .fetch_all(executor)
+            .await
+            .map_err(DatabaseError::MySql)?;
+        let source = models::TablePreviewSource {
+            schema: Some(schema_name),
+            ..source
+        };
+        Ok(QueryOutput::Table(mysql_preview_rows_to_paginated_page(
+            rows, source, page_size, offset,
+        )))
+    }
+}
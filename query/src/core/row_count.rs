@@ -0,0 +1,212 @@
+use models::{
+    DatabaseConnection, DatabaseError, QueryFilter, QueryOutput, RowCountMode, TablePreviewSource,
+    TableRowCount,
+};
+
+use super::{
+    CLICKHOUSE_DIALECT, MYSQL_DIALECT, POSTGRES_DIALECT, SQLITE_DIALECT,
+    build::{build_count_query, has_filter_conditions},
+    execute_query,
+};
+
+/// Count the rows behind a table preview according to `mode`.
+///
+/// Estimates come from planner statistics (`pg_class.reltuples`,
+/// `information_schema.tables.table_rows`, `system.tables.total_rows`) and are
+/// only used without a filter. SQLite keeps no such statistics, and a
+/// PostgreSQL table that was never analyzed (or a view, which has no storage)
+/// reports none, so both fall back to an exact count.
+pub async fn load_table_row_count(
+    connection: DatabaseConnection,
+    source: TablePreviewSource,
+    filter: Option<QueryFilter>,
+    mode: RowCountMode,
+) -> Result<Option<TableRowCount>, DatabaseError> {
+    match mode {
+        RowCountMode::Off => return Ok(None),
+        RowCountMode::Estimate if has_filter_conditions(filter.as_ref()) => return Ok(None),
+        RowCountMode::Estimate => {
+            if let Some(sql) = build_row_estimate_sql(&connection, &source)
+                && let Some(rows) = query_count(connection.clone(), sql).await?
+            {
+                return Ok(Some(TableRowCount {
+                    rows,
+                    estimated: true,
+                }));
+            }
+        }
+        RowCountMode::Exact => {}
+    }
+
+    let dialect = match &connection {
+        DatabaseConnection::Sqlite(_) => SQLITE_DIALECT,
+        DatabaseConnection::Postgres(_) => POSTGRES_DIALECT,
+        DatabaseConnection::MySql(_) => MYSQL_DIALECT,
+        DatabaseConnection::ClickHouse(_) => CLICKHOUSE_DIALECT,
+    };
+    let sql = build_count_query(&source.qualified_name, filter.as_ref(), dialect);
+    Ok(query_count(connection, sql)
+        .await?
+        .map(|rows| TableRowCount {
+            rows,
+            estimated: false,
+        }))
+}
+
+fn build_row_estimate_sql(
+    connection: &DatabaseConnection,
+    source: &TablePreviewSource,
+) -> Option<String> {
+    let table = string_literal(&source.table_name);
+    let schema = |fallback: &str| {
+        source
+            .schema
+            .as_deref()
+            .map(string_literal)
+            .unwrap_or_else(|| fallback.to_string())
+    };
+
+    match connection {
+        DatabaseConnection::Sqlite(_) => None,
+        DatabaseConnection::Postgres(_) => Some(format!(
+            "select c.reltuples::bigint from pg_catalog.pg_class c join pg_catalog.pg_namespace n on n.oid = c.relnamespace where n.nspname = {} and c.relname = {table} and c.relkind in ('r', 'p', 'm')",
+            schema("current_schema()")
+        )),
+        DatabaseConnection::MySql(_) => Some(format!(
+            "select table_rows from information_schema.tables where table_schema = {} and table_name = {table}",
+            schema("database()")
+        )),
+        DatabaseConnection::ClickHouse(_) => Some(format!(
+            "select total_rows from system.tables where database = {} and name = {table}",
+            schema("currentDatabase()")
+        )),
+    }
+}
+
+/// Run a single-value count query; missing, NULL or negative values (e.g.
+/// `reltuples = -1` before the first ANALYZE) yield `None`.
+async fn query_count(
+    connection: DatabaseConnection,
+    sql: String,
+) -> Result<Option<u64>, DatabaseError> {
+    let QueryOutput::Table(page) = execute_query(connection, sql).await? else {
+        return Ok(None);
+    };
+    Ok(page
+        .rows
+        .first()
+        .and_then(|row| row.first())
+        .and_then(|value| value.trim().parse::<f64>().ok())
+        .filter(|rows| *rows >= 0.0)
+        .map(|rows| rows as u64))
+}
+
+fn string_literal(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::load_table_row_count;
+    use models::{
+        DatabaseConnection, QueryFilter, QueryFilterMode, QueryFilterOperator, QueryFilterRule,
+        RowCountMode, TablePreviewSource, TableRowCount,
+    };
+    use sqlx::SqlitePool;
+
+    async fn seeded_pool() -> SqlitePool {
+        let pool = SqlitePool::connect(":memory:").await.unwrap();
+        sqlx::query("create table items (id integer primary key, name text)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("insert into items (name) values ('a'), ('b'), ('b')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        pool
+    }
+
+    fn items() -> TablePreviewSource {
+        TablePreviewSource {
+            schema: None,
+            table_name: "items".to_string(),
+            qualified_name: r#""items""#.to_string(),
+        }
+    }
+
+    fn name_filter(value: &str) -> QueryFilter {
+        QueryFilter {
+            mode: QueryFilterMode::And,
+            rules: vec![QueryFilterRule {
+                column_name: "name".to_string(),
+                operator: QueryFilterOperator::Equals,
+                value: value.to_string(),
+            }],
+        }
+    }
+
+    #[tokio::test]
+    async fn exact_mode_counts_filtered_rows() {
+        let connection = DatabaseConnection::Sqlite(seeded_pool().await);
+
+        let count = load_table_row_count(
+            connection,
+            items(),
+            Some(name_filter("b")),
+            RowCountMode::Exact,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            count,
+            Some(TableRowCount {
+                rows: 2,
+                estimated: false,
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn estimate_mode_falls_back_to_exact_count_on_sqlite() {
+        let connection = DatabaseConnection::Sqlite(seeded_pool().await);
+
+        let count = load_table_row_count(connection, items(), None, RowCountMode::Estimate)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            count,
+            Some(TableRowCount {
+                rows: 3,
+                estimated: false,
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn estimate_mode_skips_filtered_previews_and_off_skips_everything() {
+        let pool = seeded_pool().await;
+
+        let filtered = load_table_row_count(
+            DatabaseConnection::Sqlite(pool.clone()),
+            items(),
+            Some(name_filter("a")),
+            RowCountMode::Estimate,
+        )
+        .await
+        .unwrap();
+        let off = load_table_row_count(
+            DatabaseConnection::Sqlite(pool),
+            items(),
+            None,
+            RowCountMode::Off,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(filtered, None);
+        assert_eq!(off, None);
+    }
+}
@@ -1,6 +1,10 @@
-use models::{DatabaseError, EditableTableContext, QueryPage, TablePreviewSource};
+use models::{DatabaseError, EditableTableContext, InsertedRow, QueryPage, TablePreviewSource};
 use sqlx::{Column, Row, TypeInfo};
 
+use super::arrays::format_postgres_array;
+use super::bytea::format_bytea;
+use super::hstore::format_postgres_hstore;
+
 pub(crate) fn sqlite_rows_to_page(rows: Vec<sqlx::sqlite::SqliteRow>) -> QueryPage {
     let columns = rows
         .first()
@@ -206,6 +210,14 @@ pub(super) fn sqlite_preview_rows_to_paginated_page(
                 .unwrap_or_default()
         })
         .collect::<Vec<_>>();
+    // Views report a NULL rowid, which leaves nothing to address edits with.
+    let editable = row_locators
+        .iter()
+        .all(|locator| !locator.is_empty())
+        .then_some(EditableTableContext {
+            source,
+            row_locators,
+        });
     let rows = rows
         .into_iter()
         .map(|row| {
@@ -218,10 +230,7 @@ pub(super) fn sqlite_preview_rows_to_paginated_page(
     QueryPage {
         columns,
         rows,
-        editable: Some(EditableTableContext {
-            source,
-            row_locators,
-        }),
+        editable,
         offset,
         page_size,
         has_previous: offset > 0,
@@ -323,6 +332,48 @@ pub(super) fn mysql_preview_rows_to_paginated_page(
     }
 }
 
+pub(super) fn sqlite_inserted_row(row: &sqlx::sqlite::SqliteRow) -> InsertedRow {
+    InsertedRow {
+        locator: row
+            .try_get::<i64, _>(0)
+            .map(|v| v.to_string())
+            .unwrap_or_default(),
+        columns: inserted_row_columns(row.columns()),
+        values: (1..row.columns().len())
+            .map(|idx| sqlite_cell_to_string(row, idx))
+            .collect(),
+    }
+}
+
+pub(super) fn postgres_inserted_row(row: &sqlx::postgres::PgRow) -> InsertedRow {
+    InsertedRow {
+        locator: row.try_get::<String, _>(0).unwrap_or_default(),
+        columns: inserted_row_columns(row.columns()),
+        values: (1..row.columns().len())
+            .map(|idx| postgres_cell_to_string(row, idx))
+            .collect(),
+    }
+}
+
+pub(super) fn mysql_inserted_row(row: &sqlx::mysql::MySqlRow) -> InsertedRow {
+    InsertedRow {
+        locator: mysql_locator_to_string(row, 0),
+        columns: inserted_row_columns(row.columns()),
+        values: (1..row.columns().len())
+            .map(|idx| mysql_cell_to_string(row, idx))
+            .collect(),
+    }
+}
+
+/// Column names after the leading locator column.
+fn inserted_row_columns<C: Column>(columns: &[C]) -> Vec<String> {
+    columns
+        .iter()
+        .skip(1)
+        .map(|c| c.name().to_string())
+        .collect()
+}
+
 fn sqlite_cell_to_string(row: &sqlx::sqlite::SqliteRow, idx: usize) -> String {
     if let Ok(value) = row.try_get::<Option<String>, _>(idx) {
         return value.unwrap_or_else(|| "NULL".to_string());
@@ -402,7 +453,7 @@ fn postgres_cell_to_string(row: &sqlx::postgres::PgRow, idx: usize) -> String {
     }
     if let Ok(value) = row.try_get::<Option<Vec<u8>>, _>(idx) {
         return value
-            .map(|bytes| format!("<{} bytes>", bytes.len()))
+            .map(|bytes| format_bytea(&bytes))
             .unwrap_or_else(|| "NULL".to_string());
     }
     if let Ok(value) = row.try_get::<Option<uuid::Uuid>, _>(idx) {
@@ -440,34 +491,39 @@ fn postgres_cell_to_string(row: &sqlx::postgres::PgRow, idx: usize) -> String {
             .map(|value| value.to_string())
             .unwrap_or_else(|| "NULL".to_string());
     }
-    if let Ok(value) = row.try_get::<Option<Vec<String>>, _>(idx) {
+    if let Ok(value) = row.try_get::<Option<Vec<Option<String>>>, _>(idx) {
         return value
-            .map(format_array)
+            .map(postgres_array_to_string)
             .unwrap_or_else(|| "NULL".to_string());
     }
-    if let Ok(value) = row.try_get::<Option<Vec<i32>>, _>(idx) {
+    if let Ok(value) = row.try_get::<Option<Vec<Option<i32>>>, _>(idx) {
         return value
-            .map(format_array)
+            .map(postgres_array_to_string)
             .unwrap_or_else(|| "NULL".to_string());
     }
-    if let Ok(value) = row.try_get::<Option<Vec<i64>>, _>(idx) {
+    if let Ok(value) = row.try_get::<Option<Vec<Option<i64>>>, _>(idx) {
         return value
-            .map(format_array)
+            .map(postgres_array_to_string)
             .unwrap_or_else(|| "NULL".to_string());
     }
-    if let Ok(value) = row.try_get::<Option<Vec<f64>>, _>(idx) {
+    if let Ok(value) = row.try_get::<Option<Vec<Option<f64>>>, _>(idx) {
         return value
-            .map(format_array)
+            .map(postgres_array_to_string)
             .unwrap_or_else(|| "NULL".to_string());
     }
-    if let Ok(value) = row.try_get::<Option<Vec<bool>>, _>(idx) {
+    if let Ok(value) = row.try_get::<Option<Vec<Option<bool>>>, _>(idx) {
         return value
-            .map(format_array)
+            .map(postgres_array_to_string)
             .unwrap_or_else(|| "NULL".to_string());
     }
-    if let Ok(value) = row.try_get::<Option<Vec<uuid::Uuid>>, _>(idx) {
+    if let Ok(value) = row.try_get::<Option<Vec<Option<uuid::Uuid>>>, _>(idx) {
         return value
-            .map(format_array)
+            .map(postgres_array_to_string)
+            .unwrap_or_else(|| "NULL".to_string());
+    }
+    if let Ok(value) = row.try_get::<Option<sqlx::postgres::types::PgHstore>, _>(idx) {
+        return value
+            .map(|value| format_postgres_hstore(&value.0.into_iter().collect::<Vec<_>>()))
             .unwrap_or_else(|| "NULL".to_string());
     }
 
@@ -641,15 +697,12 @@ fn clickhouse_json_value_to_string(value: &serde_json::Value) -> String {
     }
 }
 
-fn format_array<T: ToString>(values: Vec<T>) -> String {
-    format!(
-        "[{}]",
-        values
-            .into_iter()
-            .map(|value| value.to_string())
-            .collect::<Vec<_>>()
-            .join(", ")
-    )
+fn postgres_array_to_string<T: ToString>(values: Vec<Option<T>>) -> String {
+    let elements = values
+        .into_iter()
+        .map(|value| value.map(|value| value.to_string()))
+        .collect::<Vec<_>>();
+    format_postgres_array(&elements)
 }
 
 fn mysql_locator_to_string(row: &sqlx::mysql::MySqlRow, idx: usize) -> String {
@@ -0,0 +1,125 @@
+use models::{DatabaseConnection, DatabaseError, ServerSetting, ServerSettings};
+use sqlx::{Executor, Row};
+
+use super::monitor::postgres_only;
+use super::quote_identifier;
+
+/// Reads every parameter from `pg_settings`, with the server version,
+/// encoding and whether the role may change them.
+pub async fn load_server_settings(
+    connection: DatabaseConnection,
+) -> Result<ServerSettings, DatabaseError> {
+    let DatabaseConnection::Postgres(pool) = connection else {
+        return Err(postgres_only("The server settings window"));
+    };
+
+    let header = sqlx::query(
+        r#"
+        select
+            current_setting('server_version') as server_version,
+            current_setting('server_encoding') as server_encoding,
+            current_setting('is_superuser') = 'on' as is_superuser
+        "#,
+    )
+    .fetch_one(&pool)
+    .await
+    .map_err(DatabaseError::Postgres)?;
+
+    let rows = sqlx::query(
+        r#"
+        select
+            name,
+            coalesce(setting, '') as setting,
+            unit,
+            coalesce(category, '') as category,
+            coalesce(short_desc, '') as short_desc,
+            coalesce(source, '') as source,
+            coalesce(context, '') as context
+        from pg_settings
+        order by category, name
+        "#,
+    )
+    .fetch_all(&pool)
+    .await
+    .map_err(DatabaseError::Postgres)?;
+
+    let settings = rows
+        .into_iter()
+        .map(|row| {
+            Ok(ServerSetting {
+                name: row.try_get("name")?,
+                setting: row.try_get("setting")?,
+                unit: row.try_get("unit")?,
+                category: row.try_get("category")?,
+                short_desc: row.try_get("short_desc")?,
+                source: row.try_get("source")?,
+                context: row.try_get("context")?,
+            })
+        })
+        .collect::<Result<Vec<_>, sqlx::Error>>()
+        .map_err(DatabaseError::Postgres)?;
+
+    Ok(ServerSettings {
+        server_version: header
+            .try_get("server_version")
+            .map_err(DatabaseError::Postgres)?,
+        server_encoding: header
+            .try_get("server_encoding")
+            .map_err(DatabaseError::Postgres)?,
+        is_superuser: header
+            .try_get("is_superuser")
+            .map_err(DatabaseError::Postgres)?,
+        settings,
+    })
+}
+
+/// Writes `name = value` to `postgresql.auto.conf` and reloads the
+/// configuration, so every new session picks it up. A plain `SET` would only
+/// change whichever pooled connection happened to run it.
+pub async fn set_server_setting(
+    connection: DatabaseConnection,
+    name: String,
+    value: String,
+) -> Result<(), DatabaseError> {
+    let DatabaseConnection::Postgres(pool) = connection else {
+        return Err(postgres_only("The server settings window"));
+    };
+
+    // ALTER SYSTEM refuses to run inside a transaction block, so send it
+    // through the simple query protocol.
+    let sql = alter_system_statement(&name, &value);
+    (&pool)
+        .execute(sql.as_str())
+        .await
+        .map_err(DatabaseError::Postgres)?;
+    sqlx::query("select pg_reload_conf()")
+        .execute(&pool)
+        .await
+        .map_err(DatabaseError::Postgres)?;
+    Ok(())
+}
+
+fn alter_system_statement(name: &str, value: &str) -> String {
+    format!(
+        "alter system set {} = '{}'",
+        quote_identifier(name.trim()),
+        value.trim().replace('\'', "''")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::alter_system_statement;
+
+    #[test]
+    fn alter_system_statement_quotes_the_name_and_value() {
+        assert_eq!(
+            alter_system_statement("work_mem", " 64MB "),
+            "alter system set \"work_mem\" = '64MB'"
+        );
+        assert_eq!(
+            alter_system_statement("application_name", "O'Brien"),
+            "alter system set \"application_name\" = 'O''Brien'"
+        );
+    }
+}
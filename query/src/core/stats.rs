@@ -0,0 +1,249 @@
+use models::{ColumnStats, DatabaseConnection, DatabaseError, QueryOutput, TablePreviewSource};
+
+use super::{execute_query, quote_identifier, quote_identifier_clickhouse};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum StatsDialect {
+    Sqlite,
+    Postgres,
+    MySql,
+    ClickHouse,
+}
+
+/// Aggregates requested from the database, from the richest to the most portable.
+///
+/// `AVG` fails on non-numeric types and `MIN`/`MAX` fail on types without an
+/// ordering (e.g. Postgres `json` or `boolean`), so each failing level falls
+/// back to the next one instead of surfacing the error straight away.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum StatsLevel {
+    Full,
+    WithoutAverage,
+    CountsOnly,
+}
+
+const STATS_LEVELS: [StatsLevel; 3] = [
+    StatsLevel::Full,
+    StatsLevel::WithoutAverage,
+    StatsLevel::CountsOnly,
+];
+
+/// Profile a single column of a table: row count, distinct count, null count
+/// and, where the column type allows it, MIN/MAX/AVG rendered as text.
+pub async fn load_column_stats(
+    connection: DatabaseConnection,
+    source: TablePreviewSource,
+    column: String,
+) -> Result<ColumnStats, DatabaseError> {
+    let column = column.trim().to_string();
+    if column.is_empty() {
+        return Err(DatabaseError::UnsupportedDriver(
+            "Column name is empty".to_string(),
+        ));
+    }
+
+    let dialect = match &connection {
+        DatabaseConnection::Sqlite(_) => StatsDialect::Sqlite,
+        DatabaseConnection::Postgres(_) => StatsDialect::Postgres,
+        DatabaseConnection::MySql(_) => StatsDialect::MySql,
+        DatabaseConnection::ClickHouse(_) => StatsDialect::ClickHouse,
+    };
+
+    let mut first_error = None;
+    for level in STATS_LEVELS {
+        let sql = build_column_stats_sql(dialect, &source.qualified_name, &column, level);
+        match execute_query(connection.clone(), sql).await {
+            Ok(QueryOutput::Table(page)) => {
+                return Ok(column_stats_from_row(
+                    &column,
+                    &page.columns,
+                    page.rows.first(),
+                ));
+            }
+            Ok(QueryOutput::AffectedRows(_)) => {
+                return Err(DatabaseError::UnsupportedDriver(
+                    "Column statistics query did not return rows".to_string(),
+                ));
+            }
+            Err(err) => {
+                first_error.get_or_insert(err);
+            }
+        }
+    }
+
+    Err(first_error.unwrap_or_else(|| {
+        DatabaseError::UnsupportedDriver("Column statistics are unavailable".to_string())
+    }))
+}
+
+fn build_column_stats_sql(
+    dialect: StatsDialect,
+    qualified_table_name: &str,
+    column: &str,
+    level: StatsLevel,
+) -> String {
+    let quoted = match dialect {
+        StatsDialect::Sqlite | StatsDialect::Postgres => quote_identifier(column),
+        StatsDialect::MySql | StatsDialect::ClickHouse => quote_identifier_clickhouse(column),
+    };
+    let as_text = |expression: String| match dialect {
+        StatsDialect::Sqlite | StatsDialect::Postgres => format!("cast({expression} as text)"),
+        StatsDialect::MySql => format!("cast({expression} as char)"),
+        StatsDialect::ClickHouse => format!("toString({expression})"),
+    };
+
+    let mut select_list = vec![
+        "count(*) as total_count".to_string(),
+        format!("count(distinct {quoted}) as distinct_count"),
+        format!("count(*) - count({quoted}) as null_count"),
+    ];
+    if level != StatsLevel::CountsOnly {
+        select_list.push(format!(
+            "{} as min_value",
+            as_text(format!("min({quoted})"))
+        ));
+        select_list.push(format!(
+            "{} as max_value",
+            as_text(format!("max({quoted})"))
+        ));
+    }
+    if level == StatsLevel::Full {
+        select_list.push(format!(
+            "{} as avg_value",
+            as_text(format!("avg({quoted})"))
+        ));
+    }
+
+    format!(
+        "select {} from {}",
+        select_list.join(", "),
+        qualified_table_name.trim().trim_end_matches(';')
+    )
+}
+
+fn column_stats_from_row(
+    column: &str,
+    columns: &[String],
+    row: Option<&Vec<String>>,
+) -> ColumnStats {
+    let value = |name: &str| {
+        let index = columns
+            .iter()
+            .position(|candidate| candidate.eq_ignore_ascii_case(name))?;
+        row.and_then(|row| row.get(index))
+            .filter(|value| !value.eq_ignore_ascii_case("null"))
+            .cloned()
+    };
+    let count = |name: &str| {
+        value(name)
+            .and_then(|value| value.trim().parse::<f64>().ok())
+            .map(|value| value.max(0.0) as u64)
+            .unwrap_or(0)
+    };
+
+    ColumnStats {
+        column: column.to_string(),
+        total_count: count("total_count"),
+        distinct_count: count("distinct_count"),
+        null_count: count("null_count"),
+        min: value("min_value"),
+        max: value("max_value"),
+        avg: value("avg_value"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        StatsDialect, StatsLevel, build_column_stats_sql, column_stats_from_row, load_column_stats,
+    };
+    use models::{DatabaseConnection, TablePreviewSource};
+    use sqlx::SqlitePool;
+
+    #[test]
+    fn stats_sql_quotes_column_per_dialect() {
+        assert_eq!(
+            build_column_stats_sql(
+                StatsDialect::Postgres,
+                r#""public"."orders""#,
+                "total",
+                StatsLevel::Full,
+            ),
+            r#"select count(*) as total_count, count(distinct "total") as distinct_count, count(*) - count("total") as null_count, cast(min("total") as text) as min_value, cast(max("total") as text) as max_value, cast(avg("total") as text) as avg_value from "public"."orders""#
+        );
+        assert_eq!(
+            build_column_stats_sql(
+                StatsDialect::MySql,
+                "`shop`.`orders`",
+                "total",
+                StatsLevel::WithoutAverage,
+            ),
+            "select count(*) as total_count, count(distinct `total`) as distinct_count, count(*) - count(`total`) as null_count, cast(min(`total`) as char) as min_value, cast(max(`total`) as char) as max_value from `shop`.`orders`"
+        );
+        assert_eq!(
+            build_column_stats_sql(
+                StatsDialect::ClickHouse,
+                "`default`.`events`",
+                "payload",
+                StatsLevel::CountsOnly,
+            ),
+            "select count(*) as total_count, count(distinct `payload`) as distinct_count, count(*) - count(`payload`) as null_count from `default`.`events`"
+        );
+    }
+
+    #[test]
+    fn stats_row_treats_null_text_as_missing() {
+        let columns = vec![
+            "total_count".to_string(),
+            "distinct_count".to_string(),
+            "null_count".to_string(),
+            "min_value".to_string(),
+        ];
+        let row = vec![
+            "10".to_string(),
+            "4".to_string(),
+            "2".to_string(),
+            "NULL".to_string(),
+        ];
+
+        let stats = column_stats_from_row("price", &columns, Some(&row));
+
+        assert_eq!(stats.total_count, 10);
+        assert_eq!(stats.distinct_count, 4);
+        assert_eq!(stats.null_count, 2);
+        assert_eq!(stats.min, None);
+        assert_eq!(stats.avg, None);
+    }
+
+    #[tokio::test]
+    async fn load_column_stats_profiles_sqlite_column() {
+        let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+        sqlx::query("create table products (id integer primary key, price integer)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("insert into products (price) values (10), (20), (20), (null)")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let stats = load_column_stats(
+            DatabaseConnection::Sqlite(pool),
+            TablePreviewSource {
+                schema: None,
+                table_name: "products".to_string(),
+                qualified_name: "products".to_string(),
+            },
+            "price".to_string(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(stats.total_count, 4);
+        assert_eq!(stats.distinct_count, 2);
+        assert_eq!(stats.null_count, 1);
+        assert_eq!(stats.min.as_deref(), Some("10"));
+        assert_eq!(stats.max.as_deref(), Some("20"));
+        assert!(stats.avg.is_some());
+    }
+}
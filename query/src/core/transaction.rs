@@ -0,0 +1,699 @@
+use std::sync::Arc;
+
+use models::{
+    DatabaseConnection, DatabaseError, InsertedRow, QueryFilter, QueryOutput, QuerySort,
+    TablePreviewSource, TransactionStatus,
+};
+use tokio::sync::Mutex;
+
+use super::{
+    execute_mysql_query_page, execute_postgres_query_page, execute_sqlite_query_page,
+    monitor::postgres_only,
+    mutations::{
+        check_deleted_row_count, delete_mysql_table_row, delete_mysql_table_rows,
+        delete_postgres_table_row, delete_postgres_table_rows, delete_sqlite_table_row,
+        delete_sqlite_table_rows, insert_mysql_table_row_returning,
+        insert_mysql_table_row_with_values, insert_postgres_table_row_returning,
+        insert_postgres_table_row_with_values, insert_sqlite_table_row_returning,
+        insert_sqlite_table_row_with_values, update_mysql_table_cell, update_postgres_table_cell,
+        update_sqlite_table_cell,
+    },
+    params::{QueryParameterValue, execute_postgres_query_with_parameters},
+    preview::{
+        load_mysql_table_preview_page, load_postgres_table_preview_page,
+        load_sqlite_table_preview_page,
+    },
+    quote_identifier, quote_identifier_clickhouse,
+};
+
+/// An explicit transaction pinned to one pooled connection.
+///
+/// Every statement routed through the handle waits on the same async mutex,
+/// so a COMMIT or ROLLBACK can never overtake a statement that is still
+/// running inside the transaction. Clones share the same underlying
+/// transaction.
+#[derive(Clone)]
+pub struct SqlTransaction {
+    inner: Arc<Mutex<TransactionSlot>>,
+}
+
+struct TransactionSlot {
+    open: Option<OpenTransaction>,
+    status: TransactionStatus,
+    /// Savepoints created through this handle, oldest first.
+    savepoints: Vec<String>,
+}
+
+enum OpenTransaction {
+    Sqlite(sqlx::Transaction<'static, sqlx::Sqlite>),
+    Postgres(sqlx::Transaction<'static, sqlx::Postgres>),
+    MySql {
+        transaction: sqlx::Transaction<'static, sqlx::MySql>,
+        // Metadata lookups (primary keys, current schema) go through the pool
+        // because the transaction connection is borrowed by the statement.
+        pool: sqlx::MySqlPool,
+    },
+}
+
+impl PartialEq for SqlTransaction {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl std::fmt::Debug for SqlTransaction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SqlTransaction").finish_non_exhaustive()
+    }
+}
+
+/// Open a transaction on a dedicated connection taken from the session pool.
+///
+/// ClickHouse has no multi-statement transactions over HTTP and is rejected.
+pub async fn begin_transaction(
+    connection: DatabaseConnection,
+) -> Result<SqlTransaction, DatabaseError> {
+    let open = match connection {
+        DatabaseConnection::Sqlite(pool) => {
+            OpenTransaction::Sqlite(pool.begin().await.map_err(DatabaseError::Sqlite)?)
+        }
+        DatabaseConnection::Postgres(pool) => {
+            OpenTransaction::Postgres(pool.begin().await.map_err(DatabaseError::Postgres)?)
+        }
+        DatabaseConnection::MySql(pool) => OpenTransaction::MySql {
+            transaction: pool.begin().await.map_err(DatabaseError::MySql)?,
+            pool,
+        },
+        DatabaseConnection::ClickHouse(_) => {
+            return Err(DatabaseError::UnsupportedDriver(
+                "ClickHouse does not support transactions".to_string(),
+            ));
+        }
+    };
+
+    Ok(SqlTransaction {
+        inner: Arc::new(Mutex::new(TransactionSlot {
+            open: Some(open),
+            status: TransactionStatus::Active,
+            savepoints: Vec::new(),
+        })),
+    })
+}
+
+impl SqlTransaction {
+    /// Current state as last observed by this handle.
+    pub async fn status(&self) -> TransactionStatus {
+        self.inner.lock().await.status
+    }
+
+    /// Active savepoints, oldest first.
+    pub async fn savepoints(&self) -> Vec<String> {
+        self.inner.lock().await.savepoints.clone()
+    }
+
+    pub async fn commit(&self) -> Result<(), DatabaseError> {
+        let mut slot = self.inner.lock().await;
+        let open = slot.open.take().ok_or_else(transaction_closed)?;
+        slot.status = TransactionStatus::Idle;
+        slot.savepoints.clear();
+        match open {
+            OpenTransaction::Sqlite(transaction) => {
+                transaction.commit().await.map_err(DatabaseError::Sqlite)
+            }
+            OpenTransaction::Postgres(transaction) => {
+                transaction.commit().await.map_err(DatabaseError::Postgres)
+            }
+            OpenTransaction::MySql { transaction, .. } => {
+                transaction.commit().await.map_err(DatabaseError::MySql)
+            }
+        }
+    }
+
+    pub async fn rollback(&self) -> Result<(), DatabaseError> {
+        let mut slot = self.inner.lock().await;
+        let open = slot.open.take().ok_or_else(transaction_closed)?;
+        slot.status = TransactionStatus::Idle;
+        slot.savepoints.clear();
+        match open {
+            OpenTransaction::Sqlite(transaction) => {
+                transaction.rollback().await.map_err(DatabaseError::Sqlite)
+            }
+            OpenTransaction::Postgres(transaction) => transaction
+                .rollback()
+                .await
+                .map_err(DatabaseError::Postgres),
+            OpenTransaction::MySql { transaction, .. } => {
+                transaction.rollback().await.map_err(DatabaseError::MySql)
+            }
+        }
+    }
+
+    pub async fn savepoint(&self, name: &str) -> Result<(), DatabaseError> {
+        let name = savepoint_name(name)?;
+        let mut slot = self.inner.lock().await;
+        let sql = format!("savepoint {}", slot.quote_savepoint(&name));
+        slot.execute_control(&sql).await?;
+        slot.savepoints.push(name);
+        Ok(())
+    }
+
+    /// Undo everything after `name`; the savepoint itself stays usable and a
+    /// PostgreSQL transaction aborted after it becomes active again.
+    pub async fn rollback_to_savepoint(&self, name: &str) -> Result<(), DatabaseError> {
+        let name = savepoint_name(name)?;
+        let mut slot = self.inner.lock().await;
+        let position = slot.savepoint_position(&name)?;
+        let sql = format!("rollback to savepoint {}", slot.quote_savepoint(&name));
+        slot.execute_control(&sql).await?;
+        slot.savepoints.truncate(position + 1);
+        slot.status = TransactionStatus::Active;
+        Ok(())
+    }
+
+    /// Forget `name` and every savepoint created after it, keeping their work.
+    pub async fn release_savepoint(&self, name: &str) -> Result<(), DatabaseError> {
+        let name = savepoint_name(name)?;
+        let mut slot = self.inner.lock().await;
+        let position = slot.savepoint_position(&name)?;
+        let sql = format!("release savepoint {}", slot.quote_savepoint(&name));
+        slot.execute_control(&sql).await?;
+        slot.savepoints.truncate(position);
+        Ok(())
+    }
+
+    /// Bound the transaction's remaining PostgreSQL statements to
+    /// `timeout_secs` with `set local statement_timeout`. Other drivers have
+    /// no such setting and are left alone.
+    pub async fn set_statement_timeout(&self, timeout_secs: u32) -> Result<(), DatabaseError> {
+        let mut slot = self.inner.lock().await;
+        if !matches!(slot.open, Some(OpenTransaction::Postgres(_))) {
+            return Ok(());
+        }
+        slot.execute_control(&format!("set local statement_timeout = '{timeout_secs}s'"))
+            .await
+    }
+
+    pub async fn execute_query_page(
+        &self,
+        sql: String,
+        page_size: u32,
+        offset: u64,
+        filter: Option<QueryFilter>,
+        sort: Option<QuerySort>,
+    ) -> Result<QueryOutput, DatabaseError> {
+        let mut slot = self.inner.lock().await;
+        let result = match slot.open.as_mut() {
+            Some(OpenTransaction::Sqlite(transaction)) => {
+                execute_sqlite_query_page(&sql, &mut **transaction, page_size, offset, filter, sort)
+                    .await
+            }
+            Some(OpenTransaction::Postgres(transaction)) => {
+                execute_postgres_query_page(
+                    &sql,
+                    &[],
+                    &mut **transaction,
+                    page_size,
+                    offset,
+                    filter,
+                    sort,
+                )
+                .await
+            }
+            Some(OpenTransaction::MySql { transaction, pool }) => {
+                execute_mysql_query_page(
+                    &sql,
+                    pool,
+                    &mut **transaction,
+                    page_size,
+                    offset,
+                    filter,
+                    sort,
+                )
+                .await
+            }
+            None => Err(transaction_closed()),
+        };
+        slot.observe(result)
+    }
+
+    /// Runs `sql` in the transaction with its parameters bound, as
+    /// [`execute_query_with_parameters`](super::execute_query_with_parameters)
+    /// does on the pool. Only PostgreSQL takes parameters.
+    pub async fn execute_query_with_parameters(
+        &self,
+        sql: String,
+        parameters: Vec<QueryParameterValue>,
+        page_size: u32,
+    ) -> Result<QueryOutput, DatabaseError> {
+        let mut slot = self.inner.lock().await;
+        let result = match slot.open.as_mut() {
+            Some(OpenTransaction::Postgres(transaction)) => {
+                execute_postgres_query_with_parameters(transaction, &sql, &parameters, page_size)
+                    .await
+            }
+            Some(_) => Err(postgres_only("Query parameters")),
+            None => Err(transaction_closed()),
+        };
+        slot.observe(result)
+    }
+
+    pub async fn load_table_preview_page(
+        &self,
+        source: TablePreviewSource,
+        page_size: u32,
+        offset: u64,
+        filter: Option<QueryFilter>,
+        sort: Option<QuerySort>,
+    ) -> Result<QueryOutput, DatabaseError> {
+        let mut slot = self.inner.lock().await;
+        let result = match slot.open.as_mut() {
+            Some(OpenTransaction::Sqlite(transaction)) => {
+                load_sqlite_table_preview_page(transaction, source, page_size, offset, filter, sort)
+                    .await
+            }
+            Some(OpenTransaction::Postgres(transaction)) => {
+                load_postgres_table_preview_page(
+                    transaction,
+                    source,
+                    page_size,
+                    offset,
+                    filter,
+                    sort,
+                )
+                .await
+            }
+            Some(OpenTransaction::MySql { transaction, pool }) => {
+                load_mysql_table_preview_page(
+                    pool,
+                    &mut **transaction,
+                    source,
+                    page_size,
+                    offset,
+                    filter,
+                    sort,
+                )
+                .await
+            }
+            None => Err(transaction_closed()),
+        };
+        slot.observe(result)
+    }
+
+    pub async fn update_table_cell(
+        &self,
+        source: TablePreviewSource,
+        locator: String,
+        column_name: String,
+        value: String,
+    ) -> Result<(), DatabaseError> {
+        let mut slot = self.inner.lock().await;
+        let result = match slot.open.as_mut() {
+            Some(OpenTransaction::Sqlite(transaction)) => {
+                update_sqlite_table_cell(
+                    &mut **transaction,
+                    &source,
+                    &locator,
+                    &column_name,
+                    &value,
+                )
+                .await
+            }
+            Some(OpenTransaction::Postgres(transaction)) => {
+                update_postgres_table_cell(
+                    &mut **transaction,
+                    &source,
+                    &locator,
+                    &column_name,
+                    &value,
+                )
+                .await
+            }
+            Some(OpenTransaction::MySql { transaction, pool }) => {
+                update_mysql_table_cell(
+                    pool,
+                    &mut **transaction,
+                    &source,
+                    &locator,
+                    &column_name,
+                    &value,
+                )
+                .await
+            }
+            None => Err(transaction_closed()),
+        };
+        slot.observe(result)
+    }
+
+    pub async fn insert_table_row_with_values(
+        &self,
+        source: TablePreviewSource,
+        column_values: Vec<(String, String)>,
+    ) -> Result<(), DatabaseError> {
+        let mut slot = self.inner.lock().await;
+        let result = match slot.open.as_mut() {
+            Some(OpenTransaction::Sqlite(transaction)) => {
+                insert_sqlite_table_row_with_values(&mut **transaction, &source, &column_values)
+                    .await
+            }
+            Some(OpenTransaction::Postgres(transaction)) => {
+                insert_postgres_table_row_with_values(&mut **transaction, &source, &column_values)
+                    .await
+            }
+            Some(OpenTransaction::MySql { transaction, .. }) => {
+                insert_mysql_table_row_with_values(&mut **transaction, &source, &column_values)
+                    .await
+            }
+            None => Err(transaction_closed()),
+        };
+        slot.observe(result)
+    }
+
+    pub async fn insert_table_row_returning(
+        &self,
+        source: TablePreviewSource,
+        column_values: Vec<(String, String)>,
+    ) -> Result<Option<InsertedRow>, DatabaseError> {
+        let mut slot = self.inner.lock().await;
+        let result = match slot.open.as_mut() {
+            Some(OpenTransaction::Sqlite(transaction)) => {
+                insert_sqlite_table_row_returning(&mut **transaction, &source, &column_values)
+                    .await
+                    .map(Some)
+            }
+            Some(OpenTransaction::Postgres(transaction)) => {
+                insert_postgres_table_row_returning(&mut **transaction, &source, &column_values)
+                    .await
+                    .map(Some)
+            }
+            Some(OpenTransaction::MySql { transaction, pool }) => {
+                insert_mysql_table_row_returning(pool, transaction, &source, &column_values).await
+            }
+            None => Err(transaction_closed()),
+        };
+        slot.observe(result)
+    }
+
+    pub async fn delete_table_row(
+        &self,
+        source: TablePreviewSource,
+        locator: String,
+    ) -> Result<(), DatabaseError> {
+        let mut slot = self.inner.lock().await;
+        let result = match slot.open.as_mut() {
+            Some(OpenTransaction::Sqlite(transaction)) => {
+                delete_sqlite_table_row(&mut **transaction, &source, &locator).await
+            }
+            Some(OpenTransaction::Postgres(transaction)) => {
+                delete_postgres_table_row(&mut **transaction, &source, &locator).await
+            }
+            Some(OpenTransaction::MySql { transaction, pool }) => {
+                delete_mysql_table_row(pool, &mut **transaction, &source, &locator).await
+            }
+            None => Err(transaction_closed()),
+        };
+        slot.observe(result)
+    }
+
+    /// Delete every row behind `locators`, undoing the delete through an
+    /// internal savepoint unless exactly one row per locator was removed.
+    /// Earlier work in the transaction is kept either way.
+    pub async fn delete_table_rows(
+        &self,
+        source: TablePreviewSource,
+        locators: Vec<String>,
+    ) -> Result<u64, DatabaseError> {
+        if locators.is_empty() {
+            return Ok(0);
+        }
+
+        let mut slot = self.inner.lock().await;
+        let savepoint = slot.quote_savepoint(DELETE_ROWS_SAVEPOINT);
+        slot.execute_control(&format!("savepoint {savepoint}"))
+            .await?;
+        let result = match slot.open.as_mut() {
+            Some(OpenTransaction::Sqlite(transaction)) => {
+                delete_sqlite_table_rows(&mut **transaction, &source, &locators).await
+            }
+            Some(OpenTransaction::Postgres(transaction)) => {
+                delete_postgres_table_rows(&mut **transaction, &source, &locators).await
+            }
+            Some(OpenTransaction::MySql { transaction, pool }) => {
+                delete_mysql_table_rows(pool, &mut **transaction, &source, &locators).await
+            }
+            None => Err(transaction_closed()),
+        }
+        .and_then(|deleted| check_deleted_row_count(locators.len(), deleted).map(|()| deleted));
+
+        match result {
+            Ok(deleted) => {
+                slot.execute_control(&format!("release savepoint {savepoint}"))
+                    .await?;
+                Ok(deleted)
+            }
+            Err(err) => {
+                // Rolling back to the savepoint also clears a PostgreSQL
+                // abort caused by the failed delete.
+                slot.execute_control(&format!("rollback to savepoint {savepoint}"))
+                    .await?;
+                slot.execute_control(&format!("release savepoint {savepoint}"))
+                    .await?;
+                Err(err)
+            }
+        }
+    }
+}
+
+impl TransactionSlot {
+    async fn execute_control(&mut self, sql: &str) -> Result<(), DatabaseError> {
+        let result = match self.open.as_mut() {
+            Some(OpenTransaction::Sqlite(transaction)) => sqlx::raw_sql(sql)
+                .execute(&mut **transaction)
+                .await
+                .map(|_| ())
+                .map_err(DatabaseError::Sqlite),
+            Some(OpenTransaction::Postgres(transaction)) => sqlx::raw_sql(sql)
+                .execute(&mut **transaction)
+                .await
+                .map(|_| ())
+                .map_err(DatabaseError::Postgres),
+            Some(OpenTransaction::MySql { transaction, .. }) => sqlx::raw_sql(sql)
+                .execute(&mut **transaction)
+                .await
+                .map(|_| ())
+                .map_err(DatabaseError::MySql),
+            None => Err(transaction_closed()),
+        };
+        self.observe(result)
+    }
+
+    fn quote_savepoint(&self, name: &str) -> String {
+        match self.open {
+            Some(OpenTransaction::MySql { .. }) => quote_identifier_clickhouse(name),
+            _ => quote_identifier(name),
+        }
+    }
+
+    fn savepoint_position(&self, name: &str) -> Result<usize, DatabaseError> {
+        // Savepoint names may repeat; the server resolves to the newest one.
+        self.savepoints
+            .iter()
+            .rposition(|savepoint| savepoint == name)
+            .ok_or_else(|| {
+                DatabaseError::UnsupportedDriver(format!("Savepoint {name} does not exist"))
+            })
+    }
+
+    /// Track server-side transaction state after a statement.
+    ///
+    /// PostgreSQL aborts the whole transaction on any statement error and
+    /// rejects everything but ROLLBACK afterwards; SQLite and MySQL keep the
+    /// transaction usable, so only PostgreSQL errors flip the status.
+    fn observe<T>(&mut self, result: Result<T, DatabaseError>) -> Result<T, DatabaseError> {
+        if matches!(result, Err(DatabaseError::Postgres(_)))
+            && matches!(self.open, Some(OpenTransaction::Postgres(_)))
+        {
+            self.status = TransactionStatus::Aborted;
+        }
+        result
+    }
+}
+
+/// Savepoint wrapped around a multi-row delete inside a user transaction.
+const DELETE_ROWS_SAVEPOINT: &str = "shovel_delete_rows";
+
+fn savepoint_name(name: &str) -> Result<String, DatabaseError> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(DatabaseError::UnsupportedDriver(
+            "Savepoint name is empty".to_string(),
+        ));
+    }
+    Ok(name.to_string())
+}
+
+fn transaction_closed() -> DatabaseError {
+    DatabaseError::UnsupportedDriver("Transaction is already closed".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::begin_transaction;
+    use models::{DatabaseConnection, QueryOutput, TablePreviewSource, TransactionStatus};
+    use sqlx::SqlitePool;
+
+    async fn count_rows(pool: &SqlitePool) -> i64 {
+        sqlx::query_scalar("select count(*) from items")
+            .fetch_one(pool)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn rollback_discards_statements_run_inside_transaction() {
+        let pool = SqlitePool::connect(":memory:").await.unwrap();
+        sqlx::query("create table items (id integer primary key, name text)")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let transaction = begin_transaction(DatabaseConnection::Sqlite(pool.clone()))
+            .await
+            .unwrap();
+        let output = transaction
+            .execute_query_page(
+                "insert into items (name) values ('draft')".to_string(),
+                100,
+                0,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(output, QueryOutput::AffectedRows(1));
+        assert_eq!(transaction.status().await, TransactionStatus::Active);
+
+        transaction.rollback().await.unwrap();
+
+        assert_eq!(transaction.status().await, TransactionStatus::Idle);
+        assert_eq!(count_rows(&pool).await, 0);
+        assert!(transaction.commit().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn commit_persists_statements_run_inside_transaction() {
+        let pool = SqlitePool::connect(":memory:").await.unwrap();
+        sqlx::query("create table items (id integer primary key, name text)")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let transaction = begin_transaction(DatabaseConnection::Sqlite(pool.clone()))
+            .await
+            .unwrap();
+        transaction
+            .execute_query_page(
+                "insert into items (name) values ('kept')".to_string(),
+                100,
+                0,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        transaction.commit().await.unwrap();
+
+        assert_eq!(count_rows(&pool).await, 1);
+    }
+
+    #[tokio::test]
+    async fn rollback_to_savepoint_keeps_earlier_work_and_trims_stack() {
+        let pool = SqlitePool::connect(":memory:").await.unwrap();
+        sqlx::query("create table items (id integer primary key, name text)")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let transaction = begin_transaction(DatabaseConnection::Sqlite(pool.clone()))
+            .await
+            .unwrap();
+        let insert = |name: &str| format!("insert into items (name) values ('{name}')");
+        transaction
+            .execute_query_page(insert("first"), 100, 0, None, None)
+            .await
+            .unwrap();
+        transaction.savepoint("before_second").await.unwrap();
+        transaction
+            .execute_query_page(insert("second"), 100, 0, None, None)
+            .await
+            .unwrap();
+        transaction.savepoint("before_third").await.unwrap();
+        assert_eq!(
+            transaction.savepoints().await,
+            vec!["before_second".to_string(), "before_third".to_string()]
+        );
+
+        transaction
+            .rollback_to_savepoint("before_second")
+            .await
+            .unwrap();
+        assert_eq!(
+            transaction.savepoints().await,
+            vec!["before_second".to_string()]
+        );
+        assert!(transaction.release_savepoint("before_third").await.is_err());
+
+        transaction
+            .release_savepoint("before_second")
+            .await
+            .unwrap();
+        assert!(transaction.savepoints().await.is_empty());
+        transaction.commit().await.unwrap();
+
+        assert_eq!(count_rows(&pool).await, 1);
+    }
+
+    #[tokio::test]
+    async fn failed_delete_table_rows_keeps_earlier_transaction_work() {
+        let pool = SqlitePool::connect(":memory:").await.unwrap();
+        sqlx::query("create table items (id integer primary key, name text)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("insert into items (id, name) values (1, 'a'), (2, 'b')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let source = TablePreviewSource {
+            schema: None,
+            table_name: "items".to_string(),
+            qualified_name: r#""items""#.to_string(),
+        };
+
+        let transaction = begin_transaction(DatabaseConnection::Sqlite(pool.clone()))
+            .await
+            .unwrap();
+        transaction
+            .execute_query_page(
+                "insert into items (id, name) values (3, 'c')".to_string(),
+                100,
+                0,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        let stale = transaction
+            .delete_table_rows(source.clone(), vec!["1".to_string(), "99".to_string()])
+            .await;
+        assert!(stale.is_err());
+        let deleted = transaction
+            .delete_table_rows(source, vec!["2".to_string()])
+            .await
+            .unwrap();
+        assert_eq!(deleted, 1);
+        assert!(transaction.savepoints().await.is_empty());
+        transaction.commit().await.unwrap();
+
+        assert_eq!(count_rows(&pool).await, 2);
+    }
+}
@@ -1,6 +1,9 @@
 use models::{DatabaseKind, SqlFormatSettings, SqlKeywordCase};
 use sqlformat::{Dialect, FormatOptions, Indent, QueryParams};
 
+/// Formats `sql` with one clause per line, following `settings` for keyword
+/// case, indentation and how much may stay inline. Literals and comments are
+/// copied as written.
 pub fn format_sql(kind: Option<DatabaseKind>, sql: &str, settings: &SqlFormatSettings) -> String {
     let trimmed = sql.trim();
     if trimmed.is_empty() {
@@ -21,12 +24,16 @@ pub fn format_sql(kind: Option<DatabaseKind>, sql: &str, settings: &SqlFormatSet
         joins_as_top_level: settings.joins_as_top_level,
         max_inline_block: wrap_width,
         max_inline_arguments: settings.max_inline_arguments.map(|value| value as usize),
-        max_inline_top_level: Some(
-            settings
-                .max_inline_top_level
-                .unwrap_or(settings.max_inline_block)
-                .max(20) as usize,
-        ),
+        max_inline_top_level: if settings.one_item_per_line {
+            None
+        } else {
+            Some(
+                settings
+                    .max_inline_top_level
+                    .unwrap_or(settings.max_inline_block)
+                    .max(20) as usize,
+            )
+        },
         dialect: match kind {
             Some(DatabaseKind::Postgres) => Dialect::PostgreSql,
             Some(DatabaseKind::MySql)
@@ -58,6 +65,7 @@ mod tests {
             max_inline_block: 50,
             max_inline_arguments: None,
             max_inline_top_level: None,
+            one_item_per_line: false,
         }
     }
 
@@ -209,6 +217,101 @@ mod tests {
         assert!(result.ends_with('\n'));
     }
 
+    // ── clause layout ────────────────────────────────────────────────
+
+    fn line_starting_with<'a>(formatted: &'a str, prefix: &str) -> Option<&'a str> {
+        formatted
+            .lines()
+            .find(|line| line.trim_start().starts_with(prefix))
+    }
+
+    fn indent_of(line: &str) -> usize {
+        line.len() - line.trim_start().len()
+    }
+
+    #[test]
+    fn format_sql_puts_clauses_of_a_cte_on_their_own_lines() {
+        let settings = default_settings();
+        let result = format_sql(
+            Some(DatabaseKind::Postgres),
+            "with active_users as (select id, name, email from users where active = true and deleted_at is null) select id, name from active_users where name like 'a%' order by name",
+            &settings,
+        );
+        assert!(result.starts_with("WITH"));
+        for clause in ["FROM", "WHERE", "ORDER BY"] {
+            assert!(
+                line_starting_with(&result, clause).is_some(),
+                "{clause} should start a line in:\n{result}"
+            );
+        }
+        let inner_select = line_starting_with(&result, "SELECT").unwrap();
+        assert!(indent_of(inner_select) > 0, "{result}");
+    }
+
+    #[test]
+    fn format_sql_indents_nested_subqueries_deeper() {
+        let settings = default_settings();
+        let result = format_sql(
+            None,
+            "select u.id from users u where u.id in (select o.user_id from orders o where o.total > (select avg(total) from orders where created_at > now() - interval '30 days'))",
+            &settings,
+        );
+        let selects = result
+            .lines()
+            .filter(|line| line.trim_start().starts_with("SELECT"))
+            .map(indent_of)
+            .collect::<Vec<_>>();
+        assert_eq!(selects.len(), 3, "{result}");
+        assert!(
+            selects[0] < selects[1] && selects[1] < selects[2],
+            "{result}"
+        );
+        assert!(result.contains("'30 days'"));
+    }
+
+    #[test]
+    fn format_sql_keeps_comments_and_strings_with_keywords_as_written() {
+        let settings = default_settings();
+        let result = format_sql(
+            None,
+            "select id -- select from where stays lower\nfrom users /* join on group by */ where note = 'select * from x' and tag = 'Order By'",
+            &settings,
+        );
+        assert!(result.contains("-- select from where stays lower"));
+        assert!(result.contains("/* join on group by */"));
+        assert!(result.contains("'select * from x'"));
+        assert!(result.contains("'Order By'"));
+        assert!(line_starting_with(&result, "FROM").is_some(), "{result}");
+        assert!(line_starting_with(&result, "WHERE").is_some(), "{result}");
+    }
+
+    #[test]
+    fn format_sql_puts_each_selected_column_on_its_own_line() {
+        let settings = SqlFormatSettings {
+            indent_width: 4,
+            one_item_per_line: true,
+            ..default_settings()
+        };
+        let result = format_sql(
+            None,
+            "select id, name from users u join orders o on o.user_id = u.id where id = 1 group by id, name having count(*) > 1 order by name",
+            &settings,
+        );
+        let lines = result.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0], "SELECT", "{result}");
+        assert_eq!(lines[1], "    id,", "{result}");
+        assert_eq!(lines[2], "    name", "{result}");
+        for clause in ["FROM", "JOIN", "WHERE", "GROUP BY", "HAVING", "ORDER BY"] {
+            assert!(
+                line_starting_with(&result, clause).is_some(),
+                "{clause} should start a line in:\n{result}"
+            );
+        }
+
+        let inline = format_sql(None, "select id, name from users", &default_settings());
+        assert!(inline.starts_with("SELECT id, name\n"), "{inline}");
+    }
+
     // ── max_inline_block clamped ─────────────────────────────────────
 
     #[test]
@@ -1,31 +1,146 @@
 use driver_clickhouse::execute_text_query;
-use models::{DatabaseConnection, QueryPage, TablePreviewSource};
+use futures_util::StreamExt;
+use models::{
+    CsvExportOptions, CsvFilePreview, CsvImportOptions, DatabaseConnection, JsonExportFormat,
+    JsonExportOptions, QueryExportSource, QueryFilter, QueryOutput, QueryPage, QuerySort,
+    SqlInsertExportOptions, TablePreviewSource,
+};
 use rust_xlsxwriter::Workbook;
 use serde_json::{Map, Value};
+use sqlx::postgres::PgPoolCopyExt;
 use std::{
-    collections::HashSet,
+    collections::{HashMap, HashSet},
     path::{Path, PathBuf},
 };
-use tokio::{fs, task::spawn_blocking};
+use tokio::{
+    fs,
+    io::{AsyncReadExt, AsyncWriteExt},
+    task::spawn_blocking,
+};
+
+use crate::core::{execute_query_page, load_table_preview_page};
 
 const IMPORT_BATCH_SIZE: usize = 200;
+const EXPORT_PAGE_SIZE: u32 = 1000;
+const COPY_CHUNK_SIZE: usize = 64 * 1024;
 
 #[derive(Clone, Debug)]
 struct CsvImportData {
     headers: Vec<String>,
     rows: Vec<Vec<String>>,
+    /// File line each row starts on, for error messages.
+    lines: Vec<u64>,
 }
 
-pub async fn export_query_page_csv(page: QueryPage, path: PathBuf) -> Result<usize, String> {
-    spawn_blocking(move || export_query_page_csv_sync(page, path))
+impl CsvImportData {
+    /// Rows in INSERT batches, each with the file lines it covers.
+    fn batches(&self) -> impl Iterator<Item = (&[Vec<String>], String)> {
+        self.rows
+            .chunks(IMPORT_BATCH_SIZE)
+            .zip(self.lines.chunks(IMPORT_BATCH_SIZE))
+            .map(|(rows, lines)| {
+                let label = match (lines.first(), lines.last()) {
+                    (Some(first), Some(last)) if first != last => format!("lines {first}-{last}"),
+                    (Some(line), _) => format!("line {line}"),
+                    (None, _) => "an empty batch".to_string(),
+                };
+                (rows, label)
+            })
+    }
+}
+
+pub async fn export_query_page_csv(
+    page: QueryPage,
+    path: PathBuf,
+    options: CsvExportOptions,
+) -> Result<usize, String> {
+    spawn_blocking(move || export_query_page_csv_sync(page, path, options))
         .await
         .map_err(|err| format!("csv export task failed: {err}"))?
 }
 
-pub async fn export_query_page_json(page: QueryPage, path: PathBuf) -> Result<usize, String> {
+/// Re-runs `source` page by page and streams every row into a CSV file, so the
+/// export is not limited to the rows loaded in the result grid.
+///
+/// `on_progress` receives the running total of written rows after each page.
+#[allow(clippy::too_many_arguments)]
+pub async fn export_query_result_csv(
+    connection: DatabaseConnection,
+    source: QueryExportSource,
+    filter: Option<QueryFilter>,
+    sort: Option<QuerySort>,
+    path: PathBuf,
+    options: CsvExportOptions,
+    mut on_progress: impl FnMut(usize),
+) -> Result<usize, String> {
+    ensure_parent_dir(&path).await?;
+    let mut writer = csv_export_writer(&path, options)?;
+    let mut offset = 0_u64;
+    let mut written = 0_usize;
+
+    loop {
+        let output = match &source {
+            QueryExportSource::Table(table) => {
+                load_table_preview_page(
+                    connection.clone(),
+                    table.clone(),
+                    EXPORT_PAGE_SIZE,
+                    offset,
+                    filter.clone(),
+                    sort.clone(),
+                )
+                .await
+            }
+            QueryExportSource::Sql(sql) => {
+                execute_query_page(
+                    connection.clone(),
+                    sql.clone(),
+                    EXPORT_PAGE_SIZE,
+                    offset,
+                    filter.clone(),
+                    sort.clone(),
+                )
+                .await
+            }
+        }
+        .map_err(|err| format!("failed to read rows for CSV export: {err}"))?;
+        let QueryOutput::Table(page) = output else {
+            return Err("the query no longer returns rows".to_string());
+        };
+
+        if offset == 0 {
+            writer
+                .write_record(&page.columns)
+                .map_err(|err| format!("failed to write CSV header: {err}"))?;
+        }
+        for row in &page.rows {
+            writer
+                .write_record(row)
+                .map_err(|err| format!("failed to write CSV row: {err}"))?;
+        }
+        written += page.rows.len();
+        on_progress(written);
+
+        if !page.has_next || page.rows.is_empty() {
+            break;
+        }
+        offset += page.rows.len() as u64;
+    }
+
+    writer
+        .flush()
+        .map_err(|err| format!("failed to flush CSV export {}: {err}", path.display()))?;
+
+    Ok(written)
+}
+
+pub async fn export_query_page_json(
+    page: QueryPage,
+    path: PathBuf,
+    options: JsonExportOptions,
+) -> Result<usize, String> {
     let row_count = page.rows.len();
-    let payload = query_page_to_json(page);
-    let json = serde_json::to_string_pretty(&payload)
+    let json = query_page_to_json_export(page, &options)
         .map_err(|err| format!("failed to serialize JSON export: {err}"))?;
 
     ensure_parent_dir(&path).await?;
@@ -57,19 +172,141 @@ pub async fn export_query_page_html(page: QueryPage, path: PathBuf) -> Result<us
 pub async fn export_query_page_sql_dump(
     page: QueryPage,
     path: PathBuf,
-    table_name: String,
+    options: SqlInsertExportOptions,
 ) -> Result<usize, String> {
-    spawn_blocking(move || export_query_page_sql_dump_sync(page, path, table_name))
+    spawn_blocking(move || export_query_page_sql_dump_sync(page, path, options))
         .await
         .map_err(|err| format!("sql dump export task failed: {err}"))?
 }
 
+/// Streams a whole PostgreSQL table into a CSV file with `COPY ... TO STDOUT`,
+/// which is far faster than paging rows through the driver. Grid filters and
+/// sorting do not apply, and records always end with `\n`.
+///
+/// `on_progress` receives the running total of written bytes.
+pub async fn copy_table_to_csv(
+    connection: DatabaseConnection,
+    source: TablePreviewSource,
+    path: PathBuf,
+    options: CsvExportOptions,
+    mut on_progress: impl FnMut(u64),
+) -> Result<u64, String> {
+    let DatabaseConnection::Postgres(pool) = connection else {
+        return Err("COPY export is only available for PostgreSQL".to_string());
+    };
+    let statement = postgres_copy_to_statement(&source, options)?;
+
+    ensure_parent_dir(&path).await?;
+    let mut file = fs::File::create(&path)
+        .await
+        .map_err(|err| format!("failed to open {} for CSV export: {err}", path.display()))?;
+    let mut stream = pool
+        .copy_out_raw(&statement)
+        .await
+        .map_err(|err| format!("COPY export failed: {err}"))?;
+
+    let mut written = 0_u64;
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(|err| format!("COPY export failed: {err}"))?;
+        file.write_all(&chunk)
+            .await
+            .map_err(|err| format!("failed to write {}: {err}", path.display()))?;
+        written += chunk.len() as u64;
+        on_progress(written);
+    }
+    file.flush()
+        .await
+        .map_err(|err| format!("failed to flush CSV export {}: {err}", path.display()))?;
+
+    Ok(written)
+}
+
+/// Loads a CSV file into a PostgreSQL table with `COPY ... FROM STDIN`. A
+/// header row is matched to table columns by name, as in the INSERT-based
+/// import; unquoted empty fields load as NULL.
+///
+/// `on_progress` receives the running total of sent bytes. Returns the number
+/// of rows the server copied.
+pub async fn copy_csv_into_table(
+    connection: DatabaseConnection,
+    source: TablePreviewSource,
+    path: PathBuf,
+    options: CsvImportOptions,
+    mut on_progress: impl FnMut(u64),
+) -> Result<u64, String> {
+    let DatabaseConnection::Postgres(pool) = connection else {
+        return Err("COPY import is only available for PostgreSQL".to_string());
+    };
+    let headers = if !options.column_mapping.is_empty() {
+        Some(copy_mapped_columns(&options.column_mapping)?)
+    } else if options.has_header {
+        let header_path = path.clone();
+        let header_options = options.clone();
+        Some(
+            spawn_blocking(move || read_csv_header(&header_path, &header_options))
+                .await
+                .map_err(|err| format!("csv import task failed: {err}"))??,
+        )
+    } else {
+        None
+    };
+    let statement = postgres_copy_from_statement(&source, headers.as_deref(), &options)?;
+
+    let mut file = fs::File::open(&path)
+        .await
+        .map_err(|err| format!("failed to open {}: {err}", path.display()))?;
+    let mut copy = pool
+        .copy_in_raw(&statement)
+        .await
+        .map_err(|err| format!("COPY import failed: {err}"))?;
+
+    let mut buffer = vec![0_u8; COPY_CHUNK_SIZE];
+    let mut sent = 0_u64;
+    loop {
+        let read = match file.read(&mut buffer).await {
+            Ok(read) => read,
+            Err(err) => {
+                let message = format!("failed to read {}: {err}", path.display());
+                let _ = copy.abort(message.clone()).await;
+                return Err(message);
+            }
+        };
+        if read == 0 {
+            break;
+        }
+        copy.send(&buffer[..read])
+            .await
+            .map_err(copy_import_error)?;
+        sent += read as u64;
+        on_progress(sent);
+    }
+
+    copy.finish().await.map_err(copy_import_error)
+}
+
+/// Reads the header and up to `limit` rows of a CSV file so an import can be
+/// previewed and its columns mapped before anything is written.
+pub async fn preview_csv_file(
+    path: PathBuf,
+    options: CsvImportOptions,
+    limit: usize,
+) -> Result<CsvFilePreview, String> {
+    spawn_blocking(move || preview_csv_file_sync(&path, &options, limit))
+        .await
+        .map_err(|err| format!("csv preview task failed: {err}"))?
+}
+
+/// Imports a CSV file into `source`, one multi-row INSERT per batch.
+///
+/// `on_progress` receives the running total of imported rows after each batch.
 pub async fn import_csv_into_table(
     connection: DatabaseConnection,
     source: TablePreviewSource,
     path: PathBuf,
+    options: CsvImportOptions,
+    mut on_progress: impl FnMut(u64),
 ) -> Result<u64, String> {
-    let import = spawn_blocking(move || read_csv_import_data(path))
+    let import = spawn_blocking(move || read_csv_import_data(path, &options))
         .await
         .map_err(|err| format!("csv import task failed: {err}"))??;
 
@@ -77,6 +314,8 @@ pub async fn import_csv_into_table(
         return Ok(0);
     }
 
+    let mut imported = 0_u64;
+
     match connection {
         DatabaseConnection::Sqlite(pool) => {
             let mut transaction = pool
@@ -84,7 +323,7 @@ pub async fn import_csv_into_table(
                 .await
                 .map_err(|err| format!("failed to start SQLite import transaction: {err}"))?;
 
-            for chunk in import.rows.chunks(IMPORT_BATCH_SIZE) {
+            for (chunk, lines) in import.batches() {
                 let sql = build_insert_sql(
                     &source,
                     &import.headers,
@@ -95,7 +334,9 @@ pub async fn import_csv_into_table(
                 sqlx::query(&sql)
                     .execute(&mut *transaction)
                     .await
-                    .map_err(|err| format!("SQLite import failed: {err}"))?;
+                    .map_err(|err| format!("SQLite import failed at {lines}: {err}"))?;
+                imported += chunk.len() as u64;
+                on_progress(imported);
             }
 
             transaction
@@ -109,7 +350,7 @@ pub async fn import_csv_into_table(
                 .await
                 .map_err(|err| format!("failed to start PostgreSQL import transaction: {err}"))?;
 
-            for chunk in import.rows.chunks(IMPORT_BATCH_SIZE) {
+            for (chunk, lines) in import.batches() {
                 let sql = build_insert_sql(
                     &source,
                     &import.headers,
@@ -120,7 +361,9 @@ pub async fn import_csv_into_table(
                 sqlx::query(&sql)
                     .execute(&mut *transaction)
                     .await
-                    .map_err(|err| format!("PostgreSQL import failed: {err}"))?;
+                    .map_err(|err| format!("PostgreSQL import failed at {lines}: {err}"))?;
+                imported += chunk.len() as u64;
+                on_progress(imported);
             }
 
             transaction
@@ -134,7 +377,7 @@ pub async fn import_csv_into_table(
                 .await
                 .map_err(|err| format!("failed to start MySQL import transaction: {err}"))?;
 
-            for chunk in import.rows.chunks(IMPORT_BATCH_SIZE) {
+            for (chunk, lines) in import.batches() {
                 let sql = build_insert_sql(
                     &source,
                     &import.headers,
@@ -145,7 +388,9 @@ pub async fn import_csv_into_table(
                 sqlx::query(&sql)
                     .execute(&mut *transaction)
                     .await
-                    .map_err(|err| format!("MySQL import failed: {err}"))?;
+                    .map_err(|err| format!("MySQL import failed at {lines}: {err}"))?;
+                imported += chunk.len() as u64;
+                on_progress(imported);
             }
 
             transaction
@@ -154,7 +399,7 @@ pub async fn import_csv_into_table(
                 .map_err(|err| format!("failed to commit MySQL import: {err}"))?;
         }
         DatabaseConnection::ClickHouse(config) => {
-            for chunk in import.rows.chunks(IMPORT_BATCH_SIZE) {
+            for (chunk, lines) in import.batches() {
                 let sql = build_insert_sql(
                     &source,
                     &import.headers,
@@ -164,7 +409,9 @@ pub async fn import_csv_into_table(
                 );
                 execute_text_query(&config, &sql)
                     .await
-                    .map_err(|err| format!("ClickHouse import failed: {err}"))?;
+                    .map_err(|err| format!("ClickHouse import failed at {lines}: {err}"))?;
+                imported += chunk.len() as u64;
+                on_progress(imported);
             }
         }
     }
@@ -172,11 +419,40 @@ pub async fn import_csv_into_table(
     Ok(import.rows.len() as u64)
 }
 
-fn export_query_page_csv_sync(page: QueryPage, path: PathBuf) -> Result<usize, String> {
+fn csv_export_writer(
+    path: &Path,
+    options: CsvExportOptions,
+) -> Result<csv::Writer<std::fs::File>, String> {
+    if !options.delimiter.is_ascii() {
+        return Err(format!(
+            "CSV delimiter `{}` must be an ASCII character",
+            options.delimiter
+        ));
+    }
+
+    csv::WriterBuilder::new()
+        .delimiter(options.delimiter as u8)
+        .terminator(if options.crlf {
+            csv::Terminator::CRLF
+        } else {
+            csv::Terminator::Any(b'\n')
+        })
+        .quote_style(if options.quote_all {
+            csv::QuoteStyle::Always
+        } else {
+            csv::QuoteStyle::Necessary
+        })
+        .from_path(path)
+        .map_err(|err| format!("failed to open {} for CSV export: {err}", path.display()))
+}
+
+fn export_query_page_csv_sync(
+    page: QueryPage,
+    path: PathBuf,
+    options: CsvExportOptions,
+) -> Result<usize, String> {
     ensure_parent_dir_sync(&path)?;
-    let mut writer = csv::WriterBuilder::new()
-        .from_path(&path)
-        .map_err(|err| format!("failed to open {} for CSV export: {err}", path.display()))?;
+    let mut writer = csv_export_writer(&path, options)?;
 
     writer
         .write_record(&page.columns)
@@ -251,6 +527,18 @@ fn export_query_page_xml_sync(page: QueryPage, path: PathBuf) -> Result<usize, S
 
 fn export_query_page_html_sync(page: QueryPage, path: PathBuf) -> Result<usize, String> {
     ensure_parent_dir_sync(&path)?;
+    let output = query_page_to_html(&page);
+
+    std::fs::write(&path, output)
+        .map_err(|err| format!("failed to write {}: {err}", path.display()))?;
+
+    Ok(page.rows.len())
+}
+
+/// Renders `page` as a standalone HTML document: one `<table>` with the
+/// column names in `<thead>`, the rows in `<tbody>` and a small inline
+/// stylesheet so the file reads well when opened directly in a browser.
+pub fn query_page_to_html(page: &QueryPage) -> String {
     let mut output = String::new();
     output.push_str("<!DOCTYPE html>\n");
     output.push_str("<html lang=\"en\">\n<head>\n");
@@ -286,45 +574,105 @@ fn export_query_page_html_sync(page: QueryPage, path: PathBuf) -> Result<usize,
     }
     output.push_str("    </tbody>\n  </table>\n</body>\n</html>\n");
 
-    std::fs::write(&path, output)
-        .map_err(|err| format!("failed to write {}: {err}", path.display()))?;
-
-    Ok(page.rows.len())
+    output
 }
 
 fn export_query_page_sql_dump_sync(
     page: QueryPage,
     path: PathBuf,
-    table_name: String,
+    options: SqlInsertExportOptions,
 ) -> Result<usize, String> {
     ensure_parent_dir_sync(&path)?;
-    let mut output = String::new();
+    std::fs::write(&path, query_page_to_insert_script(&page, &options))
+        .map_err(|err| format!("failed to write {}: {err}", path.display()))?;
+
+    Ok(page.rows.len())
+}
+
+/// Renders every row of `page` as one multi-row INSERT into `schema.table`,
+/// for pasting into another database. Returns an empty string when the page
+/// has no rows.
+pub fn query_page_to_insert_sql(page: &QueryPage, schema: Option<&str>, table: &str) -> String {
+    if page.rows.is_empty() {
+        return String::new();
+    }
+
+    insert_statement(
+        &quoted_insert_target(schema, table),
+        &quoted_insert_columns(&page.columns),
+        &page.rows,
+        |_, cell| sql_literal(cell),
+    )
+}
 
-    let columns = page
+/// Renders `page` as an INSERT script, `options.batch_size` rows per
+/// statement. Cells of numeric and boolean columns are written unquoted when
+/// their text parses as that type.
+fn query_page_to_insert_script(page: &QueryPage, options: &SqlInsertExportOptions) -> String {
+    let target = quoted_insert_target(options.schema.as_deref(), &options.table_name);
+    let columns = quoted_insert_columns(&page.columns);
+    let types = page
         .columns
         .iter()
-        .map(|c| quote_sql_identifier(c))
-        .collect::<Vec<_>>()
-        .join(", ");
+        .map(|column| options.column_types.get(column).map(String::as_str))
+        .collect::<Vec<_>>();
 
-    for row in &page.rows {
-        let values = row
-            .iter()
-            .map(|v| sql_literal(v))
-            .collect::<Vec<_>>()
-            .join(", ");
-        output.push_str(&format!(
-            "INSERT INTO {} ({}) VALUES ({});\n",
-            quote_sql_identifier(&table_name),
-            columns,
-            values
-        ));
+    page.rows
+        .chunks(options.batch_size.max(1))
+        .map(|rows| {
+            insert_statement(&target, &columns, rows, |index, cell| {
+                typed_sql_literal(cell, types.get(index).copied().flatten())
+            })
+        })
+        .collect()
+}
+
+fn insert_statement(
+    target: &str,
+    columns: &str,
+    rows: &[Vec<String>],
+    literal: impl Fn(usize, &str) -> String,
+) -> String {
+    let values = rows
+        .iter()
+        .map(|row| {
+            let literals = row
+                .iter()
+                .enumerate()
+                .map(|(index, cell)| literal(index, cell))
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!("({literals})")
+        })
+        .collect::<Vec<_>>();
+
+    if let [values] = values.as_slice() {
+        format!("INSERT INTO {target} ({columns}) VALUES {values};\n")
+    } else {
+        format!(
+            "INSERT INTO {target} ({columns}) VALUES\n  {};\n",
+            values.join(",\n  ")
+        )
     }
+}
 
-    std::fs::write(&path, output)
-        .map_err(|err| format!("failed to write {}: {err}", path.display()))?;
+fn quoted_insert_target(schema: Option<&str>, table: &str) -> String {
+    match schema {
+        Some(schema) => format!(
+            "{}.{}",
+            quote_sql_identifier(schema),
+            quote_sql_identifier(table)
+        ),
+        None => quote_sql_identifier(table),
+    }
+}
 
-    Ok(page.rows.len())
+fn quoted_insert_columns(columns: &[String]) -> String {
+    columns
+        .iter()
+        .map(|c| quote_sql_identifier(c))
+        .collect::<Vec<_>>()
+        .join(", ")
 }
 
 fn escape_xml(s: &str) -> String {
@@ -342,31 +690,340 @@ fn escape_html(s: &str) -> String {
         .replace('"', "&quot;")
 }
 
-fn query_page_to_json(page: QueryPage) -> Value {
-    let rows = page
-        .rows
+fn query_page_to_json_export(
+    page: QueryPage,
+    options: &JsonExportOptions,
+) -> Result<String, serde_json::Error> {
+    let columns = page.columns.clone();
+    let rows = query_page_to_json_rows(page, &options.column_types);
+
+    match options.format {
+        JsonExportFormat::Array => serde_json::to_string_pretty(&Value::Array(rows)),
+        JsonExportFormat::Lines => rows.iter().try_fold(String::new(), |mut output, row| {
+            output.push_str(&serde_json::to_string(row)?);
+            output.push('\n');
+            Ok(output)
+        }),
+        JsonExportFormat::ArrayWithMetadata => {
+            let exported_at = time::OffsetDateTime::now_utc()
+                .format(&time::format_description::well_known::Rfc3339)
+                .unwrap_or_default();
+            let columns = columns
+                .iter()
+                .map(|name| {
+                    let mut column = Map::with_capacity(2);
+                    column.insert("name".to_string(), Value::String(name.clone()));
+                    column.insert(
+                        "type".to_string(),
+                        options
+                            .column_types
+                            .get(name)
+                            .map_or(Value::Null, |data_type| Value::String(data_type.clone())),
+                    );
+                    Value::Object(column)
+                })
+                .collect();
+            let optional_string =
+                |value: &Option<String>| value.clone().map_or(Value::Null, Value::String);
+
+            let mut wrapper = Map::with_capacity(6);
+            wrapper.insert("schema".to_string(), optional_string(&options.schema));
+            wrapper.insert("table".to_string(), optional_string(&options.table_name));
+            wrapper.insert("exported_at".to_string(), Value::String(exported_at));
+            wrapper.insert("row_count".to_string(), Value::from(rows.len()));
+            wrapper.insert("columns".to_string(), Value::Array(columns));
+            wrapper.insert("rows".to_string(), Value::Array(rows));
+            serde_json::to_string_pretty(&Value::Object(wrapper))
+        }
+    }
+}
+
+fn query_page_to_json_rows(page: QueryPage, column_types: &HashMap<String, String>) -> Vec<Value> {
+    page.rows
         .into_iter()
         .map(|row| {
             let mut item = Map::with_capacity(page.columns.len());
             for (index, column_name) in page.columns.iter().enumerate() {
-                item.insert(
-                    column_name.clone(),
-                    Value::String(row.get(index).cloned().unwrap_or_default()),
-                );
+                let value = match row.get(index) {
+                    Some(cell) => {
+                        typed_json_value(cell, column_types.get(column_name).map(String::as_str))
+                    }
+                    None => Value::String(String::new()),
+                };
+                item.insert(column_name.clone(), value);
             }
             Value::Object(item)
         })
+        .collect()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum JsonValueKind {
+    Integer,
+    Float,
+    Boolean,
+    Json,
+    Text,
+}
+
+/// Converts a grid cell to the JSON value its declared column type implies,
+/// falling back to a string when the type is unknown or the text does not
+/// parse as that type.
+fn typed_json_value(cell: &str, data_type: Option<&str>) -> Value {
+    if cell == "NULL" {
+        return Value::Null;
+    }
+
+    let text = || Value::String(cell.to_string());
+    match data_type.map_or(JsonValueKind::Text, json_value_kind) {
+        JsonValueKind::Integer => cell
+            .parse::<i64>()
+            .map(Value::from)
+            .or_else(|_| cell.parse::<u64>().map(Value::from))
+            .unwrap_or_else(|_| text()),
+        JsonValueKind::Float => cell
+            .parse::<f64>()
+            .ok()
+            .and_then(serde_json::Number::from_f64)
+            .map_or_else(text, Value::Number),
+        JsonValueKind::Boolean => match cell.to_ascii_lowercase().as_str() {
+            "true" | "t" | "1" => Value::Bool(true),
+            "false" | "f" | "0" => Value::Bool(false),
+            _ => text(),
+        },
+        JsonValueKind::Json => serde_json::from_str(cell).unwrap_or_else(|_| text()),
+        JsonValueKind::Text => text(),
+    }
+}
+
+/// Renders a grid cell as an SQL literal for its declared column type:
+/// numbers and booleans unquoted when the text parses as one, anything else
+/// as a quoted string.
+fn typed_sql_literal(cell: &str, data_type: Option<&str>) -> String {
+    if cell == "NULL" {
+        return "NULL".to_string();
+    }
+
+    let Some(data_type) = data_type else {
+        return sql_literal(cell);
+    };
+    let is_number = || cell.parse::<f64>().is_ok_and(f64::is_finite);
+    match json_value_kind(data_type) {
+        JsonValueKind::Integer if cell.parse::<i128>().is_ok() => cell.to_string(),
+        JsonValueKind::Float if is_number() => cell.to_string(),
+        JsonValueKind::Boolean => match cell.to_ascii_lowercase().as_str() {
+            "true" | "t" | "1" => "TRUE".to_string(),
+            "false" | "f" | "0" => "FALSE".to_string(),
+            _ => sql_literal(cell),
+        },
+        JsonValueKind::Text
+            if matches!(base_column_type(data_type).as_str(), "numeric" | "decimal")
+                && is_number() =>
+        {
+            cell.to_string()
+        }
+        _ => sql_literal(cell),
+    }
+}
+
+fn json_value_kind(data_type: &str) -> JsonValueKind {
+    match base_column_type(data_type).as_str() {
+        "smallint" | "integer" | "int" | "bigint" | "tinyint" | "mediumint" | "int2" | "int4"
+        | "int8" | "int16" | "int32" | "int64" | "uint8" | "uint16" | "uint32" | "uint64"
+        | "serial" | "smallserial" | "bigserial" => JsonValueKind::Integer,
+        "real" | "float" | "double" | "float4" | "float8" | "float32" | "float64" => {
+            JsonValueKind::Float
+        }
+        "bool" | "boolean" => JsonValueKind::Boolean,
+        "json" | "jsonb" => JsonValueKind::Json,
+        _ => JsonValueKind::Text,
+    }
+}
+
+/// Lowercased type name without its modifiers, e.g. `numeric` for
+/// `NUMERIC(10, 2)` or `int32` for ClickHouse `Nullable(Int32)`. Array types
+/// come back whole so they never match a scalar name.
+fn base_column_type(data_type: &str) -> String {
+    let mut normalized = data_type.trim().to_ascii_lowercase();
+    // ClickHouse wraps column types, e.g. `Nullable(Int32)`.
+    while let Some(inner) = ["nullable(", "lowcardinality("].iter().find_map(|prefix| {
+        normalized
+            .strip_prefix(prefix)
+            .and_then(|rest| rest.strip_suffix(')'))
+    }) {
+        normalized = inner.to_string();
+    }
+    if normalized.ends_with("[]") {
+        return normalized;
+    }
+
+    normalized
+        .split(['(', ' '])
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// Renders a page as a GitHub-Flavored Markdown table with columns padded to
+/// a common width, ready to paste into docs or chat.
+pub fn query_page_to_markdown(page: &QueryPage) -> String {
+    let header = page
+        .columns
+        .iter()
+        .map(|column| escape_markdown_cell(column))
+        .collect::<Vec<_>>();
+    let rows = page
+        .rows
+        .iter()
+        .map(|row| {
+            (0..header.len())
+                .map(|index| {
+                    let cell = row.get(index).map(String::as_str).unwrap_or_default();
+                    escape_markdown_cell(cell)
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let widths = header
+        .iter()
+        .enumerate()
+        .map(|(index, column)| {
+            rows.iter()
+                .map(|row| row[index].chars().count())
+                .chain([column.chars().count(), 3])
+                .max()
+                .unwrap_or(3)
+        })
         .collect::<Vec<_>>();
 
-    Value::Array(rows)
+    let render_row = |cells: &[String]| {
+        let cells = cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| {
+                let padding = width - cell.chars().count();
+                format!("{cell}{}", " ".repeat(padding))
+            })
+            .collect::<Vec<_>>();
+        format!("| {} |\n", cells.join(" | "))
+    };
+
+    let mut output = render_row(&header);
+    let separator = widths
+        .iter()
+        .map(|width| "-".repeat(*width))
+        .collect::<Vec<_>>();
+    output.push_str(&format!("| {} |\n", separator.join(" | ")));
+    for row in &rows {
+        output.push_str(&render_row(row));
+    }
+    output
 }
 
-fn read_csv_import_data(path: PathBuf) -> Result<CsvImportData, String> {
-    let mut reader = csv::ReaderBuilder::new()
-        .has_headers(true)
+fn escape_markdown_cell(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('|', "\\|")
+        .replace("\r\n", "<br>")
+        .replace(['\n', '\r'], "<br>")
+}
+
+fn read_csv_import_data(
+    path: PathBuf,
+    options: &CsvImportOptions,
+) -> Result<CsvImportData, String> {
+    let mut reader = csv_import_reader(options)?
         .from_path(&path)
         .map_err(|err| format!("failed to open {}: {err}", path.display()))?;
 
+    let file_headers = if options.has_header {
+        Some(
+            reader
+                .headers()
+                .map_err(|err| format!("failed to read CSV header from {}: {err}", path.display()))?
+                .iter()
+                .enumerate()
+                .map(|(index, header)| normalize_header(index, header))
+                .collect::<Result<Vec<_>, _>>()?,
+        )
+    } else {
+        None
+    };
+
+    let mut records = reader.records().peekable();
+    // File column and target column of every imported value.
+    let (width, targets) = if !options.column_mapping.is_empty() {
+        let targets = options
+            .column_mapping
+            .iter()
+            .enumerate()
+            .filter_map(|(index, column)| column.clone().map(|column| (index, column)))
+            .collect::<Vec<_>>();
+        if targets.is_empty() {
+            return Err("Map at least one CSV column to a table column".to_string());
+        }
+        (options.column_mapping.len(), targets)
+    } else {
+        let headers = match file_headers {
+            Some(headers) => headers,
+            None => {
+                let width = match records.peek() {
+                    Some(Ok(record)) => record.len(),
+                    Some(Err(_)) | None => options.columns.len(),
+                };
+                if width > options.columns.len() {
+                    return Err(format!(
+                        "CSV rows have {width} columns, but the table only has {}",
+                        options.columns.len()
+                    ));
+                }
+                options.columns[..width].to_vec()
+            }
+        };
+        (
+            headers.len(),
+            headers.into_iter().enumerate().collect::<Vec<_>>(),
+        )
+    };
+    let headers = targets
+        .iter()
+        .map(|(_, column)| column.clone())
+        .collect::<Vec<_>>();
+
+    validate_headers(&headers)?;
+
+    let mut rows = Vec::new();
+    let mut lines = Vec::new();
+    for record in records {
+        let record = record.map_err(|err| format!("failed to parse CSV row: {err}"))?;
+        let line = record.position().map_or(0, csv::Position::line);
+        if record.len() != width {
+            return Err(format!(
+                "CSV line {line} has {} columns, expected {width}",
+                record.len()
+            ));
+        }
+        rows.push(
+            targets
+                .iter()
+                .map(|(index, _)| record[*index].to_string())
+                .collect(),
+        );
+        lines.push(line);
+    }
+
+    Ok(CsvImportData {
+        headers,
+        rows,
+        lines,
+    })
+}
+
+fn read_csv_header(path: &Path, options: &CsvImportOptions) -> Result<Vec<String>, String> {
+    let mut reader = csv_import_reader(options)?
+        .has_headers(true)
+        .from_path(path)
+        .map_err(|err| format!("failed to open {}: {err}", path.display()))?;
     let headers = reader
         .headers()
         .map_err(|err| format!("failed to read CSV header from {}: {err}", path.display()))?
@@ -376,21 +1033,157 @@ fn read_csv_import_data(path: PathBuf) -> Result<CsvImportData, String> {
         .collect::<Result<Vec<_>, _>>()?;
 
     validate_headers(&headers)?;
+    Ok(headers)
+}
 
-    let mut rows = Vec::new();
-    for record in reader.records() {
-        let record = record.map_err(|err| format!("failed to parse CSV row: {err}"))?;
-        if record.len() != headers.len() {
-            return Err(format!(
-                "CSV row has {} columns, expected {}",
-                record.len(),
-                headers.len()
-            ));
-        }
-        rows.push(record.iter().map(ToString::to_string).collect());
+fn preview_csv_file_sync(
+    path: &Path,
+    options: &CsvImportOptions,
+    limit: usize,
+) -> Result<CsvFilePreview, String> {
+    let mut reader = csv_import_reader(options)?
+        .flexible(true)
+        .from_path(path)
+        .map_err(|err| format!("failed to open {}: {err}", path.display()))?;
+
+    let mut headers = if options.has_header {
+        reader
+            .headers()
+            .map_err(|err| format!("failed to read CSV header from {}: {err}", path.display()))?
+            .iter()
+            .enumerate()
+            .map(|(index, header)| {
+                normalize_header(index, header).unwrap_or_else(|_| format!("column_{}", index + 1))
+            })
+            .collect()
+    } else {
+        Vec::new()
+    };
+    let rows = reader
+        .records()
+        .take(limit)
+        .map(|record| {
+            record
+                .map(|record| record.iter().map(ToString::to_string).collect::<Vec<_>>())
+                .map_err(|err| format!("failed to parse CSV row: {err}"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    while headers.len() < width {
+        headers.push(format!("column_{}", headers.len() + 1));
+    }
+
+    Ok(CsvFilePreview { headers, rows })
+}
+
+fn csv_import_reader(options: &CsvImportOptions) -> Result<csv::ReaderBuilder, String> {
+    let delimiter = u8::try_from(options.delimiter).map_err(|_| {
+        format!(
+            "CSV delimiter `{}` must be an ASCII character",
+            options.delimiter
+        )
+    })?;
+    let quote = u8::try_from(options.quote)
+        .map_err(|_| format!("CSV quote `{}` must be an ASCII character", options.quote))?;
+
+    let mut builder = csv::ReaderBuilder::new();
+    builder
+        .has_headers(options.has_header)
+        .delimiter(delimiter)
+        .quote(quote);
+    Ok(builder)
+}
+
+fn postgres_copy_to_statement(
+    source: &TablePreviewSource,
+    options: CsvExportOptions,
+) -> Result<String, String> {
+    let mut settings = vec![
+        "format csv".to_string(),
+        "header true".to_string(),
+        format!("delimiter {}", copy_delimiter_literal(options.delimiter)?),
+    ];
+    if options.quote_all {
+        settings.push("force_quote *".to_string());
+    }
+
+    Ok(format!(
+        "copy {} to stdout with ({})",
+        source.qualified_name,
+        settings.join(", ")
+    ))
+}
+
+fn postgres_copy_from_statement(
+    source: &TablePreviewSource,
+    headers: Option<&[String]>,
+    options: &CsvImportOptions,
+) -> Result<String, String> {
+    let columns = headers
+        .map(|headers| {
+            let columns = headers
+                .iter()
+                .map(|header| quote_sql_identifier(header))
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!(" ({columns})")
+        })
+        .unwrap_or_default();
+
+    let quote = if options.quote == '"' {
+        String::new()
+    } else {
+        format!(", quote {}", copy_delimiter_literal(options.quote)?)
+    };
+
+    Ok(format!(
+        "copy {}{columns} from stdin with (format csv, header {}, delimiter {}{quote})",
+        source.qualified_name,
+        options.has_header,
+        copy_delimiter_literal(options.delimiter)?
+    ))
+}
+
+/// COPY loads every file column, so each one needs a target column.
+fn copy_mapped_columns(mapping: &[Option<String>]) -> Result<Vec<String>, String> {
+    let columns = mapping
+        .iter()
+        .map(|column| {
+            column.clone().ok_or_else(|| {
+                "COPY import loads every CSV column; map each one or use the regular import to skip columns"
+                    .to_string()
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    validate_headers(&columns)?;
+    Ok(columns)
+}
+
+/// Adds the server's error context, which names the offending line as in
+/// `COPY users, line 3, column id: "x"`.
+fn copy_import_error(err: sqlx::Error) -> String {
+    let context = err
+        .as_database_error()
+        .and_then(|err| err.try_downcast_ref::<sqlx::postgres::PgDatabaseError>())
+        .and_then(|err| err.r#where())
+        .map(ToString::to_string);
+    match context {
+        Some(context) => format!("COPY import failed: {err} ({context})"),
+        None => format!("COPY import failed: {err}"),
     }
+}
 
-    Ok(CsvImportData { headers, rows })
+fn copy_delimiter_literal(delimiter: char) -> Result<String, String> {
+    if !delimiter.is_ascii() {
+        return Err(format!(
+            "CSV delimiter `{delimiter}` must be an ASCII character"
+        ));
+    }
+    Ok(match delimiter {
+        '\t' => r"E'\t'".to_string(),
+        other => sql_literal(&other.to_string()),
+    })
 }
 
 fn validate_headers(headers: &[String]) -> Result<(), String> {
@@ -495,7 +1288,107 @@ mod tests {
     use super::*;
     use models::{QueryPage, TablePreviewSource};
 
-    // ── query_page_to_json ────────────────────────────────────────────
+    // ── query_page_to_markdown ────────────────────────────────────────
+
+    #[test]
+    fn markdown_table_pads_columns_to_a_common_width() {
+        let page = sample_page(
+            vec!["id", "name"],
+            vec![vec!["1", "Keyboard"], vec!["22", "Mouse"]],
+        );
+
+        assert_eq!(
+            query_page_to_markdown(&page),
+            "| id  | name     |\n\
+             | --- | -------- |\n\
+             | 1   | Keyboard |\n\
+             | 22  | Mouse    |\n"
+        );
+    }
+
+    #[test]
+    fn markdown_table_escapes_pipes_and_newlines() {
+        let page = sample_page(vec!["note"], vec![vec!["a|b\nc"]]);
+
+        assert_eq!(
+            query_page_to_markdown(&page),
+            "| note      |\n| --------- |\n| a\\|b<br>c |\n"
+        );
+    }
+
+    // ── query_page_to_html ────────────────────────────────────────────
+
+    #[test]
+    fn html_table_puts_headers_in_thead_and_escapes_cells() {
+        let page = sample_page(vec!["id", "note"], vec![vec!["1", "<b>&</b>"]]);
+
+        let html = query_page_to_html(&page);
+
+        assert!(html.starts_with("<!DOCTYPE html>\n"));
+        assert!(html.contains(
+            "<thead>\n      <tr>\n        <th>id</th>\n        <th>note</th>\n      </tr>\n    </thead>"
+        ));
+        assert!(html.contains("<tbody>\n      <tr>\n        <td>1</td>\n"));
+        assert!(html.contains("<td>&lt;b&gt;&amp;&lt;/b&gt;</td>"));
+        assert!(html.ends_with("</tbody>\n  </table>\n</body>\n</html>\n"));
+    }
+
+    // ── query_page_to_insert_sql ──────────────────────────────────────
+
+    #[test]
+    fn insert_sql_qualifies_table_and_quotes_values() {
+        let page = sample_page(
+            vec!["id", "note"],
+            vec![vec!["1", "it's"], vec!["2", "NULL"]],
+        );
+
+        assert_eq!(
+            query_page_to_insert_sql(&page, Some("public"), "notes"),
+            "INSERT INTO \"public\".\"notes\" (\"id\", \"note\") VALUES\n  ('1', 'it''s'),\n  ('2', NULL);\n"
+        );
+    }
+
+    #[test]
+    fn insert_sql_is_empty_without_rows() {
+        let page = sample_page(vec!["id"], vec![]);
+
+        assert_eq!(query_page_to_insert_sql(&page, None, "notes"), "");
+    }
+
+    #[test]
+    fn insert_script_batches_rows_and_leaves_typed_values_unquoted() {
+        let page = sample_page(
+            vec!["id", "price", "active", "note"],
+            vec![
+                vec!["1", "9.50", "t", "it's"],
+                vec!["2", "NULL", "false", "42"],
+                vec!["3", "n/a", "f", "NULL"],
+            ],
+        );
+        let options = SqlInsertExportOptions {
+            schema: Some("shop".to_string()),
+            table_name: "items".to_string(),
+            batch_size: 2,
+            column_types: HashMap::from([
+                ("id".to_string(), "integer".to_string()),
+                ("price".to_string(), "numeric(10,2)".to_string()),
+                ("active".to_string(), "boolean".to_string()),
+                ("note".to_string(), "text".to_string()),
+            ]),
+        };
+
+        assert_eq!(
+            query_page_to_insert_script(&page, &options),
+            concat!(
+                "INSERT INTO \"shop\".\"items\" (\"id\", \"price\", \"active\", \"note\") VALUES\n",
+                "  (1, 9.50, TRUE, 'it''s'),\n",
+                "  (2, NULL, FALSE, '42');\n",
+                "INSERT INTO \"shop\".\"items\" (\"id\", \"price\", \"active\", \"note\") VALUES (3, 'n/a', FALSE, NULL);\n",
+            )
+        );
+    }
+
+    // ── query_page_to_json_rows ───────────────────────────────────────
 
     fn sample_page(columns: Vec<&str>, rows: Vec<Vec<&str>>) -> QueryPage {
         QueryPage {
@@ -515,15 +1408,14 @@ mod tests {
     #[test]
     fn query_page_to_json_empty_rows() {
         let page = sample_page(vec!["id", "name"], vec![]);
-        let json = query_page_to_json(page);
-        assert_eq!(json, serde_json::Value::Array(vec![]));
+        let rows = query_page_to_json_rows(page, &HashMap::new());
+        assert!(rows.is_empty());
     }
 
     #[test]
     fn query_page_to_json_single_row() {
         let page = sample_page(vec!["id", "name"], vec![vec!["1", "Alice"]]);
-        let json = query_page_to_json(page);
-        let arr = json.as_array().unwrap();
+        let arr = query_page_to_json_rows(page, &HashMap::new());
         assert_eq!(arr.len(), 1);
         assert_eq!(arr[0]["id"], "1");
         assert_eq!(arr[0]["name"], "Alice");
@@ -535,8 +1427,7 @@ mod tests {
             vec!["id", "name"],
             vec![vec!["1", "Alice"], vec!["2", "Bob"]],
         );
-        let json = query_page_to_json(page);
-        let arr = json.as_array().unwrap();
+        let arr = query_page_to_json_rows(page, &HashMap::new());
         assert_eq!(arr.len(), 2);
         assert_eq!(arr[0]["name"], "Alice");
         assert_eq!(arr[1]["name"], "Bob");
@@ -553,8 +1444,7 @@ mod tests {
             has_previous: false,
             has_next: false,
         };
-        let json = query_page_to_json(page);
-        let arr = json.as_array().unwrap();
+        let arr = query_page_to_json_rows(page, &HashMap::new());
         assert_eq!(arr[0]["id"], "1");
         assert_eq!(arr[0]["name"], ""); // default for missing
     }
@@ -775,4 +1665,363 @@ mod tests {
 
         assert!(sql.contains("`id`"));
     }
+
+    fn write_temp_csv(name: &str, content: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("shovel-{}-{name}", std::process::id()));
+        std::fs::write(&path, content).expect("write temp csv");
+        path
+    }
+
+    #[test]
+    fn read_csv_import_data_honors_custom_delimiter() {
+        let path = write_temp_csv("semicolon.csv", "id;name\n1;Alice\n2;Bob\n");
+        let options = CsvImportOptions {
+            delimiter: ';',
+            ..CsvImportOptions::default()
+        };
+
+        let import = read_csv_import_data(path.clone(), &options).expect("read csv");
+        let _ = std::fs::remove_file(path);
+
+        assert_eq!(import.headers, vec!["id", "name"]);
+        assert_eq!(import.rows.len(), 2);
+        assert_eq!(import.rows[1], vec!["2", "Bob"]);
+    }
+
+    #[test]
+    fn read_csv_import_data_without_header_uses_table_columns() {
+        let path = write_temp_csv("headerless.csv", "1,Alice\n2,Bob\n");
+        let options = CsvImportOptions {
+            has_header: false,
+            delimiter: ',',
+            columns: vec!["id".to_string(), "name".to_string(), "email".to_string()],
+            ..CsvImportOptions::default()
+        };
+
+        let import = read_csv_import_data(path.clone(), &options).expect("read csv");
+        let _ = std::fs::remove_file(path);
+
+        assert_eq!(import.headers, vec!["id", "name"]);
+        assert_eq!(import.rows[0], vec!["1", "Alice"]);
+    }
+
+    #[test]
+    fn read_csv_import_data_rejects_rows_wider_than_table() {
+        let path = write_temp_csv("too-wide.csv", "1,Alice,extra\n");
+        let options = CsvImportOptions {
+            has_header: false,
+            delimiter: ',',
+            columns: vec!["id".to_string(), "name".to_string()],
+            ..CsvImportOptions::default()
+        };
+
+        let result = read_csv_import_data(path.clone(), &options);
+        let _ = std::fs::remove_file(path);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn read_csv_import_data_applies_column_mapping_and_reports_lines() {
+        let path = write_temp_csv(
+            "mapped.csv",
+            "name,skip,id\n'Ann, Lee',x,1\nBob,y,2\nCarl,z\n",
+        );
+        let mut options = CsvImportOptions {
+            quote: '\'',
+            column_mapping: vec![Some("full_name".to_string()), None, Some("id".to_string())],
+            ..CsvImportOptions::default()
+        };
+
+        let result = read_csv_import_data(path.clone(), &options);
+        assert!(result.err().is_some_and(|err| err.contains("line: 4")));
+
+        std::fs::write(&path, "name,skip,id\n'Ann, Lee',x,1\nBob,y,2\n").unwrap();
+        let import = read_csv_import_data(path.clone(), &options).expect("read csv");
+        options.column_mapping = vec![None, None, None];
+        let unmapped = read_csv_import_data(path.clone(), &options);
+        let _ = std::fs::remove_file(path);
+
+        assert_eq!(import.headers, vec!["full_name", "id"]);
+        assert_eq!(import.rows[0], vec!["Ann, Lee", "1"]);
+        assert_eq!(import.lines, vec![2, 3]);
+        assert_eq!(
+            import.batches().map(|(_, lines)| lines).collect::<Vec<_>>(),
+            vec!["lines 2-3".to_string()]
+        );
+        assert!(unmapped.is_err());
+    }
+
+    #[test]
+    fn preview_csv_file_names_columns_past_the_header() {
+        let path = write_temp_csv("preview.csv", "id,name\n1,Ann\n2,Bob,extra\n3,Carl\n");
+        let options = CsvImportOptions::default();
+
+        let preview = preview_csv_file_sync(&path, &options, 2).expect("preview csv");
+        let _ = std::fs::remove_file(path);
+
+        assert_eq!(preview.headers, vec!["id", "name", "column_3"]);
+        assert_eq!(
+            preview.rows,
+            vec![vec!["1", "Ann"], vec!["2", "Bob", "extra"]]
+        );
+    }
+
+    #[tokio::test]
+    async fn import_csv_into_table_reports_progress() {
+        let pool = sqlx::SqlitePool::connect(":memory:").await.unwrap();
+        sqlx::query("create table users (id integer, name text)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let rows = (0..IMPORT_BATCH_SIZE + 5)
+            .map(|id| format!("{id},user{id}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let path = write_temp_csv("progress.csv", &format!("id,name\n{rows}\n"));
+        let source = TablePreviewSource {
+            schema: None,
+            table_name: "users".to_string(),
+            qualified_name: "users".to_string(),
+        };
+        let mut progress = Vec::new();
+
+        let imported = import_csv_into_table(
+            DatabaseConnection::Sqlite(pool),
+            source,
+            path.clone(),
+            CsvImportOptions::default(),
+            |rows| progress.push(rows),
+        )
+        .await
+        .expect("import csv");
+        let _ = std::fs::remove_file(path);
+
+        assert_eq!(imported, (IMPORT_BATCH_SIZE + 5) as u64);
+        assert_eq!(
+            progress,
+            vec![IMPORT_BATCH_SIZE as u64, (IMPORT_BATCH_SIZE + 5) as u64]
+        );
+    }
+
+    #[tokio::test]
+    async fn export_query_result_csv_pages_through_the_whole_table() {
+        let pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect(":memory:")
+            .await
+            .unwrap();
+        sqlx::query("create table users (id integer primary key, name text)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let total = EXPORT_PAGE_SIZE as usize + 3;
+        for id in 0..total {
+            sqlx::query("insert into users (id, name) values (?, ?)")
+                .bind(id as i64)
+                .bind(format!("user {id}"))
+                .execute(&pool)
+                .await
+                .unwrap();
+        }
+        let path =
+            std::env::temp_dir().join(format!("shovel-{}-full-export.csv", std::process::id()));
+        let source = QueryExportSource::Table(TablePreviewSource {
+            schema: None,
+            table_name: "users".to_string(),
+            qualified_name: "users".to_string(),
+        });
+        let options = CsvExportOptions {
+            delimiter: ';',
+            crlf: true,
+            quote_all: true,
+        };
+        let mut progress = Vec::new();
+
+        let written = export_query_result_csv(
+            DatabaseConnection::Sqlite(pool),
+            source,
+            None,
+            None,
+            path.clone(),
+            options,
+            |rows| progress.push(rows),
+        )
+        .await
+        .expect("export csv");
+        let content = std::fs::read_to_string(&path).expect("read exported csv");
+        let _ = std::fs::remove_file(path);
+
+        assert_eq!(written, total);
+        assert_eq!(progress, vec![EXPORT_PAGE_SIZE as usize, total]);
+        assert!(content.starts_with("\"id\";\"name\"\r\n\"0\";\"user 0\"\r\n"));
+        assert_eq!(content.matches("\r\n").count(), total + 1);
+    }
+
+    #[test]
+    fn query_page_to_json_uses_declared_column_types() {
+        let page = sample_page(
+            vec!["id", "price", "active", "tags", "note", "payload"],
+            vec![vec!["7", "1.5", "t", "NULL", "007", r#"{"a":[1,2]}"#]],
+        );
+        let column_types = [
+            ("id", "integer"),
+            ("price", "double precision"),
+            ("active", "boolean"),
+            ("tags", "text[]"),
+            ("note", "character varying(20)"),
+            ("payload", "jsonb"),
+        ]
+        .into_iter()
+        .map(|(name, data_type)| (name.to_string(), data_type.to_string()))
+        .collect();
+
+        let rows = query_page_to_json_rows(page, &column_types);
+        let row = &rows[0];
+
+        assert_eq!(row["id"], serde_json::json!(7));
+        assert_eq!(row["price"], serde_json::json!(1.5));
+        assert_eq!(row["active"], serde_json::json!(true));
+        assert_eq!(row["tags"], Value::Null);
+        assert_eq!(row["note"], serde_json::json!("007"));
+        assert_eq!(row["payload"], serde_json::json!({ "a": [1, 2] }));
+    }
+
+    #[test]
+    fn json_value_kind_unwraps_clickhouse_types() {
+        assert_eq!(
+            json_value_kind("LowCardinality(Nullable(UInt64))"),
+            JsonValueKind::Integer
+        );
+        assert_eq!(json_value_kind("Nullable(Float32)"), JsonValueKind::Float);
+        assert_eq!(json_value_kind("Array(Int32)"), JsonValueKind::Text);
+    }
+
+    #[test]
+    fn json_lines_export_writes_one_object_per_line() {
+        let page = sample_page(vec!["id"], vec![vec!["1"], vec!["2"]]);
+        let options = JsonExportOptions {
+            format: JsonExportFormat::Lines,
+            column_types: HashMap::from([("id".to_string(), "bigint".to_string())]),
+            ..JsonExportOptions::default()
+        };
+
+        let output = query_page_to_json_export(page, &options).unwrap();
+
+        assert_eq!(output, "{\"id\":1}\n{\"id\":2}\n");
+    }
+
+    #[test]
+    fn json_metadata_export_wraps_rows_with_the_source_table() {
+        let page = sample_page(vec!["id"], vec![vec!["1"]]);
+        let options = JsonExportOptions {
+            format: JsonExportFormat::ArrayWithMetadata,
+            schema: Some("public".to_string()),
+            table_name: Some("users".to_string()),
+            column_types: HashMap::from([("id".to_string(), "integer".to_string())]),
+        };
+
+        let output = query_page_to_json_export(page, &options).unwrap();
+        let wrapper: Value = serde_json::from_str(&output).unwrap();
+
+        assert_eq!(wrapper["schema"], "public");
+        assert_eq!(wrapper["table"], "users");
+        assert_eq!(wrapper["row_count"], 1);
+        assert_eq!(
+            wrapper["columns"],
+            serde_json::json!([{ "name": "id", "type": "integer" }])
+        );
+        assert_eq!(wrapper["rows"], serde_json::json!([{ "id": 1 }]));
+        assert!(
+            wrapper["exported_at"]
+                .as_str()
+                .is_some_and(|value| !value.is_empty())
+        );
+    }
+
+    // ── postgres COPY statements ──────────────────────────────────────
+
+    #[test]
+    fn copy_to_statement_applies_delimiter_and_quoting() {
+        let source = TablePreviewSource {
+            schema: Some("public".to_string()),
+            table_name: "users".to_string(),
+            qualified_name: r#""public"."users""#.to_string(),
+        };
+        let options = CsvExportOptions {
+            delimiter: '\t',
+            crlf: false,
+            quote_all: true,
+        };
+
+        let statement = postgres_copy_to_statement(&source, options).unwrap();
+
+        assert_eq!(
+            statement,
+            r#"copy "public"."users" to stdout with (format csv, header true, delimiter E'\t', force_quote *)"#
+        );
+    }
+
+    #[test]
+    fn copy_from_statement_lists_header_columns() {
+        let source = TablePreviewSource {
+            schema: None,
+            table_name: "users".to_string(),
+            qualified_name: "users".to_string(),
+        };
+        let headers = vec!["id".to_string(), "full name".to_string()];
+        let options = CsvImportOptions {
+            delimiter: ';',
+            ..CsvImportOptions::default()
+        };
+
+        let statement = postgres_copy_from_statement(&source, Some(&headers), &options).unwrap();
+
+        assert_eq!(
+            statement,
+            r#"copy users ("id", "full name") from stdin with (format csv, header true, delimiter ';')"#
+        );
+    }
+
+    #[test]
+    fn postgres_copy_from_statement_uses_mapping_and_quote() {
+        let source = TablePreviewSource {
+            schema: None,
+            table_name: "users".to_string(),
+            qualified_name: "users".to_string(),
+        };
+        let options = CsvImportOptions {
+            quote: '\'',
+            ..CsvImportOptions::default()
+        };
+        let columns = copy_mapped_columns(&[Some("id".to_string()), Some("name".to_string())])
+            .expect("every column mapped");
+
+        assert_eq!(
+            postgres_copy_from_statement(&source, Some(&columns), &options).unwrap(),
+            r#"copy users ("id", "name") from stdin with (format csv, header true, delimiter ',', quote '''')"#
+        );
+        assert!(copy_mapped_columns(&[Some("id".to_string()), None]).is_err());
+    }
+
+    #[tokio::test]
+    async fn copy_table_to_csv_requires_postgres() {
+        let pool = sqlx::SqlitePool::connect(":memory:").await.unwrap();
+        let source = TablePreviewSource {
+            schema: None,
+            table_name: "users".to_string(),
+            qualified_name: "users".to_string(),
+        };
+
+        let result = copy_table_to_csv(
+            DatabaseConnection::Sqlite(pool),
+            source,
+            std::env::temp_dir().join("shovel-copy-unused.csv"),
+            CsvExportOptions::default(),
+            |_| {},
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
 }
@@ -1,16 +1,30 @@
 pub mod core;
+pub mod dump;
 pub mod format;
 pub mod io;
 
 pub use crate::core::{
-    create_table, delete_table_row, drop_table, duplicate_table, execute_explain, execute_query,
-    execute_query_page, insert_table_row, insert_table_row_with_values, is_read_only_sql,
-    load_table_preview_page, next_table_primary_key_id, preview_source_for_sql, truncate_table,
-    update_table_cell,
+    QueryParameterValue, SqlTransaction, begin_transaction, cancel_backend, create_index,
+    create_index_statement, create_table, delete_table_row, delete_table_rows,
+    describe_query_parameters, drop_index, drop_table, duplicate_table, execute_explain,
+    execute_query, execute_query_page, execute_query_page_with_timeout,
+    execute_query_with_parameters, find_query_parameters, format_bytea, format_postgres_array,
+    format_postgres_hstore, insert_table_row, insert_table_row_returning,
+    insert_table_row_with_values, is_postgres_array_type, is_postgres_bytea_type,
+    is_postgres_hstore_type, is_read_only_sql, listen_for_notifications, load_bytea_cell,
+    load_column_stats, load_schema_size_report, load_server_activity, load_server_info,
+    load_server_locks, load_server_settings, load_statement_stats, load_table_maintenance_stats,
+    load_table_preview_page, load_table_row_count, load_table_stats, next_table_primary_key_id,
+    parse_postgres_array, parse_postgres_hstore, ping_connection, preview_source_for_sql,
+    refresh_materialized_view, reset_statement_stats, run_table_maintenance, send_notification,
+    set_row_level_security, set_sequence_value, set_server_setting, set_table_comment,
+    statement_timeout_applies, terminate_backend, truncate_table, update_table_cell,
 };
+pub use crate::dump::{DumpFormat, PgDumpCommand, pg_dump_command, run_pg_dump};
 pub use crate::format::format_sql;
 pub use crate::io::{
-    export_query_page_csv, export_query_page_html, export_query_page_json,
-    export_query_page_sql_dump, export_query_page_xlsx, export_query_page_xml,
-    import_csv_into_table,
+    copy_csv_into_table, copy_table_to_csv, export_query_page_csv, export_query_page_html,
+    export_query_page_json, export_query_page_sql_dump, export_query_page_xlsx,
+    export_query_page_xml, export_query_result_csv, import_csv_into_table, preview_csv_file,
+    query_page_to_html, query_page_to_insert_sql, query_page_to_markdown,
 };
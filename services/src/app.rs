@@ -1,5 +1,19 @@
 use futures_util::future::join_all;
-use models::{AppUiSettings, ConnectionRequest, DatabaseConnection, SqlFormatSettings};
+use models::{
+    AppUiSettings, ConnectionEnvironment, ConnectionRequest, DatabaseConnection, DatabaseError,
+    SavedConnection, ServerInfo, SqlFormatSettings,
+};
+use std::time::{Duration, Instant};
+
+/// How long a connection test may take before it is reported as failed.
+const CONNECTION_TEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How many times a dropped connection is retried before giving up.
+pub const RECONNECT_MAX_ATTEMPTS: u32 = 10;
+const RECONNECT_BASE_DELAY: Duration = Duration::from_secs(1);
+const RECONNECT_MAX_DELAY: Duration = Duration::from_secs(60);
+/// How long quitting waits for transactions to finish and pools to close.
+pub const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(3);
 
 #[derive(Clone, Debug)]
 pub struct AppStartupSettings {
@@ -10,12 +24,23 @@ pub struct AppStartupSettings {
 #[derive(Clone, Debug)]
 pub struct ConnectAndSaveResult {
     pub connection: DatabaseConnection,
+    /// Profile name the connection is listed under.
+    pub name: String,
+    pub environment: Option<ConnectionEnvironment>,
     pub save_warning: Option<String>,
 }
 
+#[derive(Clone, Debug)]
+pub struct ConnectionTestResult {
+    pub server: ServerInfo,
+    /// Time to connect and read the server version.
+    pub latency: Duration,
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct SessionRestoreResult {
-    pub restored: Vec<(ConnectionRequest, DatabaseConnection)>,
+    /// Reopened sessions, named after their saved connection profile.
+    pub restored: Vec<(SavedConnection, DatabaseConnection)>,
     pub active_connection_name: Option<String>,
     pub failed_requests: Vec<(ConnectionRequest, String)>,
 }
@@ -101,10 +126,24 @@ pub async fn restore_saved_sessions() -> Result<SessionRestoreResult, String> {
         });
     }
 
-    let restored_results = join_all(open_requests.into_iter().map(|request| async move {
-        match connection::connect_to_db(request.clone()).await {
-            Ok(connection) => Ok((request, connection)),
-            Err(err) => Err((request, err.to_string())),
+    let saved_connections = storage::load_saved_connections().await.unwrap_or_default();
+    let restored_results = join_all(open_requests.into_iter().map(|request| {
+        let saved = saved_connections
+            .iter()
+            .find(|saved| saved.request.identity_key() == request.identity_key());
+        let profile_name = saved.map(|saved| saved.name.clone());
+        let environment = saved.and_then(|saved| saved.environment.clone());
+        async move {
+            match connection::connect_to_db(request.clone()).await {
+                Ok(connection) => {
+                    let saved = SavedConnection {
+                        environment,
+                        ..SavedConnection::new(request, profile_name)
+                    };
+                    Ok((saved, connection))
+                }
+                Err(err) => Err((request, err.to_string())),
+            }
         }
     }))
     .await;
@@ -125,16 +164,104 @@ pub async fn restore_saved_sessions() -> Result<SessionRestoreResult, String> {
     })
 }
 
+/// Connects with `request` and saves it to the recent connections. A
+/// `profile_name` of `None` keeps the name the connection was saved under
+/// before.
 pub async fn connect_and_save_request(
     request: ConnectionRequest,
+    profile_name: Option<String>,
 ) -> Result<ConnectAndSaveResult, String> {
     let connection = connection::connect_to_db(request.clone())
         .await
         .map_err(|err| err.to_string())?;
-    let save_warning = storage::save_connection_request(request).await.err();
+    let (saved, save_warning) =
+        match storage::save_connection_request(request.clone(), profile_name.clone()).await {
+            Ok(saved) => (saved, None),
+            Err(err) => (SavedConnection::new(request, profile_name), Some(err)),
+        };
 
     Ok(ConnectAndSaveResult {
         connection,
+        name: saved.name,
+        environment: saved.environment,
         save_warning,
     })
 }
+
+/// Connects with `request` without saving it or opening a session, reports the
+/// server version, database and round-trip latency, and closes the connection
+/// again.
+pub async fn test_connection_request(
+    request: ConnectionRequest,
+) -> Result<ConnectionTestResult, String> {
+    let probe = async {
+        let started = Instant::now();
+        let (connection, probe_key) = connection::connect_for_test(request)
+            .await
+            .map_err(|err| err.to_string())?;
+        let server = query::load_server_info(connection.clone())
+            .await
+            .map_err(|err| err.to_string());
+        let latency = started.elapsed();
+        close_connection(connection).await;
+        connection::release_ssh_tunnel(&probe_key);
+        server.map(|server| ConnectionTestResult { server, latency })
+    };
+
+    tokio::time::timeout(CONNECTION_TEST_TIMEOUT, probe)
+        .await
+        .map_err(|_| {
+            format!(
+                "timed out after {} seconds",
+                CONNECTION_TEST_TIMEOUT.as_secs()
+            )
+        })?
+}
+
+/// Wait before reconnect `attempt` (1-based): doubles from one second and is
+/// capped at a minute.
+pub fn reconnect_delay(attempt: u32) -> Duration {
+    let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+    RECONNECT_BASE_DELAY
+        .saturating_mul(factor)
+        .min(RECONNECT_MAX_DELAY)
+}
+
+/// Reconnects with `request` after a dropped connection, backing off between
+/// up to [`RECONNECT_MAX_ATTEMPTS`] tries. `on_attempt` is called with each
+/// attempt number and the wait before it. A reopened SSH tunnel replaces the
+/// old one under the same session key. Returns the last error when every try
+/// fails.
+pub async fn reconnect_request(
+    request: ConnectionRequest,
+    mut on_attempt: impl FnMut(u32, Duration),
+) -> Result<DatabaseConnection, DatabaseError> {
+    let mut attempt = 1;
+    loop {
+        let delay = reconnect_delay(attempt);
+        on_attempt(attempt, delay);
+        tokio::time::sleep(delay).await;
+        match connection::connect_to_db(request.clone()).await {
+            Ok(connection) => return Ok(connection),
+            Err(err) if attempt >= RECONNECT_MAX_ATTEMPTS => return Err(err),
+            Err(_) => attempt += 1,
+        }
+    }
+}
+
+/// Closes every pool so servers see a clean disconnect when the app quits.
+/// Gives up after [`SHUTDOWN_TIMEOUT`] so an unreachable server cannot keep
+/// the window open.
+pub async fn close_connections(connections: Vec<DatabaseConnection>) {
+    let close_all = join_all(connections.into_iter().map(close_connection));
+    let _ = tokio::time::timeout(SHUTDOWN_TIMEOUT, close_all).await;
+}
+
+async fn close_connection(connection: DatabaseConnection) {
+    match connection {
+        DatabaseConnection::Sqlite(pool) => pool.close().await,
+        DatabaseConnection::Postgres(pool) => pool.close().await,
+        DatabaseConnection::MySql(pool) => pool.close().await,
+        DatabaseConnection::ClickHouse(_) => {}
+    }
+}
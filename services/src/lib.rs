@@ -16,24 +16,45 @@ mod app;
 // --- Connection management ---
 
 pub use app::{
-    AppStartupSettings, ConnectAndSaveResult, SessionRestoreResult, connect_and_save_request,
-    load_app_startup_settings, restore_saved_sessions, save_app_ui_settings_with_secrets,
+    AppStartupSettings, ConnectAndSaveResult, ConnectionTestResult, RECONNECT_MAX_ATTEMPTS,
+    SHUTDOWN_TIMEOUT, SessionRestoreResult, close_connections, connect_and_save_request,
+    load_app_startup_settings, reconnect_delay, reconnect_request, restore_saved_sessions,
+    save_app_ui_settings_with_secrets, test_connection_request,
 };
 pub use connection::{connect_to_db, release_ssh_tunnel};
 
 // --- Schema exploration ---
 
-pub use explorer::{describe_table, load_connection_tree, load_table_columns};
+pub use explorer::{
+    describe_table, load_column_index, load_connection_tree, load_databases, load_roles,
+    load_routine_definition, load_schema_materialized_views, load_schema_routines,
+    load_schema_sequences, load_schema_table_catalog, load_schema_tables, load_table_column_info,
+    load_table_columns, load_table_ddl, load_table_grants, load_table_indexes,
+    load_table_row_security,
+};
 
 // --- Query execution and table editing ---
 
 pub use query::{
-    create_table, delete_table_row, drop_table, duplicate_table, execute_explain, execute_query,
-    execute_query_page, export_query_page_csv, export_query_page_html, export_query_page_json,
-    export_query_page_sql_dump, export_query_page_xlsx, export_query_page_xml, format_sql,
-    import_csv_into_table, insert_table_row, insert_table_row_with_values, is_read_only_sql,
-    load_table_preview_page, next_table_primary_key_id, preview_source_for_sql, truncate_table,
-    update_table_cell,
+    DumpFormat, PgDumpCommand, QueryParameterValue, SqlTransaction, begin_transaction,
+    cancel_backend, copy_csv_into_table, copy_table_to_csv, create_index, create_index_statement,
+    create_table, delete_table_row, delete_table_rows, describe_query_parameters, drop_index,
+    drop_table, duplicate_table, execute_explain, execute_query, execute_query_page,
+    execute_query_page_with_timeout, execute_query_with_parameters, export_query_page_csv,
+    export_query_page_html, export_query_page_json, export_query_page_sql_dump,
+    export_query_page_xlsx, export_query_page_xml, export_query_result_csv, find_query_parameters,
+    format_bytea, format_postgres_array, format_postgres_hstore, format_sql, import_csv_into_table,
+    insert_table_row, insert_table_row_returning, insert_table_row_with_values,
+    is_postgres_array_type, is_postgres_bytea_type, is_postgres_hstore_type, is_read_only_sql,
+    listen_for_notifications, load_bytea_cell, load_column_stats, load_schema_size_report,
+    load_server_activity, load_server_locks, load_server_settings, load_statement_stats,
+    load_table_maintenance_stats, load_table_preview_page, load_table_row_count, load_table_stats,
+    next_table_primary_key_id, parse_postgres_array, parse_postgres_hstore, pg_dump_command,
+    ping_connection, preview_csv_file, preview_source_for_sql, query_page_to_insert_sql,
+    query_page_to_markdown, refresh_materialized_view, reset_statement_stats, run_pg_dump,
+    run_table_maintenance, send_notification, set_row_level_security, set_sequence_value,
+    set_server_setting, set_table_comment, statement_timeout_applies, terminate_backend,
+    truncate_table, update_table_cell,
 };
 
 // --- Persistence ---
@@ -41,12 +62,14 @@ pub use query::{
 pub use storage::QueryHistoryStore;
 pub use storage::{
     acp_workspace_root, append_query_history, create_chat_thread, delete_chat_thread,
-    delete_saved_query, load_app_ui_settings, load_chat_thread_messages, load_chat_threads,
-    load_codestral_api_key, load_deepseek_api_key, load_query_history, load_saved_connections,
-    load_saved_queries, load_session_state, load_session_state_sync, load_sql_format_settings,
+    delete_query_template, delete_saved_query, load_app_ui_settings, load_chat_thread_messages,
+    load_chat_threads, load_codestral_api_key, load_deepseek_api_key, load_query_history,
+    load_query_templates, load_saved_connections, load_saved_queries, load_session_state,
+    load_session_state_sync, load_sql_format_settings, load_window_geometry_sync,
     replace_connection_request, save_app_ui_settings, save_chat_thread_snapshot,
-    save_codestral_api_key, save_connection_request, save_deepseek_api_key, save_saved_query,
-    save_session_state, save_session_state_sync, save_sql_format_settings,
+    save_codestral_api_key, save_connection_request, save_deepseek_api_key, save_query_template,
+    save_saved_queries, save_saved_query, save_session_state, save_session_state_sync,
+    save_sql_format_settings, save_window_geometry_sync,
 };
 
 // --- ACP agent runtime ---
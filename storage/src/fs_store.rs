@@ -32,6 +32,10 @@ pub(crate) fn saved_queries_path() -> PathBuf {
     storage_root().join("saved_queries.json")
 }
 
+pub(crate) fn query_templates_path() -> PathBuf {
+    storage_root().join("query_templates.json")
+}
+
 pub(crate) fn sql_format_settings_path() -> PathBuf {
     storage_root().join("sql_format_settings.json")
 }
@@ -40,6 +44,10 @@ pub(crate) fn app_ui_settings_path() -> PathBuf {
     storage_root().join("app_ui_settings.json")
 }
 
+pub(crate) fn window_state_path() -> PathBuf {
+    storage_root().join("window_state.json")
+}
+
 pub(crate) fn session_state_path() -> PathBuf {
     storage_root().join("session_state.json")
 }
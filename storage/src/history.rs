@@ -1,7 +1,7 @@
 use keyring::{Entry, Error as KeyringError};
 use models::{
-    ClickHouseFormData, ConnectionRequest, MySqlFormData, PostgresFormData, QueryHistoryItem,
-    SavedConnection, SqliteFormData, SshTunnelConfig,
+    ClickHouseFormData, ConnectionEnvironment, ConnectionRequest, MySqlFormData, PostgresFormData,
+    QueryHistoryItem, SavedConnection, SqliteFormData, SshTunnelConfig,
 };
 use serde::{Deserialize, Serialize};
 use std::{
@@ -22,6 +22,8 @@ const KEYRING_SERVICE: &str = "shovel.connections";
 struct PersistedSavedConnection {
     name: String,
     request: PersistedConnectionRequest,
+    #[serde(default)]
+    environment: Option<ConnectionEnvironment>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -39,6 +41,8 @@ struct PostgresConnectionMetadata {
     username: String,
     database: String,
     #[serde(default)]
+    parameters: Vec<(String, String)>,
+    #[serde(default)]
     ssh_tunnel: Option<SshTunnelConfig>,
 }
 
@@ -106,6 +110,7 @@ pub async fn load_saved_connections() -> Result<Vec<SavedConnection>, String> {
         .map(|saved_connection| SavedConnection {
             name: saved_connection.request.display_name(),
             request: saved_connection.request,
+            environment: None,
         })
         .collect())
 }
@@ -119,16 +124,29 @@ pub async fn load_saved_connections() -> Result<Vec<SavedConnection>, String> {
 /// # Arguments
 ///
 /// * `request` - The connection request to save.
+/// * `profile_name` - The name to show for the connection. `None` keeps the
+///   name of an existing entry for the same connection; a blank name falls
+///   back to the request's display name.
+///
+/// # Returns
+///
+/// The connection as saved, with the name and environment tag it is listed
+/// under.
 ///
 /// # Errors
 ///
 /// Returns an error string if writing the JSON file or the keyring entry fails.
-pub async fn save_connection_request(request: ConnectionRequest) -> Result<(), String> {
+pub async fn save_connection_request(
+    request: ConnectionRequest,
+    profile_name: Option<String>,
+) -> Result<SavedConnection, String> {
     let mut saved_connections = load_saved_connections().await.unwrap_or_default();
     let previous_connections = saved_connections.clone();
-    upsert_saved_connection(&mut saved_connections, request, None);
+    upsert_saved_connection(&mut saved_connections, request, None, profile_name);
+    let saved = saved_connections[0].clone();
 
-    persist_saved_connections(&saved_connections, &previous_connections).await
+    persist_saved_connections(&saved_connections, &previous_connections).await?;
+    Ok(saved)
 }
 
 /// Replace an existing saved connection identified by its previous identity key.
@@ -141,6 +159,9 @@ pub async fn save_connection_request(request: ConnectionRequest) -> Result<(), S
 ///
 /// * `previous_identity_key` - The identity key of the connection to replace.
 /// * `request` - The new connection request to store.
+/// * `profile_name` - The name to show for the connection, as for
+///   [`save_connection_request`].
+/// * `environment` - The environment tag, or `None` to clear it.
 ///
 /// # Errors
 ///
@@ -148,6 +169,8 @@ pub async fn save_connection_request(request: ConnectionRequest) -> Result<(), S
 pub async fn replace_connection_request(
     previous_identity_key: String,
     request: ConnectionRequest,
+    profile_name: Option<String>,
+    environment: Option<ConnectionEnvironment>,
 ) -> Result<(), String> {
     let mut saved_connections = load_saved_connections().await.unwrap_or_default();
     let previous_connections = saved_connections.clone();
@@ -155,7 +178,9 @@ pub async fn replace_connection_request(
         &mut saved_connections,
         request,
         Some(previous_identity_key.as_str()),
+        profile_name,
     );
+    saved_connections[0].environment = environment;
 
     persist_saved_connections(&saved_connections, &previous_connections).await
 }
@@ -163,15 +188,18 @@ pub async fn replace_connection_request(
 /// Load recent query history from the SQLite-backed store.
 ///
 /// Initializes the [`QueryHistoryStore`] schema (creating tables and
-/// migrating legacy JSON data if needed), then returns the 20 most
-/// recent history items.
+/// migrating legacy JSON data if needed), then returns the pinned history
+/// items followed by the most recent ones.
 ///
 /// # Errors
 ///
 /// Returns an error string if schema initialization or the query fails.
 pub async fn load_query_history() -> Result<Vec<QueryHistoryItem>, String> {
     crate::query_history::QueryHistoryStore::init().await?;
-    crate::query_history::QueryHistoryStore::load(20).await
+    crate::query_history::QueryHistoryStore::load(
+        crate::query_history::QueryHistoryStore::MAX_ITEMS,
+    )
+    .await
 }
 
 /// Append a single [`QueryHistoryItem`] to the query history store.
@@ -327,18 +355,24 @@ fn upsert_saved_connection(
     saved_connections: &mut Vec<SavedConnection>,
     request: ConnectionRequest,
     replaced_identity_key: Option<&str>,
+    profile_name: Option<String>,
 ) {
-    if let Some(previous_identity_key) = replaced_identity_key {
-        saved_connections.retain(|saved| saved.request.identity_key() != previous_identity_key);
-    }
-
     let request_key = request.identity_key();
-    saved_connections.retain(|saved| saved.request.identity_key() != request_key);
+    let is_replaced = |saved: &SavedConnection| {
+        let saved_key = saved.request.identity_key();
+        saved_key == request_key || Some(saved_key.as_str()) == replaced_identity_key
+    };
+    let previous = saved_connections.iter().find(|saved| is_replaced(saved));
+    let environment = previous.and_then(|saved| saved.environment.clone());
+    let profile_name = profile_name
+        .or_else(|| previous.and_then(|saved| saved.profile_name().map(str::to_string)));
+
+    saved_connections.retain(|saved| !is_replaced(saved));
     saved_connections.insert(
         0,
         SavedConnection {
-            name: request.display_name(),
-            request,
+            environment,
+            ..SavedConnection::new(request, profile_name)
         },
     );
     if saved_connections.len() > MAX_SAVED_CONNECTIONS {
@@ -365,11 +399,17 @@ fn hydrate_saved_connection(
     let password = load_connection_secret(&saved_connection.name, &request_without_password)
         .ok()
         .flatten();
-    let request = persisted_request_with_password(saved_connection.request, password);
+    let mut request = persisted_request_with_password(saved_connection.request, password);
+    if let Some(tunnel) = request_ssh_tunnel_mut(&mut request) {
+        tunnel.password = load_secret(&ssh_secret_key(&request_without_password.identity_key()))
+            .ok()
+            .flatten()
+            .unwrap_or_default();
+    }
 
     Ok(SavedConnection {
-        name: request.display_name(),
-        request,
+        environment: saved_connection.environment,
+        ..SavedConnection::new(request, Some(saved_connection.name))
     })
 }
 
@@ -382,7 +422,8 @@ fn to_persisted_connection(saved_connection: SavedConnection) -> PersistedSavedC
                 port: data.port,
                 username: data.username,
                 database: data.database,
-                ssh_tunnel: data.ssh_tunnel,
+                parameters: data.parameters,
+                ssh_tunnel: data.ssh_tunnel.map(|tunnel| tunnel.without_password()),
             })
         }
         ConnectionRequest::MySql(data) => {
@@ -391,7 +432,7 @@ fn to_persisted_connection(saved_connection: SavedConnection) -> PersistedSavedC
                 port: data.port,
                 username: data.username,
                 database: data.database,
-                ssh_tunnel: data.ssh_tunnel,
+                ssh_tunnel: data.ssh_tunnel.map(|tunnel| tunnel.without_password()),
             })
         }
         ConnectionRequest::ClickHouse(data) => {
@@ -400,7 +441,7 @@ fn to_persisted_connection(saved_connection: SavedConnection) -> PersistedSavedC
                 port: data.port,
                 username: data.username,
                 database: data.database,
-                ssh_tunnel: data.ssh_tunnel,
+                ssh_tunnel: data.ssh_tunnel.map(|tunnel| tunnel.without_password()),
             })
         }
     };
@@ -408,6 +449,7 @@ fn to_persisted_connection(saved_connection: SavedConnection) -> PersistedSavedC
     PersistedSavedConnection {
         name: saved_connection.name,
         request,
+        environment: saved_connection.environment,
     }
 }
 
@@ -427,7 +469,35 @@ fn sync_connection_secret(saved_connection: &SavedConnection) -> Result<(), Stri
         }
     }
 
-    Ok(())
+    let ssh_password = request_ssh_tunnel(&saved_connection.request)
+        .map(|tunnel| tunnel.password.as_str())
+        .unwrap_or_default();
+    store_secret(
+        &ssh_secret_key(&saved_connection.request.identity_key()),
+        ssh_password,
+    )
+}
+
+fn ssh_secret_key(identity_key: &str) -> String {
+    format!("{identity_key}#ssh")
+}
+
+fn request_ssh_tunnel(request: &ConnectionRequest) -> Option<&SshTunnelConfig> {
+    match request {
+        ConnectionRequest::Sqlite(_) => None,
+        ConnectionRequest::Postgres(data) => data.ssh_tunnel.as_ref(),
+        ConnectionRequest::MySql(data) => data.ssh_tunnel.as_ref(),
+        ConnectionRequest::ClickHouse(data) => data.ssh_tunnel.as_ref(),
+    }
+}
+
+fn request_ssh_tunnel_mut(request: &mut ConnectionRequest) -> Option<&mut SshTunnelConfig> {
+    match request {
+        ConnectionRequest::Sqlite(_) => None,
+        ConnectionRequest::Postgres(data) => data.ssh_tunnel.as_mut(),
+        ConnectionRequest::MySql(data) => data.ssh_tunnel.as_mut(),
+        ConnectionRequest::ClickHouse(data) => data.ssh_tunnel.as_mut(),
+    }
 }
 
 fn store_connection_secret(saved_connection: &SavedConnection, secret: &str) -> Result<(), String> {
@@ -476,6 +546,7 @@ fn load_connection_secret(
 fn delete_connection_secret(legacy_name: &str, request: &ConnectionRequest) -> Result<(), String> {
     let current_key = request.identity_key();
     delete_secret(&current_key)?;
+    delete_secret(&ssh_secret_key(&current_key))?;
 
     let legacy_name = legacy_name.trim();
     if !legacy_name.is_empty() && legacy_name != current_key {
@@ -555,6 +626,7 @@ fn persisted_request_without_password(request: &PersistedConnectionRequest) -> C
                 username: data.username.clone(),
                 password: String::new(),
                 database: data.database.clone(),
+                parameters: data.parameters.clone(),
                 ssh_tunnel: data.ssh_tunnel.clone(),
             })
         }
@@ -592,6 +664,7 @@ fn persisted_request_with_password(
                 username: data.username,
                 password: password.clone().unwrap_or_default(),
                 database: data.database,
+                parameters: data.parameters,
                 ssh_tunnel: data.ssh_tunnel,
             })
         }
@@ -638,6 +711,7 @@ fn build_persisted_session_state(
         .map(|request| SavedConnection {
             name: request.display_name(),
             request,
+            environment: None,
         })
         .map(|saved_connection| {
             if let Err(err) = sync_connection_secret(&saved_connection) {
@@ -691,6 +765,7 @@ fn parse_session_state(content: &str, path: &Path) -> Result<PersistedSessionSta
                 .map(|request| SavedConnection {
                     name: request.display_name(),
                     request,
+                    environment: None,
                 })
                 .map(to_persisted_connection)
                 .collect(),
@@ -732,8 +807,11 @@ fn write_session_state_sync(path: PathBuf, value: &PersistedSessionState) -> Res
 
 #[cfg(test)]
 mod tests {
-    use super::upsert_saved_connection;
-    use models::{ConnectionRequest, SavedConnection, SqliteFormData};
+    use super::{PersistedConnectionRequest, to_persisted_connection, upsert_saved_connection};
+    use models::{
+        ConnectionEnvironment, ConnectionRequest, PostgresFormData, SavedConnection,
+        SqliteFormData, SshTunnelConfig,
+    };
 
     fn sqlite_request(path: &str) -> ConnectionRequest {
         ConnectionRequest::Sqlite(SqliteFormData {
@@ -748,12 +826,14 @@ mod tests {
         let mut saved_connections = vec![SavedConnection {
             name: old_request.display_name(),
             request: old_request.clone(),
+            environment: None,
         }];
 
         upsert_saved_connection(
             &mut saved_connections,
             new_request.clone(),
             Some(&old_request.identity_key()),
+            None,
         );
 
         assert_eq!(saved_connections.len(), 1);
@@ -768,17 +848,93 @@ mod tests {
             SavedConnection {
                 name: first_request.display_name(),
                 request: first_request.clone(),
+                environment: None,
             },
             SavedConnection {
                 name: second_request.display_name(),
                 request: second_request.clone(),
+                environment: None,
             },
         ];
 
-        upsert_saved_connection(&mut saved_connections, first_request.clone(), None);
+        upsert_saved_connection(&mut saved_connections, first_request.clone(), None, None);
 
         assert_eq!(saved_connections.len(), 2);
         assert_eq!(saved_connections[0].request, first_request);
         assert_eq!(saved_connections[1].request, second_request);
     }
+
+    #[test]
+    fn upsert_saved_connection_keeps_profile_name_unless_renamed() {
+        let old_request = sqlite_request("/tmp/old.db");
+        let new_request = sqlite_request("/tmp/new.db");
+        let mut saved_connections = vec![SavedConnection::new(
+            old_request.clone(),
+            Some("Local app".to_string()),
+        )];
+
+        upsert_saved_connection(
+            &mut saved_connections,
+            new_request.clone(),
+            Some(&old_request.identity_key()),
+            None,
+        );
+        assert_eq!(saved_connections[0].name, "Local app");
+
+        upsert_saved_connection(
+            &mut saved_connections,
+            new_request.clone(),
+            None,
+            Some(String::new()),
+        );
+        assert_eq!(saved_connections[0].name, new_request.display_name());
+    }
+
+    #[test]
+    fn upsert_saved_connection_keeps_environment() {
+        let request = sqlite_request("/tmp/app.db");
+        let mut saved_connections = vec![SavedConnection {
+            environment: Some(ConnectionEnvironment::Staging),
+            ..SavedConnection::new(request.clone(), None)
+        }];
+
+        upsert_saved_connection(&mut saved_connections, request, None, None);
+
+        assert_eq!(
+            saved_connections[0].environment,
+            Some(ConnectionEnvironment::Staging)
+        );
+    }
+
+    #[test]
+    fn persisted_connection_drops_ssh_password() {
+        let request = ConnectionRequest::Postgres(PostgresFormData {
+            host: "db.internal".to_string(),
+            port: 5432,
+            username: "app".to_string(),
+            password: "db-secret".to_string(),
+            database: "app".to_string(),
+            parameters: Vec::new(),
+            ssh_tunnel: Some(SshTunnelConfig {
+                host: "bastion.example.com".to_string(),
+                port: 22,
+                username: "ops".to_string(),
+                private_key_path: String::new(),
+                password: "ssh-secret".to_string(),
+            }),
+        });
+
+        let persisted = to_persisted_connection(SavedConnection {
+            name: request.display_name(),
+            request,
+            environment: None,
+        });
+
+        let PersistedConnectionRequest::Postgres(metadata) = persisted.request else {
+            panic!("expected postgres metadata");
+        };
+        let tunnel = metadata.ssh_tunnel.expect("ssh tunnel metadata");
+        assert_eq!(tunnel.username, "ops");
+        assert!(tunnel.password.is_empty());
+    }
 }
@@ -1,9 +1,10 @@
-//! Local persistence layer for Shovel — settings, sessions, connections, query history, saved queries, and chat database.
+//! Local persistence layer for Shovel — settings, sessions, connections, query history, saved queries, query templates, and chat database.
 
 mod chat;
 mod fs_store;
 mod history;
 mod query_history;
+mod query_templates;
 mod saved_queries;
 mod secrets;
 mod semantic_cache;
@@ -34,11 +35,18 @@ pub use history::{
 /// (duration, rows returned, outcome, connection info) and supports FTS5-based
 /// search across historical queries.
 pub use query_history::QueryHistoryStore;
+/// JSON-file backed query templates.
+///
+/// These functions persist user-defined templates with `{{param}}`
+/// placeholders to `query_templates.json`, next to the saved queries.
+pub use query_templates::{delete_query_template, load_query_templates, save_query_template};
 /// JSON-file backed saved SQL queries.
 ///
 /// These functions persist user-saved SQL queries to `saved_queries.json`.
 /// Queries are organized by folder and sorted by folder name, title, and ID.
-pub use saved_queries::{delete_saved_query, load_saved_queries, save_saved_query};
+pub use saved_queries::{
+    delete_saved_query, load_saved_queries, save_saved_queries, save_saved_query,
+};
 /// Embedding-based semantic cache for LLM responses.
 ///
 /// [`SemanticCacheStore`] uses sqlite-vec to store embeddings and perform
@@ -50,10 +58,13 @@ pub use semantic_cache::{CacheStats, SemanticCacheStore};
 /// These functions load and save application preferences (theme, panel
 /// visibility, etc.), SQL formatting options, and ACP provider API keys
 /// (CodeStral and DeepSeek). API keys are stored in the system keyring
-/// with a fallback to the local secret store.
+/// with a fallback to the local secret store. The main window geometry is
+/// kept in its own file, read and written synchronously around the window's
+/// lifetime.
 pub use settings::{
     load_app_ui_settings, load_codestral_api_key, load_deepseek_api_key, load_sql_format_settings,
-    save_app_ui_settings, save_codestral_api_key, save_deepseek_api_key, save_sql_format_settings,
+    load_window_geometry_sync, save_app_ui_settings, save_codestral_api_key, save_deepseek_api_key,
+    save_sql_format_settings, save_window_geometry_sync,
 };
 
 /// Returns the root directory for ACP workspace data, creating it if it doesn't exist.
@@ -3,12 +3,14 @@ use sqlx::SqlitePool;
 
 use crate::fs_store::query_history_path;
 
-const MAX_HISTORY_ITEMS: usize = 20;
-
 /// SQLite-backed storage for query history with FTS5 search support.
 pub struct QueryHistoryStore;
 
 impl QueryHistoryStore {
+    /// Unpinned items kept; older ones are dropped as new queries run.
+    /// Pinned items are kept on top of these.
+    pub const MAX_ITEMS: usize = 1000;
+
     /// Initialize the store, creating tables and migrating from JSON if needed.
     pub async fn init() -> Result<(), String> {
         let pool = crate::chat::chat_pool().await?;
@@ -21,66 +23,19 @@ impl QueryHistoryStore {
     pub async fn save(item: &QueryHistoryItem) -> Result<(), String> {
         let pool = crate::chat::chat_pool().await?;
 
-        sqlx::query(
-            r#"
-            INSERT INTO query_history (
-                id, sql, duration_ms, rows_returned, executed_at,
-                connection_name, connection_type, outcome, error_message
-            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
-            "#,
-        )
-        .bind(item.id.to_string())
-        .bind(&item.sql)
-        .bind(item.duration_ms as i64)
-        .bind(item.rows_returned.map(|r| r as i64))
-        .bind(item.executed_at)
-        .bind(&item.connection_name)
-        .bind(&item.connection_type)
-        .bind(&item.outcome)
-        .bind(item.error_message.as_ref())
-        .execute(pool)
-        .await
-        .map_err(|err| format!("failed to save query history: {err}"))?;
-
-        // Insert into FTS5 index
-        sqlx::query(
-            r#"
-            INSERT INTO query_history_fts (rowid, sql, connection_name)
-            VALUES (last_insert_rowid(), ?, ?)
-            "#,
-        )
-        .bind(&item.sql)
-        .bind(&item.connection_name)
-        .execute(pool)
-        .await
-        .map_err(|err| format!("failed to index query history: {err}"))?;
+        insert_item(pool, item).await?;
 
         // Trim to max items
-        trim_to_max(pool, MAX_HISTORY_ITEMS).await?;
+        trim_to_max(pool, Self::MAX_ITEMS).await?;
 
         Ok(())
     }
 
-    /// Load query history with optional limit.
+    /// Load every pinned item plus up to `limit` of the most recent unpinned
+    /// ones, pinned items first.
     pub async fn load(limit: usize) -> Result<Vec<QueryHistoryItem>, String> {
         let pool = crate::chat::chat_pool().await?;
-
-        let rows = sqlx::query(
-            r#"
-            SELECT
-                id, sql, duration_ms, rows_returned, executed_at,
-                connection_name, connection_type, outcome, error_message
-            FROM query_history
-            ORDER BY executed_at DESC
-            LIMIT ?
-            "#,
-        )
-        .bind(limit as i64)
-        .fetch_all(pool)
-        .await
-        .map_err(|err| format!("failed to load query history: {err}"))?;
-
-        rows.into_iter().map(row_to_item).collect()
+        load_items(pool, limit).await
     }
 
     /// Search query history using FTS5.
@@ -92,7 +47,7 @@ impl QueryHistoryStore {
             r#"
             SELECT
                 h.id, h.sql, h.duration_ms, h.rows_returned, h.executed_at,
-                h.connection_name, h.connection_type, h.outcome, h.error_message
+                h.connection_name, h.connection_type, h.outcome, h.error_message, h.pinned
             FROM query_history h
             JOIN query_history_fts f ON h.rowid = f.rowid
             WHERE query_history_fts MATCH ?
@@ -108,6 +63,27 @@ impl QueryHistoryStore {
         rows.into_iter().map(row_to_item).collect()
     }
 
+    /// Pin or unpin a history item. Pinned items are never trimmed or
+    /// cleared.
+    pub async fn set_pinned(id: u64, pinned: bool) -> Result<(), String> {
+        let pool = crate::chat::chat_pool().await?;
+
+        sqlx::query("UPDATE query_history SET pinned = ? WHERE id = ?")
+            .bind(pinned)
+            .bind(id.to_string())
+            .execute(pool)
+            .await
+            .map_err(|err| format!("failed to pin query history item: {err}"))?;
+
+        Ok(())
+    }
+
+    /// Delete every unpinned history item.
+    pub async fn clear() -> Result<(), String> {
+        let pool = crate::chat::chat_pool().await?;
+        clear_unpinned(pool).await
+    }
+
     /// Get the total count of history items.
     pub async fn count() -> Result<i64, String> {
         let pool = crate::chat::chat_pool().await?;
@@ -121,6 +97,89 @@ impl QueryHistoryStore {
     }
 }
 
+async fn insert_item(pool: &SqlitePool, item: &QueryHistoryItem) -> Result<(), String> {
+    sqlx::query(
+        r#"
+        INSERT INTO query_history (
+            id, sql, duration_ms, rows_returned, executed_at,
+            connection_name, connection_type, outcome, error_message, pinned
+        ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(item.id.to_string())
+    .bind(&item.sql)
+    .bind(item.duration_ms as i64)
+    .bind(item.rows_returned.map(|r| r as i64))
+    .bind(item.executed_at)
+    .bind(&item.connection_name)
+    .bind(&item.connection_type)
+    .bind(&item.outcome)
+    .bind(item.error_message.as_ref())
+    .bind(item.pinned)
+    .execute(pool)
+    .await
+    .map_err(|err| format!("failed to save query history: {err}"))?;
+
+    // Insert into FTS5 index
+    sqlx::query(
+        r#"
+        INSERT INTO query_history_fts (rowid, sql, connection_name)
+        VALUES (last_insert_rowid(), ?, ?)
+        "#,
+    )
+    .bind(&item.sql)
+    .bind(&item.connection_name)
+    .execute(pool)
+    .await
+    .map_err(|err| format!("failed to index query history: {err}"))?;
+
+    Ok(())
+}
+
+async fn load_items(pool: &SqlitePool, limit: usize) -> Result<Vec<QueryHistoryItem>, String> {
+    let rows = sqlx::query(
+        r#"
+        SELECT
+            id, sql, duration_ms, rows_returned, executed_at,
+            connection_name, connection_type, outcome, error_message, pinned
+        FROM query_history
+        WHERE pinned = 1
+           OR id IN (
+                SELECT id FROM query_history
+                WHERE pinned = 0
+                ORDER BY executed_at DESC
+                LIMIT ?
+           )
+        ORDER BY pinned DESC, executed_at DESC
+        "#,
+    )
+    .bind(limit as i64)
+    .fetch_all(pool)
+    .await
+    .map_err(|err| format!("failed to load query history: {err}"))?;
+
+    rows.into_iter().map(row_to_item).collect()
+}
+
+async fn clear_unpinned(pool: &SqlitePool) -> Result<(), String> {
+    sqlx::query(
+        r#"
+        DELETE FROM query_history_fts
+        WHERE rowid IN (SELECT rowid FROM query_history WHERE pinned = 0)
+        "#,
+    )
+    .execute(pool)
+    .await
+    .map_err(|err| format!("failed to clear query history index: {err}"))?;
+
+    sqlx::query("DELETE FROM query_history WHERE pinned = 0")
+        .execute(pool)
+        .await
+        .map_err(|err| format!("failed to clear query history: {err}"))?;
+
+    Ok(())
+}
+
 async fn initialize_schema(pool: &SqlitePool) -> Result<(), String> {
     // Main query_history table
     sqlx::query(
@@ -134,7 +193,8 @@ async fn initialize_schema(pool: &SqlitePool) -> Result<(), String> {
             connection_name TEXT,
             connection_type TEXT,
             outcome TEXT NOT NULL,
-            error_message TEXT
+            error_message TEXT,
+            pinned INTEGER NOT NULL DEFAULT 0
         )
         "#,
     )
@@ -142,6 +202,20 @@ async fn initialize_schema(pool: &SqlitePool) -> Result<(), String> {
     .await
     .map_err(|err| format!("failed to create query_history table: {err}"))?;
 
+    // Stores created before pinning lack the column.
+    let has_pinned: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM pragma_table_info('query_history') WHERE name = 'pinned'",
+    )
+    .fetch_one(pool)
+    .await
+    .map_err(|err| format!("failed to inspect query_history table: {err}"))?;
+    if has_pinned == 0 {
+        sqlx::query("ALTER TABLE query_history ADD COLUMN pinned INTEGER NOT NULL DEFAULT 0")
+            .execute(pool)
+            .await
+            .map_err(|err| format!("failed to add pinned column: {err}"))?;
+    }
+
     // FTS5 virtual table for full-text search
     sqlx::query(
         r#"
@@ -267,6 +341,7 @@ async fn trim_to_max(pool: &SqlitePool, max: usize) -> Result<(), String> {
     let ids_to_delete: Vec<String> = sqlx::query_scalar(
         r#"
         SELECT id FROM query_history
+        WHERE pinned = 0
         ORDER BY executed_at DESC
         LIMIT -1 OFFSET ?
         "#,
@@ -334,6 +409,130 @@ fn row_to_item(row: sqlx::sqlite::SqliteRow) -> Result<QueryHistoryItem, String>
         connection_type: row.try_get("connection_type").unwrap_or_default(),
         outcome: row.try_get("outcome").unwrap_or_default(),
         error_message: row.try_get("error_message").ok(),
+        pinned: row.try_get("pinned").unwrap_or(false),
         tab_title: String::new(), // Not stored in DB
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{clear_unpinned, initialize_schema, insert_item, load_items, trim_to_max};
+    use models::QueryHistoryItem;
+    use sqlx::SqlitePool;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn create_test_pool() -> SqlitePool {
+        SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("failed to create test pool")
+    }
+
+    fn history_item(id: u64, pinned: bool) -> QueryHistoryItem {
+        QueryHistoryItem {
+            id,
+            tab_title: String::new(),
+            connection_name: "local".to_string(),
+            sql: format!("select {id}"),
+            outcome: "success".to_string(),
+            duration_ms: 1,
+            rows_returned: Some(1),
+            executed_at: id as i64,
+            connection_type: "postgres".to_string(),
+            error_message: None,
+            pinned,
+        }
+    }
+
+    async fn stored_ids(pool: &SqlitePool, limit: usize) -> Vec<u64> {
+        load_items(pool, limit)
+            .await
+            .expect("failed to load history")
+            .into_iter()
+            .map(|item| item.id)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn load_returns_every_pinned_item_on_top_of_the_limit() {
+        let pool = create_test_pool().await;
+        initialize_schema(&pool).await.unwrap();
+        for id in 1..=6 {
+            insert_item(&pool, &history_item(id, id <= 3))
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(stored_ids(&pool, 2).await, vec![3, 2, 1, 6, 5]);
+    }
+
+    #[tokio::test]
+    async fn trim_to_max_skips_pinned_items() {
+        let pool = create_test_pool().await;
+        initialize_schema(&pool).await.unwrap();
+        for id in 1..=5 {
+            insert_item(&pool, &history_item(id, id == 1))
+                .await
+                .unwrap();
+        }
+
+        trim_to_max(&pool, 2).await.unwrap();
+
+        assert_eq!(stored_ids(&pool, 10).await, vec![1, 5, 4]);
+    }
+
+    #[tokio::test]
+    async fn clear_keeps_pinned_items() {
+        let pool = create_test_pool().await;
+        initialize_schema(&pool).await.unwrap();
+        for id in 1..=3 {
+            insert_item(&pool, &history_item(id, id == 2))
+                .await
+                .unwrap();
+        }
+
+        clear_unpinned(&pool).await.unwrap();
+
+        assert_eq!(stored_ids(&pool, 10).await, vec![2]);
+    }
+
+    #[tokio::test]
+    async fn pinned_column_is_added_to_an_old_store() {
+        let pool = create_test_pool().await;
+        sqlx::query(
+            r#"
+            CREATE TABLE query_history (
+                id TEXT PRIMARY KEY,
+                sql TEXT NOT NULL,
+                duration_ms INTEGER,
+                rows_returned INTEGER,
+                executed_at INTEGER NOT NULL,
+                connection_name TEXT,
+                connection_type TEXT,
+                outcome TEXT NOT NULL,
+                error_message TEXT
+            )
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            r#"
+            INSERT INTO query_history (id, sql, executed_at, outcome)
+            VALUES ('7', 'select 7', 7, 'success')
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        initialize_schema(&pool).await.unwrap();
+
+        let items = load_items(&pool, 10).await.unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].id, 7);
+        assert!(!items[0].pinned);
+    }
+}
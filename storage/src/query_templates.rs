@@ -0,0 +1,55 @@
+use models::QueryTemplate;
+
+use crate::fs_store::{query_templates_path, read_json_file, write_json_file};
+
+/// Load the user's query templates from `query_templates.json`.
+///
+/// Results are sorted by name, then ID. Built-in templates are not stored.
+///
+/// # Errors
+///
+/// Returns an error string if the file cannot be read or parsed.
+pub async fn load_query_templates() -> Result<Vec<QueryTemplate>, String> {
+    let mut items: Vec<QueryTemplate> = read_json_file(query_templates_path()).await?;
+    items.sort_by(|left, right| {
+        left.name
+            .cmp(&right.name)
+            .then_with(|| left.id.cmp(&right.id))
+    });
+    Ok(items)
+}
+
+/// Save (upsert) a single query template to `query_templates.json`.
+///
+/// If a template with the same ID already exists, it is replaced.
+///
+/// # Arguments
+///
+/// * `item` - The [`QueryTemplate`] to persist.
+///
+/// # Errors
+///
+/// Returns an error string if the file cannot be written.
+pub async fn save_query_template(item: QueryTemplate) -> Result<(), String> {
+    let mut items = load_query_templates().await.unwrap_or_default();
+    items.retain(|existing| existing.id != item.id);
+    items.push(item);
+    write_json_file(query_templates_path(), &items).await
+}
+
+/// Delete a query template by its ID.
+///
+/// If no template with the given ID exists, this is a no-op.
+///
+/// # Arguments
+///
+/// * `id` - The unique identifier of the template to delete.
+///
+/// # Errors
+///
+/// Returns an error string if the file cannot be written.
+pub async fn delete_query_template(id: u64) -> Result<(), String> {
+    let mut items = load_query_templates().await.unwrap_or_default();
+    items.retain(|existing| existing.id != id);
+    write_json_file(query_templates_path(), &items).await
+}
@@ -38,6 +38,25 @@ pub async fn save_saved_query(item: SavedQuery) -> Result<(), String> {
     write_json_file(saved_queries_path(), &items).await
 }
 
+/// Save (upsert) several saved queries to `saved_queries.json` in one write,
+/// as when importing a shared file.
+///
+/// Queries whose ID already exists are replaced.
+///
+/// # Arguments
+///
+/// * `new_items` - The [`SavedQuery`] values to persist.
+///
+/// # Errors
+///
+/// Returns an error string if the file cannot be written.
+pub async fn save_saved_queries(new_items: Vec<SavedQuery>) -> Result<(), String> {
+    let mut items = load_saved_queries().await.unwrap_or_default();
+    items.retain(|existing| !new_items.iter().any(|item| item.id == existing.id));
+    items.extend(new_items);
+    write_json_file(saved_queries_path(), &items).await
+}
+
 /// Delete a saved query by its ID.
 ///
 /// If no query with the given ID exists, this is a no-op.
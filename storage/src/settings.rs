@@ -1,8 +1,10 @@
 use keyring::{Entry, Error as KeyringError};
-use models::{AppUiSettings, SqlFormatSettings};
+use models::{AppUiSettings, SqlFormatSettings, WindowGeometry};
+use std::io::ErrorKind;
 
 use crate::fs_store::{
-    app_ui_settings_path, read_json_file, sql_format_settings_path, write_json_file,
+    app_ui_settings_path, read_json_file, sql_format_settings_path, window_state_path,
+    write_json_file,
 };
 use crate::secrets::{delete_fallback_secret, load_fallback_secret, save_fallback_secret};
 
@@ -27,6 +29,30 @@ pub async fn save_sql_format_settings(settings: SqlFormatSettings) -> Result<(),
     write_json_file(sql_format_settings_path(), &settings).await
 }
 
+/// Reads the saved main window geometry. It is read before the window opens,
+/// so this blocks; a missing file gives the default geometry.
+pub fn load_window_geometry_sync() -> Result<WindowGeometry, String> {
+    let path = window_state_path();
+    match std::fs::read_to_string(&path) {
+        Ok(content) => serde_json::from_str(&content)
+            .map_err(|err| format!("failed to parse {}: {err}", path.display())),
+        Err(err) if err.kind() == ErrorKind::NotFound => Ok(WindowGeometry::default()),
+        Err(err) => Err(format!("failed to read {}: {err}", path.display())),
+    }
+}
+
+/// Saves the main window geometry as the window closes, so this blocks.
+pub fn save_window_geometry_sync(geometry: WindowGeometry) -> Result<(), String> {
+    let path = window_state_path();
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|err| format!("failed to create storage dir {}: {err}", parent.display()))?;
+    }
+    let json = serde_json::to_string_pretty(&geometry)
+        .map_err(|err| format!("failed to serialize {}: {err}", path.display()))?;
+    std::fs::write(&path, json).map_err(|err| format!("failed to write {}: {err}", path.display()))
+}
+
 pub async fn load_codestral_api_key() -> Result<String, String> {
     tokio::task::spawn_blocking(|| {
         load_api_key_sync(CODESTRAL_KEYRING_SERVICE, CODESTRAL_KEYRING_ACCOUNT)
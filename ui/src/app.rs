@@ -1,12 +1,19 @@
 use crate::{
     app_state::{
         APP_SHOW_SETTINGS_MODAL, APP_SQL_FORMAT_SETTINGS, APP_STATE, APP_THEME, APP_TOOLTIP,
-        APP_UI_SETTINGS, replace_ui_settings, restore_connection_sessions, toast_error,
+        APP_UI_SETTINGS, remember_window_geometry, replace_ui_settings,
+        restore_connection_sessions, toast_error,
     },
     layout::{SettingsModal, StatusBar, ToastContainer, Toolbar},
     screens::{DbConnect, Workspace},
 };
-use dioxus::prelude::*;
+use dioxus::{
+    desktop::{
+        tao::event::{Event, WindowEvent},
+        use_window, use_wry_event_handler,
+    },
+    prelude::*,
+};
 use models::{AppUiSettings, SqlFormatSettings};
 
 #[component]
@@ -19,6 +26,21 @@ pub fn App() -> Element {
     let startup_settings =
         use_resource(move || async move { services::load_app_startup_settings().await });
 
+    // The toolbar's close button saves the geometry itself; this covers the
+    // window being closed by the system, such as with Alt+F4 or from the taskbar.
+    let desktop = use_window();
+    use_wry_event_handler(move |event, _| {
+        if let Event::WindowEvent {
+            event: WindowEvent::CloseRequested,
+            window_id,
+            ..
+        } = event
+            && *window_id == desktop.id()
+        {
+            remember_window_geometry(&desktop);
+        }
+    });
+
     use_effect(move || {
         let Some(result) = startup_settings() else {
             return;
@@ -97,6 +119,10 @@ pub fn App() -> Element {
 
         let settings = APP_UI_SETTINGS();
         *APP_THEME.write() = settings.theme.css_class().to_string();
+        // The stylesheet is sized in px, so the font size applies as a zoom.
+        if let Err(err) = dioxus::desktop::window().webview.zoom(settings.font_zoom()) {
+            eprintln!("Failed to apply font size: {err}");
+        }
 
         if last_saved_ui_settings().as_ref() == Some(&settings) {
             return;
@@ -1,15 +1,23 @@
 use dioxus::prelude::*;
 use models::{
-    AppState, AppThemePreference, AppUiSettings, ConnectionRequest, ConnectionSession,
-    DatabaseConnection, SqlFormatSettings,
+    AppState, AppThemePreference, AppUiSettings, ColumnInfo, ConnectionEnvironment,
+    ConnectionRequest, ConnectionSession, DatabaseConnection, DatabaseError, ListenCommand,
+    RowCountMode, SavedConnection, SavedQuery, SchemaColumn, ServerNotification, SqlFormatSettings,
+    TableRowCount, TransactionStatus, WindowGeometry, WorkspacePanelSizes, WorkspaceToolLayout,
 };
+use services::SqlTransaction;
 use std::collections::HashMap;
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::Mutex;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
+use tokio::sync::mpsc::UnboundedSender;
 use tokio_util::sync::CancellationToken;
 
+/// Oldest notifications are dropped past this many.
+const MAX_NOTIFICATIONS: usize = 500;
+
 // Explorer cache: session_id -> sections (valid for 5 minutes)
 const EXPLORER_CACHE_TTL: Duration = Duration::from_secs(300);
 
@@ -30,6 +38,44 @@ impl ExplorerCacheEntry {
     }
 }
 
+/// Explicit transaction currently open on a connection session.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SessionTransaction {
+    pub handle: SqlTransaction,
+    pub status: TransactionStatus,
+    /// Active savepoints, oldest first.
+    pub savepoints: Vec<String>,
+}
+
+/// Tasks of the latest result load started for a tab.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RunningTabLoad {
+    pub load_id: u64,
+    pub tasks: Vec<Task>,
+}
+
+/// Export streaming rows into `path`, which is removed when it is cancelled.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RunningTabExport {
+    pub task: Task,
+    pub path: PathBuf,
+}
+
+/// Dedicated connection of a session listening for notifications.
+#[derive(Clone, Debug)]
+pub struct NotificationListener {
+    pub channels: Vec<String>,
+    commands: UnboundedSender<ListenCommand>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReceivedNotification {
+    pub session_id: u64,
+    /// Unix time in seconds.
+    pub received_at: i64,
+    pub notification: ServerNotification,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct AppTooltip {
     pub label: String,
@@ -70,6 +116,8 @@ pub static APP_SHOW_CONNECTIONS: GlobalSignal<bool> =
 pub static APP_SHOW_EXPLORER: GlobalSignal<bool> =
     Signal::global(|| AppUiSettings::default().show_explorer);
 pub static APP_SHOW_HISTORY: GlobalSignal<bool> = Signal::global(|| false);
+pub static APP_SHOW_NOTIFICATIONS: GlobalSignal<bool> =
+    Signal::global(|| AppUiSettings::default().show_notifications);
 pub static APP_SHOW_SQL_EDITOR: GlobalSignal<bool> =
     Signal::global(|| AppUiSettings::default().show_sql_editor);
 pub static APP_SHOW_AGENT_PANEL: GlobalSignal<bool> =
@@ -77,6 +125,50 @@ pub static APP_SHOW_AGENT_PANEL: GlobalSignal<bool> =
 pub static APP_SHOW_SETTINGS_MODAL: GlobalSignal<bool> = Signal::global(|| false);
 pub static APP_TOOLTIP: GlobalSignal<Option<AppTooltip>> = Signal::global(|| None);
 pub static APP_TOAST: GlobalSignal<Vec<AppToast>> = Signal::global(Vec::new);
+pub static APP_TRANSACTIONS: GlobalSignal<HashMap<u64, SessionTransaction>> =
+    Signal::global(HashMap::new);
+/// Table preview row counts keyed by session, table, count mode and filter.
+pub static APP_ROW_COUNTS: GlobalSignal<HashMap<String, TableRowCount>> =
+    Signal::global(HashMap::new);
+/// Every column name per session, loaded when the database tree filter is
+/// first used or the SQL editor opens on the session, so both can use columns
+/// of tables that were never expanded.
+pub static APP_COLUMN_INDEX: GlobalSignal<HashMap<u64, Vec<SchemaColumn>>> =
+    Signal::global(HashMap::new);
+/// Columns with their types per `(schema, table)` of one session.
+pub type TableColumnCache = HashMap<(Option<String>, String), Vec<ColumnInfo>>;
+/// Tables the SQL editor described while completing their columns, per
+/// session, so completions show column types and know tables created after
+/// the column index was loaded. A table that failed to load is kept empty
+/// rather than asked for again.
+pub static APP_TABLE_COLUMNS: GlobalSignal<HashMap<u64, TableColumnCache>> =
+    Signal::global(HashMap::new);
+/// Table names per session and schema, listed when the SQL editor completes
+/// `schema.`, so tables created after the column index was loaded show up.
+pub static APP_SCHEMA_TABLE_NAMES: GlobalSignal<HashMap<u64, HashMap<String, Vec<String>>>> =
+    Signal::global(HashMap::new);
+/// Reconnects in progress, keyed by the session whose connection dropped.
+pub static APP_RECONNECTING: GlobalSignal<HashMap<u64, SessionReconnect>> =
+    Signal::global(HashMap::new);
+/// Outcome of the status bar's periodic ping, per session.
+pub static APP_SESSION_PINGS: GlobalSignal<HashMap<u64, SessionPing>> =
+    Signal::global(HashMap::new);
+/// Query, table preview and row count tasks still running, keyed by tab.
+pub static APP_RUNNING_TAB_LOADS: GlobalSignal<HashMap<u64, RunningTabLoad>> =
+    Signal::global(HashMap::new);
+/// Sessions listening on at least one channel.
+pub static APP_NOTIFICATION_LISTENERS: GlobalSignal<HashMap<u64, NotificationListener>> =
+    Signal::global(HashMap::new);
+/// Notifications received by every session, oldest first.
+pub static APP_NOTIFICATIONS: GlobalSignal<Vec<ReceivedNotification>> = Signal::global(Vec::new);
+/// Entire-result and COPY exports still writing, keyed by tab.
+pub static APP_RUNNING_TAB_EXPORTS: GlobalSignal<HashMap<u64, RunningTabExport>> =
+    Signal::global(HashMap::new);
+/// Bumped by [`reset_layout`] so resizable panels drop their current sizes.
+pub static APP_LAYOUT_REVISION: GlobalSignal<u64> = Signal::global(|| 0);
+/// Saved query picked from the toolbar's Favorites menu, waiting for the
+/// Saved Queries panel to load it.
+pub static APP_PENDING_SAVED_QUERY: GlobalSignal<Option<SavedQuery>> = Signal::global(|| None);
 static NEXT_TOAST_ID: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(1);
 static TOAST_CANCEL_TOKENS: std::sync::LazyLock<Mutex<HashMap<u64, CancellationToken>>> =
     std::sync::LazyLock::new(|| Mutex::new(HashMap::new()));
@@ -104,6 +196,66 @@ pub fn reset_ui_settings() {
     });
 }
 
+/// Puts the panels back to their default sizes and docks, and the window back
+/// to its default size.
+pub fn reset_layout() {
+    update_ui_settings(|current| {
+        current.panel_sizes = WorkspacePanelSizes::default();
+        current.tool_panel_layout = WorkspaceToolLayout::default();
+    });
+    *APP_LAYOUT_REVISION.write() += 1;
+
+    let geometry = WindowGeometry::default();
+    let window = dioxus::desktop::window();
+    window.set_maximized(false);
+    window.set_inner_size(dioxus::desktop::LogicalSize::new(
+        geometry.width,
+        geometry.height,
+    ));
+    if let Err(err) = services::save_window_geometry_sync(geometry) {
+        eprintln!("Failed to reset window geometry: {err}");
+    }
+}
+
+/// Window geometry saved when the app last closed, for opening the window.
+pub fn saved_window_geometry() -> WindowGeometry {
+    services::load_window_geometry_sync()
+        .unwrap_or_else(|err| {
+            eprintln!("Failed to load window geometry: {err}");
+            WindowGeometry::default()
+        })
+        .clamped()
+}
+
+/// Saves the main window's size, position and maximized state for the next
+/// launch. A maximized window keeps the size saved before it was maximized.
+pub fn remember_window_geometry(window: &dioxus::desktop::DesktopContext) {
+    let scale = window.scale_factor();
+    let maximized = window.is_maximized();
+    let geometry = if maximized {
+        WindowGeometry {
+            maximized,
+            ..services::load_window_geometry_sync().unwrap_or_default()
+        }
+    } else {
+        let size = window.inner_size().to_logical::<f64>(scale);
+        let position = window
+            .outer_position()
+            .ok()
+            .map(|position| position.to_logical::<f64>(scale));
+        WindowGeometry {
+            width: size.width,
+            height: size.height,
+            x: position.map(|position| position.x),
+            y: position.map(|position| position.y),
+            maximized,
+        }
+    };
+    if let Err(err) = services::save_window_geometry_sync(geometry) {
+        eprintln!("Failed to save window geometry: {err}");
+    }
+}
+
 pub fn set_theme_preference(theme: AppThemePreference) {
     update_ui_settings(|current| {
         current.theme = theme;
@@ -155,6 +307,12 @@ pub fn set_show_history(visible: bool) {
     });
 }
 
+pub fn set_show_notifications(visible: bool) {
+    update_ui_settings(|current| {
+        current.show_notifications = visible;
+    });
+}
+
 pub fn set_show_sql_editor(visible: bool) {
     update_ui_settings(|current| {
         current.show_sql_editor = visible;
@@ -173,6 +331,60 @@ pub fn set_default_page_size(page_size: u32) {
     });
 }
 
+pub fn set_row_count_mode(mode: RowCountMode) {
+    update_ui_settings(|current| {
+        current.row_count_mode = mode;
+    });
+}
+
+pub fn set_editor_indent_width(width: u8) {
+    update_ui_settings(|current| {
+        current.editor_indent_width = width;
+    });
+}
+
+pub fn set_font_size(size: u8) {
+    update_ui_settings(|current| {
+        current.font_size = size;
+    });
+}
+
+pub fn set_query_timeout_secs(timeout_secs: Option<u32>) {
+    update_ui_settings(|current| {
+        current.query_timeout_secs = timeout_secs;
+    });
+}
+
+pub fn set_auto_reconnect(enabled: bool) {
+    update_ui_settings(|current| {
+        current.auto_reconnect = enabled;
+    });
+}
+
+pub fn set_format_on_execute(enabled: bool) {
+    update_ui_settings(|current| {
+        current.format_on_execute = enabled;
+    });
+}
+
+pub fn set_sidebar_width(width: f64) {
+    update_ui_settings(|current| {
+        current.panel_sizes.sidebar_width = width.round() as u32;
+    });
+}
+
+pub fn set_inspector_width(width: f64) {
+    update_ui_settings(|current| {
+        current.panel_sizes.inspector_width = width.round() as u32;
+    });
+}
+
+pub fn set_editor_height(height: f64) {
+    update_ui_settings(|current| {
+        current.panel_sizes.editor_height = height.round() as u32;
+    });
+}
+
 pub fn set_codestral_enabled(enabled: bool) {
     update_ui_settings(|current| {
         current.codestral.enabled = enabled;
@@ -241,6 +453,7 @@ fn sync_runtime_ui_settings(settings: &AppUiSettings) {
     *APP_SHOW_CONNECTIONS.write() = settings.show_connections;
     *APP_SHOW_EXPLORER.write() = settings.show_explorer;
     *APP_SHOW_HISTORY.write() = settings.show_history;
+    *APP_SHOW_NOTIFICATIONS.write() = settings.show_notifications;
     *APP_SHOW_SQL_EDITOR.write() = settings.show_sql_editor;
     *APP_SHOW_AGENT_PANEL.write() = settings.ai_features_enabled && settings.show_agent_panel;
 }
@@ -292,9 +505,10 @@ pub fn show_toast(message: impl Into<String>, kind: ToastKind) {
 pub fn dismiss_toast(id: u64) {
     // Cancel any in-flight auto-dismiss timer for this toast.
     if let Ok(mut tokens) = TOAST_CANCEL_TOKENS.lock()
-        && let Some(token) = tokens.remove(&id) {
-            token.cancel();
-        }
+        && let Some(token) = tokens.remove(&id)
+    {
+        token.cancel();
+    }
     APP_TOAST.with_mut(|toasts| {
         toasts.retain(|t| t.id != id);
     });
@@ -304,6 +518,14 @@ pub fn toast_error(message: impl Into<String>) {
     show_toast(message, ToastKind::Error);
 }
 
+/// Loads `item` into the workspace through the Saved Queries panel, which is
+/// shown so it can ask for the query's `${name}` variables.
+pub fn open_saved_query(item: SavedQuery) {
+    *APP_PENDING_SAVED_QUERY.write() = Some(item);
+    set_show_saved_queries(true);
+    show_workspace();
+}
+
 pub fn open_connection_screen() {
     APP_STATE.with_mut(|state| {
         state.show_connection_screen = true;
@@ -334,8 +556,300 @@ pub fn session_connection(session_id: u64) -> Option<DatabaseConnection> {
     APP_STATE.read().session_connection(session_id).cloned()
 }
 
-pub fn add_connection_session(request: ConnectionRequest, connection: DatabaseConnection) -> u64 {
-    let session_name = request.display_name();
+/// A reconnect in progress: which attempt it is on and when that attempt
+/// connects, once its backoff wait is over.
+#[derive(Clone, Copy, Debug)]
+pub struct SessionReconnect {
+    pub attempt: u32,
+    pub retry_at: Instant,
+    task: Option<Task>,
+}
+
+pub fn session_reconnect(session_id: u64) -> Option<SessionReconnect> {
+    APP_RECONNECTING.read().get(&session_id).copied()
+}
+
+/// Round trip of a session's latest ping, `None` when it failed, and how
+/// many pings in a row have failed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SessionPing {
+    pub latency_ms: Option<u64>,
+    pub failures: u32,
+}
+
+pub fn session_ping(session_id: u64) -> Option<SessionPing> {
+    APP_SESSION_PINGS.read().get(&session_id).copied()
+}
+
+/// Records a ping of `session_id`: its round trip, or `None` when it failed
+/// or timed out. Returns the number of failures in a row.
+pub fn record_session_ping(session_id: u64, latency: Option<Duration>) -> u32 {
+    let mut pings = APP_SESSION_PINGS.write();
+    let ping = pings.entry(session_id).or_default();
+    match latency {
+        Some(latency) => {
+            ping.latency_ms = Some(u64::try_from(latency.as_millis()).unwrap_or(u64::MAX));
+            ping.failures = 0;
+        }
+        None => {
+            ping.latency_ms = None;
+            ping.failures += 1;
+        }
+    }
+    ping.failures
+}
+
+/// Starts reconnecting `session_id` in the background when `err` means its
+/// connection dropped and auto-reconnect is on. The new connection replaces
+/// the session's handle in place, so the explorer reloads with its expanded
+/// and selected nodes intact. An open transaction cannot survive the drop and
+/// is discarded.
+pub fn reconnect_session_if_lost(session_id: u64, err: &DatabaseError) {
+    if APP_UI_SETTINGS.peek().auto_reconnect && err.is_connection_lost() {
+        reconnect_session(session_id);
+    }
+}
+
+/// Starts reconnecting `session_id` in the background, as
+/// [`reconnect_session_if_lost`] does, unless it already is.
+pub fn reconnect_session(session_id: u64) {
+    if APP_RECONNECTING.peek().contains_key(&session_id) {
+        return;
+    }
+    let Some((name, request)) = APP_STATE
+        .peek()
+        .session(session_id)
+        .map(|session| (session.name.clone(), session.request.clone()))
+    else {
+        return;
+    };
+
+    APP_RECONNECTING.write().insert(
+        session_id,
+        SessionReconnect {
+            attempt: 1,
+            retry_at: Instant::now(),
+            task: None,
+        },
+    );
+    let task = spawn(async move {
+        let result = services::reconnect_request(request, |attempt, delay| {
+            if let Some(reconnect) = APP_RECONNECTING.write().get_mut(&session_id) {
+                reconnect.attempt = attempt;
+                reconnect.retry_at = Instant::now() + delay;
+            }
+        })
+        .await;
+        APP_RECONNECTING.write().remove(&session_id);
+
+        match result {
+            Ok(connection) => {
+                let mut reconnected = false;
+                APP_STATE.with_mut(|state| {
+                    if let Some(session) = state
+                        .sessions
+                        .iter_mut()
+                        .find(|session| session.id == session_id)
+                    {
+                        session.connection = connection;
+                        reconnected = true;
+                    }
+                });
+                if reconnected {
+                    APP_TRANSACTIONS.write().remove(&session_id);
+                    APP_SESSION_PINGS.write().remove(&session_id);
+                    show_toast(format!("Reconnected to {name}"), ToastKind::Success);
+                }
+            }
+            Err(err) => toast_error(format!(
+                "Could not reconnect to {name} after {} attempts: {err}",
+                services::RECONNECT_MAX_ATTEMPTS
+            )),
+        }
+    });
+    if let Some(reconnect) = APP_RECONNECTING.write().get_mut(&session_id) {
+        reconnect.task = Some(task);
+    }
+}
+
+/// Gives up reconnecting `session_id`. The session keeps its dropped
+/// connection until a query fails on it again.
+pub fn stop_reconnecting(session_id: u64) {
+    let Some(reconnect) = APP_RECONNECTING.write().remove(&session_id) else {
+        return;
+    };
+    if let Some(task) = reconnect.task {
+        task.cancel();
+    }
+}
+
+/// Subscribes `session_id` to `channel`, opening its listener connection on
+/// the first channel. The listener outlives the notifications panel so
+/// nothing is missed while it is hidden.
+pub fn listen_to_channel(session_id: u64, channel: String) {
+    let channel = channel.trim().to_string();
+    if channel.is_empty() {
+        return;
+    }
+
+    let mut listeners = APP_NOTIFICATION_LISTENERS.write();
+    if let Some(listener) = listeners.get_mut(&session_id) {
+        if !listener.channels.contains(&channel) {
+            let _ = listener
+                .commands
+                .send(ListenCommand::Listen(channel.clone()));
+            listener.channels.push(channel);
+        }
+        return;
+    }
+    let Some(connection) = session_connection(session_id) else {
+        return;
+    };
+
+    let (commands, receiver) = tokio::sync::mpsc::unbounded_channel();
+    let _ = commands.send(ListenCommand::Listen(channel.clone()));
+    listeners.insert(
+        session_id,
+        NotificationListener {
+            channels: vec![channel],
+            commands,
+        },
+    );
+    drop(listeners);
+
+    spawn_forever(async move {
+        let result = services::listen_for_notifications(connection, receiver, |notification| {
+            let received_at = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|elapsed| elapsed.as_secs() as i64)
+                .unwrap_or_default();
+            APP_NOTIFICATIONS.with_mut(|notifications| {
+                notifications.push(ReceivedNotification {
+                    session_id,
+                    received_at,
+                    notification,
+                });
+                let overflow = notifications.len().saturating_sub(MAX_NOTIFICATIONS);
+                notifications.drain(..overflow);
+            });
+        })
+        .await;
+        // Unlistening everything and listening again may already have opened
+        // a new listener for the session; only drop this one.
+        APP_NOTIFICATION_LISTENERS.with_mut(|listeners| {
+            if listeners
+                .get(&session_id)
+                .is_some_and(|listener| listener.commands.is_closed())
+            {
+                listeners.remove(&session_id);
+            }
+        });
+        if let Err(err) = result {
+            toast_error(format!("Stopped listening for notifications: {err}"));
+        }
+    });
+}
+
+/// Unsubscribes `session_id` from `channel`, closing its listener connection
+/// after the last channel.
+pub fn unlisten_channel(session_id: u64, channel: &str) {
+    APP_NOTIFICATION_LISTENERS.with_mut(|listeners| {
+        let Some(listener) = listeners.get_mut(&session_id) else {
+            return;
+        };
+        listener.channels.retain(|listened| listened != channel);
+        if listener.channels.is_empty() {
+            // Dropping the sender ends the listener task.
+            listeners.remove(&session_id);
+        } else {
+            let _ = listener
+                .commands
+                .send(ListenCommand::Unlisten(channel.to_string()));
+        }
+    });
+}
+
+pub fn session_transaction(session_id: u64) -> Option<SqlTransaction> {
+    APP_TRANSACTIONS
+        .read()
+        .get(&session_id)
+        .map(|transaction| transaction.handle.clone())
+}
+
+pub fn session_transaction_status(session_id: u64) -> TransactionStatus {
+    APP_TRANSACTIONS
+        .read()
+        .get(&session_id)
+        .map(|transaction| transaction.status)
+        .unwrap_or_default()
+}
+
+pub fn session_transaction_savepoints(session_id: u64) -> Vec<String> {
+    APP_TRANSACTIONS
+        .read()
+        .get(&session_id)
+        .map(|transaction| transaction.savepoints.clone())
+        .unwrap_or_default()
+}
+
+pub fn set_session_transaction(session_id: u64, handle: Option<SqlTransaction>) {
+    APP_TRANSACTIONS.with_mut(|transactions| match handle {
+        Some(handle) => {
+            transactions.insert(
+                session_id,
+                SessionTransaction {
+                    handle,
+                    status: TransactionStatus::Active,
+                    savepoints: Vec::new(),
+                },
+            );
+        }
+        None => {
+            transactions.remove(&session_id);
+        }
+    });
+}
+
+pub fn set_session_transaction_status(session_id: u64, status: TransactionStatus) {
+    APP_TRANSACTIONS.with_mut(|transactions| {
+        if let Some(transaction) = transactions.get_mut(&session_id) {
+            transaction.status = status;
+        }
+    });
+}
+
+pub fn set_session_transaction_savepoints(session_id: u64, savepoints: Vec<String>) {
+    APP_TRANSACTIONS.with_mut(|transactions| {
+        if let Some(transaction) = transactions.get_mut(&session_id) {
+            transaction.savepoints = savepoints;
+        }
+    });
+}
+
+/// Drop cached row counts for a table after its contents changed.
+pub fn invalidate_row_counts(session_id: u64, qualified_name: &str) {
+    let prefix = format!("{session_id}\u{1f}{qualified_name}\u{1f}");
+    APP_ROW_COUNTS.with_mut(|counts| counts.retain(|key, _| !key.starts_with(&prefix)));
+}
+
+/// Take `deleted` rows off the cached row counts for a table.
+pub fn decrement_row_counts(session_id: u64, qualified_name: &str, deleted: u64) {
+    let prefix = format!("{session_id}\u{1f}{qualified_name}\u{1f}");
+    APP_ROW_COUNTS.with_mut(|counts| {
+        for (key, count) in counts.iter_mut() {
+            if key.starts_with(&prefix) {
+                count.rows = count.rows.saturating_sub(deleted);
+            }
+        }
+    });
+}
+
+pub fn add_connection_session(
+    session_name: String,
+    environment: Option<ConnectionEnvironment>,
+    request: ConnectionRequest,
+    connection: DatabaseConnection,
+) -> u64 {
     let session_kind = request.kind();
     let session_key = request.identity_key();
 
@@ -350,6 +864,7 @@ pub fn add_connection_session(request: ConnectionRequest, connection: DatabaseCo
             existing_session.connection = connection.clone();
             existing_session.name = session_name.clone();
             existing_session.kind = session_kind;
+            existing_session.environment = environment.clone();
             activated_id = existing_session.id;
         } else {
             let session_id = state.next_session_id;
@@ -360,6 +875,8 @@ pub fn add_connection_session(request: ConnectionRequest, connection: DatabaseCo
                 kind: session_kind,
                 request,
                 connection,
+                parent_id: None,
+                environment,
             });
             activated_id = session_id;
         }
@@ -373,18 +890,88 @@ pub fn add_connection_session(request: ConnectionRequest, connection: DatabaseCo
     activated_id
 }
 
-pub fn remove_session(session_id: u64) {
+/// Opens `connection` as a session on another database of `parent_id`'s
+/// server, shown under the parent in the tree and closed along with it.
+/// Returns the existing session instead when that database is already open.
+pub fn add_database_session(
+    parent_id: u64,
+    request: ConnectionRequest,
+    connection: DatabaseConnection,
+) -> u64 {
+    let session_key = request.identity_key();
+
+    let mut activated_id = 0;
     APP_STATE.with_mut(|state| {
+        if let Some(existing_session) = state
+            .sessions
+            .iter()
+            .find(|session| session.request.identity_key() == session_key)
+        {
+            activated_id = existing_session.id;
+        } else {
+            let (parent_name, environment) = state
+                .session(parent_id)
+                .map(|session| (session.name.clone(), session.environment.clone()))
+                .unwrap_or_default();
+            let session_id = state.next_session_id;
+            state.next_session_id += 1;
+            state.sessions.push(ConnectionSession {
+                id: session_id,
+                name: format!("{parent_name} · {}", request.short_name()),
+                kind: request.kind(),
+                request,
+                connection,
+                parent_id: Some(parent_id),
+                environment,
+            });
+            activated_id = session_id;
+        }
+
+        state.active_session_id = Some(activated_id);
+    });
+
+    activated_id
+}
+
+/// Closes the session and any database sessions opened from it, returning
+/// the ids of every session removed.
+pub fn remove_session(session_id: u64) -> Vec<u64> {
+    let mut removed_ids = Vec::new();
+    APP_STATE.with_mut(|state| {
+        removed_ids = state
+            .sessions
+            .iter()
+            .filter(|session| session.id == session_id || session.parent_id == Some(session_id))
+            .map(|session| session.id)
+            .collect::<Vec<_>>();
         let removed_keys = state
             .sessions
             .iter()
-            .filter(|session| session.id == session_id)
+            .filter(|session| removed_ids.contains(&session.id))
             .map(|session| session.request.identity_key())
             .collect::<Vec<_>>();
 
-        state.sessions.retain(|session| session.id != session_id);
+        state
+            .sessions
+            .retain(|session| !removed_ids.contains(&session.id));
+        for removed_id in &removed_ids {
+            // Dropping the handle rolls back any transaction left open on the session.
+            APP_TRANSACTIONS.write().remove(removed_id);
+            let row_count_prefix = format!("{removed_id}\u{1f}");
+            APP_ROW_COUNTS.with_mut(|counts| {
+                counts.retain(|key, _| !key.starts_with(&row_count_prefix));
+            });
+            APP_COLUMN_INDEX.write().remove(removed_id);
+            APP_TABLE_COLUMNS.write().remove(removed_id);
+            APP_SCHEMA_TABLE_NAMES.write().remove(removed_id);
+            APP_SESSION_PINGS.write().remove(removed_id);
+            APP_NOTIFICATION_LISTENERS.write().remove(removed_id);
+        }
 
-        if state.active_session_id == Some(session_id) {
+        if state
+            .active_session_id
+            .is_some_and(|active_id| removed_ids.contains(&active_id))
+        {
             state.active_session_id = state.sessions.first().map(|session| session.id);
         }
 
@@ -398,10 +985,12 @@ pub fn remove_session(session_id: u64) {
         }
     });
     persist_session_state();
+
+    removed_ids
 }
 
 pub fn restore_connection_sessions(
-    restored: Vec<(ConnectionRequest, DatabaseConnection)>,
+    restored: Vec<(SavedConnection, DatabaseConnection)>,
     active_name: Option<String>,
 ) {
     // First collect existing session names and release SSH tunnels
@@ -424,15 +1013,21 @@ pub fn restore_connection_sessions(
         let mut new_sessions = Vec::with_capacity(restored.len());
         let mut next_id = 1;
 
-        for (request, connection) in restored {
-            let session_name = request.display_name();
+        for (saved_connection, connection) in restored {
+            let SavedConnection {
+                name,
+                request,
+                environment,
+            } = saved_connection;
             let session_kind = request.kind();
             new_sessions.push(ConnectionSession {
                 id: next_id,
-                name: session_name,
+                name,
                 kind: session_kind,
                 request,
                 connection,
+                parent_id: None,
+                environment,
             });
             next_id += 1;
         }
@@ -460,9 +1055,11 @@ pub fn restore_connection_sessions(
 fn persist_session_state() {
     let (open_requests, active_connection_name) = {
         let state = APP_STATE.read();
+        // Database sessions opened from the tree are not restored on launch.
         let requests = state
             .sessions
             .iter()
+            .filter(|session| session.parent_id.is_none())
             .map(|session| session.request.clone())
             .collect::<Vec<_>>();
         let active = state
@@ -507,10 +1104,11 @@ fn persist_session_state() {
                 let err = join_err.to_string();
                 eprintln!("Failed to persist session state: {}", err);
                 if let Ok(mut last_error) = LAST_SESSION_PERSIST_ERROR.lock()
-                    && last_error.as_ref() != Some(&err) {
-                        *last_error = Some(err.clone());
-                        toast_error(format!("Failed to save session state: {err}"));
-                    }
+                    && last_error.as_ref() != Some(&err)
+                {
+                    *last_error = Some(err.clone());
+                    toast_error(format!("Failed to save session state: {err}"));
+                }
             }
         }
     });
@@ -0,0 +1,139 @@
+use dioxus::prelude::*;
+
+thread_local! {
+    // Keep clipboard ownership alive for Linux/X11/Wayland instead of dropping it right after copy.
+    static PERSISTENT_CLIPBOARD: std::cell::RefCell<Option<arboard::Clipboard>> =
+        const { std::cell::RefCell::new(None) };
+}
+
+/// Copies `text` with the native clipboard, falling back to the webview's
+/// clipboard API when the native one is unavailable.
+pub(crate) async fn copy_text(text: &str) -> Result<(), String> {
+    let native_err = match write_text_to_clipboard(text) {
+        Ok(()) => return Ok(()),
+        Err(err) => err,
+    };
+    let Some(script) = clipboard_copy_script(text) else {
+        return Err(format!("Clipboard error: {native_err}"));
+    };
+
+    match document::eval(&script).join::<bool>().await {
+        Ok(true) => Ok(()),
+        Ok(false) => Err(format!("Clipboard error: {native_err}")),
+        Err(err) => Err(format_clipboard_fallback_error(&native_err, err)),
+    }
+}
+
+/// Reads text from the native clipboard, falling back to the webview's
+/// clipboard API when the native one is unavailable.
+pub(crate) async fn read_text() -> Result<String, String> {
+    let native_err = match read_text_from_clipboard() {
+        Ok(text) => return Ok(text),
+        Err(err) => err,
+    };
+
+    match document::eval(CLIPBOARD_READ_SCRIPT)
+        .join::<Option<String>>()
+        .await
+    {
+        Ok(Some(text)) => Ok(text),
+        Ok(None) => Err(format!("Clipboard error: {native_err}")),
+        Err(err) => Err(format_clipboard_fallback_error(&native_err, err)),
+    }
+}
+
+const CLIPBOARD_READ_SCRIPT: &str = r#"
+    (() => {
+        if (!navigator.clipboard || !window.isSecureContext) {
+            return null;
+        }
+        return navigator.clipboard.readText().catch(() => null);
+    })()
+"#;
+
+fn read_text_from_clipboard() -> Result<String, String> {
+    PERSISTENT_CLIPBOARD.with(|clipboard| {
+        let mut clipboard = clipboard.borrow_mut();
+        if clipboard.is_none() {
+            *clipboard = Some(arboard::Clipboard::new().map_err(|err| err.to_string())?);
+        }
+
+        let clipboard = clipboard
+            .as_mut()
+            .ok_or_else(|| "Clipboard is unavailable.".to_string())?;
+
+        clipboard.get_text().map_err(|err| err.to_string())
+    })
+}
+
+fn write_text_to_clipboard(text: &str) -> Result<(), String> {
+    PERSISTENT_CLIPBOARD.with(|clipboard| {
+        let mut clipboard = clipboard.borrow_mut();
+        if clipboard.is_none() {
+            *clipboard = Some(arboard::Clipboard::new().map_err(|err| err.to_string())?);
+        }
+
+        let clipboard = clipboard
+            .as_mut()
+            .ok_or_else(|| "Clipboard is unavailable.".to_string())?;
+
+        clipboard
+            .set_text(text.to_string())
+            .map_err(|err| err.to_string())
+    })
+}
+
+fn clipboard_copy_script(text: &str) -> Option<String> {
+    let value = serde_json::to_string(text).ok()?;
+    Some(format!(
+        r#"
+        (() => {{
+            const value = {value};
+            const copyWithExecCommand = () => {{
+                const textarea = document.createElement("textarea");
+                textarea.value = value;
+                textarea.setAttribute("readonly", "");
+                textarea.style.position = "fixed";
+                textarea.style.opacity = "0";
+                textarea.style.pointerEvents = "none";
+                document.body.appendChild(textarea);
+                textarea.focus();
+                textarea.select();
+                const copied = document.execCommand("copy");
+                textarea.remove();
+                return copied;
+            }};
+
+            if (navigator.clipboard && window.isSecureContext) {{
+                return navigator.clipboard.writeText(value)
+                    .then(() => true)
+                    .catch(() => copyWithExecCommand());
+            }}
+
+            return copyWithExecCommand();
+        }})()
+        "#
+    ))
+}
+
+fn format_clipboard_fallback_error(
+    native_err: &str,
+    fallback_err: impl std::fmt::Display,
+) -> String {
+    format!("Clipboard error: {native_err}; fallback failed: {fallback_err}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::format_clipboard_fallback_error;
+
+    #[test]
+    fn clipboard_fallback_uses_display_not_debug() {
+        let formatted = format_clipboard_fallback_error("native error", "eval failed");
+        assert_eq!(
+            formatted,
+            "Clipboard error: native error; fallback failed: eval failed"
+        );
+        assert!(!formatted.contains(":?"));
+    }
+}
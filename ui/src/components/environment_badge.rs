@@ -0,0 +1,45 @@
+use dioxus::prelude::*;
+use models::ConnectionEnvironment;
+
+/// Class modifier that picks the badge color: green for development,
+/// orange for staging, red for production and neutral for custom tags.
+pub fn environment_modifier(environment: &ConnectionEnvironment) -> &'static str {
+    match environment {
+        ConnectionEnvironment::Development => "development",
+        ConnectionEnvironment::Staging => "staging",
+        ConnectionEnvironment::Production => "production",
+        ConnectionEnvironment::Custom(_) => "custom",
+    }
+}
+
+#[component]
+pub fn EnvironmentBadge(environment: ConnectionEnvironment) -> Element {
+    let modifier = environment_modifier(&environment);
+    let label = environment.label().to_string();
+
+    rsx! {
+        span {
+            class: "environment-badge environment-badge--{modifier}",
+            title: "{label}",
+            "{label}"
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::environment_modifier;
+    use models::ConnectionEnvironment;
+
+    #[test]
+    fn custom_environments_share_the_neutral_color() {
+        assert_eq!(
+            environment_modifier(&ConnectionEnvironment::Custom("QA".to_string())),
+            "custom"
+        );
+        assert_eq!(
+            environment_modifier(&ConnectionEnvironment::Production),
+            "production"
+        );
+    }
+}
@@ -1,2 +1,3 @@
 pub mod cache_indicator;
+pub mod environment_badge;
 pub mod inline_completion;
@@ -0,0 +1,90 @@
+use crate::app_state::open_saved_query;
+use dioxus::prelude::*;
+use models::{SavedQuery, saved_queries_by_folder};
+
+/// Toolbar menu listing saved queries and snippets by folder, so one can be
+/// loaded into the active tab without opening the Saved Queries panel first.
+/// The list is read from disk each time the menu opens.
+#[component]
+pub(super) fn FavoritesMenu() -> Element {
+    let mut open = use_signal(|| false);
+    let mut favorites = use_signal(|| None::<Result<Vec<SavedQuery>, String>>);
+
+    let folders = match favorites() {
+        Some(Ok(items)) => Ok(saved_queries_by_folder(items)),
+        Some(Err(err)) => Err(err),
+        None => Ok(Vec::new()),
+    };
+    let loading = favorites().is_none();
+
+    rsx! {
+        div {
+            class: "favorites-menu",
+            button {
+                class: if open() {
+                    "button button--ghost button--small button--active"
+                } else {
+                    "button button--ghost button--small"
+                },
+                title: "Load a saved query into the active tab",
+                onclick: move |_| {
+                    if open() {
+                        open.set(false);
+                        return;
+                    }
+                    open.set(true);
+                    favorites.set(None);
+                    spawn(async move {
+                        favorites.set(Some(services::load_saved_queries().await));
+                    });
+                },
+                "Favorites"
+            }
+            if open() {
+                div {
+                    class: "favorites-menu__backdrop",
+                    onclick: move |_| open.set(false),
+                }
+                div {
+                    class: "favorites-menu__panel",
+                    match folders {
+                        Err(err) => rsx! {
+                            p { class: "favorites-menu__empty", "Could not load saved queries: {err}" }
+                        },
+                        Ok(folders) if folders.is_empty() => rsx! {
+                            p {
+                                class: "favorites-menu__empty",
+                                if loading { "Loading…" } else { "No saved queries yet." }
+                            }
+                        },
+                        Ok(folders) => rsx! {
+                            for (folder, items) in folders {
+                                div {
+                                    key: "{folder}",
+                                    class: "favorites-menu__folder",
+                                    span { class: "favorites-menu__folder-name", "{folder}" }
+                                    for item in items {
+                                        button {
+                                            key: "{item.id}",
+                                            class: "favorites-menu__entry",
+                                            title: "{item.sql}",
+                                            onclick: {
+                                                let item = item.clone();
+                                                move |_| {
+                                                    open.set(false);
+                                                    open_saved_query(item.clone());
+                                                }
+                                            },
+                                            span { class: "favorites-menu__entry-title", "{item.title}" }
+                                            span { class: "favorites-menu__entry-kind", "{item.kind_label()}" }
+                                        }
+                                    }
+                                }
+                            }
+                        },
+                    }
+                }
+            }
+        }
+    }
+}
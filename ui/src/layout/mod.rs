@@ -1,3 +1,4 @@
+mod favorites_menu;
 mod settings_modal;
 mod status_bar;
 mod toast;
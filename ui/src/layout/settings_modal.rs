@@ -1,17 +1,19 @@
 use crate::{
     app_state::{
         APP_SHOW_SETTINGS_MODAL, APP_SQL_FORMAT_SETTINGS, APP_UI_SETTINGS, close_settings_modal,
-        reset_ui_settings, set_ai_features_enabled, set_codestral_api_key, set_codestral_enabled,
-        set_codestral_model, set_deepseek_api_key, set_deepseek_base_url, set_deepseek_enabled,
-        set_deepseek_model, set_deepseek_reasoning_effort, set_deepseek_thinking_enabled,
-        set_default_page_size, set_read_only_mode, set_restore_session_on_launch,
+        reset_layout, reset_ui_settings, set_ai_features_enabled, set_auto_reconnect,
+        set_codestral_api_key, set_codestral_enabled, set_codestral_model, set_deepseek_api_key,
+        set_deepseek_base_url, set_deepseek_enabled, set_deepseek_model,
+        set_deepseek_reasoning_effort, set_deepseek_thinking_enabled, set_default_page_size,
+        set_editor_indent_width, set_font_size, set_format_on_execute, set_query_timeout_secs,
+        set_read_only_mode, set_restore_session_on_launch, set_row_count_mode,
         set_show_agent_panel, set_show_connections, set_show_explorer, set_show_history,
-        set_show_saved_queries, set_show_sql_editor, set_theme_preference,
+        set_show_notifications, set_show_saved_queries, set_show_sql_editor, set_theme_preference,
     },
     screens::SqlFormatSettingsFields,
 };
 use dioxus::prelude::*;
-use models::AppThemePreference;
+use models::{AppThemePreference, EDITOR_INDENT_WIDTHS, FONT_SIZES, RowCountMode};
 
 #[component]
 #[allow(clippy::redundant_closure)]
@@ -22,6 +24,10 @@ pub fn SettingsModal() -> Element {
 
     let mut sql_format_settings = use_signal(|| APP_SQL_FORMAT_SETTINGS());
     let settings = APP_UI_SETTINGS();
+    let query_timeout = settings
+        .query_timeout_secs
+        .map(|secs| secs.to_string())
+        .unwrap_or_default();
 
     use_effect(move || {
         let settings = sql_format_settings();
@@ -196,10 +202,19 @@ pub fn SettingsModal() -> Element {
                         div {
                             class: "settings-modal__section-header",
                             h3 { class: "settings-modal__section-title", "Workspace" }
-                            button {
-                                class: "button button--ghost button--small",
-                                onclick: move |_| reset_ui_settings(),
-                                "Reset UI"
+                            div {
+                                class: "settings-modal__section-actions",
+                                button {
+                                    class: "button button--ghost button--small",
+                                    title: "Put panels and the window back to their default sizes",
+                                    onclick: move |_| reset_layout(),
+                                    "Reset layout"
+                                }
+                                button {
+                                    class: "button button--ghost button--small",
+                                    onclick: move |_| reset_ui_settings(),
+                                    "Reset UI"
+                                }
                             }
                         }
                         div {
@@ -223,6 +238,82 @@ pub fn SettingsModal() -> Element {
                                     },
                                 }
                             }
+                            div {
+                                class: "field",
+                                span { class: "field__label", "Table row count" }
+                                select {
+                                    class: "input",
+                                    value: settings.row_count_mode.label(),
+                                    oninput: move |event| {
+                                        let value = event.value();
+                                        if let Some(mode) = RowCountMode::ALL
+                                            .into_iter()
+                                            .find(|mode| mode.label() == value)
+                                        {
+                                            set_row_count_mode(mode);
+                                        }
+                                    },
+                                    for mode in RowCountMode::ALL {
+                                        option { value: mode.label(), "{mode.label()}" }
+                                    }
+                                }
+                            }
+                            div {
+                                class: "field",
+                                span { class: "field__label", "Editor indent" }
+                                select {
+                                    class: "input",
+                                    value: "{settings.editor_indent_width}",
+                                    oninput: move |event| {
+                                        if let Ok(width) = event.value().parse::<u8>()
+                                            && EDITOR_INDENT_WIDTHS.contains(&width)
+                                        {
+                                            set_editor_indent_width(width);
+                                        }
+                                    },
+                                    for width in EDITOR_INDENT_WIDTHS {
+                                        option { value: "{width}", "{width} spaces" }
+                                    }
+                                }
+                            }
+                            div {
+                                class: "field",
+                                span { class: "field__label", "Font size" }
+                                select {
+                                    class: "input",
+                                    value: "{settings.font_size}",
+                                    oninput: move |event| {
+                                        if let Ok(size) = event.value().parse::<u8>()
+                                            && FONT_SIZES.contains(&size)
+                                        {
+                                            set_font_size(size);
+                                        }
+                                    },
+                                    for size in FONT_SIZES {
+                                        option { value: "{size}", "{size} pt" }
+                                    }
+                                }
+                            }
+                            div {
+                                class: "field",
+                                span { class: "field__label", "Query timeout (s)" }
+                                input {
+                                    class: "input",
+                                    r#type: "number",
+                                    min: "1",
+                                    placeholder: "None",
+                                    title: "PostgreSQL cancels editor queries that run longer; leave empty for no limit",
+                                    value: query_timeout,
+                                    oninput: move |event| {
+                                        let value = event.value();
+                                        if value.trim().is_empty() {
+                                            set_query_timeout_secs(None);
+                                        } else if let Ok(secs) = value.trim().parse::<u32>() {
+                                            set_query_timeout_secs(Some(secs).filter(|secs| *secs > 0));
+                                        }
+                                    },
+                                }
+                            }
                         }
                         p {
                             class: "settings-modal__section-hint",
@@ -250,6 +341,17 @@ pub fn SettingsModal() -> Element {
                             }
                             span { "Restore previous session on launch" }
                         }
+                        label {
+                            class: "settings-modal__toggle",
+                            input {
+                                r#type: "checkbox",
+                                checked: settings.auto_reconnect,
+                                oninput: move |event| {
+                                    set_auto_reconnect(event.checked());
+                                },
+                            }
+                            span { "Reconnect automatically when a connection drops" }
+                        }
                         label {
                             class: "settings-modal__toggle",
                             input {
@@ -305,6 +407,17 @@ pub fn SettingsModal() -> Element {
                             }
                             span { "Show history by default" }
                         }
+                        label {
+                            class: "settings-modal__toggle",
+                            input {
+                                r#type: "checkbox",
+                                checked: settings.show_notifications,
+                                oninput: move |event| {
+                                    set_show_notifications(event.checked());
+                                },
+                            }
+                            span { "Show notifications by default" }
+                        }
                         label {
                             class: "settings-modal__toggle",
                             input {
@@ -347,6 +460,17 @@ pub fn SettingsModal() -> Element {
                                 "Reset SQL"
                             }
                         }
+                        label {
+                            class: "settings-modal__toggle",
+                            input {
+                                r#type: "checkbox",
+                                checked: settings.format_on_execute,
+                                oninput: move |event| {
+                                    set_format_on_execute(event.checked());
+                                },
+                            }
+                            span { "Format SQL before running it" }
+                        }
                         SqlFormatSettingsFields {
                             settings: sql_format_settings,
                         }
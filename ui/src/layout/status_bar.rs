@@ -1,5 +1,16 @@
-use crate::app_state::APP_STATE;
+use crate::app_state::{
+    APP_RECONNECTING, APP_STATE, APP_UI_SETTINGS, SessionPing, reconnect_session,
+    record_session_ping, session_connection, session_ping, session_reconnect,
+    session_transaction_savepoints, session_transaction_status, stop_reconnecting,
+};
 use dioxus::prelude::*;
+use models::TransactionStatus;
+use std::time::{Duration, Instant};
+
+const PING_INTERVAL: Duration = Duration::from_secs(10);
+const PING_TIMEOUT: Duration = Duration::from_secs(5);
+/// Failed pings in a row after which the connection counts as lost.
+const PING_FAILURES_BEFORE_LOST: u32 = 3;
 
 #[cfg_attr(not(test), allow(dead_code))]
 pub fn status_bar_session_label(session_name: Option<&str>) -> String {
@@ -29,22 +40,145 @@ pub fn is_allowed_status_bar_item(text: &str) -> bool {
     true
 }
 
+pub fn status_bar_transaction_label(
+    status: TransactionStatus,
+    savepoint_depth: usize,
+) -> Option<String> {
+    let label = match status {
+        TransactionStatus::Idle => return None,
+        TransactionStatus::Active => "🔒 TRANSACTION",
+        TransactionStatus::Aborted => "🔒 TRANSACTION ABORTED",
+    };
+    Some(match savepoint_depth {
+        0 => label.to_string(),
+        1 => format!("{label} · 1 savepoint"),
+        depth => format!("{label} · {depth} savepoints"),
+    })
+}
+
+/// Counts down the backoff wait in whole seconds, then shows the attempt
+/// while it connects.
+pub fn status_bar_reconnect_label(attempt: u32, max_attempts: u32, wait: Duration) -> String {
+    match wait.as_millis().div_ceil(1000) {
+        0 => format!("Reconnecting ({attempt}/{max_attempts})…"),
+        seconds => format!("Reconnecting in {seconds}s… ({attempt}/{max_attempts})"),
+    }
+}
+
+pub fn status_bar_ping_label(ping: SessionPing) -> Option<String> {
+    if ping.failures >= PING_FAILURES_BEFORE_LOST {
+        return Some("🔴 Connection lost".to_string());
+    }
+    ping.latency_ms
+        .map(|latency_ms| format!("🟢 Connected ({latency_ms}ms)"))
+}
+
+/// Pings the active session with `select 1` and records the round trip. The
+/// ping that makes [`PING_FAILURES_BEFORE_LOST`] failures in a row starts a
+/// reconnect when auto-reconnect is on.
+async fn ping_active_session() {
+    let Some(session_id) = APP_STATE.peek().active_session_id else {
+        return;
+    };
+    if APP_RECONNECTING.peek().contains_key(&session_id) {
+        return;
+    }
+    let Some(connection) = session_connection(session_id) else {
+        return;
+    };
+
+    let latency = tokio::time::timeout(PING_TIMEOUT, services::ping_connection(connection))
+        .await
+        .ok()
+        .and_then(Result::ok);
+    let failures = record_session_ping(session_id, latency);
+    if failures == PING_FAILURES_BEFORE_LOST && APP_UI_SETTINGS.peek().auto_reconnect {
+        reconnect_session(session_id);
+    }
+}
+
 #[component]
 pub fn StatusBar() -> Element {
-    let (connection_label, session_count) = {
+    use_future(|| async {
+        loop {
+            ping_active_session().await;
+            tokio::time::sleep(PING_INTERVAL).await;
+        }
+    });
+    // Ticks while the active session reconnects, so the countdown moves.
+    let mut countdown_tick = use_signal(|| 0_u64);
+    use_future(move || async move {
+        loop {
+            tokio::time::sleep(Duration::from_secs(1)).await;
+            let reconnecting = APP_STATE
+                .peek()
+                .active_session_id
+                .is_some_and(|session_id| APP_RECONNECTING.peek().contains_key(&session_id));
+            if reconnecting {
+                countdown_tick += 1;
+            }
+        }
+    });
+    countdown_tick();
+
+    let (connection_label, session_count, active_session_id) = {
         let app_state = APP_STATE.read();
         let label = match app_state.active_session() {
             Some(session) => session.name.clone(),
             None => "No connection".to_string(),
         };
-        (label, app_state.sessions.len())
+        (label, app_state.sessions.len(), app_state.active_session_id)
     };
+    let transaction_label = active_session_id.and_then(|session_id| {
+        status_bar_transaction_label(
+            session_transaction_status(session_id),
+            session_transaction_savepoints(session_id).len(),
+        )
+    });
+    let reconnect_label = active_session_id
+        .and_then(session_reconnect)
+        .map(|reconnect| {
+            status_bar_reconnect_label(
+                reconnect.attempt,
+                services::RECONNECT_MAX_ATTEMPTS,
+                reconnect.retry_at.saturating_duration_since(Instant::now()),
+            )
+        });
+    let ping = active_session_id
+        .filter(|_| reconnect_label.is_none())
+        .and_then(session_ping);
+    let ping_lost = ping.is_some_and(|ping| ping.failures >= PING_FAILURES_BEFORE_LOST);
+    let ping_label = ping.and_then(status_bar_ping_label);
 
     rsx! {
         footer {
             class: "statusbar",
             span { class: "statusbar__item", "{connection_label}" }
             span { class: "statusbar__item", "Sessions {session_count}" }
+            if let Some(label) = ping_label {
+                span {
+                    class: if ping_lost {
+                        "statusbar__item statusbar__item--connection-lost"
+                    } else {
+                        "statusbar__item"
+                    },
+                    "{label}"
+                }
+            }
+            if let Some(label) = transaction_label {
+                span { class: "statusbar__item statusbar__item--transaction", "{label}" }
+            }
+            if let (Some(label), Some(session_id)) = (reconnect_label, active_session_id) {
+                span {
+                    class: "statusbar__item statusbar__item--reconnecting",
+                    "{label}"
+                    button {
+                        class: "statusbar__button",
+                        onclick: move |_| stop_reconnecting(session_id),
+                        "Stop"
+                    }
+                }
+            }
         }
     }
 }
@@ -70,6 +204,72 @@ mod tests {
         assert_eq!(status_bar_session_count(3), "Sessions 3");
     }
 
+    #[test]
+    fn transaction_label_reflects_status() {
+        assert_eq!(
+            status_bar_transaction_label(TransactionStatus::Idle, 0),
+            None
+        );
+        assert_eq!(
+            status_bar_transaction_label(TransactionStatus::Active, 0).as_deref(),
+            Some("🔒 TRANSACTION")
+        );
+        assert_eq!(
+            status_bar_transaction_label(TransactionStatus::Aborted, 0).as_deref(),
+            Some("🔒 TRANSACTION ABORTED")
+        );
+    }
+
+    #[test]
+    fn transaction_label_shows_savepoint_depth() {
+        assert_eq!(
+            status_bar_transaction_label(TransactionStatus::Active, 1).as_deref(),
+            Some("🔒 TRANSACTION · 1 savepoint")
+        );
+        assert_eq!(
+            status_bar_transaction_label(TransactionStatus::Active, 3).as_deref(),
+            Some("🔒 TRANSACTION · 3 savepoints")
+        );
+    }
+
+    #[test]
+    fn ping_label_shows_latency_until_the_connection_is_lost() {
+        let ping = |latency_ms, failures| SessionPing {
+            latency_ms,
+            failures,
+        };
+        assert_eq!(status_bar_ping_label(ping(None, 0)), None);
+        assert_eq!(
+            status_bar_ping_label(ping(Some(12), 0)).as_deref(),
+            Some("🟢 Connected (12ms)")
+        );
+        assert_eq!(status_bar_ping_label(ping(None, 2)), None);
+        assert_eq!(
+            status_bar_ping_label(ping(None, 3)).as_deref(),
+            Some("🔴 Connection lost")
+        );
+    }
+
+    #[test]
+    fn reconnect_label_counts_attempts() {
+        assert_eq!(
+            status_bar_reconnect_label(2, 5, Duration::ZERO),
+            "Reconnecting (2/5)…"
+        );
+    }
+
+    #[test]
+    fn reconnect_label_counts_down_the_wait() {
+        assert_eq!(
+            status_bar_reconnect_label(3, 10, Duration::from_millis(3_200)),
+            "Reconnecting in 4s… (3/10)"
+        );
+        assert_eq!(
+            status_bar_reconnect_label(1, 10, Duration::from_millis(1)),
+            "Reconnecting in 1s… (1/10)"
+        );
+    }
+
     #[test]
     fn rejects_rust_dioxus_metadata() {
         assert!(!is_allowed_status_bar_item("Rust + Dioxus 0.7"));
@@ -1,5 +1,15 @@
-use crate::app_state::{APP_STATE, open_connection_screen, open_settings_modal, show_workspace};
-use dioxus::{desktop::use_window, html::input_data::MouseButton, prelude::*};
+use super::favorites_menu::FavoritesMenu;
+use crate::app_state::{
+    APP_RUNNING_TAB_EXPORTS, APP_RUNNING_TAB_LOADS, APP_STATE, APP_TRANSACTIONS,
+    open_connection_screen, open_settings_modal, remember_window_geometry, show_workspace,
+};
+use dioxus::{
+    desktop::{DesktopContext, use_window},
+    html::input_data::MouseButton,
+    prelude::*,
+};
+use futures_util::future::join_all;
+use rfd::{AsyncMessageDialog, MessageButtons, MessageDialogResult, MessageLevel};
 
 const APP_ICON: &str = include_str!("../../../app/assets/icon.svg");
 
@@ -11,6 +21,7 @@ pub fn Toolbar() -> Element {
     let desktop_minimize = desktop.clone();
     let desktop_maximize = desktop.clone();
     let desktop_close = desktop.clone();
+    let mut closing = use_signal(|| false);
     let (connection_label, has_sessions, show_connect_screen) = {
         let app_state = APP_STATE.read();
         let label = match app_state.active_session() {
@@ -62,6 +73,9 @@ pub fn Toolbar() -> Element {
             div {
                 class: "toolbar__actions",
                 onmousedown: move |event| event.stop_propagation(),
+                if has_sessions && !show_connect_screen {
+                    FavoritesMenu {}
+                }
                 if has_sessions {
                     button {
                         class: if show_connect_screen {
@@ -103,10 +117,139 @@ pub fn Toolbar() -> Element {
                 button {
                     class: "toolbar__window-button toolbar__window-button--close",
                     title: "Close",
-                    onclick: move |_| desktop_close.close(),
+                    disabled: closing(),
+                    onclick: move |_| {
+                        if closing() {
+                            return;
+                        }
+                        let desktop = desktop_close.clone();
+                        spawn(async move {
+                            closing.set(true);
+                            close_window(desktop).await;
+                            closing.set(false);
+                        });
+                    },
                     span { class: "toolbar__window-symbol toolbar__window-symbol--close" }
                 }
             }
         }
     }
 }
+
+/// Settles open transactions and closes every connection before the window
+/// goes away, so servers see a clean disconnect instead of a dropped socket.
+/// Open transactions ask whether to commit or roll back; running queries ask
+/// for confirmation. Cancelling either keeps the window open. The window's
+/// geometry is saved for the next launch.
+async fn close_window(desktop: DesktopContext) {
+    let transaction_sessions = {
+        let transactions = APP_TRANSACTIONS.read();
+        APP_STATE
+            .read()
+            .sessions
+            .iter()
+            .filter(|session| transactions.contains_key(&session.id))
+            .map(|session| session.name.clone())
+            .collect::<Vec<_>>()
+    };
+    let has_running_loads =
+        !APP_RUNNING_TAB_LOADS.read().is_empty() || !APP_RUNNING_TAB_EXPORTS.read().is_empty();
+
+    let commit = if !transaction_sessions.is_empty() {
+        let choice = AsyncMessageDialog::new()
+            .set_title("Open transactions")
+            .set_description(format!(
+                "A transaction is still open on {}.\n\nCommit or roll back before quitting?",
+                transaction_sessions.join(", ")
+            ))
+            .set_buttons(MessageButtons::YesNoCancelCustom(
+                "Commit".to_string(),
+                "Roll back".to_string(),
+                "Cancel".to_string(),
+            ))
+            .set_level(MessageLevel::Warning)
+            .show()
+            .await;
+        match choice {
+            MessageDialogResult::Yes => true,
+            MessageDialogResult::No => false,
+            MessageDialogResult::Custom(label) if label == "Commit" => true,
+            MessageDialogResult::Custom(label) if label == "Roll back" => false,
+            _ => return,
+        }
+    } else {
+        if has_running_loads {
+            let choice = AsyncMessageDialog::new()
+                .set_title("Queries still running")
+                .set_description("Quitting cancels the queries and exports that are still running.")
+                .set_buttons(MessageButtons::OkCancelCustom(
+                    "Quit".to_string(),
+                    "Cancel".to_string(),
+                ))
+                .set_level(MessageLevel::Warning)
+                .show()
+                .await;
+            match choice {
+                MessageDialogResult::Ok => {}
+                MessageDialogResult::Custom(label) if label == "Quit" => {}
+                _ => return,
+            }
+        }
+        false
+    };
+
+    for (_, load) in APP_RUNNING_TAB_LOADS.write().drain() {
+        for task in load.tasks {
+            task.cancel();
+        }
+    }
+    for (_, export) in APP_RUNNING_TAB_EXPORTS.write().drain() {
+        export.task.cancel();
+        let _ = std::fs::remove_file(&export.path);
+    }
+
+    // Transactions hold a pooled connection, so they are settled before the
+    // pools are closed.
+    let transactions = APP_TRANSACTIONS
+        .write()
+        .drain()
+        .map(|(_, transaction)| transaction.handle)
+        .collect::<Vec<_>>();
+    let finished = join_all(transactions.into_iter().map(|transaction| async move {
+        if commit {
+            transaction.commit().await
+        } else {
+            transaction.rollback().await
+        }
+    }));
+    let errors = match tokio::time::timeout(services::SHUTDOWN_TIMEOUT, finished).await {
+        Ok(results) => results
+            .into_iter()
+            .filter_map(Result::err)
+            .map(|err| err.to_string())
+            .collect::<Vec<_>>(),
+        Err(_) => vec!["Timed out waiting for the server.".to_string()],
+    };
+    if commit && !errors.is_empty() {
+        let _ = AsyncMessageDialog::new()
+            .set_title("Commit failed")
+            .set_description(format!(
+                "Some transactions were rolled back instead.\n\n{}",
+                errors.join("\n")
+            ))
+            .set_buttons(MessageButtons::Ok)
+            .set_level(MessageLevel::Error)
+            .show()
+            .await;
+    }
+
+    let connections = APP_STATE
+        .read()
+        .sessions
+        .iter()
+        .map(|session| session.connection.clone())
+        .collect::<Vec<_>>();
+    services::close_connections(connections).await;
+    remember_window_geometry(&desktop);
+    desktop.close();
+}
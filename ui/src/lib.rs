@@ -1,8 +1,10 @@
 mod app;
 mod app_state;
+mod clipboard;
 mod completion;
 mod components;
 mod layout;
 mod screens;
 
 pub use app::App;
+pub use app_state::saved_window_geometry;
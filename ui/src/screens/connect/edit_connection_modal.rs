@@ -5,7 +5,11 @@ use models::{
 };
 use rfd::AsyncFileDialog;
 
-use super::{forms::connection_status_class, kind_selector::KindSelector};
+use super::{
+    environment_selector::{EnvironmentSelector, entered_environment},
+    forms::connection_status_class,
+    kind_selector::KindSelector,
+};
 
 #[derive(Clone, PartialEq)]
 struct RemoteConnectionDraft {
@@ -19,6 +23,7 @@ struct RemoteConnectionDraft {
     ssh_port: String,
     ssh_username: String,
     ssh_private_key_path: String,
+    ssh_password: String,
 }
 
 impl RemoteConnectionDraft {
@@ -34,6 +39,7 @@ impl RemoteConnectionDraft {
             ssh_port: "22".to_string(),
             ssh_username: String::new(),
             ssh_private_key_path: String::new(),
+            ssh_password: String::new(),
         }
     }
 
@@ -49,6 +55,7 @@ impl RemoteConnectionDraft {
             ssh_port: "22".to_string(),
             ssh_username: String::new(),
             ssh_private_key_path: String::new(),
+            ssh_password: String::new(),
         }
     }
 
@@ -64,6 +71,7 @@ impl RemoteConnectionDraft {
             ssh_port: "22".to_string(),
             ssh_username: String::new(),
             ssh_private_key_path: String::new(),
+            ssh_password: String::new(),
         }
     }
 
@@ -116,6 +124,11 @@ impl RemoteConnectionDraft {
                 .as_ref()
                 .map(|ssh| ssh.private_key_path.clone())
                 .unwrap_or_default(),
+            ssh_password: data
+                .ssh_tunnel
+                .as_ref()
+                .map(|ssh| ssh.password.clone())
+                .unwrap_or_default(),
         }
     }
 
@@ -147,6 +160,11 @@ impl RemoteConnectionDraft {
                 .as_ref()
                 .map(|ssh| ssh.private_key_path.clone())
                 .unwrap_or_default(),
+            ssh_password: data
+                .ssh_tunnel
+                .as_ref()
+                .map(|ssh| ssh.password.clone())
+                .unwrap_or_default(),
         }
     }
 
@@ -178,6 +196,11 @@ impl RemoteConnectionDraft {
                 .as_ref()
                 .map(|ssh| ssh.private_key_path.clone())
                 .unwrap_or_default(),
+            ssh_password: data
+                .ssh_tunnel
+                .as_ref()
+                .map(|ssh| ssh.password.clone())
+                .unwrap_or_default(),
         }
     }
 
@@ -191,6 +214,7 @@ impl RemoteConnectionDraft {
             port: self.ssh_port.parse().unwrap_or(22),
             username: self.ssh_username.clone(),
             private_key_path: self.ssh_private_key_path.clone(),
+            password: self.ssh_password.clone(),
         })
     }
 }
@@ -202,6 +226,13 @@ pub fn EditConnectionModal(
     mut saved_connections_revision: Signal<u64>,
     mut status: Signal<String>,
 ) -> Element {
+    let mut profile_name = use_signal(|| {
+        saved_connection
+            .profile_name()
+            .map(str::to_string)
+            .unwrap_or_default()
+    });
+    let environment = use_signal(|| saved_connection.environment.clone());
     let selected_kind = use_signal(|| saved_connection.request.kind());
     let sqlite_path = use_signal(|| match &saved_connection.request {
         ConnectionRequest::Sqlite(data) => data.path.clone(),
@@ -209,6 +240,10 @@ pub fn EditConnectionModal(
     });
     let postgres_draft =
         use_signal(|| RemoteConnectionDraft::from_postgres_request(&saved_connection.request));
+    let postgres_parameters = use_signal(|| match &saved_connection.request {
+        ConnectionRequest::Postgres(data) => data.parameters.clone(),
+        _ => Vec::new(),
+    });
     let mysql_draft =
         use_signal(|| RemoteConnectionDraft::from_mysql_request(&saved_connection.request));
     let clickhouse_draft =
@@ -270,6 +305,7 @@ pub fn EditConnectionModal(
                                     username: draft.username,
                                     password: draft.password,
                                     database: draft.database,
+                                    parameters: postgres_parameters(),
                                     ssh_tunnel,
                                 })
                             }
@@ -304,8 +340,13 @@ pub fn EditConnectionModal(
                         save_inflight.set(true);
 
                         spawn(async move {
-                            match services::replace_connection_request(previous_identity_key, next_request)
-                                .await
+                            match services::replace_connection_request(
+                                previous_identity_key,
+                                next_request,
+                                Some(profile_name()),
+                                entered_environment(environment()),
+                            )
+                            .await
                             {
                                 Ok(()) => {
                                     status.set("Saved connection updated.".to_string());
@@ -327,6 +368,22 @@ pub fn EditConnectionModal(
                             class: "connect-screen__status connect-screen__status--hint",
                             "{saved_connection.name}"
                         }
+                        div {
+                            class: "field",
+                            label { class: "field__label", r#for: "edit-name", "Name" }
+                            input {
+                                class: "input",
+                                id: "edit-name",
+                                value: "{profile_name}",
+                                placeholder: "Optional, e.g. Production",
+                                disabled: save_inflight(),
+                                oninput: move |event| profile_name.set(event.value()),
+                            }
+                        }
+                        EnvironmentSelector {
+                            environment,
+                            disabled: save_inflight(),
+                        }
                         KindSelector {
                             selected_kind,
                         }
@@ -576,7 +633,8 @@ fn RemoteSshTunnelFields(mut draft: Signal<RemoteConnectionDraft>, disabled: boo
                     p { class: "connect-screen__section-title", "SSH Tunnel" }
                     p {
                         class: "connect-screen__status connect-screen__status--hint",
-                        "Forward the database port through the local OpenSSH client using agent or private key authentication."
+                        "Forward the database port through the local OpenSSH client using agent, private key, or password authentication. "
+                        "Host and Port above are the remote bind, resolved from the SSH host, so use the address the bastion sees."
                     }
                 }
                 button {
@@ -666,6 +724,23 @@ fn RemoteSshTunnelFields(mut draft: Signal<RemoteConnectionDraft>, disabled: boo
                         }
                     }
                 }
+
+                div {
+                    class: "field",
+                    label { class: "field__label", r#for: "edit-ssh-password", "SSH Password" }
+                    input {
+                        class: "input",
+                        id: "edit-ssh-password",
+                        r#type: "password",
+                        value: current.ssh_password.clone(),
+                        placeholder: "Used when no private key path is set",
+                        disabled: disabled || !current.ssh_private_key_path.trim().is_empty(),
+                        oninput: move |event| {
+                            let value = event.value();
+                            draft.with_mut(|draft| draft.ssh_password = value);
+                        },
+                    }
+                }
             }
         }
     }
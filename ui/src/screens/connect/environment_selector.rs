@@ -0,0 +1,100 @@
+use dioxus::prelude::*;
+use models::ConnectionEnvironment;
+
+/// The environment tag to save, dropping a custom tag whose label was left
+/// blank.
+pub(super) fn entered_environment(
+    environment: Option<ConnectionEnvironment>,
+) -> Option<ConnectionEnvironment> {
+    match environment {
+        Some(ConnectionEnvironment::Custom(label)) => {
+            let label = label.trim();
+            (!label.is_empty()).then(|| ConnectionEnvironment::Custom(label.to_string()))
+        }
+        environment => environment,
+    }
+}
+
+#[component]
+pub fn EnvironmentSelector(
+    mut environment: Signal<Option<ConnectionEnvironment>>,
+    disabled: bool,
+) -> Element {
+    let current = environment();
+    let current_value = match &current {
+        None => "none",
+        Some(ConnectionEnvironment::Development) => "development",
+        Some(ConnectionEnvironment::Staging) => "staging",
+        Some(ConnectionEnvironment::Production) => "production",
+        Some(ConnectionEnvironment::Custom(_)) => "custom",
+    };
+    let custom_label = match current {
+        Some(ConnectionEnvironment::Custom(label)) => Some(label),
+        _ => None,
+    };
+
+    rsx! {
+        div { class: "field",
+            label {
+                class: "field__label",
+                r#for: "connection-environment",
+                "Environment"
+            }
+            select {
+                class: "input",
+                id: "connection-environment",
+                value: "{current_value}",
+                disabled,
+                onchange: move |event| {
+                    let next_environment = match event.value().as_str() {
+                        "development" => Some(ConnectionEnvironment::Development),
+                        "staging" => Some(ConnectionEnvironment::Staging),
+                        "production" => Some(ConnectionEnvironment::Production),
+                        "custom" => Some(ConnectionEnvironment::Custom(String::new())),
+                        _ => None,
+                    };
+                    environment.set(next_environment);
+                },
+                option { value: "none", "None" }
+                option { value: "development", "Development" }
+                option { value: "staging", "Staging" }
+                option { value: "production", "Production" }
+                option { value: "custom", "Custom…" }
+            }
+            if let Some(label) = custom_label {
+                input {
+                    class: "input",
+                    id: "connection-environment-label",
+                    value: "{label}",
+                    placeholder: "e.g. QA",
+                    disabled,
+                    oninput: move |event| {
+                        environment.set(Some(ConnectionEnvironment::Custom(event.value())));
+                    },
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::entered_environment;
+    use models::ConnectionEnvironment;
+
+    #[test]
+    fn blank_custom_environment_is_cleared() {
+        assert_eq!(
+            entered_environment(Some(ConnectionEnvironment::Custom("  ".to_string()))),
+            None
+        );
+        assert_eq!(
+            entered_environment(Some(ConnectionEnvironment::Custom(" QA ".to_string()))),
+            Some(ConnectionEnvironment::Custom("QA".to_string()))
+        );
+        assert_eq!(
+            entered_environment(Some(ConnectionEnvironment::Staging)),
+            Some(ConnectionEnvironment::Staging)
+        );
+    }
+}
@@ -10,14 +10,20 @@ pub use postgres::PostgresForm;
 pub use sqlite::SqliteForm;
 pub use ssh_tunnel::SshTunnelFields;
 
+use dioxus::prelude::*;
+use models::{ConnectionRequest, ServerInfo};
+use std::time::Duration;
+
 pub(super) fn connection_status_class(status: &str) -> &'static str {
     let normalized = status.trim();
 
     if normalized.starts_with("Error:") {
         "connect-screen__status connect-screen__status--error"
-    } else if normalized.eq_ignore_ascii_case("connecting...") {
+    } else if normalized.eq_ignore_ascii_case("connecting...")
+        || normalized.eq_ignore_ascii_case("testing...")
+    {
         "connect-screen__status connect-screen__status--busy"
-    } else if normalized.starts_with("Connected") {
+    } else if normalized.starts_with("Connected") || normalized.starts_with('✓') {
         "connect-screen__status connect-screen__status--success"
     } else {
         "connect-screen__status connect-screen__status--hint"
@@ -28,6 +34,39 @@ pub(super) fn format_connection_error(err: impl std::fmt::Display) -> String {
     format!("Error: {err}")
 }
 
+/// The optional profile name typed into a connect form. Leaving it blank keeps
+/// the name a saved connection already has.
+pub(super) fn entered_profile_name(value: &str) -> Option<String> {
+    let value = value.trim();
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+/// Probes `request` in the background and reports the server version, or
+/// the failure, on the form's status line. The form stays open either way.
+pub(super) fn test_connection(request: ConnectionRequest, mut status: Signal<String>) {
+    status.set("Testing...".to_string());
+    spawn(async move {
+        let message = match services::test_connection_request(request).await {
+            Ok(result) => connection_test_summary(&result.server, result.latency),
+            Err(err) => format_connection_error(err),
+        };
+        status.set(message);
+    });
+}
+
+pub(super) fn connection_test_summary(info: &ServerInfo, latency: Duration) -> String {
+    let mut summary = format!(
+        "✓ Connected in {}ms · {}",
+        latency.as_millis(),
+        info.version
+    );
+    if let Some(database) = &info.database {
+        summary.push_str(" · ");
+        summary.push_str(database);
+    }
+    summary
+}
+
 #[cfg_attr(not(test), allow(dead_code))]
 pub(super) fn should_render_status(status: &str) -> bool {
     !status.trim().is_empty()
@@ -83,6 +122,57 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_connection_test_status_classes() {
+        assert_eq!(
+            connection_status_class("Testing..."),
+            "connect-screen__status connect-screen__status--busy"
+        );
+        assert_eq!(
+            connection_status_class(&connection_test_summary(
+                &ServerInfo {
+                    version: "PostgreSQL 16.2".to_string(),
+                    database: Some("app".to_string()),
+                },
+                Duration::from_millis(12),
+            )),
+            "connect-screen__status connect-screen__status--success"
+        );
+    }
+
+    #[test]
+    fn test_entered_profile_name() {
+        assert_eq!(entered_profile_name("  "), None);
+        assert_eq!(
+            entered_profile_name(" Production "),
+            Some("Production".to_string())
+        );
+    }
+
+    #[test]
+    fn test_connection_test_summary() {
+        assert_eq!(
+            connection_test_summary(
+                &ServerInfo {
+                    version: "PostgreSQL 16.2".to_string(),
+                    database: Some("app".to_string()),
+                },
+                Duration::from_millis(12),
+            ),
+            "✓ Connected in 12ms · PostgreSQL 16.2 · app"
+        );
+        assert_eq!(
+            connection_test_summary(
+                &ServerInfo {
+                    version: "ClickHouse 24.3.1.2672".to_string(),
+                    database: None,
+                },
+                Duration::from_micros(1_500),
+            ),
+            "✓ Connected in 1ms · ClickHouse 24.3.1.2672"
+        );
+    }
+
     #[test]
     fn test_status_class_fallback() {
         assert_eq!(
@@ -2,7 +2,10 @@ use crate::app_state::add_connection_session;
 use dioxus::prelude::*;
 use models::{ConnectionRequest, MySqlFormData, SshTunnelConfig};
 
-use super::{SshTunnelFields, connection_status_class, format_connection_error};
+use super::{
+    SshTunnelFields, connection_status_class, entered_profile_name, format_connection_error,
+    test_connection,
+};
 
 #[component]
 pub fn MySqlForm(mut saved_connections_revision: Signal<u64>) -> Element {
@@ -16,9 +19,31 @@ pub fn MySqlForm(mut saved_connections_revision: Signal<u64>) -> Element {
     let ssh_port = use_signal(|| "22".to_string());
     let ssh_username = use_signal(String::new);
     let ssh_private_key_path = use_signal(String::new);
+    let ssh_password = use_signal(String::new);
+    let mut profile_name = use_signal(String::new);
     let mut status = use_signal(String::new);
     let status_value = status();
     let status_class = connection_status_class(&status_value);
+    let build_request = move || {
+        ConnectionRequest::MySql(MySqlFormData {
+            host: host(),
+            port: port().parse().unwrap_or(3306),
+            username: username(),
+            password: password(),
+            database: database(),
+            ssh_tunnel: if ssh_enabled() {
+                Some(SshTunnelConfig {
+                    host: ssh_host(),
+                    port: ssh_port().parse().unwrap_or(22),
+                    username: ssh_username(),
+                    private_key_path: ssh_private_key_path(),
+                    password: ssh_password(),
+                })
+            } else {
+                None
+            },
+        })
+    };
 
     rsx! {
         form {
@@ -27,28 +52,18 @@ pub fn MySqlForm(mut saved_connections_revision: Signal<u64>) -> Element {
                 event.prevent_default();
 
                 status.set("Connecting...".to_string());
-                let request = ConnectionRequest::MySql(MySqlFormData {
-                    host: host(),
-                    port: port().parse().unwrap_or(3306),
-                    username: username(),
-                    password: password(),
-                    database: database(),
-                    ssh_tunnel: if ssh_enabled() {
-                        Some(SshTunnelConfig {
-                            host: ssh_host(),
-                            port: ssh_port().parse().unwrap_or(22),
-                            username: ssh_username(),
-                            private_key_path: ssh_private_key_path(),
-                        })
-                    } else {
-                        None
-                    },
-                });
+                let request = build_request();
+                let profile_name = entered_profile_name(&profile_name());
 
                 spawn(async move {
-                    match services::connect_and_save_request(request.clone()).await {
+                    match services::connect_and_save_request(request.clone(), profile_name).await {
                         Ok(result) => {
-                            add_connection_session(request, result.connection);
+                            add_connection_session(
+                                result.name,
+                                result.environment,
+                                request,
+                                result.connection,
+                            );
                             saved_connections_revision += 1;
                             match result.save_warning {
                                 Some(err) => status.set(format!(
@@ -61,6 +76,18 @@ pub fn MySqlForm(mut saved_connections_revision: Signal<u64>) -> Element {
                     }
                 });
             },
+            div {
+                class: "field",
+                label { class: "field__label", r#for: "mysql-name", "Name" }
+                input {
+                    class: "input",
+                    id: "mysql-name",
+                    value: "{profile_name}",
+                    placeholder: "Optional, e.g. Production",
+                    oninput: move |event| profile_name.set(event.value()),
+                }
+            }
+
             div {
                 class: "connect-form__grid",
                 div {
@@ -131,6 +158,7 @@ pub fn MySqlForm(mut saved_connections_revision: Signal<u64>) -> Element {
                 port: ssh_port,
                 username: ssh_username,
                 private_key_path: ssh_private_key_path,
+                password: ssh_password,
             }
 
             div {
@@ -140,6 +168,12 @@ pub fn MySqlForm(mut saved_connections_revision: Signal<u64>) -> Element {
                     r#type: "submit",
                     "Connect"
                 }
+                button {
+                    class: "button button--ghost",
+                    r#type: "button",
+                    onclick: move |_| test_connection(build_request(), status),
+                    "Test"
+                }
                 if !status_value.is_empty() {
                     p { class: "{status_class}", "{status_value}" }
                 }
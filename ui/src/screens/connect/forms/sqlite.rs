@@ -3,11 +3,14 @@ use dioxus::prelude::*;
 use models::{ConnectionRequest, SqliteFormData};
 use rfd::AsyncFileDialog;
 
-use super::{connection_status_class, format_connection_error};
+use super::{
+    connection_status_class, entered_profile_name, format_connection_error, test_connection,
+};
 
 #[component]
 pub fn SqliteForm(mut saved_connections_revision: Signal<u64>) -> Element {
     let mut path = use_signal(|| "".to_string());
+    let mut profile_name = use_signal(String::new);
     let mut status = use_signal(String::new);
     let status_value = status();
     let status_class = connection_status_class(&status_value);
@@ -28,11 +31,17 @@ pub fn SqliteForm(mut saved_connections_revision: Signal<u64>) -> Element {
                 let request = ConnectionRequest::Sqlite(SqliteFormData {
                     path: current_path,
                 });
+                let profile_name = entered_profile_name(&profile_name());
 
                 spawn(async move {
-                    match services::connect_and_save_request(request.clone()).await {
+                    match services::connect_and_save_request(request.clone(), profile_name).await {
                         Ok(result) => {
-                            add_connection_session(request, result.connection);
+                            add_connection_session(
+                                result.name,
+                                result.environment,
+                                request,
+                                result.connection,
+                            );
                             saved_connections_revision += 1;
                             match result.save_warning {
                                 Some(err) => status.set(format!(
@@ -45,6 +54,18 @@ pub fn SqliteForm(mut saved_connections_revision: Signal<u64>) -> Element {
                     }
                 });
             },
+            div {
+                class: "field",
+                label { class: "field__label", r#for: "sqlite-name", "Name" }
+                input {
+                    class: "input",
+                    id: "sqlite-name",
+                    value: "{profile_name}",
+                    placeholder: "Optional, e.g. Production",
+                    oninput: move |event| profile_name.set(event.value()),
+                }
+            }
+
             div {
                 class: "field",
                 label {
@@ -91,6 +112,24 @@ pub fn SqliteForm(mut saved_connections_revision: Signal<u64>) -> Element {
                     r#type: "submit",
                     "Connect"
                 }
+                button {
+                    class: "button button--ghost",
+                    r#type: "button",
+                    onclick: move |_| {
+                        let current_path = path().trim().to_string();
+                        if current_path.is_empty() {
+                            status.set("Config is empty".to_string());
+                            return;
+                        }
+                        test_connection(
+                            ConnectionRequest::Sqlite(SqliteFormData {
+                                path: current_path,
+                            }),
+                            status,
+                        );
+                    },
+                    "Test"
+                }
                 if !status_value.is_empty() {
                     p { class: "{status_class}", "{status_value}" }
                 }
@@ -7,6 +7,7 @@ pub fn SshTunnelFields(
     port: Signal<String>,
     username: Signal<String>,
     private_key_path: Signal<String>,
+    password: Signal<String>,
 ) -> Element {
     rsx! {
         div {
@@ -17,7 +18,8 @@ pub fn SshTunnelFields(
                     p { class: "connect-screen__section-title", "SSH Tunnel" }
                     p {
                         class: "connect-screen__status connect-screen__status--hint",
-                        "Forward the database port through the local OpenSSH client using agent or private key authentication."
+                        "Forward the database port through the local OpenSSH client using agent, private key, or password authentication. "
+                        "Host and Port above are the remote bind, resolved from the SSH host, so use the address the bastion sees."
                     }
                 }
                 button {
@@ -83,6 +85,20 @@ pub fn SshTunnelFields(
                         }
                     }
                 }
+
+                div {
+                    class: "field",
+                    label { class: "field__label", r#for: "ssh-password", "SSH Password" }
+                    input {
+                        class: "input",
+                        id: "ssh-password",
+                        r#type: "password",
+                        value: "{password}",
+                        placeholder: "Used when no private key path is set",
+                        disabled: !private_key_path().trim().is_empty(),
+                        oninput: move |event| password.set(event.value()),
+                    }
+                }
             }
         }
     }
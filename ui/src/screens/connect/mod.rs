@@ -1,4 +1,5 @@
 mod edit_connection_modal;
+mod environment_selector;
 mod forms;
 mod kind_selector;
 mod recent_connections;
@@ -1,4 +1,5 @@
 use crate::app_state::add_connection_session;
+use crate::components::environment_badge::EnvironmentBadge;
 use dioxus::prelude::*;
 use models::SavedConnection;
 
@@ -83,6 +84,9 @@ pub fn RecentConnections(
                                 div {
                                     class: "recent-connection__meta",
                                     p { class: "recent-connection__name", "{saved_connection.name}" }
+                                    if let Some(environment) = saved_connection.environment.clone() {
+                                        EnvironmentBadge { environment }
+                                    }
                                 }
                                 div {
                                     class: "recent-connection__actions",
@@ -102,9 +106,14 @@ pub fn RecentConnections(
                                                 let request_to_connect = request.clone();
                                                 let request_to_register = request.clone();
                                                 spawn(async move {
-                                                    match services::connect_and_save_request(request_to_connect).await {
+                                                    match services::connect_and_save_request(request_to_connect, None).await {
                                                         Ok(result) => {
-                                                            add_connection_session(request_to_register, result.connection);
+                                                            add_connection_session(
+                                                                result.name,
+                                                                result.environment,
+                                                                request_to_register,
+                                                                result.connection,
+                                                            );
                                                             saved_connections_revision += 1;
                                                             match result.save_warning {
                                                                 Some(err) => status.set(format!(
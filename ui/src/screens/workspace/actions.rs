@@ -1,10 +1,24 @@
-use crate::app_state::{APP_READ_ONLY_MODE, APP_UI_SETTINGS, activate_session, session_connection};
+use crate::app_state::{
+    APP_READ_ONLY_MODE, APP_ROW_COUNTS, APP_RUNNING_TAB_EXPORTS, APP_RUNNING_TAB_LOADS,
+    APP_UI_SETTINGS, RunningTabExport, RunningTabLoad, activate_session, invalidate_row_counts,
+    reconnect_session_if_lost, session_connection, session_transaction, set_session_transaction,
+    set_session_transaction_savepoints, set_session_transaction_status,
+};
+use crate::screens::workspace::components::format_bytes;
 use dioxus::prelude::*;
 use models::{
-    DatabaseConnection, PendingTableChanges, QueryFilter, QueryFilterMode, QueryHistoryItem,
-    QueryOutput, QuerySort, QueryTabState, TablePreviewSource, WorkspaceTabKind,
+    CsvExportOptions, CsvImportOptions, DatabaseConnection, DatabaseError, JsonExportFormat,
+    JsonExportOptions, PendingTableChanges, QueryExportSource, QueryFilter, QueryFilterMode,
+    QueryHistoryItem, QueryOutput, QuerySort, QueryTabState, RowCountMode, SqlInsertExportOptions,
+    TablePreviewSource, TableRowCount, TransactionStatus, WorkspaceTabKind,
 };
-use std::time::Instant;
+use rfd::AsyncFileDialog;
+use services::{QueryParameterValue, SqlTransaction};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
 
 fn redact_sql(sql: &str) -> String {
     let lower = sql.to_lowercase();
@@ -82,6 +96,7 @@ pub fn new_query_tab(id: u64, session_id: u64, title: String, sql: String) -> Qu
         filter: None,
         sort: None,
         tab_kind: WorkspaceTabKind::Query,
+        structure_source: None,
         is_loading_more: false,
         pending_table_changes: PendingTableChanges::default(),
         execution_plan: None,
@@ -141,6 +156,7 @@ pub fn update_active_tab_sql(
             tab.filter = None;
             tab.sort = None;
             tab.tab_kind = WorkspaceTabKind::Query;
+            tab.structure_source = None;
             tab.is_loading_more = false;
             tab.pending_table_changes = PendingTableChanges::default();
         }
@@ -204,12 +220,38 @@ pub fn append_to_tab_sql(
             tab.filter = None;
             tab.sort = None;
             tab.tab_kind = WorkspaceTabKind::Query;
+            tab.structure_source = None;
             tab.is_loading_more = false;
             tab.pending_table_changes = PendingTableChanges::default();
         }
     });
 }
 
+/// Inserts `text` at the end of the tab's SQL without touching its result,
+/// separated by a space unless the editor already ends in whitespace.
+pub fn insert_text_at_end_of_tab_sql(
+    mut tabs: Signal<Vec<QueryTabState>>,
+    tab_id: u64,
+    text: String,
+    status: String,
+) {
+    tabs.with_mut(|all_tabs| {
+        if let Some(tab) = all_tabs.iter_mut().find(|tab| tab.id == tab_id) {
+            let sql = sql_with_text_at_end(&tab.sql, &text);
+            sync_tab_sql_draft(tab, &sql);
+            tab.status = status;
+        }
+    });
+}
+
+fn sql_with_text_at_end(sql: &str, text: &str) -> String {
+    if sql.is_empty() || sql.ends_with(char::is_whitespace) {
+        format!("{sql}{text}")
+    } else {
+        format!("{sql} {text}")
+    }
+}
+
 pub fn set_active_tab_status(
     mut tabs: Signal<Vec<QueryTabState>>,
     active_tab_id: u64,
@@ -287,6 +329,7 @@ pub fn open_structure_tab(
     tabs.with_mut(|all_tabs| {
         let mut tab = new_query_tab(tab_id, session_id, title, String::new());
         tab.tab_kind = WorkspaceTabKind::Structure;
+        tab.structure_source = Some(source.clone());
         tab.status = format!("Loading structure for {}...", source.table_name);
         all_tabs.push(tab);
     });
@@ -337,11 +380,125 @@ pub fn tab_connection_or_error(
     }
 }
 
+/// Latest result load started for each tab. Queries and previews run
+/// concurrently, so a slow response from an earlier load must not overwrite
+/// the result of a later one.
+static TAB_LOAD_IDS: LazyLock<Mutex<HashMap<u64, u64>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+static NEXT_TAB_LOAD_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Records a new result load for `tab_id` and returns its id; loads started
+/// earlier for the tab become stale.
+fn begin_tab_load(tab_id: u64) -> u64 {
+    let load_id = NEXT_TAB_LOAD_ID.fetch_add(1, Ordering::SeqCst);
+    TAB_LOAD_IDS
+        .lock()
+        .expect("TAB_LOAD_IDS lock poisoned")
+        .insert(tab_id, load_id);
+    load_id
+}
+
+fn current_tab_load(tab_id: u64) -> u64 {
+    TAB_LOAD_IDS
+        .lock()
+        .expect("TAB_LOAD_IDS lock poisoned")
+        .get(&tab_id)
+        .copied()
+        .unwrap_or_default()
+}
+
+fn is_current_tab_load(tab_id: u64, load_id: u64) -> bool {
+    current_tab_load(tab_id) == load_id
+}
+
+fn track_tab_load(tab_id: u64, load_id: u64, tasks: Vec<Task>) {
+    APP_RUNNING_TAB_LOADS
+        .write()
+        .insert(tab_id, RunningTabLoad { load_id, tasks });
+}
+
+fn finish_tab_load(tab_id: u64, load_id: u64) {
+    let finished = APP_RUNNING_TAB_LOADS
+        .peek()
+        .get(&tab_id)
+        .is_some_and(|load| load.load_id == load_id);
+    if finished {
+        APP_RUNNING_TAB_LOADS.write().remove(&tab_id);
+    }
+}
+
+/// Stops the query, table preview or row count still running for `tab_id`.
+/// The tab keeps the rows it showed before the load started.
+pub fn cancel_tab_load(mut tabs: Signal<Vec<QueryTabState>>, tab_id: u64) {
+    let Some(load) = APP_RUNNING_TAB_LOADS.write().remove(&tab_id) else {
+        return;
+    };
+    for task in load.tasks {
+        task.cancel();
+    }
+    begin_tab_load(tab_id);
+
+    tabs.with_mut(|all_tabs| {
+        if let Some(tab) = all_tabs.iter_mut().find(|tab| tab.id == tab_id) {
+            // The preview source was switched when the load started; point it
+            // back at the table the kept rows came from.
+            if let Some(QueryOutput::Table(page)) = tab.result.as_ref() {
+                tab.preview_source = page
+                    .editable
+                    .as_ref()
+                    .map(|editable| editable.source.clone());
+            }
+            tab.is_loading_more = false;
+            tab.status = "Load cancelled".to_string();
+        }
+    });
+}
+
 pub fn run_query_for_tab(
+    tabs: Signal<Vec<QueryTabState>>,
+    current_id: u64,
+    connection: DatabaseConnection,
+    sql: String,
+    offset: u64,
+    page_size: u32,
+    history: Option<QueryHistorySignals>,
+) {
+    run_query_in_tab(
+        tabs,
+        current_id,
+        connection,
+        sql,
+        Vec::new(),
+        offset,
+        page_size,
+        history,
+    );
+}
+
+/// Runs a PostgreSQL query with its `:name` and `$N` parameters bound to
+/// `parameters`. The SQL cannot be re-run without them, so the tab shows the
+/// first page only and is not paged, sorted or filtered afterwards.
+pub fn run_query_with_parameters_for_tab(
+    tabs: Signal<Vec<QueryTabState>>,
+    current_id: u64,
+    connection: DatabaseConnection,
+    sql: String,
+    parameters: Vec<QueryParameterValue>,
+    page_size: u32,
+    history: Option<QueryHistorySignals>,
+) {
+    run_query_in_tab(
+        tabs, current_id, connection, sql, parameters, 0, page_size, history,
+    );
+}
+
+#[allow(clippy::too_many_arguments)]
+fn run_query_in_tab(
     mut tabs: Signal<Vec<QueryTabState>>,
     current_id: u64,
     connection: DatabaseConnection,
     sql: String,
+    parameters: Vec<QueryParameterValue>,
     offset: u64,
     page_size: u32,
     history: Option<QueryHistorySignals>,
@@ -351,6 +508,20 @@ pub fn run_query_for_tab(
         return;
     }
 
+    let Some(session_id) = tabs
+        .read()
+        .iter()
+        .find(|tab| tab.id == current_id)
+        .map(|tab| tab.session_id)
+    else {
+        return;
+    };
+    if let Some(control) = transaction_control_statement(&sql) {
+        run_transaction_control(tabs, current_id, session_id, connection, control);
+        return;
+    }
+    let transaction = session_transaction(session_id);
+
     let filter = tabs
         .read()
         .iter()
@@ -373,12 +544,42 @@ pub fn run_query_for_tab(
     });
 
     let connection_type = get_connection_type(&connection);
+    let load_id = begin_tab_load(current_id);
+    let timeout_secs = APP_UI_SETTINGS.peek().query_timeout_secs;
+    let armed_timeout_secs =
+        timeout_secs.filter(|_| services::statement_timeout_applies(&connection, &sql));
+    let rerunnable = parameters.is_empty();
 
-    spawn(async move {
+    let task = spawn(async move {
         let start_time = Instant::now();
-        match services::execute_query_page(connection, sql.clone(), page_size, offset, filter, sort)
+        let result = if rerunnable {
+            execute_query_page_in_session(
+                session_id,
+                transaction,
+                connection,
+                sql.clone(),
+                page_size,
+                offset,
+                filter,
+                sort,
+                timeout_secs,
+            )
             .await
-        {
+        } else {
+            execute_query_with_parameters_in_session(
+                session_id,
+                transaction,
+                connection,
+                sql.clone(),
+                parameters,
+                page_size,
+                timeout_secs,
+            )
+            .await
+        };
+        finish_tab_load(current_id, load_id);
+
+        match result {
             Ok(output) => {
                 let (status, current_offset) = match &output {
                     QueryOutput::Table(page) => (
@@ -392,22 +593,22 @@ pub fn run_query_for_tab(
                     QueryOutput::AffectedRows(count) => Some(*count as usize),
                 };
 
-                tabs.with_mut(|all_tabs| {
-                    if let Some(tab) = all_tabs.iter_mut().find(|tab| tab.id == current_id) {
-                        tab.result = Some(output);
-                        tab.status = status.clone();
-                        tab.current_offset = current_offset;
-                        tab.page_size = page_size;
-                        tab.last_run_sql = Some(sql.clone());
-                        tab.preview_source = None;
-                        tab.is_loading_more = false;
-                        tab.pending_table_changes = PendingTableChanges::default();
-                    }
-                });
+                if is_current_tab_load(current_id, load_id) {
+                    tabs.with_mut(|all_tabs| {
+                        if let Some(tab) = all_tabs.iter_mut().find(|tab| tab.id == current_id) {
+                            tab.result = Some(output);
+                            tab.status = status.clone();
+                            tab.current_offset = current_offset;
+                            tab.page_size = page_size;
+                            tab.last_run_sql = rerunnable.then(|| sql.clone());
+                            tab.preview_source = None;
+                            tab.is_loading_more = false;
+                            tab.pending_table_changes = PendingTableChanges::default();
+                        }
+                    });
+                }
 
-                if let Some((mut history, mut next_history_id, tab_title, connection_name)) =
-                    history
-                {
+                if let Some((history, mut next_history_id, tab_title, connection_name)) = history {
                     let duration_ms = start_time.elapsed().as_millis() as u64;
                     let history_id = next_history_id();
                     next_history_id += 1;
@@ -422,30 +623,28 @@ pub fn run_query_for_tab(
                         connection_type: connection_type.clone(),
                         outcome: "Success".to_string(),
                         error_message: None,
+                        pinned: false,
                     };
-                    history.with_mut(|items| {
-                        items.insert(0, history_item.clone());
-                        if items.len() > 20 {
-                            items.truncate(20);
-                        }
-                    });
+                    record_history_item(history, history_item.clone());
                     let _ = services::append_query_history(history_item).await;
                 }
             }
             Err(err) => {
-                tabs.with_mut(|all_tabs| {
-                    if let Some(tab) = all_tabs.iter_mut().find(|tab| tab.id == current_id) {
-                        tab.result = None;
-                        tab.status = format!("Error: {err}");
-                        tab.preview_source = None;
-                        tab.is_loading_more = false;
-                        tab.pending_table_changes = PendingTableChanges::default();
-                    }
-                });
+                if is_current_tab_load(current_id, load_id) {
+                    tabs.with_mut(|all_tabs| {
+                        if let Some(tab) = all_tabs.iter_mut().find(|tab| tab.id == current_id) {
+                            tab.result = None;
+                            tab.status =
+                                query_error_status(&err, armed_timeout_secs, start_time.elapsed());
+                            tab.preview_source = None;
+                            tab.is_loading_more = false;
+                            tab.pending_table_changes = PendingTableChanges::default();
+                        }
+                    });
+                }
+                reconnect_session_if_lost(session_id, &err);
 
-                if let Some((mut history, mut next_history_id, tab_title, connection_name)) =
-                    history
-                {
+                if let Some((history, mut next_history_id, tab_title, connection_name)) = history {
                     let duration_ms = start_time.elapsed().as_millis() as u64;
                     let history_id = next_history_id();
                     next_history_id += 1;
@@ -460,18 +659,356 @@ pub fn run_query_for_tab(
                         connection_type: connection_type.clone(),
                         outcome: format!("Error: {err}"),
                         error_message: Some(err.to_string()),
+                        pinned: false,
                     };
-                    history.with_mut(|items| {
-                        items.insert(0, history_item.clone());
-                        if items.len() > 20 {
-                            items.truncate(20);
-                        }
-                    });
+                    record_history_item(history, history_item.clone());
                     let _ = services::append_query_history(history_item).await;
                 }
             }
         }
     });
+    track_tab_load(current_id, load_id, vec![task]);
+}
+
+/// Adds a run to the top of the History panel, dropping the oldest unpinned
+/// runs beyond what the history store keeps.
+fn record_history_item(mut history: Signal<Vec<QueryHistoryItem>>, item: QueryHistoryItem) {
+    history.with_mut(|items| {
+        items.insert(0, item);
+        let mut unpinned = 0;
+        items.retain(|item| {
+            if !item.pinned {
+                unpinned += 1;
+            }
+            item.pinned || unpinned <= services::QueryHistoryStore::MAX_ITEMS
+        });
+    });
+}
+
+/// Transaction control typed into the editor as plain SQL.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TransactionControl {
+    Begin,
+    Commit,
+    Rollback,
+    Savepoint(String),
+    RollbackToSavepoint(String),
+    ReleaseSavepoint(String),
+}
+
+/// Recognise `BEGIN`/`COMMIT`/`ROLLBACK` and savepoint statements so they act
+/// on the session's dedicated transaction instead of running on an arbitrary
+/// pooled connection.
+pub fn transaction_control_statement(sql: &str) -> Option<TransactionControl> {
+    let words = sql
+        .trim()
+        .trim_end_matches(';')
+        .split_whitespace()
+        .collect::<Vec<_>>();
+    let lowered = words
+        .iter()
+        .map(|word| word.to_ascii_lowercase())
+        .collect::<Vec<_>>();
+    let keywords = lowered.iter().map(String::as_str).collect::<Vec<_>>();
+    let name = || {
+        words
+            .last()
+            .map(|word| word.trim_matches(['"', '`']).to_string())
+            .unwrap_or_default()
+    };
+
+    match keywords.as_slice() {
+        ["begin"] | ["begin", "transaction" | "work"] | ["start", "transaction"] => {
+            Some(TransactionControl::Begin)
+        }
+        ["commit"] | ["commit", "transaction" | "work"] | ["end"] | ["end", "transaction"] => {
+            Some(TransactionControl::Commit)
+        }
+        ["rollback"] | ["rollback", "transaction" | "work"] | ["abort"] => {
+            Some(TransactionControl::Rollback)
+        }
+        ["savepoint", _] => Some(TransactionControl::Savepoint(name())),
+        ["release", "savepoint", _] => Some(TransactionControl::ReleaseSavepoint(name())),
+        ["release", target] if *target != "savepoint" => {
+            Some(TransactionControl::ReleaseSavepoint(name()))
+        }
+        ["rollback", rest @ ..] => {
+            let rest = match rest {
+                ["transaction" | "work", rest @ ..] => rest,
+                rest => rest,
+            };
+            match rest {
+                ["to", "savepoint", _] => Some(TransactionControl::RollbackToSavepoint(name())),
+                ["to", target] if *target != "savepoint" => {
+                    Some(TransactionControl::RollbackToSavepoint(name()))
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+pub fn run_transaction_control(
+    tabs: Signal<Vec<QueryTabState>>,
+    tab_id: u64,
+    session_id: u64,
+    connection: DatabaseConnection,
+    control: TransactionControl,
+) {
+    match control {
+        TransactionControl::Begin => {
+            begin_session_transaction(tabs, tab_id, session_id, connection)
+        }
+        TransactionControl::Commit => finish_session_transaction(tabs, tab_id, session_id, true),
+        TransactionControl::Rollback => finish_session_transaction(tabs, tab_id, session_id, false),
+        control => run_savepoint_control(tabs, tab_id, session_id, control),
+    }
+}
+
+/// Create, roll back to or release a savepoint on the session's transaction.
+pub fn run_savepoint_control(
+    tabs: Signal<Vec<QueryTabState>>,
+    tab_id: u64,
+    session_id: u64,
+    control: TransactionControl,
+) {
+    let Some(transaction) = session_transaction(session_id) else {
+        set_active_tab_status(
+            tabs,
+            tab_id,
+            "Savepoints need an open transaction".to_string(),
+        );
+        return;
+    };
+
+    spawn(async move {
+        let (result, done) = match &control {
+            TransactionControl::Savepoint(name) => (
+                transaction.savepoint(name).await,
+                format!("Savepoint {name} created"),
+            ),
+            TransactionControl::RollbackToSavepoint(name) => (
+                transaction.rollback_to_savepoint(name).await,
+                format!("Rolled back to savepoint {name}"),
+            ),
+            TransactionControl::ReleaseSavepoint(name) => (
+                transaction.release_savepoint(name).await,
+                format!("Savepoint {name} released"),
+            ),
+            _ => return,
+        };
+
+        refresh_session_transaction_status(session_id, &transaction).await;
+        set_session_transaction_savepoints(session_id, transaction.savepoints().await);
+        let status = match result {
+            Ok(()) => done,
+            Err(err) => format!("Savepoint error: {err}"),
+        };
+        set_active_tab_status(tabs, tab_id, status);
+    });
+}
+
+pub fn begin_session_transaction(
+    tabs: Signal<Vec<QueryTabState>>,
+    tab_id: u64,
+    session_id: u64,
+    connection: DatabaseConnection,
+) {
+    if session_transaction(session_id).is_some() {
+        set_active_tab_status(tabs, tab_id, "A transaction is already open".to_string());
+        return;
+    }
+
+    set_active_tab_status(tabs, tab_id, "Starting transaction...".to_string());
+    spawn(async move {
+        match services::begin_transaction(connection).await {
+            Ok(transaction) => {
+                set_session_transaction(session_id, Some(transaction));
+                set_active_tab_status(tabs, tab_id, "Transaction started".to_string());
+            }
+            Err(err) => {
+                set_active_tab_status(tabs, tab_id, format!("Transaction error: {err}"));
+            }
+        }
+    });
+}
+
+pub fn finish_session_transaction(
+    tabs: Signal<Vec<QueryTabState>>,
+    tab_id: u64,
+    session_id: u64,
+    commit: bool,
+) {
+    let Some(transaction) = session_transaction(session_id) else {
+        set_active_tab_status(tabs, tab_id, "No transaction is open".to_string());
+        return;
+    };
+
+    let action = if commit { "Committing" } else { "Rolling back" };
+    set_active_tab_status(tabs, tab_id, format!("{action} transaction..."));
+    spawn(async move {
+        let result = if commit {
+            transaction.commit().await
+        } else {
+            transaction.rollback().await
+        };
+        // The handle is spent either way; a failed COMMIT leaves nothing to retry.
+        set_session_transaction(session_id, None);
+        let status = match (result, commit) {
+            (Ok(()), true) => "Transaction committed".to_string(),
+            (Ok(()), false) => "Transaction rolled back".to_string(),
+            (Err(err), _) => format!("Transaction error: {err}"),
+        };
+        set_active_tab_status(tabs, tab_id, status);
+    });
+}
+
+async fn refresh_session_transaction_status(session_id: u64, transaction: &SqlTransaction) {
+    let status = transaction.status().await;
+    if status != TransactionStatus::Idle {
+        set_session_transaction_status(session_id, status);
+    }
+}
+
+/// Run a query page on the session's open transaction, or on the pool when
+/// the session autocommits. `timeout_secs` is set on every run, so it also
+/// holds on connections opened by a reconnect.
+#[allow(clippy::too_many_arguments)]
+pub async fn execute_query_page_in_session(
+    session_id: u64,
+    transaction: Option<SqlTransaction>,
+    connection: DatabaseConnection,
+    sql: String,
+    page_size: u32,
+    offset: u64,
+    filter: Option<QueryFilter>,
+    sort: Option<QuerySort>,
+    timeout_secs: Option<u32>,
+) -> Result<QueryOutput, DatabaseError> {
+    match transaction {
+        Some(transaction) => {
+            let result = match timeout_secs {
+                Some(timeout_secs) => transaction.set_statement_timeout(timeout_secs).await,
+                None => Ok(()),
+            };
+            let result = match result {
+                Ok(()) => {
+                    transaction
+                        .execute_query_page(sql, page_size, offset, filter, sort)
+                        .await
+                }
+                Err(err) => Err(err),
+            };
+            refresh_session_transaction_status(session_id, &transaction).await;
+            result
+        }
+        None => {
+            services::execute_query_page_with_timeout(
+                connection,
+                sql,
+                page_size,
+                offset,
+                filter,
+                sort,
+                timeout_secs,
+            )
+            .await
+        }
+    }
+}
+
+/// [`execute_query_page_in_session`] for a query with bound parameters.
+async fn execute_query_with_parameters_in_session(
+    session_id: u64,
+    transaction: Option<SqlTransaction>,
+    connection: DatabaseConnection,
+    sql: String,
+    parameters: Vec<QueryParameterValue>,
+    page_size: u32,
+    timeout_secs: Option<u32>,
+) -> Result<QueryOutput, DatabaseError> {
+    match transaction {
+        Some(transaction) => {
+            let result = match timeout_secs {
+                Some(timeout_secs) => transaction.set_statement_timeout(timeout_secs).await,
+                None => Ok(()),
+            };
+            let result = match result {
+                Ok(()) => {
+                    transaction
+                        .execute_query_with_parameters(sql, parameters, page_size)
+                        .await
+                }
+                Err(err) => Err(err),
+            };
+            refresh_session_transaction_status(session_id, &transaction).await;
+            result
+        }
+        None => {
+            services::execute_query_with_parameters(
+                connection,
+                sql,
+                parameters,
+                page_size,
+                timeout_secs,
+            )
+            .await
+        }
+    }
+}
+
+/// Tab status for a failed query, naming the timeout instead of PostgreSQL's
+/// cancellation message when that is what stopped it. `armed_timeout_secs` is
+/// the timeout the run was actually bounded by, if any, and `elapsed` how
+/// long the run took.
+fn query_error_status(
+    err: &DatabaseError,
+    armed_timeout_secs: Option<u32>,
+    elapsed: Duration,
+) -> String {
+    match timed_out_secs(err.is_query_canceled(), armed_timeout_secs, elapsed) {
+        Some(timeout_secs) => format!("Query timed out after {timeout_secs}s"),
+        None => format!("Error: {err}"),
+    }
+}
+
+/// The timeout that cancelled a run, if one did. A cancel request, such as
+/// Cancel in the activity monitor, fails the query the same way, but the
+/// timeout cannot fire before the run has lasted that long.
+fn timed_out_secs(
+    canceled: bool,
+    armed_timeout_secs: Option<u32>,
+    elapsed: Duration,
+) -> Option<u32> {
+    armed_timeout_secs
+        .filter(|timeout_secs| canceled && elapsed >= Duration::from_secs(u64::from(*timeout_secs)))
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn load_table_preview_page_in_session(
+    session_id: u64,
+    transaction: Option<SqlTransaction>,
+    connection: DatabaseConnection,
+    source: TablePreviewSource,
+    page_size: u32,
+    offset: u64,
+    filter: Option<QueryFilter>,
+    sort: Option<QuerySort>,
+) -> Result<QueryOutput, DatabaseError> {
+    match transaction {
+        Some(transaction) => {
+            let result = transaction
+                .load_table_preview_page(source, page_size, offset, filter, sort)
+                .await;
+            refresh_session_transaction_status(session_id, &transaction).await;
+            result
+        }
+        None => {
+            services::load_table_preview_page(connection, source, page_size, offset, filter, sort)
+                .await
+        }
+    }
 }
 
 pub fn run_explain_for_tab(
@@ -562,9 +1099,27 @@ pub fn run_table_preview_for_tab(
             tab.preview_source = Some(source.clone());
         }
     });
+    let session_id = tabs
+        .read()
+        .iter()
+        .find(|tab| tab.id == current_id)
+        .map(|tab| tab.session_id);
+    let transaction = session_id.and_then(session_transaction);
+    let row_count_task = session_id.and_then(|session_id| {
+        load_table_row_count_if_missing(
+            session_id,
+            connection.clone(),
+            source.clone(),
+            filter.clone(),
+        )
+    });
 
-    spawn(async move {
-        match services::load_table_preview_page(
+    let load_id = begin_tab_load(current_id);
+
+    let task = spawn(async move {
+        let result = load_table_preview_page_in_session(
+            session_id.unwrap_or_default(),
+            transaction,
             connection,
             source.clone(),
             page_size,
@@ -572,8 +1127,16 @@ pub fn run_table_preview_for_tab(
             filter,
             sort,
         )
-        .await
-        {
+        .await;
+        finish_tab_load(current_id, load_id);
+        if let (Err(err), Some(session_id)) = (&result, session_id) {
+            reconnect_session_if_lost(session_id, err);
+        }
+        if !is_current_tab_load(current_id, load_id) {
+            return;
+        }
+
+        match result {
             Ok(output) => {
                 let status = match &output {
                     QueryOutput::Table(page) => format_loaded_rows_from_source_status(
@@ -610,7 +1173,114 @@ pub fn run_table_preview_for_tab(
                 });
             }
         }
-    });
+    });
+    track_tab_load(
+        current_id,
+        load_id,
+        [Some(task), row_count_task].into_iter().flatten().collect(),
+    );
+}
+
+/// Cache key for a table preview row count; the table part doubles as the
+/// prefix used by [`invalidate_row_counts`].
+pub fn row_count_cache_key(
+    session_id: u64,
+    source: &TablePreviewSource,
+    filter: Option<&QueryFilter>,
+    mode: RowCountMode,
+) -> String {
+    format!(
+        "{session_id}\u{1f}{}\u{1f}{mode:?}\u{1f}{filter:?}",
+        source.qualified_name
+    )
+}
+
+/// Cached row count for the table the tab is previewing, if one was loaded.
+pub fn cached_row_count_for_tab(tab: &QueryTabState) -> Option<TableRowCount> {
+    let source = tab.preview_source.as_ref()?;
+    let key = row_count_cache_key(
+        tab.session_id,
+        source,
+        tab.filter.as_ref(),
+        APP_UI_SETTINGS().row_count_mode,
+    );
+    APP_ROW_COUNTS.read().get(&key).copied()
+}
+
+/// Row count cache keys whose count query is still running.
+static ROW_COUNTS_IN_FLIGHT: LazyLock<Mutex<HashSet<String>>> =
+    LazyLock::new(|| Mutex::new(HashSet::new()));
+
+/// Count the rows behind a preview once per table, filter and mode so paging
+/// through the table does not re-run the count.
+fn load_table_row_count_if_missing(
+    session_id: u64,
+    connection: DatabaseConnection,
+    source: TablePreviewSource,
+    filter: Option<QueryFilter>,
+) -> Option<Task> {
+    let mode = APP_UI_SETTINGS().row_count_mode;
+    if mode == RowCountMode::Off {
+        return None;
+    }
+    let key = row_count_cache_key(session_id, &source, filter.as_ref(), mode);
+    if APP_ROW_COUNTS.read().contains_key(&key) {
+        return None;
+    }
+    // Paging through a slow table would otherwise queue another COUNT(*) per
+    // page, and each holds a pooled connection that queries need.
+    let in_flight = claim_row_count(key.clone())?;
+
+    Some(spawn(async move {
+        let result = services::load_table_row_count(connection, source, filter, mode).await;
+        drop(in_flight);
+        if let Ok(Some(count)) = result {
+            APP_ROW_COUNTS.write().insert(key, count);
+        }
+    }))
+}
+
+/// Marks `key` as being counted, or returns `None` when a count for it is
+/// already running.
+fn claim_row_count(key: String) -> Option<RowCountInFlight> {
+    if !ROW_COUNTS_IN_FLIGHT
+        .lock()
+        .expect("ROW_COUNTS_IN_FLIGHT lock poisoned")
+        .insert(key.clone())
+    {
+        return None;
+    }
+    Some(RowCountInFlight(key))
+}
+
+/// Clears its key from [`ROW_COUNTS_IN_FLIGHT`] when the count finishes or its
+/// task is dropped with the component that spawned it.
+struct RowCountInFlight(String);
+
+impl Drop for RowCountInFlight {
+    fn drop(&mut self) {
+        if let Ok(mut keys) = ROW_COUNTS_IN_FLIGHT.lock() {
+            keys.remove(&self.0);
+        }
+    }
+}
+
+pub(crate) fn format_row_count(count: TableRowCount) -> String {
+    let digits = count.rows.to_string();
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    let noun = if count.rows == 1 { "row" } else { "rows" };
+
+    if count.estimated {
+        format!("~{grouped} {noun} (estimated)")
+    } else {
+        format!("{grouped} {noun}")
+    }
 }
 
 /// Maximum number of rows that can accumulate via infinite-scroll append.
@@ -690,10 +1360,15 @@ pub fn append_next_tab_page(mut tabs: Signal<Vec<QueryTabState>>, current_tab: Q
             tab.status = format!("Loading more rows from {}...", next_offset + 1);
         }
     });
+    let transaction = session_transaction(current_tab.session_id);
+    let tab_id = current_tab.id;
+    let load_id = current_tab_load(tab_id);
 
-    spawn(async move {
+    let task = spawn(async move {
         let next_page_result = if let Some(source) = expected_preview_source.clone() {
-            services::load_table_preview_page(
+            load_table_preview_page_in_session(
+                current_tab.session_id,
+                transaction,
                 connection,
                 source,
                 current_tab.page_size,
@@ -703,13 +1378,16 @@ pub fn append_next_tab_page(mut tabs: Signal<Vec<QueryTabState>>, current_tab: Q
             )
             .await
         } else if let Some(sql) = expected_sql.clone() {
-            services::execute_query_page(
+            execute_query_page_in_session(
+                current_tab.session_id,
+                transaction,
                 connection,
                 sql,
                 current_tab.page_size,
                 next_offset,
                 expected_filter.clone(),
                 expected_sort.clone(),
+                APP_UI_SETTINGS.peek().query_timeout_secs,
             )
             .await
         } else {
@@ -720,6 +1398,7 @@ pub fn append_next_tab_page(mut tabs: Signal<Vec<QueryTabState>>, current_tab: Q
             });
             return;
         };
+        finish_tab_load(current_tab.id, load_id);
 
         match next_page_result {
             Ok(QueryOutput::Table(next_page)) => {
@@ -728,7 +1407,8 @@ pub fn append_next_tab_page(mut tabs: Signal<Vec<QueryTabState>>, current_tab: Q
                         return;
                     };
 
-                    let same_request = tab.last_run_sql == expected_sql
+                    let same_request = is_current_tab_load(current_tab.id, load_id)
+                        && tab.last_run_sql == expected_sql
                         && tab.preview_source == expected_preview_source
                         && tab.filter == expected_filter
                         && tab.sort == expected_sort;
@@ -758,6 +1438,7 @@ pub fn append_next_tab_page(mut tabs: Signal<Vec<QueryTabState>>, current_tab: Q
                     tab.is_loading_more = false;
                 });
             }
+            Ok(_) if !is_current_tab_load(current_tab.id, load_id) => {}
             Ok(other_output) => {
                 tabs.with_mut(|all_tabs| {
                     if let Some(tab) = all_tabs.iter_mut().find(|tab| tab.id == current_tab.id) {
@@ -767,6 +1448,7 @@ pub fn append_next_tab_page(mut tabs: Signal<Vec<QueryTabState>>, current_tab: Q
                     }
                 });
             }
+            Err(_) if !is_current_tab_load(current_tab.id, load_id) => {}
             Err(err) => {
                 tabs.with_mut(|all_tabs| {
                     if let Some(tab) = all_tabs.iter_mut().find(|tab| tab.id == current_tab.id) {
@@ -777,6 +1459,7 @@ pub fn append_next_tab_page(mut tabs: Signal<Vec<QueryTabState>>, current_tab: Q
             }
         }
     });
+    track_tab_load(tab_id, load_id, vec![task]);
 }
 
 fn loaded_rows_range(offset: u64, row_count: usize) -> Option<(u64, u64)> {
@@ -870,11 +1553,499 @@ pub fn refresh_tab_result(
     }
 }
 
+/// Asks for a CSV file and imports it into `source`, reporting progress in the tab status.
+pub fn run_csv_import_for_tab(
+    tabs: Signal<Vec<QueryTabState>>,
+    tab_id: u64,
+    source: TablePreviewSource,
+    path: PathBuf,
+    options: CsvImportOptions,
+    use_copy: bool,
+) {
+    if read_only_mode_enabled() {
+        set_active_tab_status(tabs, tab_id, read_only_mode_block_status("CSV import"));
+        return;
+    }
+
+    let Some(session_id) = tabs
+        .read()
+        .iter()
+        .find(|tab| tab.id == tab_id)
+        .map(|tab| tab.session_id)
+    else {
+        return;
+    };
+    let Some(connection) = tab_connection_or_error(tabs, tab_id, session_id) else {
+        return;
+    };
+
+    set_active_tab_status(
+        tabs,
+        tab_id,
+        format!("Importing {}...", path.to_string_lossy()),
+    );
+
+    spawn(async move {
+        let table_name = source.table_name.clone();
+        let result = if use_copy {
+            services::copy_csv_into_table(connection, source.clone(), path, options, |bytes| {
+                set_active_tab_status(
+                    tabs,
+                    tab_id,
+                    format!("Copying into {table_name}... {} sent", format_bytes(bytes)),
+                );
+            })
+            .await
+        } else {
+            services::import_csv_into_table(connection, source.clone(), path, options, |rows| {
+                set_active_tab_status(
+                    tabs,
+                    tab_id,
+                    format!("Importing into {table_name}... {rows} row(s) so far"),
+                );
+            })
+            .await
+        };
+
+        match result {
+            Ok(rows) => {
+                invalidate_row_counts(session_id, &source.qualified_name);
+                set_active_tab_status(
+                    tabs,
+                    tab_id,
+                    format!("Imported {rows} row(s) into {}", source.table_name),
+                );
+                if let Some(updated_tab) = tabs.read().iter().find(|tab| tab.id == tab_id).cloned()
+                {
+                    refresh_tab_result(tabs, updated_tab, Some(source));
+                }
+            }
+            Err(err) => set_active_tab_status(tabs, tab_id, format!("CSV import error: {err}")),
+        }
+    });
+}
+
+/// Which rows a CSV export writes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CsvExportScope {
+    /// The rows currently loaded in the result grid.
+    LoadedRows,
+    /// Every row of the result, re-queried page by page.
+    EntireResult,
+    /// The whole previewed table through PostgreSQL `COPY`, ignoring the
+    /// grid's filter and sort.
+    PostgresCopy,
+}
+
+/// Whether the tab's session is PostgreSQL, where CSV transfers can use COPY.
+pub fn tab_uses_postgres(tabs: Signal<Vec<QueryTabState>>, tab_id: u64) -> bool {
+    tabs.read()
+        .iter()
+        .find(|tab| tab.id == tab_id)
+        .and_then(|tab| session_connection(tab.session_id))
+        .is_some_and(|connection| matches!(connection, DatabaseConnection::Postgres(_)))
+}
+
+pub fn run_csv_export_for_tab(
+    tabs: Signal<Vec<QueryTabState>>,
+    tab_id: u64,
+    file_name: String,
+    scope: CsvExportScope,
+    options: CsvExportOptions,
+) {
+    let Some(current_tab) = tabs.read().iter().find(|tab| tab.id == tab_id).cloned() else {
+        return;
+    };
+    let Some(QueryOutput::Table(page)) = current_tab.result.clone() else {
+        set_active_tab_status(
+            tabs,
+            tab_id,
+            "Nothing to export in the current tab".to_string(),
+        );
+        return;
+    };
+    if scope != CsvExportScope::LoadedRows && APP_RUNNING_TAB_EXPORTS.read().contains_key(&tab_id) {
+        set_active_tab_status(
+            tabs,
+            tab_id,
+            "An export is already running in this tab".to_string(),
+        );
+        return;
+    }
+
+    if scope == CsvExportScope::PostgresCopy {
+        let Some(source) = current_tab.preview_source.clone() else {
+            set_active_tab_status(
+                tabs,
+                tab_id,
+                "COPY export is available for previewed tables".to_string(),
+            );
+            return;
+        };
+        let Some(connection) = tab_connection_or_error(tabs, tab_id, current_tab.session_id) else {
+            return;
+        };
+        run_csv_copy_export(tabs, tab_id, file_name, connection, source, options);
+        return;
+    }
+
+    let full_export = match scope {
+        CsvExportScope::LoadedRows | CsvExportScope::PostgresCopy => None,
+        CsvExportScope::EntireResult => {
+            let source = match (
+                current_tab.preview_source.clone(),
+                current_tab.last_run_sql.clone(),
+            ) {
+                (Some(source), _) => QueryExportSource::Table(source),
+                (None, Some(sql)) => QueryExportSource::Sql(sql),
+                (None, None) => {
+                    set_active_tab_status(
+                        tabs,
+                        tab_id,
+                        "The full result can no longer be re-queried".to_string(),
+                    );
+                    return;
+                }
+            };
+            let Some(connection) = tab_connection_or_error(tabs, tab_id, current_tab.session_id)
+            else {
+                return;
+            };
+            Some((connection, source))
+        }
+    };
+
+    set_active_tab_status(
+        tabs,
+        tab_id,
+        "Select a destination for the CSV export".to_string(),
+    );
+
+    spawn(async move {
+        let Some(file) = AsyncFileDialog::new()
+            .set_file_name(&file_name)
+            .add_filter("CSV", &["csv"])
+            .save_file()
+            .await
+        else {
+            set_active_tab_status(tabs, tab_id, "Export cancelled".to_string());
+            return;
+        };
+
+        let path = file.path().to_path_buf();
+        let Some((connection, source)) = full_export else {
+            set_active_tab_status(
+                tabs,
+                tab_id,
+                format!("Exporting {} rows to CSV...", page.rows.len()),
+            );
+            let result = services::export_query_page_csv(page, path.clone(), options).await;
+            report_csv_export(tabs, tab_id, &path, result);
+            return;
+        };
+
+        set_active_tab_status(
+            tabs,
+            tab_id,
+            "Exporting the full result to CSV...".to_string(),
+        );
+        spawn_tab_export(tab_id, path.clone(), async move {
+            let result = services::export_query_result_csv(
+                connection,
+                source,
+                current_tab.filter,
+                current_tab.sort,
+                path.clone(),
+                options,
+                |rows| {
+                    set_active_tab_status(
+                        tabs,
+                        tab_id,
+                        format!("Exporting to CSV... {rows} row(s) so far"),
+                    );
+                },
+            )
+            .await;
+            report_csv_export(tabs, tab_id, &path, result);
+        });
+    });
+}
+
+fn report_csv_export(
+    tabs: Signal<Vec<QueryTabState>>,
+    tab_id: u64,
+    path: &Path,
+    result: Result<usize, String>,
+) {
+    match result {
+        Ok(rows) => set_active_tab_status(
+            tabs,
+            tab_id,
+            format!("Exported {rows} row(s) to {}", path.display()),
+        ),
+        Err(err) => set_active_tab_status(tabs, tab_id, format!("CSV export error: {err}")),
+    }
+}
+
+/// Runs an export that streams rows into `path` as its own task, so the
+/// results toolbar can cancel it while it writes.
+fn spawn_tab_export(tab_id: u64, path: PathBuf, export: impl Future<Output = ()> + 'static) {
+    let task = spawn(async move {
+        export.await;
+        APP_RUNNING_TAB_EXPORTS.write().remove(&tab_id);
+    });
+    APP_RUNNING_TAB_EXPORTS
+        .write()
+        .insert(tab_id, RunningTabExport { task, path });
+}
+
+/// Stops the export still writing for `tab_id` and removes its partly
+/// written file.
+pub fn cancel_tab_export(tabs: Signal<Vec<QueryTabState>>, tab_id: u64) {
+    let Some(export) = APP_RUNNING_TAB_EXPORTS.write().remove(&tab_id) else {
+        return;
+    };
+    export.task.cancel();
+    let status = match std::fs::remove_file(&export.path) {
+        Ok(()) => "Export cancelled".to_string(),
+        Err(err) => format!(
+            "Export cancelled; could not remove {}: {err}",
+            export.path.display()
+        ),
+    };
+    set_active_tab_status(tabs, tab_id, status);
+}
+
+fn run_csv_copy_export(
+    tabs: Signal<Vec<QueryTabState>>,
+    tab_id: u64,
+    file_name: String,
+    connection: DatabaseConnection,
+    source: TablePreviewSource,
+    options: CsvExportOptions,
+) {
+    set_active_tab_status(
+        tabs,
+        tab_id,
+        "Select a destination for the COPY export".to_string(),
+    );
+
+    spawn(async move {
+        let Some(file) = AsyncFileDialog::new()
+            .set_file_name(&file_name)
+            .add_filter("CSV", &["csv"])
+            .save_file()
+            .await
+        else {
+            set_active_tab_status(tabs, tab_id, "Export cancelled".to_string());
+            return;
+        };
+
+        let path = file.path().to_path_buf();
+        let table_name = source.table_name.clone();
+        set_active_tab_status(tabs, tab_id, format!("Copying {table_name} to CSV..."));
+        spawn_tab_export(tab_id, path.clone(), async move {
+            let result =
+                services::copy_table_to_csv(connection, source, path.clone(), options, |bytes| {
+                    set_active_tab_status(
+                        tabs,
+                        tab_id,
+                        format!(
+                            "Copying {table_name} to CSV... {} written",
+                            format_bytes(bytes)
+                        ),
+                    );
+                })
+                .await;
+
+            match result {
+                Ok(bytes) => set_active_tab_status(
+                    tabs,
+                    tab_id,
+                    format!(
+                        "Copied {table_name} ({}) to {}",
+                        format_bytes(bytes),
+                        path.display()
+                    ),
+                ),
+                Err(err) => set_active_tab_status(tabs, tab_id, format!("CSV export error: {err}")),
+            }
+        });
+    });
+}
+
+pub fn run_json_export_for_tab(
+    tabs: Signal<Vec<QueryTabState>>,
+    tab_id: u64,
+    file_stem: String,
+    format: JsonExportFormat,
+) {
+    let Some(current_tab) = tabs.read().iter().find(|tab| tab.id == tab_id).cloned() else {
+        return;
+    };
+    let Some(QueryOutput::Table(page)) = current_tab.result.clone() else {
+        set_active_tab_status(
+            tabs,
+            tab_id,
+            "Nothing to export in the current tab".to_string(),
+        );
+        return;
+    };
+    let source = current_tab.preview_source.clone().or_else(|| {
+        current_tab
+            .last_run_sql
+            .as_deref()
+            .and_then(services::preview_source_for_sql)
+    });
+    let connection = session_connection(current_tab.session_id);
+    let extension = match format {
+        JsonExportFormat::Lines => "ndjson",
+        JsonExportFormat::Array | JsonExportFormat::ArrayWithMetadata => "json",
+    };
+
+    set_active_tab_status(
+        tabs,
+        tab_id,
+        "Select a destination for the JSON export".to_string(),
+    );
+
+    spawn(async move {
+        let Some(file) = AsyncFileDialog::new()
+            .set_file_name(format!("{file_stem}.{extension}"))
+            .add_filter("JSON", &[extension])
+            .save_file()
+            .await
+        else {
+            set_active_tab_status(tabs, tab_id, "Export cancelled".to_string());
+            return;
+        };
+
+        let mut options = JsonExportOptions {
+            format,
+            ..JsonExportOptions::default()
+        };
+        if let Some(source) = source {
+            // Without the declared types every value exports as a string.
+            options.column_types = export_column_types(connection, &source).await;
+            options.schema = source.schema;
+            options.table_name = Some(source.table_name);
+        }
+
+        let path = file.path().to_path_buf();
+        set_active_tab_status(
+            tabs,
+            tab_id,
+            format!("Exporting {} rows to JSON...", page.rows.len()),
+        );
+        match services::export_query_page_json(page, path.clone(), options).await {
+            Ok(rows) => set_active_tab_status(
+                tabs,
+                tab_id,
+                format!("Exported {rows} row(s) to {}", path.display()),
+            ),
+            Err(err) => set_active_tab_status(tabs, tab_id, format!("JSON export error: {err}")),
+        }
+    });
+}
+
+/// Writes the loaded rows of a tab as an INSERT script into the table the
+/// rows came from, `batch_size` rows per statement.
+pub fn run_sql_export_for_tab(
+    tabs: Signal<Vec<QueryTabState>>,
+    tab_id: u64,
+    file_name: String,
+    batch_size: usize,
+) {
+    let Some(current_tab) = tabs.read().iter().find(|tab| tab.id == tab_id).cloned() else {
+        return;
+    };
+    let Some(QueryOutput::Table(page)) = current_tab.result.clone() else {
+        set_active_tab_status(
+            tabs,
+            tab_id,
+            "Nothing to export in the current tab".to_string(),
+        );
+        return;
+    };
+    let source = current_tab.preview_source.clone().or_else(|| {
+        current_tab
+            .last_run_sql
+            .as_deref()
+            .and_then(services::preview_source_for_sql)
+    });
+    let connection = session_connection(current_tab.session_id);
+
+    set_active_tab_status(
+        tabs,
+        tab_id,
+        "Select a destination for the SQL export".to_string(),
+    );
+
+    spawn(async move {
+        let Some(file) = AsyncFileDialog::new()
+            .set_file_name(&file_name)
+            .add_filter("SQL", &["sql"])
+            .save_file()
+            .await
+        else {
+            set_active_tab_status(tabs, tab_id, "Export cancelled".to_string());
+            return;
+        };
+
+        let mut options = SqlInsertExportOptions {
+            batch_size,
+            ..SqlInsertExportOptions::default()
+        };
+        if let Some(source) = source {
+            // Without the declared types every value is written quoted.
+            options.column_types = export_column_types(connection, &source).await;
+            options.schema = source.schema;
+            options.table_name = source.table_name;
+        }
+
+        let path = file.path().to_path_buf();
+        set_active_tab_status(
+            tabs,
+            tab_id,
+            format!("Exporting {} rows to SQL...", page.rows.len()),
+        );
+        match services::export_query_page_sql_dump(page, path.clone(), options).await {
+            Ok(rows) => set_active_tab_status(
+                tabs,
+                tab_id,
+                format!("Exported {rows} row(s) to {}", path.display()),
+            ),
+            Err(err) => set_active_tab_status(tabs, tab_id, format!("SQL export error: {err}")),
+        }
+    });
+}
+
+/// Declared column types of an export's source table, by column name; empty
+/// when the session is gone or the lookup fails.
+async fn export_column_types(
+    connection: Option<DatabaseConnection>,
+    source: &TablePreviewSource,
+) -> HashMap<String, String> {
+    let Some(connection) = connection else {
+        return HashMap::new();
+    };
+    services::load_table_column_info(connection, source.schema.clone(), source.table_name.clone())
+        .await
+        .map(|columns| {
+            columns
+                .into_iter()
+                .map(|column| (column.name, column.data_type))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
 pub fn mark_table_deleted(
     mut tabs: Signal<Vec<QueryTabState>>,
     session_id: u64,
     source: TablePreviewSource,
 ) {
+    invalidate_row_counts(session_id, &source.qualified_name);
     tabs.with_mut(|all_tabs| {
         for tab in all_tabs
             .iter_mut()
@@ -921,6 +2092,7 @@ pub fn mark_table_truncated(
     connection: DatabaseConnection,
     source: TablePreviewSource,
 ) {
+    invalidate_row_counts(session_id, &source.qualified_name);
     let mut preview_tabs = Vec::new();
 
     tabs.with_mut(|all_tabs| {
@@ -1093,13 +2265,17 @@ pub fn clear_active_tab_filter(mut tabs: Signal<Vec<QueryTabState>>, active_tab_
 #[cfg(test)]
 mod tests {
     use super::{
-        append_query_page, format_loaded_rows_from_source_status, format_loaded_rows_status,
-        redact_sql, rows_toolbar_summary, sync_tab_sql_draft, toggle_cached_execution_plan,
+        TransactionControl, append_query_page, begin_tab_load, claim_row_count,
+        format_loaded_rows_from_source_status, format_loaded_rows_status, format_row_count,
+        is_current_tab_load, query_error_status, redact_sql, rows_toolbar_summary,
+        sql_with_text_at_end, sync_tab_sql_draft, timed_out_secs, toggle_cached_execution_plan,
+        transaction_control_statement,
     };
     use models::{
-        EditableTableContext, ExecutionPlan, PendingTableChanges, QueryPage, QueryTabState,
-        TablePreviewSource, WorkspaceTabKind,
+        DatabaseError, EditableTableContext, ExecutionPlan, PendingTableChanges, QueryPage,
+        QueryTabState, TablePreviewSource, TableRowCount, WorkspaceTabKind,
     };
+    use std::time::Duration;
 
     fn query_tab(sql: &str) -> QueryTabState {
         QueryTabState {
@@ -1116,6 +2292,7 @@ mod tests {
             filter: None,
             sort: None,
             tab_kind: WorkspaceTabKind::Query,
+            structure_source: None,
             is_loading_more: false,
             pending_table_changes: PendingTableChanges::default(),
             execution_plan: None,
@@ -1153,6 +2330,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn later_tab_loads_make_earlier_ones_stale() {
+        let first = begin_tab_load(9_001);
+        assert!(is_current_tab_load(9_001, first));
+
+        let second = begin_tab_load(9_001);
+        let other_tab = begin_tab_load(9_002);
+        assert!(!is_current_tab_load(9_001, first));
+        assert!(is_current_tab_load(9_001, second));
+        assert!(is_current_tab_load(9_002, other_tab));
+    }
+
     #[test]
     fn formats_empty_result_status_without_invalid_range() {
         assert_eq!(format_loaded_rows_status(0, 0), "Loaded 0 rows");
@@ -1211,6 +2400,16 @@ mod tests {
         assert_eq!(tab.status, "Loaded 1 rows");
     }
 
+    #[test]
+    fn inserting_text_at_end_separates_it_from_the_previous_token() {
+        assert_eq!(sql_with_text_at_end("", "\"id\""), "\"id\"");
+        assert_eq!(
+            sql_with_text_at_end("select", "\"users\".\"id\""),
+            "select \"users\".\"id\""
+        );
+        assert_eq!(sql_with_text_at_end("select\n", "\"id\""), "select\n\"id\"");
+    }
+
     #[test]
     fn append_query_page_caps_rows_and_keeps_edit_locators_aligned() {
         let mut existing = query_page(0, 100, true);
@@ -1252,4 +2451,90 @@ mod tests {
         assert_eq!(redacted, "alter user app with password = [REDACTED]");
         assert!(!redacted.contains("abc123"));
     }
+
+    #[test]
+    fn recognises_transaction_control_statements() {
+        assert_eq!(
+            transaction_control_statement("  BEGIN;\n"),
+            Some(TransactionControl::Begin)
+        );
+        assert_eq!(
+            transaction_control_statement("start   transaction"),
+            Some(TransactionControl::Begin)
+        );
+        assert_eq!(
+            transaction_control_statement("commit work;"),
+            Some(TransactionControl::Commit)
+        );
+        assert_eq!(
+            transaction_control_statement("Rollback"),
+            Some(TransactionControl::Rollback)
+        );
+        assert_eq!(transaction_control_statement("begin; select 1;"), None);
+        assert_eq!(
+            transaction_control_statement("rollback to savepoint a"),
+            None
+        );
+    }
+
+    #[test]
+    fn formats_exact_and_estimated_row_counts() {
+        assert_eq!(
+            format_row_count(TableRowCount {
+                rows: 12_400_000,
+                estimated: true,
+            }),
+            "~12,400,000 rows (estimated)"
+        );
+        assert_eq!(
+            format_row_count(TableRowCount {
+                rows: 1,
+                estimated: false,
+            }),
+            "1 row"
+        );
+        assert_eq!(
+            format_row_count(TableRowCount {
+                rows: 999,
+                estimated: false,
+            }),
+            "999 rows"
+        );
+    }
+
+    #[test]
+    fn only_a_cancel_after_the_armed_timeout_reports_a_timeout() {
+        let after = Duration::from_secs(31);
+        let before = Duration::from_secs(5);
+
+        assert_eq!(timed_out_secs(true, Some(30), after), Some(30));
+        // A cancel request stops the query before the timeout could fire.
+        assert_eq!(timed_out_secs(true, Some(30), before), None);
+        assert_eq!(timed_out_secs(true, None, after), None);
+        assert_eq!(timed_out_secs(false, Some(30), after), None);
+
+        let err = DatabaseError::UnsupportedDriver("boom".to_string());
+        assert_eq!(
+            query_error_status(&err, Some(30), after),
+            format!("Error: {err}")
+        );
+    }
+
+    #[test]
+    fn row_count_is_claimed_once_until_its_guard_drops() {
+        let key = "test:row-count-claim".to_string();
+        let first = claim_row_count(key.clone()).expect("first count should run");
+        assert!(claim_row_count(key.clone()).is_none());
+
+        drop(first);
+        let second = claim_row_count(key.clone()).expect("key should be free again");
+
+        // A count task dropped before it ever runs still frees the key.
+        let task = async move {
+            let _in_flight = second;
+        };
+        assert!(claim_row_count(key.clone()).is_none());
+        drop(task);
+        assert!(claim_row_count(key).is_some());
+    }
 }
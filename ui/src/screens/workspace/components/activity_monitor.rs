@@ -0,0 +1,523 @@
+use crate::app_state::session_connection;
+use crate::screens::workspace::actions::{read_only_mode_block_status, read_only_mode_enabled};
+use dioxus::prelude::*;
+use models::{ServerActivity, ServerLock};
+use rfd::{AsyncMessageDialog, MessageButtons, MessageDialogResult, MessageLevel};
+use std::collections::BTreeSet;
+use std::time::Duration;
+
+const ACTIVITY_REFRESH_INTERVAL: Duration = Duration::from_secs(5);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum MonitorView {
+    Sessions,
+    Locks,
+}
+
+/// Live view of `pg_stat_activity` and `pg_locks` for one session, refreshed
+/// every few seconds while auto-refresh is on.
+#[component]
+pub(crate) fn ActivityMonitor(
+    session_id: u64,
+    connection_name: String,
+    mut show_activity: Signal<bool>,
+) -> Element {
+    let mut view = use_signal(|| MonitorView::Sessions);
+    let mut auto_refresh = use_signal(|| true);
+    let mut refresh_tick = use_signal(|| 0_u64);
+    let status = use_signal(String::new);
+
+    rsx! {
+        div {
+            class: "settings-modal__backdrop",
+            onclick: move |_| show_activity.set(false),
+            div {
+                class: "settings-modal table-modal table-modal--wide",
+                onclick: move |event| event.stop_propagation(),
+                div {
+                    class: "settings-modal__header",
+                    div {
+                        class: "settings-modal__header-copy",
+                        h2 { class: "settings-modal__title", "Activity Monitor" }
+                        p {
+                            class: "settings-modal__hint",
+                            "Other backends on {connection_name}, from pg_stat_activity and pg_locks."
+                        }
+                    }
+                    button {
+                        class: "button button--ghost button--small",
+                        onclick: move |_| show_activity.set(false),
+                        "Close"
+                    }
+                }
+
+                div {
+                    class: "table-modal__body",
+                    div {
+                        class: "table-modal__toolbar",
+                        div {
+                            class: "table-modal__segmented",
+                            for (option, label) in [(MonitorView::Sessions, "Sessions"), (MonitorView::Locks, "Locks")] {
+                                button {
+                                    class: if view() == option {
+                                        "button button--small button--active"
+                                    } else {
+                                        "button button--ghost button--small"
+                                    },
+                                    onclick: move |_| view.set(option),
+                                    "{label}"
+                                }
+                            }
+                        }
+                        label {
+                            class: "settings-modal__toggle",
+                            input {
+                                r#type: "checkbox",
+                                checked: auto_refresh(),
+                                oninput: move |event| auto_refresh.set(event.checked()),
+                            }
+                            span { "Refresh every 5 seconds" }
+                        }
+                        button {
+                            class: "button button--ghost button--small",
+                            onclick: move |_| refresh_tick += 1,
+                            "Refresh"
+                        }
+                    }
+
+                    if !status().is_empty() {
+                        p { class: "table-modal__hint", "{status}" }
+                    }
+
+                    match view() {
+                        MonitorView::Sessions => rsx! {
+                            SessionsTable { session_id, auto_refresh, refresh_tick, status }
+                        },
+                        MonitorView::Locks => rsx! {
+                            LocksTable { session_id, auto_refresh, refresh_tick, status }
+                        },
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn SessionsTable(
+    session_id: u64,
+    auto_refresh: Signal<bool>,
+    refresh_tick: Signal<u64>,
+    mut status: Signal<String>,
+) -> Element {
+    let mut activity = use_signal(|| None::<Result<Vec<ServerActivity>, String>>);
+
+    use_future(move || async move {
+        loop {
+            if *auto_refresh.peek() || activity.peek().is_none() {
+                activity.set(Some(load_activity(session_id).await));
+            }
+            tokio::time::sleep(ACTIVITY_REFRESH_INTERVAL).await;
+        }
+    });
+    use_effect(move || {
+        if refresh_tick() > 0 {
+            spawn(async move {
+                activity.set(Some(load_activity(session_id).await));
+            });
+        }
+    });
+
+    let read_only_mode = read_only_mode_enabled();
+
+    rsx! {
+        match activity() {
+            None => rsx! {
+                p { class: "table-modal__hint", "Loading activity…" }
+            },
+            Some(Err(err)) => rsx! {
+                p { class: "table-modal__error", "{err}" }
+            },
+            Some(Ok(rows)) if rows.is_empty() => rsx! {
+                p { class: "table-modal__hint", "No other backends are connected." }
+            },
+            Some(Ok(rows)) => rsx! {
+                div {
+                    class: "table-modal__table-wrap",
+                    table {
+                        class: "table-modal__table",
+                        thead {
+                            tr {
+                                th { "PID" }
+                                th { "User" }
+                                th { "Database" }
+                                th { "Application" }
+                                th { "State" }
+                                th { "Running" }
+                                th { "Wait" }
+                                th { "Query" }
+                                th {}
+                            }
+                        }
+                        tbody {
+                            for row in rows {
+                                tr {
+                                    key: "{row.pid}",
+                                    td { class: "table-modal__cell--numeric", "{row.pid}" }
+                                    td { "{row.user}" }
+                                    td { "{row.database}" }
+                                    td { "{row.application}" }
+                                    td { "{row.state}" }
+                                    td {
+                                        class: "table-modal__cell--numeric",
+                                        title: row.query_start.clone().unwrap_or_default(),
+                                        "{format_query_duration(row.query_seconds)}"
+                                    }
+                                    td { "{format_wait_event(&row)}" }
+                                    td {
+                                        class: "table-modal__cell--code",
+                                        title: "{row.query}",
+                                        "{row.query}"
+                                    }
+                                    td {
+                                        class: "table-modal__cell--actions",
+                                        button {
+                                            class: "button button--ghost button--small",
+                                            // Idle backends have nothing to cancel.
+                                            disabled: read_only_mode || row.state != "active",
+                                            title: if read_only_mode {
+                                                "Cancelling queries is blocked by read-only mode"
+                                            } else {
+                                                "Cancel the running query and keep the connection"
+                                            },
+                                            onclick: {
+                                                let pid = row.pid;
+                                                move |_| {
+                                                    spawn(async move {
+                                                        let message = cancel(session_id, pid).await;
+                                                        status.set(message);
+                                                        activity.set(Some(load_activity(session_id).await));
+                                                    });
+                                                }
+                                            },
+                                            "Cancel"
+                                        }
+                                        button {
+                                            class: "button button--ghost button--small",
+                                            disabled: read_only_mode,
+                                            title: if read_only_mode {
+                                                "Terminating backends is blocked by read-only mode"
+                                            } else {
+                                                "Terminate this backend"
+                                            },
+                                            onclick: {
+                                                let pid = row.pid;
+                                                move |_| {
+                                                    spawn(async move {
+                                                        let message = terminate(session_id, vec![pid]).await;
+                                                        status.set(message);
+                                                        activity.set(Some(load_activity(session_id).await));
+                                                    });
+                                                }
+                                            },
+                                            "Terminate"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+        }
+    }
+}
+
+/// Locks grouped by who waits and who blocks: waiting rows are tinted red,
+/// rows held by a backend that others wait on are tinted yellow.
+#[component]
+fn LocksTable(
+    session_id: u64,
+    auto_refresh: Signal<bool>,
+    refresh_tick: Signal<u64>,
+    mut status: Signal<String>,
+) -> Element {
+    let mut locks = use_signal(|| None::<Result<Vec<ServerLock>, String>>);
+
+    use_future(move || async move {
+        loop {
+            if *auto_refresh.peek() || locks.peek().is_none() {
+                locks.set(Some(load_locks(session_id).await));
+            }
+            tokio::time::sleep(ACTIVITY_REFRESH_INTERVAL).await;
+        }
+    });
+    use_effect(move || {
+        if refresh_tick() > 0 {
+            spawn(async move {
+                locks.set(Some(load_locks(session_id).await));
+            });
+        }
+    });
+
+    let read_only_mode = read_only_mode_enabled();
+
+    rsx! {
+        match locks() {
+            None => rsx! {
+                p { class: "table-modal__hint", "Loading locks…" }
+            },
+            Some(Err(err)) => rsx! {
+                p { class: "table-modal__error", "{err}" }
+            },
+            Some(Ok(rows)) if rows.is_empty() => rsx! {
+                p { class: "table-modal__hint", "No other backends hold locks." }
+            },
+            Some(Ok(rows)) => {
+                let blockers = blocking_pids(&rows);
+                let waiting_count = rows.iter().filter(|row| !row.granted).count();
+                rsx! {
+                    p {
+                        class: "table-modal__hint",
+                        "{rows.len()} locks, {waiting_count} waiting, {blockers.len()} blocking backends."
+                    }
+                    div {
+                        class: "table-modal__table-wrap",
+                        table {
+                            class: "table-modal__table",
+                            thead {
+                                tr {
+                                    th { "PID" }
+                                    th { "User" }
+                                    th { "Lock" }
+                                    th { "Mode" }
+                                    th { "Relation" }
+                                    th { "Granted" }
+                                    th { "Blocked by" }
+                                    th { "Running" }
+                                    th { "Query" }
+                                    th {}
+                                }
+                            }
+                            tbody {
+                                for row in rows {
+                                    tr {
+                                        class: if !row.granted {
+                                            "table-modal__row--danger"
+                                        } else if blockers.contains(&row.pid) {
+                                            "table-modal__row--warning"
+                                        } else {
+                                            ""
+                                        },
+                                        td { class: "table-modal__cell--numeric", "{row.pid}" }
+                                        td { "{row.user}" }
+                                        td { "{row.lock_type}" }
+                                        td { "{row.mode}" }
+                                        td { "{row.relation}" }
+                                        td { if row.granted { "yes" } else { "waiting" } }
+                                        td { "{format_pids(&row.blocked_by)}" }
+                                        td {
+                                            class: "table-modal__cell--numeric",
+                                            "{format_query_duration(row.query_seconds)}"
+                                        }
+                                        td {
+                                            class: "table-modal__cell--code",
+                                            title: "{row.query}",
+                                            "{row.query}"
+                                        }
+                                        td {
+                                            if !row.blocked_by.is_empty() {
+                                                button {
+                                                    class: "button button--ghost button--small",
+                                                    disabled: read_only_mode,
+                                                    title: if read_only_mode {
+                                                        "Terminating backends is blocked by read-only mode"
+                                                    } else {
+                                                        "Terminate the backends this one is waiting on"
+                                                    },
+                                                    onclick: {
+                                                        let blocked_by = row.blocked_by.clone();
+                                                        move |_| {
+                                                            let blocked_by = blocked_by.clone();
+                                                            spawn(async move {
+                                                                let message = terminate(session_id, blocked_by).await;
+                                                                status.set(message);
+                                                                locks.set(Some(load_locks(session_id).await));
+                                                            });
+                                                        }
+                                                    },
+                                                    "Kill Blocker"
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+async fn load_activity(session_id: u64) -> Result<Vec<ServerActivity>, String> {
+    let connection = session_connection(session_id)
+        .ok_or_else(|| "Connection is no longer open.".to_string())?;
+    services::load_server_activity(connection)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+async fn load_locks(session_id: u64) -> Result<Vec<ServerLock>, String> {
+    let connection = session_connection(session_id)
+        .ok_or_else(|| "Connection is no longer open.".to_string())?;
+    services::load_server_locks(connection)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+async fn cancel(session_id: u64, pid: i32) -> String {
+    if read_only_mode_enabled() {
+        return read_only_mode_block_status("cancelling queries");
+    }
+
+    let confirmation = AsyncMessageDialog::new()
+        .set_title("Cancel Query")
+        .set_description(format!(
+            "Cancel the query running on backend {pid}? The backend stays connected; an open transaction is left aborted."
+        ))
+        .set_buttons(MessageButtons::YesNo)
+        .set_level(MessageLevel::Warning)
+        .show()
+        .await;
+    if confirmation != MessageDialogResult::Yes {
+        return String::new();
+    }
+
+    let Some(connection) = session_connection(session_id) else {
+        return "Connection is no longer open.".to_string();
+    };
+    match services::cancel_backend(connection, pid).await {
+        Ok(true) => format!("Sent cancel to backend {pid}."),
+        Ok(false) => format!("Backend {pid} was not signalled; it may have already exited."),
+        Err(err) => format!("Cancel error for {pid}: {err}"),
+    }
+}
+
+async fn terminate(session_id: u64, pids: Vec<i32>) -> String {
+    if read_only_mode_enabled() {
+        return read_only_mode_block_status("terminating backends");
+    }
+
+    let pid_list = format_pids(&pids);
+    let confirmation = AsyncMessageDialog::new()
+        .set_title("Terminate Backend")
+        .set_description(format!(
+            "Terminate backend {pid_list}? Its current transaction is rolled back and the client is disconnected."
+        ))
+        .set_buttons(MessageButtons::YesNo)
+        .set_level(MessageLevel::Warning)
+        .show()
+        .await;
+    if confirmation != MessageDialogResult::Yes {
+        return String::new();
+    }
+
+    let Some(connection) = session_connection(session_id) else {
+        return "Connection is no longer open.".to_string();
+    };
+    let mut messages = Vec::with_capacity(pids.len());
+    for pid in pids {
+        messages.push(
+            match services::terminate_backend(connection.clone(), pid).await {
+                Ok(true) => format!("Terminated backend {pid}."),
+                Ok(false) => {
+                    format!("Backend {pid} was not terminated; it may have already exited.")
+                }
+                Err(err) => format!("Terminate error for {pid}: {err}"),
+            },
+        );
+    }
+    messages.join(" ")
+}
+
+/// Backends that at least one other backend is waiting on.
+fn blocking_pids(locks: &[ServerLock]) -> BTreeSet<i32> {
+    locks
+        .iter()
+        .flat_map(|lock| lock.blocked_by.iter().copied())
+        .collect()
+}
+
+fn format_pids(pids: &[i32]) -> String {
+    pids.iter()
+        .map(i32::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn format_query_duration(seconds: Option<f64>) -> String {
+    let Some(seconds) = seconds.filter(|seconds| *seconds >= 0.0) else {
+        return String::new();
+    };
+    let total = seconds as u64;
+    match total {
+        0 => format!("{:.0} ms", seconds * 1000.0),
+        1..60 => format!("{seconds:.1} s"),
+        60..3600 => format!("{}m {}s", total / 60, total % 60),
+        _ => format!("{}h {}m", total / 3600, (total % 3600) / 60),
+    }
+}
+
+fn format_wait_event(row: &ServerActivity) -> String {
+    match (row.wait_event_type.is_empty(), row.wait_event.is_empty()) {
+        (true, true) => String::new(),
+        (false, false) => format!("{}: {}", row.wait_event_type, row.wait_event),
+        (false, true) => row.wait_event_type.clone(),
+        (true, false) => row.wait_event.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{blocking_pids, format_query_duration};
+    use models::ServerLock;
+
+    #[test]
+    fn query_duration_scales_units() {
+        assert_eq!(format_query_duration(None), "");
+        assert_eq!(format_query_duration(Some(0.25)), "250 ms");
+        assert_eq!(format_query_duration(Some(12.34)), "12.3 s");
+        assert_eq!(format_query_duration(Some(125.0)), "2m 5s");
+        assert_eq!(format_query_duration(Some(7260.0)), "2h 1m");
+    }
+
+    fn make_lock(pid: i32, granted: bool, blocked_by: Vec<i32>) -> ServerLock {
+        ServerLock {
+            pid,
+            user: "app".to_string(),
+            lock_type: "relation".to_string(),
+            mode: "AccessExclusiveLock".to_string(),
+            granted,
+            relation: "public.orders".to_string(),
+            blocked_by,
+            query: String::new(),
+            query_seconds: None,
+        }
+    }
+
+    #[test]
+    fn blocking_pids_collects_every_waited_on_backend() {
+        let locks = vec![
+            make_lock(10, true, vec![]),
+            make_lock(11, false, vec![10]),
+            make_lock(12, false, vec![10, 11]),
+        ];
+
+        assert_eq!(
+            blocking_pids(&locks).into_iter().collect::<Vec<_>>(),
+            vec![10, 11]
+        );
+    }
+}
@@ -5,12 +5,7 @@ use pulldown_cmark::{Event, Options, Parser, html};
 use models::{AcpMessageKind, AcpPanelState, AcpUiMessage, ChatArtifact};
 
 use super::prompt::extract_sql_candidate;
-
-thread_local! {
-    // Keep clipboard ownership alive for Linux/X11/Wayland instead of dropping it right after copy.
-    static PERSISTENT_CLIPBOARD: std::cell::RefCell<Option<arboard::Clipboard>> =
-        const { std::cell::RefCell::new(None) };
-}
+use crate::clipboard::copy_text;
 
 pub(super) const AGENT_MESSAGE_BATCH: usize = 32;
 
@@ -27,85 +22,16 @@ pub(super) fn copy_text_to_clipboard(
         return;
     }
 
-    let result = write_text_to_clipboard(&text);
-
-    match result {
-        Ok(()) => {
-            panel_state.with_mut(|state| {
-                state.status = format!("Copied {label} to clipboard.");
-            });
-        }
-        Err(native_err) => {
-            let Some(script) = clipboard_copy_script(&text) else {
-                panel_state.with_mut(|state| {
-                    state.status = format!("Clipboard error: {native_err}");
-                });
-                return;
+    let label = label.to_string();
+    spawn(async move {
+        let result = copy_text(&text).await;
+        panel_state.with_mut(|state| {
+            state.status = match result {
+                Ok(()) => format!("Copied {label} to clipboard."),
+                Err(err) => err,
             };
-
-            let label = label.to_string();
-            spawn(async move {
-                let result = document::eval(&script).join::<bool>().await;
-                panel_state.with_mut(|state| {
-                    state.status = match result {
-                        Ok(true) => format!("Copied {label} to clipboard."),
-                        Ok(false) => format!("Clipboard error: {native_err}"),
-                        Err(err) => format_clipboard_fallback_error(&native_err, err),
-                    };
-                });
-            });
-        }
-    }
-}
-
-fn write_text_to_clipboard(text: &str) -> Result<(), String> {
-    PERSISTENT_CLIPBOARD.with(|clipboard| {
-        let mut clipboard = clipboard.borrow_mut();
-        if clipboard.is_none() {
-            *clipboard = Some(arboard::Clipboard::new().map_err(|err| err.to_string())?);
-        }
-
-        let clipboard = clipboard
-            .as_mut()
-            .ok_or_else(|| "Clipboard is unavailable.".to_string())?;
-
-        clipboard
-            .set_text(text.to_string())
-            .map_err(|err| err.to_string())
-    })
-}
-
-fn clipboard_copy_script(text: &str) -> Option<String> {
-    let value = serde_json::to_string(text).ok()?;
-    Some(format!(
-        r#"
-        (() => {{
-            const value = {value};
-            const copyWithExecCommand = () => {{
-                const textarea = document.createElement("textarea");
-                textarea.value = value;
-                textarea.setAttribute("readonly", "");
-                textarea.style.position = "fixed";
-                textarea.style.opacity = "0";
-                textarea.style.pointerEvents = "none";
-                document.body.appendChild(textarea);
-                textarea.focus();
-                textarea.select();
-                const copied = document.execCommand("copy");
-                textarea.remove();
-                return copied;
-            }};
-
-            if (navigator.clipboard && window.isSecureContext) {{
-                return navigator.clipboard.writeText(value)
-                    .then(() => true)
-                    .catch(() => copyWithExecCommand());
-            }}
-
-            return copyWithExecCommand();
-        }})()
-        "#
-    ))
+        });
+    });
 }
 
 #[derive(Clone, PartialEq, Eq)]
@@ -314,13 +240,6 @@ pub(super) fn artifact_title(artifact: &ChatArtifact) -> &'static str {
     }
 }
 
-pub fn format_clipboard_fallback_error(
-    native_err: &str,
-    fallback_err: impl std::fmt::Display,
-) -> String {
-    format!("Clipboard error: {native_err}; fallback failed: {fallback_err}")
-}
-
 pub fn acp_registry_loading_text() -> &'static str {
     "Loading agents..."
 }
@@ -348,9 +267,9 @@ pub fn is_verbose_acp_registry_preparing_text(text: &str) -> bool {
 mod tests {
     use super::{
         acp_registry_connecting_text, acp_registry_loading_text, artifact_title, build_thread_meta,
-        compact_connection_label, compact_header_title, format_clipboard_fallback_error,
-        is_verbose_acp_registry_loading_text, is_verbose_acp_registry_preparing_text,
-        is_visible_message, render_message_markdown_html, should_render_message_text,
+        compact_connection_label, compact_header_title, is_verbose_acp_registry_loading_text,
+        is_verbose_acp_registry_preparing_text, is_visible_message, render_message_markdown_html,
+        should_render_message_text,
     };
     use models::{AcpMessageKind, AcpOllamaConfig, AcpPanelState, AcpUiMessage, ChatArtifact};
 
@@ -531,16 +450,6 @@ mod tests {
         assert!(html.contains("world"));
     }
 
-    #[test]
-    fn clipboard_fallback_uses_display_not_debug() {
-        let formatted = format_clipboard_fallback_error("native error", "eval failed");
-        assert_eq!(
-            formatted,
-            "Clipboard error: native error; fallback failed: eval failed"
-        );
-        assert!(!formatted.contains(":?"));
-    }
-
     #[test]
     fn registry_loading_text_is_compact() {
         let text = acp_registry_loading_text();
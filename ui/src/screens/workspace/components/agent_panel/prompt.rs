@@ -634,6 +634,7 @@ mod tests {
             filter: None,
             sort: None,
             tab_kind: WorkspaceTabKind::Query,
+            structure_source: None,
             is_loading_more: false,
             pending_table_changes: PendingTableChanges::default(),
             execution_plan: None,
@@ -672,6 +673,7 @@ mod tests {
                 filter: None,
                 sort: None,
                 tab_kind: WorkspaceTabKind::TablePreview,
+                structure_source: None,
                 is_loading_more: false,
                 pending_table_changes: PendingTableChanges::default(),
                 execution_plan: None,
@@ -691,6 +693,7 @@ mod tests {
                 filter: None,
                 sort: None,
                 tab_kind: WorkspaceTabKind::Query,
+                structure_source: None,
                 is_loading_more: false,
                 pending_table_changes: PendingTableChanges::default(),
                 execution_plan: None,
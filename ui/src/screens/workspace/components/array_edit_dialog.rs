@@ -0,0 +1,211 @@
+use dioxus::prelude::*;
+
+/// PostgreSQL array cell opened for element-by-element editing.
+#[derive(Clone, PartialEq)]
+pub(crate) struct ArrayEditTarget {
+    pub(crate) column_name: String,
+    pub(crate) data_type: String,
+    /// Current cell text, an array literal such as `{1,2,3}` or `NULL`.
+    pub(crate) value: String,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct ArrayElement {
+    value: String,
+    null: bool,
+}
+
+/// Whether the array editor can open `value`: a NULL cell or a
+/// one-dimensional array literal. Other values are edited as text.
+pub(crate) fn array_editor_supports(value: &str) -> bool {
+    array_elements(value).is_some()
+}
+
+#[component]
+pub(crate) fn ArrayEditDialog(
+    target: ArrayEditTarget,
+    on_save: EventHandler<String>,
+    on_cancel: EventHandler<()>,
+) -> Element {
+    let initial_value = target.value.clone();
+    let mut elements = use_signal(move || array_elements(&initial_value).unwrap_or_default());
+    let element_type = target.data_type.trim().trim_end_matches("[]").to_string();
+    let literal = array_literal(&elements.read());
+
+    rsx! {
+        div {
+            class: "settings-modal__backdrop",
+            onclick: move |_| on_cancel.call(()),
+            div {
+                class: "settings-modal table-modal table-modal--compact",
+                onclick: move |event| event.stop_propagation(),
+                div {
+                    class: "settings-modal__header",
+                    div {
+                        class: "settings-modal__header-copy",
+                        h2 { class: "settings-modal__title", "Edit {target.column_name}" }
+                        p {
+                            class: "settings-modal__hint",
+                            "{target.data_type} · one field per element"
+                        }
+                    }
+                    button {
+                        class: "button button--ghost button--small",
+                        onclick: move |_| on_cancel.call(()),
+                        "Close"
+                    }
+                }
+
+                div {
+                    class: "table-modal__body",
+                    div {
+                        class: "table-modal__columns",
+                        if elements.read().is_empty() {
+                            p { class: "table-modal__hint", "The array is empty." }
+                        }
+                        for (index, element) in elements.read().iter().cloned().enumerate() {
+                            div {
+                                key: "{index}",
+                                class: "table-modal__column-card",
+                                div {
+                                    class: "table-modal__column-header",
+                                    span { class: "table-modal__column-title", "[{index + 1}]" }
+                                    button {
+                                        class: "button button--ghost button--small",
+                                        onclick: move |_| {
+                                            elements.with_mut(|elements| {
+                                                if index < elements.len() {
+                                                    elements.remove(index);
+                                                }
+                                            });
+                                        },
+                                        "Remove"
+                                    }
+                                }
+                                input {
+                                    class: "input",
+                                    value: "{element.value}",
+                                    placeholder: if element.null { "NULL".to_string() } else { element_type.clone() },
+                                    disabled: element.null,
+                                    oninput: move |event| {
+                                        let value = event.value();
+                                        elements.with_mut(|elements| {
+                                            if let Some(element) = elements.get_mut(index) {
+                                                element.value = value;
+                                            }
+                                        });
+                                    },
+                                }
+                                label {
+                                    class: "settings-modal__toggle",
+                                    input {
+                                        r#type: "checkbox",
+                                        checked: element.null,
+                                        oninput: move |event| {
+                                            let null = event.checked();
+                                            elements.with_mut(|elements| {
+                                                if let Some(element) = elements.get_mut(index) {
+                                                    element.null = null;
+                                                }
+                                            });
+                                        },
+                                    }
+                                    span { "NULL" }
+                                }
+                            }
+                        }
+                    }
+
+                    div {
+                        class: "table-modal__toolbar",
+                        button {
+                            class: "button button--ghost button--small",
+                            onclick: move |_| elements.write().push(ArrayElement::default()),
+                            "Add Element"
+                        }
+                    }
+
+                    div {
+                        class: "table-modal__preview",
+                        span { class: "field__label", "Value" }
+                        pre { class: "table-modal__preview-sql", "{literal}" }
+                    }
+
+                    div {
+                        class: "table-modal__actions",
+                        button {
+                            class: "button button--ghost",
+                            onclick: move |_| on_cancel.call(()),
+                            "Cancel"
+                        }
+                        button {
+                            class: "button button--primary",
+                            onclick: move |_| on_save.call(array_literal(&elements.read())),
+                            "Save"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn array_elements(value: &str) -> Option<Vec<ArrayElement>> {
+    if value == "NULL" {
+        return Some(Vec::new());
+    }
+    let elements = services::parse_postgres_array(value)?;
+    Some(
+        elements
+            .into_iter()
+            .map(|element| ArrayElement {
+                null: element.is_none(),
+                value: element.unwrap_or_default(),
+            })
+            .collect(),
+    )
+}
+
+fn array_literal(elements: &[ArrayElement]) -> String {
+    let elements = elements
+        .iter()
+        .map(|element| (!element.null).then(|| element.value.clone()))
+        .collect::<Vec<_>>();
+    services::format_postgres_array(&elements)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ArrayElement, array_editor_supports, array_elements, array_literal};
+
+    #[test]
+    fn array_elements_round_trip_through_the_literal() {
+        let elements = array_elements(r#"{1,NULL,"a b"}"#).expect("one-dimensional array");
+
+        assert_eq!(
+            elements,
+            vec![
+                ArrayElement {
+                    value: "1".to_string(),
+                    null: false,
+                },
+                ArrayElement {
+                    value: String::new(),
+                    null: true,
+                },
+                ArrayElement {
+                    value: "a b".to_string(),
+                    null: false,
+                },
+            ]
+        );
+        assert_eq!(array_literal(&elements), r#"{1,NULL,"a b"}"#);
+    }
+
+    #[test]
+    fn array_editor_opens_null_cells_but_not_nested_arrays() {
+        assert!(array_editor_supports("NULL"));
+        assert!(array_editor_supports("{}"));
+        assert!(!array_editor_supports("{{1,2},{3,4}}"));
+    }
+}
@@ -329,8 +329,7 @@ fn base64_encode(data: &[u8]) -> String {
     result
 }
 
-#[allow(dead_code)]
-fn format_bytes(size: u64) -> String {
+pub(crate) fn format_bytes(size: u64) -> String {
     const KB: u64 = 1024;
     const MB: u64 = KB * 1024;
     const GB: u64 = MB * 1024;
@@ -0,0 +1,183 @@
+use super::format_bytes;
+use crate::app_state::session_connection;
+use dioxus::prelude::*;
+use models::TablePreviewSource;
+
+/// PostgreSQL `bytea` cell opened in the read-only hex viewer. The grid only
+/// shows the start of the value, so the viewer reads it back whole.
+#[derive(Clone, PartialEq)]
+pub(crate) struct ByteaViewTarget {
+    pub(crate) column_name: String,
+    /// The row's `ctid`.
+    pub(crate) locator: String,
+}
+
+/// Bytes shown in the hex dump; exporting writes the whole value.
+const HEX_DUMP_MAX_BYTES: usize = 64 * 1024;
+const HEX_DUMP_LINE_BYTES: usize = 16;
+
+#[component]
+pub(crate) fn ByteaViewDialog(
+    target: ByteaViewTarget,
+    session_id: u64,
+    source: TablePreviewSource,
+    on_close: EventHandler<()>,
+) -> Element {
+    let mut export_status = use_signal(String::new);
+    let bytes = use_resource({
+        let target = target.clone();
+        move || {
+            let source = source.clone();
+            let target = target.clone();
+            async move {
+                let connection = session_connection(session_id)
+                    .ok_or_else(|| "Connection is no longer open.".to_string())?;
+                services::load_bytea_cell(connection, source, target.locator, target.column_name)
+                    .await
+                    .map_err(|err| err.to_string())
+            }
+        }
+    });
+    let loaded = match &*bytes.read() {
+        Some(Ok(bytes)) => Some(bytes.clone()),
+        _ => None,
+    };
+    let hint = match loaded.as_ref() {
+        Some(bytes) if bytes.len() > HEX_DUMP_MAX_BYTES => format!(
+            "bytea · {} · showing the first {}",
+            format_bytes(bytes.len() as u64),
+            format_bytes(HEX_DUMP_MAX_BYTES as u64),
+        ),
+        Some(bytes) => format!("bytea · {}", format_bytes(bytes.len() as u64)),
+        None => "bytea".to_string(),
+    };
+
+    rsx! {
+        div {
+            class: "settings-modal__backdrop",
+            onclick: move |_| on_close.call(()),
+            div {
+                class: "settings-modal table-modal",
+                onclick: move |event| event.stop_propagation(),
+                div {
+                    class: "settings-modal__header",
+                    div {
+                        class: "settings-modal__header-copy",
+                        h2 { class: "settings-modal__title", "{target.column_name}" }
+                        p { class: "settings-modal__hint", "{hint}" }
+                    }
+                    button {
+                        class: "button button--ghost button--small",
+                        onclick: move |_| on_close.call(()),
+                        "Close"
+                    }
+                }
+
+                div {
+                    class: "table-modal__body",
+                    match bytes() {
+                        None => rsx! {
+                            p { class: "table-modal__hint", "Loading value…" }
+                        },
+                        Some(Err(err)) => rsx! {
+                            p { class: "table-modal__error", "{err}" }
+                        },
+                        Some(Ok(bytes)) => rsx! {
+                            pre {
+                                class: "table-modal__preview-sql bytea-view__dump",
+                                "{hex_dump(&bytes[..bytes.len().min(HEX_DUMP_MAX_BYTES)])}"
+                            }
+                        },
+                    }
+
+                    if !export_status().is_empty() {
+                        p { class: "table-modal__hint", "{export_status}" }
+                    }
+
+                    div {
+                        class: "table-modal__actions",
+                        button {
+                            class: "button button--primary",
+                            disabled: loaded.is_none(),
+                            onclick: {
+                                let column_name = target.column_name.clone();
+                                move |_| {
+                                    let Some(bytes) = loaded.clone() else {
+                                        return;
+                                    };
+                                    let column_name = column_name.clone();
+                                    spawn(async move {
+                                        let Some(file) = rfd::AsyncFileDialog::new()
+                                            .set_file_name(format!("{column_name}.bin"))
+                                            .save_file()
+                                            .await
+                                        else {
+                                            return;
+                                        };
+                                        match std::fs::write(file.path(), &bytes) {
+                                            Ok(()) => export_status.set(format!(
+                                                "Exported {} to {}",
+                                                format_bytes(bytes.len() as u64),
+                                                file.path().display()
+                                            )),
+                                            Err(err) => export_status.set(format!("Export failed: {err}")),
+                                        }
+                                    });
+                                }
+                            },
+                            "Export to file"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Classic hex dump: the offset, sixteen bytes in hex and the same bytes as
+/// ASCII, with `.` for anything unprintable.
+fn hex_dump(bytes: &[u8]) -> String {
+    let mut dump = String::new();
+    for (line, chunk) in bytes.chunks(HEX_DUMP_LINE_BYTES).enumerate() {
+        let hex = chunk
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let ascii = chunk
+            .iter()
+            .map(|byte| {
+                if byte.is_ascii_graphic() || *byte == b' ' {
+                    *byte as char
+                } else {
+                    '.'
+                }
+            })
+            .collect::<String>();
+        if line > 0 {
+            dump.push('\n');
+        }
+        dump.push_str(&format!(
+            "{:08x}  {hex:<width$}  {ascii}",
+            line * HEX_DUMP_LINE_BYTES,
+            width = HEX_DUMP_LINE_BYTES * 3 - 1
+        ));
+    }
+    dump
+}
+
+#[cfg(test)]
+mod tests {
+    use super::hex_dump;
+
+    #[test]
+    fn hex_dump_lines_up_offsets_hex_and_ascii() {
+        let bytes = b"Hello, bytea!\x00\x01\x02\xffA";
+        assert_eq!(
+            hex_dump(bytes),
+            "00000000  48 65 6c 6c 6f 2c 20 62 79 74 65 61 21 00 01 02  Hello, bytea!...\n\
+             00000010  ff 41                                            .A"
+        );
+        assert_eq!(hex_dump(&[]), "");
+    }
+}
@@ -0,0 +1,332 @@
+use std::collections::HashMap;
+
+use dioxus::prelude::*;
+
+/// Colour a new rule starts with.
+const DEFAULT_RULE_COLOR: &str = "#e5484d";
+
+/// What a cell has to hold for its rule to colour it.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum FormatCondition {
+    /// Case-insensitive substring match.
+    Contains(String),
+    Equals(String),
+    /// Cells that parse as a number larger than this one.
+    GreaterThan(f64),
+    IsNull,
+}
+
+impl FormatCondition {
+    fn matches(&self, value: &str) -> bool {
+        match self {
+            Self::Contains(needle) => value.to_lowercase().contains(&needle.to_lowercase()),
+            Self::Equals(expected) => value == expected,
+            Self::GreaterThan(limit) => value
+                .trim()
+                .parse::<f64>()
+                .is_ok_and(|number| number > *limit),
+            Self::IsNull => value == "NULL",
+        }
+    }
+
+    fn label(&self) -> String {
+        match self {
+            Self::Contains(needle) => format!("contains “{needle}”"),
+            Self::Equals(expected) => format!("equals “{expected}”"),
+            Self::GreaterThan(limit) => format!("is greater than {limit}"),
+            Self::IsNull => "is NULL".to_string(),
+        }
+    }
+}
+
+/// Colours the cells of `column_name` that meet `condition`.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct FormattingRule {
+    pub(crate) column_name: String,
+    pub(crate) condition: FormatCondition,
+    /// `#rrggbb`, as the colour picker reports it.
+    pub(crate) color: String,
+}
+
+/// Colour of the first rule on `column_name` that `value` meets.
+pub(crate) fn cell_highlight<'a>(
+    rules: &'a [FormattingRule],
+    column_name: &str,
+    value: &str,
+) -> Option<&'a str> {
+    rules
+        .iter()
+        .find(|rule| rule.column_name == column_name && rule.condition.matches(value))
+        .map(|rule| rule.color.as_str())
+}
+
+/// Editor for the formatting rules of the tab in `target`.
+#[component]
+pub(crate) fn FormattingRulesDialog(
+    columns: Vec<String>,
+    mut rules: Signal<HashMap<u64, Vec<FormattingRule>>>,
+    mut target: Signal<Option<u64>>,
+) -> Element {
+    let mut column_name = use_signal(String::new);
+    let mut condition_kind = use_signal(|| "is_null".to_string());
+    let mut condition_value = use_signal(String::new);
+    let mut color = use_signal(|| DEFAULT_RULE_COLOR.to_string());
+    let mut error = use_signal(String::new);
+
+    let Some(tab_id) = target() else {
+        return rsx! {};
+    };
+    let tab_rules = rules.read().get(&tab_id).cloned().unwrap_or_default();
+    let selected_column = if columns.contains(&column_name()) {
+        column_name()
+    } else {
+        columns.first().cloned().unwrap_or_default()
+    };
+    let needs_value = condition_kind() != "is_null";
+
+    rsx! {
+        div {
+            class: "settings-modal__backdrop",
+            onclick: move |_| target.set(None),
+            div {
+                class: "settings-modal table-modal table-modal--compact",
+                onclick: move |event| event.stop_propagation(),
+                div {
+                    class: "settings-modal__header",
+                    div {
+                        class: "settings-modal__header-copy",
+                        h2 { class: "settings-modal__title", "Formatting Rules" }
+                        p {
+                            class: "settings-modal__hint",
+                            "Colour the cells of a column that meet a condition. The first matching rule wins."
+                        }
+                    }
+                    button {
+                        class: "button button--ghost button--small",
+                        onclick: move |_| target.set(None),
+                        "Close"
+                    }
+                }
+
+                div {
+                    class: "table-modal__body",
+                    if tab_rules.is_empty() {
+                        p { class: "table-modal__hint", "No rules yet." }
+                    } else {
+                        div {
+                            class: "table-modal__table-wrap",
+                            table {
+                                class: "table-modal__table",
+                                thead {
+                                    tr {
+                                        th { "Column" }
+                                        th { "Condition" }
+                                        th { "Colour" }
+                                        th {}
+                                    }
+                                }
+                                tbody {
+                                    for (index, rule) in tab_rules.iter().enumerate() {
+                                        tr {
+                                            key: "{index}",
+                                            td { "{rule.column_name}" }
+                                            td { "{rule.condition.label()}" }
+                                            td {
+                                                span {
+                                                    class: "formatting-rules__swatch",
+                                                    style: "background: {rule.color};",
+                                                }
+                                            }
+                                            td {
+                                                button {
+                                                    class: "button button--ghost button--small",
+                                                    onclick: move |_| {
+                                                        rules.with_mut(|rules| {
+                                                            if let Some(tab_rules) = rules.get_mut(&tab_id)
+                                                                && index < tab_rules.len()
+                                                            {
+                                                                tab_rules.remove(index);
+                                                            }
+                                                        });
+                                                    },
+                                                    "Remove"
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    div {
+                        class: "table-modal__grid",
+                        div {
+                            class: "field",
+                            span { class: "field__label", "Column" }
+                            select {
+                                class: "input",
+                                value: selected_column.clone(),
+                                onchange: move |event| column_name.set(event.value()),
+                                for column in columns.iter() {
+                                    option {
+                                        value: "{column}",
+                                        selected: *column == selected_column,
+                                        "{column}"
+                                    }
+                                }
+                            }
+                        }
+                        div {
+                            class: "field",
+                            span { class: "field__label", "Condition" }
+                            select {
+                                class: "input",
+                                value: condition_kind(),
+                                onchange: move |event| condition_kind.set(event.value()),
+                                for (value, label) in FORMAT_CONDITIONS {
+                                    option {
+                                        value,
+                                        selected: condition_kind() == value,
+                                        "{label}"
+                                    }
+                                }
+                            }
+                        }
+                        if needs_value {
+                            div {
+                                class: "field",
+                                span { class: "field__label", "Value" }
+                                input {
+                                    class: "input",
+                                    value: condition_value(),
+                                    oninput: move |event| condition_value.set(event.value()),
+                                }
+                            }
+                        }
+                        div {
+                            class: "field",
+                            span { class: "field__label", "Colour" }
+                            input {
+                                class: "input formatting-rules__color",
+                                r#type: "color",
+                                value: color(),
+                                oninput: move |event| color.set(event.value()),
+                            }
+                        }
+                    }
+
+                    if !error().is_empty() {
+                        p { class: "table-modal__error", "{error}" }
+                    }
+
+                    div {
+                        class: "table-modal__actions",
+                        button {
+                            class: "button button--primary",
+                            disabled: selected_column.is_empty(),
+                            onclick: move |_| {
+                                let condition =
+                                    match parse_format_condition(&condition_kind(), &condition_value()) {
+                                        Ok(condition) => condition,
+                                        Err(message) => {
+                                            error.set(message);
+                                            return;
+                                        }
+                                    };
+                                error.set(String::new());
+                                let rule = FormattingRule {
+                                    column_name: selected_column.clone(),
+                                    condition,
+                                    color: color(),
+                                };
+                                rules.with_mut(|rules| rules.entry(tab_id).or_default().push(rule));
+                            },
+                            "Add Rule"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+const FORMAT_CONDITIONS: [(&str, &str); 4] = [
+    ("is_null", "Is NULL"),
+    ("contains", "Contains"),
+    ("equals", "Equals"),
+    ("greater_than", "Greater than"),
+];
+
+fn parse_format_condition(kind: &str, value: &str) -> Result<FormatCondition, String> {
+    match kind {
+        "is_null" => Ok(FormatCondition::IsNull),
+        "contains" if value.is_empty() => Err("Enter the text to look for.".to_string()),
+        "contains" => Ok(FormatCondition::Contains(value.to_string())),
+        "equals" => Ok(FormatCondition::Equals(value.to_string())),
+        "greater_than" => value
+            .trim()
+            .parse::<f64>()
+            .map(FormatCondition::GreaterThan)
+            .map_err(|_| "Greater than needs a number.".to_string()),
+        _ => Err(format!("Unknown condition {kind}.")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FormatCondition, FormattingRule, cell_highlight, parse_format_condition};
+
+    fn rule(column_name: &str, condition: FormatCondition, color: &str) -> FormattingRule {
+        FormattingRule {
+            column_name: column_name.to_string(),
+            condition,
+            color: color.to_string(),
+        }
+    }
+
+    #[test]
+    fn first_matching_rule_on_the_column_wins() {
+        let rules = vec![
+            rule("balance", FormatCondition::IsNull, "#ff0000"),
+            rule("balance", FormatCondition::GreaterThan(100.0), "#00ff00"),
+            rule(
+                "note",
+                FormatCondition::Contains("urgent".to_string()),
+                "#ffa500",
+            ),
+            rule(
+                "note",
+                FormatCondition::Equals("Urgent".to_string()),
+                "#0000ff",
+            ),
+        ];
+
+        assert_eq!(cell_highlight(&rules, "balance", "NULL"), Some("#ff0000"));
+        assert_eq!(cell_highlight(&rules, "balance", " 250.5"), Some("#00ff00"));
+        assert_eq!(cell_highlight(&rules, "balance", "99"), None);
+        assert_eq!(cell_highlight(&rules, "balance", "n/a"), None);
+        assert_eq!(cell_highlight(&rules, "note", "Urgent"), Some("#ffa500"));
+        assert_eq!(cell_highlight(&rules, "other", "NULL"), None);
+    }
+
+    #[test]
+    fn conditions_need_a_usable_value() {
+        assert_eq!(
+            parse_format_condition("greater_than", "-0.5"),
+            Ok(FormatCondition::GreaterThan(-0.5))
+        );
+        assert_eq!(
+            parse_format_condition("greater_than", "ten"),
+            Err("Greater than needs a number.".to_string())
+        );
+        assert_eq!(
+            parse_format_condition("contains", ""),
+            Err("Enter the text to look for.".to_string())
+        );
+        assert_eq!(
+            parse_format_condition("equals", ""),
+            Ok(FormatCondition::Equals(String::new()))
+        );
+    }
+}
@@ -0,0 +1,215 @@
+use crate::app_state::session_connection;
+use crate::screens::workspace::components::{copy_grid_text, tsv_block};
+use dioxus::prelude::*;
+use models::{ColumnStats, QueryTabState, TablePreviewSource};
+use std::collections::HashMap;
+
+/// Header context menu / stats popup state for a single result column.
+#[derive(Clone, PartialEq)]
+pub(crate) struct ColumnStatsAnchor {
+    pub column: String,
+    /// The column's loaded values, for copying.
+    pub values: Vec<String>,
+    pub x: f64,
+    pub y: f64,
+    pub state: ColumnStatsState,
+}
+
+#[derive(Clone, PartialEq)]
+pub(crate) enum ColumnStatsState {
+    Menu,
+    Loading,
+    Loaded(ColumnStats),
+    Failed(String),
+}
+
+/// Resolve the table a column statistics query should target for a tab.
+///
+/// Table previews carry their source directly; query tabs fall back to the
+/// single-table source of the last executed SELECT, if any.
+fn column_stats_source(tab: &QueryTabState) -> Option<TablePreviewSource> {
+    tab.preview_source.clone().or_else(|| {
+        tab.last_run_sql
+            .as_deref()
+            .and_then(services::preview_source_for_sql)
+    })
+}
+
+#[component]
+pub fn ColumnStatsPopup(
+    mut anchor: Signal<Option<ColumnStatsAnchor>>,
+    tabs: Signal<Vec<QueryTabState>>,
+    active_tab_id: Signal<u64>,
+    mut pinned_columns: Signal<HashMap<u64, Vec<String>>>,
+) -> Element {
+    let Some(current) = anchor() else {
+        return rsx! {};
+    };
+    let is_pinned = pinned_columns
+        .read()
+        .get(&active_tab_id())
+        .is_some_and(|pinned| pinned.contains(&current.column));
+    let style = format!("left: {}px; top: {}px;", current.x, current.y);
+
+    rsx! {
+        div {
+            class: "column-stats__backdrop",
+            onclick: move |_| anchor.set(None),
+            oncontextmenu: move |event: MouseEvent| {
+                event.prevent_default();
+                anchor.set(None);
+            },
+        }
+        match current.state.clone() {
+            ColumnStatsState::Menu => rsx! {
+                div {
+                    class: "column-stats column-stats--menu",
+                    style: "{style}",
+                    button {
+                        class: "column-stats__menu-item",
+                        onclick: {
+                            let column = current.column.clone();
+                            move |_| load_column_stats_for_tab(anchor, tabs, active_tab_id(), column.clone())
+                        },
+                        "Column Statistics"
+                    }
+                    button {
+                        class: "column-stats__menu-item",
+                        onclick: {
+                            let values = current.values.clone();
+                            move |_| {
+                                anchor.set(None);
+                                let rows = values.iter().map(|value| vec![value.clone()]).collect::<Vec<_>>();
+                                copy_grid_text(
+                                    tabs,
+                                    active_tab_id(),
+                                    tsv_block(None, &rows),
+                                    format!("{} value(s)", rows.len()),
+                                );
+                            }
+                        },
+                        "Copy Column"
+                    }
+                    button {
+                        class: "column-stats__menu-item",
+                        onclick: {
+                            let column = current.column.clone();
+                            move |_| {
+                                anchor.set(None);
+                                pinned_columns.with_mut(|pinned| {
+                                    let pinned = pinned.entry(active_tab_id()).or_default();
+                                    match pinned.iter().position(|name| *name == column) {
+                                        Some(index) => {
+                                            pinned.remove(index);
+                                        }
+                                        None => pinned.push(column.clone()),
+                                    }
+                                });
+                            }
+                        },
+                        if is_pinned { "Unpin Column" } else { "Pin Column" }
+                    }
+                }
+            },
+            state => rsx! {
+                div {
+                    class: "column-stats",
+                    style: "{style}",
+                    div {
+                        class: "column-stats__header",
+                        span { class: "column-stats__title", "{current.column}" }
+                        button {
+                            class: "button button--ghost button--small",
+                            onclick: move |_| anchor.set(None),
+                            "Close"
+                        }
+                    }
+                    match state {
+                        ColumnStatsState::Loading | ColumnStatsState::Menu => rsx! {
+                            p { class: "column-stats__hint", "Profiling column..." }
+                        },
+                        ColumnStatsState::Failed(message) => rsx! {
+                            p { class: "column-stats__error", "{message}" }
+                        },
+                        ColumnStatsState::Loaded(stats) => rsx! {
+                            dl {
+                                class: "column-stats__list",
+                                for (label, value) in column_stats_rows(&stats) {
+                                    dt { class: "column-stats__label", "{label}" }
+                                    dd { class: "column-stats__value", title: "{value}", "{value}" }
+                                }
+                            }
+                        },
+                    }
+                }
+            },
+        }
+    }
+}
+
+fn load_column_stats_for_tab(
+    mut anchor: Signal<Option<ColumnStatsAnchor>>,
+    tabs: Signal<Vec<QueryTabState>>,
+    tab_id: u64,
+    column: String,
+) {
+    let tab = tabs.read().iter().find(|tab| tab.id == tab_id).cloned();
+    let Some(tab) = tab else {
+        anchor.set(None);
+        return;
+    };
+
+    let Some(source) = column_stats_source(&tab) else {
+        set_column_stats_state(
+            anchor,
+            ColumnStatsState::Failed(
+                "Column statistics need a single-table result (table preview or simple SELECT)."
+                    .to_string(),
+            ),
+        );
+        return;
+    };
+    let Some(connection) = session_connection(tab.session_id) else {
+        set_column_stats_state(
+            anchor,
+            ColumnStatsState::Failed("Connection is no longer open.".to_string()),
+        );
+        return;
+    };
+
+    set_column_stats_state(anchor, ColumnStatsState::Loading);
+    spawn(async move {
+        let state = match services::load_column_stats(connection, source, column.clone()).await {
+            Ok(stats) => ColumnStatsState::Loaded(stats),
+            Err(err) => ColumnStatsState::Failed(err.to_string()),
+        };
+        // Ignore late responses once the popup was closed or moved to another column.
+        if anchor
+            .read()
+            .as_ref()
+            .is_some_and(|current| current.column == column)
+        {
+            set_column_stats_state(anchor, state);
+        }
+    });
+}
+
+fn set_column_stats_state(mut anchor: Signal<Option<ColumnStatsAnchor>>, state: ColumnStatsState) {
+    anchor.with_mut(|current| {
+        if let Some(current) = current.as_mut() {
+            current.state = state;
+        }
+    });
+}
+
+fn column_stats_rows(stats: &ColumnStats) -> Vec<(&'static str, String)> {
+    let optional = |value: &Option<String>| value.clone().unwrap_or_else(|| "—".to_string());
+    vec![
+        ("Rows", stats.total_count.to_string()),
+        ("Distinct", stats.distinct_count.to_string()),
+        ("Nulls", stats.null_count.to_string()),
+        ("Min", optional(&stats.min)),
+        ("Max", optional(&stats.max)),
+        ("Avg", optional(&stats.avg)),
+    ]
+}
@@ -0,0 +1,195 @@
+use super::csv_import_dialog::{CSV_DELIMITERS, delimiter_value, parse_delimiter_value};
+use crate::screens::workspace::actions::{CsvExportScope, run_csv_export_for_tab};
+use dioxus::prelude::*;
+use models::{CsvExportOptions, QueryTabState};
+
+/// Tab whose result a pending CSV export writes out.
+#[derive(Clone, PartialEq)]
+pub(crate) struct CsvExportTarget {
+    pub(crate) tab_id: u64,
+    pub(crate) file_name: String,
+    pub(crate) loaded_rows: usize,
+    /// The grid holds only part of the result, so the whole of it can be
+    /// re-queried instead.
+    pub(crate) partial: bool,
+    /// The tab previews a PostgreSQL table, which COPY can stream whole.
+    pub(crate) copy_available: bool,
+}
+
+#[component]
+pub(crate) fn CsvExportDialog(
+    tabs: Signal<Vec<QueryTabState>>,
+    mut target: Signal<Option<CsvExportTarget>>,
+) -> Element {
+    let mut delimiter = use_signal(|| ',');
+    let mut crlf = use_signal(|| false);
+    let mut quote_all = use_signal(|| false);
+    let mut scope = use_signal(|| CsvExportScope::EntireResult);
+    let Some(current_target) = target() else {
+        return rsx! {};
+    };
+    let selected_scope = match scope() {
+        CsvExportScope::EntireResult if !current_target.partial => CsvExportScope::LoadedRows,
+        CsvExportScope::PostgresCopy if !current_target.copy_available => {
+            if current_target.partial {
+                CsvExportScope::EntireResult
+            } else {
+                CsvExportScope::LoadedRows
+            }
+        }
+        other => other,
+    };
+    let uses_copy = selected_scope == CsvExportScope::PostgresCopy;
+
+    rsx! {
+        div {
+            class: "settings-modal__backdrop",
+            onclick: move |_| target.set(None),
+            div {
+                class: "settings-modal table-modal table-modal--compact",
+                onclick: move |event| event.stop_propagation(),
+                div {
+                    class: "settings-modal__header",
+                    div {
+                        class: "settings-modal__header-copy",
+                        h2 { class: "settings-modal__title", "Export CSV" }
+                        p {
+                            class: "settings-modal__hint",
+                            "Write the current result to a CSV file."
+                        }
+                    }
+                    button {
+                        class: "button button--ghost button--small",
+                        onclick: move |_| target.set(None),
+                        "Close"
+                    }
+                }
+
+                div {
+                    class: "table-modal__body",
+                    if current_target.partial || current_target.copy_available {
+                        div {
+                            class: "field",
+                            span { class: "field__label", "Rows" }
+                            if current_target.partial {
+                                label {
+                                    class: "settings-modal__toggle",
+                                    input {
+                                        r#type: "radio",
+                                        name: "csv-export-scope",
+                                        checked: selected_scope == CsvExportScope::EntireResult,
+                                        oninput: move |_| scope.set(CsvExportScope::EntireResult),
+                                    }
+                                    span { "Entire result (re-run page by page)" }
+                                }
+                            }
+                            label {
+                                class: "settings-modal__toggle",
+                                input {
+                                    r#type: "radio",
+                                    name: "csv-export-scope",
+                                    checked: selected_scope == CsvExportScope::LoadedRows,
+                                    oninput: move |_| scope.set(CsvExportScope::LoadedRows),
+                                }
+                                if current_target.partial {
+                                    span { "Loaded rows only ({current_target.loaded_rows})" }
+                                } else {
+                                    span { "Loaded rows ({current_target.loaded_rows})" }
+                                }
+                            }
+                            if current_target.copy_available {
+                                label {
+                                    class: "settings-modal__toggle",
+                                    input {
+                                        r#type: "radio",
+                                        name: "csv-export-scope",
+                                        checked: uses_copy,
+                                        oninput: move |_| scope.set(CsvExportScope::PostgresCopy),
+                                    }
+                                    span { "Fast export of the whole table (PostgreSQL COPY)" }
+                                }
+                                if uses_copy {
+                                    p {
+                                        class: "table-modal__hint",
+                                        "COPY streams every row of the table; the grid's filter and sort do not apply."
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    div {
+                        class: "field",
+                        span { class: "field__label", "Delimiter" }
+                        select {
+                            class: "input",
+                            value: delimiter_value(delimiter()),
+                            onchange: move |event| {
+                                if let Some(value) = parse_delimiter_value(&event.value()) {
+                                    delimiter.set(value);
+                                }
+                            },
+                            for (value, label) in CSV_DELIMITERS {
+                                option {
+                                    value: delimiter_value(value),
+                                    selected: delimiter() == value,
+                                    "{label}"
+                                }
+                            }
+                        }
+                    }
+
+                    label {
+                        class: "settings-modal__toggle",
+                        input {
+                            r#type: "checkbox",
+                            checked: quote_all(),
+                            oninput: move |event| quote_all.set(event.checked()),
+                        }
+                        span { "Quote every field" }
+                    }
+                    label {
+                        class: "settings-modal__toggle",
+                        input {
+                            r#type: "checkbox",
+                            checked: crlf() && !uses_copy,
+                            disabled: uses_copy,
+                            oninput: move |event| crlf.set(event.checked()),
+                        }
+                        span { "Windows line endings (CRLF)" }
+                    }
+
+                    div {
+                        class: "table-modal__actions",
+                        button {
+                            class: "button button--ghost",
+                            onclick: move |_| target.set(None),
+                            "Cancel"
+                        }
+                        button {
+                            class: "button button--primary",
+                            onclick: move |_| {
+                                let Some(current_target) = target() else {
+                                    return;
+                                };
+                                target.set(None);
+                                run_csv_export_for_tab(
+                                    tabs,
+                                    current_target.tab_id,
+                                    current_target.file_name,
+                                    selected_scope,
+                                    CsvExportOptions {
+                                        delimiter: delimiter(),
+                                        crlf: crlf(),
+                                        quote_all: quote_all(),
+                                    },
+                                );
+                            },
+                            "Save As…"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,459 @@
+use crate::app_state::session_connection;
+use crate::screens::workspace::actions::{
+    read_only_mode_enabled, run_csv_import_for_tab, tab_uses_postgres,
+};
+use dioxus::prelude::*;
+use models::{CsvFilePreview, CsvImportOptions, QueryTabState, TablePreviewSource};
+use rfd::AsyncFileDialog;
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+pub(super) const CSV_DELIMITERS: [(char, &str); 4] = [
+    (',', "Comma (,)"),
+    (';', "Semicolon (;)"),
+    ('\t', "Tab"),
+    ('|', "Pipe (|)"),
+];
+
+const CSV_QUOTES: [(char, &str); 2] = [('"', "Double quote (\")"), ('\'', "Single quote (')")];
+
+const PREVIEW_ROWS: usize = 50;
+
+/// Table and tab that a pending CSV import reports into.
+#[derive(Clone, PartialEq)]
+pub(crate) struct CsvImportTarget {
+    pub(crate) tab_id: u64,
+    pub(crate) source: TablePreviewSource,
+}
+
+#[component]
+pub(crate) fn CsvImportDialog(
+    tabs: Signal<Vec<QueryTabState>>,
+    mut target: Signal<Option<CsvImportTarget>>,
+) -> Element {
+    let mut has_header = use_signal(|| true);
+    let mut delimiter = use_signal(|| ',');
+    let mut quote = use_signal(|| '"');
+    let mut use_copy = use_signal(|| false);
+    let mut file_path = use_signal(|| None::<PathBuf>);
+    let mut preview = use_signal(|| None::<Result<CsvFilePreview, String>>);
+    let mut table_columns = use_signal(|| Ok::<Vec<String>, String>(Vec::new()));
+    let mut mapping = use_signal(Vec::<Option<String>>::new);
+
+    use_effect(move || {
+        let Some(current_target) = target() else {
+            return;
+        };
+        file_path.set(None);
+        preview.set(None);
+        table_columns.set(Ok(Vec::new()));
+        let connection = tabs
+            .peek()
+            .iter()
+            .find(|tab| tab.id == current_target.tab_id)
+            .and_then(|tab| session_connection(tab.session_id));
+        spawn(async move {
+            let result = match connection {
+                Some(connection) => services::load_table_columns(
+                    connection,
+                    current_target.source.schema,
+                    current_target.source.table_name,
+                )
+                .await
+                .map_err(|err| err.to_string()),
+                None => Err("Connection is no longer open.".to_string()),
+            };
+            table_columns.set(result);
+        });
+    });
+
+    use_effect(move || {
+        let Some(path) = file_path() else {
+            return;
+        };
+        let options = CsvImportOptions {
+            has_header: has_header(),
+            delimiter: delimiter(),
+            quote: quote(),
+            ..CsvImportOptions::default()
+        };
+        spawn(async move {
+            let result = services::preview_csv_file(path, options, PREVIEW_ROWS).await;
+            preview.set(Some(result));
+        });
+    });
+
+    // Re-matched whenever the file, its settings or the table columns change.
+    use_effect(move || {
+        let headers = match &*preview.read() {
+            Some(Ok(preview)) => preview.headers.clone(),
+            _ => Vec::new(),
+        };
+        let columns = table_columns.read().clone().unwrap_or_default();
+        mapping.set(default_column_mapping(
+            &headers,
+            *has_header.peek(),
+            &columns,
+        ));
+    });
+
+    let Some(current_target) = target() else {
+        return rsx! {};
+    };
+    let read_only_mode = read_only_mode_enabled();
+    let copy_available = tab_uses_postgres(tabs, current_target.tab_id);
+    let copy_selected = copy_available && use_copy();
+    let columns = table_columns.read().clone();
+    let file_label = file_path()
+        .map(|path| {
+            path.file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_else(|| path.display().to_string())
+        })
+        .unwrap_or_else(|| "No file chosen".to_string());
+    let preview_state = preview();
+    let mapping_error = match &preview_state {
+        Some(Ok(_)) => mapping_problem(&mapping.read(), copy_selected),
+        _ => None,
+    };
+    let can_import = !read_only_mode
+        && matches!(preview_state, Some(Ok(_)))
+        && columns.is_ok()
+        && mapping_error.is_none();
+
+    rsx! {
+        div {
+            class: "settings-modal__backdrop",
+            onclick: move |_| target.set(None),
+            div {
+                class: "settings-modal table-modal",
+                onclick: move |event| event.stop_propagation(),
+                div {
+                    class: "settings-modal__header",
+                    div {
+                        class: "settings-modal__header-copy",
+                        h2 { class: "settings-modal__title", "Import CSV" }
+                        p {
+                            class: "settings-modal__hint",
+                            "Append rows from a CSV file to {current_target.source.qualified_name}."
+                        }
+                    }
+                    button {
+                        class: "button button--ghost button--small",
+                        onclick: move |_| target.set(None),
+                        "Close"
+                    }
+                }
+
+                div {
+                    class: "table-modal__body",
+                    div {
+                        class: "table-modal__toolbar",
+                        span { class: "field__label", "{file_label}" }
+                        button {
+                            class: "button button--ghost button--small",
+                            onclick: move |_| {
+                                spawn(async move {
+                                    if let Some(file) = AsyncFileDialog::new()
+                                        .add_filter("CSV", &["csv", "tsv", "txt"])
+                                        .pick_file()
+                                        .await
+                                    {
+                                        file_path.set(Some(file.path().to_path_buf()));
+                                    }
+                                });
+                            },
+                            "Choose File…"
+                        }
+                    }
+
+                    div {
+                        class: "table-modal__grid",
+                        div {
+                            class: "field",
+                            span { class: "field__label", "Delimiter" }
+                            select {
+                                class: "input",
+                                value: delimiter_value(delimiter()),
+                                onchange: move |event| {
+                                    if let Some(value) = parse_delimiter_value(&event.value()) {
+                                        delimiter.set(value);
+                                    }
+                                },
+                                for (value, label) in CSV_DELIMITERS {
+                                    option {
+                                        value: delimiter_value(value),
+                                        selected: delimiter() == value,
+                                        "{label}"
+                                    }
+                                }
+                            }
+                        }
+                        div {
+                            class: "field",
+                            span { class: "field__label", "Quote" }
+                            select {
+                                class: "input",
+                                value: quote().to_string(),
+                                onchange: move |event| {
+                                    if let Some(value) = event.value().chars().next() {
+                                        quote.set(value);
+                                    }
+                                },
+                                for (value, label) in CSV_QUOTES {
+                                    option {
+                                        value: value.to_string(),
+                                        selected: quote() == value,
+                                        "{label}"
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    label {
+                        class: "settings-modal__toggle",
+                        input {
+                            r#type: "checkbox",
+                            checked: has_header(),
+                            oninput: move |event| has_header.set(event.checked()),
+                        }
+                        span { "First row contains column names" }
+                    }
+
+                    if let Err(error) = &columns {
+                        p { class: "table-modal__error", "Could not load table columns: {error}" }
+                    }
+
+                    match &preview_state {
+                        None => rsx! {
+                            p { class: "table-modal__hint", "Choose a file to preview its first rows." }
+                        },
+                        Some(Err(error)) => rsx! {
+                            p { class: "table-modal__error", "{error}" }
+                        },
+                        Some(Ok(file_preview)) => rsx! {
+                            div {
+                                class: "table-modal__columns",
+                                for (index, header) in file_preview.headers.iter().cloned().enumerate() {
+                                    div {
+                                        key: "{index}",
+                                        class: "table-modal__column-card",
+                                        div {
+                                            class: "table-modal__column-header",
+                                            span { class: "table-modal__column-title", "{header}" }
+                                            select {
+                                                class: "input",
+                                                value: mapping.read().get(index).cloned().flatten().unwrap_or_default(),
+                                                onchange: move |event| {
+                                                    let value = event.value();
+                                                    mapping.with_mut(|mapping| {
+                                                        if let Some(column) = mapping.get_mut(index) {
+                                                            *column = (!value.is_empty()).then_some(value);
+                                                        }
+                                                    });
+                                                },
+                                                option {
+                                                    value: "",
+                                                    selected: mapping.read().get(index).is_none_or(Option::is_none),
+                                                    "Skip"
+                                                }
+                                                for column in columns.clone().unwrap_or_default() {
+                                                    option {
+                                                        value: "{column}",
+                                                        selected: mapping.read().get(index).is_some_and(|mapped| {
+                                                            mapped.as_deref() == Some(column.as_str())
+                                                        }),
+                                                        "{column}"
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+
+                            p {
+                                class: "table-modal__hint",
+                                "First {file_preview.rows.len()} row(s) of the file."
+                            }
+                            div {
+                                class: "table-modal__table-wrap",
+                                table {
+                                    class: "table-modal__table",
+                                    thead {
+                                        tr {
+                                            for header in file_preview.headers.iter() {
+                                                th { "{header}" }
+                                            }
+                                        }
+                                    }
+                                    tbody {
+                                        for row in file_preview.rows.iter() {
+                                            tr {
+                                                for value in row.iter() {
+                                                    td { "{value}" }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        },
+                    }
+
+                    if copy_available {
+                        label {
+                            class: "settings-modal__toggle",
+                            input {
+                                r#type: "checkbox",
+                                checked: use_copy(),
+                                oninput: move |event| use_copy.set(event.checked()),
+                            }
+                            span { "Fast import with PostgreSQL COPY" }
+                        }
+                    }
+                    p {
+                        class: "table-modal__hint",
+                        if copy_selected {
+                            "Rows load in one server-side COPY; unquoted empty fields become NULL. A failed COPY loads nothing."
+                        } else {
+                            "Rows are inserted in one transaction, so a failed import loads nothing."
+                        }
+                    }
+
+                    if let Some(error) = mapping_error.as_ref() {
+                        p { class: "table-modal__error", "{error}" }
+                    }
+
+                    div {
+                        class: "table-modal__actions",
+                        button {
+                            class: "button button--ghost",
+                            onclick: move |_| target.set(None),
+                            "Cancel"
+                        }
+                        button {
+                            class: "button button--primary",
+                            disabled: !can_import,
+                            onclick: move |_| {
+                                let (Some(current_target), Some(path)) = (target(), file_path()) else {
+                                    return;
+                                };
+                                target.set(None);
+                                run_csv_import_for_tab(
+                                    tabs,
+                                    current_target.tab_id,
+                                    current_target.source,
+                                    path,
+                                    CsvImportOptions {
+                                        has_header: has_header(),
+                                        delimiter: delimiter(),
+                                        quote: quote(),
+                                        columns: Vec::new(),
+                                        column_mapping: mapping(),
+                                    },
+                                    copy_selected,
+                                );
+                            },
+                            "Import"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub(super) fn delimiter_value(delimiter: char) -> String {
+    match delimiter {
+        '\t' => "tab".to_string(),
+        other => other.to_string(),
+    }
+}
+
+pub(super) fn parse_delimiter_value(value: &str) -> Option<char> {
+    match value {
+        "tab" => Some('\t'),
+        other => {
+            let mut chars = other.chars();
+            let first = chars.next()?;
+            chars.next().is_none().then_some(first)
+        }
+    }
+}
+
+/// Table column for each file column: header names matched ignoring case, or
+/// the table's columns in order when the file has no header.
+fn default_column_mapping(
+    headers: &[String],
+    has_header: bool,
+    table_columns: &[String],
+) -> Vec<Option<String>> {
+    headers
+        .iter()
+        .enumerate()
+        .map(|(index, header)| {
+            if has_header {
+                table_columns
+                    .iter()
+                    .find(|column| column.eq_ignore_ascii_case(header))
+                    .cloned()
+            } else {
+                table_columns.get(index).cloned()
+            }
+        })
+        .collect()
+}
+
+/// Why the mapping cannot be imported yet, if anything.
+fn mapping_problem(mapping: &[Option<String>], use_copy: bool) -> Option<String> {
+    if mapping.iter().all(Option::is_none) {
+        return Some("Map at least one CSV column to a table column.".to_string());
+    }
+    if use_copy && mapping.iter().any(Option::is_none) {
+        return Some("COPY loads every CSV column; map each one or turn off COPY.".to_string());
+    }
+    let mut seen = HashSet::new();
+    mapping
+        .iter()
+        .flatten()
+        .find(|column| !seen.insert(column.as_str()))
+        .map(|column| format!("Column \"{column}\" is mapped more than once."))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{default_column_mapping, mapping_problem};
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn default_mapping_matches_headers_or_positions() {
+        let table = strings(&["id", "name", "email"]);
+
+        assert_eq!(
+            default_column_mapping(&strings(&["Name", "notes", "ID"]), true, &table),
+            vec![Some("name".to_string()), None, Some("id".to_string())]
+        );
+        assert_eq!(
+            default_column_mapping(&strings(&["column_1", "column_2"]), false, &table),
+            vec![Some("id".to_string()), Some("name".to_string())]
+        );
+    }
+
+    #[test]
+    fn mapping_problems_block_the_import() {
+        let id = Some("id".to_string());
+
+        assert!(mapping_problem(&[None, None], false).is_some());
+        assert_eq!(mapping_problem(&[id.clone(), None], false), None);
+        assert!(mapping_problem(&[id.clone(), None], true).is_some());
+        assert_eq!(
+            mapping_problem(&[id.clone(), id], false),
+            Some("Column \"id\" is mapped more than once.".to_string())
+        );
+    }
+}
@@ -0,0 +1,368 @@
+use std::collections::HashSet;
+
+use crate::app_state::{
+    decrement_row_counts, session_connection, session_transaction, set_session_transaction_status,
+};
+use crate::screens::workspace::actions::{
+    read_only_mode_block_status, read_only_mode_enabled, set_active_tab_status,
+};
+use crate::screens::workspace::components::{InsertRowTarget, copy_grid_text, tsv_block};
+use dioxus::prelude::*;
+use models::{QueryOutput, QueryTabState, TablePreviewSource};
+
+/// How many cells of each row the confirmation shows next to its key.
+const PREVIEW_CELLS: usize = 3;
+
+/// Rows picked for deletion and the tab they are shown in.
+#[derive(Clone, PartialEq)]
+pub(crate) struct DeleteRowsTarget {
+    pub(crate) tab_id: u64,
+    pub(crate) session_id: u64,
+    pub(crate) source: TablePreviewSource,
+    /// Column names the row values line up with.
+    pub(crate) columns: Vec<String>,
+    pub(crate) rows: Vec<DeleteRowPreview>,
+}
+
+#[derive(Clone, PartialEq)]
+pub(crate) struct DeleteRowPreview {
+    pub(crate) locator: String,
+    pub(crate) values: Vec<String>,
+}
+
+/// Right-click menu on result rows.
+#[derive(Clone, PartialEq)]
+pub(crate) struct RowMenuAnchor {
+    pub(crate) x: f64,
+    pub(crate) y: f64,
+    pub(crate) tab_id: u64,
+    /// Column names and values of the clicked rows, for copying.
+    pub(crate) columns: Vec<String>,
+    pub(crate) rows: Vec<Vec<String>>,
+    /// Set when the rows can be deleted or duplicated.
+    pub(crate) target: Option<DeleteRowsTarget>,
+}
+
+#[component]
+pub(crate) fn RowContextMenu(
+    tabs: Signal<Vec<QueryTabState>>,
+    mut anchor: Signal<Option<RowMenuAnchor>>,
+    mut delete_target: Signal<Option<DeleteRowsTarget>>,
+    mut insert_target: Signal<Option<InsertRowTarget>>,
+) -> Element {
+    let Some(current) = anchor() else {
+        return rsx! {};
+    };
+    let duplicate_target = current.target.as_ref().and_then(duplicate_row_target);
+    let style = format!("left: {}px; top: {}px;", current.x, current.y);
+    let rows_label = match current.rows.len() {
+        1 => "row".to_string(),
+        count => format!("{count} rows"),
+    };
+    let mut copy_rows = move |with_header: bool| {
+        let Some(current) = anchor() else {
+            return;
+        };
+        anchor.set(None);
+        let header = with_header.then_some(current.columns.as_slice());
+        let description = match current.rows.len() {
+            1 => "1 row".to_string(),
+            count => format!("{count} rows"),
+        };
+        copy_grid_text(
+            tabs,
+            current.tab_id,
+            tsv_block(header, &current.rows),
+            description,
+        );
+    };
+
+    rsx! {
+        div {
+            class: "column-stats__backdrop",
+            onclick: move |_| anchor.set(None),
+            oncontextmenu: move |event: MouseEvent| {
+                event.prevent_default();
+                anchor.set(None);
+            },
+        }
+        div {
+            class: "column-stats column-stats--menu",
+            style: "{style}",
+            button {
+                class: "column-stats__menu-item",
+                onclick: move |_| copy_rows(false),
+                "Copy {rows_label}"
+            }
+            button {
+                class: "column-stats__menu-item",
+                onclick: move |_| copy_rows(true),
+                "Copy {rows_label} with header"
+            }
+            if let Some(duplicate_target) = duplicate_target {
+                button {
+                    class: "column-stats__menu-item",
+                    disabled: read_only_mode_enabled(),
+                    onclick: move |_| {
+                        anchor.set(None);
+                        insert_target.set(Some(duplicate_target.clone()));
+                    },
+                    "Duplicate row…"
+                }
+            }
+            if let Some(target) = current.target.clone() {
+                button {
+                    class: "column-stats__menu-item",
+                    disabled: read_only_mode_enabled(),
+                    onclick: move |_| {
+                        anchor.set(None);
+                        delete_target.set(Some(target.clone()));
+                    },
+                    "Delete {rows_label}…"
+                }
+            }
+        }
+    }
+}
+
+#[component]
+pub(crate) fn DeleteRowsDialog(
+    tabs: Signal<Vec<QueryTabState>>,
+    mut target: Signal<Option<DeleteRowsTarget>>,
+) -> Element {
+    let mut error = use_signal(String::new);
+    let mut inflight = use_signal(|| false);
+
+    let Some(current_target) = target() else {
+        return rsx! {};
+    };
+    let read_only_mode = read_only_mode_enabled();
+    let row_count = current_target.rows.len();
+    let title = if row_count == 1 {
+        "Delete row".to_string()
+    } else {
+        format!("Delete {row_count} rows")
+    };
+
+    rsx! {
+        div {
+            class: "settings-modal__backdrop",
+            onclick: move |_| {
+                if !inflight() {
+                    error.set(String::new());
+                    target.set(None);
+                }
+            },
+            div {
+                class: "settings-modal table-modal table-modal--compact",
+                onclick: move |event| event.stop_propagation(),
+                div {
+                    class: "settings-modal__header",
+                    div {
+                        class: "settings-modal__header-copy",
+                        h2 { class: "settings-modal__title", "{title}" }
+                        p {
+                            class: "settings-modal__hint",
+                            "These rows will be deleted from {current_target.source.qualified_name} right away."
+                        }
+                    }
+                }
+
+                div {
+                    class: "table-modal__body",
+                    div {
+                        class: "table-modal__table-wrap",
+                        table {
+                            class: "table-modal__table",
+                            thead {
+                                tr {
+                                    th { "Key" }
+                                    th { "Values" }
+                                }
+                            }
+                            tbody {
+                                for row in current_target.rows.iter() {
+                                    tr {
+                                        key: "{row.locator}",
+                                        td { class: "table-modal__cell--code", "{locator_label(&row.locator)}" }
+                                        td { class: "table-modal__cell--code", "{row_preview(&row.values)}" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    if !error().is_empty() {
+                        p { class: "table-modal__error", "{error}" }
+                    }
+
+                    div {
+                        class: "table-modal__actions",
+                        button {
+                            class: "button button--ghost",
+                            disabled: inflight(),
+                            onclick: move |_| {
+                                error.set(String::new());
+                                target.set(None);
+                            },
+                            "Cancel"
+                        }
+                        button {
+                            class: "button button--primary",
+                            disabled: read_only_mode || inflight(),
+                            onclick: move |_| {
+                                let Some(current_target) = target() else {
+                                    return;
+                                };
+                                if read_only_mode_enabled() {
+                                    error.set(read_only_mode_block_status("row delete"));
+                                    return;
+                                }
+                                spawn(async move {
+                                    inflight.set(true);
+                                    let result = delete_rows(&current_target).await;
+                                    inflight.set(false);
+                                    match result {
+                                        Ok(deleted) => {
+                                            error.set(String::new());
+                                            target.set(None);
+                                            finish_row_delete(tabs, current_target, deleted);
+                                        }
+                                        Err(message) => error.set(message),
+                                    }
+                                });
+                            },
+                            if inflight() { "Deleting…" } else { "Delete" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Runs the DELETE on the session's open transaction, or on the pool when the
+/// session autocommits.
+async fn delete_rows(target: &DeleteRowsTarget) -> Result<u64, String> {
+    let locators = target
+        .rows
+        .iter()
+        .map(|row| row.locator.clone())
+        .collect::<Vec<_>>();
+    let result = match session_transaction(target.session_id) {
+        Some(transaction) => {
+            let result = transaction
+                .delete_table_rows(target.source.clone(), locators)
+                .await;
+            set_session_transaction_status(target.session_id, transaction.status().await);
+            result
+        }
+        None => {
+            let Some(connection) = session_connection(target.session_id) else {
+                return Err("Connection is no longer open.".to_string());
+            };
+            services::delete_table_rows(connection, target.source.clone(), locators).await
+        }
+    };
+    result.map_err(|err| err.to_string())
+}
+
+/// Drops the deleted rows from the tab's page instead of reloading it.
+fn finish_row_delete(mut tabs: Signal<Vec<QueryTabState>>, target: DeleteRowsTarget, deleted: u64) {
+    decrement_row_counts(target.session_id, &target.source.qualified_name, deleted);
+    let locators = target
+        .rows
+        .iter()
+        .map(|row| row.locator.as_str())
+        .collect::<HashSet<_>>();
+    tabs.with_mut(|all_tabs| {
+        if let Some(tab) = all_tabs.iter_mut().find(|tab| tab.id == target.tab_id)
+            && let Some(QueryOutput::Table(page)) = tab.result.as_mut()
+            && let Some(editable) = page.editable.as_mut()
+        {
+            let mut row_index = 0;
+            page.rows.retain(|_| {
+                let keep = editable
+                    .row_locators
+                    .get(row_index)
+                    .is_none_or(|locator| !locators.contains(locator.as_str()));
+                row_index += 1;
+                keep
+            });
+            editable
+                .row_locators
+                .retain(|locator| !locators.contains(locator.as_str()));
+        }
+    });
+    let summary = if deleted == 1 {
+        "Deleted 1 row".to_string()
+    } else {
+        format!("Deleted {deleted} rows")
+    };
+    set_active_tab_status(
+        tabs,
+        target.tab_id,
+        format!("{summary} from {}", target.source.table_name),
+    );
+}
+
+/// Opens the insert dialog on a copy of the row when exactly one is picked.
+fn duplicate_row_target(target: &DeleteRowsTarget) -> Option<InsertRowTarget> {
+    let [row] = target.rows.as_slice() else {
+        return None;
+    };
+    Some(InsertRowTarget {
+        tab_id: target.tab_id,
+        session_id: target.session_id,
+        source: target.source.clone(),
+        prefill: Some(
+            target
+                .columns
+                .iter()
+                .cloned()
+                .zip(row.values.iter().cloned())
+                .collect(),
+        ),
+    })
+}
+
+/// Human-readable row key: MySQL locators are JSON arrays of primary key
+/// values, SQLite and PostgreSQL ones are a rowid or ctid.
+fn locator_label(locator: &str) -> String {
+    match serde_json::from_str::<Vec<String>>(locator) {
+        Ok(values) => values.join(", "),
+        Err(_) => locator.to_string(),
+    }
+}
+
+fn row_preview(values: &[String]) -> String {
+    let mut preview = values
+        .iter()
+        .take(PREVIEW_CELLS)
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .join(" · ");
+    if values.len() > PREVIEW_CELLS {
+        preview.push_str(" · …");
+    }
+    preview
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{locator_label, row_preview};
+
+    #[test]
+    fn locator_label_unpacks_mysql_primary_keys() {
+        assert_eq!(locator_label(r#"["42","tenant-a"]"#), "42, tenant-a");
+        assert_eq!(locator_label("(0,3)"), "(0,3)");
+        assert_eq!(locator_label("17"), "17");
+    }
+
+    #[test]
+    fn row_preview_shows_leading_cells() {
+        let values = ["1", "Ada", "ada@example.com", "admin"].map(str::to_string);
+        assert_eq!(row_preview(&values), "1 · Ada · ada@example.com · …");
+        assert_eq!(row_preview(&values[..2]), "1 · Ada");
+    }
+}
@@ -0,0 +1,127 @@
+use crate::app_state::session_connection;
+use crate::screens::workspace::actions::{read_only_mode_block_status, read_only_mode_enabled};
+use dioxus::prelude::*;
+use models::TablePreviewSource;
+
+/// Table, or one of its columns, whose comment the editor changes.
+#[derive(Clone, PartialEq)]
+pub(super) struct CommentTarget {
+    pub(super) session_id: u64,
+    pub(super) source: TablePreviewSource,
+    pub(super) column: Option<String>,
+    pub(super) comment: String,
+}
+
+#[component]
+pub(super) fn CommentModal(
+    target: CommentTarget,
+    mut comment_target: Signal<Option<CommentTarget>>,
+    /// Called with the saved comment, `None` once it has been removed.
+    on_saved: EventHandler<Option<String>>,
+) -> Element {
+    let mut draft = use_signal(|| target.comment.clone());
+    let mut error = use_signal(String::new);
+    let mut inflight = use_signal(|| false);
+    let read_only_mode = read_only_mode_enabled();
+    let object_label = match &target.column {
+        Some(column) => format!("{}.{column}", target.source.table_name),
+        None => target.source.table_name.clone(),
+    };
+
+    rsx! {
+        div {
+            class: "settings-modal__backdrop",
+            onclick: move |_| {
+                if !inflight() {
+                    comment_target.set(None);
+                }
+            },
+            div {
+                class: "settings-modal table-modal table-modal--compact",
+                onclick: move |event| event.stop_propagation(),
+                div {
+                    class: "settings-modal__header",
+                    div {
+                        class: "settings-modal__header-copy",
+                        h2 { class: "settings-modal__title", "Comment · {object_label}" }
+                        p {
+                            class: "settings-modal__hint",
+                            "Stored in the database catalog. Leave it empty to remove the comment."
+                        }
+                    }
+                    button {
+                        class: "button button--ghost button--small",
+                        disabled: inflight(),
+                        onclick: move |_| comment_target.set(None),
+                        "Close"
+                    }
+                }
+
+                div {
+                    class: "table-modal__body",
+                    div {
+                        class: "field",
+                        span { class: "field__label", "Comment" }
+                        textarea {
+                            class: "input table-comment__input",
+                            initial_value: "{target.comment}",
+                            oninput: move |event| draft.set(event.value()),
+                        }
+                    }
+
+                    if !error().is_empty() {
+                        p { class: "table-modal__error", "{error}" }
+                    }
+
+                    div {
+                        class: "table-modal__actions",
+                        button {
+                            class: "button button--ghost",
+                            disabled: inflight(),
+                            onclick: move |_| comment_target.set(None),
+                            "Cancel"
+                        }
+                        button {
+                            class: "button button--primary",
+                            disabled: inflight() || read_only_mode,
+                            onclick: {
+                                let target = target.clone();
+                                move |_| {
+                                    if read_only_mode_enabled() {
+                                        error.set(read_only_mode_block_status("comment changes"));
+                                        return;
+                                    }
+                                    let Some(connection) = session_connection(target.session_id) else {
+                                        error.set("Connection is no longer open.".to_string());
+                                        return;
+                                    };
+                                    let target = target.clone();
+                                    let comment = draft().trim().to_string();
+                                    spawn(async move {
+                                        inflight.set(true);
+                                        let result = services::set_table_comment(
+                                            connection,
+                                            target.source,
+                                            target.column,
+                                            comment.clone(),
+                                        )
+                                        .await;
+                                        inflight.set(false);
+                                        match result {
+                                            Ok(()) => {
+                                                comment_target.set(None);
+                                                on_saved.call(Some(comment).filter(|comment| !comment.is_empty()));
+                                            }
+                                            Err(err) => error.set(err.to_string()),
+                                        }
+                                    });
+                                }
+                            },
+                            if inflight() { "Saving…" } else { "Save" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,204 @@
+use super::ExplorerConnectionSection;
+use super::tree_views::ExplorerConnectionView;
+use crate::app_state::{
+    APP_STATE, activate_session, add_database_session, session_connection, toast_error,
+};
+use dioxus::prelude::*;
+use models::{ConnectionRequest, QueryTabState};
+
+/// Other databases on a PostgreSQL server, loaded on first expand.
+/// Double-clicking one opens a session on it with the same credentials and
+/// nests that session's tree here.
+#[component]
+pub(super) fn ExplorerDatabaseGroup(
+    session_id: u64,
+    database_sections: Vec<ExplorerConnectionSection>,
+    tree_reload: Signal<u64>,
+    tabs: Signal<Vec<QueryTabState>>,
+    active_tab_id: Signal<u64>,
+    next_tab_id: Signal<u64>,
+    selected_node: Signal<String>,
+    filter: String,
+) -> Element {
+    let mut expanded = use_signal(|| !database_sections.is_empty());
+    let mut databases = use_signal(|| None::<Result<Vec<String>, String>>);
+    let opening = use_signal(|| None::<String>);
+    let load_databases = move || {
+        spawn(async move {
+            let result = match session_connection(session_id) {
+                Some(connection) => services::load_databases(connection)
+                    .await
+                    .map_err(|err| err.to_string()),
+                None => Err("Connection is no longer open.".to_string()),
+            };
+            databases.set(Some(result));
+        });
+    };
+    use_effect(move || {
+        tree_reload();
+        databases.set(None);
+        if *expanded.peek() {
+            load_databases();
+        }
+    });
+
+    let (current_database, open_sections) = {
+        let state = APP_STATE.read();
+        let current_database = state
+            .session(session_id)
+            .map(|session| session.request.short_name())
+            .unwrap_or_default();
+        let open_sections = database_sections
+            .iter()
+            .filter_map(|section| {
+                let session = state.session(section.session_id)?;
+                Some((session.request.short_name(), section.clone()))
+            })
+            .collect::<Vec<_>>();
+        (current_database, open_sections)
+    };
+    let header_meta = match databases() {
+        Some(Ok(items)) => items.len().to_string(),
+        Some(Err(_)) => "!".to_string(),
+        None => String::new(),
+    };
+
+    rsx! {
+        div { class: "tree__group",
+            button {
+                class: "tree__group-header tree__group-header--toggle",
+                onclick: move |_| {
+                    expanded.toggle();
+                    if expanded() && databases.peek().is_none() {
+                        load_databases();
+                    }
+                },
+                span {
+                    class: if expanded() {
+                        "tree__chevron tree__chevron--open"
+                    } else {
+                        "tree__chevron"
+                    },
+                    ">"
+                }
+                span { "Databases" }
+                if !header_meta.is_empty() {
+                    span { class: "tree__group-count", "{header_meta}" }
+                }
+            }
+
+            if expanded() {
+                div { class: "tree__group-items",
+                    match databases() {
+                        None => rsx! {
+                            p { class: "tree__group-empty", "Loading…" }
+                        },
+                        Some(Err(err)) => rsx! {
+                            p {
+                                class: "tree__group-empty tree__group-empty--error",
+                                title: "{err}",
+                                "{err}"
+                            }
+                        },
+                        Some(Ok(items)) => rsx! {
+                            for database in items {
+                                if let Some((_, section)) = open_sections
+                                    .iter()
+                                    .find(|(name, _)| *name == database)
+                                {
+                                    ExplorerConnectionView {
+                                        key: "{database}",
+                                        section: section.clone(),
+                                        database_sections: Vec::new(),
+                                        tree_reload,
+                                        tabs,
+                                        active_tab_id,
+                                        next_tab_id,
+                                        selected_node,
+                                        filter: filter.clone(),
+                                    }
+                                } else {
+                                    ExplorerDatabaseRow {
+                                        key: "{database}",
+                                        session_id,
+                                        is_current: database == current_database,
+                                        database,
+                                        opening,
+                                    }
+                                }
+                            }
+                        },
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn ExplorerDatabaseRow(
+    session_id: u64,
+    database: String,
+    is_current: bool,
+    opening: Signal<Option<String>>,
+) -> Element {
+    let is_opening = opening().as_deref() == Some(database.as_str());
+    let details = if is_current {
+        "Current database"
+    } else if is_opening {
+        "Connecting…"
+    } else {
+        "Double-click to open"
+    };
+
+    rsx! {
+        div { class: "tree__object-row",
+            button {
+                class: "tree__object",
+                title: "{database}",
+                disabled: is_opening,
+                onclick: move |_| activate_session(session_id),
+                ondoubleclick: {
+                    let database = database.clone();
+                    move |_| {
+                        if !is_current {
+                            open_database(session_id, database.clone(), opening);
+                        }
+                    }
+                },
+                div { class: "tree__object-badge", "DB" }
+                div {
+                    class: "tree__object-copy",
+                    div { class: "tree__object-name", "{database}" }
+                    div { class: "tree__object-kind", "{details}" }
+                }
+            }
+        }
+    }
+}
+
+/// Connects to `database` with the parent session's host and credentials and
+/// registers the result as a database session under it.
+fn open_database(session_id: u64, database: String, mut opening: Signal<Option<String>>) {
+    let request = match APP_STATE
+        .read()
+        .session(session_id)
+        .map(|session| &session.request)
+    {
+        Some(ConnectionRequest::Postgres(data)) => {
+            ConnectionRequest::Postgres(data.with_database(&database))
+        }
+        _ => return,
+    };
+
+    opening.set(Some(database.clone()));
+    spawn(async move {
+        match services::connect_to_db(request.clone()).await {
+            Ok(connection) => {
+                add_database_session(session_id, request, connection);
+            }
+            Err(err) => toast_error(format!("Could not open database {database}: {err}")),
+        }
+        opening.set(None);
+    });
+}
@@ -0,0 +1,326 @@
+use crate::app_state::session_connection;
+use crate::screens::workspace::actions::{read_only_mode_block_status, read_only_mode_enabled};
+use dioxus::prelude::*;
+use models::{IndexDefinition, IndexMethod, TableIndex, TablePreviewSource};
+use rfd::{AsyncMessageDialog, MessageButtons, MessageDialogResult, MessageLevel};
+
+/// PostgreSQL table whose indexes are listed, created and dropped.
+#[derive(Clone, PartialEq)]
+pub(super) struct IndexesTarget {
+    pub(super) session_id: u64,
+    pub(super) source: TablePreviewSource,
+}
+
+#[component]
+pub(super) fn IndexesModal(
+    target: IndexesTarget,
+    mut indexes_target: Signal<Option<IndexesTarget>>,
+) -> Element {
+    let mut indexes = use_signal(|| None::<Result<Vec<TableIndex>, String>>);
+    let mut columns = use_signal(Vec::<String>::new);
+    let mut reload = use_signal(|| 0_u64);
+    let mut draft = use_signal(IndexDefinition::default);
+    let mut status = use_signal(String::new);
+    let mut running = use_signal(|| false);
+    let read_only_mode = read_only_mode_enabled();
+
+    let session_id = target.session_id;
+    let source = target.source.clone();
+    use_effect(move || {
+        reload();
+        let source = source.clone();
+        spawn(async move {
+            let Some(connection) = session_connection(session_id) else {
+                indexes.set(Some(Err("Connection is no longer open.".to_string())));
+                return;
+            };
+            let result = services::load_table_indexes(
+                connection.clone(),
+                source.schema.clone(),
+                source.table_name.clone(),
+            )
+            .await
+            .map_err(|err| err.to_string());
+            indexes.set(Some(result));
+            if let Ok(loaded) =
+                services::load_table_columns(connection, source.schema, source.table_name).await
+            {
+                columns.set(loaded);
+            }
+        });
+    });
+
+    let definition = draft();
+    let statement = services::create_index_statement(&target.source, &definition);
+    let can_create = !definition.columns.is_empty() && !running() && !read_only_mode;
+
+    rsx! {
+        div {
+            class: "settings-modal__backdrop",
+            onclick: move |_| {
+                if !running() {
+                    indexes_target.set(None);
+                }
+            },
+            div {
+                class: "settings-modal table-modal table-modal--wide",
+                onclick: move |event| event.stop_propagation(),
+                div {
+                    class: "settings-modal__header",
+                    div {
+                        class: "settings-modal__header-copy",
+                        h2 { class: "settings-modal__title", "Indexes · {target.source.table_name}" }
+                        p {
+                            class: "settings-modal__hint",
+                            "Indexes backing a primary key or unique constraint go away with the constraint."
+                        }
+                    }
+                    button {
+                        class: "button button--ghost button--small",
+                        disabled: running(),
+                        onclick: move |_| indexes_target.set(None),
+                        "Close"
+                    }
+                }
+
+                div {
+                    class: "table-modal__body",
+                    match indexes() {
+                        None => rsx! {
+                            p { class: "table-modal__hint", "Loading indexes…" }
+                        },
+                        Some(Err(err)) => rsx! {
+                            p { class: "table-modal__error", "{err}" }
+                        },
+                        Some(Ok(indexes)) if indexes.is_empty() => rsx! {
+                            p { class: "table-modal__hint", "This table has no indexes." }
+                        },
+                        Some(Ok(indexes)) => rsx! {
+                            div {
+                                class: "table-modal__table-wrap",
+                                table {
+                                    class: "table-modal__table",
+                                    thead {
+                                        tr {
+                                            th { "Name" }
+                                            th { "Method" }
+                                            th { "Unique" }
+                                            th { "Definition" }
+                                            th {}
+                                        }
+                                    }
+                                    tbody {
+                                        for index in indexes {
+                                            tr {
+                                                key: "{index.name}",
+                                                td { "{index.name}" }
+                                                td { "{index.method}" }
+                                                td { if index.is_unique { "yes" } else { "" } }
+                                                td {
+                                                    class: "table-modal__cell--code",
+                                                    title: "{index.definition}",
+                                                    "{index.definition}"
+                                                }
+                                                td {
+                                                    class: "table-modal__cell--actions",
+                                                    button {
+                                                        class: "button button--ghost button--small",
+                                                        disabled: running() || read_only_mode || index.constraint.is_some(),
+                                                        title: match index.constraint.as_ref() {
+                                                            Some(constraint) => format!("Backs constraint {constraint}; drop the constraint instead"),
+                                                            None => String::new(),
+                                                        },
+                                                        onclick: {
+                                                            let name = index.name.clone();
+                                                            let schema = target.source.schema.clone();
+                                                            move |_| {
+                                                                if read_only_mode_enabled() {
+                                                                    status.set(read_only_mode_block_status("dropping indexes"));
+                                                                    return;
+                                                                }
+                                                                let name = name.clone();
+                                                                let schema = schema.clone();
+                                                                let concurrently = draft.read().concurrently;
+                                                                spawn(async move {
+                                                                    let confirmation = AsyncMessageDialog::new()
+                                                                        .set_title("Drop Index")
+                                                                        .set_description(format!("Drop index {name}? This cannot be undone."))
+                                                                        .set_buttons(MessageButtons::YesNo)
+                                                                        .set_level(MessageLevel::Warning)
+                                                                        .show()
+                                                                        .await;
+                                                                    if confirmation != MessageDialogResult::Yes {
+                                                                        return;
+                                                                    }
+                                                                    let Some(connection) = session_connection(session_id) else {
+                                                                        status.set("Connection is no longer open.".to_string());
+                                                                        return;
+                                                                    };
+                                                                    running.set(true);
+                                                                    let result = services::drop_index(
+                                                                        connection,
+                                                                        schema,
+                                                                        name.clone(),
+                                                                        concurrently,
+                                                                    )
+                                                                    .await;
+                                                                    running.set(false);
+                                                                    match result {
+                                                                        Ok(()) => status.set(format!("Dropped {name}.")),
+                                                                        Err(err) => status.set(err.to_string()),
+                                                                    }
+                                                                    reload += 1;
+                                                                });
+                                                            }
+                                                        },
+                                                        "Drop"
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        },
+                    }
+
+                    span { class: "field__label", "Create index" }
+                    div {
+                        class: "table-modal__toolbar",
+                        input {
+                            class: "input",
+                            value: "{definition.name}",
+                            placeholder: "Index name (optional)",
+                            oninput: move |event| draft.with_mut(|draft| draft.name = event.value()),
+                        }
+                        select {
+                            class: "input",
+                            onchange: move |event| {
+                                let label = event.value();
+                                if let Some(method) =
+                                    IndexMethod::ALL.into_iter().find(|method| method.label() == label)
+                                {
+                                    draft.with_mut(|draft| draft.method = method);
+                                }
+                            },
+                            for method in IndexMethod::ALL {
+                                option {
+                                    value: "{method.label()}",
+                                    selected: definition.method == method,
+                                    "{method.label()}"
+                                }
+                            }
+                        }
+                        label {
+                            class: "settings-modal__toggle",
+                            title: if definition.method.supports_unique() {
+                                ""
+                            } else {
+                                "Only BTREE indexes can be unique"
+                            },
+                            input {
+                                r#type: "checkbox",
+                                checked: definition.unique && definition.method.supports_unique(),
+                                disabled: !definition.method.supports_unique(),
+                                oninput: move |event| draft.with_mut(|draft| draft.unique = event.checked()),
+                            }
+                            span { "Unique" }
+                        }
+                        label {
+                            class: "settings-modal__toggle",
+                            title: "Build and drop without blocking writes; slower, and not inside a transaction",
+                            input {
+                                r#type: "checkbox",
+                                checked: definition.concurrently,
+                                oninput: move |event| draft.with_mut(|draft| draft.concurrently = event.checked()),
+                            }
+                            span { "Concurrently" }
+                        }
+                    }
+                    div {
+                        class: "table-modal__column-toggles",
+                        for column in columns() {
+                            label {
+                                key: "{column}",
+                                class: "settings-modal__toggle",
+                                input {
+                                    r#type: "checkbox",
+                                    checked: definition.columns.contains(&column),
+                                    oninput: {
+                                        let column = column.clone();
+                                        move |event: FormEvent| {
+                                            let checked = event.checked();
+                                            draft.with_mut(|draft| {
+                                                draft.columns.retain(|existing| *existing != column);
+                                                if checked {
+                                                    draft.columns.push(column.clone());
+                                                }
+                                            });
+                                        }
+                                    },
+                                }
+                                span { "{column}" }
+                            }
+                        }
+                    }
+                    input {
+                        class: "input",
+                        value: "{definition.predicate}",
+                        placeholder: "WHERE condition for a partial index (optional)",
+                        oninput: move |event| draft.with_mut(|draft| draft.predicate = event.value()),
+                    }
+                    if !definition.columns.is_empty() {
+                        pre { class: "table-modal__preview-sql", "{statement}" }
+                    }
+                    if !status().is_empty() {
+                        p { class: "table-modal__hint", "{status}" }
+                    }
+
+                    div {
+                        class: "table-modal__actions",
+                        button {
+                            class: "button button--primary",
+                            disabled: !can_create,
+                            title: if read_only_mode {
+                                "Creating indexes is blocked by read-only mode"
+                            } else {
+                                "Columns are indexed in the order they were ticked"
+                            },
+                            onclick: {
+                                let source = target.source.clone();
+                                move |_| {
+                                    if read_only_mode_enabled() {
+                                        status.set(read_only_mode_block_status("creating indexes"));
+                                        return;
+                                    }
+                                    let Some(connection) = session_connection(session_id) else {
+                                        status.set("Connection is no longer open.".to_string());
+                                        return;
+                                    };
+                                    let source = source.clone();
+                                    let definition = draft();
+                                    running.set(true);
+                                    status.set("Creating index…".to_string());
+                                    spawn(async move {
+                                        let result =
+                                            services::create_index(connection, source, definition).await;
+                                        running.set(false);
+                                        match result {
+                                            Ok(()) => {
+                                                status.set("Index created.".to_string());
+                                                draft.set(IndexDefinition::default());
+                                            }
+                                            Err(err) => status.set(err.to_string()),
+                                        }
+                                        reload += 1;
+                                    });
+                                }
+                            },
+                            if running() { "Running…" } else { "Create Index" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
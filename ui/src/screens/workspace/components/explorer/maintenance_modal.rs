@@ -0,0 +1,259 @@
+use crate::app_state::session_connection;
+use crate::screens::workspace::actions::{read_only_mode_block_status, read_only_mode_enabled};
+use dioxus::prelude::*;
+use models::{MaintenanceEvent, MaintenanceKind, TableMaintenanceStats, TablePreviewSource};
+use rfd::{AsyncMessageDialog, MessageButtons, MessageDialogResult, MessageLevel};
+use std::time::Instant;
+
+/// PostgreSQL table to vacuum, analyze or reindex.
+#[derive(Clone, PartialEq)]
+pub(super) struct MaintenanceTarget {
+    pub(super) session_id: u64,
+    pub(super) source: TablePreviewSource,
+}
+
+#[component]
+pub(super) fn MaintenanceModal(
+    target: MaintenanceTarget,
+    mut maintenance_target: Signal<Option<MaintenanceTarget>>,
+) -> Element {
+    let mut stats = use_signal(|| None::<Result<TableMaintenanceStats, String>>);
+    let mut reload = use_signal(|| 0_u64);
+    let mut kind = use_signal(|| MaintenanceKind::Vacuum);
+    let mut log = use_signal(Vec::<String>::new);
+    // Backend running the command, while it runs.
+    let mut running_pid = use_signal(|| None::<i32>);
+    let mut running = use_signal(|| false);
+    let read_only_mode = read_only_mode_enabled();
+
+    let session_id = target.session_id;
+    let source = target.source.clone();
+    use_effect(move || {
+        reload();
+        let source = source.clone();
+        spawn(async move {
+            let result = match session_connection(session_id) {
+                Some(connection) => services::load_table_maintenance_stats(connection, source)
+                    .await
+                    .map_err(|err| err.to_string()),
+                None => Err("Connection is no longer open.".to_string()),
+            };
+            stats.set(Some(result));
+        });
+    });
+
+    let log_text = log.read().join("\n");
+
+    rsx! {
+        div {
+            class: "settings-modal__backdrop",
+            onclick: move |_| {
+                if !running() {
+                    maintenance_target.set(None);
+                }
+            },
+            div {
+                class: "settings-modal table-modal",
+                onclick: move |event| event.stop_propagation(),
+                div {
+                    class: "settings-modal__header",
+                    div {
+                        class: "settings-modal__header-copy",
+                        h2 { class: "settings-modal__title", "Maintenance · {target.source.table_name}" }
+                        p {
+                            class: "settings-modal__hint",
+                            "Check when the table was last vacuumed and analyzed before running anything."
+                        }
+                    }
+                    button {
+                        class: "button button--ghost button--small",
+                        disabled: running(),
+                        onclick: move |_| maintenance_target.set(None),
+                        "Close"
+                    }
+                }
+
+                div {
+                    class: "table-modal__body",
+                    match stats() {
+                        None => rsx! {
+                            p { class: "table-modal__hint", "Loading table statistics…" }
+                        },
+                        Some(Err(err)) => rsx! {
+                            p { class: "table-modal__error", "{err}" }
+                        },
+                        Some(Ok(stats)) => rsx! {
+                            dl {
+                                class: "table-modal__details",
+                                dt { "Last vacuum" }
+                                dd { "{format_last_run(&stats.last_vacuum, &stats.last_autovacuum)}" }
+                                dt { "Last analyze" }
+                                dd { "{format_last_run(&stats.last_analyze, &stats.last_autoanalyze)}" }
+                                dt { "Live rows" }
+                                dd { "{stats.live_tuples}" }
+                                dt { "Dead rows" }
+                                dd { "{stats.dead_tuples}" }
+                            }
+                        },
+                    }
+
+                    div {
+                        class: "table-modal__segmented",
+                        for option in MaintenanceKind::ALL {
+                            button {
+                                class: if kind() == option {
+                                    "button button--small button--active"
+                                } else {
+                                    "button button--ghost button--small"
+                                },
+                                disabled: running(),
+                                onclick: move |_| kind.set(option),
+                                "{option.label()}"
+                            }
+                        }
+                    }
+                    if kind().locks_table() {
+                        p {
+                            class: "table-modal__hint",
+                            "{kind().label()} locks the table against reads and writes until it finishes."
+                        }
+                    }
+
+                    if !log_text.is_empty() {
+                        pre { class: "maintenance__log", "{log_text}" }
+                    }
+
+                    div {
+                        class: "table-modal__actions",
+                        if running() {
+                            button {
+                                class: "button button--ghost",
+                                disabled: running_pid().is_none(),
+                                onclick: move |_| {
+                                    let Some(pid) = running_pid() else {
+                                        return;
+                                    };
+                                    let Some(connection) = session_connection(session_id) else {
+                                        return;
+                                    };
+                                    spawn(async move {
+                                        let line = match services::cancel_backend(connection, pid).await {
+                                            Ok(true) => "Cancel requested.".to_string(),
+                                            Ok(false) => "The command had already finished.".to_string(),
+                                            Err(err) => format!("Cancel error: {err}"),
+                                        };
+                                        log.with_mut(|lines| lines.push(line));
+                                    });
+                                },
+                                "Cancel"
+                            }
+                        }
+                        button {
+                            class: "button button--primary",
+                            disabled: running() || read_only_mode,
+                            title: if read_only_mode {
+                                "Maintenance is blocked by read-only mode"
+                            } else {
+                                ""
+                            },
+                            onclick: {
+                                let source = target.source.clone();
+                                move |_| {
+                                    if read_only_mode_enabled() {
+                                        log.set(vec![read_only_mode_block_status("running table maintenance")]);
+                                        return;
+                                    }
+                                    let source = source.clone();
+                                    let kind = kind();
+                                    spawn(async move {
+                                        if kind.locks_table() {
+                                            let confirmation = AsyncMessageDialog::new()
+                                                .set_title(kind.label())
+                                                .set_description(format!(
+                                                    "Run {} on {}? Queries on the table wait until it finishes.",
+                                                    kind.label(),
+                                                    source.qualified_name
+                                                ))
+                                                .set_buttons(MessageButtons::YesNo)
+                                                .set_level(MessageLevel::Warning)
+                                                .show()
+                                                .await;
+                                            if confirmation != MessageDialogResult::Yes {
+                                                return;
+                                            }
+                                        }
+
+                                        let Some(connection) = session_connection(session_id) else {
+                                            log.set(vec!["Connection is no longer open.".to_string()]);
+                                            return;
+                                        };
+
+                                        running.set(true);
+                                        log.set(vec![format!("{} {}", kind.label(), source.qualified_name)]);
+                                        let started = Instant::now();
+                                        let result = services::run_table_maintenance(connection, source, kind, |event| {
+                                            match event {
+                                                MaintenanceEvent::Started { pid } => {
+                                                    running_pid.set(Some(pid));
+                                                    log.with_mut(|lines| lines.push(format!("Running on backend {pid}.")));
+                                                }
+                                                MaintenanceEvent::Progress(line) => {
+                                                    log.with_mut(|lines| lines.push(line));
+                                                }
+                                            }
+                                        })
+                                        .await;
+                                        running_pid.set(None);
+                                        running.set(false);
+
+                                        let line = match result {
+                                            Ok(()) => format!(
+                                                "Finished in {:.1} s.",
+                                                started.elapsed().as_secs_f64()
+                                            ),
+                                            Err(err) => err.to_string(),
+                                        };
+                                        log.with_mut(|lines| lines.push(line));
+                                        reload += 1;
+                                    });
+                                }
+                            },
+                            if running() { "Running…" } else { "Run {kind().label()}" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The later of a manual and an automatic run, e.g. `2024-05-01 10:00:00 (auto)`.
+pub(super) fn format_last_run(manual: &Option<String>, auto: &Option<String>) -> String {
+    // Both are `YYYY-MM-DD HH24:MI:SS`, so text order is time order.
+    match (manual, auto) {
+        (Some(manual), Some(auto)) if auto > manual => format!("{auto} (auto)"),
+        (Some(manual), _) => manual.clone(),
+        (None, Some(auto)) => format!("{auto} (auto)"),
+        (None, None) => "never".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::format_last_run;
+
+    #[test]
+    fn format_last_run_picks_the_most_recent_run() {
+        let at = |value: &str| Some(value.to_string());
+
+        assert_eq!(
+            format_last_run(&at("2024-05-01 10:00:00"), &at("2024-05-02 08:00:00")),
+            "2024-05-02 08:00:00 (auto)"
+        );
+        assert_eq!(
+            format_last_run(&at("2024-05-03 09:00:00"), &at("2024-05-02 08:00:00")),
+            "2024-05-03 09:00:00"
+        );
+        assert_eq!(format_last_run(&None, &None), "never");
+    }
+}
@@ -1,17 +1,39 @@
+mod comment_modal;
 mod create_table_modal;
+mod database_group;
 mod duplicate_table_modal;
+mod indexes_modal;
+mod maintenance_modal;
+mod pg_dump_modal;
+mod privileges_modal;
+mod routine_source_modal;
+mod row_security_modal;
+mod sequence_modal;
+mod size_report_modal;
+mod table_context_menu;
+mod table_health_modal;
 mod tree_views;
 
-use crate::app_state::{APP_READ_ONLY_MODE, APP_STATE, activate_session, remove_session};
+use std::collections::{HashMap, HashSet};
+
+use crate::app_state::{
+    APP_COLUMN_INDEX, APP_READ_ONLY_MODE, APP_STATE, activate_session, remove_session,
+    session_connection,
+};
 use crate::screens::workspace::components::{ActionIcon, IconButton};
 use dioxus::prelude::*;
-use models::{DatabaseKind, ExplorerNode, ExplorerNodeKind, QueryTabState};
+use models::{
+    DatabaseKind, ExplorerNode, ExplorerNodeKind, QueryTabState, RoutineInfo, RoutineKind,
+    SchemaColumn, TableCatalogInfo,
+};
 
 use create_table_modal::{CreateTableModal, CreateTableTarget};
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct ExplorerConnectionSection {
     pub session_id: u64,
+    /// Set for database sessions opened from another connection's tree.
+    pub parent_session_id: Option<u64>,
     pub name: String,
     pub kind_label: String,
     pub status: String,
@@ -30,13 +52,51 @@ pub fn SidebarConnectionTree(
     let selected_node = use_signal(String::new);
     let mut show_create_table = use_signal(|| false);
     let mut filter_query = use_signal(String::new);
+    let mut column_index_requested = use_signal(HashSet::<u64>::new);
+    let mut column_index_revision = use_signal(|| None::<u64>);
     let query = filter_query();
+    let filter = query.trim().to_ascii_lowercase();
     let active_create_target = active_create_table_target(&sections);
-    let filtered_sections = filter_connection_sections(&sections, &query);
+    let session_ids = sections
+        .iter()
+        .map(|section| section.session_id)
+        .collect::<Vec<_>>();
+
+    // Column names are only worth loading once the filter is used; a tree
+    // reload loads them again in case tables changed.
+    use_effect(use_reactive(
+        (&filter, &session_ids),
+        move |(filter, session_ids)| {
+            let revision = tree_reload();
+            if *column_index_revision.peek() != Some(revision) {
+                column_index_revision.set(Some(revision));
+                column_index_requested.set(HashSet::new());
+            }
+            if filter.is_empty() {
+                return;
+            }
+            for session_id in session_ids {
+                if !column_index_requested.write().insert(session_id) {
+                    continue;
+                }
+                let Some(connection) = session_connection(session_id) else {
+                    continue;
+                };
+                spawn(async move {
+                    if let Ok(columns) = services::load_column_index(connection).await {
+                        APP_COLUMN_INDEX.write().insert(session_id, columns);
+                    }
+                });
+            }
+        },
+    ));
+
+    let filtered_sections = filter_connection_sections(&sections, &query, &APP_COLUMN_INDEX.read());
     let entity_count = filtered_sections
         .iter()
         .map(|section| count_objects(&section.nodes))
         .sum::<usize>();
+    let nested_sections = nest_database_sections(filtered_sections);
     let read_only_mode = APP_READ_ONLY_MODE();
 
     rsx! {
@@ -77,17 +137,24 @@ pub fn SidebarConnectionTree(
                         value: "{query}",
                         placeholder: "Filter entities",
                         oninput: move |event| filter_query.set(event.value()),
+                        onkeydown: move |event| {
+                            if event.key() == Key::Escape {
+                                filter_query.set(String::new());
+                            }
+                        },
                     }
                 }
 
                 div {
                     class: "tree__body",
-                    if filtered_sections.is_empty() {
+                    if nested_sections.is_empty() {
                         p { class: "empty-state", "No matching tables or views." }
                     } else {
-                        for section in filtered_sections {
+                        for (section, database_sections) in nested_sections {
                             tree_views::ExplorerConnectionView {
                                 section,
+                                database_sections,
+                                filter: filter.clone(),
                                 tree_reload,
                                 tabs,
                                 active_tab_id,
@@ -143,26 +210,91 @@ fn active_create_table_target(sections: &[ExplorerConnectionSection]) -> Option<
     })
 }
 
+/// Pairs each top-level section with the database sessions opened from it.
+/// A database session whose parent is filtered out stays top-level.
+fn nest_database_sections(
+    sections: Vec<ExplorerConnectionSection>,
+) -> Vec<(ExplorerConnectionSection, Vec<ExplorerConnectionSection>)> {
+    let session_ids = sections
+        .iter()
+        .map(|section| section.session_id)
+        .collect::<Vec<_>>();
+    let (top_level, children): (Vec<_>, Vec<_>) = sections.into_iter().partition(|section| {
+        section
+            .parent_session_id
+            .is_none_or(|parent_id| !session_ids.contains(&parent_id))
+    });
+
+    top_level
+        .into_iter()
+        .map(|section| {
+            let database_sections = children
+                .iter()
+                .filter(|child| child.parent_session_id == Some(section.session_id))
+                .cloned()
+                .collect();
+            (section, database_sections)
+        })
+        .collect()
+}
+
 pub(super) fn count_objects(nodes: &[ExplorerNode]) -> usize {
     nodes.iter().map(|node| node.children.len()).sum()
 }
 
-pub(super) fn split_children(children: &[ExplorerNode]) -> (Vec<ExplorerNode>, Vec<ExplorerNode>) {
-    let mut tables = Vec::new();
-    let mut views = Vec::new();
+#[derive(Default)]
+pub(super) struct SchemaObjectGroups {
+    pub(super) tables: Vec<ExplorerNode>,
+    pub(super) views: Vec<ExplorerNode>,
+    pub(super) materialized_views: Vec<ExplorerNode>,
+    pub(super) foreign_tables: Vec<ExplorerNode>,
+}
+
+impl SchemaObjectGroups {
+    pub(super) fn len(&self) -> usize {
+        self.tables.len()
+            + self.views.len()
+            + self.materialized_views.len()
+            + self.foreign_tables.len()
+    }
+
+    /// Non-empty groups with their section titles, in display order.
+    pub(super) fn into_sections(self) -> Vec<(&'static str, Vec<ExplorerNode>)> {
+        [
+            ("Tables", self.tables),
+            ("Views", self.views),
+            ("Matviews", self.materialized_views),
+            ("Foreign tables", self.foreign_tables),
+        ]
+        .into_iter()
+        .filter(|(_, nodes)| !nodes.is_empty())
+        .collect()
+    }
+}
+
+pub(super) fn split_children(children: &[ExplorerNode]) -> SchemaObjectGroups {
+    let mut groups = SchemaObjectGroups::default();
 
     for child in children {
         match child.kind {
-            ExplorerNodeKind::Table => tables.push(child.clone()),
-            ExplorerNodeKind::View => views.push(child.clone()),
+            ExplorerNodeKind::Table => groups.tables.push(child.clone()),
+            ExplorerNodeKind::View => groups.views.push(child.clone()),
+            ExplorerNodeKind::MaterializedView => groups.materialized_views.push(child.clone()),
+            ExplorerNodeKind::ForeignTable => groups.foreign_tables.push(child.clone()),
             ExplorerNodeKind::Schema => {}
         }
     }
 
-    tables.sort_by(|left, right| left.name.cmp(&right.name));
-    views.sort_by(|left, right| left.name.cmp(&right.name));
+    for nodes in [
+        &mut groups.tables,
+        &mut groups.views,
+        &mut groups.materialized_views,
+        &mut groups.foreign_tables,
+    ] {
+        nodes.sort_by(|left, right| left.name.cmp(&right.name));
+    }
 
-    (tables, views)
+    groups
 }
 
 pub(super) fn disconnect_session(
@@ -170,14 +302,14 @@ pub(super) fn disconnect_session(
     mut active_tab_id: Signal<u64>,
     session_id: u64,
 ) {
-    tabs.with_mut(|all_tabs| all_tabs.retain(|tab| tab.session_id != session_id));
+    let removed_ids = remove_session(session_id);
+    tabs.with_mut(|all_tabs| all_tabs.retain(|tab| !removed_ids.contains(&tab.session_id)));
     if let Some(first_tab) = tabs.read().first() {
         active_tab_id.set(first_tab.id);
         activate_session(first_tab.session_id);
     } else {
         active_tab_id.set(0);
     }
-    remove_session(session_id);
 }
 
 pub(super) fn default_schema_name(kind: DatabaseKind) -> String {
@@ -230,6 +362,104 @@ pub(super) fn quoted_table_name_preview(
     }
 }
 
+/// Builds a statement that calls `routine` with one placeholder per argument.
+/// Set-returning functions are selected from so their rows land in the grid.
+pub(super) fn routine_call_template(kind: DatabaseKind, routine: &RoutineInfo) -> String {
+    let name = quoted_table_name_preview(kind, Some(&routine.schema), &routine.name);
+    let placeholders = sql_placeholders(kind, routine.argument_types.len());
+
+    match routine.kind {
+        RoutineKind::Procedure => format!("CALL {name}({placeholders});"),
+        RoutineKind::Function
+            if routine.return_type.starts_with("SETOF ")
+                || routine.return_type.starts_with("TABLE(") =>
+        {
+            format!("SELECT * FROM {name}({placeholders});")
+        }
+        RoutineKind::Function => format!("SELECT {name}({placeholders});"),
+    }
+}
+
+/// Builds a `SELECT *` over `table` with a row cap, for the tree's generate action.
+/// SELECT listing `columns` by name, or `*` when none are known. With
+/// `with_where`, a commented WHERE clause puts a placeholder on every column.
+pub(super) fn select_template(
+    kind: DatabaseKind,
+    schema: Option<&str>,
+    table: &str,
+    columns: &[String],
+    with_where: bool,
+) -> String {
+    let column_list = if columns.is_empty() {
+        "*".to_string()
+    } else {
+        quoted_column_list(kind, columns)
+    };
+    let table_name = quoted_table_name_preview(kind, schema, table);
+    if !with_where || columns.is_empty() {
+        return format!("SELECT {column_list} FROM {table_name} LIMIT 100;");
+    }
+
+    let conditions = columns
+        .iter()
+        .zip(1..)
+        .map(|(column, position)| {
+            format!(
+                "{} = {}",
+                quoted_column(kind, column),
+                sql_placeholder(kind, position)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(" AND ");
+    format!("SELECT {column_list}\nFROM {table_name}\n-- WHERE {conditions}\nLIMIT 100;")
+}
+
+/// Builds an INSERT into `table` naming every column, with one placeholder
+/// per column to fill in.
+pub(super) fn insert_template(
+    kind: DatabaseKind,
+    schema: Option<&str>,
+    table: &str,
+    columns: &[String],
+) -> String {
+    format!(
+        "INSERT INTO {} ({})\nVALUES ({});",
+        quoted_table_name_preview(kind, schema, table),
+        quoted_column_list(kind, columns),
+        sql_placeholders(kind, columns.len())
+    )
+}
+
+fn quoted_column_list(kind: DatabaseKind, columns: &[String]) -> String {
+    columns
+        .iter()
+        .map(|column| quoted_column(kind, column))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn quoted_column(kind: DatabaseKind, column: &str) -> String {
+    match kind {
+        DatabaseKind::Sqlite | DatabaseKind::Postgres => quote_sql_identifier(column),
+        DatabaseKind::MySql | DatabaseKind::ClickHouse => quote_clickhouse_identifier(column),
+    }
+}
+
+fn sql_placeholders(kind: DatabaseKind, count: usize) -> String {
+    (1..=count)
+        .map(|position| sql_placeholder(kind, position))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn sql_placeholder(kind: DatabaseKind, position: usize) -> String {
+    match kind {
+        DatabaseKind::MySql | DatabaseKind::ClickHouse => "?".to_string(),
+        DatabaseKind::Sqlite | DatabaseKind::Postgres => format!("${position}"),
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Filter helpers
 // ---------------------------------------------------------------------------
@@ -237,6 +467,7 @@ pub(super) fn quoted_table_name_preview(
 fn filter_connection_sections(
     sections: &[ExplorerConnectionSection],
     query: &str,
+    column_index: &HashMap<u64, Vec<SchemaColumn>>,
 ) -> Vec<ExplorerConnectionSection> {
     let query = query.trim();
     if query.is_empty() {
@@ -249,10 +480,14 @@ fn filter_connection_sections(
         .filter_map(|section| {
             let section_matches = matches_query(&section.name, &normalized)
                 || matches_query(&section.kind_label, &normalized);
+            let columns = column_index
+                .get(&section.session_id)
+                .map(Vec::as_slice)
+                .unwrap_or_default();
             let nodes = if section_matches {
                 section.nodes.clone()
             } else {
-                filter_nodes(&section.nodes, &normalized)
+                filter_nodes(&section.nodes, &normalized, columns)
             };
 
             if section_matches || !nodes.is_empty() {
@@ -266,14 +501,18 @@ fn filter_connection_sections(
         .collect()
 }
 
-fn filter_nodes(nodes: &[ExplorerNode], query: &str) -> Vec<ExplorerNode> {
+fn filter_nodes(
+    nodes: &[ExplorerNode],
+    query: &str,
+    columns: &[SchemaColumn],
+) -> Vec<ExplorerNode> {
     nodes
         .iter()
-        .filter_map(|node| filter_node(node, query))
+        .filter_map(|node| filter_node(node, query, columns))
         .collect()
 }
 
-fn filter_node(node: &ExplorerNode, query: &str) -> Option<ExplorerNode> {
+fn filter_node(node: &ExplorerNode, query: &str, columns: &[SchemaColumn]) -> Option<ExplorerNode> {
     match node.kind {
         ExplorerNodeKind::Schema => {
             let schema_matches = matches_query(&node.name, query);
@@ -281,7 +520,7 @@ fn filter_node(node: &ExplorerNode, query: &str) -> Option<ExplorerNode> {
             filtered.children = if schema_matches {
                 node.children.clone()
             } else {
-                filter_nodes(&node.children, query)
+                filter_nodes(&node.children, query, columns)
             };
 
             if schema_matches || !filtered.children.is_empty() {
@@ -290,8 +529,14 @@ fn filter_node(node: &ExplorerNode, query: &str) -> Option<ExplorerNode> {
                 None
             }
         }
-        ExplorerNodeKind::Table | ExplorerNodeKind::View => {
-            if matches_query(&node.name, query) || matches_query(&node.qualified_name, query) {
+        ExplorerNodeKind::Table
+        | ExplorerNodeKind::View
+        | ExplorerNodeKind::MaterializedView
+        | ExplorerNodeKind::ForeignTable => {
+            if matches_query(&node.name, query)
+                || matches_query(&node.qualified_name, query)
+                || !matching_columns(columns, node, query).is_empty()
+            {
                 Some(node.clone())
             } else {
                 None
@@ -304,13 +549,86 @@ fn matches_query(value: &str, query: &str) -> bool {
     value.to_ascii_lowercase().contains(query)
 }
 
+/// Names of the columns of table `node` that match the lowercased `query`.
+pub(super) fn matching_columns<'a>(
+    columns: &'a [SchemaColumn],
+    node: &ExplorerNode,
+    query: &str,
+) -> Vec<&'a str> {
+    columns
+        .iter()
+        .filter(|column| {
+            column.table_name == node.name
+                && column.schema == node.schema
+                && matches_query(&column.column_name, query)
+        })
+        .map(|column| column.column_name.as_str())
+        .collect()
+}
+
+/// Splits `value` around the first case-insensitive occurrence of the
+/// lowercased `query`, for highlighting filter matches in the tree.
+pub(super) fn split_filter_match<'a>(
+    value: &'a str,
+    query: &str,
+) -> Option<(&'a str, &'a str, &'a str)> {
+    if query.is_empty() {
+        return None;
+    }
+    // ASCII lowercasing keeps byte offsets, so the match indexes `value` directly.
+    let start = value.to_ascii_lowercase().find(query)?;
+    let end = start + query.len();
+    Some((&value[..start], &value[start..end], &value[end..]))
+}
+
+/// Short grey label for a table row, such as `~42k  3.2 MB`.
+pub(super) fn table_size_label(info: &TableCatalogInfo) -> Option<String> {
+    let rows = info.estimated_rows.map(|rows| {
+        format!(
+            "~{}",
+            compact_number(rows as f64, 1000.0, &["", "k", "M", "B"])
+        )
+    });
+    let size = info.total_bytes.map(compact_bytes);
+    match (rows, size) {
+        (Some(rows), Some(size)) => Some(format!("{rows}  {size}")),
+        (rows, size) => rows.or(size),
+    }
+}
+
+/// Binary-scaled size such as `3.2 MB`.
+fn compact_bytes(bytes: u64) -> String {
+    compact_number(bytes as f64, 1024.0, &[" B", " KB", " MB", " GB", " TB"])
+}
+
+/// One decimal below ten units, whole numbers above.
+fn compact_number(mut value: f64, step: f64, units: &[&str]) -> String {
+    let mut unit = 0;
+    while value >= step && unit + 1 < units.len() {
+        value /= step;
+        unit += 1;
+    }
+    if unit > 0 && value < 10.0 {
+        let rounded = format!("{value:.1}");
+        let rounded = rounded.strip_suffix(".0").unwrap_or(&rounded);
+        format!("{rounded}{}", units[unit])
+    } else {
+        format!("{}{}", value.round(), units[unit])
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{
         ExplorerConnectionSection, ExplorerNodeKind, filter_connection_sections, filter_node,
-        filter_nodes, matches_query,
+        filter_nodes, insert_template, matches_query, matching_columns, nest_database_sections,
+        routine_call_template, select_template, split_children, split_filter_match,
+        table_size_label,
+    };
+    use models::{
+        DatabaseKind, ExplorerNode, RoutineInfo, RoutineKind, SchemaColumn, TableCatalogInfo,
     };
-    use models::ExplorerNode;
+    use std::collections::HashMap;
 
     fn make_node(name: &str, kind: ExplorerNodeKind, children: Vec<ExplorerNode>) -> ExplorerNode {
         let schema = if kind == ExplorerNodeKind::Schema {
@@ -335,6 +653,7 @@ mod tests {
     fn make_section(name: &str, nodes: Vec<ExplorerNode>) -> ExplorerConnectionSection {
         ExplorerConnectionSection {
             session_id: 1,
+            parent_session_id: None,
             name: name.to_string(),
             kind_label: "PostgreSQL".to_string(),
             status: "Connected".to_string(),
@@ -381,7 +700,7 @@ mod tests {
             ),
         ];
 
-        let result = filter_connection_sections(&sections, "");
+        let result = filter_connection_sections(&sections, "", &HashMap::new());
         assert_eq!(result.len(), 2);
         assert_eq!(result[0].nodes[0].children.len(), 2);
         assert_eq!(result[1].nodes[0].children.len(), 1);
@@ -398,7 +717,7 @@ mod tests {
             )],
         )];
 
-        let result = filter_connection_sections(&sections, "   ");
+        let result = filter_connection_sections(&sections, "   ", &HashMap::new());
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].nodes[0].children.len(), 1);
     }
@@ -417,7 +736,7 @@ mod tests {
         );
         let sections = vec![make_section("db", vec![schema])];
 
-        let result = filter_connection_sections(&sections, "user");
+        let result = filter_connection_sections(&sections, "user", &HashMap::new());
         assert_eq!(result.len(), 1);
         let schema_children = &result[0].nodes[0].children;
         assert_eq!(schema_children.len(), 2);
@@ -438,7 +757,7 @@ mod tests {
         );
         let sections = vec![make_section("db", vec![schema])];
 
-        let result = filter_connection_sections(&sections, "analytics");
+        let result = filter_connection_sections(&sections, "analytics", &HashMap::new());
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].nodes[0].children.len(), 2);
     }
@@ -456,7 +775,7 @@ mod tests {
         );
         let nodes = vec![schema];
 
-        let result = filter_nodes(&nodes, "analytics");
+        let result = filter_nodes(&nodes, "analytics", &[]);
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].children.len(), 3);
     }
@@ -473,7 +792,7 @@ mod tests {
         );
         let nodes = vec![schema];
 
-        let result = filter_nodes(&nodes, "event");
+        let result = filter_nodes(&nodes, "event", &[]);
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].children.len(), 1);
         assert_eq!(result[0].children[0].name, "user_events");
@@ -493,7 +812,7 @@ mod tests {
             )],
         )];
 
-        let result = filter_connection_sections(&sections, "production");
+        let result = filter_connection_sections(&sections, "production", &HashMap::new());
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].nodes[0].children.len(), 2);
     }
@@ -509,7 +828,7 @@ mod tests {
             )],
         )];
 
-        let result = filter_connection_sections(&sections, "postgresql");
+        let result = filter_connection_sections(&sections, "postgresql", &HashMap::new());
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].nodes[0].children.len(), 1);
     }
@@ -528,22 +847,22 @@ mod tests {
             )],
         )];
 
-        let result = filter_connection_sections(&sections, "nonexistent");
+        let result = filter_connection_sections(&sections, "nonexistent", &HashMap::new());
         assert!(result.is_empty());
     }
 
     #[test]
     fn filter_table_node_matches_name() {
         let node = make_node("users", ExplorerNodeKind::Table, vec![]);
-        assert!(filter_node(&node, "user").is_some());
-        assert!(filter_node(&node, "order").is_none());
+        assert!(filter_node(&node, "user", &[]).is_some());
+        assert!(filter_node(&node, "order", &[]).is_none());
     }
 
     #[test]
     fn filter_view_node_matches_name() {
         let node = make_node("active_users", ExplorerNodeKind::View, vec![]);
-        assert!(filter_node(&node, "active").is_some());
-        assert!(filter_node(&node, "deleted").is_none());
+        assert!(filter_node(&node, "active", &[]).is_some());
+        assert!(filter_node(&node, "deleted", &[]).is_none());
     }
 
     #[test]
@@ -573,7 +892,7 @@ mod tests {
             ),
         ];
 
-        let result = filter_connection_sections(&sections, "user");
+        let result = filter_connection_sections(&sections, "user", &HashMap::new());
         assert_eq!(result.len(), 2);
         assert_eq!(result[0].nodes[0].children.len(), 1);
         assert_eq!(result[0].nodes[0].children[0].name, "users");
@@ -594,10 +913,231 @@ mod tests {
         );
         let nodes = vec![schema];
 
-        let result = filter_nodes(&nodes, "active_session");
+        let result = filter_nodes(&nodes, "active_session", &[]);
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].children.len(), 1);
         assert_eq!(result[0].children[0].name, "active_sessions");
         assert_eq!(result[0].children[0].kind, ExplorerNodeKind::View);
     }
+
+    #[test]
+    fn split_children_groups_relations_by_kind() {
+        let children = vec![
+            make_node("orders", ExplorerNodeKind::Table, vec![]),
+            make_node("daily_totals", ExplorerNodeKind::MaterializedView, vec![]),
+            make_node("remote_users", ExplorerNodeKind::ForeignTable, vec![]),
+            make_node("active_orders", ExplorerNodeKind::View, vec![]),
+            make_node("customers", ExplorerNodeKind::Table, vec![]),
+        ];
+
+        let groups = split_children(&children);
+        assert_eq!(groups.len(), 5);
+        assert_eq!(groups.tables[0].name, "customers");
+
+        let titles = groups
+            .into_sections()
+            .into_iter()
+            .map(|(title, nodes)| (title, nodes.len()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            titles,
+            vec![
+                ("Tables", 2),
+                ("Views", 1),
+                ("Matviews", 1),
+                ("Foreign tables", 1)
+            ]
+        );
+    }
+
+    fn make_routine(kind: RoutineKind, argument_types: &[&str], return_type: &str) -> RoutineInfo {
+        RoutineInfo {
+            schema: "public".to_string(),
+            name: "my_func".to_string(),
+            kind,
+            argument_types: argument_types.iter().map(|ty| ty.to_string()).collect(),
+            return_type: return_type.to_string(),
+            language: "plpgsql".to_string(),
+            identity: "my_func(integer,text)".to_string(),
+        }
+    }
+
+    #[test]
+    fn routine_call_template_numbers_postgres_placeholders() {
+        let routine = make_routine(RoutineKind::Function, &["integer", "text"], "integer");
+        assert_eq!(
+            routine_call_template(DatabaseKind::Postgres, &routine),
+            "SELECT \"public\".\"my_func\"($1, $2);"
+        );
+    }
+
+    #[test]
+    fn routine_call_template_selects_from_set_returning_functions() {
+        let routine = make_routine(RoutineKind::Function, &[], "SETOF orders");
+        assert_eq!(
+            routine_call_template(DatabaseKind::Postgres, &routine),
+            "SELECT * FROM \"public\".\"my_func\"();"
+        );
+    }
+
+    #[test]
+    fn routine_call_template_calls_mysql_procedures() {
+        let routine = make_routine(RoutineKind::Procedure, &["int"], "");
+        assert_eq!(
+            routine_call_template(DatabaseKind::MySql, &routine),
+            "CALL `public`.`my_func`(?);"
+        );
+    }
+
+    #[test]
+    fn select_template_caps_rows() {
+        assert_eq!(
+            select_template(DatabaseKind::Postgres, Some("public"), "orders", &[], false),
+            "SELECT * FROM \"public\".\"orders\" LIMIT 100;"
+        );
+    }
+
+    #[test]
+    fn select_template_lists_columns_and_comments_out_the_where_clause() {
+        let columns = vec!["id".to_string(), "total".to_string()];
+        assert_eq!(
+            select_template(
+                DatabaseKind::Postgres,
+                Some("public"),
+                "orders",
+                &columns,
+                false
+            ),
+            "SELECT \"id\", \"total\" FROM \"public\".\"orders\" LIMIT 100;"
+        );
+        assert_eq!(
+            select_template(DatabaseKind::MySql, Some("shop"), "orders", &columns, true),
+            "SELECT `id`, `total`\nFROM `shop`.`orders`\n-- WHERE `id` = ? AND `total` = ?\nLIMIT 100;"
+        );
+    }
+
+    #[test]
+    fn insert_template_lists_columns_with_driver_placeholders() {
+        let columns = vec!["id".to_string(), "total".to_string()];
+        assert_eq!(
+            insert_template(DatabaseKind::Postgres, Some("public"), "orders", &columns),
+            "INSERT INTO \"public\".\"orders\" (\"id\", \"total\")\nVALUES ($1, $2);"
+        );
+        assert_eq!(
+            insert_template(DatabaseKind::MySql, Some("shop"), "orders", &columns),
+            "INSERT INTO `shop`.`orders` (`id`, `total`)\nVALUES (?, ?);"
+        );
+    }
+
+    #[test]
+    fn filter_matches_tables_by_indexed_column_names() {
+        let sections = vec![make_section(
+            "prod",
+            vec![make_node(
+                "public",
+                ExplorerNodeKind::Schema,
+                vec![
+                    make_node("users", ExplorerNodeKind::Table, vec![]),
+                    make_node("orders", ExplorerNodeKind::Table, vec![]),
+                ],
+            )],
+        )];
+        let column = |schema: &str, table: &str, name: &str| SchemaColumn {
+            schema: Some(schema.to_string()),
+            table_name: table.to_string(),
+            column_name: name.to_string(),
+        };
+        let column_index = HashMap::from([(
+            1,
+            vec![
+                column("public", "users", "email"),
+                column("public", "orders", "shipping_email"),
+                column("archive", "users", "email_hash"),
+            ],
+        )]);
+
+        let result = filter_connection_sections(&sections, "email", &column_index);
+        let names = result[0].nodes[0]
+            .children
+            .iter()
+            .map(|node| node.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["users", "orders"]);
+        assert_eq!(
+            matching_columns(&column_index[&1], &result[0].nodes[0].children[0], "email"),
+            vec!["email"]
+        );
+        assert!(filter_connection_sections(&sections, "email", &HashMap::new()).is_empty());
+    }
+
+    #[test]
+    fn split_filter_match_keeps_original_case() {
+        assert_eq!(
+            split_filter_match("UserAccounts", "acc"),
+            Some(("User", "Acc", "ounts"))
+        );
+        assert_eq!(split_filter_match("orders", "acc"), None);
+        assert_eq!(split_filter_match("orders", ""), None);
+    }
+
+    #[test]
+    fn database_sessions_nest_under_their_parent() {
+        let parent = make_section("app", Vec::new());
+        let child = ExplorerConnectionSection {
+            session_id: 2,
+            parent_session_id: Some(1),
+            ..make_section("reporting", Vec::new())
+        };
+        let orphan = ExplorerConnectionSection {
+            session_id: 3,
+            parent_session_id: Some(9),
+            ..make_section("archive", Vec::new())
+        };
+
+        let nested = nest_database_sections(vec![parent, child, orphan]);
+        let shape = nested
+            .iter()
+            .map(|(section, children)| {
+                (
+                    section.name.as_str(),
+                    children
+                        .iter()
+                        .map(|child| child.name.as_str())
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            shape,
+            vec![("app", vec!["reporting"]), ("archive", Vec::new())]
+        );
+    }
+
+    #[test]
+    fn table_size_label_abbreviates_rows_and_bytes() {
+        let info = |estimated_rows, total_bytes| TableCatalogInfo {
+            name: "users".to_string(),
+            estimated_rows,
+            total_bytes,
+            comment: None,
+        };
+
+        assert_eq!(
+            table_size_label(&info(Some(42_300), Some(3_355_443))).as_deref(),
+            Some("~42k  3.2 MB")
+        );
+        assert_eq!(
+            table_size_label(&info(Some(1_000_000), Some(8192))).as_deref(),
+            Some("~1M  8 KB")
+        );
+        assert_eq!(
+            table_size_label(&info(Some(7), Some(512))).as_deref(),
+            Some("~7  512 B")
+        );
+        assert_eq!(
+            table_size_label(&info(None, Some(2_500_000_000))).as_deref(),
+            Some("2.3 GB")
+        );
+        assert_eq!(table_size_label(&info(None, None)), None);
+    }
 }
@@ -0,0 +1,245 @@
+use crate::app_state::APP_STATE;
+use crate::clipboard::copy_text;
+use dioxus::prelude::*;
+use models::{ConnectionRequest, PostgresFormData};
+use rfd::AsyncFileDialog;
+use services::DumpFormat;
+
+/// Database, schema, or table of a PostgreSQL session to dump.
+#[derive(Clone, PartialEq)]
+pub(super) struct PgDumpTarget {
+    pub(super) session_id: u64,
+    pub(super) schema: Option<String>,
+    pub(super) table: Option<String>,
+}
+
+#[component]
+pub(super) fn PgDumpModal(
+    target: PgDumpTarget,
+    mut pg_dump_target: Signal<Option<PgDumpTarget>>,
+) -> Element {
+    let mut format = use_signal(|| DumpFormat::Custom);
+    let mut output_path = use_signal(|| None::<String>);
+    let mut log = use_signal(Vec::<String>::new);
+    let mut running = use_signal(|| false);
+    let mut status = use_signal(String::new);
+
+    let config = APP_STATE
+        .read()
+        .session(target.session_id)
+        .and_then(|session| match &session.request {
+            ConnectionRequest::Postgres(config) => Some(config.clone()),
+            _ => None,
+        });
+    let Some(config) = config else {
+        return rsx! {};
+    };
+    let object_label = match (&target.schema, &target.table) {
+        (Some(schema), Some(table)) => format!("{schema}.{table}"),
+        (None, Some(table)) => table.clone(),
+        (Some(schema), None) => format!("schema {schema}"),
+        (None, None) => format!("database {}", config.database),
+    };
+    let output = output_path().unwrap_or_else(|| default_dump_path(&config, &target, format()));
+    let command = services::pg_dump_command(
+        &config,
+        target.schema.as_deref(),
+        target.table.as_deref(),
+        format(),
+        &output,
+    );
+    let command_line = command.command_line();
+    let log_text = log.read().join("\n");
+    let tunnelled = config
+        .ssh_tunnel
+        .as_ref()
+        .is_some_and(|tunnel| tunnel.is_configured());
+
+    rsx! {
+        div {
+            class: "settings-modal__backdrop",
+            onclick: move |_| {
+                if !running() {
+                    pg_dump_target.set(None);
+                }
+            },
+            div {
+                class: "settings-modal table-modal",
+                onclick: move |event| event.stop_propagation(),
+                div {
+                    class: "settings-modal__header",
+                    div {
+                        class: "settings-modal__header-copy",
+                        h2 { class: "settings-modal__title", "pg_dump · {object_label}" }
+                        p {
+                            class: "settings-modal__hint",
+                            "Copy the command to run it yourself, or run it here. The password is passed through PGPASSWORD and never shown."
+                        }
+                    }
+                    button {
+                        class: "button button--ghost button--small",
+                        disabled: running(),
+                        onclick: move |_| pg_dump_target.set(None),
+                        "Close"
+                    }
+                }
+
+                div {
+                    class: "table-modal__body",
+                    div {
+                        class: "table-modal__grid",
+                        div {
+                            class: "field",
+                            span { class: "field__label", "Format" }
+                            select {
+                                class: "input",
+                                disabled: running(),
+                                onchange: move |event| {
+                                    if let Some(selected) = DumpFormat::ALL
+                                        .into_iter()
+                                        .find(|candidate| candidate.label() == event.value())
+                                    {
+                                        format.set(selected);
+                                    }
+                                },
+                                for candidate in DumpFormat::ALL {
+                                    option {
+                                        value: candidate.label(),
+                                        selected: candidate == format(),
+                                        "{candidate.label()}"
+                                    }
+                                }
+                            }
+                        }
+                        div {
+                            class: "field",
+                            span {
+                                class: "field__label",
+                                if format() == DumpFormat::Directory { "Output directory" } else { "Output file" }
+                            }
+                            div {
+                                class: "pg-dump__output",
+                                input {
+                                    class: "input",
+                                    value: "{output}",
+                                    disabled: running(),
+                                    oninput: move |event| output_path.set(Some(event.value())),
+                                }
+                                button {
+                                    class: "button button--ghost button--small",
+                                    disabled: running(),
+                                    onclick: {
+                                        let output = output.clone();
+                                        move |_| {
+                                            let output = output.clone();
+                                            spawn(async move {
+                                                let dialog = AsyncFileDialog::new().set_file_name(&output);
+                                                let picked = if format() == DumpFormat::Directory {
+                                                    dialog.pick_folder().await
+                                                } else {
+                                                    dialog.save_file().await
+                                                };
+                                                if let Some(picked) = picked {
+                                                    output_path.set(Some(picked.path().display().to_string()));
+                                                }
+                                            });
+                                        }
+                                    },
+                                    "Choose…"
+                                }
+                            }
+                        }
+                    }
+
+                    if tunnelled {
+                        p {
+                            class: "table-modal__hint",
+                            "This connection uses an SSH tunnel; pg_dump connects to the host directly and does not go through it."
+                        }
+                    }
+
+                    pre { class: "table-modal__preview-sql", "{command_line}" }
+
+                    if !log_text.is_empty() {
+                        pre { class: "pg-dump__log", "{log_text}" }
+                    }
+                    if !status().is_empty() {
+                        p { class: "table-modal__hint", "{status}" }
+                    }
+
+                    div {
+                        class: "table-modal__actions",
+                        button {
+                            class: "button button--ghost",
+                            onclick: {
+                                let command_line = command_line.clone();
+                                move |_| {
+                                    let command_line = command_line.clone();
+                                    spawn(async move {
+                                        let message = match copy_text(&command_line).await {
+                                            Ok(()) => "Copied the pg_dump command.".to_string(),
+                                            Err(err) => err,
+                                        };
+                                        status.set(message);
+                                    });
+                                }
+                            },
+                            "Copy Command"
+                        }
+                        button {
+                            class: "button button--primary",
+                            disabled: running() || output.trim().is_empty(),
+                            onclick: {
+                                let output = output.clone();
+                                move |_| {
+                                    let command = command.clone();
+                                    let output = output.clone();
+                                    spawn(async move {
+                                        running.set(true);
+                                        log.set(Vec::new());
+                                        status.set("Running pg_dump…".to_string());
+                                        let result = services::run_pg_dump(command, |line| {
+                                            log.with_mut(|lines| lines.push(line));
+                                        })
+                                        .await;
+                                        running.set(false);
+                                        status.set(match result {
+                                            Ok(()) => format!("Dump written to {output}."),
+                                            Err(err) => err,
+                                        });
+                                    });
+                                }
+                            },
+                            if running() { "Running…" } else { "Run" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// `<database>_<table or schema><extension>` in the working directory.
+fn default_dump_path(
+    config: &PostgresFormData,
+    target: &PgDumpTarget,
+    format: DumpFormat,
+) -> String {
+    let object = target
+        .table
+        .as_deref()
+        .or(target.schema.as_deref())
+        .map(|name| format!("_{name}"))
+        .unwrap_or_default();
+    let stem = format!("{}{object}", config.database)
+        .chars()
+        .map(|ch| {
+            if ch.is_alphanumeric() || ch == '-' {
+                ch
+            } else {
+                '_'
+            }
+        })
+        .collect::<String>();
+    format!("{stem}{}", format.file_extension())
+}
@@ -0,0 +1,328 @@
+use super::quote_sql_identifier;
+use crate::app_state::session_connection;
+use crate::screens::workspace::actions::{append_to_tab_sql, ensure_tab_for_session};
+use dioxus::prelude::*;
+use models::{QueryTabState, TableGrant, TablePreviewSource};
+
+/// Privileges shown as columns; anything else is listed under "Other".
+const PRIVILEGE_COLUMNS: [&str; 4] = ["SELECT", "INSERT", "UPDATE", "DELETE"];
+const GRANTABLE_PRIVILEGES: [&str; 8] = [
+    "SELECT",
+    "INSERT",
+    "UPDATE",
+    "DELETE",
+    "TRUNCATE",
+    "REFERENCES",
+    "TRIGGER",
+    "ALL",
+];
+
+/// PostgreSQL table whose granted privileges are shown.
+#[derive(Clone, PartialEq)]
+pub(super) struct PrivilegesTarget {
+    pub(super) session_id: u64,
+    pub(super) source: TablePreviewSource,
+}
+
+/// Privileges one role holds on the table.
+#[derive(Clone, Debug, PartialEq)]
+struct RolePrivileges {
+    grantee: String,
+    grants: Vec<TableGrant>,
+}
+
+impl RolePrivileges {
+    fn cell(&self, privilege: &str) -> &'static str {
+        match self
+            .grants
+            .iter()
+            .find(|grant| grant.privilege == privilege)
+        {
+            Some(grant) if grant.is_grantable => "✓ (grant)",
+            Some(_) => "✓",
+            None => "",
+        }
+    }
+
+    fn others(&self) -> String {
+        self.grants
+            .iter()
+            .filter(|grant| !PRIVILEGE_COLUMNS.contains(&grant.privilege.as_str()))
+            .map(|grant| grant.privilege.clone())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+#[component]
+pub(super) fn PrivilegesModal(
+    target: PrivilegesTarget,
+    mut privileges_target: Signal<Option<PrivilegesTarget>>,
+    tabs: Signal<Vec<QueryTabState>>,
+    active_tab_id: Signal<u64>,
+    next_tab_id: Signal<u64>,
+) -> Element {
+    let mut grants = use_signal(|| None::<Result<Vec<TableGrant>, String>>);
+    let mut roles = use_signal(Vec::<String>::new);
+    let mut grant = use_signal(|| true);
+    let mut privilege = use_signal(|| "SELECT".to_string());
+    let mut role = use_signal(String::new);
+
+    let session_id = target.session_id;
+    let source = target.source.clone();
+    use_effect(move || {
+        let source = source.clone();
+        spawn(async move {
+            let Some(connection) = session_connection(session_id) else {
+                grants.set(Some(Err("Connection is no longer open.".to_string())));
+                return;
+            };
+            let result =
+                services::load_table_grants(connection.clone(), source.schema, source.table_name)
+                    .await
+                    .map_err(|err| err.to_string());
+            grants.set(Some(result));
+            // Only fills the role suggestions, so a failure is not shown.
+            if let Ok(loaded) = services::load_roles(connection).await {
+                roles.set(loaded.into_iter().map(|role| role.name).collect());
+            }
+        });
+    });
+
+    let statement = privilege_statement(
+        grant(),
+        &privilege(),
+        &target.source.qualified_name,
+        &role(),
+    );
+    let has_statement = statement.is_some();
+
+    rsx! {
+        div {
+            class: "settings-modal__backdrop",
+            onclick: move |_| privileges_target.set(None),
+            div {
+                class: "settings-modal table-modal",
+                onclick: move |event| event.stop_propagation(),
+                div {
+                    class: "settings-modal__header",
+                    div {
+                        class: "settings-modal__header-copy",
+                        h2 { class: "settings-modal__title", "Privileges · {target.source.table_name}" }
+                        p {
+                            class: "settings-modal__hint",
+                            "Grants from information_schema.role_table_grants where one of your roles is the grantor or grantee."
+                        }
+                    }
+                    button {
+                        class: "button button--ghost button--small",
+                        onclick: move |_| privileges_target.set(None),
+                        "Close"
+                    }
+                }
+
+                div {
+                    class: "table-modal__body",
+                    match grants() {
+                        None => rsx! {
+                            p { class: "table-modal__hint", "Loading privileges…" }
+                        },
+                        Some(Err(err)) => rsx! {
+                            p { class: "table-modal__error", "{err}" }
+                        },
+                        Some(Ok(grants)) if grants.is_empty() => rsx! {
+                            p { class: "table-modal__hint", "No privileges are granted on this table." }
+                        },
+                        Some(Ok(grants)) => rsx! {
+                            div {
+                                class: "table-modal__table-wrap",
+                                table {
+                                    class: "table-modal__table",
+                                    thead {
+                                        tr {
+                                            th { "Role" }
+                                            for column in PRIVILEGE_COLUMNS {
+                                                th { "{column}" }
+                                            }
+                                            th { "Other" }
+                                        }
+                                    }
+                                    tbody {
+                                        for row in group_grants(grants) {
+                                            tr {
+                                                key: "{row.grantee}",
+                                                td { "{row.grantee}" }
+                                                for column in PRIVILEGE_COLUMNS {
+                                                    td { "{row.cell(column)}" }
+                                                }
+                                                td { "{row.others()}" }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        },
+                    }
+
+                    div {
+                        class: "table-modal__toolbar",
+                        div {
+                            class: "table-modal__segmented",
+                            for (option, label) in [(true, "GRANT"), (false, "REVOKE")] {
+                                button {
+                                    class: if grant() == option {
+                                        "button button--small button--active"
+                                    } else {
+                                        "button button--ghost button--small"
+                                    },
+                                    onclick: move |_| grant.set(option),
+                                    "{label}"
+                                }
+                            }
+                        }
+                        select {
+                            class: "input",
+                            onchange: move |event| privilege.set(event.value()),
+                            for option in GRANTABLE_PRIVILEGES {
+                                option {
+                                    value: "{option}",
+                                    selected: privilege() == option,
+                                    "{option}"
+                                }
+                            }
+                        }
+                        input {
+                            class: "input",
+                            list: "privileges-roles",
+                            value: "{role}",
+                            placeholder: "Role, or public",
+                            oninput: move |event| role.set(event.value()),
+                        }
+                        datalist {
+                            id: "privileges-roles",
+                            option { value: "public" }
+                            for name in roles() {
+                                option { value: "{name}" }
+                            }
+                        }
+                    }
+                    if let Some(statement) = statement.clone() {
+                        pre { class: "table-modal__preview-sql", "{statement}" }
+                    }
+
+                    div {
+                        class: "table-modal__actions",
+                        button {
+                            class: "button button--primary",
+                            disabled: !has_statement,
+                            onclick: move |_| {
+                                let Some(statement) = statement.clone() else {
+                                    return;
+                                };
+                                let tab_id =
+                                    ensure_tab_for_session(tabs, active_tab_id, next_tab_id, session_id);
+                                if tab_id == 0 {
+                                    return;
+                                }
+                                append_to_tab_sql(
+                                    tabs,
+                                    tab_id,
+                                    statement,
+                                    "Review the statement, then run it".to_string(),
+                                );
+                                privileges_target.set(None);
+                            },
+                            "Insert into Editor"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// One row per role, in the order the grants were listed.
+fn group_grants(grants: Vec<TableGrant>) -> Vec<RolePrivileges> {
+    let mut rows: Vec<RolePrivileges> = Vec::new();
+    for grant in grants {
+        match rows.iter_mut().find(|row| row.grantee == grant.grantee) {
+            Some(row) => row.grants.push(grant),
+            None => rows.push(RolePrivileges {
+                grantee: grant.grantee.clone(),
+                grants: vec![grant],
+            }),
+        }
+    }
+    rows
+}
+
+/// `GRANT`/`REVOKE` of `privilege` on the table for `role`, or `None` until a
+/// role is entered. `public` stays a bare keyword; other roles are quoted.
+fn privilege_statement(
+    grant: bool,
+    privilege: &str,
+    qualified_name: &str,
+    role: &str,
+) -> Option<String> {
+    let role = role.trim();
+    if role.is_empty() {
+        return None;
+    }
+    let role = if role.eq_ignore_ascii_case("public") {
+        "public".to_string()
+    } else {
+        quote_sql_identifier(role)
+    };
+    let qualified_name = qualified_name.trim().trim_end_matches(';');
+    Some(if grant {
+        format!(
+            "grant {} on table {qualified_name} to {role};",
+            privilege.to_lowercase()
+        )
+    } else {
+        format!(
+            "revoke {} on table {qualified_name} from {role};",
+            privilege.to_lowercase()
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{group_grants, privilege_statement};
+    use models::TableGrant;
+
+    #[test]
+    fn privilege_statement_quotes_roles_but_not_public() {
+        assert_eq!(
+            privilege_statement(true, "SELECT", "\"public\".\"orders\"", "Reporting"),
+            Some("grant select on table \"public\".\"orders\" to \"Reporting\";".to_string())
+        );
+        assert_eq!(
+            privilege_statement(false, "ALL", "\"public\".\"orders\"", " PUBLIC "),
+            Some("revoke all on table \"public\".\"orders\" from public;".to_string())
+        );
+        assert_eq!(privilege_statement(true, "SELECT", "orders", "  "), None);
+    }
+
+    #[test]
+    fn group_grants_collects_each_roles_privileges() {
+        let grant = |grantee: &str, privilege: &str, is_grantable: bool| TableGrant {
+            grantee: grantee.to_string(),
+            privilege: privilege.to_string(),
+            is_grantable,
+        };
+        let rows = group_grants(vec![
+            grant("app", "INSERT", false),
+            grant("app", "SELECT", false),
+            grant("owner", "SELECT", true),
+            grant("owner", "TRIGGER", true),
+        ]);
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].cell("SELECT"), "✓");
+        assert_eq!(rows[0].cell("DELETE"), "");
+        assert_eq!(rows[1].cell("SELECT"), "✓ (grant)");
+        assert_eq!(rows[1].others(), "TRIGGER");
+    }
+}
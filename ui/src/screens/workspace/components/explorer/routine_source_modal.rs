@@ -0,0 +1,124 @@
+use super::routine_call_template;
+use crate::app_state::session_connection;
+use crate::screens::workspace::actions::{append_to_tab_sql, ensure_tab_for_session};
+use crate::screens::workspace::components::SqlHighlightContent;
+use dioxus::prelude::*;
+use models::{DatabaseKind, QueryTabState, RoutineInfo};
+
+#[derive(Clone, PartialEq)]
+pub(super) struct RoutineSourceTarget {
+    pub(super) session_id: u64,
+    pub(super) kind: DatabaseKind,
+    pub(super) routine: RoutineInfo,
+}
+
+#[component]
+pub(super) fn RoutineSourceModal(
+    target: RoutineSourceTarget,
+    tabs: Signal<Vec<QueryTabState>>,
+    active_tab_id: Signal<u64>,
+    next_tab_id: Signal<u64>,
+    mut routine_source: Signal<Option<RoutineSourceTarget>>,
+) -> Element {
+    let definition = use_resource({
+        let session_id = target.session_id;
+        let routine = target.routine.clone();
+        move || {
+            let routine = routine.clone();
+            async move {
+                let connection = session_connection(session_id)
+                    .ok_or_else(|| "Connection is no longer open.".to_string())?;
+                services::load_routine_definition(connection, routine)
+                    .await
+                    .map_err(|err| err.to_string())
+            }
+        }
+    });
+    let routine = target.routine.clone();
+    let signature = routine.signature();
+    let details = routine.summary();
+    let call_template = routine_call_template(target.kind, &routine);
+
+    rsx! {
+        div {
+            class: "settings-modal__backdrop",
+            onclick: move |_| routine_source.set(None),
+            div {
+                class: "settings-modal table-modal",
+                onclick: move |event| event.stop_propagation(),
+                div {
+                    class: "settings-modal__header",
+                    div {
+                        class: "settings-modal__header-copy",
+                        h2 { class: "settings-modal__title", "{routine.kind.label()} {signature}" }
+                        p { class: "settings-modal__hint", "{details}" }
+                    }
+                    button {
+                        class: "button button--ghost button--small",
+                        onclick: move |_| routine_source.set(None),
+                        "Close"
+                    }
+                }
+
+                div {
+                    class: "table-modal__body",
+                    match definition() {
+                        None => rsx! {
+                            p { class: "table-modal__hint", "Loading definition…" }
+                        },
+                        Some(Err(err)) => rsx! {
+                            p { class: "table-modal__error", "{err}" }
+                        },
+                        Some(Ok(sql)) => rsx! {
+                            pre {
+                                class: "table-modal__preview-sql routine-source__code",
+                                SqlHighlightContent {
+                                    sql,
+                                    inline_cursor_position: None,
+                                    inline_suffix: None,
+                                }
+                            }
+                        },
+                    }
+
+                    div {
+                        class: "table-modal__actions",
+                        button {
+                            class: "button button--ghost",
+                            onclick: move |_| routine_source.set(None),
+                            "Close"
+                        }
+                        button {
+                            class: "button button--primary",
+                            title: "{call_template}",
+                            onclick: {
+                                let session_id = target.session_id;
+                                let name = routine.name.clone();
+                                let call_template = call_template.clone();
+                                move |_| {
+                                    let tab_id = ensure_tab_for_session(
+                                        tabs,
+                                        active_tab_id,
+                                        next_tab_id,
+                                        session_id,
+                                    );
+                                    if tab_id == 0 {
+                                        return;
+                                    }
+                                    append_to_tab_sql(
+                                        tabs,
+                                        tab_id,
+                                        call_template.clone(),
+                                        format!("Inserted call to {name}"),
+                                    );
+                                    routine_source.set(None);
+                                }
+                            },
+                            "Insert Call"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
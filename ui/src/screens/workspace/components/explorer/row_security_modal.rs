@@ -0,0 +1,212 @@
+use crate::app_state::session_connection;
+use crate::screens::workspace::actions::{read_only_mode_block_status, read_only_mode_enabled};
+use dioxus::prelude::*;
+use models::{TablePreviewSource, TableRowSecurity};
+use rfd::{AsyncMessageDialog, MessageButtons, MessageDialogResult, MessageLevel};
+
+/// PostgreSQL table whose row security policies are shown.
+#[derive(Clone, PartialEq)]
+pub(super) struct RowSecurityTarget {
+    pub(super) session_id: u64,
+    pub(super) source: TablePreviewSource,
+}
+
+#[component]
+pub(super) fn RowSecurityModal(
+    target: RowSecurityTarget,
+    mut row_security_target: Signal<Option<RowSecurityTarget>>,
+) -> Element {
+    let mut security = use_signal(|| None::<Result<TableRowSecurity, String>>);
+    let mut reload = use_signal(|| 0_u64);
+    let mut error = use_signal(String::new);
+    let mut inflight = use_signal(|| false);
+    let read_only_mode = read_only_mode_enabled();
+
+    let session_id = target.session_id;
+    let source = target.source.clone();
+    use_effect(move || {
+        reload();
+        let source = source.clone();
+        spawn(async move {
+            let result = match session_connection(session_id) {
+                Some(connection) => {
+                    services::load_table_row_security(connection, source.schema, source.table_name)
+                        .await
+                        .map_err(|err| err.to_string())
+                }
+                None => Err("Connection is no longer open.".to_string()),
+            };
+            security.set(Some(result));
+        });
+    });
+
+    let enabled = matches!(&*security.read(), Some(Ok(state)) if state.enabled);
+
+    rsx! {
+        div {
+            class: "settings-modal__backdrop",
+            onclick: move |_| {
+                if !inflight() {
+                    row_security_target.set(None);
+                }
+            },
+            div {
+                class: "settings-modal table-modal table-modal--wide",
+                onclick: move |event| event.stop_propagation(),
+                div {
+                    class: "settings-modal__header",
+                    div {
+                        class: "settings-modal__header-copy",
+                        h2 { class: "settings-modal__title", "Row Security · {target.source.table_name}" }
+                        p {
+                            class: "settings-modal__hint",
+                            "Policies from pg_policy. They only filter rows while row-level security is enabled on the table."
+                        }
+                    }
+                    button {
+                        class: "button button--ghost button--small",
+                        disabled: inflight(),
+                        onclick: move |_| row_security_target.set(None),
+                        "Close"
+                    }
+                }
+
+                div {
+                    class: "table-modal__body",
+                    match security() {
+                        None => rsx! {
+                            p { class: "table-modal__hint", "Loading policies…" }
+                        },
+                        Some(Err(err)) => rsx! {
+                            p { class: "table-modal__error", "{err}" }
+                        },
+                        Some(Ok(state)) => rsx! {
+                            dl {
+                                class: "table-modal__details",
+                                dt { "Row-level security" }
+                                dd { if state.enabled { "enabled" } else { "disabled" } }
+                                dt { "Applies to owner" }
+                                dd { if state.forced { "yes (forced)" } else { "no" } }
+                            }
+                            if state.policies.is_empty() {
+                                p {
+                                    class: "table-modal__hint",
+                                    if state.enabled {
+                                        "No policies are defined, so only the owner and superusers can see rows."
+                                    } else {
+                                        "No policies are defined."
+                                    }
+                                }
+                            } else {
+                                div {
+                                    class: "table-modal__table-wrap",
+                                    table {
+                                        class: "table-modal__table",
+                                        thead {
+                                            tr {
+                                                th { "Policy" }
+                                                th { "Command" }
+                                                th { "Type" }
+                                                th { "Roles" }
+                                                th { "Using" }
+                                                th { "With check" }
+                                            }
+                                        }
+                                        tbody {
+                                            for policy in state.policies {
+                                                tr {
+                                                    key: "{policy.name}",
+                                                    td { "{policy.name}" }
+                                                    td { "{policy.command}" }
+                                                    td { if policy.permissive { "permissive" } else { "restrictive" } }
+                                                    td { {policy.roles.join(", ")} }
+                                                    td {
+                                                        class: "table-modal__cell--code",
+                                                        {policy.using_expr.clone().unwrap_or_default()}
+                                                    }
+                                                    td {
+                                                        class: "table-modal__cell--code",
+                                                        {policy.with_check_expr.clone().unwrap_or_default()}
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        },
+                    }
+
+                    if !error().is_empty() {
+                        p { class: "table-modal__error", "{error}" }
+                    }
+
+                    div {
+                        class: "table-modal__actions",
+                        button {
+                            class: if enabled { "button button--ghost" } else { "button button--primary" },
+                            disabled: inflight() || read_only_mode || !matches!(security(), Some(Ok(_))),
+                            title: if read_only_mode {
+                                "Changing row security is blocked by read-only mode"
+                            } else {
+                                ""
+                            },
+                            onclick: {
+                                let source = target.source.clone();
+                                move |_| {
+                                    if read_only_mode_enabled() {
+                                        error.set(read_only_mode_block_status("changing row security"));
+                                        return;
+                                    }
+                                    let source = source.clone();
+                                    let enable = !enabled;
+                                    spawn(async move {
+                                        let confirmation = AsyncMessageDialog::new()
+                                            .set_title(if enable { "Enable Row Security" } else { "Disable Row Security" })
+                                            .set_description(if enable {
+                                                format!(
+                                                    "Enable row-level security on {}? Roles other than the owner only see rows a policy allows; with no policies they see none.",
+                                                    source.qualified_name
+                                                )
+                                            } else {
+                                                format!(
+                                                    "Disable row-level security on {}? Its policies stop applying and every role with table privileges sees all rows.",
+                                                    source.qualified_name
+                                                )
+                                            })
+                                            .set_buttons(MessageButtons::YesNo)
+                                            .set_level(MessageLevel::Warning)
+                                            .show()
+                                            .await;
+                                        if confirmation != MessageDialogResult::Yes {
+                                            return;
+                                        }
+
+                                        let Some(connection) = session_connection(session_id) else {
+                                            error.set("Connection is no longer open.".to_string());
+                                            return;
+                                        };
+
+                                        inflight.set(true);
+                                        let result =
+                                            services::set_row_level_security(connection, source, enable).await;
+                                        inflight.set(false);
+
+                                        match result {
+                                            Ok(()) => {
+                                                error.set(String::new());
+                                                reload += 1;
+                                            }
+                                            Err(err) => error.set(err.to_string()),
+                                        }
+                                    });
+                                }
+                            },
+                            if enabled { "Disable Row Security" } else { "Enable Row Security" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
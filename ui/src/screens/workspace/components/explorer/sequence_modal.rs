@@ -0,0 +1,173 @@
+use crate::app_state::session_connection;
+use crate::screens::workspace::actions::{read_only_mode_block_status, read_only_mode_enabled};
+use dioxus::prelude::*;
+use models::SequenceInfo;
+use rfd::{AsyncMessageDialog, MessageButtons, MessageDialogResult, MessageLevel};
+
+#[derive(Clone, PartialEq)]
+pub(super) struct SequenceTarget {
+    pub(super) session_id: u64,
+    pub(super) sequence: SequenceInfo,
+}
+
+#[component]
+pub(super) fn SequenceModal(
+    target: SequenceTarget,
+    mut selected_sequence: Signal<Option<SequenceTarget>>,
+    on_changed: EventHandler<()>,
+) -> Element {
+    let sequence = target.sequence.clone();
+    let mut value_draft = use_signal(|| {
+        sequence
+            .last_value
+            .map(|value| value.to_string())
+            .unwrap_or_default()
+    });
+    let mut set_error = use_signal(String::new);
+    let mut set_inflight = use_signal(|| false);
+    let read_only_mode = read_only_mode_enabled();
+    let parsed_value = value_draft().trim().parse::<i64>().ok();
+    let can_submit = parsed_value.is_some() && !set_inflight() && !read_only_mode;
+    let last_value = sequence
+        .last_value
+        .map(|value| value.to_string())
+        .unwrap_or_else(|| "not yet used".to_string());
+    let limits = sequence.limits();
+    let owned_by = sequence
+        .owned_by
+        .clone()
+        .unwrap_or_else(|| "none".to_string());
+
+    rsx! {
+        div {
+            class: "settings-modal__backdrop",
+            onclick: move |_| {
+                if !set_inflight() {
+                    selected_sequence.set(None);
+                }
+            },
+            div {
+                class: "settings-modal table-modal table-modal--compact",
+                onclick: move |event| event.stop_propagation(),
+                div {
+                    class: "settings-modal__header",
+                    div {
+                        class: "settings-modal__header-copy",
+                        h2 { class: "settings-modal__title", "Sequence {sequence.name}" }
+                        p { class: "settings-modal__hint", "{sequence.schema}.{sequence.name}" }
+                    }
+                    button {
+                        class: "button button--ghost button--small",
+                        disabled: set_inflight(),
+                        onclick: move |_| selected_sequence.set(None),
+                        "Close"
+                    }
+                }
+
+                div {
+                    class: "table-modal__body",
+                    dl {
+                        class: "table-modal__details",
+                        dt { "Data type" }
+                        dd { "{sequence.data_type}" }
+                        dt { "Last value" }
+                        dd { "{last_value}" }
+                        dt { "Increment" }
+                        dd { "{sequence.increment}" }
+                        dt { "Range" }
+                        dd { "{limits}" }
+                        dt { "Owned by" }
+                        dd { "{owned_by}" }
+                    }
+
+                    div {
+                        class: "field",
+                        span { class: "field__label", "Set value…" }
+                        input {
+                            class: "input",
+                            r#type: "number",
+                            value: value_draft(),
+                            placeholder: "1000",
+                            oninput: move |event| value_draft.set(event.value()),
+                        }
+                    }
+                    p {
+                        class: "table-modal__hint",
+                        "The next nextval() returns this value plus the increment. Use it to resync a serial column after an import."
+                    }
+
+                    if !set_error().is_empty() {
+                        p { class: "table-modal__error", "{set_error}" }
+                    }
+
+                    div {
+                        class: "table-modal__actions",
+                        button {
+                            class: "button button--ghost",
+                            disabled: set_inflight(),
+                            onclick: move |_| selected_sequence.set(None),
+                            "Cancel"
+                        }
+                        button {
+                            class: "button button--primary",
+                            disabled: !can_submit,
+                            onclick: {
+                                let target = target.clone();
+                                move |_| {
+                                    let Some(value) = value_draft().trim().parse::<i64>().ok() else {
+                                        return;
+                                    };
+                                    if read_only_mode_enabled() {
+                                        set_error.set(read_only_mode_block_status("setting sequence values"));
+                                        return;
+                                    }
+                                    let target = target.clone();
+                                    spawn(async move {
+                                        let sequence = target.sequence;
+                                        let confirmation = AsyncMessageDialog::new()
+                                            .set_title("Set Sequence Value")
+                                            .set_description(format!(
+                                                "Set {}.{} to {value}? Rows inserted afterwards take ids from the new value, which can collide with existing keys.",
+                                                sequence.schema, sequence.name
+                                            ))
+                                            .set_buttons(MessageButtons::YesNo)
+                                            .set_level(MessageLevel::Warning)
+                                            .show()
+                                            .await;
+                                        if confirmation != MessageDialogResult::Yes {
+                                            return;
+                                        }
+
+                                        let Some(connection) = session_connection(target.session_id) else {
+                                            set_error.set("Connection is no longer open.".to_string());
+                                            return;
+                                        };
+
+                                        set_inflight.set(true);
+                                        let result = services::set_sequence_value(
+                                            connection,
+                                            sequence.schema.clone(),
+                                            sequence.name.clone(),
+                                            value,
+                                        )
+                                        .await;
+                                        set_inflight.set(false);
+
+                                        match result {
+                                            Ok(_) => {
+                                                selected_sequence.set(None);
+                                                on_changed.call(());
+                                            }
+                                            Err(err) => set_error.set(err.to_string()),
+                                        }
+                                    });
+                                }
+                            },
+                            if set_inflight() { "Setting…" } else { "Set Value" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,304 @@
+use crate::app_state::session_connection;
+use crate::screens::workspace::components::format_bytes;
+use dioxus::prelude::*;
+use models::{SchemaSizeReport, TableSizeDetail};
+
+/// Schema of a PostgreSQL session whose table sizes are shown.
+#[derive(Clone, PartialEq)]
+pub(super) struct SizeReportTarget {
+    pub(super) session_id: u64,
+    pub(super) schema: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SizeColumn {
+    Name,
+    Rows,
+    Total,
+    Table,
+    Indexes,
+    Toast,
+}
+
+impl SizeColumn {
+    const ALL: [SizeColumn; 6] = [
+        SizeColumn::Name,
+        SizeColumn::Rows,
+        SizeColumn::Total,
+        SizeColumn::Table,
+        SizeColumn::Indexes,
+        SizeColumn::Toast,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            SizeColumn::Name => "Table",
+            SizeColumn::Rows => "Est. rows",
+            SizeColumn::Total => "Total",
+            SizeColumn::Table => "Data",
+            SizeColumn::Indexes => "Indexes",
+            SizeColumn::Toast => "TOAST",
+        }
+    }
+}
+
+/// Sortable breakdown of every table's size in one schema, each with a bar
+/// relative to the largest table.
+#[component]
+pub(super) fn SizeReportModal(
+    target: SizeReportTarget,
+    mut size_report: Signal<Option<SizeReportTarget>>,
+) -> Element {
+    let mut report = use_signal(|| None::<Result<SchemaSizeReport, String>>);
+    let mut sort = use_signal(|| (SizeColumn::Total, true));
+    let mut reload = use_signal(|| 0_u64);
+
+    let session_id = target.session_id;
+    let schema = target.schema.clone();
+    use_effect(move || {
+        reload();
+        let schema = schema.clone();
+        report.set(None);
+        spawn(async move {
+            let result = match session_connection(session_id) {
+                Some(connection) => services::load_schema_size_report(connection, schema)
+                    .await
+                    .map_err(|err| err.to_string()),
+                None => Err("Connection is no longer open.".to_string()),
+            };
+            report.set(Some(result));
+        });
+    });
+
+    let (sort_column, descending) = sort();
+
+    rsx! {
+        div {
+            class: "settings-modal__backdrop",
+            onclick: move |_| size_report.set(None),
+            div {
+                class: "settings-modal table-modal table-modal--wide",
+                onclick: move |event| event.stop_propagation(),
+                div {
+                    class: "settings-modal__header",
+                    div {
+                        class: "settings-modal__header-copy",
+                        h2 { class: "settings-modal__title", "Sizes · {target.schema}" }
+                        p {
+                            class: "settings-modal__hint",
+                            "On-disk size of each table from pg_total_relation_size. Row counts are planner estimates."
+                        }
+                    }
+                    button {
+                        class: "button button--ghost button--small",
+                        onclick: move |_| reload += 1,
+                        "Refresh"
+                    }
+                    button {
+                        class: "button button--ghost button--small",
+                        onclick: move |_| size_report.set(None),
+                        "Close"
+                    }
+                }
+
+                div {
+                    class: "table-modal__body",
+                    match report() {
+                        None => rsx! {
+                            p { class: "table-modal__hint", "Measuring tables…" }
+                        },
+                        Some(Err(err)) => rsx! {
+                            p { class: "table-modal__error", "{err}" }
+                        },
+                        Some(Ok(report)) => {
+                            let schema_bytes: u64 = report.tables.iter().map(|table| table.total_bytes).sum();
+                            let largest = report
+                                .tables
+                                .iter()
+                                .map(|table| table.total_bytes)
+                                .max()
+                                .unwrap_or_default();
+                            let mut tables = report.tables.clone();
+                            sort_table_sizes(&mut tables, sort_column, descending);
+                            rsx! {
+                                p {
+                                    class: "table-modal__hint",
+                                    "{report.tables.len()} tables using {format_bytes(schema_bytes)} of {format_bytes(report.database_bytes)} in database {report.database}."
+                                }
+                                if tables.is_empty() {
+                                    p { class: "table-modal__hint", "This schema has no tables." }
+                                } else {
+                                    div {
+                                        class: "table-modal__table-wrap",
+                                        table {
+                                            class: "table-modal__table",
+                                            thead {
+                                                tr {
+                                                    for column in SizeColumn::ALL {
+                                                        th {
+                                                            class: "size-report__heading",
+                                                            button {
+                                                                class: if column == sort_column {
+                                                                    "results__sort-button results__sort-button--active"
+                                                                } else {
+                                                                    "results__sort-button"
+                                                                },
+                                                                onclick: move |_| {
+                                                                    sort.set(next_sort(sort(), column));
+                                                                },
+                                                                span { "{column.label()}" }
+                                                                span {
+                                                                    class: "results__sort-indicator",
+                                                                    "{sort_indicator(column, sort_column, descending)}"
+                                                                }
+                                                            }
+                                                        }
+                                                    }
+                                                    th { class: "size-report__bar-heading" }
+                                                }
+                                            }
+                                            tbody {
+                                                for table in tables {
+                                                    tr {
+                                                        key: "{table.name}",
+                                                        td { "{table.name}" }
+                                                        td {
+                                                            class: "table-modal__cell--numeric",
+                                                            {table.estimated_rows.map(|rows| rows.to_string()).unwrap_or_default()}
+                                                        }
+                                                        td { class: "table-modal__cell--numeric", "{format_bytes(table.total_bytes)}" }
+                                                        td { class: "table-modal__cell--numeric", "{format_bytes(table.table_bytes)}" }
+                                                        td { class: "table-modal__cell--numeric", "{format_bytes(table.index_bytes)}" }
+                                                        td { class: "table-modal__cell--numeric", "{format_bytes(table.toast_bytes)}" }
+                                                        td {
+                                                            div {
+                                                                class: "size-report__bar",
+                                                                div {
+                                                                    class: "size-report__bar-fill",
+                                                                    style: "width: {size_share(table.total_bytes, largest)}%;",
+                                                                }
+                                                            }
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Clicking the sorted column flips its direction; a new column starts with
+/// the largest values first, or A–Z for names.
+fn next_sort(current: (SizeColumn, bool), column: SizeColumn) -> (SizeColumn, bool) {
+    if current.0 == column {
+        (column, !current.1)
+    } else {
+        (column, column != SizeColumn::Name)
+    }
+}
+
+fn sort_indicator(column: SizeColumn, sort_column: SizeColumn, descending: bool) -> &'static str {
+    match (column == sort_column, descending) {
+        (true, true) => "↓",
+        (true, false) => "↑",
+        (false, _) => "↕",
+    }
+}
+
+fn sort_table_sizes(tables: &mut [TableSizeDetail], column: SizeColumn, descending: bool) {
+    tables.sort_by(|left, right| {
+        let ordering = match column {
+            SizeColumn::Name => left.name.cmp(&right.name),
+            SizeColumn::Rows => left.estimated_rows.cmp(&right.estimated_rows),
+            SizeColumn::Total => left.total_bytes.cmp(&right.total_bytes),
+            SizeColumn::Table => left.table_bytes.cmp(&right.table_bytes),
+            SizeColumn::Indexes => left.index_bytes.cmp(&right.index_bytes),
+            SizeColumn::Toast => left.toast_bytes.cmp(&right.toast_bytes),
+        };
+        if descending {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    });
+}
+
+/// Width of a table's bar as a percentage of the largest table.
+fn size_share(bytes: u64, largest: u64) -> f64 {
+    if largest == 0 {
+        0.0
+    } else {
+        (bytes as f64 / largest as f64 * 100.0).clamp(0.0, 100.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SizeColumn, next_sort, size_share, sort_table_sizes};
+    use models::TableSizeDetail;
+
+    fn make_table(name: &str, total_bytes: u64, index_bytes: u64) -> TableSizeDetail {
+        TableSizeDetail {
+            name: name.to_string(),
+            estimated_rows: None,
+            total_bytes,
+            table_bytes: total_bytes - index_bytes,
+            index_bytes,
+            toast_bytes: 0,
+        }
+    }
+
+    #[test]
+    fn sort_table_sizes_orders_by_the_chosen_column() {
+        let mut tables = vec![
+            make_table("orders", 4096, 1024),
+            make_table("audit", 8192, 512),
+            make_table("users", 2048, 2048),
+        ];
+        let names = |tables: &[TableSizeDetail]| {
+            tables
+                .iter()
+                .map(|table| table.name.clone())
+                .collect::<Vec<_>>()
+        };
+
+        sort_table_sizes(&mut tables, SizeColumn::Total, true);
+        assert_eq!(names(&tables), vec!["audit", "orders", "users"]);
+
+        sort_table_sizes(&mut tables, SizeColumn::Indexes, true);
+        assert_eq!(names(&tables), vec!["users", "orders", "audit"]);
+
+        sort_table_sizes(&mut tables, SizeColumn::Name, false);
+        assert_eq!(names(&tables), vec!["audit", "orders", "users"]);
+    }
+
+    #[test]
+    fn next_sort_flips_the_active_column_and_starts_sizes_descending() {
+        assert_eq!(
+            next_sort((SizeColumn::Total, true), SizeColumn::Total),
+            (SizeColumn::Total, false)
+        );
+        assert_eq!(
+            next_sort((SizeColumn::Total, true), SizeColumn::Name),
+            (SizeColumn::Name, false)
+        );
+        assert_eq!(
+            next_sort((SizeColumn::Name, false), SizeColumn::Toast),
+            (SizeColumn::Toast, true)
+        );
+    }
+
+    #[test]
+    fn size_share_is_relative_to_the_largest_table() {
+        assert_eq!(size_share(512, 2048), 25.0);
+        assert_eq!(size_share(2048, 2048), 100.0);
+        assert_eq!(size_share(0, 0), 0.0);
+    }
+}
@@ -0,0 +1,566 @@
+use super::{insert_template, select_template};
+use crate::app_state::session_connection;
+use crate::clipboard::copy_text;
+use crate::screens::workspace::actions::{
+    append_to_tab_sql, ensure_tab_for_session, format_row_count, open_structure_tab,
+    set_active_tab_status,
+};
+use dioxus::prelude::*;
+use models::{
+    DatabaseKind, ExplorerNode, ExplorerNodeKind, QueryTabState, RowCountMode, TablePreviewSource,
+};
+
+/// Cursor position a tree object's context menu opens at.
+#[derive(Clone, Copy, PartialEq)]
+pub(super) struct TableContextMenuAnchor {
+    pub(super) x: f64,
+    pub(super) y: f64,
+}
+
+#[component]
+pub(super) fn TableContextMenu(
+    anchor: TableContextMenuAnchor,
+    mut context_menu: Signal<Option<TableContextMenuAnchor>>,
+    node: ExplorerNode,
+    session_id: u64,
+    kind: DatabaseKind,
+    tabs: Signal<Vec<QueryTabState>>,
+    active_tab_id: Signal<u64>,
+    next_tab_id: Signal<u64>,
+    /// Whether truncate and drop may run right now; they stay listed but
+    /// disabled otherwise.
+    can_mutate: bool,
+    /// Whether a materialized view holds data, when known.
+    populated: Option<bool>,
+    on_browse: EventHandler<()>,
+    on_show_ddl: EventHandler<()>,
+    on_edit_comment: EventHandler<()>,
+    on_pg_dump: EventHandler<()>,
+    on_row_security: EventHandler<()>,
+    on_privileges: EventHandler<()>,
+    on_indexes: EventHandler<()>,
+    on_maintenance: EventHandler<()>,
+    on_health: EventHandler<()>,
+    on_insert_row: EventHandler<()>,
+    on_truncate: EventHandler<()>,
+    on_drop: EventHandler<()>,
+    /// Refreshes a materialized view; the flag asks for `concurrently`.
+    on_refresh: EventHandler<bool>,
+) -> Element {
+    let is_table = node.kind == ExplorerNodeKind::Table;
+    // MySQL and ClickHouse only comment tables; PostgreSQL comments any relation.
+    let can_comment = match kind {
+        DatabaseKind::Postgres => true,
+        DatabaseKind::MySql | DatabaseKind::ClickHouse => is_table,
+        DatabaseKind::Sqlite => false,
+    };
+    let is_refreshable =
+        node.kind == ExplorerNodeKind::MaterializedView && kind == DatabaseKind::Postgres;
+    let source = TablePreviewSource {
+        schema: node.schema.clone(),
+        table_name: node.name.clone(),
+        qualified_name: node.qualified_name.clone(),
+    };
+    let style = format!("left: {}px; top: {}px;", anchor.x, anchor.y);
+
+    rsx! {
+        div {
+            class: "tree-context-menu__backdrop",
+            onclick: move |_| context_menu.set(None),
+            oncontextmenu: move |event: MouseEvent| {
+                event.prevent_default();
+                event.stop_propagation();
+                context_menu.set(None);
+            },
+        }
+        div {
+            class: "tree-context-menu",
+            style: "{style}",
+            onclick: move |event| event.stop_propagation(),
+            button {
+                class: "tree-context-menu__item",
+                onclick: move |_| {
+                    context_menu.set(None);
+                    on_browse.call(());
+                },
+                "Browse data"
+            }
+            button {
+                class: "tree-context-menu__item",
+                onclick: {
+                    let source = source.clone();
+                    move |_| {
+                        context_menu.set(None);
+                        count_table_rows(
+                            tabs,
+                            active_tab_id,
+                            next_tab_id,
+                            session_id,
+                            source.clone(),
+                        );
+                    }
+                },
+                "Count rows"
+            }
+            button {
+                class: "tree-context-menu__item",
+                onclick: {
+                    let source = source.clone();
+                    move |_| {
+                        context_menu.set(None);
+                        let Some(connection) = session_connection(session_id) else {
+                            return;
+                        };
+                        open_structure_tab(
+                            tabs,
+                            active_tab_id,
+                            next_tab_id,
+                            session_id,
+                            connection,
+                            source.clone(),
+                        );
+                    }
+                },
+                "Table info"
+            }
+            if is_table {
+                button {
+                    class: "tree-context-menu__item",
+                    onclick: move |_| {
+                        context_menu.set(None);
+                        on_show_ddl.call(());
+                    },
+                    "Show DDL"
+                }
+            }
+            if can_comment {
+                button {
+                    class: "tree-context-menu__item",
+                    disabled: !can_mutate,
+                    onclick: move |_| {
+                        context_menu.set(None);
+                        on_edit_comment.call(());
+                    },
+                    "Edit comment…"
+                }
+            }
+            if is_table && kind == DatabaseKind::Postgres {
+                button {
+                    class: "tree-context-menu__item",
+                    onclick: move |_| {
+                        context_menu.set(None);
+                        on_pg_dump.call(());
+                    },
+                    "Dump with pg_dump…"
+                }
+                button {
+                    class: "tree-context-menu__item",
+                    onclick: move |_| {
+                        context_menu.set(None);
+                        on_row_security.call(());
+                    },
+                    "Row security…"
+                }
+                button {
+                    class: "tree-context-menu__item",
+                    onclick: move |_| {
+                        context_menu.set(None);
+                        on_privileges.call(());
+                    },
+                    "Privileges…"
+                }
+                button {
+                    class: "tree-context-menu__item",
+                    onclick: move |_| {
+                        context_menu.set(None);
+                        on_indexes.call(());
+                    },
+                    "Indexes…"
+                }
+                button {
+                    class: "tree-context-menu__item",
+                    onclick: move |_| {
+                        context_menu.set(None);
+                        on_maintenance.call(());
+                    },
+                    "Vacuum / analyze / reindex…"
+                }
+                button {
+                    class: "tree-context-menu__item",
+                    onclick: move |_| {
+                        context_menu.set(None);
+                        on_health.call(());
+                    },
+                    "Health…"
+                }
+            }
+
+            div { class: "tree-context-menu__separator" }
+
+            button {
+                class: "tree-context-menu__item",
+                onclick: {
+                    let name = node.name.clone();
+                    move |_| {
+                        context_menu.set(None);
+                        copy_to_clipboard(tabs, active_tab_id(), name.clone());
+                    }
+                },
+                "Copy name"
+            }
+            button {
+                class: "tree-context-menu__item",
+                onclick: {
+                    let qualified_name = node.qualified_name.clone();
+                    move |_| {
+                        context_menu.set(None);
+                        copy_to_clipboard(tabs, active_tab_id(), qualified_name.clone());
+                    }
+                },
+                "Copy qualified name"
+            }
+
+            div { class: "tree-context-menu__separator" }
+
+            button {
+                class: "tree-context-menu__item",
+                onclick: {
+                    let source = source.clone();
+                    move |_| {
+                        context_menu.set(None);
+                        generate_column_template(
+                            tabs,
+                            active_tab_id,
+                            next_tab_id,
+                            session_id,
+                            kind,
+                            source.clone(),
+                            ColumnTemplate::Select,
+                        );
+                    }
+                },
+                "Generate SELECT"
+            }
+            button {
+                class: "tree-context-menu__item",
+                onclick: {
+                    let source = source.clone();
+                    move |_| {
+                        context_menu.set(None);
+                        generate_column_template(
+                            tabs,
+                            active_tab_id,
+                            next_tab_id,
+                            session_id,
+                            kind,
+                            source.clone(),
+                            ColumnTemplate::SelectWhere,
+                        );
+                    }
+                },
+                "Generate WHERE template"
+            }
+            if is_table {
+                button {
+                    class: "tree-context-menu__item",
+                    onclick: {
+                        let source = source.clone();
+                        move |_| {
+                            context_menu.set(None);
+                            generate_column_template(
+                                tabs,
+                                active_tab_id,
+                                next_tab_id,
+                                session_id,
+                                kind,
+                                source.clone(),
+                                ColumnTemplate::Insert,
+                            );
+                        }
+                    },
+                    "Generate INSERT template"
+                }
+                button {
+                    class: "tree-context-menu__item",
+                    disabled: !can_mutate,
+                    onclick: move |_| {
+                        context_menu.set(None);
+                        on_insert_row.call(());
+                    },
+                    "Insert row…"
+                }
+
+                div { class: "tree-context-menu__separator" }
+
+                button {
+                    class: "tree-context-menu__item tree-context-menu__item--danger",
+                    disabled: !can_mutate,
+                    onclick: move |_| {
+                        context_menu.set(None);
+                        on_truncate.call(());
+                    },
+                    "Truncate…"
+                }
+                button {
+                    class: "tree-context-menu__item tree-context-menu__item--danger",
+                    disabled: !can_mutate,
+                    onclick: move |_| {
+                        context_menu.set(None);
+                        on_drop.call(());
+                    },
+                    "Drop…"
+                }
+            }
+            if is_refreshable {
+                div { class: "tree-context-menu__separator" }
+
+                button {
+                    class: "tree-context-menu__item",
+                    disabled: !can_mutate,
+                    onclick: move |_| {
+                        context_menu.set(None);
+                        on_refresh.call(false);
+                    },
+                    "Refresh"
+                }
+                button {
+                    class: "tree-context-menu__item",
+                    // PostgreSQL rejects a concurrent refresh of a view that
+                    // has never been populated.
+                    disabled: !can_mutate || populated == Some(false),
+                    title: "Keeps the view readable; needs a unique index",
+                    onclick: move |_| {
+                        context_menu.set(None);
+                        on_refresh.call(true);
+                    },
+                    "Refresh concurrently"
+                }
+            }
+        }
+    }
+}
+
+/// Right-click menu on a schema header. Only PostgreSQL schemas open one.
+#[component]
+pub(super) fn SchemaContextMenu(
+    anchor: TableContextMenuAnchor,
+    mut context_menu: Signal<Option<TableContextMenuAnchor>>,
+    on_size_report: EventHandler<()>,
+) -> Element {
+    let style = format!("left: {}px; top: {}px;", anchor.x, anchor.y);
+
+    rsx! {
+        div {
+            class: "tree-context-menu__backdrop",
+            onclick: move |_| context_menu.set(None),
+            oncontextmenu: move |event: MouseEvent| {
+                event.prevent_default();
+                event.stop_propagation();
+                context_menu.set(None);
+            },
+        }
+        div {
+            class: "tree-context-menu",
+            style: "{style}",
+            onclick: move |event| event.stop_propagation(),
+            button {
+                class: "tree-context-menu__item",
+                onclick: move |_| {
+                    context_menu.set(None);
+                    on_size_report.call(());
+                },
+                "Size report…"
+            }
+        }
+    }
+}
+
+/// Confirmation for destructive tree actions that only unlocks once the
+/// object's name has been typed in full.
+#[component]
+pub(super) fn TypedConfirmModal(
+    title: String,
+    description: String,
+    expected: String,
+    confirm_label: String,
+    on_confirm: EventHandler<()>,
+    on_cancel: EventHandler<()>,
+) -> Element {
+    let mut typed = use_signal(String::new);
+    let matches = typed() == expected;
+
+    rsx! {
+        div {
+            class: "settings-modal__backdrop",
+            onclick: move |_| on_cancel.call(()),
+            div {
+                class: "settings-modal table-modal table-modal--compact",
+                onclick: move |event| event.stop_propagation(),
+                div {
+                    class: "settings-modal__header",
+                    div {
+                        class: "settings-modal__header-copy",
+                        h2 { class: "settings-modal__title", "{title}" }
+                    }
+                    button {
+                        class: "button button--ghost button--small",
+                        onclick: move |_| on_cancel.call(()),
+                        "Close"
+                    }
+                }
+
+                div {
+                    class: "table-modal__body",
+                    p { class: "table-modal__hint typed-confirm__description", "{description}" }
+                    div {
+                        class: "field",
+                        span { class: "field__label", "Type {expected} to confirm" }
+                        input {
+                            class: "input",
+                            value: typed(),
+                            placeholder: "{expected}",
+                            autofocus: true,
+                            oninput: move |event| typed.set(event.value()),
+                            onkeydown: {
+                                let expected = expected.clone();
+                                move |event: KeyboardEvent| {
+                                    if event.key() == Key::Enter && typed() == expected {
+                                        on_confirm.call(());
+                                    }
+                                }
+                            },
+                        }
+                    }
+
+                    div {
+                        class: "table-modal__actions",
+                        button {
+                            class: "button button--ghost",
+                            onclick: move |_| on_cancel.call(()),
+                            "Cancel"
+                        }
+                        button {
+                            class: "button button--primary",
+                            disabled: !matches,
+                            onclick: move |_| on_confirm.call(()),
+                            "{confirm_label}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn copy_to_clipboard(tabs: Signal<Vec<QueryTabState>>, tab_id: u64, text: String) {
+    spawn(async move {
+        let status = match copy_text(&text).await {
+            Ok(()) => format!("Copied {text}"),
+            Err(err) => err,
+        };
+        set_active_tab_status(tabs, tab_id, status);
+    });
+}
+
+fn count_table_rows(
+    tabs: Signal<Vec<QueryTabState>>,
+    active_tab_id: Signal<u64>,
+    next_tab_id: Signal<u64>,
+    session_id: u64,
+    source: TablePreviewSource,
+) {
+    let tab_id = ensure_tab_for_session(tabs, active_tab_id, next_tab_id, session_id);
+    if tab_id == 0 {
+        return;
+    }
+    let Some(connection) = session_connection(session_id) else {
+        set_active_tab_status(tabs, tab_id, "The bound connection was closed".to_string());
+        return;
+    };
+
+    set_active_tab_status(
+        tabs,
+        tab_id,
+        format!("Counting rows in {}...", source.table_name),
+    );
+    spawn(async move {
+        let status = match services::load_table_row_count(
+            connection,
+            source.clone(),
+            None,
+            RowCountMode::Exact,
+        )
+        .await
+        {
+            Ok(Some(count)) => format!("{}: {}", source.table_name, format_row_count(count)),
+            Ok(None) => format!("Row count is unavailable for {}", source.table_name),
+            Err(err) => format!("Row count error: {err}"),
+        };
+        set_active_tab_status(tabs, tab_id, status);
+    });
+}
+
+/// Statements the context menu generates from a table's column names.
+#[derive(Clone, Copy)]
+enum ColumnTemplate {
+    Select,
+    /// SELECT followed by a commented WHERE clause over every column.
+    SelectWhere,
+    Insert,
+}
+
+fn generate_column_template(
+    tabs: Signal<Vec<QueryTabState>>,
+    active_tab_id: Signal<u64>,
+    next_tab_id: Signal<u64>,
+    session_id: u64,
+    kind: DatabaseKind,
+    source: TablePreviewSource,
+    template: ColumnTemplate,
+) {
+    let tab_id = ensure_tab_for_session(tabs, active_tab_id, next_tab_id, session_id);
+    if tab_id == 0 {
+        return;
+    }
+    let Some(connection) = session_connection(session_id) else {
+        set_active_tab_status(tabs, tab_id, "The bound connection was closed".to_string());
+        return;
+    };
+
+    spawn(async move {
+        let columns = match services::load_table_columns(
+            connection,
+            source.schema.clone(),
+            source.table_name.clone(),
+        )
+        .await
+        {
+            Ok(columns) => columns,
+            Err(err) => {
+                set_active_tab_status(tabs, tab_id, format!("Column load error: {err}"));
+                return;
+            }
+        };
+        let schema = source.schema.as_deref();
+        let (sql, label) = match template {
+            ColumnTemplate::Select => (
+                select_template(kind, schema, &source.table_name, &columns, false),
+                "SELECT",
+            ),
+            ColumnTemplate::SelectWhere => (
+                select_template(kind, schema, &source.table_name, &columns, true),
+                "WHERE template",
+            ),
+            ColumnTemplate::Insert => (
+                insert_template(kind, schema, &source.table_name, &columns),
+                "INSERT template",
+            ),
+        };
+        append_to_tab_sql(
+            tabs,
+            tab_id,
+            sql,
+            format!("Generated {label} for {}", source.table_name),
+        );
+    });
+}
@@ -0,0 +1,209 @@
+use super::maintenance_modal::format_last_run;
+use crate::app_state::session_connection;
+use crate::screens::workspace::actions::{read_only_mode_block_status, read_only_mode_enabled};
+use dioxus::prelude::*;
+use models::{MaintenanceKind, TABLE_BLOAT_RATIO, TablePreviewSource, TableStats};
+
+/// PostgreSQL table whose activity statistics are shown.
+#[derive(Clone, PartialEq)]
+pub(super) struct TableHealthTarget {
+    pub(super) session_id: u64,
+    pub(super) source: TablePreviewSource,
+}
+
+#[component]
+pub(super) fn TableHealthModal(
+    target: TableHealthTarget,
+    mut health_target: Signal<Option<TableHealthTarget>>,
+) -> Element {
+    let mut stats = use_signal(|| None::<Result<TableStats, String>>);
+    let mut reload = use_signal(|| 0_u64);
+    let mut status = use_signal(String::new);
+    let mut running = use_signal(|| false);
+    let read_only_mode = read_only_mode_enabled();
+
+    let session_id = target.session_id;
+    let source = target.source.clone();
+    use_effect(move || {
+        reload();
+        let source = source.clone();
+        spawn(async move {
+            let result = match session_connection(session_id) {
+                Some(connection) => services::load_table_stats(connection, source)
+                    .await
+                    .map_err(|err| err.to_string()),
+                None => Err("Connection is no longer open.".to_string()),
+            };
+            stats.set(Some(result));
+        });
+    });
+
+    rsx! {
+        div {
+            class: "settings-modal__backdrop",
+            onclick: move |_| {
+                if !running() {
+                    health_target.set(None);
+                }
+            },
+            div {
+                class: "settings-modal table-modal",
+                onclick: move |event| event.stop_propagation(),
+                div {
+                    class: "settings-modal__header",
+                    div {
+                        class: "settings-modal__header-copy",
+                        h2 { class: "settings-modal__title", "Health · {target.source.table_name}" }
+                        p {
+                            class: "settings-modal__hint",
+                            "Counters from pg_stat_user_tables and pg_statio_user_tables since statistics were last reset."
+                        }
+                    }
+                    button {
+                        class: "button button--ghost button--small",
+                        disabled: running(),
+                        onclick: move |_| reload += 1,
+                        "Refresh"
+                    }
+                    button {
+                        class: "button button--ghost button--small",
+                        disabled: running(),
+                        onclick: move |_| health_target.set(None),
+                        "Close"
+                    }
+                }
+
+                div {
+                    class: "table-modal__body",
+                    match stats() {
+                        None => rsx! {
+                            p { class: "table-modal__hint", "Loading table statistics…" }
+                        },
+                        Some(Err(err)) => rsx! {
+                            p { class: "table-modal__error", "{err}" }
+                        },
+                        Some(Ok(stats)) => {
+                            let bloated = stats.is_bloated();
+                            let index_scans = stats
+                                .index_scans
+                                .map(|scans| scans.to_string())
+                                .unwrap_or_else(|| "no indexes".to_string());
+                            let cache_hit_ratio = stats
+                                .cache_hit_ratio()
+                                .map(|ratio| format!("{:.1}%", ratio * 100.0))
+                                .unwrap_or_else(|| "—".to_string());
+                            let bloat_percent = TABLE_BLOAT_RATIO * 100.0;
+                            rsx! {
+                                dl {
+                                    class: "table-modal__details",
+                                    dt { "Live rows" }
+                                    dd { "{stats.live_tuples}" }
+                                    dt { "Dead rows" }
+                                    dd {
+                                        class: if bloated { "table-health__warning" } else { "" },
+                                        "{format_dead_rows(&stats)}"
+                                    }
+                                    dt { "Last vacuum" }
+                                    dd { "{format_last_run(&stats.last_vacuum, &stats.last_autovacuum)}" }
+                                    dt { "Last analyze" }
+                                    dd { "{format_last_run(&stats.last_analyze, &stats.last_autoanalyze)}" }
+                                    dt { "Sequential scans" }
+                                    dd { "{stats.seq_scans}" }
+                                    dt { "Index scans" }
+                                    dd { "{index_scans}" }
+                                    dt { "Heap blocks read" }
+                                    dd { "{stats.heap_blocks_read}" }
+                                    dt { "Heap blocks hit" }
+                                    dd { "{stats.heap_blocks_hit}" }
+                                    dt { "Cache hit ratio" }
+                                    dd { "{cache_hit_ratio}" }
+                                }
+                                if bloated {
+                                    p {
+                                        class: "table-modal__hint table-health__warning",
+                                        "Dead rows exceed {bloat_percent:.0}% of live rows. VACUUM ANALYZE reclaims them and refreshes planner statistics."
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    if !status().is_empty() {
+                        p { class: "table-modal__hint", "{status}" }
+                    }
+
+                    div {
+                        class: "table-modal__actions",
+                        button {
+                            class: "button button--primary",
+                            disabled: running() || read_only_mode,
+                            title: if read_only_mode {
+                                "Maintenance is blocked by read-only mode"
+                            } else {
+                                ""
+                            },
+                            onclick: {
+                                let source = target.source.clone();
+                                move |_| {
+                                    if read_only_mode_enabled() {
+                                        status.set(read_only_mode_block_status("running table maintenance"));
+                                        return;
+                                    }
+                                    let Some(connection) = session_connection(session_id) else {
+                                        status.set("Connection is no longer open.".to_string());
+                                        return;
+                                    };
+                                    let source = source.clone();
+                                    running.set(true);
+                                    status.set(format!("VACUUM ANALYZE {}", source.qualified_name));
+                                    spawn(async move {
+                                        let result = services::run_table_maintenance(
+                                            connection,
+                                            source,
+                                            MaintenanceKind::VacuumAnalyze,
+                                            |_| {},
+                                        )
+                                        .await;
+                                        running.set(false);
+                                        match result {
+                                            Ok(()) => status.set("VACUUM ANALYZE finished.".to_string()),
+                                            Err(err) => status.set(err.to_string()),
+                                        }
+                                        reload += 1;
+                                    });
+                                }
+                            },
+                            if running() { "Running…" } else { "VACUUM ANALYZE" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Dead row count with its share of the live rows, e.g. `250 (25.0% of live)`.
+fn format_dead_rows(stats: &TableStats) -> String {
+    match stats.dead_ratio() {
+        Some(ratio) => format!("{} ({:.1}% of live)", stats.dead_tuples, ratio * 100.0),
+        None => stats.dead_tuples.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::format_dead_rows;
+    use models::TableStats;
+
+    #[test]
+    fn format_dead_rows_adds_the_share_of_live_rows() {
+        let stats = |live_tuples, dead_tuples| TableStats {
+            live_tuples,
+            dead_tuples,
+            ..TableStats::default()
+        };
+
+        assert_eq!(format_dead_rows(&stats(1000, 250)), "250 (25.0% of live)");
+        assert_eq!(format_dead_rows(&stats(0, 3)), "3");
+    }
+}
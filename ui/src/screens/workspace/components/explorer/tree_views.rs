@@ -1,14 +1,44 @@
+use super::comment_modal::{CommentModal, CommentTarget};
+use super::database_group::ExplorerDatabaseGroup;
 use super::duplicate_table_modal::{DuplicateTableModal, DuplicateTableTarget};
-use super::{count_objects, disconnect_session, split_children};
-use crate::app_state::{APP_STATE, activate_session, session_connection};
+use super::indexes_modal::{IndexesModal, IndexesTarget};
+use super::maintenance_modal::{MaintenanceModal, MaintenanceTarget};
+use super::pg_dump_modal::{PgDumpModal, PgDumpTarget};
+use super::privileges_modal::{PrivilegesModal, PrivilegesTarget};
+use super::routine_source_modal::{RoutineSourceModal, RoutineSourceTarget};
+use super::row_security_modal::{RowSecurityModal, RowSecurityTarget};
+use super::sequence_modal::{SequenceModal, SequenceTarget};
+use super::size_report_modal::{SizeReportModal, SizeReportTarget};
+use super::table_context_menu::{
+    SchemaContextMenu, TableContextMenu, TableContextMenuAnchor, TypedConfirmModal,
+};
+use super::table_health_modal::{TableHealthModal, TableHealthTarget};
+use super::{
+    count_objects, disconnect_session, matching_columns, quoted_table_name_preview,
+    routine_call_template, split_children, split_filter_match, table_size_label,
+};
+use crate::app_state::{
+    APP_COLUMN_INDEX, APP_SHOW_SQL_EDITOR, APP_STATE, ToastKind, activate_session,
+    session_connection, session_transaction, session_transaction_status, show_toast, toast_error,
+};
 use crate::screens::workspace::actions::{
-    ensure_tab_for_session, mark_table_deleted, mark_table_truncated, read_only_mode_enabled,
-    run_table_preview_for_tab, tab_connection_or_error,
+    append_to_tab_sql, ensure_tab_for_session, insert_text_at_end_of_tab_sql, mark_table_deleted,
+    mark_table_truncated, read_only_mode_enabled, run_table_preview_for_tab, set_active_tab_status,
+    tab_connection_or_error,
+};
+use crate::screens::workspace::components::{
+    ActionIcon, ActivityMonitor, CsvImportDialog, CsvImportTarget, IconButton, InsertRowDialog,
+    InsertRowTarget, RolesWindow, ServerSettingsWindow, TableDdlModal, TableDdlTarget,
+    TopQueriesWindow, insert_text_at_editor_cursor,
 };
-use crate::screens::workspace::components::{ActionIcon, IconButton};
 use dioxus::prelude::*;
-use models::{DatabaseKind, ExplorerNode, ExplorerNodeKind, QueryTabState, TablePreviewSource};
+use models::{
+    ColumnInfo, DatabaseKind, ExplorerNode, ExplorerNodeKind, QueryTabState, RoutineInfo,
+    RoutineKind, SequenceInfo, TableCatalogInfo, TablePreviewSource, TransactionStatus,
+    WorkspaceTabKind,
+};
 use rfd::{AsyncMessageDialog, MessageButtons, MessageDialogResult, MessageLevel};
+use std::collections::HashMap;
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 enum TableMutationKind {
@@ -19,14 +49,30 @@ enum TableMutationKind {
 #[component]
 pub(super) fn ExplorerConnectionView(
     section: super::ExplorerConnectionSection,
+    /// Database sessions opened from this connection, nested under it.
+    database_sections: Vec<super::ExplorerConnectionSection>,
     tree_reload: Signal<u64>,
     tabs: Signal<Vec<QueryTabState>>,
     active_tab_id: Signal<u64>,
     next_tab_id: Signal<u64>,
     selected_node: Signal<String>,
+    filter: String,
 ) -> Element {
     let mut expanded = use_signal(|| true);
+    let mut show_activity = use_signal(|| false);
+    let mut show_settings = use_signal(|| false);
+    let mut show_roles = use_signal(|| false);
+    let mut show_top_queries = use_signal(|| false);
+    use_effect(use_reactive((&filter,), move |(filter,)| {
+        if !filter.is_empty() {
+            expanded.set(true);
+        }
+    }));
     let object_count = count_objects(&section.nodes);
+    let is_postgres = APP_STATE
+        .read()
+        .session(section.session_id)
+        .is_some_and(|session| session.kind == DatabaseKind::Postgres);
 
     rsx! {
         div { class: if section.is_active {
@@ -73,6 +119,56 @@ pub(super) fn ExplorerConnectionView(
                 }
                 div {
                     class: "tree__connection-actions",
+                    if is_postgres {
+                        IconButton {
+                            icon: ActionIcon::Activity,
+                            label: "Activity monitor".to_string(),
+                            small: true,
+                            onclick: {
+                                let session_id = section.session_id;
+                                move |_| {
+                                    activate_session(session_id);
+                                    show_activity.set(true);
+                                }
+                            },
+                        }
+                        IconButton {
+                            icon: ActionIcon::ServerSettings,
+                            label: "Server settings".to_string(),
+                            small: true,
+                            onclick: {
+                                let session_id = section.session_id;
+                                move |_| {
+                                    activate_session(session_id);
+                                    show_settings.set(true);
+                                }
+                            },
+                        }
+                        IconButton {
+                            icon: ActionIcon::Roles,
+                            label: "Roles".to_string(),
+                            small: true,
+                            onclick: {
+                                let session_id = section.session_id;
+                                move |_| {
+                                    activate_session(session_id);
+                                    show_roles.set(true);
+                                }
+                            },
+                        }
+                        IconButton {
+                            icon: ActionIcon::TopQueries,
+                            label: "Top queries".to_string(),
+                            small: true,
+                            onclick: {
+                                let session_id = section.session_id;
+                                move |_| {
+                                    activate_session(session_id);
+                                    show_top_queries.set(true);
+                                }
+                            },
+                        }
+                    }
                     IconButton {
                         icon: ActionIcon::Close,
                         label: "Disconnect".to_string(),
@@ -99,9 +195,57 @@ pub(super) fn ExplorerConnectionView(
                                 active_tab_id,
                                 next_tab_id,
                                 selected_node,
+                                filter: filter.clone(),
                             }
                         }
                     }
+                    if is_postgres && section.parent_session_id.is_none() {
+                        ExplorerDatabaseGroup {
+                            session_id: section.session_id,
+                            database_sections,
+                            tree_reload,
+                            tabs,
+                            active_tab_id,
+                            next_tab_id,
+                            selected_node,
+                            filter: filter.clone(),
+                        }
+                    }
+                }
+            }
+
+            if show_activity() {
+                ActivityMonitor {
+                    session_id: section.session_id,
+                    connection_name: section.name.clone(),
+                    show_activity,
+                }
+            }
+
+            if show_settings() {
+                ServerSettingsWindow {
+                    session_id: section.session_id,
+                    connection_name: section.name.clone(),
+                    show_settings,
+                }
+            }
+
+            if show_roles() {
+                RolesWindow {
+                    session_id: section.session_id,
+                    connection_name: section.name.clone(),
+                    show_roles,
+                }
+            }
+
+            if show_top_queries() {
+                TopQueriesWindow {
+                    session_id: section.session_id,
+                    connection_name: section.name.clone(),
+                    show_top_queries,
+                    tabs,
+                    active_tab_id,
+                    next_tab_id,
                 }
             }
         }
@@ -117,62 +261,203 @@ fn ExplorerSchemaView(
     active_tab_id: Signal<u64>,
     next_tab_id: Signal<u64>,
     selected_node: Signal<String>,
+    filter: String,
 ) -> Element {
     let mut expanded = use_signal(|| true);
-    let (tables, views) = split_children(&node.children);
-    let object_count = tables.len() + views.len();
+    use_effect(use_reactive((&filter,), move |(filter,)| {
+        if !filter.is_empty() {
+            expanded.set(true);
+        }
+    }));
+    // Bumped by the schema's refresh button, or a double-click on its header,
+    // to drop the lazily loaded functions, sequences and table sizes for this
+    // schema only.
+    let mut schema_reload = use_signal(|| 0_u64);
+    let mut context_menu = use_signal(|| None::<TableContextMenuAnchor>);
+    let mut size_report = use_signal(|| None::<SizeReportTarget>);
+    let groups = split_children(&node.children);
+    let object_count = groups.len();
+    let sections = groups.into_sections();
+    let session_kind = APP_STATE
+        .read()
+        .session(session_id)
+        .map(|session| session.kind);
+    let has_lazy_groups = matches!(
+        session_kind,
+        Some(DatabaseKind::Postgres | DatabaseKind::MySql)
+    );
+    let has_table_catalog = session_kind.is_some_and(|kind| kind != DatabaseKind::Sqlite);
+    // Catalog row estimates, sizes and comments keyed by table name, kept
+    // until the schema or the whole tree is refreshed. Missing entries hide
+    // the label.
+    let mut table_catalog = use_signal(HashMap::<String, TableCatalogInfo>::new);
+    use_effect(use_reactive(
+        (&node.name, &has_table_catalog),
+        move |(schema, has_table_catalog)| {
+            tree_reload();
+            schema_reload();
+            if !has_table_catalog {
+                table_catalog.set(HashMap::new());
+                return;
+            }
+            let Some(connection) = session_connection(session_id) else {
+                return;
+            };
+            spawn(async move {
+                if let Ok(tables) = services::load_schema_table_catalog(connection, schema).await {
+                    table_catalog.set(
+                        tables
+                            .into_iter()
+                            .map(|info| (info.name.clone(), info))
+                            .collect(),
+                    );
+                }
+            });
+        },
+    ));
+    // Whether each materialized view holds data, keyed by view name. Missing
+    // entries just hide the state dot.
+    let mut matview_states = use_signal(HashMap::<String, bool>::new);
+    let has_matviews = node
+        .children
+        .iter()
+        .any(|child| child.kind == ExplorerNodeKind::MaterializedView);
+    use_effect(use_reactive(
+        (&node.name, &has_matviews),
+        move |(schema, has_matviews)| {
+            tree_reload();
+            schema_reload();
+            if !has_matviews {
+                matview_states.set(HashMap::new());
+                return;
+            }
+            let Some(connection) = session_connection(session_id) else {
+                return;
+            };
+            spawn(async move {
+                if let Ok(views) =
+                    services::load_schema_materialized_views(connection, schema).await
+                {
+                    matview_states.set(
+                        views
+                            .into_iter()
+                            .map(|view| (view.name, view.is_populated))
+                            .collect(),
+                    );
+                }
+            });
+        },
+    ));
 
     rsx! {
         div { class: "tree__schema",
-            button {
-                class: "tree__schema-toggle",
-                onclick: move |_| expanded.toggle(),
-                span {
-                    class: if expanded() {
-                        "tree__chevron tree__chevron--open"
-                    } else {
-                        "tree__chevron"
+            div { class: "tree__schema-header",
+                button {
+                    class: "tree__schema-toggle",
+                    onclick: move |_| expanded.toggle(),
+                    ondoubleclick: move |_| schema_reload += 1,
+                    oncontextmenu: move |event: MouseEvent| {
+                        if session_kind != Some(DatabaseKind::Postgres) {
+                            return;
+                        }
+                        event.prevent_default();
+                        event.stop_propagation();
+                        let point = event.client_coordinates();
+                        context_menu.set(Some(TableContextMenuAnchor {
+                            x: point.x,
+                            y: point.y,
+                        }));
                     },
-                    ">"
-                }
-                div {
-                    class: "tree__schema-copy",
-                    span { class: "tree__schema-title", "{node.name}" }
                     span {
-                        class: "tree__schema-meta",
-                        "{object_count} objects"
+                        class: if expanded() {
+                            "tree__chevron tree__chevron--open"
+                        } else {
+                            "tree__chevron"
+                        },
+                        ">"
+                    }
+                    div {
+                        class: "tree__schema-copy",
+                        span {
+                            class: "tree__schema-title",
+                            {highlighted_label(&node.name, &filter)}
+                        }
+                        span {
+                            class: "tree__schema-meta",
+                            "{object_count} objects"
+                        }
+                    }
+                }
+                if has_lazy_groups || has_table_catalog {
+                    div { class: "tree__schema-actions",
+                        IconButton {
+                            icon: ActionIcon::Refresh,
+                            label: if has_lazy_groups {
+                                format!("Refresh functions, sequences and table sizes in {}", node.name)
+                            } else {
+                                format!("Refresh table sizes in {}", node.name)
+                            },
+                            small: true,
+                            onclick: move |_| schema_reload += 1,
+                        }
                     }
                 }
             }
 
             if expanded() {
                 div { class: "tree__schema-body",
-                    if !tables.is_empty() {
+                    for (title, nodes) in sections {
                         ExplorerGroupView {
-                            title: "Tables".to_string(),
+                            key: "{title}",
+                            title: title.to_string(),
                             session_id,
                             tree_reload,
-                            nodes: tables,
+                            nodes,
                             tabs,
                             active_tab_id,
                             next_tab_id,
                             selected_node,
+                            matview_states,
+                            table_catalog,
+                            filter: filter.clone(),
                         }
                     }
-                    if !views.is_empty() {
-                        ExplorerGroupView {
-                            title: "Views".to_string(),
-                            session_id,
-                            tree_reload,
-                            nodes: views,
-                            tabs,
-                            active_tab_id,
-                            next_tab_id,
-                            selected_node,
-                        }
+                    ExplorerRoutineGroup {
+                        session_id,
+                        schema: node.name.clone(),
+                        tree_reload,
+                        schema_reload,
+                        tabs,
+                        active_tab_id,
+                        next_tab_id,
+                    }
+                    ExplorerSequenceGroup {
+                        session_id,
+                        schema: node.name.clone(),
+                        tree_reload,
+                        schema_reload,
                     }
                 }
             }
+
+            if let Some(anchor) = context_menu() {
+                SchemaContextMenu {
+                    anchor,
+                    context_menu,
+                    on_size_report: {
+                        let schema = node.name.clone();
+                        move |_| {
+                            size_report.set(Some(SizeReportTarget {
+                                session_id,
+                                schema: schema.clone(),
+                            }));
+                        }
+                    },
+                }
+            }
+            if let Some(target) = size_report() {
+                SizeReportModal { target, size_report }
+            }
         }
     }
 }
@@ -187,6 +472,9 @@ fn ExplorerGroupView(
     active_tab_id: Signal<u64>,
     next_tab_id: Signal<u64>,
     selected_node: Signal<String>,
+    matview_states: Signal<HashMap<String, bool>>,
+    table_catalog: Signal<HashMap<String, TableCatalogInfo>>,
+    filter: String,
 ) -> Element {
     rsx! {
         div { class: "tree__group",
@@ -201,9 +489,359 @@ fn ExplorerGroupView(
                         active_tab_id,
                         next_tab_id,
                         selected_node,
+                        matview_states,
+                        table_catalog,
+                        filter: filter.clone(),
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Functions and procedures of one schema, loaded the first time the group is
+/// expanded so large catalogs do not slow down the tree.
+#[component]
+fn ExplorerRoutineGroup(
+    session_id: u64,
+    schema: String,
+    tree_reload: Signal<u64>,
+    schema_reload: Signal<u64>,
+    tabs: Signal<Vec<QueryTabState>>,
+    active_tab_id: Signal<u64>,
+    next_tab_id: Signal<u64>,
+) -> Element {
+    let mut expanded = use_signal(|| false);
+    let mut routines = use_signal(|| None::<Result<Vec<RoutineInfo>, String>>);
+    let routine_source = use_signal(|| None::<RoutineSourceTarget>);
+    let connection_kind = APP_STATE
+        .read()
+        .session(session_id)
+        .map(|session| session.kind)
+        .unwrap_or(DatabaseKind::Sqlite);
+    let load_routines = {
+        let schema = schema.clone();
+        move || {
+            let schema = schema.clone();
+            spawn(async move {
+                let result = match session_connection(session_id) {
+                    Some(connection) => services::load_schema_routines(connection, schema)
+                        .await
+                        .map_err(|err| err.to_string()),
+                    None => Err("Connection is no longer open.".to_string()),
+                };
+                routines.set(Some(result));
+            });
+        }
+    };
+
+    use_effect({
+        let load_routines = load_routines.clone();
+        move || {
+            tree_reload();
+            schema_reload();
+            routines.set(None);
+            if *expanded.peek() {
+                load_routines();
+            }
+        }
+    });
+
+    if !matches!(
+        connection_kind,
+        DatabaseKind::Postgres | DatabaseKind::MySql
+    ) {
+        return rsx! {};
+    }
+
+    let header_meta = match routines() {
+        Some(Ok(items)) => items.len().to_string(),
+        Some(Err(_)) => "!".to_string(),
+        None => String::new(),
+    };
+
+    rsx! {
+        div { class: "tree__group",
+            button {
+                class: "tree__group-header tree__group-header--toggle",
+                onclick: move |_| {
+                    expanded.toggle();
+                    if expanded() && routines.peek().is_none() {
+                        load_routines();
+                    }
+                },
+                span {
+                    class: if expanded() {
+                        "tree__chevron tree__chevron--open"
+                    } else {
+                        "tree__chevron"
+                    },
+                    ">"
+                }
+                span { "Functions" }
+                if !header_meta.is_empty() {
+                    span { class: "tree__group-count", "{header_meta}" }
+                }
+            }
+
+            if expanded() {
+                div { class: "tree__group-items",
+                    match routines() {
+                        None => rsx! {
+                            p { class: "tree__group-empty", "Loading…" }
+                        },
+                        Some(Err(err)) => rsx! {
+                            p {
+                                class: "tree__group-empty tree__group-empty--error",
+                                title: "{err}",
+                                "{err}"
+                            }
+                        },
+                        Some(Ok(items)) if items.is_empty() => rsx! {
+                            p { class: "tree__group-empty", "No functions" }
+                        },
+                        Some(Ok(items)) => rsx! {
+                            for routine in items {
+                                ExplorerRoutineRow {
+                                    key: "{routine.identity}",
+                                    routine,
+                                    session_id,
+                                    kind: connection_kind,
+                                    tabs,
+                                    active_tab_id,
+                                    next_tab_id,
+                                    routine_source,
+                                }
+                            }
+                        },
+                    }
+                }
+            }
+
+            if let Some(target) = routine_source() {
+                RoutineSourceModal {
+                    key: "{target.routine.identity}",
+                    target,
+                    tabs,
+                    active_tab_id,
+                    next_tab_id,
+                    routine_source,
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn ExplorerRoutineRow(
+    routine: RoutineInfo,
+    session_id: u64,
+    kind: DatabaseKind,
+    tabs: Signal<Vec<QueryTabState>>,
+    active_tab_id: Signal<u64>,
+    next_tab_id: Signal<u64>,
+    mut routine_source: Signal<Option<RoutineSourceTarget>>,
+) -> Element {
+    let kind_badge = match routine.kind {
+        RoutineKind::Function => "ƒ",
+        RoutineKind::Procedure => "P",
+    };
+    let signature = routine.signature();
+    let details = routine.summary();
+
+    rsx! {
+        div { class: "tree__object-row",
+            button {
+                class: "tree__object",
+                title: "{signature}",
+                onclick: {
+                    let routine = routine.clone();
+                    move |_| {
+                        activate_session(session_id);
+                        routine_source.set(Some(RoutineSourceTarget {
+                            session_id,
+                            kind,
+                            routine: routine.clone(),
+                        }));
+                    }
+                },
+                div {
+                    class: "tree__object-badge tree__object-badge--routine",
+                    "{kind_badge}"
+                }
+                div {
+                    class: "tree__object-copy",
+                    div { class: "tree__object-name", "{signature}" }
+                    div { class: "tree__object-kind", "{details}" }
+                }
+            }
+            div { class: "tree__object-actions",
+                IconButton {
+                    icon: ActionIcon::SqlEditor,
+                    label: format!("Insert call to {}", routine.name),
+                    small: true,
+                    onclick: {
+                        let routine = routine.clone();
+                        move |event: MouseEvent| {
+                            event.stop_propagation();
+                            let tab_id =
+                                ensure_tab_for_session(tabs, active_tab_id, next_tab_id, session_id);
+                            if tab_id == 0 {
+                                return;
+                            }
+                            append_to_tab_sql(
+                                tabs,
+                                tab_id,
+                                routine_call_template(kind, &routine),
+                                format!("Inserted call to {}", routine.name),
+                            );
+                        }
+                    },
+                }
+            }
+        }
+    }
+}
+
+/// Sequences of one schema, loaded on first expand like the functions group.
+#[component]
+fn ExplorerSequenceGroup(
+    session_id: u64,
+    schema: String,
+    tree_reload: Signal<u64>,
+    schema_reload: Signal<u64>,
+) -> Element {
+    let mut expanded = use_signal(|| false);
+    let mut sequences = use_signal(|| None::<Result<Vec<SequenceInfo>, String>>);
+    let mut selected_sequence = use_signal(|| None::<SequenceTarget>);
+    let is_postgres = APP_STATE
+        .read()
+        .session(session_id)
+        .is_some_and(|session| session.kind == DatabaseKind::Postgres);
+
+    let load_sequences = {
+        let schema = schema.clone();
+        move || {
+            let schema = schema.clone();
+            spawn(async move {
+                let result = match session_connection(session_id) {
+                    Some(connection) => services::load_schema_sequences(connection, schema)
+                        .await
+                        .map_err(|err| err.to_string()),
+                    None => Err("Connection is no longer open.".to_string()),
+                };
+                sequences.set(Some(result));
+            });
+        }
+    };
+
+    use_effect({
+        let load_sequences = load_sequences.clone();
+        move || {
+            tree_reload();
+            schema_reload();
+            sequences.set(None);
+            if *expanded.peek() {
+                load_sequences();
+            }
+        }
+    });
+
+    if !is_postgres {
+        return rsx! {};
+    }
+    let header_meta = match sequences() {
+        Some(Ok(items)) => items.len().to_string(),
+        Some(Err(_)) => "!".to_string(),
+        None => String::new(),
+    };
+
+    rsx! {
+        div { class: "tree__group",
+            button {
+                class: "tree__group-header tree__group-header--toggle",
+                onclick: {
+                    let load_sequences = load_sequences.clone();
+                    move |_| {
+                        expanded.toggle();
+                        if expanded() && sequences.peek().is_none() {
+                            load_sequences();
+                        }
+                    }
+                },
+                span {
+                    class: if expanded() {
+                        "tree__chevron tree__chevron--open"
+                    } else {
+                        "tree__chevron"
+                    },
+                    ">"
+                }
+                span { "Sequences" }
+                if !header_meta.is_empty() {
+                    span { class: "tree__group-count", "{header_meta}" }
+                }
+            }
+
+            if expanded() {
+                div { class: "tree__group-items",
+                    match sequences() {
+                        None => rsx! {
+                            p { class: "tree__group-empty", "Loading…" }
+                        },
+                        Some(Err(err)) => rsx! {
+                            p {
+                                class: "tree__group-empty tree__group-empty--error",
+                                title: "{err}",
+                                "{err}"
+                            }
+                        },
+                        Some(Ok(items)) if items.is_empty() => rsx! {
+                            p { class: "tree__group-empty", "No sequences" }
+                        },
+                        Some(Ok(items)) => rsx! {
+                            for sequence in items {
+                                div {
+                                    key: "{sequence.name}",
+                                    class: "tree__object-row",
+                                    button {
+                                        class: "tree__object",
+                                        title: "{sequence.limits()}",
+                                        onclick: {
+                                            let sequence = sequence.clone();
+                                            move |_| {
+                                                activate_session(session_id);
+                                                selected_sequence.set(Some(SequenceTarget {
+                                                    session_id,
+                                                    sequence: sequence.clone(),
+                                                }));
+                                            }
+                                        },
+                                        div { class: "tree__object-badge tree__object-badge--sequence", "S" }
+                                        div {
+                                            class: "tree__object-copy",
+                                            div { class: "tree__object-name", "{sequence.name}" }
+                                            div {
+                                                class: "tree__object-kind",
+                                                "{sequence.summary()}"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        },
                     }
                 }
             }
+
+            if let Some(target) = selected_sequence() {
+                SequenceModal {
+                    key: "{target.sequence.name}",
+                    target,
+                    selected_sequence,
+                    on_changed: move |_| load_sequences(),
+                }
+            }
         }
     }
 }
@@ -217,9 +855,27 @@ fn ExplorerObjectRow(
     active_tab_id: Signal<u64>,
     next_tab_id: Signal<u64>,
     selected_node: Signal<String>,
+    matview_states: Signal<HashMap<String, bool>>,
+    table_catalog: Signal<HashMap<String, TableCatalogInfo>>,
+    filter: String,
 ) -> Element {
     let mut table_mutation_inflight = use_signal(|| None::<TableMutationKind>);
+    let matview_refresh_inflight = use_signal(|| false);
     let mut show_duplicate_table = use_signal(|| false);
+    let mut table_ddl = use_signal(|| None::<TableDdlTarget>);
+    let mut comment_target = use_signal(|| None::<CommentTarget>);
+    let mut pg_dump_target = use_signal(|| None::<PgDumpTarget>);
+    let mut row_security_target = use_signal(|| None::<RowSecurityTarget>);
+    let mut privileges_target = use_signal(|| None::<PrivilegesTarget>);
+    let mut indexes_target = use_signal(|| None::<IndexesTarget>);
+    let mut maintenance_target = use_signal(|| None::<MaintenanceTarget>);
+    let mut health_target = use_signal(|| None::<TableHealthTarget>);
+    let mut csv_import_target = use_signal(|| None::<CsvImportTarget>);
+    let mut insert_row_target = use_signal(|| None::<InsertRowTarget>);
+    let mut columns_expanded = use_signal(|| false);
+    let mut columns = use_signal(|| None::<Result<Vec<ColumnInfo>, String>>);
+    let mut context_menu = use_signal(|| None::<TableContextMenuAnchor>);
+    let mut typed_confirmation = use_signal(|| None::<TableMutationKind>);
     let (connection_name, connection_kind) = APP_STATE
         .read()
         .session(session_id)
@@ -234,17 +890,140 @@ fn ExplorerObjectRow(
     let can_duplicate_table = node.kind == ExplorerNodeKind::Table;
     let can_truncate_table = node.kind == ExplorerNodeKind::Table;
     let can_drop_table = node.kind == ExplorerNodeKind::Table;
+    let can_import_csv = node.kind == ExplorerNodeKind::Table;
     let read_only_mode = read_only_mode_enabled();
+    // Table DDL runs on the pool and would wait on locks held by the session's
+    // own open transaction, so it stays blocked until that transaction ends.
+    let transaction_open = session_transaction_status(session_id) != TransactionStatus::Idle;
     let kind_badge = match node.kind {
         ExplorerNodeKind::Table => "T",
         ExplorerNodeKind::View => "V",
+        ExplorerNodeKind::MaterializedView => "M",
+        ExplorerNodeKind::ForeignTable => "F",
         ExplorerNodeKind::Schema => "",
     };
-    let kind_label = match node.kind {
-        ExplorerNodeKind::Table => "Table",
-        ExplorerNodeKind::View => "View",
-        ExplorerNodeKind::Schema => "Schema",
+    let badge_class = match node.kind {
+        ExplorerNodeKind::View => "tree__object-badge tree__object-badge--view",
+        ExplorerNodeKind::MaterializedView => "tree__object-badge tree__object-badge--matview",
+        ExplorerNodeKind::ForeignTable => "tree__object-badge tree__object-badge--foreign",
+        ExplorerNodeKind::Table | ExplorerNodeKind::Schema => "tree__object-badge",
+    };
+    let kind_label = node.kind.label();
+    let matview_populated = if node.kind == ExplorerNodeKind::MaterializedView {
+        matview_states.read().get(&node.name).copied()
+    } else {
+        None
+    };
+    let (size_label, table_comment) = table_catalog
+        .read()
+        .get(&node.name)
+        .map(|info| (table_size_label(info), info.comment.clone()))
+        .unwrap_or_default();
+    let name_title = match &table_comment {
+        Some(comment) => format!("{}\n{comment}", node.qualified_name),
+        None => node.qualified_name.clone(),
+    };
+    // MySQL keeps column comments inside the column definition, so only
+    // PostgreSQL and ClickHouse columns get the editor.
+    let can_comment_columns = match connection_kind {
+        DatabaseKind::Postgres => true,
+        DatabaseKind::ClickHouse => node.kind == ExplorerNodeKind::Table,
+        DatabaseKind::MySql | DatabaseKind::Sqlite => false,
     };
+    // A table kept by the filter only for its column names opens on the
+    // matching columns.
+    let matched_columns = if filter.is_empty() || split_filter_match(&node.name, &filter).is_some()
+    {
+        Vec::new()
+    } else {
+        APP_COLUMN_INDEX
+            .read()
+            .get(&session_id)
+            .map(|columns| {
+                matching_columns(columns, &node, &filter)
+                    .into_iter()
+                    .map(str::to_string)
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default()
+    };
+    let column_filtered = !matched_columns.is_empty();
+    let mutation_context = TableMutationContext {
+        session_id,
+        tabs,
+        tree_reload,
+        selected_node,
+        inflight: table_mutation_inflight,
+    };
+
+    let browse_table = {
+        let source = preview_source.clone();
+        let qualified_name = node.qualified_name.clone();
+        move || {
+            selected_node.set(qualified_name.clone());
+            let current_id = ensure_tab_for_session(tabs, active_tab_id, next_tab_id, session_id);
+            let current_tab = tabs.read().iter().find(|tab| tab.id == current_id).cloned();
+            let Some(current_tab) = current_tab else {
+                return;
+            };
+
+            let Some(connection) =
+                tab_connection_or_error(tabs, current_id, current_tab.session_id)
+            else {
+                return;
+            };
+
+            run_table_preview_for_tab(
+                tabs,
+                current_id,
+                connection,
+                source.clone(),
+                0,
+                current_tab.page_size,
+            );
+        }
+    };
+
+    let load_columns = {
+        let schema = node.schema.clone();
+        let table = node.name.clone();
+        move || {
+            let schema = schema.clone();
+            let table = table.clone();
+            spawn(async move {
+                let result = match session_connection(session_id) {
+                    Some(connection) => services::load_table_column_info(connection, schema, table)
+                        .await
+                        .map_err(|err| err.to_string()),
+                    None => Err("Connection is no longer open.".to_string()),
+                };
+                columns.set(Some(result));
+            });
+        }
+    };
+
+    use_effect({
+        let load_columns = load_columns.clone();
+        move || {
+            tree_reload();
+            columns.set(None);
+            if *columns_expanded.peek() {
+                load_columns();
+            }
+        }
+    });
+
+    use_effect(use_reactive((&column_filtered,), {
+        let load_columns = load_columns.clone();
+        move |(column_filtered,)| {
+            if column_filtered && !*columns_expanded.peek() {
+                columns_expanded.set(true);
+                if columns.peek().is_none() {
+                    load_columns();
+                }
+            }
+        }
+    }));
 
     rsx! {
         div {
@@ -253,6 +1032,41 @@ fn ExplorerObjectRow(
             } else {
                 "tree__object-row"
             },
+            oncontextmenu: {
+                let qualified_name = node.qualified_name.clone();
+                move |event: MouseEvent| {
+                    event.prevent_default();
+                    event.stop_propagation();
+                    selected_node.set(qualified_name.clone());
+                    let point = event.client_coordinates();
+                    context_menu.set(Some(TableContextMenuAnchor {
+                        x: point.x,
+                        y: point.y,
+                    }));
+                }
+            },
+            button {
+                class: "tree__object-toggle",
+                title: if columns_expanded() { "Hide columns" } else { "Show columns" },
+                onclick: {
+                    let load_columns = load_columns.clone();
+                    move |event: MouseEvent| {
+                        event.stop_propagation();
+                        columns_expanded.toggle();
+                        if columns_expanded() && columns.peek().is_none() {
+                            load_columns();
+                        }
+                    }
+                },
+                span {
+                    class: if columns_expanded() {
+                        "tree__chevron tree__chevron--open"
+                    } else {
+                        "tree__chevron"
+                    },
+                    ">"
+                }
+            }
             button {
                 class: if selected {
                     "tree__object tree__object--selected"
@@ -267,67 +1081,114 @@ fn ExplorerObjectRow(
                     }
                 },
                 ondoubleclick: {
-                    let source = preview_source.clone();
-                    let qualified_name = node.qualified_name.clone();
-                    move |_| {
-                        selected_node.set(qualified_name.clone());
-                        let current_id =
-                            ensure_tab_for_session(tabs, active_tab_id, next_tab_id, session_id);
-                        let current_tab = tabs
-                            .read()
-                            .iter()
-                            .find(|tab| tab.id == current_id)
-                            .cloned();
-                        let Some(current_tab) = current_tab else {
-                            return;
-                        };
-
-                        let Some(connection) =
-                            tab_connection_or_error(tabs, current_id, current_tab.session_id)
-                        else {
-                            return;
-                        };
-
-                        run_table_preview_for_tab(
-                            tabs,
-                            current_id,
-                            connection,
-                            source.clone(),
-                            0,
-                            current_tab.page_size,
-                        );
-                    }
+                    let mut browse_table = browse_table.clone();
+                    move |_| browse_table()
                 },
                 div {
-                    class: "tree__object-badge",
+                    class: "{badge_class}",
                     "{kind_badge}"
                 }
                 div {
                     class: "tree__object-copy",
                     div {
                         class: "tree__object-name",
-                        title: "{node.qualified_name}",
-                        "{node.name}"
+                        title: "{name_title}",
+                        {highlighted_label(&node.name, &filter)}
                     }
                     div { class: "tree__object-kind", "{kind_label}" }
                 }
+                if let Some(size_label) = size_label {
+                    span {
+                        class: "tree__object-size",
+                        title: "Estimated rows and total size from catalog statistics",
+                        "{size_label}"
+                    }
+                }
+                if let Some(populated) = matview_populated {
+                    span {
+                        class: if populated {
+                            "tree__matview-state tree__matview-state--populated"
+                        } else {
+                            "tree__matview-state tree__matview-state--empty"
+                        },
+                        title: if populated { "Populated" } else { "Not populated, refresh to load data" },
+                    }
+                }
             }
-            if can_duplicate_table || can_truncate_table || can_drop_table {
+            if can_duplicate_table || can_truncate_table || can_drop_table || can_import_csv {
                 div { class: "tree__object-actions",
+                    if can_import_csv {
+                        IconButton {
+                            icon: ActionIcon::ImportCsv,
+                            label: if read_only_mode {
+                                format!("Import CSV into {} is blocked by read-only mode", node.name)
+                            } else {
+                                format!("Import CSV into {}", node.name)
+                            },
+                            small: true,
+                            disabled: read_only_mode,
+                            onclick: {
+                                let source = preview_source.clone();
+                                move |event: MouseEvent| {
+                                    event.stop_propagation();
+                                    if read_only_mode_enabled() {
+                                        return;
+                                    }
+                                    let current_id = ensure_tab_for_session(
+                                        tabs,
+                                        active_tab_id,
+                                        next_tab_id,
+                                        session_id,
+                                    );
+                                    let Some(page_size) = tabs
+                                        .read()
+                                        .iter()
+                                        .find(|tab| tab.id == current_id)
+                                        .map(|tab| tab.page_size)
+                                    else {
+                                        return;
+                                    };
+                                    let Some(connection) =
+                                        tab_connection_or_error(tabs, current_id, session_id)
+                                    else {
+                                        return;
+                                    };
+                                    // Preview the target first so the tab refreshes onto it
+                                    // once the import finishes.
+                                    run_table_preview_for_tab(
+                                        tabs,
+                                        current_id,
+                                        connection,
+                                        source.clone(),
+                                        0,
+                                        page_size,
+                                    );
+                                    csv_import_target.set(Some(CsvImportTarget {
+                                        tab_id: current_id,
+                                        source: source.clone(),
+                                    }));
+                                }
+                            },
+                        }
+                    }
                     if can_duplicate_table {
                         IconButton {
                             icon: ActionIcon::Duplicate,
                             label: if read_only_mode {
                                 format!("Duplicate table {} is blocked by read-only mode", node.name)
+                            } else if transaction_open {
+                                format!("Duplicate table {} is blocked while a transaction is open", node.name)
                             } else {
                                 format!("Duplicate table {}", node.name)
                             },
                             small: true,
-                            disabled: table_mutation_inflight().is_some() || read_only_mode,
+                            disabled: table_mutation_inflight().is_some() || read_only_mode || transaction_open,
                             onclick: {
                                 move |event: MouseEvent| {
                                     event.stop_propagation();
-                                    if read_only_mode_enabled() {
+                                    if read_only_mode_enabled()
+                                        || session_transaction(session_id).is_some()
+                                    {
                                         return;
                                     }
                                     show_duplicate_table.set(true);
@@ -340,6 +1201,8 @@ fn ExplorerObjectRow(
                             icon: ActionIcon::Truncate,
                             label: if read_only_mode {
                                 format!("Truncate table {} is blocked by read-only mode", node.name)
+                            } else if transaction_open {
+                                format!("Truncate table {} is blocked while a transaction is open", node.name)
                             } else {
                                 table_mutation_button_label(
                                     TableMutationKind::Truncate,
@@ -348,13 +1211,14 @@ fn ExplorerObjectRow(
                                 )
                             },
                             small: true,
-                            disabled: table_mutation_inflight().is_some() || read_only_mode,
+                            disabled: table_mutation_inflight().is_some() || read_only_mode || transaction_open,
                             onclick: {
                                 let source = preview_source.clone();
                                 move |event: MouseEvent| {
                                     event.stop_propagation();
                                     if table_mutation_inflight().is_some()
                                         || read_only_mode_enabled()
+                                        || session_transaction(session_id).is_some()
                                     {
                                         return;
                                     }
@@ -380,53 +1244,12 @@ fn ExplorerObjectRow(
                                             return;
                                         }
 
-                                        let Some(connection) = session_connection(session_id) else {
-                                            let _ = AsyncMessageDialog::new()
-                                                .set_title(table_mutation_error_title(
-                                                    TableMutationKind::Truncate,
-                                                ))
-                                                .set_description(table_mutation_connection_closed_description(
-                                                    TableMutationKind::Truncate,
-                                                ))
-                                                .set_buttons(MessageButtons::Ok)
-                                                .set_level(MessageLevel::Error)
-                                                .show()
-                                                .await;
-                                            return;
-                                        };
-
-                                        let refresh_connection = connection.clone();
-                                        table_mutation_inflight
-                                            .set(Some(TableMutationKind::Truncate));
-                                        let result =
-                                            services::truncate_table(connection, source.clone()).await;
-                                        table_mutation_inflight.set(None);
-
-                                        match result {
-                                            Ok(()) => {
-                                                mark_table_truncated(
-                                                    tabs,
-                                                    session_id,
-                                                    refresh_connection,
-                                                    source.clone(),
-                                                );
-                                            }
-                                            Err(err) => {
-                                                let _ = AsyncMessageDialog::new()
-                                                    .set_title(table_mutation_error_title(
-                                                        TableMutationKind::Truncate,
-                                                    ))
-                                                    .set_description(format!(
-                                                        "Failed to truncate {}.\n\n{}",
-                                                        source.qualified_name,
-                                                        err
-                                                    ))
-                                                    .set_buttons(MessageButtons::Ok)
-                                                    .set_level(MessageLevel::Error)
-                                                    .show()
-                                                    .await;
-                                            }
-                                        }
+                                        run_table_mutation(
+                                            TableMutationKind::Truncate,
+                                            mutation_context,
+                                            source,
+                                        )
+                                        .await;
                                     });
                                 }
                             },
@@ -436,6 +1259,8 @@ fn ExplorerObjectRow(
                         icon: ActionIcon::Delete,
                         label: if read_only_mode {
                             format!("Drop table {} is blocked by read-only mode", node.name)
+                        } else if transaction_open {
+                            format!("Drop table {} is blocked while a transaction is open", node.name)
                         } else {
                             table_mutation_button_label(
                                 TableMutationKind::Drop,
@@ -444,18 +1269,19 @@ fn ExplorerObjectRow(
                             )
                         },
                         small: true,
-                        disabled: table_mutation_inflight().is_some() || read_only_mode,
+                        disabled: table_mutation_inflight().is_some() || read_only_mode || transaction_open,
                         onclick: {
                             let source = preview_source.clone();
-                            let selected_qualified_name = node.qualified_name.clone();
                             move |event: MouseEvent| {
                                 event.stop_propagation();
-                                if table_mutation_inflight().is_some() || read_only_mode_enabled() {
+                                if table_mutation_inflight().is_some()
+                                    || read_only_mode_enabled()
+                                    || session_transaction(session_id).is_some()
+                                {
                                     return;
                                 }
 
                                 let source = source.clone();
-                                let selected_qualified_name = selected_qualified_name.clone();
 
                                 spawn(async move {
                                     let confirmation = AsyncMessageDialog::new()
@@ -476,55 +1302,209 @@ fn ExplorerObjectRow(
                                         return;
                                     }
 
-                                    let Some(connection) = session_connection(session_id) else {
-                                        let _ = AsyncMessageDialog::new()
-                                            .set_title(table_mutation_error_title(
-                                                TableMutationKind::Drop,
-                                            ))
-                                            .set_description(table_mutation_connection_closed_description(
-                                                TableMutationKind::Drop,
-                                            ))
-                                            .set_buttons(MessageButtons::Ok)
-                                            .set_level(MessageLevel::Error)
-                                            .show()
-                                            .await;
-                                        return;
-                                    };
-
-                                    table_mutation_inflight.set(Some(TableMutationKind::Drop));
-                                    let result = services::drop_table(connection, source.clone()).await;
-                                    table_mutation_inflight.set(None);
-
-                                    match result {
-                                        Ok(()) => {
-                                            if selected_node() == selected_qualified_name {
-                                                selected_node.set(String::new());
-                                            }
-                                            mark_table_deleted(tabs, session_id, source.clone());
-                                            tree_reload += 1;
-                                        }
-                                        Err(err) => {
-                                            let _ = AsyncMessageDialog::new()
-                                                .set_title(table_mutation_error_title(
-                                                    TableMutationKind::Drop,
-                                                ))
-                                                .set_description(format!(
-                                                    "Failed to drop {}.\n\n{}",
-                                                    source.qualified_name,
-                                                    err
-                                                ))
-                                                .set_buttons(MessageButtons::Ok)
-                                                .set_level(MessageLevel::Error)
-                                                .show()
-                                                .await;
-                                        }
-                                    }
+                                    run_table_mutation(
+                                        TableMutationKind::Drop,
+                                        mutation_context,
+                                        source,
+                                    )
+                                    .await;
                                 });
                             }
                         },
                     }
                 }
             }
+            CsvImportDialog { tabs, target: csv_import_target }
+            InsertRowDialog { tabs, target: insert_row_target }
+            if let Some(anchor) = context_menu() {
+                TableContextMenu {
+                    anchor,
+                    context_menu,
+                    node: node.clone(),
+                    session_id,
+                    kind: connection_kind,
+                    tabs,
+                    active_tab_id,
+                    next_tab_id,
+                    can_mutate: table_mutation_inflight().is_none()
+                        && !matview_refresh_inflight()
+                        && !read_only_mode
+                        && !transaction_open,
+                    populated: matview_populated,
+                    on_browse: {
+                        let mut browse_table = browse_table.clone();
+                        move |_| browse_table()
+                    },
+                    on_show_ddl: {
+                        let source = preview_source.clone();
+                        move |_| {
+                            table_ddl.set(Some(TableDdlTarget {
+                                session_id,
+                                source: source.clone(),
+                            }));
+                        }
+                    },
+                    on_edit_comment: {
+                        let source = preview_source.clone();
+                        move |_| {
+                            comment_target.set(Some(CommentTarget {
+                                session_id,
+                                source: source.clone(),
+                                column: None,
+                                comment: table_comment.clone().unwrap_or_default(),
+                            }));
+                        }
+                    },
+                    on_pg_dump: {
+                        let source = preview_source.clone();
+                        move |_| {
+                            pg_dump_target.set(Some(PgDumpTarget {
+                                session_id,
+                                schema: source.schema.clone(),
+                                table: Some(source.table_name.clone()),
+                            }));
+                        }
+                    },
+                    on_row_security: {
+                        let source = preview_source.clone();
+                        move |_| {
+                            row_security_target.set(Some(RowSecurityTarget {
+                                session_id,
+                                source: source.clone(),
+                            }));
+                        }
+                    },
+                    on_privileges: {
+                        let source = preview_source.clone();
+                        move |_| {
+                            privileges_target.set(Some(PrivilegesTarget {
+                                session_id,
+                                source: source.clone(),
+                            }));
+                        }
+                    },
+                    on_indexes: {
+                        let source = preview_source.clone();
+                        move |_| {
+                            indexes_target.set(Some(IndexesTarget {
+                                session_id,
+                                source: source.clone(),
+                            }));
+                        }
+                    },
+                    on_maintenance: {
+                        let source = preview_source.clone();
+                        move |_| {
+                            maintenance_target.set(Some(MaintenanceTarget {
+                                session_id,
+                                source: source.clone(),
+                            }));
+                        }
+                    },
+                    on_health: {
+                        let source = preview_source.clone();
+                        move |_| {
+                            health_target.set(Some(TableHealthTarget {
+                                session_id,
+                                source: source.clone(),
+                            }));
+                        }
+                    },
+                    on_insert_row: {
+                        let source = preview_source.clone();
+                        move |_| {
+                            if read_only_mode_enabled() {
+                                return;
+                            }
+                            let current_id = ensure_tab_for_session(
+                                tabs,
+                                active_tab_id,
+                                next_tab_id,
+                                session_id,
+                            );
+                            let Some(page_size) = tabs
+                                .read()
+                                .iter()
+                                .find(|tab| tab.id == current_id)
+                                .map(|tab| tab.page_size)
+                            else {
+                                return;
+                            };
+                            let Some(connection) =
+                                tab_connection_or_error(tabs, current_id, session_id)
+                            else {
+                                return;
+                            };
+                            // Preview the table so the inserted row shows up once the
+                            // tab refreshes.
+                            run_table_preview_for_tab(
+                                tabs,
+                                current_id,
+                                connection,
+                                source.clone(),
+                                0,
+                                page_size,
+                            );
+                            insert_row_target.set(Some(InsertRowTarget {
+                                tab_id: current_id,
+                                session_id,
+                                source: source.clone(),
+                                prefill: None,
+                            }));
+                        }
+                    },
+                    on_truncate: move |_| typed_confirmation.set(Some(TableMutationKind::Truncate)),
+                    on_drop: move |_| typed_confirmation.set(Some(TableMutationKind::Drop)),
+                    on_refresh: {
+                        let source = preview_source.clone();
+                        move |concurrently: bool| {
+                            if matview_refresh_inflight()
+                                || read_only_mode_enabled()
+                                || session_transaction(session_id).is_some()
+                            {
+                                return;
+                            }
+                            spawn(run_materialized_view_refresh(
+                                session_id,
+                                tabs,
+                                source.clone(),
+                                concurrently,
+                                matview_refresh_inflight,
+                                matview_states,
+                            ));
+                        }
+                    },
+                }
+            }
+            if let Some(action) = typed_confirmation() {
+                TypedConfirmModal {
+                    title: table_mutation_dialog_title(action).to_string(),
+                    description: table_mutation_confirmation_description(
+                        action,
+                        connection_kind,
+                        &preview_source,
+                    ),
+                    expected: node.name.clone(),
+                    confirm_label: table_mutation_button_label(action, &node.name, false),
+                    on_cancel: move |_| typed_confirmation.set(None),
+                    on_confirm: {
+                        let source = preview_source.clone();
+                        move |_| {
+                            typed_confirmation.set(None);
+                            if table_mutation_inflight().is_some()
+                                || read_only_mode_enabled()
+                                || session_transaction(session_id).is_some()
+                            {
+                                return;
+                            }
+                            let source = source.clone();
+                            spawn(async move {
+                                run_table_mutation(action, mutation_context, source).await;
+                            });
+                        }
+                    },
+                }
+            }
             if show_duplicate_table() {
                 DuplicateTableModal {
                     target: DuplicateTableTarget {
@@ -538,14 +1518,374 @@ fn ExplorerObjectRow(
                     show_duplicate_table,
                 }
             }
+            if let Some(target) = table_ddl() {
+                TableDdlModal {
+                    target,
+                    tabs,
+                    active_tab_id,
+                    next_tab_id,
+                    table_ddl,
+                }
+            }
+            if let Some(target) = pg_dump_target() {
+                PgDumpModal { target, pg_dump_target }
+            }
+            if let Some(target) = row_security_target() {
+                RowSecurityModal { target, row_security_target }
+            }
+            if let Some(target) = privileges_target() {
+                PrivilegesModal {
+                    target,
+                    privileges_target,
+                    tabs,
+                    active_tab_id,
+                    next_tab_id,
+                }
+            }
+            if let Some(target) = indexes_target() {
+                IndexesModal { target, indexes_target }
+            }
+            if let Some(target) = maintenance_target() {
+                MaintenanceModal { target, maintenance_target }
+            }
+            if let Some(target) = health_target() {
+                TableHealthModal { target, health_target }
+            }
+            if let Some(target) = comment_target() {
+                CommentModal {
+                    target: target.clone(),
+                    comment_target,
+                    on_saved: {
+                        let table_name = node.name.clone();
+                        move |comment: Option<String>| {
+                            save_comment(
+                                table_catalog,
+                                columns,
+                                &table_name,
+                                target.column.as_deref(),
+                                comment,
+                            );
+                        }
+                    },
+                }
+            }
+        }
+        if columns_expanded() {
+            div { class: "tree__columns",
+                match columns() {
+                    None => rsx! {
+                        p { class: "tree__group-empty", "Loading…" }
+                    },
+                    Some(Err(err)) => rsx! {
+                        p {
+                            class: "tree__group-empty tree__group-empty--error",
+                            title: "{err}",
+                            "{err}"
+                        }
+                    },
+                    Some(Ok(items)) if items.is_empty() => rsx! {
+                        p { class: "tree__group-empty", "No columns" }
+                    },
+                    Some(Ok(items)) => rsx! {
+                        for column in items
+                            .into_iter()
+                            .filter(|column| !column_filtered || matched_columns.contains(&column.name))
+                        {
+                            ExplorerColumnRow {
+                                key: "{column.name}",
+                                column,
+                                filter: if column_filtered { filter.clone() } else { String::new() },
+                                table_name: node.name.clone(),
+                                source: preview_source.clone(),
+                                can_edit_comment: can_comment_columns && !read_only_mode,
+                                comment_target,
+                                session_id,
+                                tabs,
+                                active_tab_id,
+                                next_tab_id,
+                            }
+                        }
+                    },
+                }
+            }
         }
     }
 }
 
+#[component]
+fn ExplorerColumnRow(
+    column: ColumnInfo,
+    /// Tree filter to highlight in the column name, empty for none.
+    filter: String,
+    table_name: String,
+    source: TablePreviewSource,
+    can_edit_comment: bool,
+    mut comment_target: Signal<Option<CommentTarget>>,
+    session_id: u64,
+    tabs: Signal<Vec<QueryTabState>>,
+    active_tab_id: Signal<u64>,
+    next_tab_id: Signal<u64>,
+) -> Element {
+    let (key_class, key_label) = if column.primary_key {
+        ("tree__column-key tree__column-key--primary", "PK")
+    } else if column.foreign_key {
+        ("tree__column-key tree__column-key--foreign", "FK")
+    } else {
+        ("tree__column-key", "")
+    };
+    let nullability = if column.nullable { "null" } else { "not null" };
+    let title = match &column.comment {
+        Some(comment) => format!(
+            "{} {} {nullability}. Click to insert.\n{comment}",
+            column.name, column.data_type
+        ),
+        None => format!(
+            "{} {} {nullability}. Click to insert.",
+            column.name, column.data_type
+        ),
+    };
+    let column_name = column.name.clone();
+    let column_comment = column.comment.clone().unwrap_or_default();
+
+    rsx! {
+        div {
+            class: "tree__column",
+            title: "{title}",
+            onclick: move |_| {
+                let kind = APP_STATE
+                    .read()
+                    .session(session_id)
+                    .map(|session| session.kind)
+                    .unwrap_or(DatabaseKind::Sqlite);
+                let text = quoted_table_name_preview(kind, Some(&table_name), &column.name);
+                let status = format!("Inserted {table_name}.{}", column.name);
+                // The open editor takes the name at its caret; otherwise it
+                // goes to the end of the session's query tab.
+                let current_id = active_tab_id();
+                let editor_shows_session = APP_SHOW_SQL_EDITOR()
+                    && tabs.read().iter().any(|tab| {
+                        tab.id == current_id
+                            && tab.session_id == session_id
+                            && tab.tab_kind == WorkspaceTabKind::Query
+                    });
+                if editor_shows_session {
+                    spawn(async move {
+                        if insert_text_at_editor_cursor(&text).await {
+                            set_active_tab_status(tabs, current_id, status);
+                        } else {
+                            insert_text_at_end_of_tab_sql(tabs, current_id, text, status);
+                        }
+                    });
+                    return;
+                }
+                let tab_id = ensure_tab_for_session(tabs, active_tab_id, next_tab_id, session_id);
+                if tab_id == 0 {
+                    return;
+                }
+                insert_text_at_end_of_tab_sql(tabs, tab_id, text, status);
+            },
+            span { class: "{key_class}", "{key_label}" }
+            span { class: "tree__column-name", {highlighted_label(&column.name, &filter)} }
+            span { class: "tree__column-type", "{column.data_type}" }
+            if can_edit_comment {
+                div { class: "tree__column-actions",
+                    IconButton {
+                        icon: ActionIcon::Comment,
+                        label: format!("Edit comment on {column_name}"),
+                        small: true,
+                        onclick: move |event: MouseEvent| {
+                            event.stop_propagation();
+                            comment_target.set(Some(CommentTarget {
+                                session_id,
+                                source: source.clone(),
+                                column: Some(column_name.clone()),
+                                comment: column_comment.clone(),
+                            }));
+                        },
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Keeps the tree in step with a saved comment without reloading the schema.
+fn save_comment(
+    mut table_catalog: Signal<HashMap<String, TableCatalogInfo>>,
+    mut columns: Signal<Option<Result<Vec<ColumnInfo>, String>>>,
+    table_name: &str,
+    column: Option<&str>,
+    comment: Option<String>,
+) {
+    match column {
+        Some(column) => columns.with_mut(|columns| {
+            if let Some(Ok(items)) = columns
+                && let Some(item) = items.iter_mut().find(|item| item.name == column)
+            {
+                item.comment = comment;
+            }
+        }),
+        None => table_catalog.with_mut(|catalog| {
+            catalog
+                .entry(table_name.to_string())
+                .or_insert_with(|| TableCatalogInfo {
+                    name: table_name.to_string(),
+                    estimated_rows: None,
+                    total_bytes: None,
+                    comment: None,
+                })
+                .comment = comment;
+        }),
+    }
+}
+
+/// Renders `label` with the part matching the tree filter wrapped in a mark.
+fn highlighted_label(label: &str, filter: &str) -> Element {
+    match split_filter_match(label, filter) {
+        Some((before, matched, after)) => rsx! {
+            "{before}"
+            mark { class: "tree__match", "{matched}" }
+            "{after}"
+        },
+        None => rsx! { "{label}" },
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Table mutation helpers
 // ---------------------------------------------------------------------------
 
+/// State a truncate or drop updates once the statement has run.
+#[derive(Clone, Copy)]
+struct TableMutationContext {
+    session_id: u64,
+    tabs: Signal<Vec<QueryTabState>>,
+    tree_reload: Signal<u64>,
+    selected_node: Signal<String>,
+    inflight: Signal<Option<TableMutationKind>>,
+}
+
+/// Runs an already confirmed truncate or drop and reports failures in a
+/// native dialog.
+async fn run_table_mutation(
+    action: TableMutationKind,
+    context: TableMutationContext,
+    source: TablePreviewSource,
+) {
+    let TableMutationContext {
+        session_id,
+        tabs,
+        mut tree_reload,
+        mut selected_node,
+        mut inflight,
+    } = context;
+
+    let Some(connection) = session_connection(session_id) else {
+        let _ = AsyncMessageDialog::new()
+            .set_title(table_mutation_error_title(action))
+            .set_description(table_mutation_connection_closed_description(action))
+            .set_buttons(MessageButtons::Ok)
+            .set_level(MessageLevel::Error)
+            .show()
+            .await;
+        return;
+    };
+
+    let refresh_connection = connection.clone();
+    inflight.set(Some(action));
+    let result = match action {
+        TableMutationKind::Truncate => services::truncate_table(connection, source.clone()).await,
+        TableMutationKind::Drop => services::drop_table(connection, source.clone()).await,
+    };
+    inflight.set(None);
+
+    match result {
+        Ok(()) => match action {
+            TableMutationKind::Truncate => {
+                mark_table_truncated(tabs, session_id, refresh_connection, source);
+            }
+            TableMutationKind::Drop => {
+                if selected_node() == source.qualified_name {
+                    selected_node.set(String::new());
+                }
+                mark_table_deleted(tabs, session_id, source);
+                tree_reload += 1;
+            }
+        },
+        Err(err) => {
+            let verb = match action {
+                TableMutationKind::Truncate => "truncate",
+                TableMutationKind::Drop => "drop",
+            };
+            let _ = AsyncMessageDialog::new()
+                .set_title(table_mutation_error_title(action))
+                .set_description(format!(
+                    "Failed to {verb} {}.\n\n{}",
+                    source.qualified_name, err
+                ))
+                .set_buttons(MessageButtons::Ok)
+                .set_level(MessageLevel::Error)
+                .show()
+                .await;
+        }
+    }
+}
+
+/// Refreshes a materialized view, marks it populated in the tree and reloads
+/// any preview tab showing it. Failures, such as a concurrent refresh on a view
+/// without a unique index, are reported as a toast.
+async fn run_materialized_view_refresh(
+    session_id: u64,
+    tabs: Signal<Vec<QueryTabState>>,
+    source: TablePreviewSource,
+    concurrently: bool,
+    mut inflight: Signal<bool>,
+    mut matview_states: Signal<HashMap<String, bool>>,
+) {
+    let Some(connection) = session_connection(session_id) else {
+        toast_error("Connection is no longer open.");
+        return;
+    };
+
+    inflight.set(true);
+    let result =
+        services::refresh_materialized_view(connection.clone(), source.clone(), concurrently).await;
+    inflight.set(false);
+
+    if let Err(err) = result {
+        toast_error(format!(
+            "Failed to refresh {}: {err}",
+            source.qualified_name
+        ));
+        return;
+    }
+
+    matview_states.with_mut(|states| {
+        states.insert(source.table_name.clone(), true);
+    });
+    show_toast(
+        format!("Refreshed {}", source.qualified_name),
+        ToastKind::Success,
+    );
+
+    let preview_tabs = tabs
+        .read()
+        .iter()
+        .filter(|tab| tab.session_id == session_id && tab.preview_source.as_ref() == Some(&source))
+        .map(|tab| (tab.id, tab.current_offset, tab.page_size))
+        .collect::<Vec<_>>();
+    for (tab_id, offset, page_size) in preview_tabs {
+        run_table_preview_for_tab(
+            tabs,
+            tab_id,
+            connection.clone(),
+            source.clone(),
+            offset,
+            page_size,
+        );
+    }
+}
+
 fn table_mutation_button_label(
     action: TableMutationKind,
     table_name: &str,
@@ -0,0 +1,139 @@
+use crate::clipboard::copy_text;
+use crate::screens::workspace::actions::set_active_tab_status;
+use dioxus::prelude::*;
+use models::QueryTabState;
+
+/// Joins rows into tab-separated text that spreadsheets paste cell by cell,
+/// optionally led by a header row.
+pub(crate) fn tsv_block(header: Option<&[String]>, rows: &[Vec<String>]) -> String {
+    header
+        .into_iter()
+        .map(<[String]>::to_vec)
+        .chain(rows.iter().cloned())
+        .map(|row| {
+            row.iter()
+                .map(|value| tsv_field(value))
+                .collect::<Vec<_>>()
+                .join("\t")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Splits text copied from a spreadsheet into rows of cells, undoing the
+/// quoting `tsv_block` applies. Returns `None` unless the text holds a tab or
+/// a line break, since a single value is not a block of cells.
+pub(crate) fn parse_tsv_block(text: &str) -> Option<Vec<Vec<String>>> {
+    let text = text
+        .strip_suffix("\r\n")
+        .or_else(|| text.strip_suffix('\n'))
+        .unwrap_or(text);
+    if text.is_empty() || !text.contains(['\t', '\n']) {
+        return None;
+    }
+
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut chars = text.chars().peekable();
+    let mut at_field_start = true;
+    let mut quoted = false;
+    while let Some(ch) = chars.next() {
+        if quoted {
+            match ch {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => quoted = false,
+                _ => field.push(ch),
+            }
+            continue;
+        }
+        match ch {
+            '"' if at_field_start => {
+                quoted = true;
+                at_field_start = false;
+            }
+            '\t' => {
+                row.push(std::mem::take(&mut field));
+                at_field_start = true;
+            }
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+                at_field_start = true;
+            }
+            _ => {
+                field.push(ch);
+                at_field_start = false;
+            }
+        }
+    }
+    row.push(field);
+    rows.push(row);
+    Some(rows)
+}
+
+/// Copies `text` and reports the outcome in the tab's status line as
+/// "Copied {description}".
+pub(crate) fn copy_grid_text(
+    tabs: Signal<Vec<QueryTabState>>,
+    tab_id: u64,
+    text: String,
+    description: String,
+) {
+    spawn(async move {
+        let status = match copy_text(&text).await {
+            Ok(()) => format!("Copied {description}"),
+            Err(err) => err,
+        };
+        set_active_tab_status(tabs, tab_id, status);
+    });
+}
+
+/// Quotes a field holding a tab, line break or quote, the way spreadsheets
+/// expect in pasted TSV.
+fn tsv_field(value: &str) -> String {
+    if value.contains(['\t', '\n', '\r', '"']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_tsv_block, tsv_block};
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn tsv_block_joins_rows_and_quotes_special_fields() {
+        let rows = vec![strings(&["1", "a\tb"]), strings(&["2", "say \"hi\"\nbye"])];
+
+        assert_eq!(
+            tsv_block(None, &rows),
+            "1\t\"a\tb\"\n2\t\"say \"\"hi\"\"\nbye\""
+        );
+        assert_eq!(
+            tsv_block(Some(&strings(&["id", "note"])), &rows[..1]),
+            "id\tnote\n1\t\"a\tb\""
+        );
+    }
+
+    #[test]
+    fn parse_tsv_block_reads_back_quoted_fields() {
+        let rows = vec![strings(&["1", "a\tb"]), strings(&["2", "say \"hi\"\nbye"])];
+
+        assert_eq!(parse_tsv_block(&tsv_block(None, &rows)), Some(rows));
+        assert_eq!(
+            parse_tsv_block("1\t\r\n2\tx\r\n"),
+            Some(vec![strings(&["1", ""]), strings(&["2", "x"])])
+        );
+        assert_eq!(parse_tsv_block("just one value"), None);
+    }
+}
@@ -1,6 +1,7 @@
 use crate::app_state::{APP_STATE, activate_session};
 use dioxus::prelude::*;
 use models::{QueryHistoryItem, QueryTabState};
+use rfd::{AsyncMessageDialog, MessageButtons, MessageDialogResult, MessageLevel};
 
 use crate::screens::workspace::actions::set_active_tab_sql;
 
@@ -211,12 +212,48 @@ fn build_csv(items: &[QueryHistoryItem]) -> String {
     csv
 }
 
+fn set_history_pinned(mut history: Signal<Vec<QueryHistoryItem>>, id: u64, pinned: bool) {
+    history.with_mut(|items| {
+        if let Some(item) = items.iter_mut().find(|item| item.id == id) {
+            item.pinned = pinned;
+        }
+    });
+    spawn(async move {
+        let _ = services::QueryHistoryStore::set_pinned(id, pinned).await;
+    });
+}
+
+/// Deletes every unpinned query from the panel and the store, after asking.
+fn clear_history(mut history: Signal<Vec<QueryHistoryItem>>) {
+    spawn(async move {
+        let choice = AsyncMessageDialog::new()
+            .set_title("Clear query history")
+            .set_description("Delete every query in the history except pinned ones?")
+            .set_buttons(MessageButtons::OkCancelCustom(
+                "Clear".to_string(),
+                "Cancel".to_string(),
+            ))
+            .set_level(MessageLevel::Warning)
+            .show()
+            .await;
+        match choice {
+            MessageDialogResult::Ok => {}
+            MessageDialogResult::Custom(label) if label == "Clear" => {}
+            _ => return,
+        }
+
+        history.with_mut(|items| items.retain(|item| item.pinned));
+        let _ = services::QueryHistoryStore::clear().await;
+    });
+}
+
 #[component]
 pub fn QueryHistoryPanel(
-    history: Vec<QueryHistoryItem>,
+    history: Signal<Vec<QueryHistoryItem>>,
     tabs: Signal<Vec<QueryTabState>>,
     active_tab_id: Signal<u64>,
 ) -> Element {
+    let history_items = history();
     let mut search_query = use_signal(String::new);
     let mut date_filter = use_signal(|| DateFilter::All);
     let mut connection_filter = use_signal(String::new);
@@ -240,12 +277,12 @@ pub fn QueryHistoryPanel(
     let base_items: Vec<QueryHistoryItem> = if searching {
         search_results().unwrap_or_default()
     } else {
-        history.clone()
+        history_items.clone()
     };
 
     let connection_names: Vec<String> = {
         let mut set = std::collections::HashSet::new();
-        for item in &history {
+        for item in &history_items {
             if !item.connection_name.is_empty() {
                 set.insert(item.connection_name.clone());
             }
@@ -255,12 +292,14 @@ pub fn QueryHistoryPanel(
         names
     };
 
-    let filtered = apply_filters(
+    let mut filtered = apply_filters(
         base_items,
         date_filter(),
         &connection_filter(),
         outcome_filter(),
     );
+    filtered.sort_by_key(|item| !item.pinned);
+    let has_unpinned = history_items.iter().any(|item| !item.pinned);
 
     let total_items = filtered.len();
     let total_pages = if total_items == 0 {
@@ -312,6 +351,13 @@ pub fn QueryHistoryPanel(
                         },
                         "Export history"
                     }
+                    button {
+                        class: "button button--ghost button--small",
+                        disabled: !has_unpinned,
+                        title: "Delete every query that is not pinned",
+                        onclick: move |_| clear_history(history),
+                        "Clear history"
+                    }
                 }
             }
 
@@ -432,6 +478,8 @@ pub fn QueryHistoryPanel(
                             };
                             let outcome_label = if is_error { "Error" } else { "Success" };
                             let display_sql = redact_sql_display(&item.sql);
+                            let item_id = item.id;
+                            let pinned = item.pinned;
                             let dur_class = duration_class(item.duration_ms);
                             let dur_text = format_duration(item.duration_ms);
                             let rows_text = format_rows(item.rows_returned);
@@ -443,7 +491,19 @@ pub fn QueryHistoryPanel(
 
                             rsx! {
                                 div {
-                                    class: "history__item",
+                                    class: if pinned { "history__item history__item--pinned" } else { "history__item" },
+                                    title: "Double-click to load into the editor",
+                                    ondoubleclick: {
+                                        let sql = item.sql.clone();
+                                        move |_| {
+                                            set_active_tab_sql(
+                                                tabs,
+                                                active_tab_id(),
+                                                sql.clone(),
+                                                "Loaded query from history".to_string(),
+                                            );
+                                        }
+                                    },
                                     div {
                                         class: "history__meta",
                                         div {
@@ -545,6 +605,16 @@ pub fn QueryHistoryPanel(
                                             },
                                             "Copy to editor"
                                         }
+                                        button {
+                                            class: "button button--ghost button--small",
+                                            title: if pinned {
+                                                "Let this query be trimmed and cleared again"
+                                            } else {
+                                                "Keep this query when the history is trimmed or cleared"
+                                            },
+                                            onclick: move |_| set_history_pinned(history, item_id, !pinned),
+                                            if pinned { "Unpin" } else { "Pin" }
+                                        }
                                     }
                                 }
                             }
@@ -0,0 +1,238 @@
+use dioxus::prelude::*;
+
+/// PostgreSQL `hstore` cell opened for editing as key/value pairs.
+#[derive(Clone, PartialEq)]
+pub(crate) struct HstoreEditTarget {
+    pub(crate) column_name: String,
+    /// Current cell text, an hstore literal such as `"a"=>"1"` or `NULL`.
+    pub(crate) value: String,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct HstorePair {
+    key: String,
+    value: String,
+    null: bool,
+}
+
+/// Whether the pair editor can open `value`: a NULL cell or a well-formed
+/// hstore literal. Other values are edited as text.
+pub(crate) fn hstore_editor_supports(value: &str) -> bool {
+    hstore_pairs(value).is_some()
+}
+
+#[component]
+pub(crate) fn HstoreEditDialog(
+    target: HstoreEditTarget,
+    on_save: EventHandler<String>,
+    on_cancel: EventHandler<()>,
+) -> Element {
+    let initial_value = target.value.clone();
+    let mut pairs = use_signal(move || hstore_pairs(&initial_value).unwrap_or_default());
+    let literal = hstore_literal(&pairs.read());
+    let duplicate_key = duplicate_key(&pairs.read());
+
+    rsx! {
+        div {
+            class: "settings-modal__backdrop",
+            onclick: move |_| on_cancel.call(()),
+            div {
+                class: "settings-modal table-modal table-modal--compact",
+                onclick: move |event| event.stop_propagation(),
+                div {
+                    class: "settings-modal__header",
+                    div {
+                        class: "settings-modal__header-copy",
+                        h2 { class: "settings-modal__title", "Edit {target.column_name}" }
+                        p { class: "settings-modal__hint", "hstore · one row per key" }
+                    }
+                    button {
+                        class: "button button--ghost button--small",
+                        onclick: move |_| on_cancel.call(()),
+                        "Close"
+                    }
+                }
+
+                div {
+                    class: "table-modal__body",
+                    div {
+                        class: "table-modal__columns",
+                        if pairs.read().is_empty() {
+                            p { class: "table-modal__hint", "No keys yet." }
+                        }
+                        for (index, pair) in pairs.read().iter().cloned().enumerate() {
+                            div {
+                                key: "{index}",
+                                class: "table-modal__column-card",
+                                div {
+                                    class: "table-modal__column-grid",
+                                    input {
+                                        class: "input",
+                                        value: "{pair.key}",
+                                        placeholder: "key",
+                                        oninput: move |event| {
+                                            let key = event.value();
+                                            pairs.with_mut(|pairs| {
+                                                if let Some(pair) = pairs.get_mut(index) {
+                                                    pair.key = key;
+                                                }
+                                            });
+                                        },
+                                    }
+                                    input {
+                                        class: "input",
+                                        value: "{pair.value}",
+                                        placeholder: if pair.null { "NULL" } else { "value" },
+                                        disabled: pair.null,
+                                        oninput: move |event| {
+                                            let value = event.value();
+                                            pairs.with_mut(|pairs| {
+                                                if let Some(pair) = pairs.get_mut(index) {
+                                                    pair.value = value;
+                                                }
+                                            });
+                                        },
+                                    }
+                                }
+                                div {
+                                    class: "table-modal__column-header",
+                                    label {
+                                        class: "settings-modal__toggle",
+                                        input {
+                                            r#type: "checkbox",
+                                            checked: pair.null,
+                                            oninput: move |event| {
+                                                let null = event.checked();
+                                                pairs.with_mut(|pairs| {
+                                                    if let Some(pair) = pairs.get_mut(index) {
+                                                        pair.null = null;
+                                                    }
+                                                });
+                                            },
+                                        }
+                                        span { "NULL value" }
+                                    }
+                                    button {
+                                        class: "button button--ghost button--small",
+                                        onclick: move |_| {
+                                            pairs.with_mut(|pairs| {
+                                                if index < pairs.len() {
+                                                    pairs.remove(index);
+                                                }
+                                            });
+                                        },
+                                        "Remove"
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    div {
+                        class: "table-modal__toolbar",
+                        button {
+                            class: "button button--ghost button--small",
+                            onclick: move |_| pairs.write().push(HstorePair::default()),
+                            "Add Pair"
+                        }
+                    }
+
+                    div {
+                        class: "table-modal__preview",
+                        span { class: "field__label", "Value" }
+                        pre { class: "table-modal__preview-sql", "{literal}" }
+                    }
+
+                    if let Some(key) = duplicate_key.as_ref() {
+                        p { class: "table-modal__error", "Key \"{key}\" appears more than once." }
+                    }
+
+                    div {
+                        class: "table-modal__actions",
+                        button {
+                            class: "button button--ghost",
+                            onclick: move |_| on_cancel.call(()),
+                            "Cancel"
+                        }
+                        button {
+                            class: "button button--primary",
+                            disabled: duplicate_key.is_some(),
+                            onclick: move |_| on_save.call(hstore_literal(&pairs.read())),
+                            "Save"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn hstore_pairs(value: &str) -> Option<Vec<HstorePair>> {
+    if value == "NULL" {
+        return Some(Vec::new());
+    }
+    let pairs = services::parse_postgres_hstore(value)?;
+    Some(
+        pairs
+            .into_iter()
+            .map(|(key, value)| HstorePair {
+                key,
+                null: value.is_none(),
+                value: value.unwrap_or_default(),
+            })
+            .collect(),
+    )
+}
+
+/// Serialises the pairs, skipping rows whose key is still empty.
+fn hstore_literal(pairs: &[HstorePair]) -> String {
+    let pairs = pairs
+        .iter()
+        .filter(|pair| !pair.key.is_empty())
+        .map(|pair| (pair.key.clone(), (!pair.null).then(|| pair.value.clone())))
+        .collect::<Vec<_>>();
+    services::format_postgres_hstore(&pairs)
+}
+
+/// First key entered twice; hstore keeps only one value per key.
+fn duplicate_key(pairs: &[HstorePair]) -> Option<String> {
+    pairs.iter().enumerate().find_map(|(index, pair)| {
+        (!pair.key.is_empty() && pairs[..index].iter().any(|other| other.key == pair.key))
+            .then(|| pair.key.clone())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{HstorePair, duplicate_key, hstore_literal, hstore_pairs};
+
+    fn pair(key: &str, value: &str, null: bool) -> HstorePair {
+        HstorePair {
+            key: key.to_string(),
+            value: value.to_string(),
+            null,
+        }
+    }
+
+    #[test]
+    fn hstore_pairs_round_trip_and_skip_empty_keys() {
+        let mut pairs = hstore_pairs(r#""a"=>"1", "b"=>NULL"#).expect("hstore literal");
+        assert_eq!(pairs, vec![pair("a", "1", false), pair("b", "", true)]);
+
+        pairs.push(HstorePair::default());
+        assert_eq!(hstore_literal(&pairs), r#""a"=>"1", "b"=>NULL"#);
+        assert_eq!(hstore_pairs("NULL"), Some(Vec::new()));
+    }
+
+    #[test]
+    fn duplicate_keys_are_reported() {
+        assert_eq!(
+            duplicate_key(&[pair("a", "1", false), pair("a", "2", false)]),
+            Some("a".to_string())
+        );
+        assert_eq!(
+            duplicate_key(&[pair("", "1", false), pair("", "2", false)]),
+            None
+        );
+    }
+}
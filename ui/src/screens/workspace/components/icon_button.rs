@@ -7,6 +7,7 @@ pub enum ActionIcon {
     Connections,
     Explorer,
     History,
+    Notifications,
     SqlEditor,
     Agent,
     Refresh,
@@ -14,6 +15,7 @@ pub enum ActionIcon {
     Run,
     Clear,
     Format,
+    Templates,
     Generate,
     Explain,
     CreateTable,
@@ -23,11 +25,15 @@ pub enum ActionIcon {
     ExportXlsx,
     ExportXml,
     ExportHtml,
+    CopyMarkdown,
+    CopyInsert,
     ExportSql,
     ImportCsv,
     InsertRow,
+    InsertRowForm,
     Apply,
     Undo,
+    UndoStep,
     Delete,
     Truncate,
     Duplicate,
@@ -36,8 +42,15 @@ pub enum ActionIcon {
     Filter,
     FilterApply,
     FilterClear,
+    Formatting,
+    Comment,
     Previous,
     Next,
+    Activity,
+    ServerSettings,
+    Roles,
+    TopQueries,
+    SchemaDiff,
     Close,
 }
 
@@ -136,6 +149,10 @@ fn IconGlyph(icon: ActionIcon) -> Element {
                     path { d: "M12 8v4l3 2" }
                     path { d: "M8 4H5v3" }
                 },
+                ActionIcon::Notifications => rsx! {
+                    path { d: "M6 16V11a6 6 0 0 1 12 0v5l1.5 2h-15z" }
+                    path { d: "M10 20a2 2 0 0 0 4 0" }
+                },
                 ActionIcon::SqlEditor => rsx! {
                     path { d: "M8 3h7l4 4v13a1 1 0 0 1-1 1H8a1 1 0 0 1-1-1V4a1 1 0 0 1 1-1z" }
                     path { d: "M15 3v5h4" }
@@ -172,6 +189,13 @@ fn IconGlyph(icon: ActionIcon) -> Element {
                     path { d: "M5 15h14" }
                     path { d: "M5 19h10" }
                 },
+                ActionIcon::Templates => rsx! {
+                    rect { x: "4", y: "4", width: "16", height: "16", rx: "2" }
+                    path { d: "M4 9h16" }
+                    path { d: "M9 9v11" }
+                    path { d: "M12 13h5" }
+                    path { d: "M12 16h3" }
+                },
                 ActionIcon::Generate => rsx! {
                     path { d: "M12 4v4" }
                     path { d: "M12 16v4" }
@@ -232,6 +256,12 @@ fn IconGlyph(icon: ActionIcon) -> Element {
                     path { d: "M9 13l3-3 3 3" }
                     path { d: "M12 16v3" }
                 },
+                ActionIcon::CopyMarkdown => rsx! {
+                    rect { x: "3", y: "6", width: "18", height: "12", rx: "2" }
+                    path { d: "M7 15V9l2.5 3L12 9v6" }
+                    path { d: "M16.5 9v6" }
+                    path { d: "m14.5 13 2 2 2-2" }
+                },
                 ActionIcon::ExportHtml => rsx! {
                     path { d: "M7 4h7l3 3v13H7z" }
                     path { d: "M14 4v3h3" }
@@ -239,6 +269,12 @@ fn IconGlyph(icon: ActionIcon) -> Element {
                     path { d: "M9 15h6" }
                     path { d: "M9 18h4" }
                 },
+                ActionIcon::CopyInsert => rsx! {
+                    rect { x: "8", y: "8", width: "12", height: "12", rx: "2" }
+                    path { d: "M16 8V6a2 2 0 0 0-2-2H6a2 2 0 0 0-2 2v8a2 2 0 0 0 2 2h2" }
+                    path { d: "M14 11v6" }
+                    path { d: "M11 14h6" }
+                },
                 ActionIcon::ExportSql => rsx! {
                     path { d: "M7 4h7l3 3v13H7z" }
                     path { d: "M14 4v3h3" }
@@ -257,6 +293,13 @@ fn IconGlyph(icon: ActionIcon) -> Element {
                     path { d: "M9 7h6" }
                     path { d: "M8 13h8" }
                 },
+                ActionIcon::InsertRowForm => rsx! {
+                    rect { x: "4", y: "4", width: "12", height: "16", rx: "2" }
+                    path { d: "M7 9h6" }
+                    path { d: "M7 13h6" }
+                    path { d: "M19 14v6" }
+                    path { d: "M16 17h6" }
+                },
                 ActionIcon::Apply => rsx! {
                     path { d: "m5 13 4 4L19 7" }
                 },
@@ -264,6 +307,10 @@ fn IconGlyph(icon: ActionIcon) -> Element {
                     path { d: "M9 8H5v4" }
                     path { d: "M5 12c1.8-4.2 8.7-5.8 12.7-2.2 2.6 2.3 2.8 5.6 1.6 8.2" }
                 },
+                ActionIcon::UndoStep => rsx! {
+                    path { d: "M9 14 4 9l5-5" }
+                    path { d: "M4 9h10.5a5.5 5.5 0 0 1 0 11H11" }
+                },
                 ActionIcon::Delete => rsx! {
                     path { d: "M4 7h16" }
                     path { d: "M9 7V5h6v2" }
@@ -314,12 +361,50 @@ fn IconGlyph(icon: ActionIcon) -> Element {
                     path { d: "m17 16 4 4" }
                     path { d: "m21 16-4 4" }
                 },
+                ActionIcon::Formatting => rsx! {
+                    path { d: "M12 3s6 6.5 6 11a6 6 0 0 1-12 0c0-4.5 6-11 6-11z" }
+                    path { d: "M9 15a3 3 0 0 0 3 3" }
+                },
+                ActionIcon::Comment => rsx! {
+                    path { d: "M5 5h14v10H10l-5 4z" }
+                },
                 ActionIcon::Previous => rsx! {
                     path { d: "m15 6-6 6 6 6" }
                 },
                 ActionIcon::Next => rsx! {
                     path { d: "m9 6 6 6-6 6" }
                 },
+                ActionIcon::Activity => rsx! {
+                    path { d: "M3 12h4l3-7 4 14 3-7h4" }
+                },
+                ActionIcon::ServerSettings => rsx! {
+                    path { d: "M4 7h10" }
+                    path { d: "M18 7h2" }
+                    circle { cx: "16", cy: "7", r: "2" }
+                    path { d: "M4 17h2" }
+                    path { d: "M10 17h10" }
+                    circle { cx: "8", cy: "17", r: "2" }
+                },
+                ActionIcon::Roles => rsx! {
+                    circle { cx: "9", cy: "8", r: "3" }
+                    path { d: "M3 19a6 6 0 0 1 12 0" }
+                    path { d: "M16 5a3 3 0 0 1 0 6" }
+                    path { d: "M18 14a5 5 0 0 1 3 5" }
+                },
+                ActionIcon::TopQueries => rsx! {
+                    path { d: "M4 20h16" }
+                    path { d: "M6 16v-3" }
+                    path { d: "M10 16V8" }
+                    path { d: "M14 16v-5" }
+                    path { d: "M18 16V4" }
+                },
+                ActionIcon::SchemaDiff => rsx! {
+                    rect { x: "3", y: "4", width: "7", height: "16", rx: "1.5" }
+                    rect { x: "14", y: "4", width: "7", height: "16", rx: "1.5" }
+                    path { d: "M5.5 9h2" }
+                    path { d: "M16.5 9h2" }
+                    path { d: "M17.5 8v2" }
+                },
                 ActionIcon::Close => rsx! {
                     path { d: "m4 4 16 16" }
                     path { d: "m20 4-16 16" }
@@ -0,0 +1,828 @@
+use crate::app_state::{
+    invalidate_row_counts, session_connection, session_transaction, set_session_transaction_status,
+};
+use crate::screens::workspace::actions::{
+    read_only_mode_block_status, read_only_mode_enabled, refresh_tab_result, set_active_tab_status,
+};
+use dioxus::prelude::*;
+use models::{ColumnInfo, InsertedRow, QueryOutput, QueryPage, QueryTabState, TablePreviewSource};
+
+/// Table and tab a row inserted from the dialog is shown in.
+#[derive(Clone, PartialEq)]
+pub(crate) struct InsertRowTarget {
+    pub(crate) tab_id: u64,
+    pub(crate) session_id: u64,
+    pub(crate) source: TablePreviewSource,
+    /// Cell values of the row being duplicated, by column name.
+    pub(crate) prefill: Option<Vec<(String, String)>>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum InsertValueMode {
+    Value,
+    Null,
+    /// The column is left out of the INSERT so the database fills it in.
+    Default,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct InsertRowField {
+    mode: InsertValueMode,
+    value: String,
+}
+
+impl InsertRowField {
+    fn for_column(column: &ColumnInfo) -> Self {
+        let mode = if column.generated || column.identity || column.default_value.is_some() {
+            InsertValueMode::Default
+        } else if column.nullable {
+            InsertValueMode::Null
+        } else {
+            InsertValueMode::Value
+        };
+        let value = match column_input_kind(&column.data_type) {
+            ColumnInputKind::Boolean => "false".to_string(),
+            _ => String::new(),
+        };
+        Self { mode, value }
+    }
+
+    /// Copies a cell of the duplicated row. Keys and computed columns go
+    /// back to DEFAULT so the database fills them in for the copy.
+    fn duplicate(column: &ColumnInfo, value: Option<&str>) -> Self {
+        let field = Self::for_column(column);
+        let Some(value) = value else {
+            return field;
+        };
+        if column.generated || column.identity || is_sequence_default(column) {
+            return field;
+        }
+        if value == "NULL" && column.nullable {
+            return Self {
+                mode: InsertValueMode::Null,
+                ..field
+            };
+        }
+        let value = match column_input_kind(&column.data_type) {
+            ColumnInputKind::Boolean => {
+                matches!(value.to_ascii_lowercase().as_str(), "true" | "t" | "1").to_string()
+            }
+            _ => value.to_string(),
+        };
+        Self {
+            mode: InsertValueMode::Value,
+            value,
+        }
+    }
+}
+
+/// Serial and auto-increment columns number themselves through their default.
+fn is_sequence_default(column: &ColumnInfo) -> bool {
+    column
+        .default_value
+        .as_deref()
+        .is_some_and(|default_value| {
+            default_value.starts_with("nextval(") || default_value == "auto_increment"
+        })
+}
+
+/// The dialog's starting fields, copied from `prefill` when duplicating.
+fn initial_fields(
+    columns: &[ColumnInfo],
+    prefill: Option<&[(String, String)]>,
+) -> Vec<InsertRowField> {
+    columns
+        .iter()
+        .map(|column| match prefill {
+            Some(prefill) => {
+                let value = prefill
+                    .iter()
+                    .find(|(name, _)| *name == column.name)
+                    .map(|(_, value)| value.as_str());
+                InsertRowField::duplicate(column, value)
+            }
+            None => InsertRowField::for_column(column),
+        })
+        .collect()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ColumnInputKind {
+    Boolean,
+    Integer,
+    Decimal,
+    Text,
+}
+
+#[component]
+pub(crate) fn InsertRowDialog(
+    tabs: Signal<Vec<QueryTabState>>,
+    mut target: Signal<Option<InsertRowTarget>>,
+) -> Element {
+    let mut columns = use_signal(|| None::<Result<Vec<ColumnInfo>, String>>);
+    let mut fields = use_signal(Vec::<InsertRowField>::new);
+    let mut error = use_signal(String::new);
+    let mut inflight = use_signal(|| false);
+
+    use_effect(move || {
+        let Some(current_target) = target() else {
+            return;
+        };
+        columns.set(None);
+        error.set(String::new());
+        spawn(async move {
+            let InsertRowTarget {
+                session_id,
+                source,
+                prefill,
+                ..
+            } = current_target;
+            let result = match session_connection(session_id) {
+                Some(connection) => {
+                    services::load_table_column_info(connection, source.schema, source.table_name)
+                        .await
+                        .map_err(|err| err.to_string())
+                }
+                None => Err("Connection is no longer open.".to_string()),
+            };
+            if let Ok(items) = &result {
+                fields.set(initial_fields(items, prefill.as_deref()));
+            }
+            columns.set(Some(result));
+        });
+    });
+
+    let Some(current_target) = target() else {
+        return rsx! {};
+    };
+    let read_only_mode = read_only_mode_enabled();
+    let title = if current_target.prefill.is_some() {
+        "Duplicate row"
+    } else {
+        "Insert row"
+    };
+    let loaded_columns = match columns() {
+        Some(Ok(items)) => items,
+        _ => Vec::new(),
+    };
+
+    rsx! {
+        div {
+            class: "settings-modal__backdrop",
+            onclick: move |_| {
+                if !inflight() {
+                    target.set(None);
+                }
+            },
+            div {
+                class: "settings-modal table-modal",
+                onclick: move |event| event.stop_propagation(),
+                div {
+                    class: "settings-modal__header",
+                    div {
+                        class: "settings-modal__header-copy",
+                        h2 { class: "settings-modal__title", "{title}" }
+                        p {
+                            class: "settings-modal__hint",
+                            "Add a row to {current_target.source.qualified_name}."
+                        }
+                    }
+                    button {
+                        class: "button button--ghost button--small",
+                        disabled: inflight(),
+                        onclick: move |_| target.set(None),
+                        "Close"
+                    }
+                }
+
+                div {
+                    class: "table-modal__body",
+                    match columns() {
+                        None => rsx! {
+                            p { class: "table-modal__hint", "Loading columns…" }
+                        },
+                        Some(Err(err)) => rsx! {
+                            p { class: "table-modal__error", "{err}" }
+                        },
+                        Some(Ok(_)) => rsx! {
+                            div {
+                                class: "table-modal__columns",
+                                for (index, column) in loaded_columns.iter().cloned().enumerate() {
+                                    InsertRowColumnField {
+                                        key: "{column.name}",
+                                        column,
+                                        index,
+                                        fields,
+                                    }
+                                }
+                            }
+                        },
+                    }
+
+                    if !error().is_empty() {
+                        p { class: "table-modal__error", "{error}" }
+                    }
+
+                    div {
+                        class: "table-modal__actions",
+                        button {
+                            class: "button button--ghost",
+                            disabled: inflight(),
+                            onclick: move |_| target.set(None),
+                            "Cancel"
+                        }
+                        button {
+                            class: "button button--primary",
+                            disabled: read_only_mode || inflight() || loaded_columns.is_empty(),
+                            onclick: {
+                                let loaded_columns = loaded_columns.clone();
+                                move |_| {
+                                    let Some(current_target) = target() else {
+                                        return;
+                                    };
+                                    if read_only_mode_enabled() {
+                                        error.set(read_only_mode_block_status("row insert"));
+                                        return;
+                                    }
+                                    let checked =
+                                        insert_column_values(&loaded_columns, &fields.read());
+                                    let column_values = match checked {
+                                        Ok(column_values) => column_values,
+                                        Err(message) => {
+                                            error.set(message);
+                                            return;
+                                        }
+                                    };
+                                    spawn(async move {
+                                        inflight.set(true);
+                                        let result =
+                                            insert_row(&current_target, column_values).await;
+                                        inflight.set(false);
+                                        match result {
+                                            Ok(inserted) => {
+                                                target.set(None);
+                                                finish_row_insert(tabs, current_target, inserted);
+                                            }
+                                            Err(message) => error.set(message),
+                                        }
+                                    });
+                                }
+                            },
+                            if inflight() { "Inserting…" } else { "Insert" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn InsertRowColumnField(
+    column: ColumnInfo,
+    index: usize,
+    mut fields: Signal<Vec<InsertRowField>>,
+) -> Element {
+    let Some(field) = fields.read().get(index).cloned() else {
+        return rsx! {};
+    };
+    let kind = column_input_kind(&column.data_type);
+    let editing = field.mode == InsertValueMode::Value;
+    let has_default = column.default_value.is_some() || column.identity;
+    let mut update = move |change: &dyn Fn(&mut InsertRowField)| {
+        fields.with_mut(|fields| {
+            if let Some(field) = fields.get_mut(index) {
+                change(field);
+            }
+        });
+    };
+    let placeholder = match (&column.default_value, kind) {
+        _ if column.generated => "Generated by the database".to_string(),
+        (Some(default_value), _) => format!("Default: {default_value}"),
+        (None, ColumnInputKind::Integer) => "Whole number".to_string(),
+        (None, ColumnInputKind::Decimal) => "Number".to_string(),
+        (None, _) => String::new(),
+    };
+
+    rsx! {
+        div {
+            class: "table-modal__column-card",
+            div {
+                class: "table-modal__column-header",
+                span { class: "table-modal__column-title", "{column.name}" }
+                span { class: "table-modal__hint", "{column.data_type}" }
+            }
+            if kind == ColumnInputKind::Boolean {
+                select {
+                    class: "input",
+                    disabled: !editing,
+                    value: field.value.clone(),
+                    onchange: move |event| {
+                        let value = event.value();
+                        update(&|field| field.value = value.clone());
+                    },
+                    option { value: "true", selected: field.value == "true", "true" }
+                    option { value: "false", selected: field.value == "false", "false" }
+                }
+            } else {
+                input {
+                    class: "input",
+                    disabled: !editing,
+                    value: field.value.clone(),
+                    placeholder,
+                    oninput: move |event| {
+                        let value = event.value();
+                        update(&|field| field.value = value.clone());
+                    },
+                }
+            }
+            if !column.generated && (column.nullable || has_default) {
+                div {
+                    class: "table-modal__column-toggles",
+                    if column.nullable {
+                        label {
+                            class: "settings-modal__toggle",
+                            input {
+                                r#type: "checkbox",
+                                checked: field.mode == InsertValueMode::Null,
+                                oninput: move |event| {
+                                    let mode = if event.checked() {
+                                        InsertValueMode::Null
+                                    } else {
+                                        InsertValueMode::Value
+                                    };
+                                    update(&|field| field.mode = mode);
+                                },
+                            }
+                            span { "NULL" }
+                        }
+                    }
+                    if has_default {
+                        label {
+                            class: "settings-modal__toggle",
+                            input {
+                                r#type: "checkbox",
+                                checked: field.mode == InsertValueMode::Default,
+                                oninput: move |event| {
+                                    let mode = if event.checked() {
+                                        InsertValueMode::Default
+                                    } else {
+                                        InsertValueMode::Value
+                                    };
+                                    update(&|field| field.mode = mode);
+                                },
+                            }
+                            span { "DEFAULT" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Runs the INSERT on the session's open transaction, or on the pool when the
+/// session autocommits.
+async fn insert_row(
+    target: &InsertRowTarget,
+    column_values: Vec<(String, String)>,
+) -> Result<Option<InsertedRow>, String> {
+    let result = match session_transaction(target.session_id) {
+        Some(transaction) => {
+            let result = transaction
+                .insert_table_row_returning(target.source.clone(), column_values)
+                .await;
+            set_session_transaction_status(target.session_id, transaction.status().await);
+            result
+        }
+        None => {
+            let Some(connection) = session_connection(target.session_id) else {
+                return Err("Connection is no longer open.".to_string());
+            };
+            services::insert_table_row_returning(connection, target.source.clone(), column_values)
+                .await
+        }
+    };
+    result.map_err(|err| err.to_string())
+}
+
+/// Appends the inserted row to the tab's page when it shows that table, and
+/// reloads the tab otherwise.
+fn finish_row_insert(
+    mut tabs: Signal<Vec<QueryTabState>>,
+    target: InsertRowTarget,
+    inserted: Option<InsertedRow>,
+) {
+    invalidate_row_counts(target.session_id, &target.source.qualified_name);
+    set_active_tab_status(
+        tabs,
+        target.tab_id,
+        format!("Inserted a row into {}", target.source.table_name),
+    );
+    if let Some(inserted) = inserted {
+        let mut appended = false;
+        tabs.with_mut(|all_tabs| {
+            if let Some(tab) = all_tabs.iter_mut().find(|tab| tab.id == target.tab_id)
+                && let Some(QueryOutput::Table(page)) = tab.result.as_mut()
+            {
+                appended = append_inserted_row(page, &target.source, &inserted);
+            }
+        });
+        if appended {
+            return;
+        }
+    }
+    let current_tab = tabs
+        .read()
+        .iter()
+        .find(|tab| tab.id == target.tab_id)
+        .cloned();
+    if let Some(current_tab) = current_tab {
+        refresh_tab_result(tabs, current_tab, Some(target.source));
+    }
+}
+
+/// Adds `inserted` to the end of `page` when the page edits `source` and
+/// shows only columns the inserted row has.
+fn append_inserted_row(
+    page: &mut QueryPage,
+    source: &TablePreviewSource,
+    inserted: &InsertedRow,
+) -> bool {
+    let Some(editable) = page
+        .editable
+        .as_mut()
+        .filter(|editable| editable.source.qualified_name == source.qualified_name)
+    else {
+        return false;
+    };
+    let Some(row) = page
+        .columns
+        .iter()
+        .map(|column| {
+            let index = inserted.columns.iter().position(|name| name == column)?;
+            inserted.values.get(index).cloned()
+        })
+        .collect::<Option<Vec<_>>>()
+    else {
+        return false;
+    };
+    editable.row_locators.push(inserted.locator.clone());
+    page.rows.push(row);
+    true
+}
+
+fn column_input_kind(data_type: &str) -> ColumnInputKind {
+    let data_type = data_type.trim().to_ascii_lowercase();
+    let data_type = data_type
+        .strip_prefix("nullable(")
+        .unwrap_or(&data_type)
+        .trim_end_matches(')');
+    // MySQL reports booleans as tinyint(1).
+    if data_type == "tinyint(1" {
+        return ColumnInputKind::Boolean;
+    }
+    let base = data_type
+        .split(|ch: char| !ch.is_ascii_alphanumeric() && ch != '_')
+        .next()
+        .unwrap_or_default();
+
+    match base {
+        "bool" | "boolean" => ColumnInputKind::Boolean,
+        "int" | "int2" | "int4" | "int8" | "integer" | "smallint" | "bigint" | "tinyint"
+        | "mediumint" | "serial" | "smallserial" | "bigserial" | "int16" | "int32" | "int64"
+        | "int128" | "int256" | "uint8" | "uint16" | "uint32" | "uint64" | "uint128"
+        | "uint256" => ColumnInputKind::Integer,
+        "numeric" | "decimal" | "real" | "float" | "float4" | "float8" | "float32" | "float64"
+        | "double" => ColumnInputKind::Decimal,
+        _ => ColumnInputKind::Text,
+    }
+}
+
+/// Turns the dialog fields into the `(column, value)` pairs the insert takes.
+/// DEFAULT and generated columns are left out and NULL is sent as the `NULL`
+/// literal. Returns the first validation message when a value does not fit
+/// its column.
+fn insert_column_values(
+    columns: &[ColumnInfo],
+    fields: &[InsertRowField],
+) -> Result<Vec<(String, String)>, String> {
+    let mut column_values = Vec::new();
+    for (column, field) in columns.iter().zip(fields) {
+        if let Some(value) = insert_field_value(column, field)? {
+            column_values.push((column.name.clone(), value));
+        }
+    }
+    Ok(column_values)
+}
+
+/// Turns one row pasted from a spreadsheet into `(column, value)` pairs,
+/// pairing cells with the table's insertable columns in order. Empty cells
+/// fall back to DEFAULT and then NULL, and `NULL` is read literally where the
+/// column allows it. Returns a message for every cell that does not fit.
+pub(super) fn pasted_row_values(
+    columns: &[ColumnInfo],
+    cells: &[String],
+) -> Result<Vec<(String, String)>, Vec<String>> {
+    let columns = columns
+        .iter()
+        .filter(|column| !column.generated)
+        .collect::<Vec<_>>();
+    if cells.len() > columns.len() {
+        return Err(vec![format!(
+            "{} cells do not fit the table's {} insertable columns.",
+            cells.len(),
+            columns.len()
+        )]);
+    }
+
+    let mut column_values = Vec::new();
+    let mut errors = Vec::new();
+    for (column, cell) in columns.into_iter().zip(cells) {
+        let field = match pasted_field(column, cell) {
+            Ok(field) => field,
+            Err(message) => {
+                errors.push(message);
+                continue;
+            }
+        };
+        match insert_field_value(column, &field) {
+            Ok(Some(value)) => column_values.push((column.name.clone(), value)),
+            Ok(None) => {}
+            Err(message) => errors.push(message),
+        }
+    }
+    if errors.is_empty() {
+        Ok(column_values)
+    } else {
+        Err(errors)
+    }
+}
+
+fn pasted_field(column: &ColumnInfo, cell: &str) -> Result<InsertRowField, String> {
+    let has_default = column.default_value.is_some() || column.identity;
+    let mode = match cell.trim() {
+        "" if has_default => InsertValueMode::Default,
+        "" | "NULL" if column.nullable => InsertValueMode::Null,
+        _ => InsertValueMode::Value,
+    };
+    let value = match column_input_kind(&column.data_type) {
+        ColumnInputKind::Boolean if mode == InsertValueMode::Value => {
+            match cell.trim().to_ascii_lowercase().as_str() {
+                "true" | "t" | "1" | "yes" | "y" => "true".to_string(),
+                "false" | "f" | "0" | "no" | "n" => "false".to_string(),
+                _ => return Err(format!("{} expects true or false.", column.name)),
+            }
+        }
+        _ => cell.to_string(),
+    };
+    Ok(InsertRowField { mode, value })
+}
+
+/// The value `field` sends for `column`, or `None` when the column is left
+/// out of the INSERT.
+fn insert_field_value(
+    column: &ColumnInfo,
+    field: &InsertRowField,
+) -> Result<Option<String>, String> {
+    if column.generated {
+        return Ok(None);
+    }
+    let value = match field.mode {
+        InsertValueMode::Default => return Ok(None),
+        InsertValueMode::Null if !column.nullable => {
+            return Err(format!("{} cannot be NULL.", column.name));
+        }
+        InsertValueMode::Null => "NULL".to_string(),
+        InsertValueMode::Value => {
+            let value = field.value.trim();
+            match column_input_kind(&column.data_type) {
+                ColumnInputKind::Boolean if value == "true" => "1".to_string(),
+                ColumnInputKind::Boolean => "0".to_string(),
+                ColumnInputKind::Integer if value.parse::<i128>().is_err() => {
+                    return Err(format!("{} expects a whole number.", column.name));
+                }
+                ColumnInputKind::Decimal if value.parse::<f64>().is_err() => {
+                    return Err(format!("{} expects a number.", column.name));
+                }
+                ColumnInputKind::Integer | ColumnInputKind::Decimal => value.to_string(),
+                ColumnInputKind::Text => field.value.clone(),
+            }
+        }
+    };
+    Ok(Some(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        ColumnInputKind, InsertRowField, InsertValueMode, append_inserted_row, column_input_kind,
+        initial_fields, insert_column_values, pasted_row_values,
+    };
+    use models::{ColumnInfo, EditableTableContext, InsertedRow, QueryPage, TablePreviewSource};
+
+    fn column(
+        name: &str,
+        data_type: &str,
+        nullable: bool,
+        default_value: Option<&str>,
+    ) -> ColumnInfo {
+        ColumnInfo {
+            name: name.to_string(),
+            data_type: data_type.to_string(),
+            nullable,
+            primary_key: false,
+            foreign_key: false,
+            default_value: default_value.map(str::to_string),
+            identity: false,
+            generated: false,
+            comment: None,
+        }
+    }
+
+    #[test]
+    fn input_kind_follows_the_column_type() {
+        assert_eq!(column_input_kind("boolean"), ColumnInputKind::Boolean);
+        assert_eq!(column_input_kind("tinyint(1)"), ColumnInputKind::Boolean);
+        assert_eq!(
+            column_input_kind("bigint unsigned"),
+            ColumnInputKind::Integer
+        );
+        assert_eq!(
+            column_input_kind("Nullable(UInt32)"),
+            ColumnInputKind::Integer
+        );
+        assert_eq!(column_input_kind("numeric(10,2)"), ColumnInputKind::Decimal);
+        assert_eq!(
+            column_input_kind("double precision"),
+            ColumnInputKind::Decimal
+        );
+        assert_eq!(column_input_kind("interval"), ColumnInputKind::Text);
+        assert_eq!(column_input_kind("point"), ColumnInputKind::Text);
+    }
+
+    #[test]
+    fn fields_start_on_default_then_null_then_value() {
+        assert_eq!(
+            InsertRowField::for_column(&column("id", "integer", false, Some("nextval('s')"))).mode,
+            InsertValueMode::Default
+        );
+        assert_eq!(
+            InsertRowField::for_column(&column("note", "text", true, None)).mode,
+            InsertValueMode::Null
+        );
+        assert_eq!(
+            InsertRowField::for_column(&column("name", "text", false, None)).mode,
+            InsertValueMode::Value
+        );
+    }
+
+    #[test]
+    fn values_skip_defaults_and_reject_bad_numbers() {
+        let columns = vec![
+            column("id", "integer", false, Some("nextval('s')")),
+            column("active", "boolean", false, None),
+            column("note", "text", true, None),
+            column("price", "numeric", false, None),
+        ];
+        let mut fields = columns
+            .iter()
+            .map(InsertRowField::for_column)
+            .collect::<Vec<_>>();
+        fields[1].value = "true".to_string();
+        fields[3].mode = InsertValueMode::Value;
+        fields[3].value = "12.50".to_string();
+
+        assert_eq!(
+            insert_column_values(&columns, &fields),
+            Ok(vec![
+                ("active".to_string(), "1".to_string()),
+                ("note".to_string(), "NULL".to_string()),
+                ("price".to_string(), "12.50".to_string()),
+            ])
+        );
+
+        fields[3].value = "twelve".to_string();
+        assert_eq!(
+            insert_column_values(&columns, &fields),
+            Err("price expects a number.".to_string())
+        );
+    }
+
+    #[test]
+    fn duplicated_rows_reset_keys_and_computed_columns() {
+        let mut identity = column("id", "bigint", false, None);
+        identity.identity = true;
+        let mut total = column("total", "numeric", true, Some("(price * 2)"));
+        total.generated = true;
+        let columns = vec![
+            identity,
+            column("serial_no", "integer", false, Some("nextval('s')")),
+            column("active", "tinyint(1)", false, None),
+            column("note", "text", true, None),
+            column("price", "numeric", false, Some("0")),
+            total,
+        ];
+        let prefill = [
+            ("id", "7"),
+            ("serial_no", "12"),
+            ("active", "1"),
+            ("note", "NULL"),
+            ("price", "12.50"),
+            ("total", "25.00"),
+        ]
+        .map(|(name, value)| (name.to_string(), value.to_string()));
+
+        let fields = initial_fields(&columns, Some(&prefill));
+        let modes = fields.iter().map(|field| field.mode).collect::<Vec<_>>();
+        assert_eq!(
+            modes,
+            vec![
+                InsertValueMode::Default,
+                InsertValueMode::Default,
+                InsertValueMode::Value,
+                InsertValueMode::Null,
+                InsertValueMode::Value,
+                InsertValueMode::Default,
+            ]
+        );
+        assert_eq!(
+            insert_column_values(&columns, &fields),
+            Ok(vec![
+                ("active".to_string(), "1".to_string()),
+                ("note".to_string(), "NULL".to_string()),
+                ("price".to_string(), "12.50".to_string()),
+            ])
+        );
+    }
+
+    #[test]
+    fn pasted_rows_fill_columns_in_order_and_list_every_bad_cell() {
+        let mut total = column("total", "numeric", true, None);
+        total.generated = true;
+        let columns = vec![
+            column("id", "integer", false, Some("nextval('s')")),
+            total,
+            column("active", "boolean", false, None),
+            column("note", "text", true, None),
+            column("price", "numeric", false, None),
+        ];
+        let cells = |values: &[&str]| values.iter().map(ToString::to_string).collect::<Vec<_>>();
+
+        assert_eq!(
+            pasted_row_values(&columns, &cells(&["", "TRUE", "NULL", "12.50"])),
+            Ok(vec![
+                ("active".to_string(), "1".to_string()),
+                ("note".to_string(), "NULL".to_string()),
+                ("price".to_string(), "12.50".to_string()),
+            ])
+        );
+        assert_eq!(
+            pasted_row_values(&columns, &cells(&["7", "maybe", "", "twelve"])),
+            Err(vec![
+                "active expects true or false.".to_string(),
+                "price expects a number.".to_string(),
+            ])
+        );
+        assert_eq!(
+            pasted_row_values(&columns, &cells(&["1", "t", "", "1", "extra"])),
+            Err(vec![
+                "5 cells do not fit the table's 4 insertable columns.".to_string()
+            ])
+        );
+    }
+
+    #[test]
+    fn inserted_rows_follow_the_page_column_order() {
+        let source = TablePreviewSource {
+            schema: None,
+            table_name: "items".to_string(),
+            qualified_name: r#""items""#.to_string(),
+        };
+        let mut page = QueryPage {
+            columns: vec!["name".to_string(), "id".to_string()],
+            rows: vec![vec!["a".to_string(), "1".to_string()]],
+            editable: Some(EditableTableContext {
+                source: source.clone(),
+                row_locators: vec!["1".to_string()],
+            }),
+            offset: 0,
+            page_size: 100,
+            has_previous: false,
+            has_next: false,
+        };
+        let inserted = InsertedRow {
+            locator: "2".to_string(),
+            columns: vec!["id".to_string(), "name".to_string()],
+            values: vec!["2".to_string(), "b".to_string()],
+        };
+
+        assert!(append_inserted_row(&mut page, &source, &inserted));
+        assert_eq!(page.rows[1], vec!["b", "2"]);
+        assert_eq!(page.editable.as_ref().unwrap().row_locators, vec!["1", "2"]);
+
+        page.columns.push("missing".to_string());
+        assert!(!append_inserted_row(&mut page, &source, &inserted));
+        assert_eq!(page.rows.len(), 2);
+    }
+}
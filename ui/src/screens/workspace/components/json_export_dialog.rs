@@ -0,0 +1,109 @@
+use crate::screens::workspace::actions::run_json_export_for_tab;
+use dioxus::prelude::*;
+use models::{JsonExportFormat, QueryTabState};
+
+const JSON_FORMATS: [(JsonExportFormat, &str, &str); 3] = [
+    (
+        JsonExportFormat::Array,
+        "JSON array",
+        "A pretty-printed array with one object per row.",
+    ),
+    (
+        JsonExportFormat::Lines,
+        "Newline-delimited JSON",
+        "One compact object per line (.ndjson), for streaming tools.",
+    ),
+    (
+        JsonExportFormat::ArrayWithMetadata,
+        "JSON with metadata",
+        "The rows wrapped with the source table, column types and export time.",
+    ),
+];
+
+/// Tab whose loaded rows a pending JSON export writes out.
+#[derive(Clone, PartialEq)]
+pub(crate) struct JsonExportTarget {
+    pub(crate) tab_id: u64,
+    /// File name without its extension; the extension follows the format.
+    pub(crate) file_stem: String,
+}
+
+#[component]
+pub(crate) fn JsonExportDialog(
+    tabs: Signal<Vec<QueryTabState>>,
+    mut target: Signal<Option<JsonExportTarget>>,
+) -> Element {
+    let mut format = use_signal(JsonExportFormat::default);
+    if target().is_none() {
+        return rsx! {};
+    }
+
+    rsx! {
+        div {
+            class: "settings-modal__backdrop",
+            onclick: move |_| target.set(None),
+            div {
+                class: "settings-modal table-modal table-modal--compact",
+                onclick: move |event| event.stop_propagation(),
+                div {
+                    class: "settings-modal__header",
+                    div {
+                        class: "settings-modal__header-copy",
+                        h2 { class: "settings-modal__title", "Export JSON" }
+                        p {
+                            class: "settings-modal__hint",
+                            "Numbers, booleans, NULLs and json columns keep their types when the result comes from a table."
+                        }
+                    }
+                    button {
+                        class: "button button--ghost button--small",
+                        onclick: move |_| target.set(None),
+                        "Close"
+                    }
+                }
+
+                div {
+                    class: "table-modal__body",
+                    for (value, label, hint) in JSON_FORMATS {
+                        label {
+                            class: "settings-modal__toggle",
+                            input {
+                                r#type: "radio",
+                                name: "json-export-format",
+                                checked: format() == value,
+                                oninput: move |_| format.set(value),
+                            }
+                            span { "{label}" }
+                        }
+                        p { class: "table-modal__hint", "{hint}" }
+                    }
+
+                    div {
+                        class: "table-modal__actions",
+                        button {
+                            class: "button button--ghost",
+                            onclick: move |_| target.set(None),
+                            "Cancel"
+                        }
+                        button {
+                            class: "button button--primary",
+                            onclick: move |_| {
+                                let Some(current_target) = target() else {
+                                    return;
+                                };
+                                target.set(None);
+                                run_json_export_for_tab(
+                                    tabs,
+                                    current_target.tab_id,
+                                    current_target.file_stem,
+                                    format(),
+                                );
+                            },
+                            "Save As…"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
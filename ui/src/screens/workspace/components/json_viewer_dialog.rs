@@ -0,0 +1,202 @@
+use dioxus::prelude::*;
+
+/// Cell opened in the viewer.
+#[derive(Clone, PartialEq)]
+pub(crate) struct JsonViewerTarget {
+    pub(crate) column_name: String,
+    pub(crate) value: String,
+    /// Whether to pretty-print the value as JSON; a long text cell opened to
+    /// read in full is shown as written.
+    pub(crate) json: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct JsonSegment {
+    class_name: &'static str,
+    text: String,
+}
+
+/// Whether cells of a column with this declared type hold JSON documents.
+pub(crate) fn is_json_column_type(data_type: &str) -> bool {
+    matches!(
+        data_type.trim().to_ascii_lowercase().as_str(),
+        "json" | "jsonb"
+    )
+}
+
+#[component]
+pub(crate) fn JsonViewerDialog(target: JsonViewerTarget, on_close: EventHandler<()>) -> Element {
+    let (segments, error) = if target.json {
+        match serde_json::from_str::<serde_json::Value>(&target.value) {
+            Ok(value) => (
+                json_segments(&serde_json::to_string_pretty(&value).unwrap_or_default()),
+                None,
+            ),
+            Err(err) => (Vec::new(), Some(format!("Not valid JSON: {err}"))),
+        }
+    } else {
+        (Vec::new(), None)
+    };
+    let hint = if target.json {
+        "JSON value"
+    } else {
+        "Full value"
+    };
+
+    rsx! {
+        div {
+            class: "settings-modal__backdrop",
+            onclick: move |_| on_close.call(()),
+            div {
+                class: "settings-modal table-modal",
+                onclick: move |event| event.stop_propagation(),
+                div {
+                    class: "settings-modal__header",
+                    div {
+                        class: "settings-modal__header-copy",
+                        h2 { class: "settings-modal__title", "{target.column_name}" }
+                        p { class: "settings-modal__hint", "{hint}" }
+                    }
+                    button {
+                        class: "button button--ghost button--small",
+                        onclick: move |_| on_close.call(()),
+                        "Close"
+                    }
+                }
+
+                div {
+                    class: "table-modal__body",
+                    if let Some(error) = error {
+                        p { class: "table-modal__error", "{error}" }
+                        pre { class: "table-modal__preview-sql json-viewer", "{target.value}" }
+                    } else if !target.json {
+                        pre { class: "table-modal__preview-sql json-viewer", "{target.value}" }
+                    } else {
+                        pre {
+                            class: "table-modal__preview-sql json-viewer",
+                            for segment in segments {
+                                span {
+                                    class: format!("sql-editor__token {}", segment.class_name),
+                                    "{segment.text}"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Splits pretty-printed JSON into coloured runs: object keys, strings,
+/// numbers and `true`/`false`/`null`, with everything else as punctuation.
+fn json_segments(json: &str) -> Vec<JsonSegment> {
+    let mut segments = Vec::<JsonSegment>::new();
+    let mut push = |class_name: &'static str, text: &str| match segments.last_mut() {
+        Some(last) if last.class_name == class_name => last.text.push_str(text),
+        _ => segments.push(JsonSegment {
+            class_name,
+            text: text.to_string(),
+        }),
+    };
+
+    let mut index = 0;
+    while let Some(ch) = json[index..].chars().next() {
+        let end = match ch {
+            '"' => {
+                let end = string_end(json, index);
+                let is_key = json[end..].trim_start().starts_with(':');
+                push(
+                    if is_key {
+                        "sql-editor__token--keyword"
+                    } else {
+                        "sql-editor__token--string"
+                    },
+                    &json[index..end],
+                );
+                end
+            }
+            '-' | '0'..='9' => {
+                let end = run_end(json, index, |ch| {
+                    ch.is_ascii_digit() || matches!(ch, '-' | '+' | '.' | 'e' | 'E')
+                });
+                push("sql-editor__token--number", &json[index..end]);
+                end
+            }
+            'a'..='z' => {
+                let end = run_end(json, index, |ch| ch.is_ascii_lowercase());
+                push("sql-editor__token--type", &json[index..end]);
+                end
+            }
+            _ => {
+                let end = index + ch.len_utf8();
+                push("sql-editor__token--punctuation", &json[index..end]);
+                end
+            }
+        };
+        index = end;
+    }
+    segments
+}
+
+/// Byte offset just past the string literal opening at `start`.
+fn string_end(json: &str, start: usize) -> usize {
+    let mut escaped = false;
+    for (offset, ch) in json[start + 1..].char_indices() {
+        match ch {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '"' => return start + 1 + offset + 1,
+            _ => {}
+        }
+    }
+    json.len()
+}
+
+fn run_end(json: &str, start: usize, matches: impl Fn(char) -> bool) -> usize {
+    json[start..]
+        .char_indices()
+        .find(|(_, ch)| !matches(*ch))
+        .map_or(json.len(), |(offset, _)| start + offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{JsonSegment, is_json_column_type, json_segments};
+
+    fn segment(class_name: &'static str, text: &str) -> JsonSegment {
+        JsonSegment {
+            class_name,
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn json_segments_colour_keys_strings_numbers_and_literals() {
+        assert_eq!(
+            json_segments(r#"{"a\"b": "x", "n": -1.5e3, "ok": null}"#),
+            vec![
+                segment("sql-editor__token--punctuation", "{"),
+                segment("sql-editor__token--keyword", r#""a\"b""#),
+                segment("sql-editor__token--punctuation", ": "),
+                segment("sql-editor__token--string", r#""x""#),
+                segment("sql-editor__token--punctuation", ", "),
+                segment("sql-editor__token--keyword", r#""n""#),
+                segment("sql-editor__token--punctuation", ": "),
+                segment("sql-editor__token--number", "-1.5e3"),
+                segment("sql-editor__token--punctuation", ", "),
+                segment("sql-editor__token--keyword", r#""ok""#),
+                segment("sql-editor__token--punctuation", ": "),
+                segment("sql-editor__token--type", "null"),
+                segment("sql-editor__token--punctuation", "}"),
+            ]
+        );
+    }
+
+    #[test]
+    fn json_column_types_are_detected() {
+        assert!(is_json_column_type("jsonb"));
+        assert!(is_json_column_type(" JSON "));
+        assert!(!is_json_column_type("jsonb[]"));
+    }
+}
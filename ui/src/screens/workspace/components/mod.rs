@@ -1,33 +1,85 @@
+mod activity_monitor;
 mod agent_panel;
+mod array_edit_dialog;
 mod blob_viewer;
+mod bytea_view_dialog;
+mod cell_formatting;
 mod chart;
+mod column_stats;
+mod csv_export_dialog;
+mod csv_import_dialog;
 mod data_diff;
+mod delete_rows_dialog;
 mod er_diagram;
 mod execution_plan;
 mod explorer;
+mod grid_copy;
 mod history;
+mod hstore_edit_dialog;
 mod icon_button;
+mod insert_row_dialog;
+mod json_export_dialog;
+mod json_viewer_dialog;
+mod notifications;
+mod paste_rows_dialog;
+mod query_parameters;
+mod query_templates;
 mod result_table;
+mod roles;
 mod saved_queries;
+mod savepoint_menu;
+mod schema_diff;
+mod server_settings;
 mod session_rail;
 mod sql_editor;
+mod sql_export_dialog;
 mod sql_format_settings;
+mod table_ddl_modal;
 mod table_editor;
 mod tabs;
+mod top_queries;
 
+pub(crate) use activity_monitor::ActivityMonitor;
 pub(crate) use agent_panel::{
     AcpAgentPanel, AgentSqlExecutionMode, apply_acp_events, default_acp_panel_state,
     ensure_default_sql_agent_connected, execute_agent_sql_request, extract_sql_candidate,
     preferred_sql_target_tab_id, replace_messages, send_sql_generation_request,
 };
+pub(crate) use array_edit_dialog::{ArrayEditDialog, ArrayEditTarget, array_editor_supports};
+pub(crate) use blob_viewer::format_bytes;
+pub(crate) use bytea_view_dialog::{ByteaViewDialog, ByteaViewTarget};
+pub(crate) use cell_formatting::{FormattingRule, FormattingRulesDialog, cell_highlight};
 pub use chart::ResultChart;
+pub(crate) use column_stats::{ColumnStatsAnchor, ColumnStatsPopup, ColumnStatsState};
+pub(crate) use csv_export_dialog::{CsvExportDialog, CsvExportTarget};
+pub(crate) use csv_import_dialog::{CsvImportDialog, CsvImportTarget};
+pub(crate) use delete_rows_dialog::{
+    DeleteRowPreview, DeleteRowsDialog, DeleteRowsTarget, RowContextMenu, RowMenuAnchor,
+};
 pub use execution_plan::ExecutionPlanView;
 pub use explorer::{ExplorerConnectionSection, SidebarConnectionTree};
+pub(crate) use grid_copy::{copy_grid_text, parse_tsv_block, tsv_block};
 pub use history::QueryHistoryPanel;
+pub(crate) use hstore_edit_dialog::{HstoreEditDialog, HstoreEditTarget, hstore_editor_supports};
 pub use icon_button::{ActionIcon, IconButton};
+pub(crate) use insert_row_dialog::{InsertRowDialog, InsertRowTarget};
+pub(crate) use json_export_dialog::{JsonExportDialog, JsonExportTarget};
+pub(crate) use json_viewer_dialog::{JsonViewerDialog, JsonViewerTarget, is_json_column_type};
+pub use notifications::NotificationsPanel;
+pub(crate) use paste_rows_dialog::{PasteRowsDialog, PasteRowsTarget};
+pub(crate) use query_parameters::{QueryParametersDialog, QueryParametersTarget};
+pub(crate) use query_templates::QueryTemplatesDialog;
 pub use result_table::ResultTable;
+pub(crate) use roles::RolesWindow;
 pub use saved_queries::SavedQueriesPanel;
+pub(crate) use savepoint_menu::SavepointMenu;
+pub(crate) use schema_diff::SchemaDiffWindow;
+pub(crate) use server_settings::ServerSettingsWindow;
 pub use session_rail::SessionRail;
 pub use sql_editor::SqlEditor;
+pub(crate) use sql_editor::{SqlHighlightContent, insert_text_at_editor_cursor};
+pub(crate) use sql_export_dialog::{SqlExportDialog, SqlExportTarget};
 pub use sql_format_settings::SqlFormatSettingsFields;
+pub(crate) use table_ddl_modal::{TableDdlModal, TableDdlTarget};
 pub use tabs::TabsManager;
+pub(crate) use top_queries::TopQueriesWindow;
@@ -0,0 +1,219 @@
+use crate::app_state::{
+    APP_NOTIFICATION_LISTENERS, APP_NOTIFICATIONS, APP_STATE, listen_to_channel,
+    session_connection, unlisten_channel,
+};
+use dioxus::prelude::*;
+use models::DatabaseKind;
+
+/// LISTEN/NOTIFY for the active PostgreSQL session: the channels it listens
+/// on, a box to send a notification, and everything received, newest first.
+#[component]
+pub fn NotificationsPanel() -> Element {
+    let mut listen_channel = use_signal(String::new);
+    let mut notify_channel = use_signal(String::new);
+    let mut notify_payload = use_signal(String::new);
+    let mut status = use_signal(String::new);
+    let mut sending = use_signal(|| false);
+
+    let active_session = APP_STATE
+        .read()
+        .active_session()
+        .map(|session| (session.id, session.kind));
+    let postgres_session_id = active_session
+        .filter(|(_, kind)| *kind == DatabaseKind::Postgres)
+        .map(|(session_id, _)| session_id);
+    let channels = postgres_session_id
+        .and_then(|session_id| {
+            APP_NOTIFICATION_LISTENERS
+                .read()
+                .get(&session_id)
+                .map(|listener| listener.channels.clone())
+        })
+        .unwrap_or_default();
+    let received = APP_NOTIFICATIONS
+        .read()
+        .iter()
+        .rev()
+        .filter(|received| Some(received.session_id) == postgres_session_id)
+        .cloned()
+        .collect::<Vec<_>>();
+
+    rsx! {
+        section {
+            class: "workspace__panel notifications",
+            div {
+                class: "notifications__header",
+                div {
+                    class: "notifications__header-row",
+                    h2 { class: "workspace__section-title", "Notifications" }
+                    button {
+                        class: "button button--ghost button--small",
+                        disabled: received.is_empty(),
+                        onclick: move |_| {
+                            APP_NOTIFICATIONS.with_mut(|notifications| {
+                                notifications.retain(|received| {
+                                    Some(received.session_id) != postgres_session_id
+                                });
+                            });
+                        },
+                        "Clear"
+                    }
+                }
+                p {
+                    class: "workspace__hint",
+                    if postgres_session_id.is_none() {
+                        "LISTEN and NOTIFY need an active PostgreSQL connection."
+                    } else if status().trim().is_empty() {
+                        "Listening uses its own connection, so queries keep running."
+                    } else {
+                        "{status}"
+                    }
+                }
+            }
+
+            if let Some(session_id) = postgres_session_id {
+                form {
+                    class: "notifications__form",
+                    onsubmit: move |event| {
+                        event.prevent_default();
+                        let channel = listen_channel().trim().to_string();
+                        if channel.is_empty() {
+                            return;
+                        }
+                        listen_to_channel(session_id, channel);
+                        listen_channel.set(String::new());
+                    },
+                    input {
+                        class: "input",
+                        value: "{listen_channel}",
+                        placeholder: "Channel",
+                        oninput: move |event| listen_channel.set(event.value()),
+                    }
+                    button {
+                        class: "button button--small",
+                        r#type: "submit",
+                        disabled: listen_channel().trim().is_empty(),
+                        "Listen"
+                    }
+                }
+
+                if !channels.is_empty() {
+                    div {
+                        class: "notifications__channels",
+                        for channel in channels {
+                            span {
+                                key: "{channel}",
+                                class: "notifications__channel",
+                                span { "{channel}" }
+                                button {
+                                    class: "notifications__unlisten",
+                                    title: "Unlisten {channel}",
+                                    onclick: {
+                                        let channel = channel.clone();
+                                        move |_| unlisten_channel(session_id, &channel)
+                                    },
+                                    "Unlisten"
+                                }
+                            }
+                        }
+                    }
+                }
+
+                form {
+                    class: "notifications__form",
+                    onsubmit: move |event| {
+                        event.prevent_default();
+                        let channel = notify_channel().trim().to_string();
+                        if channel.is_empty() {
+                            return;
+                        }
+                        let Some(connection) = session_connection(session_id) else {
+                            status.set("Connection is no longer open.".to_string());
+                            return;
+                        };
+                        let payload = notify_payload();
+                        sending.set(true);
+                        spawn(async move {
+                            let result =
+                                services::send_notification(connection, channel.clone(), payload)
+                                    .await;
+                            sending.set(false);
+                            match result {
+                                Ok(()) => {
+                                    status.set(format!("Sent NOTIFY on {channel}."));
+                                    notify_payload.set(String::new());
+                                }
+                                Err(err) => status.set(err.to_string()),
+                            }
+                        });
+                    },
+                    input {
+                        class: "input notifications__notify-channel",
+                        value: "{notify_channel}",
+                        placeholder: "NOTIFY channel",
+                        oninput: move |event| notify_channel.set(event.value()),
+                    }
+                    input {
+                        class: "input",
+                        value: "{notify_payload}",
+                        placeholder: "Payload",
+                        oninput: move |event| notify_payload.set(event.value()),
+                    }
+                    button {
+                        class: "button button--ghost button--small",
+                        r#type: "submit",
+                        disabled: sending() || notify_channel().trim().is_empty(),
+                        "Send"
+                    }
+                }
+
+                div {
+                    class: "notifications__list",
+                    if received.is_empty() {
+                        p { class: "empty-state", "No notifications received yet." }
+                    } else {
+                        for received in received {
+                            article {
+                                class: "notifications__item",
+                                div {
+                                    class: "notifications__meta",
+                                    span { class: "notifications__time", "{format_time_of_day(received.received_at)}" }
+                                    span { class: "notifications__item-channel", "{received.notification.channel}" }
+                                    span { class: "notifications__pid", "pid {received.notification.pid}" }
+                                }
+                                if !received.notification.payload.is_empty() {
+                                    pre {
+                                        class: "notifications__payload",
+                                        "{received.notification.payload}"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// `HH:MM:SS` (UTC) of a Unix timestamp in seconds.
+fn format_time_of_day(epoch: i64) -> String {
+    let time_of_day = epoch.rem_euclid(86_400);
+    format!(
+        "{:02}:{:02}:{:02}",
+        time_of_day / 3600,
+        time_of_day % 3600 / 60,
+        time_of_day % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::format_time_of_day;
+
+    #[test]
+    fn format_time_of_day_ignores_the_date() {
+        assert_eq!(format_time_of_day(0), "00:00:00");
+        assert_eq!(format_time_of_day(1_714_557_845), "10:04:05");
+    }
+}
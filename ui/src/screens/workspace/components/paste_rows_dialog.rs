@@ -0,0 +1,374 @@
+use super::insert_row_dialog::pasted_row_values;
+use crate::app_state::{
+    invalidate_row_counts, session_connection, session_transaction, set_session_transaction_status,
+};
+use crate::screens::workspace::actions::{
+    read_only_mode_block_status, read_only_mode_enabled, refresh_tab_result, set_active_tab_status,
+};
+use dioxus::prelude::*;
+use models::{ColumnInfo, QueryTabState, TablePreviewSource};
+use services::SqlTransaction;
+
+/// Savepoint a paste runs under when the session already has a transaction open.
+const PASTE_SAVEPOINT: &str = "shovel_paste_rows";
+
+/// Rows pasted from the clipboard and the table they go into.
+#[derive(Clone, PartialEq)]
+pub(crate) struct PasteRowsTarget {
+    pub(crate) tab_id: u64,
+    pub(crate) session_id: u64,
+    pub(crate) source: TablePreviewSource,
+    pub(crate) rows: Vec<Vec<String>>,
+}
+
+#[component]
+pub(crate) fn PasteRowsDialog(
+    tabs: Signal<Vec<QueryTabState>>,
+    mut target: Signal<Option<PasteRowsTarget>>,
+) -> Element {
+    let mut columns = use_signal(|| None::<Result<Vec<ColumnInfo>, String>>);
+    let mut error = use_signal(String::new);
+    let mut inflight = use_signal(|| false);
+
+    use_effect(move || {
+        let Some(current_target) = target() else {
+            return;
+        };
+        columns.set(None);
+        error.set(String::new());
+        spawn(async move {
+            let source = current_target.source;
+            let result = match session_connection(current_target.session_id) {
+                Some(connection) => {
+                    services::load_table_column_info(connection, source.schema, source.table_name)
+                        .await
+                        .map_err(|err| err.to_string())
+                }
+                None => Err("Connection is no longer open.".to_string()),
+            };
+            columns.set(Some(result));
+        });
+    });
+
+    let Some(current_target) = target() else {
+        return rsx! {};
+    };
+    let read_only_mode = read_only_mode_enabled();
+    let row_count = current_target.rows.len();
+    let loaded_columns = match columns() {
+        Some(Ok(items)) => items,
+        _ => Vec::new(),
+    };
+    let headers = pasted_column_headers(&loaded_columns, &current_target.rows);
+    let checked = current_target
+        .rows
+        .iter()
+        .map(|cells| pasted_row_values(&loaded_columns, cells))
+        .collect::<Vec<_>>();
+    let problems = paste_problems(&checked);
+    let title = if row_count == 1 {
+        "Paste 1 row".to_string()
+    } else {
+        format!("Paste {row_count} rows")
+    };
+
+    rsx! {
+        div {
+            class: "settings-modal__backdrop",
+            onclick: move |_| {
+                if !inflight() {
+                    target.set(None);
+                }
+            },
+            div {
+                class: "settings-modal table-modal",
+                onclick: move |event| event.stop_propagation(),
+                div {
+                    class: "settings-modal__header",
+                    div {
+                        class: "settings-modal__header-copy",
+                        h2 { class: "settings-modal__title", "{title}" }
+                        p {
+                            class: "settings-modal__hint",
+                            "Pasted columns fill the columns of {current_target.source.qualified_name} in order. Empty cells use the column default, then NULL."
+                        }
+                    }
+                    button {
+                        class: "button button--ghost button--small",
+                        disabled: inflight(),
+                        onclick: move |_| target.set(None),
+                        "Close"
+                    }
+                }
+
+                div {
+                    class: "table-modal__body",
+                    match columns() {
+                        None => rsx! {
+                            p { class: "table-modal__hint", "Loading columns…" }
+                        },
+                        Some(Err(err)) => rsx! {
+                            p { class: "table-modal__error", "{err}" }
+                        },
+                        Some(Ok(_)) => rsx! {
+                            div {
+                                class: "table-modal__table-wrap",
+                                table {
+                                    class: "table-modal__table",
+                                    thead {
+                                        tr {
+                                            th { "Row" }
+                                            for (index, header) in headers.iter().enumerate() {
+                                                th { key: "{index}", "{header}" }
+                                            }
+                                        }
+                                    }
+                                    tbody {
+                                        for (row_number, cells) in (1_usize..).zip(current_target.rows.iter()) {
+                                            tr {
+                                                key: "{row_number}",
+                                                td { "{row_number}" }
+                                                for (cell_index, cell) in cells.iter().enumerate() {
+                                                    td {
+                                                        key: "{cell_index}",
+                                                        class: "table-modal__cell--code",
+                                                        "{cell}"
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        },
+                    }
+
+                    if !problems.is_empty() {
+                        div {
+                            class: "table-modal__error",
+                            p { "Fix these cells and copy them again; nothing was inserted." }
+                            ul {
+                                class: "table-modal__problems",
+                                for (index, problem) in problems.iter().enumerate() {
+                                    li { key: "{index}", "{problem}" }
+                                }
+                            }
+                        }
+                    }
+
+                    if !error().is_empty() {
+                        p { class: "table-modal__error", "{error}" }
+                    }
+
+                    div {
+                        class: "table-modal__actions",
+                        button {
+                            class: "button button--ghost",
+                            disabled: inflight(),
+                            onclick: move |_| target.set(None),
+                            "Cancel"
+                        }
+                        button {
+                            class: "button button--primary",
+                            disabled: read_only_mode
+                                || inflight()
+                                || loaded_columns.is_empty()
+                                || !problems.is_empty(),
+                            onclick: {
+                                let checked = checked.clone();
+                                move |_| {
+                                    let Some(current_target) = target() else {
+                                        return;
+                                    };
+                                    if read_only_mode_enabled() {
+                                        error.set(read_only_mode_block_status("row paste"));
+                                        return;
+                                    }
+                                    let Ok(rows) = checked.iter().cloned().collect::<Result<Vec<_>, _>>()
+                                    else {
+                                        return;
+                                    };
+                                    spawn(async move {
+                                        inflight.set(true);
+                                        let result = insert_pasted_rows(&current_target, rows).await;
+                                        inflight.set(false);
+                                        match result {
+                                            Ok(inserted) => {
+                                                target.set(None);
+                                                finish_rows_paste(tabs, current_target, inserted);
+                                            }
+                                            Err(message) => error.set(message),
+                                        }
+                                    });
+                                }
+                            },
+                            if inflight() { "Inserting…" } else { "Insert" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Runs every INSERT in one transaction: under a savepoint when the session
+/// has a transaction open, otherwise in one begun for the paste. A failing
+/// row undoes the rows before it.
+async fn insert_pasted_rows(
+    target: &PasteRowsTarget,
+    rows: Vec<Vec<(String, String)>>,
+) -> Result<usize, String> {
+    if let Some(transaction) = session_transaction(target.session_id) {
+        transaction
+            .savepoint(PASTE_SAVEPOINT)
+            .await
+            .map_err(|err| err.to_string())?;
+        let mut result = insert_rows(&transaction, &target.source, rows).await;
+        if result.is_err() {
+            let _ = transaction.rollback_to_savepoint(PASTE_SAVEPOINT).await;
+        }
+        if let Err(err) = transaction.release_savepoint(PASTE_SAVEPOINT).await {
+            result = result.and(Err(err.to_string()));
+        }
+        set_session_transaction_status(target.session_id, transaction.status().await);
+        return result;
+    }
+
+    let Some(connection) = session_connection(target.session_id) else {
+        return Err("Connection is no longer open.".to_string());
+    };
+    let transaction = services::begin_transaction(connection)
+        .await
+        .map_err(|err| err.to_string())?;
+    match insert_rows(&transaction, &target.source, rows).await {
+        Ok(inserted) => {
+            transaction.commit().await.map_err(|err| err.to_string())?;
+            Ok(inserted)
+        }
+        Err(message) => {
+            let _ = transaction.rollback().await;
+            Err(message)
+        }
+    }
+}
+
+async fn insert_rows(
+    transaction: &SqlTransaction,
+    source: &TablePreviewSource,
+    rows: Vec<Vec<(String, String)>>,
+) -> Result<usize, String> {
+    let row_count = rows.len();
+    for (index, column_values) in rows.into_iter().enumerate() {
+        transaction
+            .insert_table_row_with_values(source.clone(), column_values)
+            .await
+            .map_err(|err| format!("Row {} failed: {err}", index + 1))?;
+    }
+    Ok(row_count)
+}
+
+fn finish_rows_paste(tabs: Signal<Vec<QueryTabState>>, target: PasteRowsTarget, inserted: usize) {
+    invalidate_row_counts(target.session_id, &target.source.qualified_name);
+    let summary = if inserted == 1 {
+        "Pasted 1 row".to_string()
+    } else {
+        format!("Pasted {inserted} rows")
+    };
+    set_active_tab_status(
+        tabs,
+        target.tab_id,
+        format!("{summary} into {}", target.source.table_name),
+    );
+    let current_tab = tabs
+        .read()
+        .iter()
+        .find(|tab| tab.id == target.tab_id)
+        .cloned();
+    if let Some(current_tab) = current_tab {
+        refresh_tab_result(tabs, current_tab, Some(target.source));
+    }
+}
+
+/// Names the table column each pasted column lands in, as wide as the widest
+/// pasted row.
+fn pasted_column_headers(columns: &[ColumnInfo], rows: &[Vec<String>]) -> Vec<String> {
+    let width = rows.iter().map(Vec::len).max().unwrap_or_default();
+    let mut names = columns
+        .iter()
+        .filter(|column| !column.generated)
+        .map(|column| column.name.as_str());
+    (0..width)
+        .map(|_| names.next().unwrap_or("(no column)").to_string())
+        .collect()
+}
+
+/// Every cell that failed to convert, labelled with its pasted row number.
+fn paste_problems(checked: &[Result<Vec<(String, String)>, Vec<String>>]) -> Vec<String> {
+    checked
+        .iter()
+        .enumerate()
+        .filter_map(|(index, result)| Some((index, result.as_ref().err()?)))
+        .flat_map(|(index, messages)| {
+            messages
+                .iter()
+                .map(move |message| format!("Row {}: {message}", index + 1))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{paste_problems, pasted_column_headers};
+    use models::ColumnInfo;
+
+    fn column(name: &str, generated: bool) -> ColumnInfo {
+        ColumnInfo {
+            name: name.to_string(),
+            data_type: "text".to_string(),
+            nullable: true,
+            primary_key: false,
+            foreign_key: false,
+            default_value: None,
+            identity: false,
+            generated,
+            comment: None,
+        }
+    }
+
+    #[test]
+    fn headers_skip_generated_columns_and_flag_extra_cells() {
+        let columns = vec![
+            column("id", false),
+            column("total", true),
+            column("note", false),
+        ];
+        let rows = vec![
+            vec!["1".to_string()],
+            vec!["2".to_string(), "b".to_string(), "extra".to_string()],
+        ];
+
+        assert_eq!(
+            pasted_column_headers(&columns, &rows),
+            vec!["id", "note", "(no column)"]
+        );
+    }
+
+    #[test]
+    fn problems_are_numbered_by_pasted_row() {
+        let checked = vec![
+            Ok(Vec::new()),
+            Err(vec![
+                "active expects true or false.".to_string(),
+                "price expects a number.".to_string(),
+            ]),
+        ];
+
+        assert_eq!(
+            paste_problems(&checked),
+            vec![
+                "Row 2: active expects true or false.",
+                "Row 2: price expects a number.",
+            ]
+        );
+    }
+}
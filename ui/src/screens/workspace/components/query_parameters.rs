@@ -0,0 +1,247 @@
+use crate::app_state::session_connection;
+use crate::screens::workspace::actions::{
+    run_query_with_parameters_for_tab, tab_connection_or_error,
+};
+use dioxus::prelude::*;
+use models::{QueryHistoryItem, QueryTabState};
+use std::collections::{HashMap, HashSet};
+
+/// PostgreSQL query waiting for its `:name` and `$N` parameters before it runs.
+#[derive(Clone, PartialEq)]
+pub(crate) struct QueryParametersTarget {
+    pub(crate) tab_id: u64,
+    pub(crate) session_id: u64,
+    pub(crate) sql: String,
+    pub(crate) parameters: Vec<String>,
+    pub(crate) page_size: u32,
+    pub(crate) tab_title: String,
+    pub(crate) connection_name: String,
+}
+
+/// Types a parameter value can be cast to, besides leaving it to PostgreSQL.
+const PARAMETER_TYPES: [&str; 10] = [
+    "text",
+    "integer",
+    "bigint",
+    "numeric",
+    "boolean",
+    "date",
+    "timestamp",
+    "timestamptz",
+    "uuid",
+    "jsonb",
+];
+
+/// Asks for a value per query parameter, showing the type PostgreSQL infers
+/// for it, then runs the query with the values bound, cast to the type picked
+/// for each. Values and types are kept per parameter name for the next run,
+/// so re-running with a different id is one edit.
+#[component]
+pub(crate) fn QueryParametersDialog(
+    tabs: Signal<Vec<QueryTabState>>,
+    history: Signal<Vec<QueryHistoryItem>>,
+    next_history_id: Signal<u64>,
+    mut target: Signal<Option<QueryParametersTarget>>,
+) -> Element {
+    let mut values = use_signal(HashMap::<String, String>::new);
+    let mut casts = use_signal(HashMap::<String, String>::new);
+    let mut nulls = use_signal(HashSet::<String>::new);
+    let mut types = use_signal(Vec::<Option<String>>::new);
+
+    use_effect(move || {
+        let Some(current) = target() else {
+            return;
+        };
+        types.set(Vec::new());
+        spawn(async move {
+            let Some(connection) = session_connection(current.session_id) else {
+                return;
+            };
+            // A query PostgreSQL cannot prepare simply gets no type hints; the
+            // error shows up when it runs.
+            if let Ok(described) =
+                services::describe_query_parameters(connection, current.sql).await
+            {
+                types.set(described);
+            }
+        });
+    });
+
+    let Some(current_target) = target() else {
+        return rsx! {};
+    };
+    let described = types();
+    let fields = current_target
+        .parameters
+        .iter()
+        .enumerate()
+        .map(|(index, name)| {
+            let value = values.read().get(name).cloned().unwrap_or_default();
+            let cast = casts.read().get(name).cloned().unwrap_or_default();
+            let null = nulls.read().contains(name);
+            let type_name = described.get(index).cloned().flatten();
+            let caption = match type_name.as_ref() {
+                Some(type_name) => format!("{name} · {type_name}"),
+                None => name.clone(),
+            };
+            (
+                name.clone(),
+                caption,
+                value,
+                null,
+                cast,
+                type_name.unwrap_or_default(),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let mut run = move || {
+        let Some(current) = target() else {
+            return;
+        };
+        let bound_values = current
+            .parameters
+            .iter()
+            .map(|name| services::QueryParameterValue {
+                name: name.clone(),
+                value: (!nulls.read().contains(name))
+                    .then(|| values.read().get(name).cloned().unwrap_or_default()),
+                type_name: casts
+                    .read()
+                    .get(name)
+                    .filter(|cast| !cast.is_empty())
+                    .cloned(),
+            })
+            .collect::<Vec<_>>();
+        target.set(None);
+        let Some(connection) = tab_connection_or_error(tabs, current.tab_id, current.session_id)
+        else {
+            return;
+        };
+        run_query_with_parameters_for_tab(
+            tabs,
+            current.tab_id,
+            connection,
+            current.sql,
+            bound_values,
+            current.page_size,
+            Some((
+                history,
+                next_history_id,
+                current.tab_title,
+                current.connection_name,
+            )),
+        );
+    };
+
+    rsx! {
+        div {
+            class: "settings-modal__backdrop",
+            onclick: move |_| target.set(None),
+            div {
+                class: "settings-modal table-modal table-modal--compact",
+                onclick: move |event| event.stop_propagation(),
+                div {
+                    class: "settings-modal__header",
+                    div {
+                        class: "settings-modal__header-copy",
+                        h2 { class: "settings-modal__title", "Query Parameters" }
+                        p {
+                            class: "settings-modal__hint",
+                            "Values are sent as bound parameters, cast to the type picked beside them or the one PostgreSQL infers."
+                        }
+                    }
+                    button {
+                        class: "button button--ghost button--small",
+                        onclick: move |_| target.set(None),
+                        "Close"
+                    }
+                }
+
+                div {
+                    class: "table-modal__body",
+                    for (index, (name, caption, value, null, cast, type_name)) in fields.into_iter().enumerate() {
+                        div {
+                            key: "{name}",
+                            class: "field",
+                            span { class: "field__label", "{caption}" }
+                            div {
+                                class: "table-modal__parameter-row",
+                                input {
+                                    class: "input",
+                                    value: "{value}",
+                                    autofocus: index == 0,
+                                    placeholder: if null { "NULL".to_string() } else { type_name.clone() },
+                                    disabled: null,
+                                    oninput: {
+                                        let name = name.clone();
+                                        move |event: FormEvent| {
+                                            values.with_mut(|values| {
+                                                values.insert(name.clone(), event.value());
+                                            });
+                                        }
+                                    },
+                                    onkeydown: move |event| {
+                                        if event.key() == Key::Enter {
+                                            event.prevent_default();
+                                            run();
+                                        }
+                                    },
+                                }
+                                select {
+                                    class: "input",
+                                    title: "Cast the value to this type",
+                                    value: "{cast}",
+                                    oninput: {
+                                        let name = name.clone();
+                                        move |event: FormEvent| {
+                                            casts.with_mut(|casts| {
+                                                casts.insert(name.clone(), event.value());
+                                            });
+                                        }
+                                    },
+                                    option { value: "", "Inferred" }
+                                    for type_option in PARAMETER_TYPES {
+                                        option { value: type_option, "{type_option}" }
+                                    }
+                                }
+                                label {
+                                    class: "settings-modal__toggle",
+                                    input {
+                                        r#type: "checkbox",
+                                        checked: null,
+                                        oninput: move |event| {
+                                            let null = event.checked();
+                                            nulls.with_mut(|nulls| {
+                                                if null {
+                                                    nulls.insert(name.clone());
+                                                } else {
+                                                    nulls.remove(&name);
+                                                }
+                                            });
+                                        },
+                                    }
+                                    span { "NULL" }
+                                }
+                            }
+                        }
+                    }
+
+                    div {
+                        class: "table-modal__actions",
+                        button {
+                            class: "button button--ghost",
+                            onclick: move |_| target.set(None),
+                            "Cancel"
+                        }
+                        button {
+                            class: "button button--primary",
+                            onclick: move |_| run(),
+                            "Run"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,272 @@
+use crate::screens::workspace::actions::append_to_tab_sql;
+use dioxus::prelude::*;
+use models::{
+    QueryTabState, QueryTemplate, TemplateParam, builtin_query_templates, template_placeholders,
+};
+
+/// Built-in and saved query templates. Using one asks for its parameters and
+/// inserts the filled-in SQL into the active tab.
+#[component]
+pub(crate) fn QueryTemplatesDialog(
+    tabs: Signal<Vec<QueryTabState>>,
+    active_tab_id: Signal<u64>,
+    mut show_templates: Signal<bool>,
+) -> Element {
+    let mut user_templates = use_signal(Vec::<QueryTemplate>::new);
+    // Template being filled in and the value entered for each parameter.
+    let mut selected = use_signal(|| None::<QueryTemplate>);
+    let mut values = use_signal(Vec::<(String, String)>::new);
+    let mut save_name = use_signal(String::new);
+    let mut save_description = use_signal(String::new);
+    let mut status = use_signal(String::new);
+
+    use_effect(move || {
+        spawn(async move {
+            match services::load_query_templates().await {
+                Ok(items) => user_templates.set(items),
+                Err(err) => status.set(err),
+            }
+        });
+    });
+
+    let active_sql = tabs
+        .read()
+        .iter()
+        .find(|tab| tab.id == active_tab_id())
+        .map(|tab| tab.sql.trim().to_string())
+        .unwrap_or_default();
+    let mut templates = builtin_query_templates();
+    templates.extend(user_templates());
+
+    rsx! {
+        div {
+            class: "settings-modal__backdrop",
+            onclick: move |_| show_templates.set(false),
+            div {
+                class: "settings-modal table-modal table-modal--wide",
+                onclick: move |event| event.stop_propagation(),
+                div {
+                    class: "settings-modal__header",
+                    div {
+                        class: "settings-modal__header-copy",
+                        h2 { class: "settings-modal__title", "Query Templates" }
+                        p {
+                            class: "settings-modal__hint",
+                            "Reusable SQL with {{{{param}}}} placeholders filled in before it reaches the editor."
+                        }
+                    }
+                    button {
+                        class: "button button--ghost button--small",
+                        onclick: move |_| show_templates.set(false),
+                        "Close"
+                    }
+                }
+
+                div {
+                    class: "table-modal__body",
+                    if !status().is_empty() {
+                        p { class: "table-modal__hint", "{status}" }
+                    }
+
+                    if let Some(template) = selected() {
+                        h3 { class: "query-templates__name", "{template.name}" }
+                        if !template.description.is_empty() {
+                            p { class: "table-modal__hint", "{template.description}" }
+                        }
+                        for (index, (name, value)) in values().into_iter().enumerate() {
+                            label {
+                                key: "{name}",
+                                class: "field",
+                                span { class: "field__label", "{name}" }
+                                input {
+                                    class: "input",
+                                    value: "{value}",
+                                    oninput: move |event| {
+                                        values.with_mut(|values| {
+                                            if let Some(entry) = values.get_mut(index) {
+                                                entry.1 = event.value();
+                                            }
+                                        });
+                                    },
+                                }
+                            }
+                        }
+                        pre { class: "table-modal__preview-sql", "{template.render(&values())}" }
+                        div {
+                            class: "table-modal__actions",
+                            button {
+                                class: "button button--ghost",
+                                onclick: move |_| selected.set(None),
+                                "Back"
+                            }
+                            button {
+                                class: "button button--primary",
+                                onclick: {
+                                    let template = template.clone();
+                                    move |_| {
+                                        let tab_id = active_tab_id();
+                                        if tab_id == 0 {
+                                            status.set("Open a query tab first.".to_string());
+                                            return;
+                                        }
+                                        append_to_tab_sql(
+                                            tabs,
+                                            tab_id,
+                                            template.render(&values()),
+                                            format!("Inserted template {}", template.name),
+                                        );
+                                        show_templates.set(false);
+                                    }
+                                },
+                                "Insert into Editor"
+                            }
+                        }
+                    } else {
+                        div {
+                            class: "table-modal__table-wrap",
+                            table {
+                                class: "table-modal__table",
+                                thead {
+                                    tr {
+                                        th { "Template" }
+                                        th { "Parameters" }
+                                        th {}
+                                    }
+                                }
+                                tbody {
+                                    for template in templates {
+                                        tr {
+                                            key: "{template.builtin}-{template.id}-{template.name}",
+                                            td {
+                                                p { class: "query-templates__name", "{template.name}" }
+                                                if !template.description.is_empty() {
+                                                    p { class: "query-templates__description", "{template.description}" }
+                                                }
+                                            }
+                                            td {
+                                                class: "table-modal__cell--code",
+                                                {parameter_names(&template.parameters)}
+                                            }
+                                            td {
+                                                class: "table-modal__cell--actions",
+                                                button {
+                                                    class: "button button--ghost button--small",
+                                                    onclick: {
+                                                        let template = template.clone();
+                                                        move |_| {
+                                                            values.set(
+                                                                template
+                                                                    .parameters
+                                                                    .iter()
+                                                                    .map(|param| (param.name.clone(), param.default_value.clone()))
+                                                                    .collect(),
+                                                            );
+                                                            status.set(String::new());
+                                                            selected.set(Some(template.clone()));
+                                                        }
+                                                    },
+                                                    "Use"
+                                                }
+                                                if !template.builtin {
+                                                    button {
+                                                        class: "button button--ghost button--small",
+                                                        onclick: {
+                                                            let id = template.id;
+                                                            let name = template.name.clone();
+                                                            move |_| {
+                                                                user_templates.with_mut(|items| {
+                                                                    items.retain(|existing| existing.id != id);
+                                                                });
+                                                                status.set(format!("Deleted {name}."));
+                                                                spawn(async move {
+                                                                    let _ = services::delete_query_template(id).await;
+                                                                });
+                                                            }
+                                                        },
+                                                        "Delete"
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+
+                        div {
+                            class: "table-modal__toolbar",
+                            input {
+                                class: "input",
+                                value: "{save_name}",
+                                placeholder: "Template name",
+                                oninput: move |event| save_name.set(event.value()),
+                            }
+                            input {
+                                class: "input",
+                                value: "{save_description}",
+                                placeholder: "Description",
+                                oninput: move |event| save_description.set(event.value()),
+                            }
+                            button {
+                                class: "button button--primary button--small",
+                                disabled: active_sql.is_empty() || save_name().trim().is_empty(),
+                                title: "Save the active tab's SQL; each {{{{param}}}} becomes a parameter",
+                                onclick: {
+                                    let body = active_sql.clone();
+                                    move |_| {
+                                        let item = QueryTemplate {
+                                            id: next_template_id(&user_templates.read()),
+                                            name: save_name().trim().to_string(),
+                                            description: save_description().trim().to_string(),
+                                            parameters: template_placeholders(&body)
+                                                .into_iter()
+                                                .map(|name| TemplateParam {
+                                                    name,
+                                                    default_value: String::new(),
+                                                })
+                                                .collect(),
+                                            body: body.clone(),
+                                            builtin: false,
+                                        };
+                                        status.set(format!("Saved {}.", item.name));
+                                        user_templates.with_mut(|items| {
+                                            items.push(item.clone());
+                                            items.sort_by(|left, right| {
+                                                left.name
+                                                    .cmp(&right.name)
+                                                    .then_with(|| left.id.cmp(&right.id))
+                                            });
+                                        });
+                                        save_name.set(String::new());
+                                        save_description.set(String::new());
+                                        spawn(async move {
+                                            let _ = services::save_query_template(item).await;
+                                        });
+                                    }
+                                },
+                                "Save Current SQL"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn parameter_names(parameters: &[TemplateParam]) -> String {
+    parameters
+        .iter()
+        .map(|param| param.name.as_str())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn next_template_id(templates: &[QueryTemplate]) -> u64 {
+    templates
+        .iter()
+        .map(|template| template.id)
+        .max()
+        .unwrap_or(0)
+        + 1
+}
@@ -1,19 +1,35 @@
 use std::collections::{HashMap, HashSet};
+use std::ops::RangeInclusive;
 
+use crate::app_state::{
+    APP_RUNNING_TAB_EXPORTS, APP_RUNNING_TAB_LOADS, invalidate_row_counts, session_connection,
+    session_transaction, set_session_transaction_status,
+};
+use crate::clipboard::read_text;
 use crate::screens::workspace::actions::{
-    append_next_tab_page, apply_active_tab_filter, clear_active_tab_filter, load_tab_page,
+    append_next_tab_page, apply_active_tab_filter, cached_row_count_for_tab, cancel_tab_export,
+    cancel_tab_load, clear_active_tab_filter, format_row_count, load_tab_page,
     read_only_mode_block_status, read_only_mode_enabled, refresh_tab_result, rows_toolbar_summary,
     set_active_tab_status, tab_connection_or_error, toggle_active_tab_sort,
 };
-use crate::screens::workspace::components::{ActionIcon, IconButton, ResultChart};
+use crate::screens::workspace::components::{
+    ActionIcon, ArrayEditDialog, ArrayEditTarget, ByteaViewDialog, ByteaViewTarget,
+    ColumnStatsAnchor, ColumnStatsPopup, ColumnStatsState, DeleteRowPreview, DeleteRowsDialog,
+    DeleteRowsTarget, FormattingRule, FormattingRulesDialog, HstoreEditDialog, HstoreEditTarget,
+    IconButton, InsertRowDialog, InsertRowTarget, JsonViewerDialog, JsonViewerTarget,
+    PasteRowsDialog, PasteRowsTarget, ResultChart, RowContextMenu, RowMenuAnchor, TableDdlModal,
+    TableDdlTarget, array_editor_supports, cell_highlight, copy_grid_text, hstore_editor_supports,
+    is_json_column_type, parse_tsv_block, tsv_block,
+};
 use dioxus::html::input_data::MouseButton;
 use dioxus::prelude::*;
 use models::{
     EditableTableContext, PendingCellChange, PendingDeleteRow, PendingInsertRow,
     PendingTableChanges, QueryFilter, QueryFilterMode, QueryFilterOperator, QueryFilterRule,
-    QueryOutput, QuerySort, QueryTabState,
+    QueryOutput, QuerySort, QueryTabState, TablePreviewSource,
 };
 use serde_json::{Map, Value};
+use services::SqlTransaction;
 
 #[derive(Clone, PartialEq)]
 struct EditingCell {
@@ -22,6 +38,36 @@ struct EditingCell {
     value: String,
 }
 
+/// Rectangle of grid cells between two corners, as `(row, column)` indices.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct CellRange {
+    anchor: (usize, usize),
+    end: (usize, usize),
+}
+
+impl CellRange {
+    fn rows(self) -> RangeInclusive<usize> {
+        self.anchor.0.min(self.end.0)..=self.anchor.0.max(self.end.0)
+    }
+
+    fn columns(self) -> RangeInclusive<usize> {
+        self.anchor.1.min(self.end.1)..=self.anchor.1.max(self.end.1)
+    }
+
+    fn contains(self, row: usize, column: usize) -> bool {
+        self.rows().contains(&row) && self.columns().contains(&column)
+    }
+}
+
+/// Cell of a structured PostgreSQL type, edited in a dialog instead of inline.
+#[derive(Clone, PartialEq)]
+enum StructuredCellEdit {
+    Array(ArrayEditTarget),
+    Hstore(HstoreEditTarget),
+    /// Shown read-only; the grid holds only the start of the value.
+    Bytea(ByteaViewTarget),
+}
+
 #[derive(Clone, Copy, PartialEq, Eq)]
 enum RowDetailsView {
     Fields,
@@ -45,6 +91,7 @@ pub fn ResultTable(
     result: Option<QueryOutput>,
     tabs: Signal<Vec<QueryTabState>>,
     active_tab_id: Signal<u64>,
+    next_tab_id: Signal<u64>,
 ) -> Element {
     let mut editing_cell = use_signal(|| None::<EditingCell>);
     let mut filter_draft = use_signal(|| QueryFilter {
@@ -54,12 +101,26 @@ pub fn ResultTable(
     let mut filter_sync_key = use_signal(String::new);
     let mut filter_panel_open = use_signal(|| false);
     let mut selected_row_index = use_signal(|| None::<usize>);
+    // Rows added with Ctrl/Cmd- or Shift-click on top of the focused row.
+    let mut selected_rows = use_signal(HashSet::<usize>::new);
+    let mut selected_col_index = use_signal(|| None::<usize>);
+    // Block of cells picked with Shift-click or a mouse drag, for copying.
+    let mut cell_range = use_signal(|| None::<CellRange>);
+    let mut drag_anchor = use_signal(|| None::<(usize, usize)>);
     let mut selected_row_sync_key = use_signal(String::new);
     let mut show_row_details = use_signal(|| false);
     let mut row_details_view = use_signal(|| RowDetailsView::Fields);
     let mut editing_row_values = use_signal(Vec::<(usize, String)>::new);
     let mut editing_row_ref = use_signal(|| None::<EditableRowRef>);
     let mut display_rows_cache = use_signal(Vec::<DisplayRow>::new);
+    // Narrows the loaded rows in memory; the cache itself stays unfiltered so
+    // row indices keep pointing at the same rows.
+    let mut client_filter = use_signal(String::new);
+    // Pinned column names per tab, kept in the order they were pinned.
+    let pinned_columns = use_signal(HashMap::<u64, Vec<String>>::new);
+    let formatting_rules = use_signal(HashMap::<u64, Vec<FormattingRule>>::new);
+    let mut formatting_rules_target = use_signal(|| None::<u64>);
+    let mut table_ddl = use_signal(|| None::<TableDdlTarget>);
     let mut details_width = use_signal(|| 360.0);
     let mut details_resize_active = use_signal(|| false);
     let mut resize_start_x = use_signal(|| 0.0_f64);
@@ -67,6 +128,15 @@ pub fn ResultTable(
     let mut scroll_offset = use_signal(|| 0.0_f64);
     let mut viewport_height = use_signal(|| 600.0_f64);
     let mut show_chart = use_signal(|| false);
+    let mut column_stats_anchor = use_signal(|| None::<ColumnStatsAnchor>);
+    let mut insert_row_target = use_signal(|| None::<InsertRowTarget>);
+    let mut row_menu_anchor = use_signal(|| None::<RowMenuAnchor>);
+    let mut delete_rows_target = use_signal(|| None::<DeleteRowsTarget>);
+    let paste_rows_target = use_signal(|| None::<PasteRowsTarget>);
+    // Declared column types of the edited table, by column name.
+    let mut column_types = use_signal(HashMap::<String, String>::new);
+    let mut structured_edit = use_signal(|| None::<(EditingCell, StructuredCellEdit)>);
+    let mut json_viewer = use_signal(|| None::<JsonViewerTarget>);
 
     let current_editing = editing_cell();
     let active_tab = tabs
@@ -85,13 +155,78 @@ pub fn ResultTable(
         .map(|tab| tab.pending_table_changes.clone())
         .unwrap_or_default();
     let has_pending_changes = !pending_changes.is_empty();
+    let can_undo_pending_change = pending_changes.can_undo();
     let is_loading_more = active_tab.as_ref().is_some_and(|tab| tab.is_loading_more);
+    let running_load_label = active_tab
+        .as_ref()
+        .filter(|tab| APP_RUNNING_TAB_LOADS.read().contains_key(&tab.id))
+        .map(running_load_text);
+    let export_running = active_tab
+        .as_ref()
+        .is_some_and(|tab| APP_RUNNING_TAB_EXPORTS.read().contains_key(&tab.id));
+    let row_count = active_tab.as_ref().and_then(cached_row_count_for_tab);
     let sort_enabled = active_tab.as_ref().is_some_and(can_sort_tab);
     let filter_enabled = active_tab.as_ref().is_some_and(can_filter_tab);
     let current_columns = result_columns(result.as_ref());
     let next_filter_draft = filter_draft_from_state(active_filter.as_ref(), &current_columns);
     let next_filter_sync_key = filter_sync_key_for_tab(active_tab.as_ref(), &current_columns);
     let next_row_sync_key = row_sync_key_for_tab(active_tab.as_ref(), result.as_ref());
+    let column_type_source = active_tab.as_ref().and_then(|tab| match result.as_ref() {
+        Some(QueryOutput::Table(page)) => page
+            .editable
+            .as_ref()
+            .map(|editable| (tab.session_id, editable.source.clone())),
+        _ => None,
+    });
+
+    let save_structured_edit = move |value: String| {
+        if let Some((edit, _)) = structured_edit() {
+            structured_edit.set(None);
+            commit_cell_edit(
+                editing_cell,
+                tabs,
+                active_tab_id,
+                EditingCell { value, ..edit },
+            );
+        }
+        let _ = document::eval(FOCUS_RESULTS_TABLE_SCRIPT);
+    };
+    let cancel_structured_edit = move |_: ()| {
+        structured_edit.set(None);
+        let _ = document::eval(FOCUS_RESULTS_TABLE_SCRIPT);
+    };
+    let json_columns = column_types
+        .read()
+        .iter()
+        .filter(|(_, data_type)| is_json_column_type(data_type))
+        .map(|(name, _)| name.clone())
+        .collect::<HashSet<_>>();
+
+    use_effect(use_reactive(
+        (&column_type_source,),
+        move |(column_type_source,)| {
+            column_types.set(HashMap::new());
+            let Some((session_id, source)) = column_type_source else {
+                return;
+            };
+            let Some(connection) = session_connection(session_id) else {
+                return;
+            };
+            spawn(async move {
+                if let Ok(columns) =
+                    services::load_table_column_info(connection, source.schema, source.table_name)
+                        .await
+                {
+                    column_types.set(
+                        columns
+                            .into_iter()
+                            .map(|column| (column.name, column.data_type))
+                            .collect(),
+                    );
+                }
+            });
+        },
+    ));
 
     use_effect(move || {
         if filter_sync_key() != next_filter_sync_key {
@@ -110,6 +245,9 @@ pub fn ResultTable(
         if selected_row_sync_key() != next_row_sync_key {
             selected_row_sync_key.set(next_row_sync_key.clone());
             selected_row_index.set(None);
+            selected_rows.set(HashSet::new());
+            selected_col_index.set(None);
+            cell_range.set(None);
             row_details_view.set(RowDetailsView::Fields);
         }
     });
@@ -169,15 +307,31 @@ pub fn ResultTable(
                 }
 
                 let display_rows = display_rows_cache();
+                let shown_rows = client_filter_matches(&display_rows, &client_filter());
+                let column_order = grid_column_order(
+                    &page.columns,
+                    pinned_columns
+                        .read()
+                        .get(&active_tab_id())
+                        .map(Vec::as_slice)
+                        .unwrap_or_default(),
+                );
+                let pinned_count = column_order.iter().filter(|(_, pin)| pin.is_some()).count();
+                let tab_formatting_rules = formatting_rules
+                    .read()
+                    .get(&active_tab_id())
+                    .cloned()
+                    .unwrap_or_default();
+                let client_filter_active = !client_filter().trim().is_empty();
                 let virtual_row_height: f64 = 28.0;
                 let virtual_buffer: usize = 10;
                 let virtual_first = ((scroll_offset() / virtual_row_height) as usize).saturating_sub(virtual_buffer);
                 let virtual_last = {
                     let raw = (((scroll_offset() + viewport_height()) / virtual_row_height + 1.0) as usize).saturating_add(virtual_buffer);
-                    raw.min(display_rows.len())
+                    raw.min(shown_rows.len())
                 };
                 let virtual_top_height = virtual_first as f64 * virtual_row_height;
-                let virtual_bottom_height = (display_rows.len().saturating_sub(virtual_last)) as f64 * virtual_row_height;
+                let virtual_bottom_height = (shown_rows.len().saturating_sub(virtual_last)) as f64 * virtual_row_height;
                 // Pre-compute O(1) lookup set for cell_class (avoids linear scan per visible cell).
                 let updated_cells_set: HashSet<(String, String)> = pending_changes
                     .updated_cells
@@ -196,6 +350,8 @@ pub fn ResultTable(
                 let selected_row_label = selected_row
                     .as_ref()
                     .map(|(row_index, row)| display_row_label(page.offset, draft_rows, *row_index, row));
+                let selected_indices = selected_row_indices(selected_row_index(), &selected_rows.read());
+                let aggregates = selection_aggregates(&page.columns, &display_rows, &selected_indices);
                 let details_json = selected_row
                     .as_ref()
                     .map(|(_, row)| format_row_json(&page.columns, &row.values))
@@ -241,6 +397,18 @@ pub fn ResultTable(
                                                 class: "results__toolbar-chip",
                                                 "{rows_toolbar_summary(page.offset, page.rows.len(), page.page_size)}"
                                             }
+                                            if let Some(count) = row_count {
+                                                span {
+                                                    class: "results__toolbar-chip",
+                                                    "{format_row_count(count)}"
+                                                }
+                                            }
+                                            if client_filter_active {
+                                                span {
+                                                    class: "results__toolbar-chip",
+                                                    "{shown_rows.len()} of {display_rows.len()} rows (filtered)"
+                                                }
+                                            }
                                             if should_render_result_status_chip(&status_text, has_pending_changes) {
                                                 span {
                                                     class: "results__toolbar-chip",
@@ -260,6 +428,20 @@ pub fn ResultTable(
                                         }
                                         div {
                                         class: "results__toolbar-actions",
+                                        input {
+                                            class: "input results__quick-filter",
+                                            r#type: "search",
+                                            value: "{client_filter}",
+                                            placeholder: "Filter loaded rows",
+                                            oninput: move |event| {
+                                                client_filter.set(event.value());
+                                                selected_row_index.set(None);
+                                                selected_rows.set(HashSet::new());
+                                                cell_range.set(None);
+                                                scroll_offset.set(0.0);
+                                                let _ = document::eval(RESET_RESULTS_SCROLL_SCRIPT);
+                                            },
+                                        }
                                         if filter_enabled {
                                             IconButton {
                                                 icon: ActionIcon::Filter,
@@ -269,6 +451,42 @@ pub fn ResultTable(
                                                 onclick: move |_| filter_panel_open.toggle(),
                                             }
                                         }
+                                        if let Some(target) = active_tab.as_ref().and_then(|tab| {
+                                            tab.structure_source.clone().map(|source| TableDdlTarget {
+                                                session_id: tab.session_id,
+                                                source,
+                                            })
+                                        }) {
+                                            IconButton {
+                                                icon: ActionIcon::ExportSql,
+                                                label: "Show DDL".to_string(),
+                                                small: true,
+                                                onclick: move |_| table_ddl.set(Some(target.clone())),
+                                            }
+                                        }
+                                        IconButton {
+                                            icon: ActionIcon::Formatting,
+                                            label: "Formatting rules".to_string(),
+                                            active: !tab_formatting_rules.is_empty(),
+                                            small: true,
+                                            onclick: move |_| formatting_rules_target.set(Some(active_tab_id())),
+                                        }
+                                        if let Some(label) = running_load_label {
+                                            button {
+                                                class: "results__toolbar-chip results__cancel-load",
+                                                title: "Cancel loading and keep the rows shown",
+                                                onclick: move |_| cancel_tab_load(tabs, active_tab_id()),
+                                                "{label} ✕"
+                                            }
+                                        }
+                                        if export_running {
+                                            button {
+                                                class: "results__toolbar-chip results__cancel-load",
+                                                title: "Cancel the export and remove the partly written file",
+                                                onclick: move |_| cancel_tab_export(tabs, active_tab_id()),
+                                                "Exporting… ✕"
+                                            }
+                                        }
                                         IconButton {
                                             icon: ActionIcon::Previous,
                                             label: "Previous page".to_string(),
@@ -315,6 +533,38 @@ pub fn ResultTable(
                                                 disabled: read_only_mode,
                                                 onclick: move |_| insert_empty_row(tabs, active_tab_id),
                                             }
+                                            IconButton {
+                                                icon: ActionIcon::InsertRowForm,
+                                                label: if read_only_mode {
+                                                    "Insert row is blocked by read-only mode".to_string()
+                                                } else {
+                                                    "Insert row…".to_string()
+                                                },
+                                                small: true,
+                                                disabled: read_only_mode,
+                                                onclick: {
+                                                    let source = page
+                                                        .editable
+                                                        .as_ref()
+                                                        .map(|editable| editable.source.clone());
+                                                    let session_id = active_tab
+                                                        .as_ref()
+                                                        .map(|tab| tab.session_id);
+                                                    move |_| {
+                                                        let (Some(source), Some(session_id)) =
+                                                            (source.clone(), session_id)
+                                                        else {
+                                                            return;
+                                                        };
+                                                        insert_row_target.set(Some(InsertRowTarget {
+                                                            tab_id: active_tab_id(),
+                                                            session_id,
+                                                            source,
+                                                            prefill: None,
+                                                        }));
+                                                    }
+                                                },
+                                            }
                                             IconButton {
                                                 icon: ActionIcon::Apply,
                                                 label: if read_only_mode {
@@ -326,6 +576,13 @@ pub fn ResultTable(
                                                 disabled: !has_pending_changes || read_only_mode,
                                                 onclick: move |_| apply_pending_changes(tabs, active_tab_id),
                                             }
+                                            IconButton {
+                                                icon: ActionIcon::UndoStep,
+                                                label: "Undo last edit (Ctrl+Z)".to_string(),
+                                                small: true,
+                                                disabled: !can_undo_pending_change,
+                                                onclick: move |_| undo_pending_change(tabs, active_tab_id),
+                                            }
                                             IconButton {
                                                 icon: ActionIcon::Undo,
                                                 label: "Discard pending changes".to_string(),
@@ -499,6 +756,180 @@ pub fn ResultTable(
 
                                     div {
                                         class: "results__table-wrap",
+                                        id: RESULTS_TABLE_WRAP_ID,
+                                        tabindex: "0",
+                                        onmouseup: move |_| drag_anchor.set(None),
+                                        onkeydown: {
+                                            let columns = page.columns.clone();
+                                            let paste_source = page
+                                                .editable
+                                                .as_ref()
+                                                .map(|editable| editable.source.clone())
+                                                .zip(active_tab.as_ref().map(|tab| tab.session_id));
+                                            move |event: KeyboardEvent| {
+                                                if editing_cell.read().is_some() || structured_edit.read().is_some() {
+                                                    return;
+                                                }
+
+                                                let key = event.key();
+                                                let modifiers = event.modifiers();
+                                                let is_copy_key = matches!(
+                                                    &key,
+                                                    Key::Character(text) if text.eq_ignore_ascii_case("c")
+                                                );
+                                                if is_copy_key && (modifiers.ctrl() || modifiers.meta()) {
+                                                    let row_indices =
+                                                        selected_row_indices(selected_row_index(), &selected_rows.read());
+                                                    let rows = display_rows_cache.read();
+                                                    let shown = client_filter_matches(&rows, &client_filter.read());
+                                                    let copied = grid_copy_text(
+                                                        &columns,
+                                                        &rows,
+                                                        &shown,
+                                                        cell_range(),
+                                                        &row_indices,
+                                                        selected_row_index().zip(selected_col_index()),
+                                                        modifiers.shift(),
+                                                    );
+                                                    if let Some((text, description)) = copied {
+                                                        event.prevent_default();
+                                                        copy_grid_text(tabs, active_tab_id(), text, description);
+                                                    }
+                                                    return;
+                                                }
+                                                let is_paste_key = matches!(
+                                                    &key,
+                                                    Key::Character(text) if text.eq_ignore_ascii_case("v")
+                                                );
+                                                if is_paste_key && (modifiers.ctrl() || modifiers.meta()) {
+                                                    let Some((source, session_id)) = paste_source.clone() else {
+                                                        return;
+                                                    };
+                                                    event.prevent_default();
+                                                    if read_only_mode_enabled() {
+                                                        set_active_tab_status(
+                                                            tabs,
+                                                            active_tab_id(),
+                                                            read_only_mode_block_status("row paste"),
+                                                        );
+                                                        return;
+                                                    }
+                                                    open_paste_rows_dialog(
+                                                        tabs,
+                                                        active_tab_id(),
+                                                        session_id,
+                                                        source,
+                                                        paste_rows_target,
+                                                    );
+                                                    return;
+                                                }
+                                                let is_undo_key = matches!(
+                                                    &key,
+                                                    Key::Character(text) if text.eq_ignore_ascii_case("z")
+                                                );
+                                                if is_undo_key
+                                                    && (modifiers.ctrl() || modifiers.meta())
+                                                    && !modifiers.shift()
+                                                {
+                                                    event.prevent_default();
+                                                    undo_pending_change(tabs, active_tab_id);
+                                                    return;
+                                                }
+                                                if key == Key::Delete {
+                                                    let row_indices =
+                                                        selected_row_indices(selected_row_index(), &selected_rows.read());
+                                                    if row_indices.is_empty() {
+                                                        return;
+                                                    }
+                                                    event.prevent_default();
+                                                    if let Some(target) =
+                                                        delete_rows_target_for_selection(tabs, active_tab_id(), &row_indices)
+                                                    {
+                                                        delete_rows_target.set(Some(target));
+                                                    }
+                                                    return;
+                                                }
+                                                if key == Key::Enter {
+                                                    let (Some(row_index), Some(col_index)) =
+                                                        (selected_row_index(), selected_col_index())
+                                                    else {
+                                                        return;
+                                                    };
+                                                    if !table_cells_editable {
+                                                        return;
+                                                    }
+                                                    let rows = display_rows_cache.read();
+                                                    if let Some(row) = rows.get(row_index)
+                                                        && let Some(value) = row.values.get(col_index)
+                                                    {
+                                                        event.prevent_default();
+                                                        begin_cell_edit(
+                                                            editing_cell,
+                                                            structured_edit,
+                                                            column_types,
+                                                            columns.get(col_index),
+                                                            EditingCell {
+                                                                row_ref: row.row_ref.clone(),
+                                                                col_index,
+                                                                value: value.clone(),
+                                                            },
+                                                        );
+                                                    }
+                                                    return;
+                                                }
+
+                                                let Some(movement) =
+                                                    grid_move_for_key(&key, event.modifiers().shift())
+                                                else {
+                                                    return;
+                                                };
+
+                                                let rows = display_rows_cache.read();
+                                                let shown = client_filter_matches(&rows, &client_filter.read());
+                                                let order = grid_column_order(
+                                                    &columns,
+                                                    pinned_columns
+                                                        .read()
+                                                        .get(&active_tab_id())
+                                                        .map(Vec::as_slice)
+                                                        .unwrap_or_default(),
+                                                )
+                                                .into_iter()
+                                                .map(|(col_index, _)| col_index)
+                                                .collect::<Vec<_>>();
+                                                let current_col = selected_col_index()
+                                                    .and_then(|col| order.iter().position(|ordered| *ordered == col))
+                                                    .unwrap_or(0);
+                                                let current = selected_row_index()
+                                                    .and_then(|row| shown.iter().position(|shown_row| *shown_row == row))
+                                                    .map(|position| (position, current_col));
+                                                let Some((position, col_position)) =
+                                                    next_grid_cell(current, movement, shown.len(), order.len())
+                                                else {
+                                                    return;
+                                                };
+                                                event.prevent_default();
+                                                let row_index = shown[position];
+                                                let col_index = order[col_position];
+
+                                                selected_row_index.set(Some(row_index));
+                                                selected_rows.set(HashSet::new());
+                                                selected_col_index.set(Some(col_index));
+                                                cell_range.set(None);
+                                                if let Some(row) = rows.get(row_index) {
+                                                    editing_row_values.set(
+                                                        row.values.iter().cloned().enumerate().collect(),
+                                                    );
+                                                    editing_row_ref.set(Some(row.row_ref.clone()));
+                                                }
+                                                let _ = document::eval(&scroll_grid_cell_into_view_script(
+                                                    row_index,
+                                                    position,
+                                                    col_index,
+                                                    virtual_row_height,
+                                                ));
+                                            }
+                                        },
                                         onscroll: move |event| {
                                             let scroll_state = event.data();
                                             scroll_offset.set(scroll_state.scroll_top());
@@ -527,9 +958,32 @@ pub fn ResultTable(
                                             class: "results__table",
                                             thead {
                                                 tr {
-                                                    for column in page.columns.iter().cloned() {
+                                                    for (col_index, column, pin) in column_order
+                                                        .iter()
+                                                        .map(|(col_index, pin)| (*col_index, page.columns[*col_index].clone(), *pin))
+                                                    {
                                                         th {
-                                                            class: "results__head",
+                                                            class: "results__head{pinned_column_class(pin, pinned_count)}",
+                                                            style: pinned_column_style(pin),
+                                                            oncontextmenu: {
+                                                                let column_name = column.clone();
+                                                                move |event: MouseEvent| {
+                                                                    event.prevent_default();
+                                                                    let point = event.client_coordinates();
+                                                                    let values = display_rows_cache
+                                                                        .read()
+                                                                        .iter()
+                                                                        .filter_map(|row| row.values.get(col_index).cloned())
+                                                                        .collect();
+                                                                    column_stats_anchor.set(Some(ColumnStatsAnchor {
+                                                                        column: column_name.clone(),
+                                                                        values,
+                                                                        x: point.x,
+                                                                        y: point.y,
+                                                                        state: ColumnStatsState::Menu,
+                                                                    }));
+                                                                }
+                                                            },
                                                             if sort_enabled {
                                                                 button {
                                                                     class: sort_button_class(active_sort.as_ref(), &column),
@@ -567,13 +1021,95 @@ pub fn ResultTable(
                                                     }
                                                 }
 
-                                                for visible_idx in virtual_first..virtual_last {
-                                                    if let Some(row) = display_rows.get(visible_idx) {
+                                                for position in virtual_first..virtual_last {
+                                                    if let Some((visible_idx, row)) = shown_rows
+                                                        .get(position)
+                                                        .and_then(|index| display_rows.get(*index).map(|row| (*index, row)))
+                                                    {
                                                         tr {
-                                                            class: row_class(selected_row_index() == Some(visible_idx), row),
+                                                            class: row_class(
+                                                                is_row_selected(selected_row_index(), &selected_rows.read(), visible_idx),
+                                                                row,
+                                                            ),
                                                             key: "{display_row_key(row)}",
-                                                            onclick: move |_| {
+                                                            "data-row-index": "{visible_idx}",
+                                                            oncontextmenu: {
+                                                                let columns = page.columns.clone();
+                                                                move |event: MouseEvent| {
+                                                                    event.prevent_default();
+                                                                    let mut row_indices =
+                                                                        selected_row_indices(selected_row_index(), &selected_rows.read());
+                                                                    if !row_indices.contains(&visible_idx) {
+                                                                        selected_row_index.set(Some(visible_idx));
+                                                                        selected_rows.set(HashSet::new());
+                                                                        row_indices = vec![visible_idx];
+                                                                    }
+                                                                    let rows = {
+                                                                        let display_rows = display_rows_cache.read();
+                                                                        row_indices
+                                                                            .iter()
+                                                                            .filter_map(|row_index| display_rows.get(*row_index))
+                                                                            .map(|row| row.values.clone())
+                                                                            .collect()
+                                                                    };
+                                                                    // Checked here so the delete helper does not
+                                                                    // report why it is unavailable on every right-click.
+                                                                    let target = (table_cells_editable
+                                                                        && !read_only_mode_enabled()
+                                                                        && !has_pending_changes)
+                                                                        .then(|| {
+                                                                            delete_rows_target_for_selection(
+                                                                                tabs,
+                                                                                active_tab_id(),
+                                                                                &row_indices,
+                                                                            )
+                                                                        })
+                                                                        .flatten();
+                                                                    let point = event.client_coordinates();
+                                                                    row_menu_anchor.set(Some(RowMenuAnchor {
+                                                                        x: point.x,
+                                                                        y: point.y,
+                                                                        tab_id: active_tab_id(),
+                                                                        columns: columns.clone(),
+                                                                        rows,
+                                                                        target,
+                                                                    }));
+                                                                }
+                                                            },
+                                                            onclick: move |event: MouseEvent| {
+                                                                let modifiers = event.modifiers();
+                                                                if modifiers.shift() {
+                                                                    let anchor = selected_row_index().unwrap_or(visible_idx);
+                                                                    let shown = client_filter_matches(
+                                                                        &display_rows_cache.read(),
+                                                                        &client_filter.read(),
+                                                                    );
+                                                                    selected_rows.set(
+                                                                        row_range(anchor, visible_idx)
+                                                                            .into_iter()
+                                                                            .filter(|row| shown.binary_search(row).is_ok())
+                                                                            .collect(),
+                                                                    );
+                                                                    return;
+                                                                }
+                                                                if modifiers.ctrl() || modifiers.meta() {
+                                                                    let mut rows = selected_row_indices(
+                                                                        selected_row_index(),
+                                                                        &selected_rows.read(),
+                                                                    )
+                                                                    .into_iter()
+                                                                    .collect::<HashSet<_>>();
+                                                                    if !rows.remove(&visible_idx) {
+                                                                        rows.insert(visible_idx);
+                                                                    }
+                                                                    if rows.is_empty() {
+                                                                        selected_row_index.set(None);
+                                                                    }
+                                                                    selected_rows.set(rows);
+                                                                    return;
+                                                                }
                                                                 selected_row_index.set(Some(visible_idx));
+                                                                selected_rows.set(HashSet::new());
                                                                 show_row_details.set(true);
                                                                 let rows = display_rows_cache.read();
                                                                 if let Some(r) = rows.get(visible_idx) {
@@ -585,25 +1121,95 @@ pub fn ResultTable(
                                                                     editing_row_ref.set(Some(r.row_ref.clone()));
                                                                 }
                                                             },
-                                                            for (col_index, cell) in row.values.iter().enumerate() {
+                                                            for (col_index, cell, pin, highlight) in column_order
+                                                                .iter()
+                                                                .filter_map(|(col_index, pin)| {
+                                                                    let cell = row.values.get(*col_index)?;
+                                                                    let highlight = page.columns.get(*col_index).and_then(|column| {
+                                                                        cell_highlight(&tab_formatting_rules, column, cell)
+                                                                    });
+                                                                    Some((*col_index, cell, *pin, highlight))
+                                                                })
+                                                            {
                                                                 td {
-                                                                    class: cell_class(
-                                                                        table_cells_editable,
-                                                                        row,
-                                                                        page.columns.get(col_index),
-                                                                        &updated_cells_set,
+                                                                    class: format!(
+                                                                        "{}{}{}",
+                                                                        grid_cell_class(
+                                                                            cell_class(
+                                                                                table_cells_editable,
+                                                                                row,
+                                                                                page.columns.get(col_index),
+                                                                                &updated_cells_set,
+                                                                            ),
+                                                                            selected_row_index() == Some(visible_idx)
+                                                                                && selected_col_index() == Some(col_index),
+                                                                            cell_range().is_some_and(|range| range.contains(visible_idx, col_index)),
+                                                                        ),
+                                                                        pinned_column_class(pin, pinned_count),
+                                                                        highlight.map_or("", |_| " results__cell--highlighted"),
+                                                                    ),
+                                                                    style: format!(
+                                                                        "{}{}",
+                                                                        pinned_column_style(pin),
+                                                                        highlight
+                                                                            .map(|color| format!("--cell-highlight: {color};"))
+                                                                            .unwrap_or_default(),
                                                                     ),
+                                                                    "data-col-index": "{col_index}",
+                                                                    onmousedown: move |event: MouseEvent| {
+                                                                        if event.trigger_button() == Some(MouseButton::Primary)
+                                                                            && !event.modifiers().shift()
+                                                                        {
+                                                                            drag_anchor.set(Some((visible_idx, col_index)));
+                                                                            cell_range.set(None);
+                                                                        }
+                                                                    },
+                                                                    onmouseenter: move |event: MouseEvent| {
+                                                                        let Some(anchor) = drag_anchor() else {
+                                                                            return;
+                                                                        };
+                                                                        if !event.held_buttons().contains(MouseButton::Primary) {
+                                                                            drag_anchor.set(None);
+                                                                            return;
+                                                                        }
+                                                                        cell_range.set(Some(CellRange {
+                                                                            anchor,
+                                                                            end: (visible_idx, col_index),
+                                                                        }));
+                                                                    },
+                                                                    onclick: move |event: MouseEvent| {
+                                                                        if event.modifiers().shift() {
+                                                                            // The focused cell stays put as the range's anchor.
+                                                                            let anchor = cell_range()
+                                                                                .map(|range| range.anchor)
+                                                                                .or_else(|| selected_row_index().zip(selected_col_index()))
+                                                                                .unwrap_or((visible_idx, col_index));
+                                                                            cell_range.set(Some(CellRange {
+                                                                                anchor,
+                                                                                end: (visible_idx, col_index),
+                                                                            }));
+                                                                            return;
+                                                                        }
+                                                                        selected_col_index.set(Some(col_index));
+                                                                    },
                                                                     ondoubleclick: {
                                                                         let cell_value = cell.clone();
                                                                         let editable = table_cells_editable;
                                                                         let row_ref = row.row_ref.clone();
+                                                                        let column_name = page.columns.get(col_index).cloned();
                                                                         move |_| {
                                                                             if editable {
-                                                                                editing_cell.set(Some(EditingCell {
-                                                                                    row_ref: row_ref.clone(),
-                                                                                    col_index,
-                                                                                    value: cell_value.clone(),
-                                                                                }));
+                                                                                begin_cell_edit(
+                                                                                    editing_cell,
+                                                                                    structured_edit,
+                                                                                    column_types,
+                                                                                    column_name.as_ref(),
+                                                                                    EditingCell {
+                                                                                        row_ref: row_ref.clone(),
+                                                                                        col_index,
+                                                                                        value: cell_value.clone(),
+                                                                                    },
+                                                                                );
                                                                             }
                                                                         }
                                                                     },
@@ -611,6 +1217,7 @@ pub fn ResultTable(
                                                                         if current_edit.row_ref == row.row_ref && current_edit.col_index == col_index {
                                                                             input {
                                                                                 class: "results__cell-input",
+                                                                                autofocus: true,
                                                                                 value: "{current_edit.value}",
                                                                                 oninput: move |event| {
                                                                                     let value = event.value();
@@ -622,6 +1229,8 @@ pub fn ResultTable(
                                                                                 },
                                                                                 onkeydown: move |event| {
                                                                                     if event.key() == Key::Enter {
+                                                                                        // Keep the grid from reopening the editor on the same keypress.
+                                                                                        event.stop_propagation();
                                                                                         if let Some(editing) = editing_cell() {
                                                                                             commit_cell_edit(
                                                                                                 editing_cell,
@@ -630,8 +1239,11 @@ pub fn ResultTable(
                                                                                                 editing,
                                                                                             );
                                                                                         }
+                                                                                        let _ = document::eval(FOCUS_RESULTS_TABLE_SCRIPT);
                                                                                     } else if event.key() == Key::Escape {
+                                                                                        event.stop_propagation();
                                                                                         editing_cell.set(None);
+                                                                                        let _ = document::eval(FOCUS_RESULTS_TABLE_SCRIPT);
                                                                                     }
                                                                                 },
                                                                                 onblur: move |_| {
@@ -646,17 +1258,19 @@ pub fn ResultTable(
                                                                                 }
                                                                             }
                                                                         } else {
-                                                                            div {
-                                                                                class: "results__cell-content",
-                                                                                title: "{cell}",
-                                                                                "{cell}"
+                                                                            ResultCellContent {
+                                                                                value: cell.clone(),
+                                                                                column_name: page.columns.get(col_index).cloned().unwrap_or_default(),
+                                                                                json: page.columns.get(col_index).is_some_and(|column| json_columns.contains(column)),
+                                                                                json_viewer,
                                                                             }
                                                                         }
                                                                     } else {
-                                                                        div {
-                                                                            class: "results__cell-content",
-                                                                            title: "{cell}",
-                                                                            "{cell}"
+                                                                        ResultCellContent {
+                                                                            value: cell.clone(),
+                                                                            column_name: page.columns.get(col_index).cloned().unwrap_or_default(),
+                                                                            json: page.columns.get(col_index).is_some_and(|column| json_columns.contains(column)),
+                                                                            json_viewer,
                                                                         }
                                                                     }
                                                                     }
@@ -687,6 +1301,20 @@ pub fn ResultTable(
                                         }
                                     }
 
+                                    if !selected_indices.is_empty() {
+                                        div {
+                                            class: "results__aggregates",
+                                            span { "Count: {selected_indices.len()}" }
+                                            for (index, aggregate) in aggregates.iter().enumerate() {
+                                                span {
+                                                    key: "{index}",
+                                                    strong { "{aggregate.column}" }
+                                                    " Sum: {format_aggregate(aggregate.sum)} · Avg: {format_aggregate(aggregate.avg)} · Min: {format_aggregate(aggregate.min)} · Max: {format_aggregate(aggregate.max)}"
+                                                }
+                                            }
+                                        }
+                                    }
+
                                     if details_visible {
                                     aside {
                                         class: if details_resize_active() {
@@ -829,6 +1457,68 @@ pub fn ResultTable(
                                 rows: page.rows.clone(),
                                 visible: show_chart,
                             }
+                            ColumnStatsPopup {
+                                anchor: column_stats_anchor,
+                                tabs,
+                                active_tab_id,
+                                pinned_columns,
+                            }
+                            InsertRowDialog { tabs, target: insert_row_target }
+                            RowContextMenu {
+                                tabs,
+                                anchor: row_menu_anchor,
+                                delete_target: delete_rows_target,
+                                insert_target: insert_row_target,
+                            }
+                            DeleteRowsDialog { tabs, target: delete_rows_target }
+                            PasteRowsDialog { tabs, target: paste_rows_target }
+                            FormattingRulesDialog {
+                                columns: page.columns.clone(),
+                                rules: formatting_rules,
+                                target: formatting_rules_target,
+                            }
+                            if let Some(target) = table_ddl() {
+                                TableDdlModal {
+                                    target,
+                                    tabs,
+                                    active_tab_id,
+                                    next_tab_id,
+                                    table_ddl,
+                                }
+                            }
+                            if let Some(target) = json_viewer() {
+                                JsonViewerDialog {
+                                    target,
+                                    on_close: move |_| {
+                                        json_viewer.set(None);
+                                        let _ = document::eval(FOCUS_RESULTS_TABLE_SCRIPT);
+                                    },
+                                }
+                            }
+                            if let Some((_, StructuredCellEdit::Array(target))) = structured_edit() {
+                                ArrayEditDialog {
+                                    target,
+                                    on_save: save_structured_edit,
+                                    on_cancel: cancel_structured_edit,
+                                }
+                            }
+                            if let Some((_, StructuredCellEdit::Hstore(target))) = structured_edit() {
+                                HstoreEditDialog {
+                                    target,
+                                    on_save: save_structured_edit,
+                                    on_cancel: cancel_structured_edit,
+                                }
+                            }
+                            if let Some((_, StructuredCellEdit::Bytea(target))) = structured_edit() {
+                                if let Some((session_id, source)) = column_type_source.clone() {
+                                    ByteaViewDialog {
+                                        target,
+                                        session_id,
+                                        source,
+                                        on_close: cancel_structured_edit,
+                                    }
+                                }
+                            }
                         }
                     }
                 }
@@ -843,6 +1533,15 @@ pub fn ResultTable(
                             pre { class: "results__error-body", "{error}" }
                         }
                     }
+                } else if let Some(label) = running_load_label {
+                    p { class: "empty-state",
+                        "{label} "
+                        button {
+                            class: "results__toolbar-chip results__cancel-load",
+                            onclick: move |_| cancel_tab_load(tabs, active_tab_id()),
+                            "Cancel ✕"
+                        }
+                    }
                 } else {
                     p { class: "empty-state", "Double-click a table in Explorer or run SQL to see rows here." }
                 }
@@ -851,6 +1550,16 @@ pub fn ResultTable(
     }
 }
 
+fn running_load_text(tab: &QueryTabState) -> &'static str {
+    if tab.is_loading_more {
+        "Loading more rows…"
+    } else if tab.preview_source.is_some() {
+        "Loading table…"
+    } else {
+        "Running query…"
+    }
+}
+
 fn result_error_message(status: &str) -> Option<String> {
     [
         "Error: ",
@@ -890,16 +1599,269 @@ pub fn format_row_edit_error(operation: &str, err: impl std::fmt::Display) -> St
     format!("{operation} error: {err}")
 }
 
+/// Rows the next delete applies to: the Ctrl/Shift-click selection when there
+/// is one, otherwise the focused row. Sorted top to bottom.
+/// Reads the clipboard and opens the paste dialog when it holds a block of
+/// cells copied from a spreadsheet.
+fn open_paste_rows_dialog(
+    tabs: Signal<Vec<QueryTabState>>,
+    tab_id: u64,
+    session_id: u64,
+    source: TablePreviewSource,
+    mut target: Signal<Option<PasteRowsTarget>>,
+) {
+    spawn(async move {
+        let text = match read_text().await {
+            Ok(text) => text,
+            Err(err) => {
+                set_active_tab_status(tabs, tab_id, err);
+                return;
+            }
+        };
+        let Some(rows) = parse_tsv_block(&text) else {
+            set_active_tab_status(
+                tabs,
+                tab_id,
+                "Clipboard holds no rows to paste; copy a block of cells first".to_string(),
+            );
+            return;
+        };
+        target.set(Some(PasteRowsTarget {
+            tab_id,
+            session_id,
+            source,
+            rows,
+        }));
+    });
+}
+
+fn selected_row_indices(focused: Option<usize>, selected_rows: &HashSet<usize>) -> Vec<usize> {
+    if selected_rows.is_empty() {
+        return focused.into_iter().collect();
+    }
+    let mut rows = selected_rows.iter().copied().collect::<Vec<_>>();
+    rows.sort_unstable();
+    rows
+}
+
+fn is_row_selected(focused: Option<usize>, selected_rows: &HashSet<usize>, row: usize) -> bool {
+    if selected_rows.is_empty() {
+        focused == Some(row)
+    } else {
+        selected_rows.contains(&row)
+    }
+}
+
+/// Sum, average and extremes of one column's numbers among the selected rows.
+#[derive(Clone, Debug, PartialEq)]
+struct ColumnAggregate {
+    column: String,
+    sum: f64,
+    avg: f64,
+    min: f64,
+    max: f64,
+}
+
+/// Aggregates each column whose non-NULL cells in `row_indices` all hold
+/// numbers, the way a spreadsheet's status bar sums a selection.
+fn selection_aggregates(
+    columns: &[String],
+    rows: &[DisplayRow],
+    row_indices: &[usize],
+) -> Vec<ColumnAggregate> {
+    columns
+        .iter()
+        .enumerate()
+        .filter_map(|(col_index, column)| {
+            let mut numbers = Vec::new();
+            for row_index in row_indices {
+                let value = rows.get(*row_index)?.values.get(col_index)?.trim();
+                if value.is_empty() || value == "NULL" {
+                    continue;
+                }
+                numbers.push(
+                    value
+                        .parse::<f64>()
+                        .ok()
+                        .filter(|number| number.is_finite())?,
+                );
+            }
+            if numbers.is_empty() {
+                return None;
+            }
+            let sum = numbers.iter().sum::<f64>();
+            Some(ColumnAggregate {
+                column: column.clone(),
+                sum,
+                avg: sum / numbers.len() as f64,
+                min: numbers.iter().copied().fold(f64::INFINITY, f64::min),
+                max: numbers.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+            })
+        })
+        .collect()
+}
+
+/// Shows an aggregate with at most four decimals and no trailing zeros.
+fn format_aggregate(value: f64) -> String {
+    let text = format!("{value:.4}");
+    let text = text.trim_end_matches('0').trim_end_matches('.');
+    if text == "-0" { "0" } else { text }.to_string()
+}
+
+/// Indices of the rows with a cell containing `filter`, ignoring case; every
+/// row while the filter is blank.
+fn client_filter_matches(rows: &[DisplayRow], filter: &str) -> Vec<usize> {
+    let needle = filter.trim().to_lowercase();
+    rows.iter()
+        .enumerate()
+        .filter(|(_, row)| {
+            needle.is_empty()
+                || row
+                    .values
+                    .iter()
+                    .any(|value| value.to_lowercase().contains(&needle))
+        })
+        .map(|(index, _)| index)
+        .collect()
+}
+
+fn row_range(anchor: usize, row: usize) -> HashSet<usize> {
+    (anchor.min(row)..=anchor.max(row)).collect()
+}
+
+/// Text Ctrl+C copies, with a status description: the cell block when one is
+/// picked, else the selected rows, else the focused cell as is. `with_header`
+/// (Ctrl+Shift+C) leads with the column names. Rows outside `shown_rows` are
+/// left out of a cell block.
+fn grid_copy_text(
+    columns: &[String],
+    rows: &[DisplayRow],
+    shown_rows: &[usize],
+    range: Option<CellRange>,
+    selected_rows: &[usize],
+    focused: Option<(usize, usize)>,
+    with_header: bool,
+) -> Option<(String, String)> {
+    if let Some(range) = range {
+        let header = range
+            .columns()
+            .filter_map(|column| columns.get(column).cloned())
+            .collect::<Vec<_>>();
+        let block = range
+            .rows()
+            .filter(|row| shown_rows.binary_search(row).is_ok())
+            .filter_map(|row| rows.get(row))
+            .map(|row| {
+                range
+                    .columns()
+                    .filter_map(|column| row.values.get(column).cloned())
+                    .collect()
+            })
+            .collect::<Vec<_>>();
+        let description = format!("{}×{} cells", block.len(), header.len());
+        return Some((
+            tsv_block(with_header.then_some(header.as_slice()), &block),
+            description,
+        ));
+    }
+
+    let (row, column) = match (selected_rows, focused) {
+        ([_, _, ..], _) | (_, None) => {
+            let block = selected_rows
+                .iter()
+                .filter_map(|row| rows.get(*row))
+                .map(|row| row.values.clone())
+                .collect::<Vec<_>>();
+            if block.is_empty() {
+                return None;
+            }
+            let description = match block.len() {
+                1 => "1 row".to_string(),
+                count => format!("{count} rows"),
+            };
+            return Some((
+                tsv_block(with_header.then_some(columns), &block),
+                description,
+            ));
+        }
+        (_, Some(focused)) => focused,
+    };
+    let value = rows.get(row)?.values.get(column)?.clone();
+    let text = if with_header {
+        tsv_block(
+            columns.get(column).map(std::slice::from_ref),
+            &[vec![value]],
+        )
+    } else {
+        value
+    };
+    Some((text, "cell".to_string()))
+}
+
+/// Column indices in display order, pinned columns first in the order they
+/// were pinned, each with its place among the pinned columns.
+fn grid_column_order(columns: &[String], pinned: &[String]) -> Vec<(usize, Option<usize>)> {
+    let pinned_indices = pinned
+        .iter()
+        .filter_map(|name| columns.iter().position(|column| column == name))
+        .collect::<Vec<_>>();
+    pinned_indices
+        .iter()
+        .enumerate()
+        .map(|(pin, col_index)| (*col_index, Some(pin)))
+        .chain(
+            (0..columns.len())
+                .filter(|col_index| !pinned_indices.contains(col_index))
+                .map(|col_index| (col_index, None)),
+        )
+        .collect()
+}
+
+fn pinned_column_class(pin: Option<usize>, pinned_count: usize) -> &'static str {
+    match pin {
+        Some(pin) if pin + 1 == pinned_count => " results__cell--pinned results__cell--pinned-edge",
+        Some(_) => " results__cell--pinned",
+        None => "",
+    }
+}
+
+/// Pinned columns share one fixed width so each knows its sticky offset.
+fn pinned_column_style(pin: Option<usize>) -> String {
+    pin.map(|pin| {
+        format!(
+            "left: {}px; width: {PINNED_COLUMN_WIDTH}px; min-width: {PINNED_COLUMN_WIDTH}px; max-width: {PINNED_COLUMN_WIDTH}px;",
+            pin as f64 * PINNED_COLUMN_WIDTH
+        )
+    })
+    .unwrap_or_default()
+}
+
+fn grid_cell_class(base: &str, focused: bool, in_range: bool) -> String {
+    let mut class = base.to_string();
+    if in_range {
+        class.push_str(" results__cell--in-range");
+    }
+    if focused {
+        class.push_str(" results__cell--focused");
+    }
+    class
+}
+
 #[cfg(test)]
 #[allow(clippy::items_after_test_module)]
 mod tests {
     use super::{
-        filter_panel_should_auto_open, filter_panel_should_collapse_after_clear,
-        format_row_edit_error, result_error_message, result_status_text_for_display,
-        should_render_result_status_chip,
+        CellRange, ColumnAggregate, DisplayRow, EditableRowRef, GridMove, cell_tooltip,
+        client_filter_matches, filter_panel_should_auto_open,
+        filter_panel_should_collapse_after_clear, format_aggregate, format_row_edit_error,
+        grid_column_order, grid_copy_text, grid_move_for_key, is_row_selected, next_grid_cell,
+        result_error_message, result_status_text_for_display, row_range, selected_row_indices,
+        selection_aggregates, should_render_result_status_chip,
     };
     use crate::screens::workspace::actions::rows_toolbar_summary;
+    use dioxus::prelude::Key;
     use models::{QueryFilter, QueryFilterMode, QueryFilterOperator, QueryFilterRule};
+    use std::collections::HashSet;
 
     #[test]
     fn extracts_query_error_from_status() {
@@ -909,6 +1871,158 @@ mod tests {
         );
     }
 
+    #[test]
+    fn multi_row_selection_overrides_the_focused_row() {
+        let none = HashSet::new();
+        assert_eq!(selected_row_indices(Some(4), &none), vec![4]);
+        assert!(is_row_selected(Some(4), &none, 4));
+
+        let range = row_range(5, 2);
+        assert_eq!(selected_row_indices(Some(5), &range), vec![2, 3, 4, 5]);
+        assert!(!is_row_selected(Some(7), &range, 7));
+        assert!(is_row_selected(Some(7), &range, 3));
+    }
+
+    #[test]
+    fn copies_cell_block_rows_or_focused_cell() {
+        let columns = vec!["id".to_string(), "name".to_string(), "note".to_string()];
+        let rows = (1..=3)
+            .map(|id| DisplayRow {
+                row_ref: EditableRowRef::Existing(id.to_string()),
+                values: vec![id.to_string(), format!("n{id}"), format!("x{id}")],
+            })
+            .collect::<Vec<_>>();
+        let block = CellRange {
+            anchor: (2, 2),
+            end: (1, 1),
+        };
+
+        assert_eq!(
+            grid_copy_text(
+                &columns,
+                &rows,
+                &[0, 1, 2],
+                Some(block),
+                &[],
+                Some((2, 2)),
+                true
+            ),
+            Some((
+                "name\tnote\nn2\tx2\nn3\tx3".to_string(),
+                "2×2 cells".to_string()
+            ))
+        );
+        assert_eq!(
+            grid_copy_text(
+                &columns,
+                &rows,
+                &[0, 1, 2],
+                None,
+                &[0, 2],
+                Some((0, 1)),
+                false
+            ),
+            Some(("1\tn1\tx1\n3\tn3\tx3".to_string(), "2 rows".to_string()))
+        );
+        assert_eq!(
+            grid_copy_text(&columns, &rows, &[0, 1, 2], None, &[1], Some((1, 1)), false),
+            Some(("n2".to_string(), "cell".to_string()))
+        );
+        assert_eq!(
+            grid_copy_text(&columns, &rows, &[0, 1, 2], None, &[], None, false),
+            None
+        );
+        assert_eq!(
+            grid_copy_text(&columns, &rows, &[0, 2], Some(block), &[], None, false),
+            Some(("n3\tx3".to_string(), "1×2 cells".to_string()))
+        );
+    }
+
+    #[test]
+    fn pinned_columns_lead_in_pin_order() {
+        let columns = ["id", "name", "email", "note"].map(ToString::to_string);
+        let pinned = ["email", "missing", "id"].map(ToString::to_string);
+
+        assert_eq!(
+            grid_column_order(&columns, &pinned),
+            vec![(2, Some(0)), (0, Some(1)), (1, None), (3, None)]
+        );
+        assert_eq!(
+            grid_column_order(&columns[..2], &[]),
+            vec![(0, None), (1, None)]
+        );
+    }
+
+    #[test]
+    fn client_filter_matches_any_cell_ignoring_case() {
+        let rows = ["Alice", "bob", "ALBERT"]
+            .iter()
+            .enumerate()
+            .map(|(index, name)| DisplayRow {
+                row_ref: EditableRowRef::Existing(index.to_string()),
+                values: vec![index.to_string(), name.to_string()],
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(client_filter_matches(&rows, " al "), vec![0, 2]);
+        assert_eq!(client_filter_matches(&rows, "2"), vec![2]);
+        assert_eq!(client_filter_matches(&rows, ""), vec![0, 1, 2]);
+        assert!(client_filter_matches(&rows, "carol").is_empty());
+    }
+
+    #[test]
+    fn selection_aggregates_cover_numeric_columns_only() {
+        let rows = [
+            ("1", "apple", "10.5"),
+            ("2", "pear", "NULL"),
+            ("3", "fig", "-4"),
+        ]
+        .iter()
+        .map(|(id, name, amount)| DisplayRow {
+            row_ref: EditableRowRef::Existing(id.to_string()),
+            values: vec![id.to_string(), name.to_string(), amount.to_string()],
+        })
+        .collect::<Vec<_>>();
+        let columns = ["id", "name", "amount"].map(str::to_string);
+
+        let aggregates = selection_aggregates(&columns, &rows, &[0, 1, 2]);
+        assert_eq!(
+            aggregates,
+            vec![
+                ColumnAggregate {
+                    column: "id".to_string(),
+                    sum: 6.0,
+                    avg: 2.0,
+                    min: 1.0,
+                    max: 3.0,
+                },
+                ColumnAggregate {
+                    column: "amount".to_string(),
+                    sum: 6.5,
+                    avg: 3.25,
+                    min: -4.0,
+                    max: 10.5,
+                },
+            ]
+        );
+        assert_eq!(selection_aggregates(&columns, &rows, &[1]).len(), 1);
+        assert_eq!(format_aggregate(6.5), "6.5");
+        assert_eq!(format_aggregate(2.0), "2");
+        assert_eq!(format_aggregate(10.0 / 3.0), "3.3333");
+    }
+
+    #[test]
+    fn long_cell_tooltips_are_cut_at_200_characters() {
+        assert_eq!(cell_tooltip("short"), "short");
+        let exact = "é".repeat(200);
+        assert_eq!(cell_tooltip(&exact), exact);
+        let long = format!("{}tail", "é".repeat(200));
+        assert_eq!(
+            cell_tooltip(&long),
+            format!("{}…(click to expand)", "é".repeat(200))
+        );
+    }
+
     #[test]
     fn ignores_non_error_status() {
         assert_eq!(result_error_message("Loaded rows 1-10"), None);
@@ -1038,6 +2152,136 @@ mod tests {
         assert_eq!(formatted, "Row insert error: constraint violation");
         assert!(!formatted.contains(":?"));
     }
+
+    #[test]
+    fn maps_navigation_keys_to_grid_moves() {
+        assert_eq!(grid_move_for_key(&Key::ArrowUp, false), Some(GridMove::Up));
+        assert_eq!(grid_move_for_key(&Key::Tab, false), Some(GridMove::Next));
+        assert_eq!(grid_move_for_key(&Key::Tab, true), Some(GridMove::Previous));
+        assert_eq!(grid_move_for_key(&Key::Enter, false), None);
+    }
+
+    #[test]
+    fn arrow_moves_clamp_at_grid_edges() {
+        assert_eq!(next_grid_cell(None, GridMove::Down, 3, 2), Some((0, 0)));
+        assert_eq!(
+            next_grid_cell(Some((0, 0)), GridMove::Up, 3, 2),
+            Some((0, 0))
+        );
+        assert_eq!(
+            next_grid_cell(Some((2, 1)), GridMove::Down, 3, 2),
+            Some((2, 1))
+        );
+        assert_eq!(
+            next_grid_cell(Some((1, 1)), GridMove::Right, 3, 2),
+            Some((1, 1))
+        );
+        assert_eq!(
+            next_grid_cell(Some((1, 1)), GridMove::Left, 3, 2),
+            Some((1, 0))
+        );
+        assert_eq!(next_grid_cell(Some((0, 0)), GridMove::Down, 0, 2), None);
+    }
+
+    #[test]
+    fn tab_moves_wrap_across_rows_and_leave_at_the_ends() {
+        assert_eq!(
+            next_grid_cell(Some((0, 1)), GridMove::Next, 3, 2),
+            Some((1, 0))
+        );
+        assert_eq!(next_grid_cell(Some((2, 1)), GridMove::Next, 3, 2), None);
+        assert_eq!(
+            next_grid_cell(Some((1, 0)), GridMove::Previous, 3, 2),
+            Some((0, 1))
+        );
+        assert_eq!(next_grid_cell(Some((0, 0)), GridMove::Previous, 3, 2), None);
+        assert_eq!(next_grid_cell(None, GridMove::Next, 3, 2), Some((0, 0)));
+        assert_eq!(next_grid_cell(None, GridMove::Previous, 3, 2), None);
+    }
+}
+
+const RESULTS_TABLE_WRAP_ID: &str = "results-table-wrap";
+const PINNED_COLUMN_WIDTH: f64 = 160.0;
+const FOCUS_RESULTS_TABLE_SCRIPT: &str =
+    "document.getElementById('results-table-wrap')?.focus({ preventScroll: true });";
+const RESET_RESULTS_SCROLL_SCRIPT: &str =
+    "document.getElementById('results-table-wrap')?.scrollTo({ top: 0 });";
+
+/// Keyboard movement of the focused result cell.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum GridMove {
+    Up,
+    Down,
+    Left,
+    Right,
+    Next,
+    Previous,
+}
+
+fn grid_move_for_key(key: &Key, shift: bool) -> Option<GridMove> {
+    match key {
+        Key::ArrowUp => Some(GridMove::Up),
+        Key::ArrowDown => Some(GridMove::Down),
+        Key::ArrowLeft => Some(GridMove::Left),
+        Key::ArrowRight => Some(GridMove::Right),
+        Key::Tab if shift => Some(GridMove::Previous),
+        Key::Tab => Some(GridMove::Next),
+        _ => None,
+    }
+}
+
+/// Target cell for a keyboard move; arrows clamp at the grid edges while
+/// Tab and Shift+Tab wrap across row boundaries. Tab past the last cell and
+/// Shift+Tab before the first have no target, so focus leaves the grid.
+fn next_grid_cell(
+    current: Option<(usize, usize)>,
+    movement: GridMove,
+    row_count: usize,
+    column_count: usize,
+) -> Option<(usize, usize)> {
+    if row_count == 0 || column_count == 0 {
+        return None;
+    }
+    let Some((row, col)) = current else {
+        return (movement != GridMove::Previous).then_some((0, 0));
+    };
+    let row = row.min(row_count - 1);
+    let col = col.min(column_count - 1);
+
+    let next = match movement {
+        GridMove::Up => (row.saturating_sub(1), col),
+        GridMove::Down => ((row + 1).min(row_count - 1), col),
+        GridMove::Left => (row, col.saturating_sub(1)),
+        GridMove::Right => (row, (col + 1).min(column_count - 1)),
+        GridMove::Next if col + 1 < column_count => (row, col + 1),
+        GridMove::Next if row + 1 < row_count => (row + 1, 0),
+        GridMove::Previous if col > 0 => (row, col - 1),
+        GridMove::Previous if row > 0 => (row - 1, column_count - 1),
+        GridMove::Next | GridMove::Previous => return None,
+    };
+    Some(next)
+}
+
+/// `position` is the row's place among the rows shown, which differs from
+/// `row_index` while the quick filter hides rows.
+fn scroll_grid_cell_into_view_script(
+    row_index: usize,
+    position: usize,
+    col_index: usize,
+    row_height: f64,
+) -> String {
+    format!(
+        r#"(() => {{
+            const wrap = document.getElementById("{RESULTS_TABLE_WRAP_ID}");
+            if (!wrap) return;
+            const row = wrap.querySelector('tr[data-row-index="{row_index}"]');
+            if (!row) {{
+                wrap.scrollTop = {position} * {row_height};
+                return;
+            }}
+            (row.querySelector('td[data-col-index="{col_index}"]') ?? row).scrollIntoView({{ block: "nearest", inline: "nearest" }});
+        }})();"#
+    )
 }
 
 fn can_sort_tab(tab: &QueryTabState) -> bool {
@@ -1508,6 +2752,123 @@ fn original_cell_value(
     page.rows.get(row_index)?.get(col_index).cloned()
 }
 
+/// Opens the editor for a cell: the element list for PostgreSQL array columns,
+/// the inline input otherwise.
+/// Cell text, or a "View JSON" button for non-NULL cells of a JSON column.
+/// Hovering shows the value; a long one is cut short there and opens in
+/// full on click.
+#[component]
+fn ResultCellContent(
+    value: String,
+    column_name: String,
+    json: bool,
+    mut json_viewer: Signal<Option<JsonViewerTarget>>,
+) -> Element {
+    let tooltip = cell_tooltip(&value);
+    let mut open_viewer = {
+        let value = value.clone();
+        move |json: bool| {
+            json_viewer.set(Some(JsonViewerTarget {
+                column_name: column_name.clone(),
+                value: value.clone(),
+                json,
+            }));
+        }
+    };
+
+    if !json || value == "NULL" {
+        let long = value.chars().nth(CELL_TOOLTIP_MAX_CHARS).is_some();
+        return rsx! {
+            div {
+                class: "results__cell-content",
+                title: "{tooltip}",
+                onclick: move |event: MouseEvent| {
+                    if long && !event.modifiers().shift() {
+                        open_viewer(false);
+                    }
+                },
+                "{value}"
+            }
+        };
+    }
+
+    rsx! {
+        button {
+            class: "results__json-button",
+            title: "{tooltip}",
+            onclick: move |event: MouseEvent| {
+                event.stop_propagation();
+                open_viewer(true);
+            },
+            ondoubleclick: move |event| event.stop_propagation(),
+            "View JSON"
+        }
+    }
+}
+
+/// Longest value a cell's hover tooltip shows in full.
+const CELL_TOOLTIP_MAX_CHARS: usize = 200;
+
+/// Hover text for a cell: its value, or the start of a long one and a hint
+/// that clicking shows the rest.
+fn cell_tooltip(value: &str) -> String {
+    match value.char_indices().nth(CELL_TOOLTIP_MAX_CHARS) {
+        Some((end, _)) => format!("{}…(click to expand)", &value[..end]),
+        None => value.to_string(),
+    }
+}
+
+fn begin_cell_edit(
+    mut editing_cell: Signal<Option<EditingCell>>,
+    mut structured_edit: Signal<Option<(EditingCell, StructuredCellEdit)>>,
+    column_types: Signal<HashMap<String, String>>,
+    column_name: Option<&String>,
+    edit: EditingCell,
+) {
+    let data_type =
+        column_name.and_then(|column_name| column_types.read().get(column_name).cloned());
+    let target = match (column_name, data_type) {
+        (Some(column_name), Some(data_type))
+            if services::is_postgres_array_type(&data_type)
+                && array_editor_supports(&edit.value) =>
+        {
+            StructuredCellEdit::Array(ArrayEditTarget {
+                column_name: column_name.clone(),
+                data_type,
+                value: edit.value.clone(),
+            })
+        }
+        (Some(column_name), Some(data_type))
+            if services::is_postgres_hstore_type(&data_type)
+                && hstore_editor_supports(&edit.value) =>
+        {
+            StructuredCellEdit::Hstore(HstoreEditTarget {
+                column_name: column_name.clone(),
+                value: edit.value.clone(),
+            })
+        }
+        (Some(column_name), Some(data_type)) if services::is_postgres_bytea_type(&data_type) => {
+            match &edit.row_ref {
+                EditableRowRef::Existing(locator) => StructuredCellEdit::Bytea(ByteaViewTarget {
+                    column_name: column_name.clone(),
+                    locator: locator.clone(),
+                }),
+                // A draft row has nothing stored yet, so its value is typed in.
+                EditableRowRef::PendingInsert(_) => {
+                    editing_cell.set(Some(edit));
+                    return;
+                }
+            }
+        }
+        _ => {
+            editing_cell.set(Some(edit));
+            return;
+        }
+    };
+
+    structured_edit.set(Some((edit, target)));
+}
+
 fn commit_cell_edit(
     mut editing_cell: Signal<Option<EditingCell>>,
     mut tabs: Signal<Vec<QueryTabState>>,
@@ -1545,45 +2906,40 @@ fn commit_cell_edit(
             return;
         };
 
-        match editing.row_ref {
-            EditableRowRef::PendingInsert(insert_id) => {
-                if let Some(row) = tab
-                    .pending_table_changes
-                    .inserted_rows
-                    .iter_mut()
-                    .find(|row| row.id == insert_id)
-                    && let Some(value) = row.values.get_mut(editing.col_index)
-                {
-                    *value = Some(editing.value);
+        tab.pending_table_changes
+            .stage_edit(|changes| match editing.row_ref {
+                EditableRowRef::PendingInsert(insert_id) => {
+                    if let Some(row) = changes
+                        .inserted_rows
+                        .iter_mut()
+                        .find(|row| row.id == insert_id)
+                        && let Some(value) = row.values.get_mut(editing.col_index)
+                    {
+                        *value = Some(editing.value);
+                    }
                 }
-            }
-            EditableRowRef::Existing(locator) => {
-                let original_value =
-                    original_cell_value(&page, locator.as_str(), editing.col_index)
-                        .unwrap_or_default();
-
-                if original_value == editing.value {
-                    tab.pending_table_changes.updated_cells.retain(|change| {
-                        !(change.locator == locator && change.column_name == column_name)
-                    });
-                } else if let Some(change) = tab
-                    .pending_table_changes
-                    .updated_cells
-                    .iter_mut()
-                    .find(|change| change.locator == locator && change.column_name == column_name)
-                {
-                    change.value = editing.value;
-                } else {
-                    tab.pending_table_changes
-                        .updated_cells
-                        .push(PendingCellChange {
+                EditableRowRef::Existing(locator) => {
+                    let original_value =
+                        original_cell_value(&page, locator.as_str(), editing.col_index)
+                            .unwrap_or_default();
+
+                    if original_value == editing.value {
+                        changes.updated_cells.retain(|change| {
+                            !(change.locator == locator && change.column_name == column_name)
+                        });
+                    } else if let Some(change) = changes.updated_cells.iter_mut().find(|change| {
+                        change.locator == locator && change.column_name == column_name
+                    }) {
+                        change.value = editing.value;
+                    } else {
+                        changes.updated_cells.push(PendingCellChange {
                             locator,
                             column_name,
                             value: editing.value,
                         });
+                    }
                 }
-            }
-        }
+            });
 
         tab.status = pending_changes_summary(&tab.pending_table_changes);
     });
@@ -1623,13 +2979,15 @@ fn insert_empty_row(mut tabs: Signal<Vec<QueryTabState>>, active_tab_id: Signal<
         if let Some(tab) = all_tabs.iter_mut().find(|tab| tab.id == current_id) {
             let insert_id = tab.pending_table_changes.next_insert_id;
             tab.pending_table_changes.next_insert_id += 1;
-            tab.pending_table_changes.inserted_rows.insert(
-                0,
-                PendingInsertRow {
-                    id: insert_id,
-                    values: vec![None; page.columns.len()],
-                },
-            );
+            tab.pending_table_changes.stage_edit(|changes| {
+                changes.inserted_rows.insert(
+                    0,
+                    PendingInsertRow {
+                        id: insert_id,
+                        values: vec![None; page.columns.len()],
+                    },
+                );
+            });
             tab.status = pending_changes_summary(&tab.pending_table_changes);
             inserted_row_id = Some(insert_id);
         }
@@ -1725,6 +3083,10 @@ fn apply_pending_changes(mut tabs: Signal<Vec<QueryTabState>>, active_tab_id: Si
         return;
     };
 
+    // Inside an open transaction the edits stay uncommitted until the user
+    // commits or rolls back the session transaction.
+    let session_id = current_tab.session_id;
+    let transaction = session_transaction(session_id);
     let columns = page.columns.clone();
     let summary = pending_changes_summary(&pending_changes);
     set_active_tab_status(tabs, current_id, format!("Applying {summary}..."));
@@ -1738,46 +3100,82 @@ fn apply_pending_changes(mut tabs: Signal<Vec<QueryTabState>>, active_tab_id: Si
                 .filter_map(|(column_name, value)| value.map(|value| (column_name, value)))
                 .collect::<Vec<_>>();
 
-            if let Err(err) = services::insert_table_row_with_values(
-                connection.clone(),
-                editable.source.clone(),
-                column_values,
-            )
-            .await
-            {
+            let result = match &transaction {
+                Some(transaction) => {
+                    transaction
+                        .insert_table_row_with_values(editable.source.clone(), column_values)
+                        .await
+                }
+                None => {
+                    services::insert_table_row_with_values(
+                        connection.clone(),
+                        editable.source.clone(),
+                        column_values,
+                    )
+                    .await
+                }
+            };
+            if let Err(err) = result {
+                sync_transaction_status(session_id, transaction.as_ref()).await;
                 set_active_tab_status(tabs, current_id, format_row_edit_error("Row insert", err));
                 return;
             }
         }
 
         for change in pending_changes.updated_cells {
-            if let Err(err) = services::update_table_cell(
-                connection.clone(),
-                editable.source.clone(),
-                change.locator,
-                change.column_name,
-                change.value,
-            )
-            .await
-            {
+            let result = match &transaction {
+                Some(transaction) => {
+                    transaction
+                        .update_table_cell(
+                            editable.source.clone(),
+                            change.locator,
+                            change.column_name,
+                            change.value,
+                        )
+                        .await
+                }
+                None => {
+                    services::update_table_cell(
+                        connection.clone(),
+                        editable.source.clone(),
+                        change.locator,
+                        change.column_name,
+                        change.value,
+                    )
+                    .await
+                }
+            };
+            if let Err(err) = result {
+                sync_transaction_status(session_id, transaction.as_ref()).await;
                 set_active_tab_status(tabs, current_id, format_row_edit_error("Cell update", err));
                 return;
             }
         }
 
         for delete in pending_changes.deleted_rows {
-            if let Err(err) = services::delete_table_row(
-                connection.clone(),
-                editable.source.clone(),
-                delete.locator,
-            )
-            .await
-            {
+            let result = match &transaction {
+                Some(transaction) => {
+                    transaction
+                        .delete_table_row(editable.source.clone(), delete.locator)
+                        .await
+                }
+                None => {
+                    services::delete_table_row(
+                        connection.clone(),
+                        editable.source.clone(),
+                        delete.locator,
+                    )
+                    .await
+                }
+            };
+            if let Err(err) = result {
+                sync_transaction_status(session_id, transaction.as_ref()).await;
                 set_active_tab_status(tabs, current_id, format_row_edit_error("Row delete", err));
                 return;
             }
         }
 
+        invalidate_row_counts(session_id, &editable.source.qualified_name);
         let mut updated_tab = None;
         tabs.with_mut(|all_tabs| {
             if let Some(tab) = all_tabs.iter_mut().find(|tab| tab.id == current_id) {
@@ -1793,6 +3191,12 @@ fn apply_pending_changes(mut tabs: Signal<Vec<QueryTabState>>, active_tab_id: Si
     });
 }
 
+async fn sync_transaction_status(session_id: u64, transaction: Option<&SqlTransaction>) {
+    if let Some(transaction) = transaction {
+        set_session_transaction_status(session_id, transaction.status().await);
+    }
+}
+
 fn discard_pending_changes(mut tabs: Signal<Vec<QueryTabState>>, active_tab_id: Signal<u64>) {
     let current_id = active_tab_id();
     tabs.with_mut(|all_tabs| {
@@ -1803,6 +3207,22 @@ fn discard_pending_changes(mut tabs: Signal<Vec<QueryTabState>>, active_tab_id:
     });
 }
 
+fn undo_pending_change(mut tabs: Signal<Vec<QueryTabState>>, active_tab_id: Signal<u64>) {
+    let current_id = active_tab_id();
+    tabs.with_mut(|all_tabs| {
+        if let Some(tab) = all_tabs.iter_mut().find(|tab| tab.id == current_id) {
+            tab.status = if tab.pending_table_changes.undo() {
+                format!(
+                    "Undid last edit · {}",
+                    pending_changes_summary(&tab.pending_table_changes)
+                )
+            } else {
+                "Nothing to undo".to_string()
+            };
+        }
+    });
+}
+
 fn delete_selected_row(
     mut tabs: Signal<Vec<QueryTabState>>,
     active_tab_id: Signal<u64>,
@@ -1843,9 +3263,9 @@ fn delete_selected_row(
     if let EditableRowRef::PendingInsert(insert_id) = row.row_ref {
         tabs.with_mut(|all_tabs| {
             if let Some(tab) = all_tabs.iter_mut().find(|tab| tab.id == current_id) {
-                tab.pending_table_changes
-                    .inserted_rows
-                    .retain(|row| row.id != insert_id);
+                tab.pending_table_changes.stage_edit(|changes| {
+                    changes.inserted_rows.retain(|row| row.id != insert_id);
+                });
                 tab.status = pending_changes_summary(&tab.pending_table_changes);
             }
         });
@@ -1858,19 +3278,84 @@ fn delete_selected_row(
 
     tabs.with_mut(|all_tabs| {
         if let Some(tab) = all_tabs.iter_mut().find(|tab| tab.id == current_id) {
-            tab.pending_table_changes
-                .deleted_rows
-                .push(PendingDeleteRow {
+            tab.pending_table_changes.stage_edit(|changes| {
+                changes.deleted_rows.push(PendingDeleteRow {
                     locator: locator.clone(),
                 });
-            tab.pending_table_changes
-                .updated_cells
-                .retain(|change| change.locator != locator);
+                changes
+                    .updated_cells
+                    .retain(|change| change.locator != locator);
+            });
             tab.status = pending_changes_summary(&tab.pending_table_changes);
         }
     });
 }
 
+/// The confirmation for deleting `row_indices` of the tab's grid, or `None`
+/// with the reason on the tab status when the rows cannot be deleted.
+fn delete_rows_target_for_selection(
+    tabs: Signal<Vec<QueryTabState>>,
+    tab_id: u64,
+    row_indices: &[usize],
+) -> Option<DeleteRowsTarget> {
+    if read_only_mode_enabled() {
+        set_active_tab_status(tabs, tab_id, read_only_mode_block_status("row delete"));
+        return None;
+    }
+
+    let current_tab = tabs.read().iter().find(|tab| tab.id == tab_id).cloned()?;
+    let Some(QueryOutput::Table(page)) = current_tab.result.as_ref() else {
+        return None;
+    };
+    let Some(editable) = page.editable.as_ref() else {
+        set_active_tab_status(
+            tabs,
+            tab_id,
+            "Row delete is available only for editable table views".to_string(),
+        );
+        return None;
+    };
+    // Grid indices include staged inserts; deleting against them would mix
+    // staged and immediate edits.
+    if !current_tab.pending_table_changes.is_empty() {
+        set_active_tab_status(
+            tabs,
+            tab_id,
+            "Apply or discard pending changes before deleting rows".to_string(),
+        );
+        return None;
+    }
+
+    let display_rows = materialize_display_rows(page, &current_tab.pending_table_changes);
+    let rows = row_indices
+        .iter()
+        .filter_map(|row_index| display_rows.get(*row_index))
+        .filter_map(|row| match &row.row_ref {
+            EditableRowRef::Existing(locator) => Some(DeleteRowPreview {
+                locator: locator.clone(),
+                values: row.values.clone(),
+            }),
+            EditableRowRef::PendingInsert(_) => None,
+        })
+        .collect::<Vec<_>>();
+    if rows.is_empty() {
+        set_active_tab_status(
+            tabs,
+            tab_id,
+            "The selected rows are no longer available".to_string(),
+        );
+        return None;
+    }
+
+    Some(DeleteRowsTarget {
+        tab_id,
+        session_id: current_tab.session_id,
+        source: editable.source.clone(),
+        columns: page.columns.clone(),
+        rows,
+    })
+}
+
 fn next_pending_auto_id(
     pending_changes: &PendingTableChanges,
     column_index: usize,
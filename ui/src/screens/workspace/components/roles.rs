@@ -0,0 +1,174 @@
+use crate::app_state::session_connection;
+use dioxus::prelude::*;
+use models::RoleInfo;
+
+/// Roles of a PostgreSQL server from `pg_roles`, with their attributes and
+/// memberships.
+#[component]
+pub(crate) fn RolesWindow(
+    session_id: u64,
+    connection_name: String,
+    mut show_roles: Signal<bool>,
+) -> Element {
+    let mut roles = use_signal(|| None::<Result<Vec<RoleInfo>, String>>);
+    let mut reload = use_signal(|| 0_u64);
+    let mut search = use_signal(String::new);
+
+    use_effect(move || {
+        reload();
+        spawn(async move {
+            let result = match session_connection(session_id) {
+                Some(connection) => services::load_roles(connection)
+                    .await
+                    .map_err(|err| err.to_string()),
+                None => Err("Connection is no longer open.".to_string()),
+            };
+            roles.set(Some(result));
+        });
+    });
+
+    rsx! {
+        div {
+            class: "settings-modal__backdrop",
+            onclick: move |_| show_roles.set(false),
+            div {
+                class: "settings-modal table-modal table-modal--wide",
+                onclick: move |event| event.stop_propagation(),
+                div {
+                    class: "settings-modal__header",
+                    div {
+                        class: "settings-modal__header-copy",
+                        h2 { class: "settings-modal__title", "Roles" }
+                        p {
+                            class: "settings-modal__hint",
+                            "Roles on {connection_name}. Table privileges are listed from a table's right-click menu."
+                        }
+                    }
+                    button {
+                        class: "button button--ghost button--small",
+                        onclick: move |_| reload += 1,
+                        "Refresh"
+                    }
+                    button {
+                        class: "button button--ghost button--small",
+                        onclick: move |_| show_roles.set(false),
+                        "Close"
+                    }
+                }
+
+                div {
+                    class: "table-modal__body",
+                    input {
+                        class: "input",
+                        r#type: "search",
+                        value: "{search}",
+                        placeholder: "Filter roles",
+                        oninput: move |event| search.set(event.value()),
+                    }
+                    match roles() {
+                        None => rsx! {
+                            p { class: "table-modal__hint", "Loading roles…" }
+                        },
+                        Some(Err(err)) => rsx! {
+                            p { class: "table-modal__error", "{err}" }
+                        },
+                        Some(Ok(roles)) => {
+                            let roles = filter_roles(roles, &search());
+                            rsx! {
+                                if roles.is_empty() {
+                                    p { class: "table-modal__hint", "No roles match the filter." }
+                                } else {
+                                    div {
+                                        class: "table-modal__table-wrap",
+                                        table {
+                                            class: "table-modal__table",
+                                            thead {
+                                                tr {
+                                                    th { "Role" }
+                                                    th { "Login" }
+                                                    th { "Superuser" }
+                                                    th { "Create DB" }
+                                                    th { "Create role" }
+                                                    th { "Member of" }
+                                                }
+                                            }
+                                            tbody {
+                                                for role in roles {
+                                                    tr {
+                                                        key: "{role.name}",
+                                                        td { "{role.name}" }
+                                                        td { {yes_no(role.can_login)} }
+                                                        td { {yes_no(role.superuser)} }
+                                                        td { {yes_no(role.create_db)} }
+                                                        td { {yes_no(role.create_role)} }
+                                                        td { {role.member_of.join(", ")} }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn yes_no(value: bool) -> &'static str {
+    if value { "yes" } else { "" }
+}
+
+/// Roles whose name or memberships contain `search`, ignoring case.
+fn filter_roles(roles: Vec<RoleInfo>, search: &str) -> Vec<RoleInfo> {
+    let search = search.trim().to_lowercase();
+    if search.is_empty() {
+        return roles;
+    }
+    roles
+        .into_iter()
+        .filter(|role| {
+            role.name.to_lowercase().contains(&search)
+                || role
+                    .member_of
+                    .iter()
+                    .any(|group| group.to_lowercase().contains(&search))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::filter_roles;
+    use models::RoleInfo;
+
+    #[test]
+    fn filter_roles_matches_names_and_memberships() {
+        let role = |name: &str, member_of: &[&str]| RoleInfo {
+            name: name.to_string(),
+            can_login: true,
+            superuser: false,
+            create_db: false,
+            create_role: false,
+            member_of: member_of.iter().map(|group| group.to_string()).collect(),
+        };
+        let roles = vec![
+            role("alice", &["Readers"]),
+            role("bob", &[]),
+            role("readers", &[]),
+        ];
+        let names =
+            |roles: Vec<RoleInfo>| roles.into_iter().map(|role| role.name).collect::<Vec<_>>();
+
+        assert_eq!(
+            names(filter_roles(roles.clone(), "READ")),
+            vec!["alice", "readers"]
+        );
+        assert_eq!(
+            names(filter_roles(roles, " ")),
+            vec!["alice", "bob", "readers"]
+        );
+    }
+}
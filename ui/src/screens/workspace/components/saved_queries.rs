@@ -1,10 +1,13 @@
 use crate::{
-    app_state::APP_STATE,
+    app_state::{APP_PENDING_SAVED_QUERY, APP_STATE},
     screens::workspace::actions::{append_to_tab_sql, ensure_tab_for_session, set_active_tab_sql},
 };
 use dioxus::prelude::*;
-use models::{QueryTabState, SavedQuery, SavedQueryKind};
+use models::{QueryTabState, SavedQuery, SavedQueryKind, saved_queries_by_folder};
 
+/// Saved queries and snippets grouped by folder. `${name}` variables in
+/// their SQL are asked for on load, and the whole list can be exported to a
+/// JSON file and imported elsewhere.
 #[component]
 pub fn SavedQueriesPanel(
     saved_queries: Vec<SavedQuery>,
@@ -15,7 +18,55 @@ pub fn SavedQueriesPanel(
     next_tab_id: Signal<u64>,
 ) -> Element {
     let mut save_title = use_signal(String::new);
+    let mut save_folder = use_signal(String::new);
     let mut panel_status = use_signal(String::new);
+    let mut editing = use_signal(|| None::<SavedQuery>);
+    // Query waiting for its `${name}` variables, with the session it was
+    // saved from, and the value entered for each variable.
+    let mut filling = use_signal(|| None::<(SavedQuery, Option<u64>)>);
+    let mut variable_values = use_signal(Vec::<(String, String)>::new);
+
+    // Loads an item, first asking for its `${name}` variables if it has any.
+    let mut open_item = move |item: SavedQuery, source_session_id: Option<u64>| {
+        let variables = item.variables();
+        if !variables.is_empty() {
+            variable_values.set(
+                variables
+                    .into_iter()
+                    .map(|name| (name, String::new()))
+                    .collect(),
+            );
+            filling.set(Some((item, source_session_id)));
+            return;
+        }
+        load_saved_query_into_workspace(
+            item.clone(),
+            source_session_id,
+            tabs,
+            active_tab_id,
+            next_tab_id,
+        );
+        panel_status.set(format!("{} loaded into workspace.", item.title));
+    };
+
+    // Items picked from the toolbar's Favorites menu.
+    use_effect(move || {
+        if APP_PENDING_SAVED_QUERY.read().is_none() {
+            return;
+        }
+        let Some(item) = APP_PENDING_SAVED_QUERY.write().take() else {
+            return;
+        };
+        let source_session_id = item.connection_name.as_ref().and_then(|name| {
+            APP_STATE
+                .peek()
+                .sessions
+                .iter()
+                .find(|session| session.name == *name)
+                .map(|session| session.id)
+        });
+        open_item(item, source_session_id);
+    });
 
     let active_tab = tabs
         .read()
@@ -35,12 +86,9 @@ pub fn SavedQueriesPanel(
         .map(|session| (session.name.clone(), session.id))
         .collect::<std::collections::HashMap<_, _>>();
 
-    let mut items = saved_queries;
-    items.sort_by(|left, right| {
-        left.title
-            .cmp(&right.title)
-            .then_with(|| left.id.cmp(&right.id))
-    });
+    let export_items = saved_queries.clone();
+    let has_items = !saved_queries.is_empty();
+    let folders = saved_queries_by_folder(saved_queries);
 
     rsx! {
         section {
@@ -67,6 +115,12 @@ pub fn SavedQueriesPanel(
                             .unwrap_or_else(|| "Saved Query".to_string()),
                         oninput: move |event| save_title.set(event.value()),
                     }
+                    input {
+                        class: "input",
+                        value: "{save_folder}",
+                        placeholder: "Folder (optional)",
+                        oninput: move |event| save_folder.set(event.value()),
+                    }
                     div { class: "saved-queries__form-actions",
                         button {
                             class: "button button--ghost button--small",
@@ -78,6 +132,7 @@ pub fn SavedQueriesPanel(
                                         SavedQueryKind::Snippet,
                                         active_tab.clone(),
                                         save_title,
+                                        save_folder,
                                         next_saved_query_id,
                                         saved_queries_signal,
                                         panel_status,
@@ -96,6 +151,7 @@ pub fn SavedQueriesPanel(
                                         SavedQueryKind::Query,
                                         active_tab.clone(),
                                         save_title,
+                                        save_folder,
                                         next_saved_query_id,
                                         saved_queries_signal,
                                         panel_status,
@@ -106,81 +162,217 @@ pub fn SavedQueriesPanel(
                         }
                     }
                 }
+
+                div { class: "saved-queries__actions",
+                    button {
+                        class: "button button--ghost button--small",
+                        disabled: !has_items,
+                        title: "Save every saved query to a JSON file to share",
+                        onclick: move |_| export_saved_queries(export_items.clone(), panel_status),
+                        "Export"
+                    }
+                    button {
+                        class: "button button--ghost button--small",
+                        title: "Add the saved queries of an exported JSON file",
+                        onclick: move |_| {
+                            import_saved_queries(saved_queries_signal, next_saved_query_id, panel_status)
+                        },
+                        "Import"
+                    }
+                }
             }
 
             div {
                 class: "saved-queries__body",
-                if items.is_empty() {
+                if let Some((item, source_session_id)) = filling() {
+                    article { class: "saved-queries__item",
+                        div { class: "saved-queries__item-top",
+                            p { class: "saved-queries__title", "{item.title}" }
+                            span { class: "saved-queries__kind", "Variables" }
+                        }
+                        for (index, (name, value)) in variable_values().into_iter().enumerate() {
+                            label {
+                                key: "{name}",
+                                class: "field",
+                                span { class: "field__label", "{name}" }
+                                input {
+                                    class: "input",
+                                    value: "{value}",
+                                    autofocus: index == 0,
+                                    oninput: move |event| {
+                                        variable_values.with_mut(|values| {
+                                            if let Some(entry) = values.get_mut(index) {
+                                                entry.1 = event.value();
+                                            }
+                                        });
+                                    },
+                                }
+                            }
+                        }
+                        pre { class: "saved-queries__sql", "{item.render(&variable_values())}" }
+                        div { class: "saved-queries__actions",
+                            button {
+                                class: "button button--ghost button--small",
+                                onclick: move |_| filling.set(None),
+                                "Cancel"
+                            }
+                            button {
+                                class: "button button--primary button--small",
+                                onclick: {
+                                    let item = item.clone();
+                                    move |_| {
+                                        let rendered = SavedQuery {
+                                            sql: item.render(&variable_values()),
+                                            ..item.clone()
+                                        };
+                                        load_saved_query_into_workspace(
+                                            rendered,
+                                            source_session_id,
+                                            tabs,
+                                            active_tab_id,
+                                            next_tab_id,
+                                        );
+                                        panel_status.set(format!("{} loaded into workspace.", item.title));
+                                        filling.set(None);
+                                    }
+                                },
+                                "Load"
+                            }
+                        }
+                    }
+                } else if folders.is_empty() {
                     p { class: "empty-state", "No saved queries or snippets yet." }
                 } else {
-                    for item in items {
-                        {
-                            let source_session_id = item
-                                .connection_name
-                                .as_ref()
-                                .and_then(|name| sessions_by_name.get(name))
-                                .copied();
-                            let load_label = if item.kind == SavedQueryKind::Snippet {
-                                "Insert in tab"
-                            } else {
-                                "Load in tab"
-                            };
+                    for (folder, entries) in folders {
+                        div {
+                            key: "{folder}",
+                            class: "saved-queries__folder",
+                            p { class: "saved-queries__folder-name", "{folder}" }
+                            span { class: "saved-queries__folder-count", "{entries.len()}" }
+                        }
+                        for item in entries {
+                            {
+                                let source_session_id = item
+                                    .connection_name
+                                    .as_ref()
+                                    .and_then(|name| sessions_by_name.get(name))
+                                    .copied();
+                                let load_label = if item.kind == SavedQueryKind::Snippet {
+                                    "Insert in tab"
+                                } else {
+                                    "Load in tab"
+                                };
+                                let edited = editing().filter(|edited| edited.id == item.id);
 
-                            rsx! {
-                                article { class: "saved-queries__item",
-                                    div { class: "saved-queries__item-top",
-                                        p { class: "saved-queries__title", "{item.title}" }
-                                        span { class: "saved-queries__kind", "{item.kind_label()}" }
-                                    }
-                                    if let Some(connection_name) = item.connection_name.clone() {
-                                        p {
-                                            class: "saved-queries__connection",
-                                            title: "{connection_name}",
-                                            "{connection_name}"
-                                        }
-                                    }
-                                    pre {
-                                        class: "saved-queries__sql",
-                                        title: "{item.sql}",
-                                        "{item.sql}"
-                                    }
-                                    div { class: "saved-queries__actions",
-                                        button {
-                                            class: "button button--ghost button--small",
-                                            onclick: {
-                                                let item = item.clone();
-                                                move |_| {
-                                                    load_saved_query_into_workspace(
-                                                        item.clone(),
-                                                        source_session_id,
-                                                        tabs,
-                                                        active_tab_id,
-                                                        next_tab_id,
-                                                    );
-                                                    panel_status.set(format!(
-                                                        "{} loaded into workspace.",
-                                                        item.title
-                                                    ));
-                                                }
-                                            },
-                                            "{load_label}"
-                                        }
-                                        button {
-                                            class: "button button--ghost button--small",
-                                            onclick: {
-                                                let item_id = item.id;
-                                                let item_title = item.title.clone();
-                                                move |_| {
-                                                    saved_queries_signal.with_mut(|items| {
-                                                        items.retain(|existing| existing.id != item_id);
+                                rsx! {
+                                    if let Some(edited) = edited {
+                                        article { class: "saved-queries__item",
+                                            input {
+                                                class: "input",
+                                                value: "{edited.title}",
+                                                placeholder: "Name",
+                                                oninput: move |event| {
+                                                    editing.with_mut(|editing| {
+                                                        if let Some(editing) = editing.as_mut() {
+                                                            editing.title = event.value();
+                                                        }
                                                     });
-                                                    panel_status.set(format!("Deleted {item_title}."));
-                                                    spawn(async move {
-                                                        let _ = services::delete_saved_query(item_id).await;
+                                                },
+                                            }
+                                            input {
+                                                class: "input",
+                                                value: "{edited.folder}",
+                                                placeholder: "Folder (optional)",
+                                                oninput: move |event| {
+                                                    editing.with_mut(|editing| {
+                                                        if let Some(editing) = editing.as_mut() {
+                                                            editing.folder = event.value();
+                                                        }
                                                     });
+                                                },
+                                            }
+                                            textarea {
+                                                class: "input saved-queries__sql-input",
+                                                value: "{edited.sql}",
+                                                spellcheck: false,
+                                                oninput: move |event| {
+                                                    editing.with_mut(|editing| {
+                                                        if let Some(editing) = editing.as_mut() {
+                                                            editing.sql = event.value();
+                                                        }
+                                                    });
+                                                },
+                                            }
+                                            div { class: "saved-queries__actions",
+                                                button {
+                                                    class: "button button--ghost button--small",
+                                                    onclick: move |_| editing.set(None),
+                                                    "Cancel"
+                                                }
+                                                button {
+                                                    class: "button button--primary button--small",
+                                                    onclick: move |_| {
+                                                        if let Some(edited) = editing() {
+                                                            save_edited_query(edited, saved_queries_signal, editing, panel_status);
+                                                        }
+                                                    },
+                                                    "Save"
+                                                }
+                                            }
+                                        }
+                                    } else {
+                                        article { class: "saved-queries__item",
+                                            div { class: "saved-queries__item-top",
+                                                p { class: "saved-queries__title", "{item.title}" }
+                                                span { class: "saved-queries__kind", "{item.kind_label()}" }
+                                            }
+                                            if let Some(connection_name) = item.connection_name.clone() {
+                                                p {
+                                                    class: "saved-queries__connection",
+                                                    title: "{connection_name}",
+                                                    "{connection_name}"
+                                                }
+                                            }
+                                            pre {
+                                                class: "saved-queries__sql",
+                                                title: "{item.sql}",
+                                                "{item.sql}"
+                                            }
+                                            div { class: "saved-queries__actions",
+                                                button {
+                                                    class: "button button--ghost button--small",
+                                                    onclick: {
+                                                        let item = item.clone();
+                                                        move |_| open_item(item.clone(), source_session_id)
+                                                    },
+                                                    "{load_label}"
+                                                }
+                                                button {
+                                                    class: "button button--ghost button--small",
+                                                    onclick: {
+                                                        let item = item.clone();
+                                                        move |_| editing.set(Some(item.clone()))
+                                                    },
+                                                    "Edit"
                                                 }
-                                            },
-                                            "Delete"
+                                                button {
+                                                    class: "button button--ghost button--small",
+                                                    onclick: {
+                                                        let item_id = item.id;
+                                                        let item_title = item.title.clone();
+                                                        move |_| {
+                                                            saved_queries_signal.with_mut(|items| {
+                                                                items.retain(|existing| existing.id != item_id);
+                                                            });
+                                                            panel_status.set(format!("Deleted {item_title}."));
+                                                            spawn(async move {
+                                                                let _ = services::delete_saved_query(item_id).await;
+                                                            });
+                                                        }
+                                                    },
+                                                    "Delete"
+                                                }
+                                            }
                                         }
                                     }
                                 }
@@ -197,6 +389,7 @@ fn save_current_sql(
     kind: SavedQueryKind,
     active_tab: Option<QueryTabState>,
     mut save_title: Signal<String>,
+    save_folder: Signal<String>,
     mut next_saved_query_id: Signal<u64>,
     mut saved_queries_signal: Signal<Vec<SavedQuery>>,
     mut panel_status: Signal<String>,
@@ -219,7 +412,7 @@ fn save_current_sql(
     let item = SavedQuery {
         id: next_saved_query_id(),
         title: title.clone(),
-        folder: String::new(),
+        folder: save_folder().trim().to_string(),
         sql: active_tab.sql,
         kind,
         connection_name,
@@ -242,6 +435,104 @@ fn save_current_sql(
     });
 }
 
+fn save_edited_query(
+    item: SavedQuery,
+    mut saved_queries_signal: Signal<Vec<SavedQuery>>,
+    mut editing: Signal<Option<SavedQuery>>,
+    mut panel_status: Signal<String>,
+) {
+    if item.title.trim().is_empty() || item.sql.trim().is_empty() {
+        panel_status.set("A saved query needs a name and SQL.".to_string());
+        return;
+    }
+
+    let item = SavedQuery {
+        title: item.title.trim().to_string(),
+        folder: item.folder.trim().to_string(),
+        ..item
+    };
+    saved_queries_signal.with_mut(|items| {
+        if let Some(existing) = items.iter_mut().find(|existing| existing.id == item.id) {
+            *existing = item.clone();
+        }
+    });
+    editing.set(None);
+    panel_status.set(format!("Saved {}.", item.title));
+
+    spawn(async move {
+        let _ = services::save_saved_query(item).await;
+    });
+}
+
+fn export_saved_queries(items: Vec<SavedQuery>, mut panel_status: Signal<String>) {
+    spawn(async move {
+        let Some(file) = rfd::AsyncFileDialog::new()
+            .set_file_name("saved_queries.json")
+            .add_filter("JSON", &["json"])
+            .save_file()
+            .await
+        else {
+            return;
+        };
+        let written = serde_json::to_string_pretty(&items)
+            .map_err(|err| err.to_string())
+            .and_then(|json| std::fs::write(file.path(), json).map_err(|err| err.to_string()));
+        match written {
+            Ok(()) => panel_status.set(format!("Exported {} saved queries.", items.len())),
+            Err(err) => panel_status.set(format!("Export failed: {err}")),
+        }
+    });
+}
+
+/// Adds the saved queries of an exported file under fresh ids, skipping any
+/// already saved with the same name, folder and SQL.
+fn import_saved_queries(
+    mut saved_queries_signal: Signal<Vec<SavedQuery>>,
+    mut next_saved_query_id: Signal<u64>,
+    mut panel_status: Signal<String>,
+) {
+    spawn(async move {
+        let Some(file) = rfd::AsyncFileDialog::new()
+            .add_filter("JSON", &["json"])
+            .pick_file()
+            .await
+        else {
+            return;
+        };
+        let parsed = std::fs::read_to_string(file.path())
+            .map_err(|err| err.to_string())
+            .and_then(|content| {
+                serde_json::from_str::<Vec<SavedQuery>>(&content).map_err(|err| err.to_string())
+            });
+        let mut imported = match parsed {
+            Ok(items) => items,
+            Err(err) => {
+                panel_status.set(format!("Import failed: {err}"));
+                return;
+            }
+        };
+
+        imported.retain(|item| {
+            !saved_queries_signal.read().iter().any(|existing| {
+                existing.title == item.title
+                    && existing.folder_name() == item.folder_name()
+                    && existing.sql == item.sql
+            })
+        });
+        for item in &mut imported {
+            item.id = next_saved_query_id();
+            next_saved_query_id += 1;
+        }
+        let count = imported.len();
+        saved_queries_signal.with_mut(|items| items.extend(imported.clone()));
+        panel_status.set(format!("Imported {count} saved queries."));
+
+        if let Err(err) = services::save_saved_queries(imported).await {
+            panel_status.set(format!("Imported, but failed to save them: {err}"));
+        }
+    });
+}
+
 fn load_saved_query_into_workspace(
     item: SavedQuery,
     source_session_id: Option<u64>,
@@ -0,0 +1,164 @@
+use crate::screens::workspace::actions::{TransactionControl, run_savepoint_control};
+use dioxus::prelude::*;
+use models::QueryTabState;
+
+/// Savepoints sub-menu of the editor's transaction controls: create one by
+/// name, then roll back to or release any active savepoint.
+#[component]
+pub(crate) fn SavepointMenu(
+    tabs: Signal<Vec<QueryTabState>>,
+    active_tab_id: Signal<u64>,
+    session_id: u64,
+    /// Active savepoints, oldest first.
+    savepoints: Vec<String>,
+) -> Element {
+    let mut open = use_signal(|| false);
+    let mut name_draft = use_signal(String::new);
+    let label = if savepoints.is_empty() {
+        "Savepoints".to_string()
+    } else {
+        format!("Savepoints ({})", savepoints.len())
+    };
+    let can_create = !name_draft().trim().is_empty();
+
+    rsx! {
+        div {
+            class: "savepoint-menu",
+            button {
+                class: if open() {
+                    "button button--ghost button--small button--active"
+                } else {
+                    "button button--ghost button--small"
+                },
+                title: "Create, roll back to, or release savepoints",
+                onclick: {
+                    let savepoints = savepoints.clone();
+                    move |_| {
+                        if !open() {
+                            name_draft.set(next_savepoint_name(&savepoints));
+                        }
+                        open.toggle();
+                    }
+                },
+                "{label}"
+            }
+            if open() {
+                div {
+                    class: "savepoint-menu__backdrop",
+                    onclick: move |_| open.set(false),
+                }
+                div {
+                    class: "savepoint-menu__panel",
+                    form {
+                        class: "savepoint-menu__create",
+                        onsubmit: move |event| {
+                            event.prevent_default();
+                            let name = name_draft().trim().to_string();
+                            if name.is_empty() {
+                                return;
+                            }
+                            open.set(false);
+                            run_savepoint_control(
+                                tabs,
+                                active_tab_id(),
+                                session_id,
+                                TransactionControl::Savepoint(name),
+                            );
+                        },
+                        input {
+                            class: "input savepoint-menu__input",
+                            value: name_draft(),
+                            placeholder: "Savepoint name",
+                            autofocus: true,
+                            oninput: move |event| name_draft.set(event.value()),
+                            onkeydown: move |event: KeyboardEvent| {
+                                if event.key() == Key::Escape {
+                                    open.set(false);
+                                }
+                            },
+                        }
+                        button {
+                            class: "button button--primary button--small",
+                            r#type: "submit",
+                            disabled: !can_create,
+                            "Create"
+                        }
+                    }
+
+                    if savepoints.is_empty() {
+                        p { class: "savepoint-menu__empty", "No savepoints in this transaction yet." }
+                    }
+                    for savepoint in savepoints.iter().rev().cloned() {
+                        div {
+                            key: "{savepoint}",
+                            class: "savepoint-menu__item",
+                            span { class: "savepoint-menu__name", "{savepoint}" }
+                            button {
+                                class: "button button--ghost button--small",
+                                title: "Undo everything after {savepoint}",
+                                onclick: {
+                                    let savepoint = savepoint.clone();
+                                    move |_| {
+                                        open.set(false);
+                                        run_savepoint_control(
+                                            tabs,
+                                            active_tab_id(),
+                                            session_id,
+                                            TransactionControl::RollbackToSavepoint(
+                                                savepoint.clone(),
+                                            ),
+                                        );
+                                    }
+                                },
+                                "Roll back"
+                            }
+                            button {
+                                class: "button button--ghost button--small",
+                                title: "Forget {savepoint} and later savepoints, keeping their work",
+                                onclick: {
+                                    let savepoint = savepoint.clone();
+                                    move |_| {
+                                        open.set(false);
+                                        run_savepoint_control(
+                                            tabs,
+                                            active_tab_id(),
+                                            session_id,
+                                            TransactionControl::ReleaseSavepoint(savepoint.clone()),
+                                        );
+                                    }
+                                },
+                                "Release"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// First `spN` name not already taken by an active savepoint.
+fn next_savepoint_name(savepoints: &[String]) -> String {
+    (savepoints.len() + 1..)
+        .map(|index| format!("sp{index}"))
+        .find(|name| !savepoints.contains(name))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::next_savepoint_name;
+
+    #[test]
+    fn next_savepoint_name_skips_names_in_use() {
+        assert_eq!(next_savepoint_name(&[]), "sp1");
+        assert_eq!(
+            next_savepoint_name(&["sp1".to_string(), "sp2".to_string()]),
+            "sp3"
+        );
+        assert_eq!(
+            next_savepoint_name(&["before_import".to_string(), "sp2".to_string()]),
+            "sp3"
+        );
+    }
+}
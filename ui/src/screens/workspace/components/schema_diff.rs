@@ -0,0 +1,361 @@
+use super::ExplorerConnectionSection;
+use crate::app_state::session_connection;
+use crate::screens::workspace::actions::{append_to_tab_sql, ensure_tab_for_session};
+use dioxus::prelude::*;
+use models::{
+    ExplorerNodeKind, QueryTabState, SchemaChange, SchemaChangeKind, diff_schemas,
+    schema_migration_sql,
+};
+
+/// One side of the comparison: an open session and a schema in it.
+#[derive(Clone, Debug, Default, PartialEq)]
+struct SchemaSide {
+    session_id: u64,
+    schema: String,
+}
+
+/// Compares the tables, columns, indexes and constraints of two PostgreSQL
+/// schemas, from the same session or different ones, and can write a script
+/// that turns the left schema into the right one.
+#[component]
+pub(crate) fn SchemaDiffWindow(
+    sections: Vec<ExplorerConnectionSection>,
+    mut show_schema_diff: Signal<bool>,
+    tabs: Signal<Vec<QueryTabState>>,
+    active_tab_id: Signal<u64>,
+    next_tab_id: Signal<u64>,
+) -> Element {
+    let sections = sections
+        .into_iter()
+        .filter(|section| section.kind_label == "PostgreSQL")
+        .collect::<Vec<_>>();
+    let initial = default_side(&sections);
+    let mut left = use_signal(|| initial.clone());
+    let mut right = use_signal(|| initial);
+    let mut changes = use_signal(|| None::<Result<Vec<SchemaChange>, String>>);
+    // Left schema the current result was computed for, so the script keeps
+    // naming it if the pickers change afterwards.
+    let mut compared = use_signal(SchemaSide::default);
+    let mut comparing = use_signal(|| false);
+    let mut show_migration = use_signal(|| false);
+
+    let left_side = left();
+    let right_side = right();
+    let left_schemas = section_schemas(&sections, left_side.session_id);
+    let right_schemas = section_schemas(&sections, right_side.session_id);
+    let can_compare = !comparing()
+        && !left_side.schema.is_empty()
+        && !right_side.schema.is_empty()
+        && left_side != right_side;
+    let migration = match changes() {
+        Some(Ok(changes)) if !changes.is_empty() => {
+            Some(schema_migration_sql(&compared().schema, &changes))
+        }
+        _ => None,
+    };
+
+    rsx! {
+        div {
+            class: "settings-modal__backdrop",
+            onclick: move |_| show_schema_diff.set(false),
+            div {
+                class: "settings-modal table-modal table-modal--wide",
+                onclick: move |event| event.stop_propagation(),
+                div {
+                    class: "settings-modal__header",
+                    div {
+                        class: "settings-modal__header-copy",
+                        h2 { class: "settings-modal__title", "Schema Diff" }
+                        p {
+                            class: "settings-modal__hint",
+                            "Green is only in the right schema, red only in the left, yellow differs between them."
+                        }
+                    }
+                    button {
+                        class: "button button--ghost button--small",
+                        onclick: move |_| show_schema_diff.set(false),
+                        "Close"
+                    }
+                }
+
+                div {
+                    class: "table-modal__body",
+                    if sections.is_empty() {
+                        p { class: "table-modal__hint", "Open a PostgreSQL connection to compare schemas." }
+                    } else {
+                        for (label, side, schemas, is_left) in [
+                            ("Left", left_side.clone(), left_schemas, true),
+                            ("Right", right_side.clone(), right_schemas, false),
+                        ] {
+                            div {
+                                key: "{label}",
+                                class: "table-modal__toolbar",
+                                span { class: "field__label", "{label}" }
+                                select {
+                                    class: "input",
+                                    onchange: {
+                                        let sections = sections.clone();
+                                        move |event: FormEvent| {
+                                            let Ok(session_id) = event.value().parse::<u64>() else {
+                                                return;
+                                            };
+                                            let schema = section_schemas(&sections, session_id)
+                                                .into_iter()
+                                                .next()
+                                                .unwrap_or_default();
+                                            let side = SchemaSide { session_id, schema };
+                                            if is_left {
+                                                left.set(side);
+                                            } else {
+                                                right.set(side);
+                                            }
+                                        }
+                                    },
+                                    for section in sections.iter() {
+                                        option {
+                                            value: "{section.session_id}",
+                                            selected: section.session_id == side.session_id,
+                                            "{section.name}"
+                                        }
+                                    }
+                                }
+                                select {
+                                    class: "input",
+                                    onchange: move |event| {
+                                        let schema = event.value();
+                                        if is_left {
+                                            left.with_mut(|side| side.schema = schema);
+                                        } else {
+                                            right.with_mut(|side| side.schema = schema);
+                                        }
+                                    },
+                                    for schema in schemas {
+                                        option {
+                                            value: "{schema}",
+                                            selected: schema == side.schema,
+                                            "{schema}"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+
+                        div {
+                            class: "table-modal__actions",
+                            button {
+                                class: "button button--primary",
+                                disabled: !can_compare,
+                                onclick: move |_| {
+                                    let left_side = left();
+                                    let right_side = right();
+                                    comparing.set(true);
+                                    show_migration.set(false);
+                                    changes.set(None);
+                                    spawn(async move {
+                                        let result = load_diff(&left_side, &right_side).await;
+                                        compared.set(left_side);
+                                        changes.set(Some(result));
+                                        comparing.set(false);
+                                    });
+                                },
+                                if comparing() { "Comparing…" } else { "Compare" }
+                            }
+                        }
+                    }
+
+                    match changes() {
+                        None => rsx! {},
+                        Some(Err(err)) => rsx! {
+                            p { class: "table-modal__error", "{err}" }
+                        },
+                        Some(Ok(changes)) if changes.is_empty() => rsx! {
+                            p { class: "table-modal__hint", "The schemas match." }
+                        },
+                        Some(Ok(changes)) => rsx! {
+                            div {
+                                class: "table-modal__table-wrap",
+                                table {
+                                    class: "table-modal__table",
+                                    thead {
+                                        tr {
+                                            th { "Table" }
+                                            th { "Object" }
+                                            th { "Left" }
+                                            th { "Right" }
+                                        }
+                                    }
+                                    tbody {
+                                        for change in changes {
+                                            tr {
+                                                class: change_row_class(change.kind()),
+                                                td { "{change.table()}" }
+                                                td { "{change.object()}" }
+                                                td { class: "table-modal__cell--code", "{change.left_text()}" }
+                                                td { class: "table-modal__cell--code", "{change.right_text()}" }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        },
+                    }
+
+                    if let Some(migration) = migration {
+                        div {
+                            class: "table-modal__actions",
+                            button {
+                                class: "button button--ghost",
+                                onclick: move |_| show_migration.set(!show_migration()),
+                                if show_migration() { "Hide Migration" } else { "Generate Migration" }
+                            }
+                            if show_migration() {
+                                button {
+                                    class: "button button--primary",
+                                    onclick: {
+                                        let migration = migration.clone();
+                                        move |_| {
+                                            let tab_id = ensure_tab_for_session(
+                                                tabs,
+                                                active_tab_id,
+                                                next_tab_id,
+                                                compared().session_id,
+                                            );
+                                            if tab_id == 0 {
+                                                return;
+                                            }
+                                            append_to_tab_sql(
+                                                tabs,
+                                                tab_id,
+                                                migration.clone(),
+                                                "Review the migration, then run it".to_string(),
+                                            );
+                                            show_schema_diff.set(false);
+                                        }
+                                    },
+                                    "Insert into Editor"
+                                }
+                            }
+                        }
+                        if show_migration() {
+                            p {
+                                class: "table-modal__hint",
+                                "Runs against the left schema. Foreign keys are copied as written and may still reference the right schema."
+                            }
+                            pre { class: "table-modal__preview-sql", "{migration}" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+async fn load_diff(left: &SchemaSide, right: &SchemaSide) -> Result<Vec<SchemaChange>, String> {
+    let (Some(left_connection), Some(right_connection)) = (
+        session_connection(left.session_id),
+        session_connection(right.session_id),
+    ) else {
+        return Err("Connection is no longer open.".to_string());
+    };
+    let left_tables = services::load_schema_tables(left_connection, left.schema.clone())
+        .await
+        .map_err(|err| err.to_string())?;
+    let right_tables = services::load_schema_tables(right_connection, right.schema.clone())
+        .await
+        .map_err(|err| err.to_string())?;
+    Ok(diff_schemas(&left_tables, &right_tables))
+}
+
+/// The active session when it is listed, otherwise the first one, on its
+/// `public` schema if it has one.
+fn default_side(sections: &[ExplorerConnectionSection]) -> SchemaSide {
+    let Some(section) = sections
+        .iter()
+        .find(|section| section.is_active)
+        .or_else(|| sections.first())
+    else {
+        return SchemaSide::default();
+    };
+    let schemas = section_schemas(sections, section.session_id);
+    let schema = schemas
+        .iter()
+        .find(|schema| *schema == "public")
+        .or_else(|| schemas.first())
+        .cloned()
+        .unwrap_or_default();
+    SchemaSide {
+        session_id: section.session_id,
+        schema,
+    }
+}
+
+fn section_schemas(sections: &[ExplorerConnectionSection], session_id: u64) -> Vec<String> {
+    sections
+        .iter()
+        .find(|section| section.session_id == session_id)
+        .map(|section| {
+            section
+                .nodes
+                .iter()
+                .filter(|node| node.kind == ExplorerNodeKind::Schema)
+                .map(|node| node.name.clone())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn change_row_class(kind: SchemaChangeKind) -> &'static str {
+    match kind {
+        SchemaChangeKind::Added => "schema-diff__row schema-diff__row--added",
+        SchemaChangeKind::Removed => "schema-diff__row schema-diff__row--removed",
+        SchemaChangeKind::Changed => "schema-diff__row schema-diff__row--changed",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SchemaSide, default_side};
+    use crate::screens::workspace::components::ExplorerConnectionSection;
+    use models::{ExplorerNode, ExplorerNodeKind};
+
+    #[test]
+    fn default_side_prefers_the_active_session_and_public_schema() {
+        let schema = |name: &str| ExplorerNode {
+            name: name.to_string(),
+            kind: ExplorerNodeKind::Schema,
+            schema: None,
+            qualified_name: name.to_string(),
+            children: Vec::new(),
+        };
+        let section =
+            |session_id: u64, is_active: bool, schemas: &[&str]| ExplorerConnectionSection {
+                session_id,
+                parent_session_id: None,
+                name: format!("db{session_id}"),
+                kind_label: "PostgreSQL".to_string(),
+                status: String::new(),
+                is_active,
+                nodes: schemas.iter().map(|name| schema(name)).collect(),
+            };
+        let sections = vec![
+            section(1, false, &["sales"]),
+            section(2, true, &["audit", "public"]),
+        ];
+
+        assert_eq!(
+            default_side(&sections),
+            SchemaSide {
+                session_id: 2,
+                schema: "public".to_string(),
+            }
+        );
+        assert_eq!(
+            default_side(&sections[..1]),
+            SchemaSide {
+                session_id: 1,
+                schema: "sales".to_string(),
+            }
+        );
+        assert_eq!(default_side(&[]), SchemaSide::default());
+    }
+}
@@ -0,0 +1,380 @@
+use crate::app_state::session_connection;
+use crate::screens::workspace::actions::{read_only_mode_block_status, read_only_mode_enabled};
+use dioxus::prelude::*;
+use models::{ServerSetting, ServerSettings};
+use rfd::{AsyncMessageDialog, MessageButtons, MessageDialogResult, MessageLevel};
+
+/// Searchable `pg_settings` grouped by category, with values that differ from
+/// the built-in default highlighted. Superusers can change `user` parameters.
+#[component]
+pub(crate) fn ServerSettingsWindow(
+    session_id: u64,
+    connection_name: String,
+    mut show_settings: Signal<bool>,
+) -> Element {
+    let mut settings = use_signal(|| None::<Result<ServerSettings, String>>);
+    let mut reload = use_signal(|| 0_u64);
+    let mut search = use_signal(String::new);
+    let mut only_modified = use_signal(|| false);
+    // Parameter being edited and the value typed so far.
+    let editing = use_signal(|| None::<(String, String)>);
+    let status = use_signal(String::new);
+    let saving = use_signal(|| false);
+    let read_only_mode = read_only_mode_enabled();
+
+    use_effect(move || {
+        reload();
+        spawn(async move {
+            let result = match session_connection(session_id) {
+                Some(connection) => services::load_server_settings(connection)
+                    .await
+                    .map_err(|err| err.to_string()),
+                None => Err("Connection is no longer open.".to_string()),
+            };
+            settings.set(Some(result));
+        });
+    });
+
+    rsx! {
+        div {
+            class: "settings-modal__backdrop",
+            onclick: move |_| {
+                if !saving() {
+                    show_settings.set(false);
+                }
+            },
+            div {
+                class: "settings-modal table-modal table-modal--wide",
+                onclick: move |event| event.stop_propagation(),
+                div {
+                    class: "settings-modal__header",
+                    div {
+                        class: "settings-modal__header-copy",
+                        h2 { class: "settings-modal__title", "Server Settings" }
+                        p {
+                            class: "settings-modal__hint",
+                            "Run-time parameters of {connection_name}, from pg_settings."
+                        }
+                    }
+                    button {
+                        class: "button button--ghost button--small",
+                        onclick: move |_| reload += 1,
+                        "Refresh"
+                    }
+                    button {
+                        class: "button button--ghost button--small",
+                        disabled: saving(),
+                        onclick: move |_| show_settings.set(false),
+                        "Close"
+                    }
+                }
+
+                div {
+                    class: "table-modal__body",
+                    match settings() {
+                        None => rsx! {
+                            p { class: "table-modal__hint", "Loading settings…" }
+                        },
+                        Some(Err(err)) => rsx! {
+                            p { class: "table-modal__error", "{err}" }
+                        },
+                        Some(Ok(server)) => {
+                            let can_edit = server.is_superuser && !read_only_mode;
+                            let groups = group_settings(&server.settings, &search(), only_modified());
+                            rsx! {
+                                dl {
+                                    class: "table-modal__details server-settings__summary",
+                                    dt { "Version" }
+                                    dd { "{server.server_version}" }
+                                    dt { "Encoding" }
+                                    dd { "{server.server_encoding}" }
+                                }
+                                div {
+                                    class: "table-modal__toolbar",
+                                    input {
+                                        class: "input",
+                                        r#type: "search",
+                                        value: "{search}",
+                                        placeholder: "Filter by name, value or description",
+                                        oninput: move |event| search.set(event.value()),
+                                    }
+                                    label {
+                                        class: "settings-modal__toggle",
+                                        input {
+                                            r#type: "checkbox",
+                                            checked: only_modified(),
+                                            oninput: move |event| only_modified.set(event.checked()),
+                                        }
+                                        span { "Changed from default only" }
+                                    }
+                                }
+                                if !status().is_empty() {
+                                    p { class: "table-modal__hint", "{status}" }
+                                } else if !server.is_superuser {
+                                    p {
+                                        class: "table-modal__hint",
+                                        "Connect as a superuser to change settings."
+                                    }
+                                }
+
+                                if groups.is_empty() {
+                                    p { class: "table-modal__hint", "No settings match the filter." }
+                                } else {
+                                    div {
+                                        class: "table-modal__table-wrap",
+                                        table {
+                                            class: "table-modal__table",
+                                            thead {
+                                                tr {
+                                                    th { "Name" }
+                                                    th { "Value" }
+                                                    th { "Source" }
+                                                    th { "Description" }
+                                                }
+                                            }
+                                            for (category, category_settings) in groups {
+                                                tbody {
+                                                    key: "{category}",
+                                                    tr {
+                                                        class: "server-settings__category",
+                                                        td { colspan: "4", "{category}" }
+                                                    }
+                                                    for setting in category_settings {
+                                                        ServerSettingRow {
+                                                            key: "{setting.name}",
+                                                            setting,
+                                                            session_id,
+                                                            can_edit,
+                                                            editing,
+                                                            status,
+                                                            saving,
+                                                            reload,
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn ServerSettingRow(
+    setting: ServerSetting,
+    session_id: u64,
+    can_edit: bool,
+    mut editing: Signal<Option<(String, String)>>,
+    mut status: Signal<String>,
+    mut saving: Signal<bool>,
+    mut reload: Signal<u64>,
+) -> Element {
+    let edited_value = editing()
+        .filter(|(name, _)| *name == setting.name)
+        .map(|(_, value)| value);
+
+    rsx! {
+        tr {
+            class: if setting.is_modified() { "server-settings__row--modified" } else { "" },
+            td { class: "table-modal__cell--code", "{setting.name}" }
+            td {
+                if let Some(value) = edited_value {
+                    div {
+                        class: "server-settings__edit",
+                        input {
+                            class: "input",
+                            value: "{value}",
+                            oninput: {
+                                let name = setting.name.clone();
+                                move |event: FormEvent| editing.set(Some((name.clone(), event.value())))
+                            },
+                        }
+                        button {
+                            class: "button button--primary button--small",
+                            disabled: saving(),
+                            onclick: {
+                                let name = setting.name.clone();
+                                let value = value.clone();
+                                move |_| {
+                                    if read_only_mode_enabled() {
+                                        status.set(read_only_mode_block_status("changing server settings"));
+                                        return;
+                                    }
+                                    let name = name.clone();
+                                    let value = value.trim().to_string();
+                                    spawn(async move {
+                                        let confirmation = AsyncMessageDialog::new()
+                                            .set_title("Change Server Setting")
+                                            .set_description(format!(
+                                                "Set {name} to '{value}' with ALTER SYSTEM and reload the configuration? New sessions on the whole server use it."
+                                            ))
+                                            .set_buttons(MessageButtons::YesNo)
+                                            .set_level(MessageLevel::Warning)
+                                            .show()
+                                            .await;
+                                        if confirmation != MessageDialogResult::Yes {
+                                            return;
+                                        }
+
+                                        let Some(connection) = session_connection(session_id) else {
+                                            status.set("Connection is no longer open.".to_string());
+                                            return;
+                                        };
+
+                                        saving.set(true);
+                                        let result =
+                                            services::set_server_setting(connection, name.clone(), value).await;
+                                        saving.set(false);
+
+                                        match result {
+                                            Ok(()) => {
+                                                status.set(format!("Saved {name} and reloaded the configuration."));
+                                                editing.set(None);
+                                                reload += 1;
+                                            }
+                                            Err(err) => status.set(err.to_string()),
+                                        }
+                                    });
+                                }
+                            },
+                            "Save"
+                        }
+                        button {
+                            class: "button button--ghost button--small",
+                            disabled: saving(),
+                            onclick: move |_| editing.set(None),
+                            "Cancel"
+                        }
+                    }
+                } else {
+                    div {
+                        class: "server-settings__value",
+                        span { "{setting.display_value()}" }
+                        if can_edit && setting.is_user_settable() {
+                            button {
+                                class: "button button--ghost button--small",
+                                disabled: saving(),
+                                onclick: {
+                                    let name = setting.name.clone();
+                                    let value = setting.setting.clone();
+                                    move |_| {
+                                        status.set(String::new());
+                                        editing.set(Some((name.clone(), value.clone())));
+                                    }
+                                },
+                                "Edit"
+                            }
+                        }
+                    }
+                }
+            }
+            td { "{setting.source}" }
+            td { class: "server-settings__description", "{setting.short_desc}" }
+        }
+    }
+}
+
+/// Settings matching `search` in any text column, grouped by category in the
+/// order the server listed them.
+fn group_settings(
+    settings: &[ServerSetting],
+    search: &str,
+    only_modified: bool,
+) -> Vec<(String, Vec<ServerSetting>)> {
+    let search = search.trim().to_lowercase();
+    let mut groups: Vec<(String, Vec<ServerSetting>)> = Vec::new();
+    for setting in settings {
+        if only_modified && !setting.is_modified() {
+            continue;
+        }
+        if !search.is_empty()
+            && ![
+                &setting.name,
+                &setting.setting,
+                &setting.category,
+                &setting.short_desc,
+            ]
+            .iter()
+            .any(|text| text.to_lowercase().contains(&search))
+        {
+            continue;
+        }
+        match groups.last_mut() {
+            Some((category, members)) if *category == setting.category => {
+                members.push(setting.clone());
+            }
+            _ => groups.push((setting.category.clone(), vec![setting.clone()])),
+        }
+    }
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::group_settings;
+    use models::ServerSetting;
+
+    fn make_setting(name: &str, category: &str, source: &str) -> ServerSetting {
+        ServerSetting {
+            name: name.to_string(),
+            setting: "on".to_string(),
+            unit: None,
+            category: category.to_string(),
+            short_desc: format!("Controls {name}."),
+            source: source.to_string(),
+            context: "user".to_string(),
+        }
+    }
+
+    #[test]
+    fn group_settings_filters_and_keeps_category_order() {
+        let settings = vec![
+            make_setting("autovacuum", "Autovacuum", "default"),
+            make_setting(
+                "log_connections",
+                "Reporting and Logging",
+                "configuration file",
+            ),
+            make_setting("log_disconnections", "Reporting and Logging", "default"),
+        ];
+        let names = |groups: Vec<(String, Vec<ServerSetting>)>| {
+            groups
+                .into_iter()
+                .map(|(category, settings)| {
+                    let names = settings
+                        .into_iter()
+                        .map(|setting| setting.name)
+                        .collect::<Vec<_>>();
+                    (category, names)
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            names(group_settings(&settings, "", false)),
+            vec![
+                ("Autovacuum".to_string(), vec!["autovacuum".to_string()]),
+                (
+                    "Reporting and Logging".to_string(),
+                    vec![
+                        "log_connections".to_string(),
+                        "log_disconnections".to_string()
+                    ]
+                ),
+            ]
+        );
+        assert_eq!(
+            names(group_settings(&settings, "LOG_", true)),
+            vec![(
+                "Reporting and Logging".to_string(),
+                vec!["log_connections".to_string()]
+            )]
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use crate::app_state::{APP_STATE, activate_session, open_connection_screen, remove_session};
+use crate::components::environment_badge::EnvironmentBadge;
 use crate::screens::workspace::components::{ActionIcon, IconButton};
 use dioxus::prelude::*;
 use models::{ConnectionRequest, QueryTabState};
@@ -97,6 +98,9 @@ pub fn SessionRail(
                                     title: "{target_label}",
                                     "{target_label}"
                                 }
+                                if let Some(environment) = session.environment.clone() {
+                                    EnvironmentBadge { environment }
+                                }
                             }
                             IconButton {
                                 icon: ActionIcon::Close,
@@ -151,12 +155,12 @@ fn disconnect_session(
     mut active_tab_id: Signal<u64>,
     session_id: u64,
 ) {
-    tabs.with_mut(|all_tabs| all_tabs.retain(|tab| tab.session_id != session_id));
+    let removed_ids = remove_session(session_id);
+    tabs.with_mut(|all_tabs| all_tabs.retain(|tab| !removed_ids.contains(&tab.session_id)));
     if let Some(first_tab) = tabs.read().first() {
         active_tab_id.set(first_tab.id);
         activate_session(first_tab.session_id);
     } else {
         active_tab_id.set(0);
     }
-    remove_session(session_id);
 }
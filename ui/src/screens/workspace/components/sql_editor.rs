@@ -1,34 +1,69 @@
+#[path = "sql_editor/brackets.rs"]
+mod brackets;
+#[path = "sql_editor/case.rs"]
+mod case;
+#[path = "sql_editor/comment.rs"]
+mod comment;
+#[path = "sql_editor/context.rs"]
+mod context;
 #[path = "sql_editor/highlight.rs"]
 mod highlight;
+#[path = "sql_editor/indent.rs"]
+mod indent;
+#[path = "sql_editor/search.rs"]
+mod search;
 #[path = "sql_editor/selection.rs"]
 mod selection;
 
-use crate::app_state::{APP_UI_SETTINGS, toast_error};
+use crate::app_state::{
+    APP_COLUMN_INDEX, APP_SCHEMA_TABLE_NAMES, APP_TABLE_COLUMNS, APP_UI_SETTINGS,
+    session_connection, toast_error,
+};
 use crate::completion::CompletionService;
 use crate::completion::CompletionToken;
 use crate::screens::workspace::actions::{replace_active_tab_sql, sync_active_tab_sql_draft};
 use crate::screens::workspace::components::explorer::ExplorerConnectionSection;
 use dioxus::prelude::*;
-use models::{ExplorerNodeKind, QueryTabState};
+use models::{ExplorerNodeKind, QueryTabState, SchemaColumn};
 use std::time::Duration;
 
-use self::{
-    highlight::SqlHighlightContent,
-    selection::{
-        EditorSelection, current_token_range, editor_value_and_selection_query_script,
-        set_editor_value_script, sync_editor_selection, sync_editor_selection_debounced,
-    },
+use self::brackets::matching_bracket_pair;
+use self::case::change_keyword_case;
+use self::comment::toggle_line_comments;
+use self::context::{column_context_tables, completion_catalog, context_completion, typed_schema};
+pub(crate) use self::highlight::SqlHighlightContent;
+use self::highlight::keyword_ranges;
+use self::indent::indent_edit;
+use self::search::{
+    SEARCH_INPUT_ID, SearchBar, focus_search_input_script, match_at_or_after, replace_all,
+};
+use self::selection::{
+    EditorSelection, changed_range, current_token_range, editor_value_and_selection_query_script,
+    insert_editor_text_script, replace_editor_range_script, reveal_editor_range_script,
+    set_editor_value_and_selection_script, set_editor_value_script, sync_editor_selection,
+    sync_editor_selection_debounced,
 };
 
 const SQL_EDITOR_TEXTAREA_ID: &str = "workspace-sql-editor";
 const COMPLETION_DEBOUNCE_MS: u64 = 180;
 const HIGHLIGHT_IDLE_MS: u64 = 90;
 
+/// Inserts `text` at the SQL editor caret, replacing any selection. Returns
+/// `false` when the editor is not on screen.
+pub(crate) async fn insert_text_at_editor_cursor(text: &str) -> bool {
+    document::eval(&insert_editor_text_script(SQL_EDITOR_TEXTAREA_ID, text))
+        .join::<bool>()
+        .await
+        .unwrap_or(false)
+}
+
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 struct InlineCompletion {
     cursor: usize,
     source_sql: String,
     text: String,
+    /// Shown after the ghost text, e.g. the type of a completed column.
+    detail: Option<String>,
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
@@ -75,12 +110,14 @@ impl CompletionRuntime {
         cursor: usize,
         source_sql: String,
         text: String,
+        detail: Option<String>,
     ) {
         if self.finish_request(request_id, snapshot) {
             self.active = Some(InlineCompletion {
                 cursor,
                 source_sql,
                 text,
+                detail,
             });
         }
     }
@@ -118,6 +155,49 @@ fn is_completion_accept_key(event: &KeyboardEvent) -> bool {
     event.key() == Key::Tab || event.code() == Code::Tab
 }
 
+/// Editor shortcuts that rewrite the lines under the selection.
+#[derive(Clone, Copy)]
+enum LineEdit {
+    Indent { outdent: bool },
+    ToggleComment,
+    KeywordCase { upper: bool },
+}
+
+/// `Some(replace_mode)` for the find (Ctrl+F) and replace (Ctrl+H) shortcuts.
+fn search_shortcut(event: &KeyboardEvent) -> Option<bool> {
+    let modifiers = event.modifiers();
+    if !(modifiers.ctrl() || modifiers.meta()) {
+        return None;
+    }
+    match event.key() {
+        Key::Character(text) if text.eq_ignore_ascii_case("f") => Some(false),
+        Key::Character(text) if text.eq_ignore_ascii_case("h") => Some(true),
+        _ => None,
+    }
+}
+
+fn line_edit_for_key(event: &KeyboardEvent) -> Option<LineEdit> {
+    let modifiers = event.modifiers();
+    match event.key() {
+        Key::Tab => Some(LineEdit::Indent {
+            outdent: modifiers.shift(),
+        }),
+        Key::Character(text) if text == "/" && (modifiers.ctrl() || modifiers.meta()) => {
+            Some(LineEdit::ToggleComment)
+        }
+        Key::Character(text) if modifiers.shift() && (modifiers.ctrl() || modifiers.meta()) => {
+            if text.eq_ignore_ascii_case("u") {
+                Some(LineEdit::KeywordCase { upper: true })
+            } else if text.eq_ignore_ascii_case("l") {
+                Some(LineEdit::KeywordCase { upper: false })
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
 /// Returns true if the word looks like it starts a new SQL clause.
 fn is_sql_clause_start(word: &str) -> bool {
     matches!(
@@ -256,7 +336,13 @@ fn suffix_prefix_overlap_byte_len(suffix: &str, completion: &str) -> usize {
     best_overlap
 }
 
-fn build_schema_context(sections: &[ExplorerConnectionSection], session_id: u64) -> String {
+/// Tables and columns of the session for the completion prompt. Columns of
+/// tables never expanded in the tree come from the column index.
+fn build_schema_context(
+    sections: &[ExplorerConnectionSection],
+    column_index: Option<&[SchemaColumn]>,
+    session_id: u64,
+) -> String {
     let section = match sections.iter().find(|s| s.session_id == session_id) {
         Some(s) => s,
         None => return String::new(),
@@ -269,44 +355,43 @@ fn build_schema_context(sections: &[ExplorerConnectionSection], session_id: u64)
         if node.kind == ExplorerNodeKind::Schema {
             let schema_name = &node.name;
             for table in &node.children {
-                if table.kind == ExplorerNodeKind::Table || table.kind == ExplorerNodeKind::View {
+                if table.kind.is_relation() {
                     if !first_table {
                         lines.push(String::new());
                     }
                     first_table = false;
 
-                    let kind_label = if table.kind == ExplorerNodeKind::View {
-                        "View"
-                    } else {
-                        "Table"
-                    };
+                    let kind_label = table.kind.label();
 
                     let full_name = format!("{schema_name}.{}", table.name);
                     lines.push(format!("-- {kind_label}: {full_name}"));
 
-                    if !table.children.is_empty() {
-                        let cols: Vec<String> =
-                            table.children.iter().map(|col| col.name.clone()).collect();
+                    let cols: Vec<String> = if table.children.is_empty() {
+                        indexed_columns(column_index, Some(schema_name), &table.name)
+                    } else {
+                        table.children.iter().map(|col| col.name.clone()).collect()
+                    };
+                    if !cols.is_empty() {
                         lines.push(format!("--   Columns: {}", cols.join(", ")));
                     }
                 }
             }
-        } else if node.kind == ExplorerNodeKind::Table || node.kind == ExplorerNodeKind::View {
+        } else if node.kind.is_relation() {
             if !first_table {
                 lines.push(String::new());
             }
             first_table = false;
 
-            let kind_label = if node.kind == ExplorerNodeKind::View {
-                "View"
-            } else {
-                "Table"
-            };
+            let kind_label = node.kind.label();
 
             lines.push(format!("-- {kind_label}: {}", node.name));
 
-            if !node.children.is_empty() {
-                let cols: Vec<String> = node.children.iter().map(|col| col.name.clone()).collect();
+            let cols: Vec<String> = if node.children.is_empty() {
+                indexed_columns(column_index, node.schema.as_ref(), &node.name)
+            } else {
+                node.children.iter().map(|col| col.name.clone()).collect()
+            };
+            if !cols.is_empty() {
                 lines.push(format!("--   Columns: {}", cols.join(", ")));
             }
         }
@@ -321,6 +406,19 @@ fn build_schema_context(sections: &[ExplorerConnectionSection], session_id: u64)
     format!("{}\n", lines.join("\n"))
 }
 
+fn indexed_columns(
+    column_index: Option<&[SchemaColumn]>,
+    schema: Option<&String>,
+    table: &str,
+) -> Vec<String> {
+    column_index
+        .unwrap_or_default()
+        .iter()
+        .filter(|column| column.table_name == table && column.schema.as_ref() == schema)
+        .map(|column| column.column_name.clone())
+        .collect()
+}
+
 /// Extract a few lines of SQL that precede the cursor position — the
 /// "surrounding context" — so the LLM sees what kind of queries the user
 /// is writing, not just the single statement being completed.
@@ -368,6 +466,7 @@ pub fn SqlEditor(
     let mut completion_runtime = use_signal(CompletionRuntime::default);
     let mut has_synced_editor_dom = use_signal(|| false);
     let mut synced_editor_tab_id = use_signal(|| active_tab_id_value);
+    let mut search_bar = use_signal(|| None::<SearchBar>);
 
     let editor_offset = format!(
         "transform: translate(-{}px, -{}px);",
@@ -376,7 +475,27 @@ pub fn SqlEditor(
     );
 
     let schema_context = use_memo(use_reactive((&active_session_id,), move |(session_id,)| {
-        build_schema_context(&explorer_sections(), session_id)
+        build_schema_context(
+            &explorer_sections(),
+            APP_COLUMN_INDEX.read().get(&session_id).map(Vec::as_slice),
+            session_id,
+        )
+    }));
+
+    // Every column of the connection, loaded once so completions know tables
+    // that were never expanded in the tree.
+    use_effect(use_reactive((&active_session_id,), move |(session_id,)| {
+        if APP_COLUMN_INDEX.peek().contains_key(&session_id) {
+            return;
+        }
+        let Some(connection) = session_connection(session_id) else {
+            return;
+        };
+        spawn(async move {
+            if let Ok(columns) = services::load_column_index(connection).await {
+                APP_COLUMN_INDEX.write().insert(session_id, columns);
+            }
+        });
     }));
 
     use_effect(use_reactive(
@@ -479,25 +598,128 @@ pub fn SqlEditor(
         let settings = APP_UI_SETTINGS();
         let completion_service = CompletionService::new(&settings);
 
-        if completion_service.is_empty() {
-            invalidate_completion(completion_runtime);
-            return;
-        }
-
         spawn(async move {
             tokio::time::sleep(Duration::from_millis(COMPLETION_DEBOUNCE_MS)).await;
+            if editor_revision() != revision {
+                return;
+            }
 
-            // Read SQL from DOM (most accurate), fall back to signals.
-            let sql_text = if let Ok((sql, _, _)) = document::eval(
+            // Read SQL and caret from DOM (most accurate), fall back to signals.
+            let (sql_text, selection) = if let Ok((sql, start, end)) = document::eval(
                 &editor_value_and_selection_query_script(SQL_EDITOR_TEXTAREA_ID),
             )
             .join::<(String, usize, usize)>()
             .await
             {
-                sql
+                (sql, EditorSelection { start, end })
             } else {
-                draft_sql.peek().clone()
+                (draft_sql.peek().clone(), *editor_selection.peek())
+            };
+
+            let Some((cursor, prefix, suffix)) = completion_request_parts(&sql_text, selection)
+            else {
+                eprintln!("[completion] bail: no cursor (selection range)");
+                invalidate_completion(completion_runtime);
+                return;
             };
+            let sql_hash = hash_completion_snapshot(&sql_text, cursor);
+
+            // Table and column names the cursor position calls for come from
+            // the catalog without asking a provider.
+            let build_catalog = || {
+                let sections = explorer_sections.peek();
+                let column_index = APP_COLUMN_INDEX.peek();
+                let table_columns = APP_TABLE_COLUMNS.peek();
+                let schema_tables = APP_SCHEMA_TABLE_NAMES.peek();
+                completion_catalog(
+                    sections
+                        .iter()
+                        .find(|section| section.session_id == active_session_id),
+                    column_index.get(&active_session_id).map(Vec::as_slice),
+                    table_columns.get(&active_session_id),
+                    schema_tables.get(&active_session_id),
+                )
+            };
+            let mut catalog = build_catalog();
+
+            // `schema.` lists that schema's tables once, for tables created
+            // since the column index was loaded.
+            let unlisted_schema = typed_schema(&sql_text, cursor, &catalog).filter(|schema| {
+                APP_SCHEMA_TABLE_NAMES
+                    .peek()
+                    .get(&active_session_id)
+                    .is_none_or(|schemas| !schemas.contains_key(schema))
+            });
+            if let Some(schema) = unlisted_schema
+                && let Some(connection) = session_connection(active_session_id)
+            {
+                let names = services::load_schema_table_catalog(connection, schema.clone())
+                    .await
+                    .map(|tables| tables.into_iter().map(|table| table.name).collect())
+                    .unwrap_or_default();
+                APP_SCHEMA_TABLE_NAMES
+                    .write()
+                    .entry(active_session_id)
+                    .or_default()
+                    .insert(schema, names);
+                if editor_revision() != revision {
+                    return;
+                }
+                catalog = build_catalog();
+            }
+
+            // Tables whose columns the cursor calls for are described once
+            // each, for column types and for tables the index predates.
+            let undescribed = {
+                let table_columns = APP_TABLE_COLUMNS.peek();
+                let described = table_columns.get(&active_session_id);
+                column_context_tables(&sql_text, cursor, &catalog)
+                    .into_iter()
+                    .filter(|table| described.is_none_or(|tables| !tables.contains_key(table)))
+                    .collect::<Vec<_>>()
+            };
+            if !undescribed.is_empty()
+                && let Some(connection) = session_connection(active_session_id)
+            {
+                for (schema, table) in undescribed {
+                    let columns = services::load_table_column_info(
+                        connection.clone(),
+                        schema.clone(),
+                        table.clone(),
+                    )
+                    .await
+                    .unwrap_or_default();
+                    APP_TABLE_COLUMNS
+                        .write()
+                        .entry(active_session_id)
+                        .or_default()
+                        .insert((schema, table), columns);
+                }
+                if editor_revision() != revision {
+                    return;
+                }
+                catalog = build_catalog();
+            }
+
+            if let Some(completion) = context_completion(&sql_text, cursor, &catalog) {
+                completion_runtime.with_mut(|state| {
+                    let request_id = state.begin_request(sql_hash);
+                    state.set_active(
+                        request_id,
+                        sql_hash,
+                        cursor,
+                        sql_text.clone(),
+                        completion.text,
+                        completion.detail,
+                    );
+                });
+                return;
+            }
+
+            if completion_service.is_empty() {
+                invalidate_completion(completion_runtime);
+                return;
+            }
 
             if sql_text.len() < 3 {
                 eprintln!(
@@ -508,17 +730,6 @@ pub fn SqlEditor(
                 return;
             }
 
-            // Complete at the end of the SQL — most reliable position.
-            let cursor = sql_text.len();
-            let selection = EditorSelection::collapsed(cursor);
-
-            let Some((cursor, prefix, suffix)) = completion_request_parts(&sql_text, selection)
-            else {
-                eprintln!("[completion] bail: no cursor (selection range)");
-                invalidate_completion(completion_runtime);
-                return;
-            };
-
             // Re-check settings after debounce (they may have changed).
             if CompletionService::new(&APP_UI_SETTINGS()).is_empty() {
                 eprintln!("[completion] bail: settings changed, no providers");
@@ -526,7 +737,6 @@ pub fn SqlEditor(
                 return;
             }
 
-            let sql_hash = hash_completion_snapshot(&sql_text, cursor);
             let completion_snapshot = completion_runtime.peek().clone();
             if completion_snapshot.last_completed_snapshot == Some(sql_hash)
                 && completion_snapshot.pending_snapshot.is_none()
@@ -579,6 +789,7 @@ pub fn SqlEditor(
                                     cursor,
                                     source_sql: sql_for_result.clone(),
                                     text: accumulated.clone(),
+                                    detail: None,
                                 });
                             });
                         }
@@ -612,6 +823,7 @@ pub fn SqlEditor(
                                     cursor,
                                     sql_for_result.clone(),
                                     accumulated,
+                                    None,
                                 );
                             });
                         }
@@ -654,6 +866,164 @@ pub fn SqlEditor(
         .as_ref()
         .is_some_and(|completion| !completion.is_empty());
     let inline_cursor_position = completion_active.then_some(inline_cursor);
+    let inline_detail = render_completion
+        .filter(|_| completion_active)
+        .and_then(|completion| completion.detail.clone());
+    let selection = editor_selection();
+    let matched_brackets = (!typing_now && !completion_active && selection.start == selection.end)
+        .then(|| matching_bracket_pair(&current_sql, selection.end))
+        .flatten();
+
+    let search = search_bar();
+    let search_matches = match search.as_ref() {
+        Some(bar) if !typing_now => bar.matches(&current_sql),
+        _ => Vec::new(),
+    };
+    let current_search_match = search
+        .as_ref()
+        .filter(|_| !search_matches.is_empty())
+        .map(|bar| bar.current.min(search_matches.len() - 1));
+    let search_count = match (current_search_match, search.as_ref()) {
+        (Some(index), _) => format!("{} of {}", index + 1, search_matches.len()),
+        (None, Some(bar)) if !bar.query.is_empty() && !typing_now => "No results".to_string(),
+        _ => String::new(),
+    };
+
+    // Selects match `index` of the current search in the editor, keeping the
+    // find bar focused.
+    let mut reveal_search_match = move |index: usize| {
+        let Some(bar) = search_bar.peek().clone() else {
+            return;
+        };
+        let matches = bar.matches(&draft_sql.peek());
+        let Some(found) = matches.get(index).cloned() else {
+            return;
+        };
+        search_bar.with_mut(|bar| {
+            if let Some(bar) = bar {
+                bar.current = index;
+            }
+        });
+        editor_selection.set(EditorSelection {
+            start: found.start,
+            end: found.end,
+        });
+        spawn(async move {
+            let _ = document::eval(&reveal_editor_range_script(
+                SQL_EDITOR_TEXTAREA_ID,
+                found.start,
+                found.end,
+                true,
+            ))
+            .join::<bool>()
+            .await;
+        });
+    };
+    // Moves to the match after (or before) the current one, wrapping around.
+    let mut step_search_match = move |backwards: bool| {
+        let Some(bar) = search_bar.peek().clone() else {
+            return;
+        };
+        let count = bar.matches(&draft_sql.peek()).len();
+        if count == 0 {
+            return;
+        }
+        let current = bar.current.min(count - 1);
+        let next = if backwards {
+            (current + count - 1) % count
+        } else {
+            (current + 1) % count
+        };
+        reveal_search_match(next);
+    };
+    // After the query or an option changes, the current match is the first one
+    // from the caret on.
+    let mut update_search = move |update: &dyn Fn(&mut SearchBar)| {
+        let Some(mut bar) = search_bar.peek().clone() else {
+            return;
+        };
+        update(&mut bar);
+        let matches = bar.matches(&draft_sql.peek());
+        let index = match_at_or_after(&matches, editor_selection.peek().start);
+        bar.current = index;
+        search_bar.set(Some(bar));
+        reveal_search_match(index);
+    };
+    let mut close_search = move || {
+        search_bar.set(None);
+        let selection = *editor_selection.peek();
+        spawn(async move {
+            let _ = document::eval(&reveal_editor_range_script(
+                SQL_EDITOR_TEXTAREA_ID,
+                selection.start,
+                selection.end,
+                false,
+            ))
+            .join::<bool>()
+            .await;
+        });
+    };
+    let mut apply_search_edit = move |next_sql: String, next_selection: EditorSelection| {
+        draft_sql.set(next_sql.clone());
+        editor_selection.set(next_selection);
+        invalidate_active_completion(completion_runtime);
+        editor_revision += 1;
+        sync_active_tab_sql_draft(tabs, active_tab_id_value, next_sql.clone());
+        spawn(async move {
+            let _ = document::eval(&set_editor_value_and_selection_script(
+                SQL_EDITOR_TEXTAREA_ID,
+                &next_sql,
+                next_selection.start,
+                next_selection.end,
+            ))
+            .join::<bool>()
+            .await;
+        });
+    };
+    let mut replace_current_match = move || {
+        let Some(bar) = search_bar.peek().clone() else {
+            return;
+        };
+        let sql_text = draft_sql.peek().clone();
+        let matches = bar.matches(&sql_text);
+        let Some(found) = matches.get(bar.current.min(matches.len().saturating_sub(1))) else {
+            return;
+        };
+        let caret = found.start + bar.replacement.len();
+        let next_sql = format!(
+            "{}{}{}",
+            &sql_text[..found.start],
+            bar.replacement,
+            &sql_text[found.end..]
+        );
+        apply_search_edit(next_sql.clone(), EditorSelection::collapsed(caret));
+        let next_matches = bar.matches(&next_sql);
+        if !next_matches.is_empty() {
+            reveal_search_match(match_at_or_after(&next_matches, caret));
+        }
+    };
+    let mut replace_all_matches = move || {
+        let Some(bar) = search_bar.peek().clone() else {
+            return;
+        };
+        let sql_text = draft_sql.peek().clone();
+        let matches = bar.matches(&sql_text);
+        if matches.is_empty() {
+            return;
+        }
+        let (next_sql, caret) = replace_all(
+            &sql_text,
+            &matches,
+            &bar.replacement,
+            editor_selection.peek().end,
+        );
+        apply_search_edit(next_sql, EditorSelection::collapsed(caret));
+        search_bar.with_mut(|bar| {
+            if let Some(bar) = bar {
+                bar.current = 0;
+            }
+        });
+    };
 
     rsx! {
         div {
@@ -670,6 +1040,10 @@ pub fn SqlEditor(
                             sql: current_sql.clone(),
                             inline_cursor_position,
                             inline_suffix,
+                            inline_detail,
+                            matched_brackets,
+                            search_matches: search_matches.clone(),
+                            current_search_match,
                         }
                     }
                 }
@@ -712,15 +1086,42 @@ pub fn SqlEditor(
                         completion_state.active.clone()
                     };
 
-                    if is_completion_accept_key(&event)
+                    if let Some(replace_mode) = search_shortcut(&event) {
+                        event.prevent_default();
+                        spawn(async move {
+                            // A single-line selection becomes the search text.
+                            let selected = document::eval(
+                                &editor_value_and_selection_query_script(SQL_EDITOR_TEXTAREA_ID),
+                            )
+                            .join::<(String, usize, usize)>()
+                            .await
+                            .ok()
+                            .and_then(|(sql_text, start, end)| {
+                                sql_text.get(start..end).map(str::to_string)
+                            })
+                            .filter(|text| !text.is_empty() && !text.contains('\n'));
+                            let mut bar = search_bar.peek().clone().unwrap_or_default();
+                            bar.replace_mode = replace_mode;
+                            if let Some(selected) = selected {
+                                bar.query = selected;
+                            }
+                            let matches = bar.matches(&draft_sql.peek());
+                            bar.current = match_at_or_after(&matches, editor_selection.peek().start);
+                            search_bar.set(Some(bar));
+                            let _ = document::eval(&focus_search_input_script())
+                                .join::<bool>()
+                                .await;
+                        });
+                    } else if is_completion_accept_key(&event)
                         && let Some(completion_state) = active_completion.clone()
                         && !completion_state.text.is_empty()
                     {
                         event.prevent_default();
                         let completion_text_raw = completion_state.text.clone();
                         spawn(async move {
-                        // Read current SQL from DOM, always complete at the end.
-                        let actual_sql = if let Ok((sql, _, _)) = document::eval(
+                        // Read current SQL and caret from DOM; the completion
+                        // was computed for the caret, wherever it is.
+                        let (actual_sql, cursor) = if let Ok((sql, _, end)) = document::eval(
                             &editor_value_and_selection_query_script(
                                 SQL_EDITOR_TEXTAREA_ID,
                             ),
@@ -728,11 +1129,14 @@ pub fn SqlEditor(
                         .join::<(String, usize, usize)>()
                         .await
                         {
-                            sql
+                            (sql, end)
                         } else {
-                            draft_sql.peek().clone()
+                            (draft_sql.peek().clone(), completion_state.cursor)
                         };
-                        let cursor = actual_sql.len();
+                        let cursor = cursor.min(actual_sql.len());
+                        if !actual_sql.is_char_boundary(cursor) {
+                            return;
+                        }
                         let mut completion_text = trim_completion_for_cursor(
                             &actual_sql,
                             cursor,
@@ -794,6 +1198,58 @@ pub fn SqlEditor(
                             .await;
                         });
                     });
+                    } else if let Some(line_edit) = line_edit_for_key(&event) {
+                        event.prevent_default();
+                        let width = usize::from(APP_UI_SETTINGS.peek().editor_indent_width);
+                        spawn(async move {
+                            let Ok((sql_text, start, end)) = document::eval(
+                                &editor_value_and_selection_query_script(SQL_EDITOR_TEXTAREA_ID),
+                            )
+                            .join::<(String, usize, usize)>()
+                            .await
+                            else {
+                                return;
+                            };
+                            let selection = EditorSelection { start, end };
+                            let (next_sql, next_selection) = match line_edit {
+                                LineEdit::Indent { outdent } => {
+                                    indent_edit(&sql_text, selection, width, outdent)
+                                }
+                                LineEdit::ToggleComment => {
+                                    toggle_line_comments(&sql_text, selection)
+                                }
+                                LineEdit::KeywordCase { upper } => (
+                                    change_keyword_case(
+                                        &sql_text,
+                                        selection,
+                                        &keyword_ranges(&sql_text),
+                                        upper,
+                                    ),
+                                    selection,
+                                ),
+                            };
+                            if next_sql == sql_text {
+                                return;
+                            }
+                            draft_sql.set(next_sql.clone());
+                            editor_selection.set(next_selection);
+                            invalidate_active_completion(completion_runtime);
+                            editor_revision += 1;
+                            sync_active_tab_sql_draft(tabs, active_tab_id_value, next_sql.clone());
+                            // Only the changed span is typed over, so the
+                            // edit is one step of the editor's own undo.
+                            let (replaced, inserted) = changed_range(&sql_text, &next_sql);
+                            let _ = document::eval(&replace_editor_range_script(
+                                SQL_EDITOR_TEXTAREA_ID,
+                                replaced.start,
+                                replaced.end,
+                                &next_sql[inserted],
+                                next_selection.start,
+                                next_selection.end,
+                            ))
+                            .join::<bool>()
+                            .await;
+                        });
                     }
                 },
 
@@ -833,6 +1289,119 @@ pub fn SqlEditor(
                     scroll_left.set(event.data().scroll_left());
                 },
             }
+
+            if let Some(bar) = search {
+                div {
+                    class: "sql-editor__search",
+                    div {
+                        class: "sql-editor__search-row",
+                        input {
+                            id: SEARCH_INPUT_ID,
+                            class: "input sql-editor__search-input",
+                            placeholder: "Find",
+                            spellcheck: "false",
+                            value: "{bar.query}",
+                            oninput: move |event| {
+                                let query = event.value();
+                                update_search(&|bar| bar.query = query.clone());
+                            },
+                            onkeydown: move |event| match event.key() {
+                                Key::Enter => {
+                                    event.prevent_default();
+                                    step_search_match(event.modifiers().shift());
+                                }
+                                Key::Escape => {
+                                    event.prevent_default();
+                                    close_search();
+                                }
+                                _ => {}
+                            },
+                        }
+                        span { class: "sql-editor__search-count", "{search_count}" }
+                        button {
+                            class: if bar.case_sensitive {
+                                "button button--ghost button--small sql-editor__search-toggle sql-editor__search-toggle--active"
+                            } else {
+                                "button button--ghost button--small sql-editor__search-toggle"
+                            },
+                            title: "Match case",
+                            onclick: move |_| update_search(&|bar| bar.case_sensitive = !bar.case_sensitive),
+                            "Aa"
+                        }
+                        button {
+                            class: if bar.whole_word {
+                                "button button--ghost button--small sql-editor__search-toggle sql-editor__search-toggle--active"
+                            } else {
+                                "button button--ghost button--small sql-editor__search-toggle"
+                            },
+                            title: "Match whole word",
+                            onclick: move |_| update_search(&|bar| bar.whole_word = !bar.whole_word),
+                            "ab"
+                        }
+                        button {
+                            class: "button button--ghost button--small",
+                            title: "Previous match (Shift+Enter)",
+                            disabled: search_matches.is_empty(),
+                            onclick: move |_| step_search_match(true),
+                            "↑"
+                        }
+                        button {
+                            class: "button button--ghost button--small",
+                            title: "Next match (Enter)",
+                            disabled: search_matches.is_empty(),
+                            onclick: move |_| step_search_match(false),
+                            "↓"
+                        }
+                        button {
+                            class: "button button--ghost button--small",
+                            title: "Close (Escape)",
+                            onclick: move |_| close_search(),
+                            "×"
+                        }
+                    }
+                    if bar.replace_mode {
+                        div {
+                            class: "sql-editor__search-row",
+                            input {
+                                class: "input sql-editor__search-input",
+                                placeholder: "Replace",
+                                spellcheck: "false",
+                                value: "{bar.replacement}",
+                                oninput: move |event| {
+                                    search_bar.with_mut(|bar| {
+                                        if let Some(bar) = bar {
+                                            bar.replacement = event.value();
+                                        }
+                                    });
+                                },
+                                onkeydown: move |event| match event.key() {
+                                    Key::Enter => {
+                                        event.prevent_default();
+                                        replace_current_match();
+                                    }
+                                    Key::Escape => {
+                                        event.prevent_default();
+                                        close_search();
+                                    }
+                                    _ => {}
+                                },
+                            }
+                            button {
+                                class: "button button--ghost button--small",
+                                disabled: search_matches.is_empty(),
+                                onclick: move |_| replace_current_match(),
+                                "Replace"
+                            }
+                            button {
+                                class: "button button--ghost button--small",
+                                disabled: search_matches.is_empty(),
+                                onclick: move |_| replace_all_matches(),
+                                "Replace all"
+                            }
+                        }
+                    }
+                }
+            }
         }
     }
 }
@@ -0,0 +1,88 @@
+use super::highlight::literal_end;
+
+/// Byte offsets of the bracket touching `cursor` and its partner, ordered
+/// opening first. The bracket just before the cursor wins over the one after
+/// it; brackets inside strings and comments are ignored.
+pub(super) fn matching_bracket_pair(sql: &str, cursor: usize) -> Option<(usize, usize)> {
+    let pairs = bracket_pairs(sql);
+    [cursor.checked_sub(1), Some(cursor)]
+        .into_iter()
+        .flatten()
+        .find_map(|position| {
+            pairs
+                .iter()
+                .find(|(open, close)| *open == position || *close == position)
+                .copied()
+        })
+}
+
+/// Balanced `()` and `[]` pairs outside literals. A closing bracket that does
+/// not match the innermost open one is left unpaired.
+fn bracket_pairs(sql: &str) -> Vec<(usize, usize)> {
+    let bytes = sql.as_bytes();
+    let mut pairs = Vec::new();
+    let mut open = Vec::<(usize, u8)>::new();
+    let mut index = 0;
+
+    while index < bytes.len() {
+        if let Some(end) = literal_end(sql, index) {
+            index = end;
+            continue;
+        }
+        match bytes[index] {
+            byte @ (b'(' | b'[') => open.push((index, byte)),
+            byte @ (b')' | b']') => {
+                let expected = if byte == b')' { b'(' } else { b'[' };
+                if let Some(&(start, opening)) = open.last()
+                    && opening == expected
+                {
+                    open.pop();
+                    pairs.push((start, index));
+                }
+            }
+            _ => {}
+        }
+        index += 1;
+    }
+
+    pairs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::matching_bracket_pair;
+
+    #[test]
+    fn cursor_next_to_a_bracket_finds_its_partner() {
+        let sql = "select count(*) from (select a[1] from t) s";
+        let open = sql.find("(select").unwrap();
+        let close = sql.rfind(')').unwrap();
+
+        assert_eq!(matching_bracket_pair(sql, open), Some((open, close)));
+        assert_eq!(matching_bracket_pair(sql, close + 1), Some((open, close)));
+        assert_eq!(
+            matching_bracket_pair(sql, sql.find('[').unwrap()),
+            Some((sql.find('[').unwrap(), sql.find(']').unwrap()))
+        );
+        assert_eq!(matching_bracket_pair(sql, 3), None);
+    }
+
+    #[test]
+    fn brackets_inside_literals_are_skipped() {
+        let sql = "select (')' || \"a(\" || $$ ) $$) -- )\n";
+        let open = sql.find('(').unwrap();
+        let close = sql.find("$$)").unwrap() + 2;
+
+        assert_eq!(matching_bracket_pair(sql, open), Some((open, close)));
+        assert_eq!(
+            matching_bracket_pair(sql, sql.find("')'").unwrap() + 1),
+            None
+        );
+    }
+
+    #[test]
+    fn mismatched_brackets_are_left_unpaired() {
+        assert_eq!(matching_bracket_pair("select (a]", 7), None);
+        assert_eq!(matching_bracket_pair("select (a", 7), None);
+    }
+}
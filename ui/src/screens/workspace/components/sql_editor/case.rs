@@ -0,0 +1,85 @@
+use super::indent::touched_lines;
+use super::selection::EditorSelection;
+use std::ops::Range;
+
+/// Applies Ctrl+Shift+U (`upper`) or Ctrl+Shift+L to the editor text.
+///
+/// Every keyword range in `keywords` that overlaps the selection, or the
+/// cursor's line when nothing is selected, is upper- or lowercased as a whole.
+/// Identifiers, literals and comments are never in `keywords`, so they keep
+/// their case. Keywords are ASCII, so no byte offset moves.
+pub(super) fn change_keyword_case(
+    sql: &str,
+    selection: EditorSelection,
+    keywords: &[Range<usize>],
+    upper: bool,
+) -> String {
+    let selection = selection.clamped(sql);
+    let start = selection.start.min(selection.end);
+    let end = selection.start.max(selection.end);
+    let scope = if start == end {
+        touched_lines(sql, start, end)
+    } else {
+        start..end
+    };
+
+    let mut changed = sql.to_string();
+    for keyword in keywords
+        .iter()
+        .filter(|keyword| keyword.start < scope.end && keyword.end > scope.start)
+        .filter(|keyword| {
+            sql.get(keyword.start..keyword.end)
+                .is_some_and(|text| text.is_ascii())
+        })
+    {
+        let text = &sql[keyword.clone()];
+        let cased = if upper {
+            text.to_ascii_uppercase()
+        } else {
+            text.to_ascii_lowercase()
+        };
+        changed.replace_range(keyword.clone(), &cased);
+    }
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EditorSelection, Range, change_keyword_case};
+
+    fn keywords(sql: &str, words: &[&str]) -> Vec<Range<usize>> {
+        let mut ranges = Vec::new();
+        let mut from = 0;
+        for word in words {
+            let start = from + sql[from..].find(word).unwrap();
+            ranges.push(start..start + word.len());
+            from = start + word.len();
+        }
+        ranges
+    }
+
+    #[test]
+    fn only_keywords_inside_the_selection_change_case() {
+        let sql = "select name from users where note = 'select' and id in (1)";
+        let ranges = keywords(sql, &["select", "from", "where", "and", "in"]);
+        let selection = EditorSelection {
+            start: 0,
+            end: sql.find("where").unwrap() + 2,
+        };
+        assert_eq!(
+            change_keyword_case(sql, selection, &ranges, true),
+            "SELECT name FROM users WHERE note = 'select' and id in (1)"
+        );
+    }
+
+    #[test]
+    fn a_collapsed_cursor_changes_its_whole_line() {
+        let sql = "SELECT id\nFROM Users\nWHERE id = 1";
+        let ranges = keywords(sql, &["SELECT", "FROM", "WHERE"]);
+        let cursor = EditorSelection::collapsed(sql.find("Users").unwrap());
+        assert_eq!(
+            change_keyword_case(sql, cursor, &ranges, false),
+            "SELECT id\nfrom Users\nWHERE id = 1"
+        );
+    }
+}
@@ -0,0 +1,112 @@
+use super::indent::touched_lines;
+use super::selection::EditorSelection;
+
+/// Applies Ctrl+/ to the editor text.
+///
+/// When every non-blank line the selection touches already starts with `--`
+/// (after its indentation), the marker and one following space are removed;
+/// otherwise `-- ` is prepended to each non-blank line. A collapsed cursor
+/// stays on the same character, a selection stays selected as whole lines.
+pub(super) fn toggle_line_comments(
+    sql: &str,
+    selection: EditorSelection,
+) -> (String, EditorSelection) {
+    let selection = selection.clamped(sql);
+    let start = selection.start.min(selection.end);
+    let end = selection.start.max(selection.end);
+    let lines = touched_lines(sql, start, end);
+    let (block_start, block_end) = (lines.start, lines.end);
+    let block = &sql[block_start..block_end];
+
+    let uncomment = block
+        .split('\n')
+        .filter(|line| !line.trim().is_empty())
+        .all(|line| line.trim_start().starts_with("--"))
+        && !block.trim().is_empty();
+
+    let mut cursor_shift = 0isize;
+    let edited = block
+        .split('\n')
+        .enumerate()
+        .map(|(index, line)| {
+            if line.trim().is_empty() {
+                return line.to_string();
+            }
+            let indent = line.len() - line.trim_start().len();
+            let (next_line, shift) = if uncomment {
+                let marker_len = if line[indent..].starts_with("-- ") {
+                    3
+                } else {
+                    2
+                };
+                let next_line = format!("{}{}", &line[..indent], &line[indent + marker_len..]);
+                let cursor_in_line = start - block_start;
+                let removed = marker_len.min(cursor_in_line.saturating_sub(indent));
+                (next_line, -(removed as isize))
+            } else {
+                (format!("-- {line}"), 3)
+            };
+            if index == 0 {
+                cursor_shift = shift;
+            }
+            next_line
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let next_sql = format!("{}{edited}{}", &sql[..block_start], &sql[block_end..]);
+    let next_selection = if start == end {
+        EditorSelection::collapsed(start.saturating_add_signed(cursor_shift))
+    } else {
+        EditorSelection {
+            start: block_start,
+            end: block_start + edited.len(),
+        }
+    };
+    (next_sql, next_selection)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EditorSelection, toggle_line_comments};
+
+    #[test]
+    fn comments_the_cursor_line_and_keeps_the_cursor_in_place() {
+        let sql = "select 1;\nselect 2;";
+        let (next, selection) = toggle_line_comments(sql, EditorSelection::collapsed(12));
+
+        assert_eq!(next, "select 1;\n-- select 2;");
+        assert_eq!(selection, EditorSelection::collapsed(15));
+    }
+
+    #[test]
+    fn selection_is_commented_unless_every_line_already_is() {
+        let sql = "-- select a,\n\n  b\nfrom t";
+        let selection = EditorSelection {
+            start: 0,
+            end: "-- select a,\n\n  b".len(),
+        };
+
+        let (next, selection) = toggle_line_comments(sql, selection);
+        assert_eq!(next, "-- -- select a,\n\n--   b\nfrom t");
+
+        let (next, _) = toggle_line_comments(&next, selection);
+        assert_eq!(next, "-- select a,\n\n  b\nfrom t");
+    }
+
+    #[test]
+    fn uncomment_keeps_indentation_and_accepts_a_bare_marker() {
+        let sql = "  -- select 1\n--x";
+        let selection = EditorSelection {
+            start: 0,
+            end: sql.len(),
+        };
+
+        let (next, _) = toggle_line_comments(sql, selection);
+        assert_eq!(next, "  select 1\nx");
+
+        let (next, selection) = toggle_line_comments("  -- a", EditorSelection::collapsed(6));
+        assert_eq!(next, "  a");
+        assert_eq!(selection, EditorSelection::collapsed(3));
+    }
+}
@@ -0,0 +1,779 @@
+use super::highlight::literal_end;
+use crate::app_state::TableColumnCache;
+use crate::screens::workspace::components::explorer::ExplorerConnectionSection;
+use models::{ExplorerNodeKind, SchemaColumn};
+use std::collections::HashMap;
+
+/// A table the editor can complete, with whatever columns are known for it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(super) struct CompletionTable {
+    pub(super) schema: Option<String>,
+    pub(super) name: String,
+    pub(super) columns: Vec<CompletionColumn>,
+}
+
+/// A column name and, once the table has been described, its type.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(super) struct CompletionColumn {
+    pub(super) name: String,
+    pub(super) data_type: Option<String>,
+}
+
+impl CompletionColumn {
+    fn untyped(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            data_type: None,
+        }
+    }
+}
+
+impl CompletionTable {
+    fn qualified_name(&self) -> String {
+        match &self.schema {
+            Some(schema) => format!("{schema}.{}", self.name),
+            None => self.name.clone(),
+        }
+    }
+}
+
+/// A completed token and a hint to show beside it, such as a column's type.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(super) struct ContextCompletion {
+    pub(super) text: String,
+    pub(super) detail: Option<String>,
+}
+
+/// Tables of one connection from its column index, plus relations of the
+/// explorer tree and schema listings the index does not list yet. Tables
+/// described on demand replace their index entry, so their columns carry
+/// types.
+pub(super) fn completion_catalog(
+    section: Option<&ExplorerConnectionSection>,
+    column_index: Option<&[SchemaColumn]>,
+    table_columns: Option<&TableColumnCache>,
+    schema_tables: Option<&HashMap<String, Vec<String>>>,
+) -> Vec<CompletionTable> {
+    let mut tables: Vec<CompletionTable> = Vec::new();
+    for column in column_index.unwrap_or_default() {
+        match tables.last_mut() {
+            Some(table) if table.name == column.table_name && table.schema == column.schema => {
+                table
+                    .columns
+                    .push(CompletionColumn::untyped(&column.column_name));
+            }
+            _ => tables.push(CompletionTable {
+                schema: column.schema.clone(),
+                name: column.table_name.clone(),
+                columns: vec![CompletionColumn::untyped(&column.column_name)],
+            }),
+        }
+    }
+
+    for ((schema, name), columns) in table_columns.into_iter().flatten() {
+        if columns.is_empty() {
+            continue;
+        }
+        let columns = columns
+            .iter()
+            .map(|column| CompletionColumn {
+                name: column.name.clone(),
+                data_type: Some(column.data_type.clone()),
+            })
+            .collect::<Vec<_>>();
+        match tables
+            .iter_mut()
+            .find(|table| table.name == *name && table.schema == *schema)
+        {
+            Some(table) => table.columns = columns,
+            None => tables.push(CompletionTable {
+                schema: schema.clone(),
+                name: name.clone(),
+                columns,
+            }),
+        }
+    }
+
+    for (schema, names) in schema_tables.into_iter().flatten() {
+        for name in names {
+            let known = tables
+                .iter()
+                .any(|table| table.name == *name && table.schema.as_ref() == Some(schema));
+            if !known {
+                tables.push(CompletionTable {
+                    schema: Some(schema.clone()),
+                    name: name.clone(),
+                    columns: Vec::new(),
+                });
+            }
+        }
+    }
+
+    let Some(section) = section else {
+        return tables;
+    };
+    for node in &section.nodes {
+        let (schema, relations) = if node.kind == ExplorerNodeKind::Schema {
+            (Some(node.name.clone()), node.children.as_slice())
+        } else {
+            (node.schema.clone(), std::slice::from_ref(node))
+        };
+        for relation in relations
+            .iter()
+            .filter(|relation| relation.kind.is_relation())
+        {
+            let known = tables
+                .iter()
+                .any(|table| table.name == relation.name && table.schema == schema);
+            if !known {
+                tables.push(CompletionTable {
+                    schema: schema.clone(),
+                    name: relation.name.clone(),
+                    columns: relation
+                        .children
+                        .iter()
+                        .map(|column| CompletionColumn::untyped(&column.name))
+                        .collect(),
+                });
+            }
+        }
+    }
+    tables
+}
+
+/// Keywords after which a table name is expected.
+const TABLE_KEYWORDS: [&str; 5] = ["from", "join", "update", "into", "table"];
+/// Clauses that take column names.
+const COLUMN_CLAUSES: [&str; 7] = ["select", "where", "on", "by", "having", "set", "returning"];
+/// Clauses that end a column context when they are the nearest one.
+const OTHER_CLAUSES: [&str; 6] = ["from", "join", "into", "update", "values", "limit"];
+/// Words that can follow a table reference but are never its alias.
+const NOT_ALIASES: [&str; 24] = [
+    "where",
+    "join",
+    "on",
+    "using",
+    "left",
+    "right",
+    "inner",
+    "outer",
+    "full",
+    "cross",
+    "natural",
+    "lateral",
+    "group",
+    "order",
+    "having",
+    "limit",
+    "offset",
+    "union",
+    "except",
+    "intersect",
+    "set",
+    "values",
+    "returning",
+    "window",
+];
+
+/// A word or punctuation character of the statement, outside literals.
+#[derive(Clone, Debug, PartialEq)]
+struct Token<'a> {
+    start: usize,
+    text: &'a str,
+}
+
+impl Token<'_> {
+    fn is(&self, keyword: &str) -> bool {
+        self.text.eq_ignore_ascii_case(keyword)
+    }
+
+    fn is_word(&self) -> bool {
+        self.text.bytes().next().is_some_and(is_word_byte)
+    }
+}
+
+/// A table named in the statement and the alias it goes by, if any.
+#[derive(Clone, Debug, PartialEq)]
+struct TableReference {
+    name: String,
+    alias: Option<String>,
+}
+
+/// What the statement around the cursor asks for: the partly typed token,
+/// whether a table or column name belongs there, and the tables the
+/// statement references.
+struct CursorContext<'a> {
+    typed: &'a str,
+    after_table_keyword: bool,
+    in_column_clause: bool,
+    references: Vec<TableReference>,
+}
+
+/// How far the dotted name under the cursor has got, read one `.` at a time:
+/// a bare name, `qualifier.` where the qualifier is an alias, a table or a
+/// schema, or `schema.table.`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum TypedName<'a> {
+    Bare,
+    Qualified { qualifier: &'a str },
+    SchemaTable { schema: &'a str, table: &'a str },
+}
+
+/// The state `typed` ends in and the length of its part up to the last dot,
+/// or `None` for an empty part or more than two dots.
+fn typed_name(typed: &str) -> Option<(TypedName<'_>, usize)> {
+    let mut state = TypedName::Bare;
+    let mut part_start = 0;
+    for (index, byte) in typed.bytes().enumerate() {
+        if byte != b'.' {
+            continue;
+        }
+        let part = &typed[part_start..index];
+        if part.is_empty() {
+            return None;
+        }
+        state = match state {
+            TypedName::Bare => TypedName::Qualified { qualifier: part },
+            TypedName::Qualified { qualifier } => TypedName::SchemaTable {
+                schema: qualifier,
+                table: part,
+            },
+            TypedName::SchemaTable { .. } => return None,
+        };
+        part_start = index + 1;
+    }
+    Some((state, part_start))
+}
+
+/// The context at `cursor`, or `None` when it is inside a literal or
+/// comment.
+fn cursor_context(sql: &str, cursor: usize) -> Option<CursorContext<'_>> {
+    let cursor = cursor.min(sql.len());
+    if !sql.is_char_boundary(cursor) {
+        return None;
+    }
+    let token_start = sql[..cursor]
+        .bytes()
+        .rposition(|byte| !is_word_byte(byte) && byte != b'.')
+        .map_or(0, |index| index + 1);
+    let typed = &sql[token_start..cursor];
+    let statement_start = sql[..token_start].rfind(';').map_or(0, |index| index + 1);
+    let statement_end = sql[cursor..]
+        .find(';')
+        .map_or(sql.len(), |index| cursor + index);
+    if inside_literal(sql, statement_start, cursor) {
+        return None;
+    }
+    let tokens = tokenize(sql, statement_start, statement_end);
+    let before = tokens
+        .iter()
+        .filter(|token| token.start < token_start)
+        .collect::<Vec<_>>();
+    let nearest_clause = before.iter().rev().find(|token| {
+        COLUMN_CLAUSES
+            .iter()
+            .chain(OTHER_CLAUSES.iter())
+            .any(|keyword| token.is(keyword))
+    });
+    let after_table_keyword = before.last().is_some_and(|token| {
+        TABLE_KEYWORDS.iter().any(|keyword| token.is(keyword))
+            || (token.is(",") && nearest_clause.is_some_and(|clause| clause.is("from")))
+    });
+    let in_column_clause = nearest_clause
+        .is_some_and(|clause| COLUMN_CLAUSES.iter().any(|keyword| clause.is(keyword)));
+    Some(CursorContext {
+        typed,
+        after_table_keyword,
+        in_column_clause,
+        references: table_references(&tokens),
+    })
+}
+
+/// The token under the cursor completed from `catalog`, or `None` when the
+/// cursor is not somewhere a table or column name belongs. After
+/// `FROM`/`JOIN` this is a schema-qualified table name, after `alias.` one of
+/// that table's columns, and in `SELECT`, `WHERE` and similar clauses a column
+/// of a table the statement references. The typed part keeps its case, and a
+/// completed column carries its type when it is known.
+pub(super) fn context_completion(
+    sql: &str,
+    cursor: usize,
+    catalog: &[CompletionTable],
+) -> Option<ContextCompletion> {
+    let CursorContext {
+        typed,
+        after_table_keyword,
+        in_column_clause,
+        references,
+    } = cursor_context(sql, cursor)?;
+
+    let (typed_name, prefix_len) = typed_name(typed)?;
+    let prefix = &typed[..prefix_len];
+    let columns_of = |table: &CompletionTable| {
+        table
+            .columns
+            .iter()
+            .map(|column| (format!("{prefix}{}", column.name), column.data_type.clone()))
+            .collect::<Vec<_>>()
+    };
+
+    let candidates = if let TypedName::SchemaTable { schema, table } = typed_name {
+        columns_of(resolve_table(&format!("{schema}.{table}"), &[], catalog)?)
+    } else if let TypedName::Qualified { qualifier } = typed_name {
+        if let Some(table) = resolve_table(qualifier, &references, catalog) {
+            columns_of(table)
+        } else {
+            catalog
+                .iter()
+                .filter(|table| {
+                    table
+                        .schema
+                        .as_deref()
+                        .is_some_and(|schema| schema.eq_ignore_ascii_case(qualifier))
+                })
+                .map(|table| (format!("{prefix}{}", table.name), None))
+                .collect()
+        }
+    } else if typed.is_empty() {
+        return None;
+    } else if after_table_keyword {
+        let mut names = catalog
+            .iter()
+            .map(|table| (table.qualified_name(), None))
+            .collect::<Vec<_>>();
+        names.extend(catalog.iter().map(|table| (table.name.clone(), None)));
+        names
+    } else if in_column_clause {
+        references
+            .iter()
+            .filter_map(|reference| resolve_table(&reference.name, &[], catalog))
+            .flat_map(|table| {
+                table
+                    .columns
+                    .iter()
+                    .map(|column| (column.name.clone(), column.data_type.clone()))
+            })
+            .collect()
+    } else {
+        return None;
+    };
+
+    let typed_lower = typed.to_lowercase();
+    candidates
+        .into_iter()
+        .find(|(candidate, _)| {
+            candidate.len() > typed.len()
+                && candidate.to_lowercase().starts_with(&typed_lower)
+                && candidate.is_char_boundary(typed.len())
+        })
+        .map(|(candidate, detail)| ContextCompletion {
+            text: format!("{typed}{}", &candidate[typed.len()..]),
+            detail,
+        })
+}
+
+/// `(schema, table)` of each table the cursor completes columns of, for
+/// describing the ones whose column types are not known yet: the `schema.table`
+/// being typed, and in column clauses every table the statement references. A
+/// bare name takes the schema the catalog has it under; a table the catalog
+/// does not know keeps `None`, which the driver reads as its default schema.
+pub(super) fn column_context_tables(
+    sql: &str,
+    cursor: usize,
+    catalog: &[CompletionTable],
+) -> Vec<(Option<String>, String)> {
+    let Some(context) = cursor_context(sql, cursor) else {
+        return Vec::new();
+    };
+    let mut names = Vec::new();
+    if let Some((TypedName::SchemaTable { schema, table }, _)) = typed_name(context.typed) {
+        names.push(format!("{schema}.{table}"));
+    }
+    if !context.after_table_keyword && (context.in_column_clause || context.typed.contains('.')) {
+        names.extend(
+            context
+                .references
+                .iter()
+                .map(|reference| reference.name.clone()),
+        );
+    }
+
+    let mut tables = Vec::new();
+    for name in names {
+        let table = match resolve_table(&name, &[], catalog) {
+            Some(table) => (table.schema.clone(), table.name.clone()),
+            None => match name.rsplit_once('.') {
+                Some((schema, name)) => (Some(schema.to_string()), name.to_string()),
+                None => (None, name),
+            },
+        };
+        if !tables.contains(&table) {
+            tables.push(table);
+        }
+    }
+    tables
+}
+
+/// The schema whose tables the cursor completes, as in `from public.`, so
+/// tables created since the column index was loaded can be listed. Aliases
+/// and tables the statement references are not schemas. The catalog's
+/// spelling of the schema wins over the typed one.
+pub(super) fn typed_schema(
+    sql: &str,
+    cursor: usize,
+    catalog: &[CompletionTable],
+) -> Option<String> {
+    let context = cursor_context(sql, cursor)?;
+    let (TypedName::Qualified { qualifier }, _) = typed_name(context.typed)? else {
+        return None;
+    };
+    let referenced = context.references.iter().any(|reference| {
+        reference.name.eq_ignore_ascii_case(qualifier)
+            || reference
+                .alias
+                .as_deref()
+                .is_some_and(|alias| alias.eq_ignore_ascii_case(qualifier))
+    });
+    if referenced || resolve_table(qualifier, &[], catalog).is_some() {
+        return None;
+    }
+    Some(
+        catalog
+            .iter()
+            .filter_map(|table| table.schema.as_deref())
+            .find(|schema| schema.eq_ignore_ascii_case(qualifier))
+            .unwrap_or(qualifier)
+            .to_string(),
+    )
+}
+
+/// Whether `cursor` sits inside a literal, comment or quoted identifier that
+/// starts at or after `start`.
+fn inside_literal(sql: &str, start: usize, cursor: usize) -> bool {
+    let mut index = start;
+    while index < cursor {
+        match literal_end(sql, index) {
+            Some(end) if end >= cursor => return true,
+            Some(end) => index = end,
+            None => index += sql[index..].chars().next().map_or(1, char::len_utf8),
+        }
+    }
+    false
+}
+
+/// Words and punctuation of `sql[start..end]`, skipping literals, comments
+/// and quoted identifiers.
+fn tokenize(sql: &str, start: usize, end: usize) -> Vec<Token<'_>> {
+    let bytes = sql.as_bytes();
+    let mut tokens = Vec::new();
+    let mut index = start;
+    while index < end {
+        if let Some(literal) = literal_end(sql, index) {
+            index = literal;
+            continue;
+        }
+        let byte = bytes[index];
+        if is_word_byte(byte) {
+            let word_end = sql[index..end]
+                .bytes()
+                .position(|byte| !is_word_byte(byte) && byte != b'.')
+                .map_or(end, |offset| index + offset);
+            tokens.push(Token {
+                start: index,
+                text: &sql[index..word_end],
+            });
+            index = word_end;
+        } else {
+            if matches!(byte, b',' | b'(' | b')') {
+                tokens.push(Token {
+                    start: index,
+                    text: &sql[index..index + 1],
+                });
+            }
+            index += sql[index..].chars().next().map_or(1, char::len_utf8);
+        }
+    }
+    tokens
+}
+
+/// Tables after `FROM`, `JOIN`, `UPDATE` and `INTO`, including every entry
+/// of a comma-separated `FROM` list, with their aliases.
+fn table_references(tokens: &[Token<'_>]) -> Vec<TableReference> {
+    let mut references = Vec::new();
+    let mut index = 0;
+    while index < tokens.len() {
+        let token = &tokens[index];
+        index += 1;
+        if !["from", "join", "update", "into"]
+            .iter()
+            .any(|keyword| token.is(keyword))
+        {
+            continue;
+        }
+        let is_from = token.is("from");
+        while let Some(name) = tokens.get(index).filter(|token| token.is_word()) {
+            index += 1;
+            if tokens.get(index).is_some_and(|token| token.is("as")) {
+                index += 1;
+            }
+            let alias = tokens
+                .get(index)
+                .filter(|token| {
+                    token.is_word() && !NOT_ALIASES.iter().any(|keyword| token.is(keyword))
+                })
+                .map(|token| token.text.to_string());
+            if alias.is_some() {
+                index += 1;
+            }
+            references.push(TableReference {
+                name: name.text.to_string(),
+                alias,
+            });
+            if !(is_from && tokens.get(index).is_some_and(|token| token.is(","))) {
+                break;
+            }
+            index += 1;
+        }
+    }
+    references
+}
+
+/// The catalog table `name` refers to: an alias from `references`, a
+/// `schema.table` or a bare table name.
+fn resolve_table<'a>(
+    name: &str,
+    references: &[TableReference],
+    catalog: &'a [CompletionTable],
+) -> Option<&'a CompletionTable> {
+    let name = references
+        .iter()
+        .find(|reference| {
+            reference
+                .alias
+                .as_deref()
+                .is_some_and(|alias| alias.eq_ignore_ascii_case(name))
+        })
+        .map_or(name, |reference| reference.name.as_str());
+    let (schema, table_name) = match name.rsplit_once('.') {
+        Some((schema, table_name)) => (Some(schema), table_name),
+        None => (None, name),
+    };
+    catalog.iter().find(|table| {
+        table.name.eq_ignore_ascii_case(table_name)
+            && schema.is_none_or(|schema| {
+                table
+                    .schema
+                    .as_deref()
+                    .is_some_and(|candidate| candidate.eq_ignore_ascii_case(schema))
+            })
+    })
+}
+
+fn is_word_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'_' || byte >= 0x80
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CompletionColumn, CompletionTable, TypedName, column_context_tables, completion_catalog,
+        context_completion, typed_name, typed_schema,
+    };
+    use crate::app_state::TableColumnCache;
+    use models::{ColumnInfo, SchemaColumn};
+    use std::collections::HashMap;
+
+    fn catalog() -> Vec<CompletionTable> {
+        let table = |schema: &str, name: &str, columns: &[&str]| CompletionTable {
+            schema: Some(schema.to_string()),
+            name: name.to_string(),
+            columns: columns
+                .iter()
+                .map(|column| CompletionColumn::untyped(column))
+                .collect(),
+        };
+        vec![
+            table("public", "users", &["id", "name", "email"]),
+            table("public", "orders", &["id", "user_id", "total"]),
+            table("sales", "invoices", &["id", "amount"]),
+        ]
+    }
+
+    fn complete(sql_with_cursor: &str) -> Option<String> {
+        let cursor = sql_with_cursor.find('|').unwrap();
+        let sql = sql_with_cursor.replace('|', "");
+        context_completion(&sql, cursor, &catalog()).map(|completion| completion.text)
+    }
+
+    #[test]
+    fn tables_after_from_and_join_are_schema_qualified() {
+        assert_eq!(complete("select * from pub|"), Some("public.users".into()));
+        assert_eq!(
+            complete("select * from users u join ord|"),
+            Some("orders".into())
+        );
+        assert_eq!(
+            complete("select * from sales.i|"),
+            Some("sales.invoices".into())
+        );
+        assert_eq!(
+            complete("select * from users, inv|"),
+            Some("invoices".into())
+        );
+    }
+
+    #[test]
+    fn alias_dot_offers_only_that_tables_columns() {
+        assert_eq!(
+            complete("select o.| from users u join orders o on o.user_id = u.id"),
+            Some("o.id".into())
+        );
+        assert_eq!(
+            complete("select o.to| from users u, public.orders as o"),
+            Some("o.total".into())
+        );
+        assert_eq!(complete("select x.na| from users u"), None);
+    }
+
+    #[test]
+    fn where_prefers_columns_of_referenced_tables_at_the_cursor() {
+        assert_eq!(
+            complete("select * from orders where us| and total > 0"),
+            Some("user_id".into())
+        );
+        assert_eq!(
+            complete("select * from users where Em|"),
+            Some("Email".into())
+        );
+        assert_eq!(complete("select * from users where amo|"), None);
+        assert_eq!(complete("select 'fro|m users"), None);
+    }
+
+    #[test]
+    fn described_tables_add_column_types() {
+        let index = [SchemaColumn {
+            schema: Some("public".into()),
+            table_name: "users".into(),
+            column_name: "id".into(),
+        }];
+        let column = |name: &str, data_type: &str| ColumnInfo {
+            name: name.into(),
+            data_type: data_type.into(),
+            nullable: true,
+            primary_key: false,
+            foreign_key: false,
+            default_value: None,
+            identity: false,
+            generated: false,
+            comment: None,
+        };
+        let mut described = TableColumnCache::new();
+        described.insert(
+            (Some("public".into()), "users".into()),
+            vec![column("id", "integer"), column("created_at", "timestamp")],
+        );
+        described.insert((None, "events".into()), vec![column("kind", "text")]);
+        let catalog = completion_catalog(None, Some(&index), Some(&described), None);
+
+        let completion = context_completion("select * from users where cre", 29, &catalog).unwrap();
+        assert_eq!(completion.text, "created_at");
+        assert_eq!(completion.detail.as_deref(), Some("timestamp"));
+        let completion = context_completion("select e.k from events e", 10, &catalog).unwrap();
+        assert_eq!(completion.text, "e.kind");
+        assert_eq!(completion.detail.as_deref(), Some("text"));
+    }
+
+    #[test]
+    fn column_clauses_list_the_referenced_tables_to_describe() {
+        let tables = |sql_with_cursor: &str| {
+            let cursor = sql_with_cursor.find('|').unwrap();
+            let sql = sql_with_cursor.replace('|', "");
+            column_context_tables(&sql, cursor, &catalog())
+        };
+        assert_eq!(
+            tables("select * from users u join audit.log l on l.|"),
+            vec![
+                (Some("public".into()), "users".into()),
+                (Some("audit".into()), "log".into()),
+            ]
+        );
+        assert_eq!(
+            tables("select * from events where ki|"),
+            vec![(None, "events".into())]
+        );
+        assert!(tables("select * from use|").is_empty());
+    }
+
+    #[test]
+    fn dotted_names_are_read_one_part_at_a_time() {
+        assert_eq!(typed_name("us"), Some((TypedName::Bare, 0)));
+        assert_eq!(
+            typed_name("public.us"),
+            Some((
+                TypedName::Qualified {
+                    qualifier: "public"
+                },
+                7
+            ))
+        );
+        assert_eq!(
+            typed_name("public.users."),
+            Some((
+                TypedName::SchemaTable {
+                    schema: "public",
+                    table: "users"
+                },
+                13
+            ))
+        );
+        assert_eq!(typed_name("a..b"), None);
+        assert_eq!(typed_name("a.b.c.d"), None);
+    }
+
+    #[test]
+    fn schema_and_schema_table_prefixes_complete_tables_then_columns() {
+        assert_eq!(
+            complete("select * from sales.|"),
+            Some("sales.invoices".into())
+        );
+        assert_eq!(
+            complete("select public.orders.to| from public.orders"),
+            Some("public.orders.total".into())
+        );
+        assert_eq!(
+            complete("select Public.Users.em|"),
+            Some("Public.Users.email".into())
+        );
+        assert_eq!(complete("select public.missing.i|"), None);
+
+        let tables = |names: &[&str]| {
+            let mut listed = HashMap::new();
+            listed.insert(
+                "audit".to_string(),
+                names.iter().map(|name| name.to_string()).collect(),
+            );
+            completion_catalog(None, None, None, Some(&listed))
+        };
+        let catalog = tables(&["log", "events"]);
+        let completion = context_completion("select * from audit.ev", 22, &catalog).unwrap();
+        assert_eq!(completion.text, "audit.events");
+        assert_eq!(
+            column_context_tables("select audit.log.| from audit.log", 17, &catalog),
+            vec![(Some("audit".into()), "log".into())]
+        );
+    }
+
+    #[test]
+    fn only_unreferenced_qualifiers_are_listed_as_schemas() {
+        let schema = |sql_with_cursor: &str| {
+            let cursor = sql_with_cursor.find('|').unwrap();
+            let sql = sql_with_cursor.replace('|', "");
+            typed_schema(&sql, cursor, &catalog())
+        };
+        assert_eq!(schema("select * from SALES.|"), Some("sales".into()));
+        assert_eq!(schema("select * from audit.|"), Some("audit".into()));
+        assert_eq!(schema("select u.| from users u"), None);
+        assert_eq!(schema("select users.| from users"), None);
+        assert_eq!(schema("select * from users where na|"), None);
+    }
+}
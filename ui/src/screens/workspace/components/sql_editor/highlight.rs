@@ -1,5 +1,7 @@
 use dioxus::prelude::*;
 use std::cell::RefCell;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::ops::Range;
 use tree_sitter_highlight::{HighlightConfiguration, HighlightEvent, Highlighter};
 
 const SQL_HIGHLIGHT_NAMES: [&str; 21] = [
@@ -29,19 +31,35 @@ const SQL_HIGHLIGHT_NAMES: [&str; 21] = [
 #[derive(Clone, PartialEq)]
 struct SqlHighlightSegment {
     class_name: &'static str,
+    /// Extra class layered over the token colour, such as a search match.
+    mark: &'static str,
     text: String,
 }
 
 thread_local! {
     static SQL_HIGHLIGHT_CONFIG: RefCell<Option<HighlightConfiguration>> =
         RefCell::new(build_highlight_config());
+    /// Segments of the last highlighted text, keyed by its hash and length, so
+    /// remounting the layer after a typing pause or a tab switch does not
+    /// re-parse an unchanged script. Colours come from CSS classes, so the
+    /// theme is not part of the key.
+    static SQL_HIGHLIGHT_CACHE: RefCell<Option<((u64, usize), Vec<SqlHighlightSegment>)>> =
+        const { RefCell::new(None) };
 }
 
 #[component]
-pub(super) fn SqlHighlightContent(
+pub(crate) fn SqlHighlightContent(
     sql: String,
     inline_cursor_position: Option<usize>,
     inline_suffix: Option<String>,
+    /// Hint after the ghost text, such as the completed column's type.
+    inline_detail: Option<String>,
+    /// Byte offsets of a bracket pair to outline, e.g. the one at the cursor.
+    matched_brackets: Option<(usize, usize)>,
+    /// Byte ranges found by the find bar, and which of them is current.
+    #[props(default)]
+    search_matches: Vec<Range<usize>>,
+    current_search_match: Option<usize>,
 ) -> Element {
     let inline_cursor_position = inline_cursor_position.unwrap_or(sql.len()).min(sql.len());
     let highlighted_before = use_memo(use_reactive(
@@ -51,9 +69,9 @@ pub(super) fn SqlHighlightContent(
                 .as_ref()
                 .is_some_and(|suffix| !suffix.is_empty())
             {
-                highlight_sql(&sql[..inline_cursor_position])
+                highlight_sql_cached(&sql[..inline_cursor_position])
             } else {
-                highlight_sql(&sql)
+                highlight_sql_cached(&sql)
             }
         },
     ));
@@ -64,9 +82,12 @@ pub(super) fn SqlHighlightContent(
                 "-- Write SQL here. Syntax highlighting is powered by tree-sitter."
             }
         } else {
-            for segment in highlighted_before() {
+            for segment in with_matched_brackets(
+                with_search_matches(highlighted_before(), &search_matches, current_search_match),
+                matched_brackets,
+            ) {
                 span {
-                    class: format!("sql-editor__token {}", segment.class_name),
+                    class: format!("sql-editor__token {} {}", segment.class_name, segment.mark),
                     "{segment.text}"
                 }
             }
@@ -76,12 +97,37 @@ pub(super) fn SqlHighlightContent(
                         class: "sql-editor__token sql-editor__token--inline",
                         "{suffix}"
                     }
+                    if let Some(detail) = inline_detail {
+                        span {
+                            class: "sql-editor__token sql-editor__token--inline-detail",
+                            "{detail}"
+                        }
+                    }
                 }
             }
         }
     }
 }
 
+/// Byte ranges of the keywords and word operators such as `AND` in `sql`,
+/// as the highlighter colours them. Types are left out: the grammar colours
+/// table names as types too.
+pub(super) fn keyword_ranges(sql: &str) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    let mut offset = 0;
+    for segment in highlight_sql_cached(sql) {
+        let end = offset + segment.text.len();
+        if matches!(
+            segment.class_name,
+            "sql-editor__token--keyword" | "sql-editor__token--operator"
+        ) {
+            ranges.push(offset..end);
+        }
+        offset = end;
+    }
+    ranges
+}
+
 fn build_highlight_config() -> Option<HighlightConfiguration> {
     let mut config = HighlightConfiguration::new(
         tree_sitter_sequel::LANGUAGE.into(),
@@ -95,6 +141,27 @@ fn build_highlight_config() -> Option<HighlightConfiguration> {
     Some(config)
 }
 
+fn highlight_sql_cached(sql: &str) -> Vec<SqlHighlightSegment> {
+    let mut hasher = DefaultHasher::new();
+    sql.hash(&mut hasher);
+    let key = (hasher.finish(), sql.len());
+
+    let cached = SQL_HIGHLIGHT_CACHE.with(|cache| {
+        cache
+            .borrow()
+            .as_ref()
+            .filter(|(cached_key, _)| *cached_key == key)
+            .map(|(_, segments)| segments.clone())
+    });
+    if let Some(segments) = cached {
+        return segments;
+    }
+
+    let segments = highlight_sql(sql);
+    SQL_HIGHLIGHT_CACHE.with(|cache| *cache.borrow_mut() = Some((key, segments.clone())));
+    segments
+}
+
 fn highlight_sql(sql: &str) -> Vec<SqlHighlightSegment> {
     if sql.is_empty() {
         return Vec::new();
@@ -112,6 +179,7 @@ fn highlight_sql(sql: &str) -> Vec<SqlHighlightSegment> {
             Err(_) => return vec![plain_segment(sql)],
         };
 
+        let overrides = literal_overrides(sql);
         let mut segments = Vec::new();
         let mut highlight_stack = Vec::<usize>::new();
 
@@ -121,14 +189,13 @@ fn highlight_sql(sql: &str) -> Vec<SqlHighlightSegment> {
                 Ok(HighlightEvent::HighlightEnd) => {
                     highlight_stack.pop();
                 }
-                Ok(HighlightEvent::Source { start, end }) => {
-                    let text = &sql[start..end];
-                    push_segment(
-                        &mut segments,
-                        token_class(highlight_stack.last().copied()),
-                        text,
-                    );
-                }
+                Ok(HighlightEvent::Source { start, end }) => push_source_segments(
+                    &mut segments,
+                    sql,
+                    start..end,
+                    token_class(highlight_stack.last().copied()),
+                    &overrides,
+                ),
                 Err(_) => return vec![plain_segment(sql)],
             }
         }
@@ -137,6 +204,195 @@ fn highlight_sql(sql: &str) -> Vec<SqlHighlightSegment> {
     })
 }
 
+/// Pushes `sql[source]`, colouring any part inside an overridden literal with
+/// the literal's class whatever tree-sitter made of it.
+fn push_source_segments(
+    segments: &mut Vec<SqlHighlightSegment>,
+    sql: &str,
+    source: Range<usize>,
+    class_name: &'static str,
+    overrides: &[(Range<usize>, &'static str)],
+) {
+    let mut cursor = source.start;
+    for (literal, literal_class) in overrides
+        .iter()
+        .filter(|(literal, _)| literal.start < source.end && literal.end > source.start)
+    {
+        let literal_start = literal.start.max(cursor);
+        let literal_end = literal.end.min(source.end);
+        push_segment(segments, class_name, &sql[cursor..literal_start]);
+        push_segment(segments, literal_class, &sql[literal_start..literal_end]);
+        cursor = literal_end;
+    }
+    push_segment(segments, class_name, &sql[cursor..source.end]);
+}
+
+/// Byte ranges whose colour is decided here rather than by tree-sitter, with
+/// the class to use: PostgreSQL dollar-quoted literals (`$$ ... $$` or
+/// `$tag$ ... $tag$`) as strings and `/* ... */` comments as comments,
+/// delimiters included. Both carry across lines, which tree-sitter loses as
+/// soon as the surrounding statement fails to parse, and an unterminated one
+/// runs to the end of the text, as it does while typing.
+fn literal_overrides(sql: &str) -> Vec<(Range<usize>, &'static str)> {
+    let bytes = sql.as_bytes();
+    let mut overrides = Vec::new();
+    let mut index = 0;
+
+    while index < bytes.len() {
+        match literal_end(sql, index) {
+            Some(end) => {
+                match bytes[index] {
+                    b'$' => overrides.push((index..end, "sql-editor__token--string")),
+                    b'/' => overrides.push((index..end, "sql-editor__token--comment")),
+                    _ => {}
+                }
+                index = end;
+            }
+            None => index += 1,
+        }
+    }
+
+    overrides
+}
+
+/// End of the quote, quoted identifier, comment or dollar-quoted literal
+/// starting at byte `index`, if one starts there. A `$` only opens a body
+/// outside identifiers and when followed by a valid tag, so `$1` never does.
+pub(super) fn literal_end(sql: &str, index: usize) -> Option<usize> {
+    let bytes = sql.as_bytes();
+    let end = match bytes[index] {
+        quote @ (b'\'' | b'"') => sql[index + 1..]
+            .find(quote as char)
+            .map_or(bytes.len(), |offset| index + offset + 2),
+        b'-' if bytes.get(index + 1) == Some(&b'-') => sql[index..]
+            .find('\n')
+            .map_or(bytes.len(), |offset| index + offset + 1),
+        b'/' if bytes.get(index + 1) == Some(&b'*') => sql[index + 2..]
+            .find("*/")
+            .map_or(bytes.len(), |offset| index + offset + 4),
+        b'$' if index == 0 || !is_identifier_byte(bytes[index - 1]) => {
+            let tag_len = dollar_quote_tag_len(&bytes[index..])?;
+            let tag = &sql[index..index + tag_len];
+            let body_start = index + tag_len;
+            sql[body_start..]
+                .find(tag)
+                .map_or(bytes.len(), |offset| body_start + offset + tag_len)
+        }
+        _ => return None,
+    };
+    Some(end)
+}
+
+/// Length of the `$tag$` opener at the start of `bytes`, if there is one.
+/// Tags follow identifier rules, so `$1` parameters never match.
+fn dollar_quote_tag_len(bytes: &[u8]) -> Option<usize> {
+    let tag = &bytes[1..];
+    let closing = tag.iter().position(|byte| *byte == b'$')?;
+    let name = &tag[..closing];
+    let valid = name.first().is_none_or(|first| !first.is_ascii_digit())
+        && name.iter().copied().all(is_identifier_byte);
+    valid.then_some(closing + 2)
+}
+
+fn is_identifier_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'_' || !byte.is_ascii()
+}
+
+/// Splits the bracket characters at the given byte offsets out of their
+/// segments so they render with the match outline.
+fn with_matched_brackets(
+    segments: Vec<SqlHighlightSegment>,
+    matched_brackets: Option<(usize, usize)>,
+) -> Vec<SqlHighlightSegment> {
+    let Some((open, close)) = matched_brackets else {
+        return segments;
+    };
+
+    let mut marked = Vec::with_capacity(segments.len() + 4);
+    let mut segment_start = 0;
+    for segment in segments {
+        let segment_end = segment_start + segment.text.len();
+        let mut cursor = 0;
+        for position in [open, close] {
+            if position < segment_start || position >= segment_end {
+                continue;
+            }
+            let local = position - segment_start;
+            if local > cursor {
+                marked.push(SqlHighlightSegment {
+                    class_name: segment.class_name,
+                    mark: segment.mark,
+                    text: segment.text[cursor..local].to_string(),
+                });
+            }
+            marked.push(SqlHighlightSegment {
+                class_name: "sql-editor__token--punctuation sql-editor__token--bracket-match",
+                mark: segment.mark,
+                text: segment.text[local..local + 1].to_string(),
+            });
+            cursor = local + 1;
+        }
+        if cursor < segment.text.len() {
+            marked.push(SqlHighlightSegment {
+                class_name: segment.class_name,
+                mark: segment.mark,
+                text: segment.text[cursor..].to_string(),
+            });
+        }
+        segment_start = segment_end;
+    }
+
+    marked
+}
+
+/// Splits segments at the edges of search matches so the matches render with
+/// the match background, the current one more strongly.
+fn with_search_matches(
+    segments: Vec<SqlHighlightSegment>,
+    matches: &[Range<usize>],
+    current: Option<usize>,
+) -> Vec<SqlHighlightSegment> {
+    if matches.is_empty() {
+        return segments;
+    }
+
+    let mut marked = Vec::with_capacity(segments.len() + matches.len() * 2);
+    let mut segment_start = 0;
+    for segment in segments {
+        let segment_end = segment_start + segment.text.len();
+        let piece = |range: Range<usize>, mark: &'static str| SqlHighlightSegment {
+            class_name: segment.class_name,
+            mark,
+            text: segment.text[range.start - segment_start..range.end - segment_start].to_string(),
+        };
+        let mut cursor = segment_start;
+        for (index, found) in matches
+            .iter()
+            .enumerate()
+            .filter(|(_, found)| found.start < segment_end && found.end > segment_start)
+        {
+            let start = found.start.max(segment_start);
+            let end = found.end.min(segment_end);
+            if start > cursor {
+                marked.push(piece(cursor..start, segment.mark));
+            }
+            let mark = if current == Some(index) {
+                "sql-editor__search-match sql-editor__search-match--current"
+            } else {
+                "sql-editor__search-match"
+            };
+            marked.push(piece(start..end, mark));
+            cursor = end;
+        }
+        if cursor < segment_end {
+            marked.push(piece(cursor..segment_end, segment.mark));
+        }
+        segment_start = segment_end;
+    }
+
+    marked
+}
+
 fn push_segment(segments: &mut Vec<SqlHighlightSegment>, class_name: &'static str, text: &str) {
     if text.is_empty() {
         return;
@@ -151,6 +407,7 @@ fn push_segment(segments: &mut Vec<SqlHighlightSegment>, class_name: &'static st
 
     segments.push(SqlHighlightSegment {
         class_name,
+        mark: "",
         text: text.to_string(),
     });
 }
@@ -158,6 +415,7 @@ fn push_segment(segments: &mut Vec<SqlHighlightSegment>, class_name: &'static st
 fn plain_segment(sql: &str) -> SqlHighlightSegment {
     SqlHighlightSegment {
         class_name: "sql-editor__token--plain",
+        mark: "",
         text: sql.to_string(),
     }
 }
@@ -178,3 +436,62 @@ fn token_class(highlight_index: Option<usize>) -> &'static str {
         _ => "sql-editor__token--plain",
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::literal_overrides;
+
+    fn overridden<'a>(sql: &'a str, class_name: &str) -> Vec<&'a str> {
+        literal_overrides(sql)
+            .into_iter()
+            .filter(|(_, literal_class)| *literal_class == class_name)
+            .map(|(range, _)| &sql[range])
+            .collect()
+    }
+
+    fn quoted(sql: &str) -> Vec<&str> {
+        overridden(sql, "sql-editor__token--string")
+    }
+
+    fn block_comments(sql: &str) -> Vec<&str> {
+        overridden(sql, "sql-editor__token--comment")
+    }
+
+    #[test]
+    fn dollar_quoted_bodies_span_lines_and_respect_tags() {
+        let sql = "create function f() returns int as $body$\nbegin\n  return 'x$$';\nend;\n$body$ language plpgsql;\nselect $$a$$, $q$b$$c$q$;";
+        assert_eq!(
+            quoted(sql),
+            vec![
+                "$body$\nbegin\n  return 'x$$';\nend;\n$body$",
+                "$$a$$",
+                "$q$b$$c$q$",
+            ]
+        );
+    }
+
+    #[test]
+    fn dollar_signs_outside_quote_openers_are_ignored() {
+        assert!(quoted("select $1, a$b$c from t where x = '$$' -- $$\n/* $$ */").is_empty());
+        assert!(quoted("select \"col$$\" from t").is_empty());
+    }
+
+    #[test]
+    fn unterminated_dollar_quote_runs_to_the_end() {
+        assert_eq!(
+            quoted("do $$ begin perform 1;"),
+            vec!["$$ begin perform 1;"]
+        );
+    }
+
+    #[test]
+    fn block_comments_carry_across_lines() {
+        let sql = "select 1 /* first\n  'not a string'\n $$ */, 2;\n/* open\nselect";
+        assert_eq!(
+            block_comments(sql),
+            vec!["/* first\n  'not a string'\n $$ */", "/* open\nselect"]
+        );
+        assert!(quoted(sql).is_empty());
+        assert!(block_comments("select '/* no */', \"/*\" -- /* no\n").is_empty());
+    }
+}
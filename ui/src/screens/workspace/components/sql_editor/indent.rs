@@ -0,0 +1,126 @@
+use super::selection::EditorSelection;
+use std::ops::Range;
+
+/// Applies Tab (or Shift+Tab when `outdent`) to the editor text.
+///
+/// A collapsed cursor gets `width` spaces inserted; a selection indents every
+/// line it touches and stays selected as whole lines. Shift+Tab removes up to
+/// `width` leading spaces, or one leading tab, from the same lines.
+pub(super) fn indent_edit(
+    sql: &str,
+    selection: EditorSelection,
+    width: usize,
+    outdent: bool,
+) -> (String, EditorSelection) {
+    let selection = selection.clamped(sql);
+    let start = selection.start.min(selection.end);
+    let end = selection.start.max(selection.end);
+
+    if start == end && !outdent {
+        let mut next_sql = String::with_capacity(sql.len() + width);
+        next_sql.push_str(&sql[..start]);
+        next_sql.push_str(&" ".repeat(width));
+        next_sql.push_str(&sql[start..]);
+        return (next_sql, EditorSelection::collapsed(start + width));
+    }
+
+    let lines = touched_lines(sql, start, end);
+    let (block_start, block_end) = (lines.start, lines.end);
+
+    let mut removed_before_cursor = 0;
+    let edited = sql[block_start..block_end]
+        .split('\n')
+        .enumerate()
+        .map(|(index, line)| {
+            if outdent {
+                let removable = removable_indent(line, width);
+                if index == 0 {
+                    removed_before_cursor = removable.min(start - block_start);
+                }
+                line[removable..].to_string()
+            } else if line.is_empty() {
+                String::new()
+            } else {
+                format!("{}{line}", " ".repeat(width))
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let next_sql = format!("{}{edited}{}", &sql[..block_start], &sql[block_end..]);
+    let next_selection = if start == end {
+        EditorSelection::collapsed(start - removed_before_cursor)
+    } else {
+        EditorSelection {
+            start: block_start,
+            end: block_start + edited.len(),
+        }
+    };
+    (next_sql, next_selection)
+}
+
+/// Byte range of the whole lines touched by `start..end`, without the final
+/// newline. A selection that ends at the start of a line does not touch that
+/// line.
+pub(super) fn touched_lines(sql: &str, start: usize, end: usize) -> Range<usize> {
+    let block_start = sql[..start].rfind('\n').map_or(0, |index| index + 1);
+    let last_line_end = if end > block_start && sql[..end].ends_with('\n') {
+        end - 1
+    } else {
+        end
+    };
+    let block_end = sql[last_line_end..]
+        .find('\n')
+        .map_or(sql.len(), |index| last_line_end + index);
+    block_start..block_end
+}
+
+fn removable_indent(line: &str, width: usize) -> usize {
+    if line.starts_with('\t') {
+        return 1;
+    }
+    line.bytes()
+        .take(width)
+        .take_while(|byte| *byte == b' ')
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EditorSelection, indent_edit};
+
+    #[test]
+    fn tab_at_cursor_inserts_spaces() {
+        let (sql, selection) = indent_edit("selectx", EditorSelection::collapsed(6), 4, false);
+        assert_eq!(sql, "select    x");
+        assert_eq!(selection, EditorSelection::collapsed(10));
+    }
+
+    #[test]
+    fn tab_on_selection_indents_touched_lines() {
+        let sql = "select a,\n\nb\nfrom t";
+        let selection = EditorSelection {
+            start: 3,
+            end: "select a,\n\nb\n".len(),
+        };
+        let (next, selection) = indent_edit(sql, selection, 2, false);
+
+        assert_eq!(next, "  select a,\n\n  b\nfrom t");
+        assert_eq!(&next[selection.start..selection.end], "  select a,\n\n  b");
+    }
+
+    #[test]
+    fn shift_tab_removes_up_to_one_indent() {
+        let sql = "select\n      a,\n\tb,\n c";
+        let selection = EditorSelection {
+            start: 8,
+            end: sql.len(),
+        };
+        let (next, _) = indent_edit(sql, selection, 4, true);
+        assert_eq!(next, "select\n  a,\nb,\nc");
+
+        let (next, selection) = indent_edit("    from t", EditorSelection::collapsed(6), 4, true);
+        assert_eq!(next, "from t");
+        assert_eq!(selection, EditorSelection::collapsed(2));
+    }
+}
@@ -0,0 +1,180 @@
+use std::ops::Range;
+
+pub(super) const SEARCH_INPUT_ID: &str = "workspace-sql-editor-search";
+
+/// State of the find / replace bar over the SQL editor.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(super) struct SearchBar {
+    pub(super) query: String,
+    pub(super) replacement: String,
+    pub(super) replace_mode: bool,
+    pub(super) case_sensitive: bool,
+    pub(super) whole_word: bool,
+    /// Index into the matches of the match shown as current.
+    pub(super) current: usize,
+}
+
+impl SearchBar {
+    /// Non-overlapping byte ranges of the query in `sql`, in order.
+    pub(super) fn matches(&self, sql: &str) -> Vec<Range<usize>> {
+        if self.query.is_empty() {
+            return Vec::new();
+        }
+
+        let mut matches = Vec::new();
+        let mut index = 0;
+        while index < sql.len() {
+            match self.match_end(sql, index) {
+                Some(end) => {
+                    matches.push(index..end);
+                    index = end;
+                }
+                None => {
+                    index += sql[index..].chars().next().map_or(1, char::len_utf8);
+                }
+            }
+        }
+        matches
+    }
+
+    fn match_end(&self, sql: &str, start: usize) -> Option<usize> {
+        let mut end = start;
+        let mut text = sql[start..].chars();
+        for expected in self.query.chars() {
+            let actual = text.next()?;
+            let equal = if self.case_sensitive {
+                actual == expected
+            } else {
+                actual.to_lowercase().eq(expected.to_lowercase())
+            };
+            if !equal {
+                return None;
+            }
+            end += actual.len_utf8();
+        }
+
+        if self.whole_word {
+            let before = sql[..start].chars().next_back();
+            let after = sql[end..].chars().next();
+            if before.is_some_and(is_word_char) || after.is_some_and(is_word_char) {
+                return None;
+            }
+        }
+        Some(end)
+    }
+}
+
+/// Index of the first match starting at or after `offset`, wrapping to the
+/// first match.
+pub(super) fn match_at_or_after(matches: &[Range<usize>], offset: usize) -> usize {
+    matches
+        .iter()
+        .position(|range| range.start >= offset)
+        .unwrap_or(0)
+}
+
+/// `sql` with every match replaced, and `caret` moved so it stays next to the
+/// same text. A caret inside a match lands after its replacement.
+pub(super) fn replace_all(
+    sql: &str,
+    matches: &[Range<usize>],
+    replacement: &str,
+    caret: usize,
+) -> (String, usize) {
+    let mut replaced = String::with_capacity(sql.len());
+    let mut cursor = 0;
+    let mut next_caret = caret;
+    for range in matches {
+        replaced.push_str(&sql[cursor..range.start]);
+        replaced.push_str(replacement);
+        cursor = range.end;
+        if range.end <= caret {
+            next_caret = next_caret + replacement.len() - range.len();
+        } else if range.start < caret {
+            next_caret = replaced.len();
+        }
+    }
+    replaced.push_str(&sql[cursor..]);
+    (replaced, next_caret)
+}
+
+/// Focuses the search field and selects its text, as on Ctrl+F.
+pub(super) fn focus_search_input_script() -> String {
+    format!(
+        r#"
+        (() => {{
+            const input = document.getElementById({SEARCH_INPUT_ID:?});
+            if (!input) {{
+                return false;
+            }}
+            input.focus();
+            input.select();
+            return true;
+        }})()
+        "#
+    )
+}
+
+fn is_word_char(ch: char) -> bool {
+    ch.is_alphanumeric() || ch == '_'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SearchBar, match_at_or_after, replace_all};
+
+    fn search(query: &str, case_sensitive: bool, whole_word: bool) -> SearchBar {
+        SearchBar {
+            query: query.to_string(),
+            case_sensitive,
+            whole_word,
+            ..SearchBar::default()
+        }
+    }
+
+    #[test]
+    fn matches_respect_case_and_whole_word_options() {
+        let sql = "select id, user_id from Users where ID = 1";
+        let texts = |bar: SearchBar| {
+            bar.matches(sql)
+                .into_iter()
+                .map(|range| &sql[range])
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(texts(search("id", false, false)), vec!["id", "id", "ID"]);
+        assert_eq!(texts(search("id", true, false)), vec!["id", "id"]);
+        assert_eq!(texts(search("id", false, true)), vec!["id", "ID"]);
+        assert_eq!(texts(search("users", false, true)), vec!["Users"]);
+        assert!(search("", false, false).matches(sql).is_empty());
+    }
+
+    #[test]
+    fn matches_handle_multibyte_text() {
+        let sql = "select 'Straße', 'STRASSE', 'straße'";
+        let matches = search("straße", false, false).matches(sql);
+        assert_eq!(matches.len(), 2);
+        assert_eq!(&sql[matches[1].clone()], "straße");
+    }
+
+    #[test]
+    fn current_match_follows_the_caret_and_wraps() {
+        let matches = vec![2..4, 10..12, 20..22];
+        assert_eq!(match_at_or_after(&matches, 0), 0);
+        assert_eq!(match_at_or_after(&matches, 10), 1);
+        assert_eq!(match_at_or_after(&matches, 21), 0);
+    }
+
+    #[test]
+    fn replace_all_keeps_the_caret_beside_the_same_text() {
+        let sql = "select a, a, b from t where a = 1";
+        let matches = search("a", true, true).matches(sql);
+        let caret = sql.find("b").unwrap();
+        let (replaced, next_caret) = replace_all(sql, &matches, "alpha", caret);
+        assert_eq!(replaced, "select alpha, alpha, b from t where alpha = 1");
+        assert_eq!(&replaced[next_caret..next_caret + 1], "b");
+
+        let (_, inside) = replace_all("abc abc", &[0..3, 4..7], "x", 5);
+        assert_eq!(inside, 3);
+    }
+}
@@ -257,9 +257,197 @@ pub fn set_editor_value_script(
     )
 }
 
+/// Types `text` over the editor selection and fires an input event so the
+/// editor picks up the change like a keystroke. A space is put in front when
+/// the text would otherwise run into the previous word.
+pub fn insert_editor_text_script(editor_id: &str, text: &str) -> String {
+    let text = serde_json::to_string(text).unwrap_or_else(|_| "\"\"".to_string());
+    format!(
+        r#"
+        (() => {{
+            const editor = document.getElementById({editor_id:?});
+            if (!editor) {{
+                return false;
+            }}
+            const start = editor.selectionStart ?? editor.value.length;
+            const end = editor.selectionEnd ?? start;
+            const before = editor.value.slice(0, start);
+            const text = before && !/[\s(,.]$/.test(before) ? " " + {text} : {text};
+            editor.focus();
+            editor.setRangeText(text, start, end, "end");
+            editor.dispatchEvent(new Event("input", {{ bubbles: true }}));
+            return true;
+        }})()
+        "#
+    )
+}
+
+/// Replaces the editor text and selects the byte range `start..end`, as
+/// after an indent edit.
+pub fn set_editor_value_and_selection_script(
+    editor_id: &str,
+    value: &str,
+    start: usize,
+    end: usize,
+) -> String {
+    let value = serde_json::to_string(value).unwrap_or_else(|_| "\"\"".to_string());
+    format!(
+        r#"
+        (() => {{
+            const editor = document.getElementById({editor_id:?});
+            if (!editor) {{
+                return false;
+            }}
+            const nextValue = {value};
+            const encoder = new TextEncoder();
+            const toUtf16Position = (position) => {{
+                let utf16Position = 0;
+                let byteOffset = 0;
+                for (const ch of nextValue) {{
+                    const nextByteOffset = byteOffset + encoder.encode(ch).length;
+                    if (nextByteOffset > position) {{
+                        break;
+                    }}
+                    byteOffset = nextByteOffset;
+                    utf16Position += ch.length;
+                }}
+                return utf16Position;
+            }};
+            if (editor.value !== nextValue) {{
+                editor.value = nextValue;
+            }}
+            editor.setSelectionRange(toUtf16Position({start}), toUtf16Position({end}));
+            return true;
+        }})()
+        "#
+    )
+}
+
+/// The byte range of `old` that differs from `new`, and what replaces it in
+/// `new`: everything between their common prefix and common suffix.
+pub fn changed_range(old: &str, new: &str) -> (std::ops::Range<usize>, std::ops::Range<usize>) {
+    let prefix = old
+        .char_indices()
+        .zip(new.chars())
+        .find(|((_, old_char), new_char)| old_char != new_char)
+        .map_or(old.len().min(new.len()), |((index, _), _)| index);
+    let suffix = old[prefix..]
+        .chars()
+        .rev()
+        .zip(new[prefix..].chars().rev())
+        .take_while(|(old_char, new_char)| old_char == new_char)
+        .map(|(old_char, _)| old_char.len_utf8())
+        .sum::<usize>();
+    (prefix..old.len() - suffix, prefix..new.len() - suffix)
+}
+
+/// Types `text` over the byte range `start..end` of the editor text the way a
+/// keystroke would, so Ctrl+Z takes the edit back, then selects the byte range
+/// `selection_start..selection_end` of the result.
+pub fn replace_editor_range_script(
+    editor_id: &str,
+    start: usize,
+    end: usize,
+    text: &str,
+    selection_start: usize,
+    selection_end: usize,
+) -> String {
+    let text = serde_json::to_string(text).unwrap_or_else(|_| "\"\"".to_string());
+    format!(
+        r#"
+        (() => {{
+            const editor = document.getElementById({editor_id:?});
+            if (!editor) {{
+                return false;
+            }}
+            const encoder = new TextEncoder();
+            const toUtf16Position = (position) => {{
+                let utf16Position = 0;
+                let byteOffset = 0;
+                for (const ch of editor.value) {{
+                    const nextByteOffset = byteOffset + encoder.encode(ch).length;
+                    if (nextByteOffset > position) {{
+                        break;
+                    }}
+                    byteOffset = nextByteOffset;
+                    utf16Position += ch.length;
+                }}
+                return utf16Position;
+            }};
+            editor.focus();
+            editor.setSelectionRange(toUtf16Position({start}), toUtf16Position({end}));
+            if (!document.execCommand("insertText", false, {text})) {{
+                editor.setRangeText({text}, editor.selectionStart, editor.selectionEnd, "end");
+                editor.dispatchEvent(new Event("input", {{ bubbles: true }}));
+            }}
+            editor.setSelectionRange(
+                toUtf16Position({selection_start}),
+                toUtf16Position({selection_end}),
+            );
+            return true;
+        }})()
+        "#
+    )
+}
+
+/// Selects the byte range `start..end` in the editor and scrolls it into
+/// view. With `return_focus` the element that had focus gets it back, so the
+/// find bar can step through matches while keeping its input focused.
+pub fn reveal_editor_range_script(
+    editor_id: &str,
+    start: usize,
+    end: usize,
+    return_focus: bool,
+) -> String {
+    format!(
+        r#"
+        (() => {{
+            const editor = document.getElementById({editor_id:?});
+            if (!editor) {{
+                return false;
+            }}
+            const value = editor.value ?? "";
+            const encoder = new TextEncoder();
+            const toUtf16Position = (position) => {{
+                let utf16Position = 0;
+                let byteOffset = 0;
+                for (const ch of value) {{
+                    const nextByteOffset = byteOffset + encoder.encode(ch).length;
+                    if (nextByteOffset > position) {{
+                        break;
+                    }}
+                    byteOffset = nextByteOffset;
+                    utf16Position += ch.length;
+                }}
+                return utf16Position;
+            }};
+            const previous = document.activeElement;
+            editor.focus();
+            editor.setSelectionRange(toUtf16Position({start}), toUtf16Position({end}));
+            if ({return_focus} && previous && previous !== editor) {{
+                previous.focus();
+            }}
+            return true;
+        }})()
+        "#
+    )
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{EditorSelection, current_token_range};
+    use super::{EditorSelection, changed_range, current_token_range};
+
+    #[test]
+    fn changed_range_spans_only_the_edited_text() {
+        assert_eq!(
+            changed_range("select a from t", "SELECT a FROM t"),
+            (0..13, 0..13)
+        );
+        assert_eq!(changed_range("select 1", "-- select 1"), (0..0, 0..3));
+        assert_eq!(changed_range("a\nb\nc", "a\n-- b\nc"), (2..2, 2..5));
+        assert_eq!(changed_range("éa", "éb"), (2..3, 2..3));
+        assert_eq!(changed_range("same", "same"), (4..4, 4..4));
+    }
 
     #[test]
     fn selection_clamps_invalid_utf8_offsets_to_char_boundaries() {
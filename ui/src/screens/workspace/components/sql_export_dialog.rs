@@ -0,0 +1,121 @@
+use crate::screens::workspace::actions::run_sql_export_for_tab;
+use dioxus::prelude::*;
+use models::QueryTabState;
+
+/// Tab whose loaded rows a pending SQL INSERT export writes out.
+#[derive(Clone, PartialEq)]
+pub(crate) struct SqlExportTarget {
+    pub(crate) tab_id: u64,
+    pub(crate) file_name: String,
+    /// Qualified name of the table the INSERT statements target.
+    pub(crate) table_label: String,
+}
+
+#[component]
+pub(crate) fn SqlExportDialog(
+    tabs: Signal<Vec<QueryTabState>>,
+    mut target: Signal<Option<SqlExportTarget>>,
+) -> Element {
+    let mut batch_size_draft = use_signal(|| "1".to_string());
+    let Some(current_target) = target() else {
+        return rsx! {};
+    };
+    let batch_size = parse_batch_size(&batch_size_draft());
+
+    rsx! {
+        div {
+            class: "settings-modal__backdrop",
+            onclick: move |_| target.set(None),
+            div {
+                class: "settings-modal table-modal table-modal--compact",
+                onclick: move |event| event.stop_propagation(),
+                div {
+                    class: "settings-modal__header",
+                    div {
+                        class: "settings-modal__header-copy",
+                        h2 { class: "settings-modal__title", "Export SQL INSERTs" }
+                        p {
+                            class: "settings-modal__hint",
+                            "INSERT INTO {current_target.table_label}"
+                        }
+                    }
+                    button {
+                        class: "button button--ghost button--small",
+                        onclick: move |_| target.set(None),
+                        "Close"
+                    }
+                }
+
+                div {
+                    class: "table-modal__body",
+                    div {
+                        class: "field",
+                        span { class: "field__label", "Rows per statement" }
+                        input {
+                            class: "input",
+                            r#type: "number",
+                            min: "1",
+                            value: batch_size_draft(),
+                            placeholder: "1",
+                            oninput: move |event| batch_size_draft.set(event.value()),
+                        }
+                    }
+                    p {
+                        class: "table-modal__hint",
+                        if batch_size == 1 {
+                            "One INSERT per row."
+                        } else {
+                            "Up to {batch_size} rows per multi-row VALUES statement."
+                        }
+                    }
+                    p {
+                        class: "table-modal__hint",
+                        "Numbers and booleans are written unquoted when the column types are known."
+                    }
+
+                    div {
+                        class: "table-modal__actions",
+                        button {
+                            class: "button button--ghost",
+                            onclick: move |_| target.set(None),
+                            "Cancel"
+                        }
+                        button {
+                            class: "button button--primary",
+                            onclick: move |_| {
+                                let Some(current_target) = target() else {
+                                    return;
+                                };
+                                target.set(None);
+                                run_sql_export_for_tab(
+                                    tabs,
+                                    current_target.tab_id,
+                                    current_target.file_name,
+                                    batch_size,
+                                );
+                            },
+                            "Save As…"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Rows per statement from the draft, at least 1.
+fn parse_batch_size(draft: &str) -> usize {
+    draft.trim().parse::<usize>().unwrap_or(1).max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_batch_size;
+
+    #[test]
+    fn batch_size_falls_back_to_one_row() {
+        assert_eq!(parse_batch_size(" 500 "), 500);
+        assert_eq!(parse_batch_size("0"), 1);
+        assert_eq!(parse_batch_size("many"), 1);
+    }
+}
@@ -142,6 +142,21 @@ pub fn SqlFormatSettingsFields(mut settings: Signal<SqlFormatSettings>) -> Eleme
                 }
                 span { "Move JOIN clauses to top level" }
             }
+
+            label {
+                class: "editor__format-settings-toggle",
+                input {
+                    r#type: "checkbox",
+                    checked: settings().one_item_per_line,
+                    disabled: settings().inline,
+                    oninput: move |event| {
+                        settings.with_mut(|current| {
+                            current.one_item_per_line = event.checked();
+                        });
+                    },
+                }
+                span { "Put each selected column on its own line" }
+            }
         }
     }
 }
@@ -0,0 +1,148 @@
+use crate::app_state::session_connection;
+use crate::clipboard::copy_text;
+use crate::screens::workspace::actions::{
+    append_to_tab_sql, ensure_tab_for_session, set_active_tab_status,
+};
+use crate::screens::workspace::components::SqlHighlightContent;
+use dioxus::prelude::*;
+use models::{QueryTabState, TablePreviewSource};
+
+/// Table whose generated CREATE statements the DDL viewer shows.
+#[derive(Clone, PartialEq)]
+pub(crate) struct TableDdlTarget {
+    pub(crate) session_id: u64,
+    pub(crate) source: TablePreviewSource,
+}
+
+#[component]
+pub(crate) fn TableDdlModal(
+    target: TableDdlTarget,
+    tabs: Signal<Vec<QueryTabState>>,
+    active_tab_id: Signal<u64>,
+    next_tab_id: Signal<u64>,
+    mut table_ddl: Signal<Option<TableDdlTarget>>,
+) -> Element {
+    let ddl = use_resource({
+        let session_id = target.session_id;
+        let source = target.source.clone();
+        move || {
+            let source = source.clone();
+            async move {
+                let connection = session_connection(session_id)
+                    .ok_or_else(|| "Connection is no longer open.".to_string())?;
+                services::load_table_ddl(connection, source.schema, source.table_name)
+                    .await
+                    .map_err(|err| err.to_string())
+            }
+        }
+    });
+    let loaded_ddl = match &*ddl.read() {
+        Some(Ok(sql)) => Some(sql.clone()),
+        _ => None,
+    };
+    let table_name = target.source.table_name.clone();
+
+    rsx! {
+        div {
+            class: "settings-modal__backdrop",
+            onclick: move |_| table_ddl.set(None),
+            div {
+                class: "settings-modal table-modal",
+                onclick: move |event| event.stop_propagation(),
+                div {
+                    class: "settings-modal__header",
+                    div {
+                        class: "settings-modal__header-copy",
+                        h2 { class: "settings-modal__title", "DDL · {table_name}" }
+                        p {
+                            class: "settings-modal__hint",
+                            "CREATE statements rebuilt from {target.source.qualified_name}."
+                        }
+                    }
+                    button {
+                        class: "button button--ghost button--small",
+                        onclick: move |_| table_ddl.set(None),
+                        "Close"
+                    }
+                }
+
+                div {
+                    class: "table-modal__body",
+                    match ddl() {
+                        None => rsx! {
+                            p { class: "table-modal__hint", "Loading DDL…" }
+                        },
+                        Some(Err(err)) => rsx! {
+                            p { class: "table-modal__error", "{err}" }
+                        },
+                        Some(Ok(sql)) => rsx! {
+                            pre {
+                                class: "table-modal__preview-sql table-ddl__code",
+                                SqlHighlightContent {
+                                    sql,
+                                    inline_cursor_position: None,
+                                    inline_suffix: None,
+                                }
+                            }
+                        },
+                    }
+
+                    div {
+                        class: "table-modal__actions",
+                        button {
+                            class: "button button--ghost",
+                            disabled: loaded_ddl.is_none(),
+                            onclick: {
+                                let loaded_ddl = loaded_ddl.clone();
+                                let table_name = table_name.clone();
+                                move |_| {
+                                    let Some(sql) = loaded_ddl.clone() else {
+                                        return;
+                                    };
+                                    let table_name = table_name.clone();
+                                    spawn(async move {
+                                        let status = match copy_text(&sql).await {
+                                            Ok(()) => format!("Copied DDL for {table_name}"),
+                                            Err(err) => err,
+                                        };
+                                        set_active_tab_status(tabs, active_tab_id(), status);
+                                    });
+                                }
+                            },
+                            "Copy"
+                        }
+                        button {
+                            class: "button button--primary",
+                            disabled: loaded_ddl.is_none(),
+                            onclick: {
+                                let session_id = target.session_id;
+                                move |_| {
+                                    let Some(sql) = loaded_ddl.clone() else {
+                                        return;
+                                    };
+                                    let tab_id = ensure_tab_for_session(
+                                        tabs,
+                                        active_tab_id,
+                                        next_tab_id,
+                                        session_id,
+                                    );
+                                    if tab_id == 0 {
+                                        return;
+                                    }
+                                    append_to_tab_sql(
+                                        tabs,
+                                        tab_id,
+                                        sql,
+                                        format!("Inserted DDL for {table_name}"),
+                                    );
+                                    table_ddl.set(None);
+                                }
+                            },
+                            "Open in Editor"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
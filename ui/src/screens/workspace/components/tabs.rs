@@ -1,29 +1,34 @@
 use crate::{
     app_state::{
-        APP_AI_FEATURES_ENABLED, APP_SHOW_SQL_EDITOR, APP_SQL_FORMAT_SETTINGS, APP_STATE,
-        open_connection_screen,
+        APP_AI_FEATURES_ENABLED, APP_LAYOUT_REVISION, APP_SHOW_SQL_EDITOR, APP_SQL_FORMAT_SETTINGS,
+        APP_STATE, APP_UI_SETTINGS, open_connection_screen, session_connection,
+        session_transaction_savepoints, session_transaction_status, set_editor_height,
     },
+    clipboard::copy_text,
     screens::workspace::actions::{
-        new_query_tab, open_structure_tab, read_only_mode_block_status, read_only_mode_enabled,
-        refresh_tab_result, replace_active_tab_sql, run_explain_for_tab, run_query_for_tab,
-        set_active_tab_status, tab_connection_or_error, toggle_execution_plan_for_tab,
+        begin_session_transaction, finish_session_transaction, new_query_tab, open_structure_tab,
+        read_only_mode_block_status, read_only_mode_enabled, replace_active_tab_sql,
+        run_explain_for_tab, run_query_for_tab, set_active_tab_status, tab_connection_or_error,
+        tab_uses_postgres, toggle_execution_plan_for_tab,
     },
 };
 use dioxus::prelude::*;
 use models::{
-    AcpPanelState, QueryHistoryItem, QueryOutput, QueryTabState, SqlFormatSettings,
-    TablePreviewSource,
+    AcpPanelState, DatabaseConnection, QueryHistoryItem, QueryOutput, QueryTabState,
+    SqlFormatSettings, TablePreviewSource, TransactionStatus,
 };
 use rfd::AsyncFileDialog;
 
 use super::{
-    ActionIcon, ExecutionPlanView, ExplorerConnectionSection, IconButton, ResultTable, SqlEditor,
-    ensure_default_sql_agent_connected, send_sql_generation_request,
+    ActionIcon, CsvExportDialog, CsvExportTarget, CsvImportDialog, CsvImportTarget,
+    ExecutionPlanView, ExplorerConnectionSection, IconButton, JsonExportDialog, JsonExportTarget,
+    QueryParametersDialog, QueryParametersTarget, QueryTemplatesDialog, ResultTable, SavepointMenu,
+    SqlEditor, SqlExportDialog, SqlExportTarget, ensure_default_sql_agent_connected,
+    send_sql_generation_request,
 };
 
 const EDITOR_MIN_HEIGHT: f64 = 160.0;
 const EDITOR_MAX_HEIGHT: f64 = 720.0;
-const EDITOR_DEFAULT_HEIGHT: f64 = 180.0;
 
 #[derive(Clone, Copy, PartialEq)]
 struct EditorResizeState {
@@ -33,34 +38,25 @@ struct EditorResizeState {
 
 #[derive(Clone, Copy)]
 enum ExportFormat {
-    Csv,
-    Json,
     Xlsx,
     Xml,
     Html,
-    SqlDump,
 }
 
 impl ExportFormat {
     fn extension(self) -> &'static str {
         match self {
-            Self::Csv => "csv",
-            Self::Json => "json",
             Self::Xlsx => "xlsx",
             Self::Xml => "xml",
             Self::Html => "html",
-            Self::SqlDump => "sql",
         }
     }
 
     fn label(self) -> &'static str {
         match self {
-            Self::Csv => "CSV",
-            Self::Json => "JSON",
             Self::Xlsx => "XLSX",
             Self::Xml => "XML",
             Self::Html => "HTML",
-            Self::SqlDump => "SQL Dump",
         }
     }
 }
@@ -77,13 +73,36 @@ pub fn TabsManager(
     chat_revision: Signal<u64>,
     allow_agent_db_read: Signal<bool>,
 ) -> Element {
-    let mut editor_height = use_signal(|| EDITOR_DEFAULT_HEIGHT);
+    let mut editor_height = use_signal(|| {
+        f64::from(APP_UI_SETTINGS.peek().panel_sizes.editor_height)
+            .clamp(EDITOR_MIN_HEIGHT, EDITOR_MAX_HEIGHT)
+    });
     let mut editor_resize = use_signal(|| None::<EditorResizeState>);
+    use_effect(move || {
+        APP_LAYOUT_REVISION();
+        editor_height.set(
+            f64::from(APP_UI_SETTINGS.peek().panel_sizes.editor_height)
+                .clamp(EDITOR_MIN_HEIGHT, EDITOR_MAX_HEIGHT),
+        );
+    });
+    // Persist the height once per drag rather than on every mouse move.
+    let mut finish_editor_resize = move || {
+        if editor_resize.peek().is_some() {
+            editor_resize.set(None);
+            set_editor_height(*editor_height.peek());
+        }
+    };
     let mut show_generate_sql_window = use_signal(|| false);
     let mut generate_sql_prompt = use_signal(String::new);
     let mut generate_sql_input_revision = use_signal(|| 0_u64);
     let mut renaming_tab_id = use_signal(|| None::<u64>);
     let mut rename_value = use_signal(String::new);
+    let mut csv_import_target = use_signal(|| None::<CsvImportTarget>);
+    let mut csv_export_target = use_signal(|| None::<CsvExportTarget>);
+    let mut json_export_target = use_signal(|| None::<JsonExportTarget>);
+    let mut sql_export_target = use_signal(|| None::<SqlExportTarget>);
+    let mut show_templates = use_signal(|| false);
+    let mut query_parameters_target = use_signal(|| None::<QueryParametersTarget>);
     let active_tab = use_memo(move || {
         tabs.read()
             .iter()
@@ -104,6 +123,16 @@ pub fn TabsManager(
     let generate_sql_prompt_empty = generate_sql_prompt().trim().is_empty();
     let generate_sql_input_key = format!("generate-sql-{}", generate_sql_input_revision());
     let read_only_mode = read_only_mode_enabled();
+    let active_session_id = active_tab.read().as_ref().map(|tab| tab.session_id);
+    let transaction_status = active_session_id
+        .map(session_transaction_status)
+        .unwrap_or_default();
+    let savepoints = active_session_id
+        .map(session_transaction_savepoints)
+        .unwrap_or_default();
+    let transactions_supported = active_session_id
+        .and_then(session_connection)
+        .is_some_and(|connection| !matches!(connection, DatabaseConnection::ClickHouse(_)));
 
     rsx! {
         div {
@@ -131,7 +160,7 @@ pub fn TabsManager(
                 };
 
                 if event.held_buttons().is_empty() {
-                    editor_resize.set(None);
+                    finish_editor_resize();
                     return;
                 }
 
@@ -140,8 +169,8 @@ pub fn TabsManager(
                     (resize.start_height + delta_y).clamp(EDITOR_MIN_HEIGHT, EDITOR_MAX_HEIGHT);
                 editor_height.set(next_height);
             },
-            onmouseup: move |_| editor_resize.set(None),
-            onmouseleave: move |_| editor_resize.set(None),
+            onmouseup: move |_| finish_editor_resize(),
+            onmouseleave: move |_| finish_editor_resize(),
             div {
                 class: "tabbar",
                 for tab in tabs() {
@@ -306,7 +335,7 @@ pub fn TabsManager(
                                 return;
                             };
 
-                            let sql = current_tab.sql.trim().to_string();
+                            let mut sql = current_tab.sql.trim().to_string();
                             let tab_title = current_tab.title.clone();
                             let page_size = current_tab.page_size;
                             let connection_name = APP_STATE
@@ -324,12 +353,47 @@ pub fn TabsManager(
                                 return;
                             }
 
+                            if APP_UI_SETTINGS.peek().format_on_execute {
+                                let session_kind = APP_STATE
+                                    .read()
+                                    .session(current_tab.session_id)
+                                    .map(|session| session.kind);
+                                let formatted = services::format_sql(
+                                    session_kind,
+                                    &sql,
+                                    &APP_SQL_FORMAT_SETTINGS.peek(),
+                                );
+                                sql = formatted.trim_end().to_string();
+                                replace_active_tab_sql(
+                                    tabs,
+                                    current_id,
+                                    formatted,
+                                    "SQL formatted".to_string(),
+                                );
+                            }
+
                             let Some(connection) =
                                 tab_connection_or_error(tabs, current_id, current_tab.session_id)
                             else {
                                 return;
                             };
 
+                            if matches!(connection, DatabaseConnection::Postgres(_)) {
+                                let parameters = services::find_query_parameters(&sql);
+                                if !parameters.is_empty() {
+                                    query_parameters_target.set(Some(QueryParametersTarget {
+                                        tab_id: current_id,
+                                        session_id: current_tab.session_id,
+                                        sql,
+                                        parameters,
+                                        page_size,
+                                        tab_title,
+                                        connection_name,
+                                    }));
+                                    return;
+                                }
+                            }
+
                             run_query_for_tab(
                                 tabs,
                                 current_id,
@@ -351,6 +415,11 @@ pub fn TabsManager(
                             move |_| format_active_sql(tabs, current_tab.clone(), format_settings.clone())
                         },
                     }
+                    IconButton {
+                        icon: ActionIcon::Templates,
+                        label: "Query templates".to_string(),
+                        onclick: move |_| show_templates.set(true),
+                    }
                     IconButton {
                         icon: ActionIcon::Generate,
                         label: "Generate SQL".to_string(),
@@ -438,7 +507,11 @@ pub fn TabsManager(
                         disabled: !has_tabular_result(tab),
                         onclick: {
                             let current_tab = tab.clone();
-                            move |_| export_active_page(tabs, current_tab.clone(), ExportFormat::Csv)
+                            move |_| {
+                                if let Some(target) = csv_export_target_for_tab(tabs, &current_tab) {
+                                    csv_export_target.set(Some(target));
+                                }
+                            }
                         },
                     }
                     IconButton {
@@ -447,7 +520,11 @@ pub fn TabsManager(
                         disabled: !has_tabular_result(tab),
                         onclick: {
                             let current_tab = tab.clone();
-                            move |_| export_active_page(tabs, current_tab.clone(), ExportFormat::Json)
+                            move |_| {
+                                if let Some(target) = json_export_target_for_tab(tabs, &current_tab) {
+                                    json_export_target.set(Some(target));
+                                }
+                            }
                         },
                     }
                     IconButton {
@@ -477,13 +554,35 @@ pub fn TabsManager(
                             move |_| export_active_page(tabs, current_tab.clone(), ExportFormat::Html)
                         },
                     }
+                    IconButton {
+                        icon: ActionIcon::CopyMarkdown,
+                        label: "Copy as Markdown".to_string(),
+                        disabled: !has_tabular_result(tab),
+                        onclick: {
+                            let current_tab = tab.clone();
+                            move |_| copy_active_page_markdown(tabs, current_tab.clone())
+                        },
+                    }
+                    IconButton {
+                        icon: ActionIcon::CopyInsert,
+                        label: "Copy as INSERT".to_string(),
+                        disabled: !has_tabular_result(tab),
+                        onclick: {
+                            let current_tab = tab.clone();
+                            move |_| copy_active_page_inserts(tabs, current_tab.clone())
+                        },
+                    }
                     IconButton {
                         icon: ActionIcon::ExportSql,
-                        label: "SQL Dump".to_string(),
+                        label: "Export SQL INSERTs".to_string(),
                         disabled: !has_tabular_result(tab),
                         onclick: {
                             let current_tab = tab.clone();
-                            move |_| export_active_page(tabs, current_tab.clone(), ExportFormat::SqlDump)
+                            move |_| {
+                                if let Some(target) = sql_export_target_for_tab(tabs, &current_tab) {
+                                    sql_export_target.set(Some(target));
+                                }
+                            }
                         },
                     }
                     IconButton {
@@ -496,9 +595,87 @@ pub fn TabsManager(
                         disabled: active_actionable_source.is_none() || read_only_mode,
                         onclick: {
                             let current_tab = tab.clone();
-                            move |_| import_csv_into_active_table(tabs, current_tab.clone())
+                            move |_| {
+                                if let Some(target) = csv_import_target_for_tab(tabs, &current_tab) {
+                                    csv_import_target.set(Some(target));
+                                }
+                            }
                         },
                     }
+                    if transaction_status == TransactionStatus::Idle {
+                        button {
+                            class: "button button--ghost button--small",
+                            disabled: !transactions_supported,
+                            title: if transactions_supported {
+                                "Run the following statements in one transaction"
+                            } else {
+                                "Transactions are not supported for this connection"
+                            },
+                            onclick: move |_| {
+                                let current_id = active_tab_id();
+                                let Some(session_id) = tabs
+                                    .read()
+                                    .iter()
+                                    .find(|tab| tab.id == current_id)
+                                    .map(|tab| tab.session_id)
+                                else {
+                                    return;
+                                };
+                                let Some(connection) =
+                                    tab_connection_or_error(tabs, current_id, session_id)
+                                else {
+                                    return;
+                                };
+                                begin_session_transaction(tabs, current_id, session_id, connection);
+                            },
+                            "Begin"
+                        }
+                    } else {
+                        span {
+                            class: if transaction_status == TransactionStatus::Aborted {
+                                "editor__transaction editor__transaction--aborted"
+                            } else {
+                                "editor__transaction"
+                            },
+                            title: if transaction_status == TransactionStatus::Aborted {
+                                "A statement failed; roll back to continue"
+                            } else {
+                                "Statements run inside an open transaction"
+                            },
+                            if transaction_status == TransactionStatus::Aborted {
+                                "TRANSACTION ABORTED"
+                            } else {
+                                "TRANSACTION"
+                            }
+                        }
+                        button {
+                            class: "button button--ghost button--small",
+                            disabled: transaction_status == TransactionStatus::Aborted,
+                            onclick: move |_| {
+                                if let Some(session_id) = active_session_id {
+                                    finish_session_transaction(tabs, active_tab_id(), session_id, true);
+                                }
+                            },
+                            "Commit"
+                        }
+                        if let Some(session_id) = active_session_id {
+                            SavepointMenu {
+                                tabs,
+                                active_tab_id,
+                                session_id,
+                                savepoints: savepoints.clone(),
+                            }
+                        }
+                        button {
+                            class: "button button--ghost button--small",
+                            onclick: move |_| {
+                                if let Some(session_id) = active_session_id {
+                                    finish_session_transaction(tabs, active_tab_id(), session_id, false);
+                                }
+                            },
+                            "Rollback"
+                        }
+                    }
                 }
                 div {
                     class: "workspace__results",
@@ -610,6 +787,7 @@ pub fn TabsManager(
                                 result: tab.result.clone(),
                                 tabs,
                                 active_tab_id,
+                                next_tab_id,
                             }
                         }
                     } else {
@@ -617,6 +795,7 @@ pub fn TabsManager(
                             result: tab.result.clone(),
                             tabs,
                             active_tab_id,
+                            next_tab_id,
                         }
                     }
                 }
@@ -626,6 +805,19 @@ pub fn TabsManager(
                     p { class: "empty-state", "No active tab for the selected connection." }
                 }
             }
+            CsvImportDialog { tabs, target: csv_import_target }
+            CsvExportDialog { tabs, target: csv_export_target }
+            JsonExportDialog { tabs, target: json_export_target }
+            SqlExportDialog { tabs, target: sql_export_target }
+            QueryParametersDialog {
+                tabs,
+                history,
+                next_history_id,
+                target: query_parameters_target,
+            }
+            if show_templates() {
+                QueryTemplatesDialog { tabs, active_tab_id, show_templates }
+            }
         }
     }
 }
@@ -674,19 +866,9 @@ fn export_active_page(
         );
 
         let export_result = match format {
-            ExportFormat::Csv => services::export_query_page_csv(page, path.clone()).await,
-            ExportFormat::Json => services::export_query_page_json(page, path.clone()).await,
             ExportFormat::Xlsx => services::export_query_page_xlsx(page, path.clone()).await,
             ExportFormat::Xml => services::export_query_page_xml(page, path.clone()).await,
             ExportFormat::Html => services::export_query_page_html(page, path.clone()).await,
-            ExportFormat::SqlDump => {
-                let table_name = current_tab
-                    .preview_source
-                    .as_ref()
-                    .map(|s| s.table_name.clone())
-                    .unwrap_or_else(|| "exported_table".to_string());
-                services::export_query_page_sql_dump(page, path.clone(), table_name).await
-            }
         };
 
         match export_result {
@@ -711,85 +893,169 @@ fn export_active_page(
     });
 }
 
-fn import_csv_into_active_table(tabs: Signal<Vec<QueryTabState>>, current_tab: QueryTabState) {
-    if read_only_mode_enabled() {
+fn copy_active_page_markdown(tabs: Signal<Vec<QueryTabState>>, current_tab: QueryTabState) {
+    let Some(QueryOutput::Table(page)) = current_tab.result.as_ref() else {
         set_active_tab_status(
             tabs,
             current_tab.id,
-            read_only_mode_block_status("CSV import"),
+            "Nothing to copy in the current tab".to_string(),
         );
         return;
-    }
+    };
 
-    let Some(source) = actionable_table_source(&current_tab) else {
+    let markdown = services::query_page_to_markdown(page);
+    let rows = page.rows.len();
+    spawn(async move {
+        let status = match copy_text(&markdown).await {
+            Ok(()) => format!("Copied {rows} row(s) as Markdown"),
+            Err(err) => err,
+        };
+        set_active_tab_status(tabs, current_tab.id, status);
+    });
+}
+
+fn copy_active_page_inserts(tabs: Signal<Vec<QueryTabState>>, current_tab: QueryTabState) {
+    let Some(QueryOutput::Table(page)) = current_tab.result.as_ref() else {
         set_active_tab_status(
             tabs,
             current_tab.id,
-            "Import CSV is available for previewed tables and simple single-table SELECT queries"
-                .to_string(),
+            "Nothing to copy in the current tab".to_string(),
         );
         return;
     };
 
-    let Some(connection) = tab_connection_or_error(tabs, current_tab.id, current_tab.session_id)
-    else {
-        return;
+    let (schema, table_name) = insert_target_for_tab(&current_tab);
+    let sql = services::query_page_to_insert_sql(page, schema.as_deref(), &table_name);
+    let rows = page.rows.len();
+    spawn(async move {
+        let status = match copy_text(&sql).await {
+            Ok(()) => format!("Copied {rows} row(s) as INSERT into {table_name}"),
+            Err(err) => err,
+        };
+        set_active_tab_status(tabs, current_tab.id, status);
+    });
+}
+
+/// Table that INSERT exports of a tab's rows target: the previewed table, or
+/// the single table a plain SELECT read from.
+fn insert_target_for_tab(tab: &QueryTabState) -> (Option<String>, String) {
+    tab.preview_source
+        .clone()
+        .or_else(|| {
+            tab.last_run_sql
+                .as_deref()
+                .and_then(services::preview_source_for_sql)
+        })
+        .map(|source| (source.schema, source.table_name))
+        .unwrap_or_else(|| (None, "exported_table".to_string()))
+}
+
+fn csv_export_target_for_tab(
+    tabs: Signal<Vec<QueryTabState>>,
+    current_tab: &QueryTabState,
+) -> Option<CsvExportTarget> {
+    let Some(QueryOutput::Table(page)) = current_tab.result.as_ref() else {
+        set_active_tab_status(
+            tabs,
+            current_tab.id,
+            "Nothing to export in the current tab".to_string(),
+        );
+        return None;
     };
 
-    set_active_tab_status(
-        tabs,
-        current_tab.id,
-        format!("Select a CSV file to import into {}", source.table_name),
-    );
+    Some(CsvExportTarget {
+        tab_id: current_tab.id,
+        file_name: format!("{}.csv", export_file_stem(current_tab)),
+        loaded_rows: page.rows.len(),
+        partial: page.has_next || page.offset > 0,
+        copy_available: current_tab.preview_source.is_some()
+            && tab_uses_postgres(tabs, current_tab.id),
+    })
+}
 
-    spawn(async move {
-        let Some(file) = AsyncFileDialog::new()
-            .add_filter("CSV", &["csv"])
-            .pick_file()
-            .await
-        else {
-            set_active_tab_status(tabs, current_tab.id, "CSV import cancelled".to_string());
-            return;
-        };
+fn json_export_target_for_tab(
+    tabs: Signal<Vec<QueryTabState>>,
+    current_tab: &QueryTabState,
+) -> Option<JsonExportTarget> {
+    if !matches!(current_tab.result, Some(QueryOutput::Table(_))) {
+        set_active_tab_status(
+            tabs,
+            current_tab.id,
+            "Nothing to export in the current tab".to_string(),
+        );
+        return None;
+    }
 
-        let path = file.path().to_path_buf();
+    Some(JsonExportTarget {
+        tab_id: current_tab.id,
+        file_stem: export_file_stem(current_tab),
+    })
+}
+
+fn sql_export_target_for_tab(
+    tabs: Signal<Vec<QueryTabState>>,
+    current_tab: &QueryTabState,
+) -> Option<SqlExportTarget> {
+    if !matches!(current_tab.result, Some(QueryOutput::Table(_))) {
         set_active_tab_status(
             tabs,
             current_tab.id,
-            format!("Importing {}...", path.to_string_lossy()),
+            "Nothing to export in the current tab".to_string(),
         );
+        return None;
+    }
 
-        match services::import_csv_into_table(connection, source.clone(), path).await {
-            Ok(rows) => {
-                set_active_tab_status(
-                    tabs,
-                    current_tab.id,
-                    format!("Imported {rows} row(s) into {}", source.table_name),
-                );
-                if let Some(updated_tab) = tabs
-                    .read()
-                    .iter()
-                    .find(|tab| tab.id == current_tab.id)
-                    .cloned()
-                {
-                    refresh_tab_result(tabs, updated_tab, Some(source));
-                }
-            }
-            Err(err) => {
-                set_active_tab_status(tabs, current_tab.id, format!("CSV import error: {err}"))
-            }
-        }
-    });
+    let (schema, table_name) = insert_target_for_tab(current_tab);
+    Some(SqlExportTarget {
+        tab_id: current_tab.id,
+        file_name: format!("{}.sql", export_file_stem(current_tab)),
+        table_label: match schema {
+            Some(schema) => format!("{schema}.{table_name}"),
+            None => table_name,
+        },
+    })
+}
+
+fn csv_import_target_for_tab(
+    tabs: Signal<Vec<QueryTabState>>,
+    current_tab: &QueryTabState,
+) -> Option<CsvImportTarget> {
+    if read_only_mode_enabled() {
+        set_active_tab_status(
+            tabs,
+            current_tab.id,
+            read_only_mode_block_status("CSV import"),
+        );
+        return None;
+    }
+
+    let Some(source) = actionable_table_source(current_tab) else {
+        set_active_tab_status(
+            tabs,
+            current_tab.id,
+            "Import CSV is available for previewed tables and simple single-table SELECT queries"
+                .to_string(),
+        );
+        return None;
+    };
+
+    Some(CsvImportTarget {
+        tab_id: current_tab.id,
+        source,
+    })
 }
 
 fn default_export_file_name(tab: &QueryTabState, format: ExportFormat) -> String {
+    format!("{}.{}", export_file_stem(tab), format.extension())
+}
+
+fn export_file_stem(tab: &QueryTabState) -> String {
     let base = tab
         .preview_source
         .as_ref()
         .map(|source| source.table_name.clone())
         .unwrap_or_else(|| tab.title.clone());
-    let sanitized = sanitize_file_name(&base);
-    format!("{sanitized}.{}", format.extension())
+    sanitize_file_name(&base)
 }
 
 fn sanitize_file_name(value: &str) -> String {
@@ -0,0 +1,249 @@
+use crate::app_state::session_connection;
+use crate::screens::workspace::actions::{
+    append_to_tab_sql, ensure_tab_for_session, read_only_mode_block_status, read_only_mode_enabled,
+};
+use dioxus::prelude::*;
+use models::{QueryTabState, StatementStat};
+use rfd::{AsyncMessageDialog, MessageButtons, MessageDialogResult, MessageLevel};
+
+const LIMIT_OPTIONS: [i64; 4] = [10, 25, 50, 100];
+const QUERY_PREVIEW_CHARS: usize = 160;
+
+/// Statements with the most total execution time, from `pg_stat_statements`.
+/// Clicking one inserts its normalized text into the editor.
+#[component]
+pub(crate) fn TopQueriesWindow(
+    session_id: u64,
+    connection_name: String,
+    mut show_top_queries: Signal<bool>,
+    tabs: Signal<Vec<QueryTabState>>,
+    active_tab_id: Signal<u64>,
+    next_tab_id: Signal<u64>,
+) -> Element {
+    let mut statements = use_signal(|| None::<Result<Option<Vec<StatementStat>>, String>>);
+    let mut reload = use_signal(|| 0_u64);
+    let mut limit = use_signal(|| 25_i64);
+    let mut status = use_signal(String::new);
+    let read_only_mode = read_only_mode_enabled();
+
+    use_effect(move || {
+        reload();
+        let limit = limit();
+        spawn(async move {
+            let result = match session_connection(session_id) {
+                Some(connection) => services::load_statement_stats(connection, limit)
+                    .await
+                    .map_err(|err| err.to_string()),
+                None => Err("Connection is no longer open.".to_string()),
+            };
+            statements.set(Some(result));
+        });
+    });
+
+    let installed = matches!(statements(), Some(Ok(Some(_))));
+
+    rsx! {
+        div {
+            class: "settings-modal__backdrop",
+            onclick: move |_| show_top_queries.set(false),
+            div {
+                class: "settings-modal table-modal table-modal--wide",
+                onclick: move |event| event.stop_propagation(),
+                div {
+                    class: "settings-modal__header",
+                    div {
+                        class: "settings-modal__header-copy",
+                        h2 { class: "settings-modal__title", "Top Queries" }
+                        p {
+                            class: "settings-modal__hint",
+                            "Statements on {connection_name} by total execution time, from pg_stat_statements."
+                        }
+                    }
+                    button {
+                        class: "button button--ghost button--small",
+                        onclick: move |_| reload += 1,
+                        "Refresh"
+                    }
+                    button {
+                        class: "button button--ghost button--small",
+                        onclick: move |_| show_top_queries.set(false),
+                        "Close"
+                    }
+                }
+
+                div {
+                    class: "table-modal__body",
+                    if installed {
+                        div {
+                            class: "table-modal__toolbar",
+                            select {
+                                class: "input",
+                                onchange: move |event| {
+                                    if let Ok(value) = event.value().parse() {
+                                        limit.set(value);
+                                    }
+                                },
+                                for option in LIMIT_OPTIONS {
+                                    option {
+                                        value: "{option}",
+                                        selected: limit() == option,
+                                        "Top {option}"
+                                    }
+                                }
+                            }
+                            button {
+                                class: "button button--ghost button--small",
+                                disabled: read_only_mode,
+                                title: if read_only_mode {
+                                    "Resetting statistics is blocked by read-only mode"
+                                } else {
+                                    ""
+                                },
+                                onclick: move |_| {
+                                    if read_only_mode_enabled() {
+                                        status.set(read_only_mode_block_status("resetting pg_stat_statements"));
+                                        return;
+                                    }
+                                    spawn(async move {
+                                        let confirmation = AsyncMessageDialog::new()
+                                            .set_title("Reset Statistics")
+                                            .set_description(
+                                                "Discard everything pg_stat_statements has collected on this server?",
+                                            )
+                                            .set_buttons(MessageButtons::YesNo)
+                                            .set_level(MessageLevel::Warning)
+                                            .show()
+                                            .await;
+                                        if confirmation != MessageDialogResult::Yes {
+                                            return;
+                                        }
+                                        let Some(connection) = session_connection(session_id) else {
+                                            status.set("Connection is no longer open.".to_string());
+                                            return;
+                                        };
+                                        match services::reset_statement_stats(connection).await {
+                                            Ok(()) => {
+                                                status.set("Statistics reset.".to_string());
+                                                reload += 1;
+                                            }
+                                            Err(err) => status.set(err.to_string()),
+                                        }
+                                    });
+                                },
+                                "Reset statistics"
+                            }
+                        }
+                    }
+                    if !status().is_empty() {
+                        p { class: "table-modal__hint", "{status}" }
+                    }
+
+                    match statements() {
+                        None => rsx! {
+                            p { class: "table-modal__hint", "Loading statement statistics…" }
+                        },
+                        Some(Err(err)) => rsx! {
+                            p { class: "table-modal__error", "{err}" }
+                        },
+                        Some(Ok(None)) => rsx! {
+                            p {
+                                class: "table-modal__hint",
+                                "pg_stat_statements is not installed in this database. Add it to shared_preload_libraries in postgresql.conf, restart the server, then run:"
+                            }
+                            pre { class: "table-modal__preview-sql", "create extension pg_stat_statements;" }
+                        },
+                        Some(Ok(Some(statements))) if statements.is_empty() => rsx! {
+                            p { class: "table-modal__hint", "No statements recorded since the last reset." }
+                        },
+                        Some(Ok(Some(statements))) => rsx! {
+                            div {
+                                class: "table-modal__table-wrap",
+                                table {
+                                    class: "table-modal__table",
+                                    thead {
+                                        tr {
+                                            th { "Calls" }
+                                            th { "Total (ms)" }
+                                            th { "Mean (ms)" }
+                                            th { "Rows" }
+                                            th { "Query" }
+                                        }
+                                    }
+                                    tbody {
+                                        for statement in statements {
+                                            tr {
+                                                class: "top-queries__row",
+                                                title: "Insert into the editor",
+                                                onclick: {
+                                                    let query = statement.query.clone();
+                                                    move |_| {
+                                                        let tab_id = ensure_tab_for_session(
+                                                            tabs,
+                                                            active_tab_id,
+                                                            next_tab_id,
+                                                            session_id,
+                                                        );
+                                                        if tab_id == 0 {
+                                                            return;
+                                                        }
+                                                        append_to_tab_sql(
+                                                            tabs,
+                                                            tab_id,
+                                                            query.clone(),
+                                                            "Inserted statement from pg_stat_statements".to_string(),
+                                                        );
+                                                        show_top_queries.set(false);
+                                                    }
+                                                },
+                                                td { class: "table-modal__cell--numeric", "{statement.calls}" }
+                                                td { class: "table-modal__cell--numeric", "{statement.total_exec_ms:.1}" }
+                                                td { class: "table-modal__cell--numeric", "{statement.mean_exec_ms:.2}" }
+                                                td { class: "table-modal__cell--numeric", "{statement.rows}" }
+                                                td {
+                                                    class: "table-modal__cell--code",
+                                                    "{query_preview(&statement.query)}"
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        },
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// `query` on one line, cut to [`QUERY_PREVIEW_CHARS`] characters.
+fn query_preview(query: &str) -> String {
+    let single_line = query.split_whitespace().collect::<Vec<_>>().join(" ");
+    if single_line.chars().count() <= QUERY_PREVIEW_CHARS {
+        return single_line;
+    }
+    let mut preview = single_line
+        .chars()
+        .take(QUERY_PREVIEW_CHARS)
+        .collect::<String>();
+    preview.push('…');
+    preview
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{QUERY_PREVIEW_CHARS, query_preview};
+
+    #[test]
+    fn query_preview_collapses_whitespace_and_truncates() {
+        assert_eq!(
+            query_preview("select *\n  from orders\twhere id = $1"),
+            "select * from orders where id = $1"
+        );
+
+        let long = format!("select {}", "x".repeat(QUERY_PREVIEW_CHARS));
+        let preview = query_preview(&long);
+        assert_eq!(preview.chars().count(), QUERY_PREVIEW_CHARS + 1);
+        assert!(preview.ends_with('…'));
+    }
+}
@@ -133,6 +133,7 @@ pub async fn load_explorer_section(
         Ok(nodes) => {
             let section = ExplorerConnectionSection {
                 session_id: session.id,
+                parent_session_id: session.parent_id,
                 name: connection_target_label(&session.request),
                 kind_label: kind_label.clone(),
                 status: "Ready".to_string(),
@@ -147,6 +148,7 @@ pub async fn load_explorer_section(
         }
         Err(err) => ExplorerConnectionSection {
             session_id: session.id,
+            parent_session_id: session.parent_id,
             name: connection_target_label(&session.request),
             kind_label,
             status: format_explorer_error(&err),
@@ -174,6 +176,7 @@ pub fn unloaded_explorer_section(
 
     ExplorerConnectionSection {
         session_id: session.id,
+        parent_session_id: session.parent_id,
         name: connection_target_label(&session.request),
         kind_label,
         status: status.to_string(),
@@ -187,6 +190,7 @@ pub struct ToolPanelVisibility {
     pub show_connections: bool,
     pub show_explorer: bool,
     pub show_history: bool,
+    pub show_notifications: bool,
     pub show_agent_panel: bool,
     pub ai_features_enabled: bool,
 }
@@ -197,6 +201,7 @@ fn is_tool_panel_visible(panel: WorkspaceToolPanel, vis: &ToolPanelVisibility) -
         WorkspaceToolPanel::Connections => vis.show_connections,
         WorkspaceToolPanel::Explorer => vis.show_explorer,
         WorkspaceToolPanel::History => vis.show_history,
+        WorkspaceToolPanel::Notifications => vis.show_notifications,
         WorkspaceToolPanel::Agent => vis.ai_features_enabled && vis.show_agent_panel,
     }
 }
@@ -354,6 +359,7 @@ pub fn tool_panel_class(panel: WorkspaceToolPanel) -> &'static str {
         WorkspaceToolPanel::Explorer => " workspace__tool-panel--explorer",
         WorkspaceToolPanel::SavedQueries => " workspace__tool-panel--saved",
         WorkspaceToolPanel::History => " workspace__tool-panel--history",
+        WorkspaceToolPanel::Notifications => " workspace__tool-panel--notifications",
         WorkspaceToolPanel::Agent => " workspace__tool-panel--agent",
     }
 }
@@ -6,10 +6,12 @@ pub mod helpers;
 mod hooks;
 
 use crate::app_state::{
-    APP_AI_FEATURES_ENABLED, APP_SHOW_AGENT_PANEL, APP_SHOW_CONNECTIONS, APP_SHOW_EXPLORER,
-    APP_SHOW_HISTORY, APP_SHOW_SAVED_QUERIES, APP_SHOW_SQL_EDITOR, APP_STATE, APP_UI_SETTINGS,
-    open_connection_screen, set_show_agent_panel, set_show_connections, set_show_explorer,
-    set_show_history, set_show_saved_queries, set_show_sql_editor, update_ui_settings,
+    APP_AI_FEATURES_ENABLED, APP_LAYOUT_REVISION, APP_SHOW_AGENT_PANEL, APP_SHOW_CONNECTIONS,
+    APP_SHOW_EXPLORER, APP_SHOW_HISTORY, APP_SHOW_NOTIFICATIONS, APP_SHOW_SAVED_QUERIES,
+    APP_SHOW_SQL_EDITOR, APP_STATE, APP_UI_SETTINGS, open_connection_screen, set_inspector_width,
+    set_show_agent_panel, set_show_connections, set_show_explorer, set_show_history,
+    set_show_notifications, set_show_saved_queries, set_show_sql_editor, set_sidebar_width,
+    update_ui_settings,
 };
 use dioxus::{html::input_data::MouseButton, prelude::*};
 use models::{
@@ -20,8 +22,8 @@ use models::{
 use self::{
     chat::{create_chat_thread, delete_chat_thread, select_chat_thread},
     components::{
-        AcpAgentPanel, ActionIcon, IconButton, QueryHistoryPanel, SavedQueriesPanel, SessionRail,
-        SidebarConnectionTree, TabsManager,
+        AcpAgentPanel, ActionIcon, IconButton, NotificationsPanel, QueryHistoryPanel,
+        SavedQueriesPanel, SchemaDiffWindow, SessionRail, SidebarConnectionTree, TabsManager,
     },
     helpers::{
         DockDropTarget, INSPECTOR_MAX_WIDTH, INSPECTOR_MIN_WIDTH, SIDEBAR_MAX_WIDTH,
@@ -244,12 +246,15 @@ fn WorkspacePanelContent(
             div {
                 class: "workspace__panel workspace__panel--history",
                 QueryHistoryPanel {
-                    history: history(),
+                    history,
                     tabs,
                     active_tab_id,
                 }
             }
         },
+        WorkspaceToolPanel::Notifications => rsx! {
+            NotificationsPanel {}
+        },
         WorkspaceToolPanel::Agent => rsx! {
             AgentToolPanel {
                 acp_panel_state,
@@ -466,6 +471,7 @@ fn WorkspaceBody(
     ai_features_enabled: bool,
     show_agent_panel: bool,
     show_history: bool,
+    show_notifications: bool,
     tree_reload: Signal<u64>,
     dragging_panel: Signal<Option<WorkspaceToolPanel>>,
     drop_target: Signal<Option<DockDropTarget>>,
@@ -478,7 +484,10 @@ fn WorkspaceBody(
     chat_threads: Signal<Vec<ChatThreadSummary>>,
     active_chat_thread_id: Signal<Option<i64>>,
     connection_label: String,
+    production: bool,
 ) -> Element {
+    let mut show_schema_diff = use_signal(|| false);
+
     rsx! {
         if show_sidebar {
             aside {
@@ -541,7 +550,10 @@ fn WorkspaceBody(
                         .await;
 
                         match result {
-                            Ok(width) => sidebar_width.set(width),
+                            Ok(width) => {
+                                sidebar_width.set(width);
+                                set_sidebar_width(width);
+                            }
                             Err(err) => {
                                 eprintln!("Failed to resize workspace sidebar: {err:?}");
                             }
@@ -553,7 +565,18 @@ fn WorkspaceBody(
             }
         }
         section {
-            class: "workspace__main",
+            class: if production {
+                "workspace__main workspace__main--production"
+            } else {
+                "workspace__main"
+            },
+            if production {
+                div {
+                    class: "workspace__production-banner",
+                    role: "status",
+                    "⚠ PRODUCTION"
+                }
+            }
             header {
                 class: "workspace__header",
                 div {
@@ -602,6 +625,17 @@ fn WorkspaceBody(
                         small: true,
                         onclick: move |_| set_show_history(!APP_SHOW_HISTORY()),
                     }
+                    IconButton {
+                        icon: ActionIcon::Notifications,
+                        label: if show_notifications {
+                            "Hide notifications".to_string()
+                        } else {
+                            "Show notifications".to_string()
+                        },
+                        active: show_notifications,
+                        small: true,
+                        onclick: move |_| set_show_notifications(!APP_SHOW_NOTIFICATIONS()),
+                    }
                     IconButton {
                         icon: ActionIcon::SqlEditor,
                         label: if APP_SHOW_SQL_EDITOR() {
@@ -626,6 +660,13 @@ fn WorkspaceBody(
                             onclick: move |_| set_show_agent_panel(!APP_SHOW_AGENT_PANEL()),
                         }
                     }
+                    IconButton {
+                        icon: ActionIcon::SchemaDiff,
+                        label: "Schema diff".to_string(),
+                        active: show_schema_diff(),
+                        small: true,
+                        onclick: move |_| show_schema_diff.set(true),
+                    }
                     IconButton {
                         icon: ActionIcon::Refresh,
                         label: "Refresh explorer".to_string(),
@@ -692,7 +733,10 @@ fn WorkspaceBody(
                                 .await;
 
                                 match result {
-                                    Ok(width) => inspector_width.set(width),
+                                    Ok(width) => {
+                                        inspector_width.set(width);
+                                        set_inspector_width(width);
+                                    }
                                     Err(err) => {
                                         eprintln!(
                                             "Failed to resize workspace inspector: {err:?}"
@@ -734,6 +778,15 @@ fn WorkspaceBody(
                 }
             }
         }
+        if show_schema_diff() {
+            SchemaDiffWindow {
+                sections: tree_sections(),
+                show_schema_diff,
+                tabs,
+                active_tab_id,
+                next_tab_id,
+            }
+        }
     }
 }
 
@@ -744,12 +797,32 @@ pub fn Workspace() -> Element {
         .as_ref()
         .map(|session| session.name.clone())
         .unwrap_or_else(|| "No connection".to_string());
+    let production = active_session
+        .as_ref()
+        .and_then(|session| session.environment.as_ref())
+        .is_some_and(|environment| environment.is_production());
     let show_history = APP_SHOW_HISTORY();
+    let show_notifications = APP_SHOW_NOTIFICATIONS();
 
     // ── Layout signals (owned by Workspace) ────────────────────────
-    let sidebar_width = use_signal(|| 320.0);
+    let mut sidebar_width = use_signal(|| {
+        f64::from(APP_UI_SETTINGS.peek().panel_sizes.sidebar_width)
+            .clamp(SIDEBAR_MIN_WIDTH, SIDEBAR_MAX_WIDTH)
+    });
     let sidebar_resize_active = use_signal(|| false);
-    let inspector_width = use_signal(|| 360.0);
+    let mut inspector_width = use_signal(|| {
+        f64::from(APP_UI_SETTINGS.peek().panel_sizes.inspector_width)
+            .clamp(INSPECTOR_MIN_WIDTH, INSPECTOR_MAX_WIDTH)
+    });
+    // "Reset layout" puts the saved sizes back, and the panels follow.
+    use_effect(move || {
+        APP_LAYOUT_REVISION();
+        let sizes = APP_UI_SETTINGS.peek().panel_sizes;
+        sidebar_width
+            .set(f64::from(sizes.sidebar_width).clamp(SIDEBAR_MIN_WIDTH, SIDEBAR_MAX_WIDTH));
+        inspector_width
+            .set(f64::from(sizes.inspector_width).clamp(INSPECTOR_MIN_WIDTH, INSPECTOR_MAX_WIDTH));
+    });
     let inspector_resize_active = use_signal(|| false);
     let mut dragging_panel = use_signal(|| None::<WorkspaceToolPanel>);
     let mut drop_target = use_signal(|| None::<DockDropTarget>);
@@ -830,6 +903,7 @@ pub fn Workspace() -> Element {
         show_connections: APP_SHOW_CONNECTIONS(),
         show_explorer: APP_SHOW_EXPLORER(),
         show_history,
+        show_notifications,
         show_agent_panel: APP_SHOW_AGENT_PANEL(),
         ai_features_enabled: APP_AI_FEATURES_ENABLED(),
     };
@@ -904,6 +978,7 @@ pub fn Workspace() -> Element {
                 ai_features_enabled: APP_AI_FEATURES_ENABLED(),
                 show_agent_panel: APP_SHOW_AGENT_PANEL(),
                 show_history,
+                show_notifications,
                 tree_reload,
                 dragging_panel,
                 drop_target,
@@ -916,6 +991,7 @@ pub fn Workspace() -> Element {
                 chat_threads,
                 active_chat_thread_id,
                 connection_label: connection_label.clone(),
+                production,
             }
         }
     }